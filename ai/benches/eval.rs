@@ -0,0 +1,55 @@
+//! Evaluator throughput, run with `cargo bench`
+
+extern crate rand;
+extern crate tendeuce_ai;
+
+use std::time::Instant;
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use tendeuce_ai::util::cardset::*;
+use tendeuce_ai::util::deck::*;
+use tendeuce_ai::util::evaluator::*;
+
+const DEALS: usize = 1_000_000;
+
+/// Runs `f`, which evaluates `hands` hands, and prints the rate
+fn bench(name: &str, hands: usize, f: &mut dyn FnMut() -> usize) {
+    let start = Instant::now();
+    let checksum = f();
+    let seconds = start.elapsed().as_secs_f64();
+    println!("{:<22} {:>8.2} M hands/s  (checksum {})", name, hands as f64 / seconds / 1e6, checksum);
+}
+
+fn main() {
+    // Heads-up deals: a board and two players' hole cards
+    let mut rng = StdRng::seed_from_u64(0xbe7c);
+    let mut deals: Vec<[CardSet; 3]> = Vec::with_capacity(DEALS);
+    for _ in 0..DEALS {
+        let mut deck = Deck::new();
+        deck.shuffle(&mut rng);
+        let mut deal = |n: usize| (0..n).map(|_| deck.deal().unwrap()).collect::<CardSet>();
+        deals.push([deal(5), deal(2), deal(2)]);
+    }
+    let hands: Vec<CardSet> = deals.iter().map(|deal| deal[0] | deal[1]).collect();
+    let ids: Vec<Vec<usize>> = hands.iter().map(|hand| hand.ids().collect()).collect();
+
+    bench("eval", DEALS, &mut || ids.iter().map(|ids| eval(ids.iter(), 7)).sum());
+    bench("eval_set", DEALS, &mut || hands.iter().map(|&hand| eval_set(hand)).sum());
+
+    let mut ranks = vec![0; DEALS];
+    bench("eval_batch", DEALS, &mut || {
+        eval_batch(&hands, &mut ranks);
+        ranks.iter().sum()
+    });
+
+    bench("eval_set, heads-up", 2 * DEALS, &mut || {
+        deals.iter().map(|deal| eval_set(deal[0] | deal[1]) + eval_set(deal[0] | deal[2])).sum()
+    });
+    bench("partial, heads-up", 2 * DEALS, &mut || {
+        deals.iter().map(|deal| {
+            let board = Partial::from(deal[0]);
+            board.with_set(deal[1]).eval() + board.with_set(deal[2]).eval()
+        }).sum()
+    });
+}
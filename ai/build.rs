@@ -0,0 +1,364 @@
+// Generates the evaluator lookup tables and hand descriptors into OUT_DIR.
+// Follows the perfect hash scheme of PHEvaluator: flushes are looked up by the
+// 13 bit rank mask of the flush suit, everything else by a hash of the number
+// of cards of each rank.
+
+use std::collections::HashMap;
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+/// The largest hand the suit and rank hashes are sized for
+const MAX_CARDS: usize = 9;
+
+const RANK_CHARS: [char; 13] = ['2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A'];
+const SINGULAR: [&str; 13] = ["Deuce", "Trey", "Four", "Five", "Six", "Seven", "Eight", "Nine", "Ten", "Jack", "Queen", "King", "Ace"];
+const PLURAL: [&str; 13] = ["Deuces", "Treys", "Fours", "Fives", "Sixes", "Sevens", "Eights", "Nines", "Tens", "Jacks", "Queens", "Kings", "Aces"];
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum Category {
+    StraightFlush,
+    FourOfAKind,
+    FullHouse,
+    Flush,
+    Straight,
+    ThreeOfAKind,
+    TwoPair,
+    Pair,
+    HighCard,
+}
+
+/// One of the 7462 equivalence classes of five card hands, the ranks in order of significance
+#[derive(Copy, Clone)]
+struct Class {
+    category: Category,
+    ranks: [usize; 5],
+}
+
+impl Class {
+    fn is_suited(&self) -> bool {
+        self.category == Category::StraightFlush || self.category == Category::Flush
+    }
+
+    fn is_wheel(&self) -> bool {
+        self.ranks == [3, 2, 1, 0, 12]
+    }
+
+    fn description(&self) -> (String, String) {
+        let cards: Vec<String> = self.ranks.iter().map(|&rank| RANK_CHARS[rank].to_string()).collect();
+        let [a, _, c, d, _] = self.ranks;
+        let name = match self.category {
+            Category::StraightFlush if a == 12 => "Royal Flush".to_string(),
+            Category::StraightFlush => format!("{}-High Straight Flush", SINGULAR[a]),
+            Category::FourOfAKind => format!("Four {}", PLURAL[a]),
+            Category::FullHouse => format!("{} Full over {}", PLURAL[a], PLURAL[d]),
+            Category::Flush => format!("{}-High Flush", SINGULAR[a]),
+            Category::Straight => format!("{}-High Straight", SINGULAR[a]),
+            Category::ThreeOfAKind => format!("Three {}", PLURAL[a]),
+            Category::TwoPair => format!("{} and {}", PLURAL[a], PLURAL[c]),
+            Category::Pair => format!("Pair of {}", PLURAL[a]),
+            Category::HighCard => format!("{}-High", SINGULAR[a]),
+        };
+        (cards.join(" "), name)
+    }
+}
+
+/// The top card of each straight, best first, the wheel last
+fn straights() -> Vec<[usize; 5]> {
+    (3..13).rev().map(|top| [top, (top + 12) % 13, (top + 11) % 13, (top + 10) % 13, (top + 9) % 13]).collect()
+}
+
+/// Every set of five distinct ranks that is not a straight, best first
+fn no_straights() -> Vec<[usize; 5]> {
+    let straights = straights();
+    let mut ranks = Vec::new();
+    for a in (0..13).rev() {
+        for b in (0..a).rev() {
+            for c in (0..b).rev() {
+                for d in (0..c).rev() {
+                    for e in (0..d).rev() {
+                        let hand = [a, b, c, d, e];
+                        let mut wheel = hand;
+                        wheel.rotate_left(1);
+                        if !straights.contains(&hand) && !straights.contains(&wheel) {
+                            ranks.push(hand);
+                        }
+                    }
+                }
+            }
+        }
+    }
+    ranks
+}
+
+/// All classes, in rank order, best first
+fn classes() -> Vec<Class> {
+    let mut classes = vec![];
+    let mut push = |category, ranks| classes.push(Class { category, ranks });
+    let desc = || (0..13).rev();
+
+    for &ranks in straights().iter() {
+        push(Category::StraightFlush, ranks);
+    }
+    for quads in desc() {
+        for kicker in desc().filter(|&kicker| kicker != quads) {
+            push(Category::FourOfAKind, [quads, quads, quads, quads, kicker]);
+        }
+    }
+    for trips in desc() {
+        for pair in desc().filter(|&pair| pair != trips) {
+            push(Category::FullHouse, [trips, trips, trips, pair, pair]);
+        }
+    }
+    for &ranks in no_straights().iter() {
+        push(Category::Flush, ranks);
+    }
+    for &ranks in straights().iter() {
+        push(Category::Straight, ranks);
+    }
+    for trips in desc() {
+        for first in desc().filter(|&first| first != trips) {
+            for second in (0..first).rev().filter(|&second| second != trips) {
+                push(Category::ThreeOfAKind, [trips, trips, trips, first, second]);
+            }
+        }
+    }
+    for high in desc() {
+        for low in (0..high).rev() {
+            for kicker in desc().filter(|&kicker| kicker != high && kicker != low) {
+                push(Category::TwoPair, [high, high, low, low, kicker]);
+            }
+        }
+    }
+    for pair in desc() {
+        for first in desc().filter(|&first| first != pair) {
+            for second in (0..first).rev().filter(|&second| second != pair) {
+                for third in (0..second).rev().filter(|&third| third != pair) {
+                    push(Category::Pair, [pair, pair, first, second, third]);
+                }
+            }
+        }
+    }
+    for &ranks in no_straights().iter() {
+        push(Category::HighCard, ranks);
+    }
+    classes
+}
+
+/// The ranks of a hand sorted high to low, which identifies its class together with the suitedness
+fn key(ranks: &[usize]) -> [usize; 5] {
+    let mut key = [0; 5];
+    key.copy_from_slice(ranks);
+    key.sort_by(|a, b| b.cmp(a));
+    key
+}
+
+/// Calls `f` with every multiset of five ranks within the given number of cards of each rank
+fn for_each_five(counts: &mut [usize; 13], rank: usize, hand: &mut Vec<usize>, f: &mut dyn FnMut(&[usize])) {
+    if hand.len() == 5 {
+        f(hand);
+        return;
+    }
+    if rank == 13 {
+        return;
+    }
+    for take in 0..=counts[rank].min(5 - hand.len()) {
+        for _ in 0..take {
+            hand.push(rank);
+        }
+        for_each_five(counts, rank + 1, hand, f);
+        for _ in 0..take {
+            hand.pop();
+        }
+    }
+}
+
+/// Calls `f` with every number of cards of each rank that adds up to `n`
+fn for_each_quinary(quinary: &mut [usize; 13], rank: usize, n: usize, f: &mut dyn FnMut(&mut [usize; 13])) {
+    if rank == 13 {
+        if n == 0 {
+            f(quinary);
+        }
+        return;
+    }
+    for count in 0..=n.min(4) {
+        quinary[rank] = count;
+        for_each_quinary(quinary, rank + 1, n - count, f);
+    }
+    quinary[rank] = 0;
+}
+
+/// DP[q][n][k] counts the hashes skipped by a rank holding q cards, with n ranks left to fill and k cards left to place
+fn dp() -> Vec<Vec<Vec<usize>>> {
+    // sequences[n][k] is the number of ways to put k cards in n ranks, at most 4 each
+    let mut sequences = vec![vec![0usize; MAX_CARDS + 1]; 14];
+    sequences[0][0] = 1;
+    for n in 1..14 {
+        for k in 0..=MAX_CARDS {
+            sequences[n][k] = (0..=k.min(4)).map(|count| sequences[n - 1][k - count]).sum();
+        }
+    }
+    // The last rank holds all cards that are left, so it never skips any hashes
+    let mut dp = vec![vec![vec![0usize; MAX_CARDS + 1]; 14]; 5];
+    for (q, skipped) in dp.iter_mut().enumerate() {
+        for n in 1..14 {
+            for k in 0..=MAX_CARDS {
+                skipped[n][k] = (0..q.min(k + 1)).map(|count| sequences[n][k - count]).sum();
+            }
+        }
+    }
+    dp
+}
+
+fn hash_quinary(dp: &[Vec<Vec<usize>>], quinary: &[usize; 13], n: usize) -> usize {
+    let mut hash = 0;
+    let mut k = n;
+    for i in 0..13 {
+        hash += dp[quinary[i]][13 - i - 1][k];
+        if k <= quinary[i] {
+            break;
+        }
+        k -= quinary[i];
+    }
+    hash
+}
+
+fn choose(n: usize, k: usize) -> usize {
+    if k > n {
+        return 0;
+    }
+    (0..k).fold(1, |c, i| c * (n - i) / (i + 1))
+}
+
+fn table<T: std::fmt::Display>(out: &mut String, header: &str, values: &[T], per_line: usize) {
+    writeln!(out, "{} = [", header).unwrap();
+    for line in values.chunks(per_line) {
+        let line: Vec<String> = line.iter().map(|value| value.to_string()).collect();
+        writeln!(out, "    {},", line.join(", ")).unwrap();
+    }
+    writeln!(out, "];\n").unwrap();
+}
+
+fn main() {
+    let classes = classes();
+    assert_eq!(classes.len(), 7462);
+
+    let mut ranks: HashMap<(bool, [usize; 5]), usize> = HashMap::new();
+    for (i, class) in classes.iter().enumerate() {
+        ranks.insert((class.is_suited(), key(&class.ranks)), i + 1);
+    }
+
+    let mut out = String::new();
+
+    let value_bins: Vec<String> = (0..52).map(|id| format!("{:#x}", 1 << (id / 4))).collect();
+    table(&mut out, "pub static VALUE_BINS: [usize; 52]", &value_bins, 4);
+    let suit_bins: Vec<String> = (0..52).map(|id| format!("{:#x}", 1 << (3 * (id % 4)))).collect();
+    table(&mut out, "pub static SUIT_BINS: [usize; 52]", &suit_bins, 4);
+
+    writeln!(out, "#[allow(dead_code)]\npub static CHOOSE: [[usize; {}]; 53] = [", MAX_CARDS + 1).unwrap();
+    for n in 0..53 {
+        let row: Vec<String> = (0..=MAX_CARDS).map(|k| choose(n, k).to_string()).collect();
+        writeln!(out, "    [{}],", row.join(", ")).unwrap();
+    }
+    writeln!(out, "];\n").unwrap();
+
+    let dp = dp();
+    writeln!(out, "pub static DP: [[[usize; {}]; 14]; 5] = [", MAX_CARDS + 1).unwrap();
+    for q in dp.iter() {
+        writeln!(out, "    [").unwrap();
+        for n in q.iter() {
+            let row: Vec<String> = n.iter().map(|count| count.to_string()).collect();
+            writeln!(out, "        [{}],", row.join(", ")).unwrap();
+        }
+        writeln!(out, "    ],").unwrap();
+    }
+    writeln!(out, "];\n").unwrap();
+
+    // Three bits per suit, indexed by the sum of SUIT_BINS, the flush suit + 1 or 0.
+    // Eight or more cards of a suit carry into the next suit, where the last hand found is kept.
+    // Such hashes are shared by two hands of more than seven cards.
+    let mut suits = vec![0usize; MAX_CARDS * 0x200 + 1];
+    for clubs in 0..=MAX_CARDS {
+        for diamonds in 0..=MAX_CARDS - clubs {
+            for hearts in 0..=MAX_CARDS - clubs - diamonds {
+                for spades in 0..=MAX_CARDS - clubs - diamonds - hearts {
+                    let counts = [clubs, diamonds, hearts, spades];
+                    let hash = clubs + diamonds * 0x8 + hearts * 0x40 + spades * 0x200;
+                    if let Some(suit) = counts.iter().position(|&count| count >= 5) {
+                        suits[hash] = suit + 1;
+                    }
+                }
+            }
+        }
+    }
+    table(&mut out, &format!("pub static SUITS: [usize; {}]", suits.len()), &suits, 8);
+
+    // The best flush of each 13 bit mask of ranks
+    let mut flush = vec![0usize; 1 << 13];
+    for (mask, best) in flush.iter_mut().enumerate() {
+        let bits: Vec<usize> = (0..13).filter(|rank| mask & (1 << rank) != 0).collect();
+        if bits.len() < 5 || bits.len() > MAX_CARDS {
+            continue;
+        }
+        let mut counts = [0; 13];
+        for &rank in bits.iter() {
+            counts[rank] = 1;
+        }
+        let mut rank = usize::MAX;
+        for_each_five(&mut counts, 0, &mut Vec::new(), &mut |hand| rank = rank.min(ranks[&(true, key(hand))]));
+        *best = rank;
+    }
+    table(&mut out, "pub static FLUSH: [u16; 8192]", &flush, 8);
+
+    // The best hand of each number of cards of each rank, ignoring flushes
+    for n in 5..8 {
+        let mut noflush = Vec::new();
+        for_each_quinary(&mut [0; 13], 0, n, &mut |quinary| {
+            let hash = hash_quinary(&dp, quinary, n);
+            if noflush.len() <= hash {
+                noflush.resize(hash + 1, 0);
+            }
+            let mut rank = usize::MAX;
+            for_each_five(quinary, 0, &mut Vec::new(), &mut |hand| {
+                if let Some(&class) = ranks.get(&(false, key(hand))) {
+                    rank = rank.min(class);
+                }
+            });
+            noflush[hash] = rank;
+        });
+        table(&mut out, &format!("pub static NOFLUSH{}: [u16; {}]", n, noflush.len()), &noflush, 8);
+    }
+
+    // Deuce-to-seven lowball reverses the order, except that A-5-4-3-2 is no straight and
+    // ranks as the best ace high hand of its kind
+    let mut lowball: Vec<(Category, [usize; 5], usize)> = classes.iter().enumerate().map(|(i, class)| {
+        let (category, ranks) = match (class.category, class.is_wheel()) {
+            (Category::StraightFlush, true) => (Category::Flush, [12, 3, 2, 1, 0]),
+            (Category::Straight, true) => (Category::HighCard, [12, 3, 2, 1, 0]),
+            _ => (class.category, class.ranks),
+        };
+        (category, ranks, i + 1)
+    }).collect();
+    lowball.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+    let mut lowball_ranks = vec![0usize; classes.len() + 1];
+    for (i, &(_, _, rank)) in lowball.iter().enumerate() {
+        lowball_ranks[rank] = i + 1;
+    }
+    table(&mut out, &format!("pub static LOWBALL: [u16; {}]", lowball_ranks.len()), &lowball_ranks, 8);
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("lookup.rs"), out).unwrap();
+
+    let mut descriptors = String::new();
+    writeln!(descriptors, "pub static DESCRIPTORS: [(&str, &str); {}] = [", classes.len() + 1).unwrap();
+    writeln!(descriptors, "    (\"\", \"\"),").unwrap();
+    for class in classes.iter() {
+        let (cards, name) = class.description();
+        writeln!(descriptors, "    (\"{}\", \"{}\"),", cards, name).unwrap();
+    }
+    writeln!(descriptors, "];").unwrap();
+    fs::write(Path::new(&out_dir).join("descriptors.rs"), descriptors).unwrap();
+
+    println!("cargo:rerun-if-changed=build.rs");
+}
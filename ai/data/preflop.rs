@@ -0,0 +1,3393 @@
+// Preflop equity of the 169 starting hands, in the order of StartingHand::index.
+// Generated by `cargo run --release --example preflop > data/preflop.rs`,
+// 50000 random boards to an entry, seed 0x169
+
+/// The equity of each starting hand against 1..9 random hands, in percent
+#[allow(clippy::approx_constant)]
+static VERSUS_RANDOM: [[f32; 9]; 169] = [
+    // AA
+    [
+        85.06, 73.31, 63.94, 55.83, 49.47, 43.87, 38.66, 34.79, 31.04,
+    ],
+    // AKs
+    [
+        66.85, 50.99, 41.37, 35.38, 31.02, 27.50, 25.11, 22.81, 20.74,
+    ],
+    // AQs
+    [
+        66.04, 49.35, 39.93, 33.76, 29.45, 25.89, 23.00, 21.11, 19.17,
+    ],
+    // AJs
+    [
+        65.54, 47.76, 38.48, 32.08, 27.91, 24.45, 21.66, 19.67, 18.48,
+    ],
+    // ATs
+    [
+        64.62, 47.42, 37.47, 31.12, 26.77, 23.30, 21.02, 19.25, 17.50,
+    ],
+    // A9s
+    [
+        63.13, 44.83, 35.05, 28.30, 23.88, 21.12, 18.34, 16.66, 15.14,
+    ],
+    // A8s
+    [
+        61.54, 43.64, 33.37, 27.60, 23.07, 20.14, 18.25, 15.89, 14.90,
+    ],
+    // A7s
+    [
+        61.41, 42.32, 32.67, 26.32, 22.47, 19.55, 17.50, 15.59, 14.19,
+    ],
+    // A6s
+    [
+        59.78, 41.30, 31.15, 25.33, 21.56, 18.95, 16.53, 15.15, 14.16,
+    ],
+    // A5s
+    [
+        60.05, 41.08, 32.12, 25.90, 22.21, 19.27, 17.35, 15.64, 14.45,
+    ],
+    // A4s
+    [
+        59.06, 40.72, 30.69, 25.31, 21.58, 18.99, 17.05, 15.45, 14.15,
+    ],
+    // A3s
+    [
+        58.13, 39.31, 30.42, 24.79, 21.00, 18.59, 16.89, 15.04, 14.00,
+    ],
+    // A2s
+    [
+        57.20, 38.83, 29.47, 23.76, 20.59, 18.26, 16.20, 14.63, 13.51,
+    ],
+    // AKo
+    [
+        65.11, 48.25, 38.66, 32.21, 27.87, 24.60, 21.81, 19.29, 17.29,
+    ],
+    // KK
+    [
+        82.40, 69.00, 58.16, 49.90, 42.92, 37.31, 32.76, 29.08, 26.12,
+    ],
+    // KQs
+    [
+        63.40, 46.88, 38.06, 32.44, 28.50, 25.00, 22.52, 20.48, 18.82,
+    ],
+    // KJs
+    [
+        62.61, 45.68, 36.95, 31.15, 26.76, 23.80, 21.46, 19.21, 17.83,
+    ],
+    // KTs
+    [
+        61.59, 44.79, 35.52, 29.66, 25.74, 23.01, 20.26, 18.32, 17.38,
+    ],
+    // K9s
+    [
+        60.16, 42.77, 33.11, 27.23, 23.31, 20.22, 18.23, 16.01, 14.92,
+    ],
+    // K8s
+    [
+        58.47, 40.11, 30.82, 24.97, 21.21, 18.93, 16.68, 14.93, 13.77,
+    ],
+    // K7s
+    [
+        57.37, 39.33, 29.53, 24.27, 20.72, 18.07, 15.86, 14.29, 13.06,
+    ],
+    // K6s
+    [
+        56.48, 38.37, 29.00, 23.71, 19.88, 17.53, 15.73, 13.95, 12.78,
+    ],
+    // K5s
+    [
+        55.20, 37.59, 28.62, 23.04, 19.53, 16.97, 14.89, 13.78, 12.36,
+    ],
+    // K4s
+    [
+        55.20, 36.31, 27.41, 22.19, 19.04, 16.69, 15.08, 13.29, 12.16,
+    ],
+    // K3s
+    [
+        53.81, 35.70, 26.75, 21.98, 18.78, 16.59, 14.46, 13.34, 12.34,
+    ],
+    // K2s
+    [
+        53.36, 34.63, 25.93, 21.23, 18.17, 15.82, 14.04, 13.01, 11.90,
+    ],
+    // AQo
+    [
+        64.33, 46.90, 37.13, 30.42, 25.69, 22.20, 19.73, 17.21, 15.65,
+    ],
+    // KQo
+    [
+        61.53, 44.57, 34.83, 29.13, 25.09, 21.78, 19.04, 16.84, 14.86,
+    ],
+    // QQ
+    [
+        79.73, 64.97, 53.51, 44.56, 37.63, 32.29, 28.53, 24.97, 22.21,
+    ],
+    // QJs
+    [
+        60.51, 43.80, 35.71, 30.07, 26.42, 23.21, 20.39, 19.06, 17.04,
+    ],
+    // QTs
+    [
+        59.79, 43.15, 34.57, 28.66, 25.66, 22.48, 19.89, 18.07, 16.44,
+    ],
+    // Q9s
+    [
+        57.70, 40.68, 31.85, 26.46, 22.66, 19.69, 17.59, 15.99, 14.42,
+    ],
+    // Q8s
+    [
+        55.98, 38.67, 29.80, 24.40, 20.99, 17.88, 16.06, 14.56, 13.39,
+    ],
+    // Q7s
+    [
+        54.23, 36.65, 27.96, 22.64, 19.26, 16.27, 14.72, 13.40, 11.83,
+    ],
+    // Q6s
+    [
+        53.90, 35.83, 26.94, 22.15, 18.48, 16.19, 14.19, 12.62, 11.68,
+    ],
+    // Q5s
+    [
+        52.41, 34.97, 26.68, 21.28, 17.77, 15.55, 13.86, 12.51, 11.47,
+    ],
+    // Q4s
+    [
+        52.04, 34.32, 25.66, 20.42, 17.41, 15.18, 13.78, 12.44, 11.43,
+    ],
+    // Q3s
+    [
+        50.94, 32.66, 24.76, 20.05, 17.30, 15.15, 13.35, 12.42, 11.29,
+    ],
+    // Q2s
+    [
+        49.86, 32.64, 24.39, 19.64, 16.77, 14.63, 12.91, 11.88, 10.93,
+    ],
+    // AJo
+    [
+        64.05, 45.54, 35.36, 28.69, 24.00, 21.33, 18.25, 16.15, 14.29,
+    ],
+    // KJo
+    [
+        60.50, 43.21, 33.84, 27.35, 23.56, 20.10, 17.94, 15.66, 13.99,
+    ],
+    // QJo
+    [
+        58.27, 41.80, 32.59, 27.00, 22.97, 19.85, 17.39, 15.08, 13.76,
+    ],
+    // JJ
+    [
+        77.41, 61.41, 49.24, 40.77, 33.57, 28.37, 24.69, 21.59, 19.42,
+    ],
+    // JTs
+    [
+        57.77, 42.04, 33.64, 28.56, 24.71, 21.80, 19.56, 18.08, 16.48,
+    ],
+    // J9s
+    [
+        55.35, 39.33, 31.48, 25.84, 22.56, 19.76, 17.38, 15.46, 14.50,
+    ],
+    // J8s
+    [
+        53.64, 37.61, 29.11, 24.25, 20.74, 18.02, 15.90, 14.62, 13.15,
+    ],
+    // J7s
+    [
+        52.20, 35.49, 26.76, 21.86, 18.76, 16.33, 14.43, 13.02, 11.85,
+    ],
+    // J6s
+    [
+        50.73, 33.68, 25.32, 20.53, 17.38, 14.87, 13.47, 12.09, 10.99,
+    ],
+    // J5s
+    [
+        49.62, 32.86, 24.53, 20.23, 16.85, 14.70, 13.27, 11.80, 10.84,
+    ],
+    // J4s
+    [
+        49.15, 32.05, 23.82, 19.74, 16.15, 14.30, 12.89, 11.41, 10.70,
+    ],
+    // J3s
+    [
+        48.33, 30.67, 23.23, 19.00, 15.98, 13.83, 12.43, 11.11, 10.62,
+    ],
+    // J2s
+    [
+        47.44, 30.59, 22.65, 18.66, 15.53, 13.71, 12.30, 11.13, 10.42,
+    ],
+    // ATo
+    [
+        63.16, 44.36, 33.93, 27.36, 23.07, 19.72, 17.06, 14.99, 13.51,
+    ],
+    // KTo
+    [
+        59.87, 41.65, 32.54, 25.96, 22.16, 19.08, 16.52, 14.81, 12.93,
+    ],
+    // QTo
+    [
+        57.38, 40.20, 31.32, 25.82, 21.44, 18.35, 15.87, 14.45, 12.85,
+    ],
+    // JTo
+    [
+        55.48, 38.80, 30.68, 25.18, 21.51, 18.50, 16.13, 14.48, 13.24,
+    ],
+    // TT
+    [
+        74.72, 57.87, 45.38, 36.48, 29.83, 25.30, 21.49, 18.76, 17.32,
+    ],
+    // T9s
+    [
+        53.40, 38.89, 30.74, 25.82, 22.53, 19.71, 17.63, 16.26, 14.78,
+    ],
+    // T8s
+    [
+        51.87, 36.71, 29.14, 23.99, 20.67, 17.95, 16.12, 14.83, 13.40,
+    ],
+    // T7s
+    [
+        51.01, 34.30, 26.73, 21.95, 18.68, 16.47, 14.81, 13.38, 12.17,
+    ],
+    // T6s
+    [
+        48.97, 32.57, 25.04, 20.45, 17.60, 15.37, 13.60, 12.18, 10.98,
+    ],
+    // T5s
+    [
+        47.19, 30.55, 23.22, 18.63, 15.96, 13.90, 12.41, 11.12, 10.49,
+    ],
+    // T4s
+    [
+        46.49, 30.08, 22.69, 18.08, 15.69, 13.78, 12.10, 10.67, 9.96,
+    ],
+    // T3s
+    [
+        45.75, 29.22, 22.28, 17.96, 15.55, 13.07, 11.83, 11.11, 9.89,
+    ],
+    // T2s
+    [
+        44.87, 28.97, 21.72, 17.25, 15.19, 12.88, 11.60, 10.55, 9.64,
+    ],
+    // A9o
+    [
+        60.83, 41.80, 30.72, 24.50, 20.12, 16.99, 14.56, 12.61, 11.09,
+    ],
+    // K9o
+    [
+        57.16, 39.58, 29.69, 23.63, 19.44, 16.55, 14.21, 12.24, 10.94,
+    ],
+    // Q9o
+    [
+        55.18, 37.54, 28.31, 22.95, 18.86, 16.55, 14.23, 12.32, 10.77,
+    ],
+    // J9o
+    [
+        53.06, 36.60, 27.75, 22.39, 18.90, 16.01, 13.70, 12.28, 10.85,
+    ],
+    // T9o
+    [
+        51.62, 35.64, 27.59, 22.47, 18.83, 16.38, 14.17, 12.44, 11.19,
+    ],
+    // 99
+    [
+        72.14, 53.86, 41.29, 32.51, 26.65, 22.20, 19.48, 17.16, 15.66,
+    ],
+    // 98s
+    [
+        50.90, 35.98, 28.41, 23.54, 20.15, 17.61, 15.83, 14.58, 13.18,
+    ],
+    // 97s
+    [
+        49.12, 34.16, 26.70, 22.15, 18.72, 16.39, 14.76, 13.42, 12.58,
+    ],
+    // 96s
+    [
+        48.09, 32.21, 24.95, 20.30, 17.28, 15.38, 13.68, 12.35, 11.42,
+    ],
+    // 95s
+    [
+        45.51, 29.61, 23.07, 19.04, 15.97, 13.94, 12.38, 11.32, 10.67,
+    ],
+    // 94s
+    [
+        43.83, 28.57, 21.48, 17.32, 14.72, 12.95, 11.33, 10.49, 9.50,
+    ],
+    // 93s
+    [
+        43.02, 27.72, 20.95, 16.67, 14.58, 12.36, 10.77, 10.22, 9.13,
+    ],
+    // 92s
+    [
+        42.20, 27.25, 20.41, 16.02, 13.69, 12.27, 10.82, 9.93, 9.10,
+    ],
+    // A8o
+    [
+        59.64, 40.50, 29.57, 23.61, 19.29, 15.93, 13.80, 12.16, 10.39,
+    ],
+    // K8o
+    [
+        56.09, 37.41, 27.36, 21.14, 17.50, 14.36, 12.73, 10.76, 9.48,
+    ],
+    // Q8o
+    [
+        53.68, 34.96, 25.84, 20.58, 16.72, 13.88, 12.13, 10.61, 9.23,
+    ],
+    // J8o
+    [
+        51.38, 34.16, 25.25, 20.43, 16.62, 13.86, 12.17, 10.58, 9.44,
+    ],
+    // T8o
+    [
+        49.78, 33.74, 25.18, 20.13, 16.97, 14.08, 12.41, 10.96, 10.14,
+    ],
+    // 98o
+    [
+        48.22, 33.04, 25.07, 20.13, 16.53, 14.28, 12.30, 10.96, 9.88,
+    ],
+    // 88
+    [
+        69.10, 50.00, 37.45, 29.54, 24.05, 20.48, 17.65, 16.02, 14.60,
+    ],
+    // 87s
+    [
+        47.83, 33.57, 26.79, 22.07, 19.12, 16.56, 15.02, 13.55, 12.84,
+    ],
+    // 86s
+    [
+        46.26, 32.04, 24.91, 20.71, 17.71, 15.61, 13.97, 12.83, 11.82,
+    ],
+    // 85s
+    [
+        44.74, 30.09, 23.35, 19.16, 16.08, 14.36, 13.09, 11.83, 11.02,
+    ],
+    // 84s
+    [
+        42.61, 28.50, 21.29, 17.78, 14.87, 12.79, 11.83, 10.63, 9.82,
+    ],
+    // 83s
+    [
+        40.92, 26.37, 19.62, 15.71, 13.53, 12.00, 10.87, 9.77, 8.86,
+    ],
+    // 82s
+    [
+        40.41, 26.08, 19.42, 16.06, 13.32, 11.71, 10.75, 9.72, 8.85,
+    ],
+    // A7o
+    [
+        58.93, 39.10, 28.78, 22.63, 18.33, 15.18, 13.07, 11.51, 10.10,
+    ],
+    // K7o
+    [
+        55.10, 36.21, 26.17, 20.33, 16.39, 13.90, 11.85, 10.28, 8.73,
+    ],
+    // Q7o
+    [
+        51.99, 33.00, 23.60, 18.37, 14.97, 12.38, 10.61, 9.00, 8.12,
+    ],
+    // J7o
+    [
+        49.59, 31.89, 23.28, 18.14, 14.93, 12.23, 10.69, 8.98, 8.07,
+    ],
+    // T7o
+    [
+        48.07, 31.07, 23.46, 18.32, 15.03, 12.72, 11.04, 9.53, 8.52,
+    ],
+    // 97o
+    [
+        46.32, 30.72, 22.69, 18.18, 15.15, 12.99, 11.12, 9.77, 8.81,
+    ],
+    // 87o
+    [
+        45.35, 30.43, 23.22, 18.57, 15.16, 13.20, 11.31, 10.10, 9.31,
+    ],
+    // 77
+    [
+        66.49, 46.78, 34.35, 26.79, 21.75, 18.60, 16.48, 15.03, 13.72,
+    ],
+    // 76s
+    [
+        45.04, 31.93, 24.88, 20.45, 17.92, 16.05, 14.03, 13.36, 12.31,
+    ],
+    // 75s
+    [
+        43.28, 29.96, 23.42, 19.26, 16.64, 14.83, 13.53, 12.45, 11.25,
+    ],
+    // 74s
+    [
+        41.69, 28.39, 21.69, 17.96, 15.54, 13.39, 12.20, 11.27, 10.47,
+    ],
+    // 73s
+    [
+        40.03, 26.55, 20.01, 16.26, 14.29, 12.33, 11.28, 10.22, 9.30,
+    ],
+    // 72s
+    [
+        38.64, 24.71, 18.47, 15.10, 12.52, 11.33, 10.08, 9.19, 8.66,
+    ],
+    // A6o
+    [
+        57.56, 37.73, 27.84, 21.56, 17.58, 14.28, 12.48, 10.61, 9.62,
+    ],
+    // K6o
+    [
+        54.06, 35.16, 25.22, 19.79, 16.06, 13.36, 11.42, 9.55, 8.57,
+    ],
+    // Q6o
+    [
+        50.82, 32.31, 23.36, 17.77, 14.59, 12.21, 10.08, 8.75, 7.50,
+    ],
+    // J6o
+    [
+        47.77, 29.49, 21.11, 16.23, 13.53, 10.73, 9.26, 8.08, 7.01,
+    ],
+    // T6o
+    [
+        46.14, 29.01, 20.92, 16.36, 13.47, 11.14, 9.64, 8.22, 7.38,
+    ],
+    // 96o
+    [
+        44.19, 28.78, 20.99, 16.21, 13.28, 11.19, 9.90, 8.99, 7.64,
+    ],
+    // 86o
+    [
+        42.92, 28.63, 21.31, 16.97, 13.82, 11.81, 10.13, 9.12, 8.18,
+    ],
+    // 76o
+    [
+        42.47, 28.07, 21.18, 17.00, 14.08, 12.13, 10.81, 9.74, 8.72,
+    ],
+    // 66
+    [
+        63.13, 43.22, 30.75, 24.36, 19.96, 16.94, 15.16, 13.97, 12.67,
+    ],
+    // 65s
+    [
+        43.18, 30.32, 24.12, 19.87, 17.07, 15.14, 14.09, 12.85, 12.02,
+    ],
+    // 64s
+    [
+        41.56, 28.43, 22.04, 18.06, 15.67, 13.98, 12.88, 11.76, 10.98,
+    ],
+    // 63s
+    [
+        39.48, 26.62, 20.37, 16.81, 14.42, 12.83, 11.86, 10.68, 10.20,
+    ],
+    // 62s
+    [
+        37.84, 24.78, 18.79, 15.53, 13.23, 11.62, 10.72, 9.72, 9.12,
+    ],
+    // A5o
+    [
+        57.60, 38.14, 27.94, 21.89, 18.01, 15.17, 12.98, 11.35, 10.09,
+    ],
+    // K5o
+    [
+        53.38, 33.63, 24.33, 18.75, 15.13, 12.79, 10.95, 9.40, 8.17,
+    ],
+    // Q5o
+    [
+        50.16, 31.27, 22.28, 17.18, 13.86, 11.58, 9.78, 8.22, 7.34,
+    ],
+    // J5o
+    [
+        47.13, 28.98, 20.87, 15.91, 12.51, 10.50, 8.92, 7.69, 6.84,
+    ],
+    // T5o
+    [
+        44.56, 27.09, 19.20, 14.99, 11.75, 9.98, 8.32, 7.11, 6.38,
+    ],
+    // 95o
+    [
+        42.93, 26.78, 19.36, 14.75, 11.86, 10.21, 8.47, 7.29, 6.63,
+    ],
+    // 85o
+    [
+        41.76, 26.47, 19.13, 15.16, 12.17, 10.35, 8.79, 8.04, 7.25,
+    ],
+    // 75o
+    [
+        40.16, 26.64, 19.87, 15.77, 12.79, 10.94, 9.82, 8.77, 8.07,
+    ],
+    // 65o
+    [
+        39.85, 26.83, 19.72, 16.12, 13.17, 11.45, 10.03, 9.15, 8.35,
+    ],
+    // 55
+    [
+        60.25, 39.91, 29.32, 22.51, 18.38, 16.20, 14.42, 13.53, 12.31,
+    ],
+    // 54s
+    [
+        41.63, 29.13, 22.55, 18.88, 16.48, 15.06, 13.65, 12.60, 11.77,
+    ],
+    // 53s
+    [
+        39.80, 27.06, 21.00, 17.53, 15.44, 13.76, 12.81, 11.52, 10.86,
+    ],
+    // 52s
+    [
+        37.46, 25.45, 19.60, 15.74, 13.94, 12.47, 11.35, 10.52, 10.05,
+    ],
+    // A4o
+    [
+        57.09, 37.37, 27.03, 21.05, 17.28, 14.86, 12.84, 11.09, 10.09,
+    ],
+    // K4o
+    [
+        51.99, 32.51, 23.31, 18.28, 14.86, 12.44, 10.59, 8.98, 8.07,
+    ],
+    // Q4o
+    [
+        48.84, 30.47, 21.60, 16.07, 13.35, 10.99, 9.35, 8.19, 7.05,
+    ],
+    // J4o
+    [
+        46.26, 28.12, 19.76, 15.10, 12.31, 10.00, 8.64, 7.50, 6.52,
+    ],
+    // T4o
+    [
+        43.46, 26.62, 18.64, 14.25, 11.58, 9.68, 7.95, 7.24, 5.96,
+    ],
+    // 94o
+    [
+        40.73, 24.62, 16.91, 12.95, 10.50, 8.38, 7.28, 6.51, 5.61,
+    ],
+    // 84o
+    [
+        39.56, 24.17, 17.49, 13.31, 10.72, 9.02, 7.77, 6.83, 6.17,
+    ],
+    // 74o
+    [
+        38.42, 24.36, 17.87, 14.00, 11.39, 9.69, 8.44, 7.55, 6.74,
+    ],
+    // 64o
+    [
+        37.56, 24.69, 18.32, 14.40, 11.97, 10.41, 9.42, 8.11, 7.62,
+    ],
+    // 54o
+    [
+        38.10, 25.32, 18.83, 15.43, 12.51, 10.99, 9.71, 8.97, 8.16,
+    ],
+    // 44
+    [
+        57.10, 36.74, 26.09, 20.49, 17.39, 15.12, 13.93, 12.58, 12.27,
+    ],
+    // 43s
+    [
+        38.76, 26.67, 20.47, 17.02, 14.95, 13.52, 12.06, 11.25, 10.45,
+    ],
+    // 42s
+    [
+        36.99, 24.93, 19.02, 15.74, 13.39, 12.17, 11.20, 10.42, 9.58,
+    ],
+    // A3o
+    [
+        56.02, 36.69, 26.47, 20.55, 16.88, 14.44, 12.36, 10.90, 9.46,
+    ],
+    // K3o
+    [
+        51.59, 32.37, 22.84, 17.59, 14.38, 12.32, 10.50, 8.63, 7.85,
+    ],
+    // Q3o
+    [
+        48.46, 29.67, 20.57, 15.92, 12.82, 10.71, 9.22, 7.88, 6.93,
+    ],
+    // J3o
+    [
+        45.40, 27.44, 18.85, 14.91, 11.61, 9.81, 8.49, 7.22, 6.21,
+    ],
+    // T3o
+    [
+        43.15, 25.46, 18.05, 13.64, 10.98, 9.10, 7.88, 6.64, 6.04,
+    ],
+    // 93o
+    [
+        39.85, 24.12, 16.88, 12.82, 10.16, 8.48, 7.07, 6.07, 5.48,
+    ],
+    // 83o
+    [
+        37.22, 22.71, 15.65, 11.69, 9.57, 7.79, 6.55, 5.86, 5.13,
+    ],
+    // 73o
+    [
+        36.40, 22.60, 15.91, 12.21, 10.02, 8.17, 7.05, 6.28, 5.74,
+    ],
+    // 63o
+    [
+        36.34, 22.80, 16.47, 12.92, 10.48, 9.05, 7.89, 7.13, 6.27,
+    ],
+    // 53o
+    [
+        36.76, 23.14, 17.15, 13.57, 11.40, 9.71, 8.70, 7.95, 7.35,
+    ],
+    // 43o
+    [
+        34.96, 22.79, 16.49, 12.87, 10.58, 9.52, 8.37, 7.52, 6.96,
+    ],
+    // 33
+    [
+        53.76, 33.71, 23.86, 18.96, 16.31, 14.64, 13.42, 12.71, 12.13,
+    ],
+    // 32s
+    [
+        36.03, 23.99, 18.32, 14.79, 13.35, 11.99, 10.67, 9.76, 9.01,
+    ],
+    // A2o
+    [
+        54.97, 35.20, 25.70, 19.94, 16.44, 14.05, 11.79, 10.53, 9.19,
+    ],
+    // K2o
+    [
+        50.57, 31.09, 21.96, 16.90, 13.82, 11.69, 10.14, 8.74, 7.60,
+    ],
+    // Q2o
+    [
+        47.53, 28.75, 20.21, 15.26, 12.40, 10.30, 8.62, 7.81, 6.91,
+    ],
+    // J2o
+    [
+        44.32, 26.67, 18.66, 14.49, 11.60, 9.48, 8.04, 7.09, 6.17,
+    ],
+    // T2o
+    [
+        42.24, 24.78, 17.41, 13.23, 10.57, 8.82, 7.56, 6.38, 5.87,
+    ],
+    // 92o
+    [
+        38.93, 23.20, 15.85, 12.17, 9.70, 8.03, 6.74, 6.08, 5.19,
+    ],
+    // 82o
+    [
+        37.01, 21.86, 15.30, 11.57, 8.91, 7.54, 6.42, 5.78, 5.10,
+    ],
+    // 72o
+    [
+        34.83, 20.53, 14.30, 10.71, 8.51, 7.01, 6.13, 5.40, 4.70,
+    ],
+    // 62o
+    [
+        34.33, 20.71, 14.66, 11.21, 9.13, 7.68, 6.70, 5.93, 5.49,
+    ],
+    // 52o
+    [
+        34.20, 21.33, 15.55, 11.97, 9.86, 8.59, 7.78, 6.89, 6.32,
+    ],
+    // 42o
+    [
+        33.46, 20.56, 14.63, 11.40, 9.45, 8.07, 7.48, 6.51, 5.95,
+    ],
+    // 32o
+    [
+        32.22, 19.52, 14.04, 10.88, 8.96, 7.70, 6.74, 6.12, 5.71,
+    ],
+    // 22
+    [
+        50.66, 31.03, 22.14, 17.78, 15.58, 13.87, 13.16, 12.32, 12.18,
+    ],
+];
+
+/// The equity of each starting hand against each other, in percent
+#[allow(clippy::approx_constant)]
+static HEAD_TO_HEAD: [[f32; 169]; 169] = [
+    // AA
+    [
+        50.00, 87.79, 87.64, 86.81, 86.79, 88.39, 88.04, 88.02, 88.18, 87.03, 86.85, 87.41, 87.82,
+        93.24, 82.03, 83.28, 82.77, 82.44, 82.82, 83.73, 83.28, 83.52, 83.85, 84.24, 84.55, 84.83,
+        92.53, 87.26, 81.57, 80.97, 80.57, 81.32, 81.95, 83.61, 83.05, 83.42, 83.79, 84.18, 84.72,
+        92.42, 86.66, 84.70, 81.32, 78.94, 79.50, 80.46, 81.98, 83.13, 82.94, 83.37, 83.65, 83.92,
+        91.87, 86.39, 84.42, 82.59, 80.81, 78.07, 78.85, 80.42, 81.07, 83.02, 83.05, 83.51, 83.70,
+        93.87, 86.93, 85.07, 83.18, 81.81, 80.50, 78.14, 78.91, 80.46, 82.09, 83.37, 83.65, 84.05,
+        93.34, 88.26, 86.49, 84.71, 82.85, 81.93, 80.52, 77.55, 78.66, 80.42, 81.84, 83.67, 83.48,
+        93.49, 87.73, 87.31, 85.89, 84.28, 83.08, 81.41, 80.63, 77.89, 78.89, 80.65, 82.20, 83.73,
+        93.48, 87.73, 87.68, 87.29, 85.45, 84.05, 82.68, 81.46, 80.43, 77.29, 78.94, 80.83, 82.43,
+        91.85, 87.97, 87.28, 87.09, 86.97, 86.12, 84.25, 83.02, 81.34, 80.77, 78.67, 80.81, 82.22,
+        92.33, 88.23, 87.96, 87.42, 87.14, 88.02, 86.14, 84.60, 83.46, 82.80, 81.21, 80.92, 82.56,
+        92.77, 88.98, 88.59, 87.90, 87.54, 87.89, 87.68, 86.50, 84.74, 84.65, 84.99, 81.74, 83.20,
+        93.16, 89.34, 88.55, 88.49, 87.84, 88.17, 87.61, 88.07, 86.43, 86.12, 86.71, 87.03, 82.27,
+    ],
+    // AKs
+    [
+        12.21, 50.00, 71.37, 71.23, 70.85, 71.28, 71.01, 70.19, 70.80, 70.00, 70.20, 70.27, 70.93,
+        52.56, 33.89, 71.30, 71.05, 70.67, 72.88, 72.46, 71.82, 72.02, 73.08, 73.24, 73.38, 74.24,
+        75.41, 75.54, 45.82, 63.77, 63.18, 64.36, 65.29, 66.35, 65.99, 65.97, 66.54, 66.58, 67.50,
+        75.27, 75.57, 66.42, 45.83, 61.98, 63.35, 63.89, 65.08, 65.81, 65.78, 65.78, 66.28, 66.78,
+        75.00, 74.94, 66.46, 64.75, 45.75, 61.43, 63.07, 63.56, 64.35, 65.91, 65.94, 66.20, 66.90,
+        75.39, 77.15, 67.56, 66.12, 64.98, 47.15, 63.03, 62.53, 63.47, 64.60, 66.04, 65.42, 66.62,
+        74.89, 76.78, 68.45, 67.35, 65.35, 66.07, 47.44, 61.28, 62.20, 62.98, 64.55, 66.12, 65.99,
+        74.72, 76.61, 69.12, 68.34, 66.72, 65.49, 64.21, 47.85, 60.97, 62.06, 63.95, 64.60, 66.09,
+        74.74, 76.35, 68.99, 68.64, 67.55, 66.04, 65.23, 64.04, 47.87, 61.22, 62.06, 63.62, 64.41,
+        73.78, 77.09, 69.20, 68.60, 68.91, 67.22, 66.32, 65.04, 64.14, 48.16, 61.17, 62.37, 64.02,
+        74.60, 77.30, 69.64, 69.19, 68.96, 68.98, 67.72, 66.32, 65.09, 64.31, 48.76, 62.61, 64.18,
+        74.58, 77.68, 69.60, 69.73, 69.42, 68.95, 68.93, 67.79, 66.38, 65.26, 65.48, 49.00, 64.44,
+        75.07, 78.52, 70.39, 69.84, 69.64, 69.63, 68.87, 68.92, 67.74, 66.86, 67.41, 67.75, 49.77,
+    ],
+    // AQs
+    [
+        12.36, 28.63, 50.00, 70.96, 70.81, 70.61, 70.68, 70.39, 70.44, 69.18, 69.83, 70.31, 70.70,
+        29.93, 31.86, 71.63, 61.76, 61.46, 62.67, 63.47, 63.17, 63.07, 63.38, 63.63, 63.55, 64.22,
+        52.41, 75.33, 33.85, 70.10, 69.95, 71.17, 71.33, 72.49, 71.99, 72.27, 73.14, 73.39, 74.24,
+        74.84, 64.68, 73.90, 45.63, 62.09, 62.89, 64.46, 65.33, 66.31, 65.79, 66.55, 66.71, 67.15,
+        75.00, 64.10, 73.51, 65.01, 45.83, 62.47, 62.86, 64.40, 65.12, 66.37, 65.95, 66.62, 66.85,
+        75.15, 65.71, 75.72, 66.39, 65.00, 47.26, 61.76, 63.39, 63.36, 64.41, 65.90, 65.63, 66.19,
+        74.74, 66.92, 75.52, 66.97, 66.43, 64.69, 47.36, 61.98, 62.21, 63.14, 64.85, 65.88, 65.81,
+        74.68, 66.13, 77.06, 67.83, 66.96, 65.98, 64.48, 48.24, 61.28, 62.31, 63.99, 65.24, 66.34,
+        74.87, 65.94, 76.54, 69.27, 67.75, 66.58, 64.98, 63.78, 48.36, 61.10, 62.44, 63.38, 65.06,
+        73.41, 66.19, 77.01, 69.35, 69.00, 67.55, 66.39, 65.63, 64.07, 48.70, 61.12, 62.89, 63.56,
+        73.88, 66.48, 77.74, 69.66, 69.00, 68.75, 67.89, 66.80, 65.10, 64.34, 48.98, 62.80, 64.67,
+        74.13, 67.05, 78.08, 69.92, 69.59, 68.63, 69.39, 68.25, 66.61, 65.09, 65.46, 49.62, 64.56,
+        75.27, 67.53, 77.77, 70.12, 69.93, 69.43, 69.11, 69.34, 67.77, 66.85, 67.37, 67.64, 50.27,
+    ],
+    // AJs
+    [
+        13.19, 28.77, 29.04, 50.00, 70.23, 70.01, 69.80, 70.14, 69.66, 68.75, 69.12, 68.91, 69.43,
+        30.16, 32.42, 58.90, 71.03, 61.61, 63.37, 64.00, 63.49, 63.20, 63.15, 63.58, 63.67, 63.91,
+        30.34, 61.57, 32.09, 69.80, 61.09, 61.36, 62.47, 63.97, 63.10, 63.36, 63.14, 63.62, 63.80,
+        52.60, 75.35, 74.23, 34.40, 68.57, 70.36, 70.24, 71.48, 72.44, 72.43, 72.53, 73.21, 73.94,
+        74.15, 64.36, 63.41, 72.11, 45.60, 62.08, 63.23, 64.38, 65.16, 67.08, 66.38, 66.99, 67.10,
+        74.11, 65.88, 64.92, 74.25, 65.09, 46.93, 61.74, 62.76, 64.23, 64.68, 66.07, 66.30, 66.35,
+        73.80, 67.10, 65.32, 74.30, 65.93, 64.50, 47.29, 61.52, 62.68, 63.89, 64.83, 66.09, 66.16,
+        73.68, 66.12, 66.34, 75.53, 67.28, 65.39, 64.48, 48.01, 62.05, 62.98, 64.11, 64.83, 66.52,
+        74.10, 66.33, 66.27, 76.69, 68.42, 66.46, 65.60, 64.52, 48.49, 61.80, 62.82, 64.21, 65.16,
+        72.52, 66.50, 66.11, 76.58, 69.55, 67.79, 66.72, 65.78, 64.38, 48.82, 61.89, 62.96, 63.59,
+        72.57, 66.66, 66.58, 77.25, 69.74, 69.06, 68.21, 66.72, 65.70, 64.38, 49.07, 63.22, 64.12,
+        73.35, 67.02, 66.97, 77.63, 70.02, 69.17, 69.09, 68.05, 66.74, 65.63, 66.04, 49.62, 64.96,
+        73.76, 67.56, 66.94, 78.05, 70.17, 69.50, 69.28, 69.51, 68.03, 66.99, 67.37, 67.56, 50.35,
+    ],
+    // ATs
+    [
+        13.21, 29.15, 29.19, 29.77, 50.00, 69.40, 68.93, 68.44, 68.69, 67.50, 67.69, 68.05, 68.24,
+        30.44, 32.51, 58.91, 59.01, 71.06, 63.29, 64.39, 63.69, 62.81, 64.15, 63.79, 64.34, 64.42,
+        30.49, 61.37, 32.22, 58.10, 69.67, 62.26, 62.86, 63.95, 63.40, 63.37, 63.76, 64.09, 64.16,
+        31.20, 61.65, 60.93, 32.32, 68.53, 61.21, 61.63, 62.95, 63.48, 63.37, 63.41, 63.97, 64.33,
+        52.48, 75.37, 73.64, 72.36, 34.76, 68.94, 68.89, 70.19, 71.23, 72.58, 72.91, 73.20, 73.26,
+        72.98, 65.84, 65.14, 63.90, 72.81, 47.41, 62.24, 62.92, 63.54, 65.57, 66.42, 66.30, 66.95,
+        73.15, 67.07, 65.98, 64.27, 72.80, 64.74, 47.66, 61.18, 62.49, 64.15, 65.20, 66.47, 66.54,
+        73.01, 66.62, 66.53, 65.41, 74.18, 65.75, 64.62, 48.12, 61.50, 62.62, 64.24, 65.25, 66.49,
+        72.60, 66.06, 65.93, 66.23, 75.31, 66.58, 64.95, 64.70, 48.73, 62.04, 63.09, 63.96, 65.56,
+        71.78, 66.71, 66.52, 66.46, 76.98, 68.18, 66.85, 65.99, 64.68, 49.10, 61.94, 63.41, 64.25,
+        71.86, 66.58, 66.80, 66.27, 76.90, 69.34, 68.37, 67.01, 65.15, 64.82, 49.43, 63.12, 64.73,
+        71.79, 67.19, 66.98, 66.90, 77.52, 69.30, 69.67, 68.39, 67.03, 66.17, 66.11, 49.99, 64.73,
+        72.54, 67.62, 67.60, 66.97, 77.95, 69.96, 69.57, 69.66, 68.48, 67.12, 67.31, 67.88, 51.07,
+    ],
+    // A9s
+    [
+        11.61, 28.72, 29.39, 29.99, 30.60, 50.00, 66.87, 66.34, 66.19, 64.80, 66.15, 65.67, 66.25,
+        29.89, 31.99, 57.80, 57.28, 57.20, 71.22, 62.86, 62.65, 62.25, 61.97, 63.05, 62.81, 63.14,
+        30.57, 60.41, 31.76, 56.69, 56.58, 69.49, 61.31, 62.46, 62.25, 62.39, 62.73, 62.69, 63.15,
+        31.33, 60.01, 58.99, 31.97, 55.84, 68.47, 61.05, 61.62, 62.49, 62.39, 62.36, 62.63, 62.63,
+        32.29, 59.94, 59.02, 58.08, 31.42, 67.10, 59.62, 60.51, 61.29, 62.10, 62.65, 62.56, 63.30,
+        52.41, 75.59, 73.62, 72.38, 71.10, 33.68, 67.53, 68.62, 69.47, 71.46, 72.97, 73.11, 73.66,
+        70.48, 65.85, 65.05, 63.44, 62.71, 71.40, 45.66, 61.23, 62.15, 63.91, 65.02, 65.83, 66.24,
+        70.10, 65.59, 65.45, 64.42, 63.22, 73.04, 63.85, 46.52, 61.29, 62.68, 63.68, 65.40, 66.11,
+        70.36, 64.91, 65.11, 65.26, 64.05, 74.16, 65.13, 64.07, 46.82, 61.44, 62.44, 63.73, 65.11,
+        68.97, 64.96, 64.92, 64.97, 65.36, 75.64, 66.52, 65.36, 64.05, 47.47, 61.42, 62.48, 63.59,
+        68.73, 65.83, 65.17, 65.40, 65.25, 77.10, 67.85, 66.62, 65.50, 64.36, 48.41, 63.16, 64.61,
+        69.14, 65.34, 65.82, 65.67, 65.60, 77.04, 69.42, 68.41, 66.73, 65.57, 65.99, 49.10, 64.56,
+        69.96, 66.16, 65.66, 66.12, 65.86, 77.51, 69.51, 69.61, 68.30, 66.11, 67.13, 67.39, 49.23,
+    ],
+    // A8s
+    [
+        11.96, 28.99, 29.32, 30.20, 31.07, 33.13, 50.00, 64.64, 64.60, 62.92, 63.12, 63.54, 63.60,
+        30.25, 32.12, 56.94, 56.62, 56.64, 58.99, 72.25, 63.07, 62.47, 62.24, 62.50, 63.01, 63.00,
+        30.97, 59.72, 31.73, 56.12, 56.50, 57.78, 70.71, 63.39, 62.19, 61.94, 62.38, 63.00, 62.76,
+        31.58, 59.70, 58.74, 32.04, 55.31, 56.90, 69.78, 61.64, 62.37, 62.13, 62.51, 63.07, 62.80,
+        32.35, 59.96, 58.69, 57.97, 32.14, 56.04, 68.27, 60.70, 61.89, 62.62, 62.48, 62.28, 62.65,
+        34.62, 61.34, 60.49, 59.69, 58.54, 32.57, 67.05, 59.52, 60.98, 61.75, 62.97, 63.49, 63.04,
+        52.50, 76.86, 75.29, 73.78, 72.28, 70.88, 34.03, 67.89, 68.41, 69.88, 71.59, 72.77, 73.08,
+        68.10, 65.67, 66.10, 64.84, 63.85, 62.81, 71.07, 46.39, 61.63, 62.94, 64.22, 65.30, 67.14,
+        68.04, 65.34, 65.42, 65.70, 64.10, 63.57, 72.58, 64.26, 46.28, 61.62, 62.94, 64.32, 65.32,
+        66.31, 65.60, 65.35, 64.91, 65.35, 64.81, 74.16, 65.92, 63.93, 47.76, 61.60, 62.71, 63.71,
+        67.26, 65.58, 65.24, 65.58, 65.62, 65.86, 76.24, 66.67, 65.39, 64.78, 48.25, 63.46, 64.13,
+        67.34, 65.63, 65.88, 65.54, 65.96, 65.92, 77.39, 68.22, 66.93, 65.46, 66.49, 49.40, 64.52,
+        67.55, 66.25, 66.12, 66.12, 66.11, 65.78, 77.27, 69.71, 68.03, 67.40, 67.02, 67.58, 49.63,
+    ],
+    // A7s
+    [
+        11.98, 29.81, 29.61, 29.86, 31.56, 33.66, 35.36, 50.00, 61.37, 60.32, 60.48, 60.43, 60.43,
+        30.78, 32.11, 57.12, 57.06, 56.88, 58.68, 60.05, 72.18, 62.07, 62.57, 62.91, 62.62, 63.16,
+        30.78, 59.63, 31.87, 55.51, 55.47, 56.76, 58.35, 72.18, 62.51, 62.05, 62.66, 62.66, 62.81,
+        31.64, 59.88, 57.95, 31.78, 55.00, 56.67, 57.48, 70.74, 62.24, 62.53, 62.81, 62.36, 63.11,
+        32.74, 59.42, 58.11, 57.27, 31.98, 55.77, 56.82, 69.18, 61.29, 62.83, 62.15, 61.82, 62.68,
+        34.84, 61.41, 59.90, 59.21, 58.36, 32.61, 56.27, 68.42, 60.62, 61.64, 62.93, 62.58, 63.50,
+        37.45, 62.65, 61.21, 60.73, 59.51, 59.02, 32.63, 66.90, 59.70, 60.46, 61.91, 62.94, 63.28,
+        52.56, 76.60, 76.51, 74.83, 73.10, 72.23, 70.73, 34.65, 67.40, 68.92, 70.69, 71.98, 73.49,
+        65.44, 65.29, 65.08, 65.54, 64.41, 63.92, 62.67, 71.48, 45.96, 61.55, 62.73, 64.38, 65.56,
+        63.89, 65.72, 65.80, 65.48, 65.47, 64.76, 63.51, 73.13, 64.36, 46.87, 61.91, 62.56, 64.16,
+        63.76, 65.39, 65.45, 65.59, 65.45, 66.22, 64.90, 74.85, 66.10, 64.20, 47.70, 63.59, 64.52,
+        64.18, 65.71, 65.59, 65.80, 65.29, 65.80, 65.89, 76.23, 66.91, 65.85, 66.03, 49.48, 64.66,
+        64.57, 66.19, 66.13, 66.14, 66.10, 66.36, 66.27, 77.96, 68.98, 67.14, 67.40, 68.16, 50.20,
+    ],
+    // A6s
+    [
+        11.82, 29.20, 29.56, 30.34, 31.31, 33.81, 35.40, 38.63, 50.00, 56.77, 56.72, 57.38, 57.17,
+        30.53, 32.05, 56.93, 56.51, 56.86, 58.18, 59.30, 59.44, 72.04, 62.79, 62.80, 62.96, 63.06,
+        30.57, 59.93, 31.78, 55.24, 55.41, 57.18, 58.56, 59.27, 72.02, 62.52, 62.68, 62.66, 63.23,
+        31.71, 59.40, 58.09, 31.82, 53.66, 55.86, 56.92, 57.60, 71.89, 61.84, 62.10, 62.30, 62.45,
+        32.62, 59.64, 58.04, 56.51, 32.16, 54.88, 55.97, 57.37, 70.66, 62.42, 62.20, 62.41, 62.19,
+        35.09, 61.68, 60.15, 58.29, 57.61, 32.72, 55.48, 56.72, 69.26, 61.32, 62.84, 62.50, 62.86,
+        36.85, 62.19, 61.06, 59.54, 58.83, 57.94, 32.38, 55.53, 67.67, 60.58, 61.81, 62.81, 62.91,
+        40.52, 62.02, 62.49, 61.12, 59.79, 59.27, 58.49, 32.64, 66.91, 60.00, 61.34, 62.34, 63.08,
+        52.54, 76.86, 76.00, 75.84, 74.40, 73.49, 71.85, 70.65, 34.26, 67.65, 68.65, 70.73, 72.28,
+        59.53, 65.83, 65.65, 65.37, 65.06, 64.82, 63.74, 62.83, 71.46, 46.39, 61.82, 62.73, 63.97,
+        60.06, 65.88, 65.31, 65.16, 64.81, 65.45, 64.72, 64.01, 73.09, 64.23, 47.70, 62.89, 64.20,
+        60.04, 66.01, 66.11, 65.18, 65.54, 65.54, 65.76, 65.05, 74.74, 65.93, 65.87, 48.28, 64.40,
+        60.75, 66.24, 66.03, 65.60, 65.43, 66.09, 65.72, 66.43, 76.39, 67.22, 67.40, 67.78, 49.39,
+    ],
+    // A5s
+    [
+        12.97, 30.00, 30.82, 31.25, 32.50, 35.20, 37.08, 39.68, 43.23, 50.00, 54.94, 55.46, 55.29,
+        31.51, 33.67, 57.76, 56.88, 56.72, 58.99, 59.67, 60.01, 59.45, 72.81, 64.26, 64.17, 63.82,
+        32.02, 60.56, 33.32, 56.07, 55.48, 57.15, 58.61, 59.65, 59.19, 72.23, 63.59, 63.87, 64.24,
+        32.99, 59.84, 58.66, 33.59, 54.54, 56.02, 57.24, 58.47, 59.15, 71.60, 63.38, 63.09, 63.59,
+        33.85, 59.75, 58.33, 57.43, 33.03, 54.50, 55.61, 57.13, 57.87, 71.68, 63.08, 63.02, 63.83,
+        36.44, 61.40, 59.95, 58.67, 57.52, 33.90, 55.63, 56.84, 57.69, 70.93, 63.86, 63.55, 63.79,
+        38.52, 62.68, 61.42, 59.92, 58.66, 57.94, 33.95, 55.75, 56.45, 69.51, 62.47, 63.84, 63.68,
+        41.98, 62.71, 62.44, 61.12, 59.75, 59.53, 58.19, 33.77, 56.03, 68.46, 62.06, 62.93, 64.58,
+        45.30, 62.57, 62.15, 62.30, 60.42, 59.96, 59.32, 58.99, 34.50, 66.78, 61.00, 62.36, 63.56,
+        52.58, 76.75, 75.79, 75.89, 75.72, 74.70, 73.46, 72.10, 70.43, 36.59, 69.13, 70.51, 72.06,
+        57.92, 67.00, 66.75, 66.55, 65.83, 67.00, 65.67, 64.09, 64.19, 73.20, 48.05, 64.60, 65.37,
+        58.49, 66.86, 66.87, 66.40, 66.15, 66.80, 66.79, 65.90, 64.89, 74.81, 67.34, 48.71, 65.92,
+        58.26, 67.67, 67.20, 66.69, 66.29, 66.63, 66.83, 67.76, 66.47, 76.70, 68.98, 68.75, 50.03,
+    ],
+    // A4s
+    [
+        13.15, 29.80, 30.17, 30.88, 32.31, 33.85, 36.88, 39.52, 43.28, 45.06, 50.00, 52.73, 53.12,
+        30.97, 32.99, 57.36, 56.96, 56.83, 57.88, 59.33, 59.39, 59.10, 60.20, 72.52, 63.88, 64.53,
+        31.57, 60.18, 32.87, 55.78, 55.53, 57.29, 58.36, 59.13, 59.03, 60.09, 72.37, 63.94, 63.59,
+        32.59, 59.88, 58.43, 32.81, 54.65, 55.83, 57.08, 58.03, 59.40, 59.68, 72.06, 63.38, 63.49,
+        33.66, 59.84, 57.72, 56.87, 33.03, 54.90, 55.66, 56.87, 57.91, 59.77, 71.64, 63.33, 63.23,
+        35.77, 61.33, 59.49, 58.53, 57.19, 33.60, 54.80, 55.79, 56.84, 59.13, 71.77, 63.20, 63.28,
+        38.79, 62.58, 60.92, 59.64, 58.46, 57.45, 33.55, 55.05, 56.03, 58.33, 70.34, 63.47, 63.34,
+        41.41, 62.05, 62.05, 61.04, 59.28, 58.65, 58.22, 34.17, 55.48, 57.69, 69.50, 63.03, 63.86,
+        45.40, 62.07, 62.13, 61.79, 60.81, 59.53, 58.99, 57.75, 34.39, 56.65, 68.29, 61.52, 62.62,
+        47.08, 63.30, 62.83, 62.73, 62.37, 61.80, 61.07, 60.52, 59.33, 33.67, 68.57, 60.63, 62.16,
+        52.48, 77.08, 76.55, 76.16, 75.60, 75.90, 74.39, 73.73, 72.18, 72.08, 36.70, 71.06, 72.25,
+        55.67, 66.96, 66.87, 66.74, 66.26, 66.14, 65.96, 65.63, 64.53, 63.73, 74.91, 48.90, 66.00,
+        55.93, 67.26, 66.95, 66.47, 66.46, 66.22, 66.46, 67.13, 65.76, 64.74, 76.13, 68.96, 49.83,
+    ],
+    // A3s
+    [
+        12.59, 29.73, 29.69, 31.09, 31.95, 34.33, 36.46, 39.57, 42.62, 44.54, 47.27, 50.00, 52.49,
+        30.32, 32.60, 57.51, 56.89, 56.73, 58.18, 58.86, 59.23, 59.30, 59.89, 60.51, 72.50, 64.10,
+        31.51, 59.89, 32.30, 55.53, 55.34, 56.83, 57.36, 58.99, 58.86, 59.97, 60.13, 72.33, 63.75,
+        32.10, 59.34, 58.26, 32.60, 54.41, 55.78, 56.74, 57.34, 58.80, 59.76, 59.76, 72.12, 63.63,
+        33.52, 59.07, 58.13, 57.45, 32.51, 54.15, 55.49, 56.64, 57.53, 59.37, 59.57, 72.00, 62.98,
+        36.24, 60.96, 59.92, 58.82, 57.09, 33.41, 54.60, 55.71, 56.60, 58.69, 59.87, 71.34, 63.29,
+        38.30, 61.71, 60.44, 59.79, 58.14, 57.67, 33.13, 54.62, 55.47, 57.33, 58.57, 72.12, 63.04,
+        41.76, 61.66, 61.90, 60.19, 59.63, 58.75, 56.75, 33.87, 54.82, 56.79, 58.03, 71.13, 63.53,
+        45.04, 61.64, 61.35, 61.47, 60.28, 59.44, 58.00, 57.63, 33.81, 55.82, 57.85, 69.55, 62.88,
+        46.86, 62.98, 62.76, 62.41, 62.53, 61.51, 60.08, 59.78, 58.93, 33.17, 56.29, 69.56, 61.87,
+        50.01, 63.31, 63.23, 62.96, 62.25, 62.87, 61.02, 61.08, 60.25, 59.70, 33.90, 70.00, 62.37,
+        52.54, 77.35, 76.53, 76.34, 76.04, 75.92, 75.41, 74.43, 73.52, 73.38, 73.97, 37.50, 72.48,
+        55.66, 66.70, 66.66, 66.54, 66.02, 66.18, 65.75, 66.71, 66.26, 64.77, 65.17, 76.45, 49.56,
+    ],
+    // A2s
+    [
+        12.18, 29.07, 29.30, 30.57, 31.76, 33.75, 36.40, 39.57, 42.83, 44.71, 46.88, 47.51, 50.00,
+        29.86, 32.06, 57.06, 56.91, 56.52, 58.17, 58.59, 58.35, 58.73, 59.81, 59.92, 60.80, 72.98,
+        31.03, 60.00, 32.45, 55.44, 55.85, 56.97, 57.78, 58.21, 58.50, 59.68, 59.90, 60.12, 72.73,
+        31.98, 60.11, 58.60, 31.97, 54.67, 55.77, 56.64, 57.11, 58.50, 59.04, 59.60, 59.70, 72.35,
+        32.99, 59.26, 57.69, 57.00, 32.39, 54.86, 55.30, 56.28, 57.00, 59.05, 59.10, 59.64, 71.73,
+        35.57, 60.74, 59.66, 58.26, 57.25, 33.03, 54.98, 55.66, 56.27, 58.63, 60.13, 59.68, 71.72,
+        37.96, 61.62, 60.70, 59.16, 58.31, 57.25, 33.63, 54.02, 55.14, 57.32, 58.40, 59.73, 71.75,
+        41.27, 61.78, 61.35, 60.19, 58.93, 58.28, 57.12, 33.54, 53.77, 56.86, 57.41, 58.64, 71.76,
+        44.49, 61.44, 61.00, 61.05, 60.17, 59.32, 57.83, 56.31, 33.34, 55.31, 56.86, 57.68, 70.23,
+        46.51, 62.62, 62.36, 62.30, 61.94, 61.20, 59.81, 59.09, 58.16, 33.61, 55.93, 57.16, 70.58,
+        49.59, 62.72, 62.55, 62.26, 61.85, 62.50, 61.34, 60.24, 59.37, 58.84, 34.15, 57.67, 71.61,
+        49.61, 63.31, 63.32, 62.48, 62.44, 62.47, 62.25, 61.39, 60.75, 59.82, 60.57, 34.35, 71.54,
+        52.45, 77.09, 76.95, 76.60, 75.98, 76.15, 75.74, 76.09, 75.15, 74.85, 75.35, 75.84, 37.67,
+    ],
+    // AKo
+    [
+        6.76, 47.44, 70.07, 69.84, 69.56, 70.11, 69.75, 69.22, 69.47, 68.49, 69.03, 69.68, 70.14,
+        50.00, 30.16, 70.66, 69.91, 69.94, 71.93, 71.30, 71.02, 71.48, 71.93, 72.07, 73.04, 73.40,
+        74.22, 74.85, 43.33, 60.87, 60.68, 61.76, 63.22, 63.88, 63.26, 63.89, 64.25, 64.44, 65.00,
+        74.32, 74.65, 65.04, 43.15, 59.44, 60.86, 61.41, 62.46, 63.24, 63.50, 63.66, 64.33, 64.05,
+        73.79, 74.21, 64.70, 63.32, 43.20, 59.68, 60.21, 61.62, 62.05, 63.52, 63.24, 63.94, 64.19,
+        74.44, 76.19, 65.58, 64.37, 63.06, 45.15, 60.29, 59.96, 60.92, 62.14, 63.53, 63.23, 64.02,
+        73.96, 75.91, 66.69, 65.02, 63.68, 64.13, 44.72, 58.93, 59.92, 60.82, 62.06, 63.56, 63.62,
+        73.77, 75.63, 68.00, 66.64, 64.76, 63.99, 62.62, 44.90, 58.58, 59.66, 61.00, 62.30, 63.58,
+        74.16, 75.83, 67.29, 67.01, 66.22, 64.69, 63.41, 62.02, 45.17, 58.49, 59.80, 61.08, 62.46,
+        72.83, 76.04, 67.89, 67.09, 67.31, 65.94, 64.68, 63.29, 62.12, 45.49, 58.79, 60.10, 61.03,
+        73.20, 76.74, 67.92, 67.81, 67.50, 67.20, 66.04, 65.06, 63.54, 62.43, 45.81, 59.97, 61.75,
+        73.96, 77.20, 68.47, 67.76, 67.65, 67.18, 67.12, 66.15, 65.19, 63.65, 63.83, 46.33, 62.31,
+        74.03, 77.41, 68.44, 68.36, 68.07, 67.63, 67.50, 67.23, 66.31, 65.29, 65.31, 65.76, 47.51,
+    ],
+    // KK
+    [
+        17.97, 66.11, 68.14, 67.58, 67.49, 68.01, 67.88, 67.89, 67.95, 66.33, 67.01, 67.40, 67.94,
+        69.84, 50.00, 86.14, 85.70, 85.13, 87.06, 88.43, 88.15, 88.16, 87.97, 88.75, 88.74, 89.30,
+        72.05, 91.19, 81.51, 82.18, 81.88, 81.85, 82.47, 83.79, 83.68, 83.27, 84.21, 84.25, 84.61,
+        71.23, 90.88, 86.19, 81.32, 80.39, 80.25, 80.79, 82.19, 83.17, 83.00, 83.64, 83.77, 84.17,
+        70.77, 90.43, 86.03, 84.65, 80.99, 78.66, 79.43, 80.61, 81.85, 82.94, 83.22, 83.17, 83.77,
+        71.91, 92.20, 86.35, 84.57, 82.63, 80.55, 78.42, 78.89, 80.44, 81.68, 82.91, 83.11, 83.82,
+        71.70, 93.73, 86.75, 85.01, 83.03, 82.15, 80.66, 78.08, 79.24, 80.46, 81.88, 83.81, 84.07,
+        71.15, 93.37, 87.99, 86.26, 84.21, 82.88, 82.15, 80.58, 77.45, 78.86, 80.47, 82.13, 83.93,
+        71.98, 93.47, 87.60, 87.53, 85.66, 84.21, 82.89, 81.31, 80.59, 77.20, 78.83, 80.93, 82.50,
+        70.15, 93.24, 87.43, 87.21, 87.02, 85.88, 84.41, 82.88, 81.47, 80.19, 77.30, 78.92, 80.78,
+        70.43, 93.75, 87.86, 87.51, 87.07, 87.34, 86.00, 84.45, 82.96, 81.23, 80.88, 79.62, 80.96,
+        71.04, 94.23, 88.51, 87.88, 87.15, 87.53, 88.06, 86.10, 84.93, 83.32, 83.13, 81.51, 81.45,
+        71.18, 94.41, 89.02, 88.34, 87.96, 87.74, 87.93, 87.85, 86.50, 84.85, 85.30, 85.75, 81.60,
+    ],
+    // KQs
+    [
+        16.72, 28.70, 28.37, 41.10, 41.09, 42.20, 43.06, 42.88, 43.07, 42.24, 42.64, 42.49, 42.94,
+        29.34, 13.86, 50.00, 70.62, 70.67, 70.78, 70.96, 70.74, 70.47, 70.85, 71.15, 71.80, 72.17,
+        29.63, 52.42, 35.16, 70.48, 70.50, 71.32, 72.36, 72.38, 72.56, 71.94, 72.05, 72.81, 73.14,
+        43.99, 74.91, 74.95, 46.60, 63.16, 64.31, 65.12, 66.14, 66.80, 67.19, 66.75, 67.14, 67.64,
+        43.71, 74.67, 74.85, 66.22, 46.66, 62.93, 63.92, 65.04, 65.86, 66.82, 66.96, 67.10, 67.70,
+        45.48, 74.65, 75.27, 67.16, 65.71, 46.95, 63.03, 62.88, 64.28, 64.90, 66.24, 66.24, 66.63,
+        46.29, 75.26, 76.67, 68.09, 67.06, 65.97, 48.61, 63.20, 63.05, 63.54, 65.45, 66.24, 66.58,
+        45.54, 74.92, 76.68, 68.89, 67.44, 66.44, 66.50, 48.42, 62.03, 62.96, 63.99, 64.95, 66.40,
+        45.52, 74.83, 75.48, 70.26, 68.34, 66.55, 65.67, 64.41, 48.77, 61.56, 62.63, 64.03, 65.02,
+        45.20, 74.47, 75.99, 70.04, 69.77, 67.34, 66.77, 65.51, 64.79, 49.73, 61.66, 62.79, 64.19,
+        45.88, 75.24, 76.91, 70.06, 69.51, 68.97, 68.49, 66.66, 65.63, 64.52, 50.25, 62.93, 64.33,
+        45.69, 75.80, 77.22, 70.41, 70.09, 69.12, 69.63, 68.22, 67.19, 65.74, 65.87, 51.17, 64.79,
+        45.66, 76.04, 77.80, 71.00, 70.11, 69.70, 69.82, 69.53, 68.25, 66.87, 67.25, 67.73, 51.54,
+    ],
+    // KJs
+    [
+        17.23, 28.95, 38.24, 28.97, 40.99, 42.72, 43.38, 42.94, 43.49, 43.12, 43.04, 43.11, 43.09,
+        30.09, 14.30, 29.38, 50.00, 69.77, 70.25, 70.00, 69.62, 69.86, 69.73, 70.31, 70.45, 70.91,
+        40.91, 30.16, 32.19, 70.94, 61.85, 62.84, 63.72, 64.68, 64.36, 64.08, 64.26, 64.65, 64.65,
+        29.66, 52.44, 75.09, 35.85, 69.45, 69.82, 70.78, 70.92, 72.07, 72.23, 72.27, 72.94, 73.08,
+        43.72, 73.74, 64.55, 73.12, 46.45, 63.50, 64.66, 65.19, 65.75, 66.85, 66.68, 66.91, 67.24,
+        45.46, 74.38, 65.82, 73.60, 66.19, 47.44, 62.51, 63.18, 64.71, 65.64, 66.11, 66.55, 66.93,
+        45.94, 74.34, 66.38, 75.16, 66.99, 65.75, 48.60, 62.10, 63.40, 63.83, 65.27, 66.88, 66.29,
+        45.58, 74.01, 67.69, 75.22, 68.24, 66.48, 65.14, 48.92, 62.04, 62.74, 63.79, 65.24, 65.94,
+        46.14, 73.96, 67.43, 76.62, 69.30, 67.37, 66.68, 64.98, 49.41, 61.75, 63.20, 64.49, 65.37,
+        46.22, 73.95, 67.26, 76.38, 69.72, 68.38, 66.62, 65.76, 65.01, 49.78, 61.36, 62.79, 63.82,
+        45.60, 74.34, 67.19, 76.61, 69.58, 69.34, 68.28, 66.96, 66.00, 64.43, 50.51, 63.34, 64.28,
+        45.80, 74.96, 68.20, 77.09, 70.51, 69.73, 69.38, 68.23, 67.67, 65.77, 66.01, 51.08, 65.22,
+        46.11, 75.25, 68.32, 77.82, 70.88, 69.62, 69.04, 69.73, 68.73, 67.23, 67.76, 68.10, 51.35,
+    ],
+    // KTs
+    [
+        17.56, 29.33, 38.54, 38.39, 28.94, 42.80, 43.36, 43.12, 43.14, 43.28, 43.17, 43.27, 43.48,
+        30.06, 14.87, 29.33, 30.23, 50.00, 69.00, 69.36, 69.64, 68.69, 69.25, 69.02, 69.27, 69.94,
+        41.26, 30.73, 32.45, 59.24, 70.64, 63.12, 64.20, 64.67, 64.19, 64.79, 64.76, 65.15, 64.78,
+        40.94, 31.31, 61.24, 32.45, 69.12, 62.25, 63.11, 63.86, 65.01, 64.40, 64.14, 64.76, 65.23,
+        29.85, 52.52, 74.36, 72.93, 35.65, 68.27, 70.24, 70.04, 70.93, 72.24, 72.14, 73.09, 73.50,
+        45.43, 73.34, 65.95, 65.20, 72.10, 47.18, 62.70, 63.13, 64.57, 65.50, 66.45, 66.94, 67.03,
+        45.73, 73.45, 66.90, 65.86, 74.33, 66.10, 48.39, 62.39, 63.34, 64.07, 65.42, 66.72, 66.34,
+        45.78, 72.99, 67.91, 66.30, 74.01, 66.44, 65.13, 48.38, 61.91, 62.98, 64.26, 65.16, 66.57,
+        46.25, 73.18, 67.60, 67.66, 74.97, 67.09, 66.01, 64.83, 49.41, 62.37, 63.44, 64.68, 65.66,
+        46.24, 73.03, 67.81, 67.20, 76.30, 68.64, 66.98, 65.62, 65.18, 50.28, 62.23, 63.21, 64.89,
+        45.96, 73.21, 67.54, 67.50, 76.45, 69.89, 68.30, 67.05, 66.35, 64.61, 50.78, 63.16, 65.06,
+        46.14, 73.68, 67.77, 67.79, 77.19, 69.54, 69.90, 68.63, 67.10, 66.28, 66.16, 51.45, 65.05,
+        46.31, 73.60, 68.36, 68.17, 77.25, 70.25, 69.90, 69.56, 68.39, 67.61, 67.93, 68.35, 52.20,
+    ],
+    // K9s
+    [
+        17.18, 27.12, 37.33, 36.63, 36.71, 28.78, 41.01, 41.32, 41.82, 41.01, 42.12, 41.82, 41.83,
+        28.07, 12.94, 29.22, 29.75, 31.00, 50.00, 67.54, 66.86, 66.85, 66.65, 67.02, 67.17, 67.70,
+        40.16, 30.76, 31.62, 57.51, 56.91, 70.85, 63.06, 63.83, 63.22, 63.33, 63.06, 63.50, 63.94,
+        39.40, 31.57, 59.64, 31.56, 56.02, 69.49, 61.84, 62.77, 63.04, 63.02, 63.15, 63.37, 63.61,
+        39.73, 32.29, 60.07, 58.59, 31.58, 67.92, 60.65, 61.36, 62.26, 63.09, 63.03, 63.58, 63.65,
+        29.89, 52.48, 74.74, 72.97, 71.80, 35.03, 68.74, 68.72, 69.51, 70.59, 72.16, 72.26, 73.25,
+        44.02, 71.48, 65.74, 64.77, 63.72, 72.77, 47.00, 61.61, 62.77, 63.95, 65.23, 66.45, 66.38,
+        43.97, 70.90, 66.54, 65.54, 64.37, 72.63, 64.75, 46.93, 61.25, 62.40, 64.25, 64.96, 66.08,
+        44.58, 70.63, 66.42, 66.18, 65.43, 73.71, 65.99, 64.60, 47.92, 61.68, 63.12, 64.00, 65.09,
+        44.18, 70.54, 66.10, 65.86, 66.19, 74.56, 66.71, 65.44, 64.54, 48.69, 61.57, 62.67, 63.77,
+        44.71, 70.91, 66.36, 66.39, 66.28, 76.47, 68.42, 66.94, 65.82, 64.77, 49.58, 62.95, 64.66,
+        45.02, 70.86, 66.66, 66.64, 66.09, 76.61, 69.27, 68.33, 66.63, 65.63, 66.07, 49.86, 64.83,
+        44.65, 71.61, 66.96, 66.81, 66.48, 77.28, 69.70, 69.39, 68.32, 67.37, 67.48, 67.51, 50.66,
+    ],
+    // K8s
+    [
+        16.27, 27.54, 36.53, 36.00, 35.61, 37.14, 27.75, 39.95, 40.70, 40.33, 40.67, 41.14, 41.41,
+        28.70, 11.57, 29.04, 30.00, 30.64, 32.46, 50.00, 64.66, 64.29, 63.88, 64.17, 64.54, 64.34,
+        39.40, 29.96, 30.97, 56.17, 55.74, 57.73, 70.75, 62.88, 62.14, 61.96, 61.99, 62.62, 62.86,
+        38.52, 31.20, 58.51, 31.46, 54.82, 56.94, 69.40, 61.60, 62.64, 62.08, 62.35, 62.54, 62.69,
+        37.94, 32.30, 58.77, 57.66, 31.32, 56.15, 67.72, 60.54, 61.71, 62.49, 61.94, 62.56, 62.49,
+        39.57, 33.87, 60.37, 59.36, 58.21, 31.95, 67.01, 59.71, 60.39, 61.55, 62.49, 62.81, 62.93,
+        28.66, 52.51, 74.73, 73.15, 71.66, 71.29, 33.78, 67.45, 68.44, 69.35, 70.82, 72.41, 72.52,
+        42.95, 68.22, 65.72, 64.31, 63.22, 62.25, 70.96, 45.96, 61.97, 62.29, 64.03, 64.94, 66.18,
+        43.19, 67.69, 64.84, 65.62, 64.50, 63.35, 72.34, 63.86, 46.60, 61.16, 62.78, 63.88, 65.42,
+        42.85, 67.65, 64.96, 64.61, 65.40, 64.46, 73.34, 64.66, 63.88, 47.34, 61.33, 62.76, 63.85,
+        43.71, 67.62, 64.94, 65.47, 65.13, 64.84, 74.92, 66.74, 65.34, 63.68, 48.54, 63.21, 64.17,
+        44.09, 68.48, 65.54, 65.68, 65.51, 65.31, 76.82, 68.45, 66.97, 65.33, 66.13, 49.70, 64.05,
+        43.84, 68.78, 65.71, 65.55, 65.44, 65.58, 76.92, 69.68, 68.50, 67.03, 66.87, 67.66, 50.13,
+    ],
+    // K7s
+    [
+        16.72, 28.18, 36.83, 36.51, 36.31, 37.35, 36.93, 27.82, 40.56, 39.99, 40.61, 40.77, 41.65,
+        28.98, 11.85, 29.26, 30.38, 30.36, 33.14, 35.34, 50.00, 61.16, 60.94, 61.39, 61.37, 62.06,
+        39.24, 30.59, 31.67, 55.34, 55.14, 57.03, 58.43, 71.87, 62.20, 62.34, 62.50, 62.46, 62.78,
+        39.26, 31.03, 57.70, 31.27, 54.59, 56.00, 57.98, 70.30, 62.61, 62.43, 62.50, 62.97, 63.24,
+        38.87, 32.14, 58.05, 57.09, 31.51, 55.37, 56.65, 69.16, 61.47, 62.84, 61.89, 62.37, 62.31,
+        40.24, 34.45, 59.91, 58.73, 58.37, 32.36, 56.40, 67.76, 60.97, 61.63, 62.70, 63.04, 62.84,
+        39.59, 37.55, 61.12, 60.01, 59.64, 58.76, 32.56, 66.54, 60.05, 60.95, 62.14, 63.11, 63.17,
+        28.58, 52.56, 76.28, 74.96, 73.10, 71.91, 70.86, 34.41, 67.29, 68.78, 70.04, 71.17, 72.83,
+        43.07, 64.64, 65.39, 65.59, 64.56, 63.71, 62.61, 71.09, 46.10, 61.70, 63.02, 63.70, 65.59,
+        43.15, 65.10, 65.53, 65.29, 65.36, 64.34, 63.44, 72.64, 64.69, 47.85, 61.51, 62.80, 64.36,
+        43.51, 64.73, 65.41, 65.11, 64.95, 65.79, 64.64, 73.96, 65.81, 64.32, 48.42, 63.04, 64.24,
+        43.83, 65.30, 65.36, 65.48, 65.32, 65.53, 66.02, 75.64, 67.30, 65.64, 65.70, 48.86, 64.92,
+        44.07, 65.38, 65.95, 66.04, 65.59, 65.84, 65.96, 77.08, 68.44, 67.26, 67.30, 67.78, 50.32,
+    ],
+    // K6s
+    [
+        16.48, 27.98, 36.93, 36.80, 37.19, 37.75, 37.53, 37.93, 27.96, 40.55, 40.90, 40.70, 41.27,
+        28.52, 11.84, 29.53, 30.14, 31.31, 33.15, 35.71, 38.84, 50.00, 58.08, 58.45, 58.33, 58.35,
+        39.57, 30.52, 31.75, 55.32, 55.45, 57.37, 58.31, 59.68, 71.74, 62.47, 62.91, 62.69, 62.76,
+        39.16, 31.20, 58.40, 31.61, 54.31, 55.58, 56.68, 57.70, 71.55, 62.21, 62.18, 62.24, 62.66,
+        38.94, 32.77, 58.18, 56.17, 31.58, 55.16, 55.72, 57.14, 70.06, 62.53, 62.24, 62.40, 62.35,
+        40.21, 34.62, 59.50, 58.41, 57.36, 32.11, 55.76, 56.82, 68.92, 61.90, 62.72, 62.13, 62.56,
+        40.55, 37.68, 61.19, 59.79, 58.89, 58.70, 33.17, 55.83, 68.08, 60.58, 61.52, 63.12, 63.22,
+        39.85, 39.96, 62.34, 60.78, 60.28, 58.87, 58.72, 32.76, 66.54, 59.35, 61.13, 62.05, 63.61,
+        28.70, 52.48, 75.85, 75.95, 73.97, 73.02, 71.70, 70.01, 34.64, 67.19, 68.90, 70.06, 71.41,
+        43.07, 61.15, 65.60, 65.19, 65.70, 64.56, 63.43, 62.39, 71.13, 46.94, 61.10, 62.87, 64.08,
+        43.44, 61.79, 65.32, 65.52, 64.99, 65.36, 64.69, 63.79, 72.60, 64.56, 47.87, 63.27, 64.22,
+        43.81, 61.70, 65.69, 65.46, 65.56, 65.86, 66.12, 64.88, 74.39, 65.77, 65.91, 48.70, 64.46,
+        44.31, 61.73, 66.13, 65.71, 65.53, 65.46, 65.94, 66.29, 75.94, 66.70, 67.42, 67.52, 49.52,
+    ],
+    // K5s
+    [
+        16.15, 26.92, 36.62, 36.85, 35.85, 38.03, 37.76, 37.43, 37.21, 27.19, 39.80, 40.11, 40.19,
+        28.07, 12.03, 29.15, 30.27, 30.75, 33.35, 36.12, 39.06, 41.92, 50.00, 55.19, 55.11, 55.76,
+        39.52, 30.33, 32.07, 55.67, 55.21, 56.92, 58.15, 59.50, 59.35, 71.90, 63.13, 62.88, 63.14,
+        39.45, 31.74, 58.30, 31.96, 54.38, 55.98, 57.03, 58.17, 58.99, 70.91, 62.43, 62.92, 62.93,
+        38.89, 32.40, 57.59, 56.79, 31.38, 54.23, 55.60, 56.61, 57.79, 71.11, 62.16, 61.91, 61.92,
+        40.18, 34.82, 59.71, 58.32, 57.31, 32.26, 55.00, 55.87, 57.56, 70.05, 62.53, 62.35, 62.51,
+        40.21, 37.61, 61.11, 59.76, 57.86, 57.87, 33.13, 55.89, 56.83, 68.59, 61.81, 63.35, 62.50,
+        40.07, 40.24, 62.48, 60.98, 59.50, 59.00, 58.06, 33.10, 55.84, 67.46, 61.29, 62.21, 63.09,
+        39.99, 44.08, 61.85, 62.12, 60.39, 60.03, 59.47, 58.42, 33.67, 66.39, 59.98, 61.22, 62.58,
+        28.75, 52.48, 75.92, 75.55, 75.46, 73.77, 72.68, 71.56, 70.18, 35.41, 67.36, 68.55, 70.24,
+        41.92, 58.29, 65.97, 65.51, 65.41, 65.91, 64.96, 63.55, 62.45, 70.96, 47.61, 63.06, 64.59,
+        42.87, 58.60, 66.16, 65.55, 65.47, 65.85, 66.11, 64.81, 64.27, 73.02, 66.27, 48.10, 64.69,
+        43.19, 58.57, 66.47, 66.13, 65.58, 65.90, 66.18, 66.34, 65.30, 74.56, 67.58, 67.77, 49.50,
+    ],
+    // K4s
+    [
+        15.76, 26.76, 36.37, 36.42, 36.21, 36.95, 37.50, 37.09, 37.20, 35.74, 27.48, 39.49, 40.08,
+        27.93, 11.25, 28.85, 29.69, 30.98, 32.98, 35.83, 38.61, 41.55, 44.81, 50.00, 52.72, 53.00,
+        39.08, 30.21, 31.13, 55.09, 55.24, 55.98, 58.07, 58.96, 58.95, 59.07, 71.63, 62.89, 63.21,
+        38.88, 31.12, 58.37, 31.39, 54.03, 55.25, 56.42, 57.64, 59.24, 58.96, 71.28, 62.81, 62.86,
+        38.73, 32.20, 57.43, 57.17, 31.27, 54.42, 55.41, 56.62, 58.16, 59.40, 71.00, 62.43, 62.32,
+        40.10, 34.56, 59.19, 58.09, 56.76, 31.95, 54.37, 55.71, 56.32, 57.49, 70.86, 62.20, 62.25,
+        40.15, 37.71, 60.74, 59.20, 58.24, 57.00, 32.88, 54.67, 55.97, 57.39, 70.00, 62.78, 62.93,
+        39.81, 40.31, 62.02, 60.56, 59.30, 58.08, 57.85, 33.10, 55.11, 56.04, 68.86, 61.90, 62.92,
+        39.21, 43.64, 62.19, 61.61, 60.47, 58.69, 58.88, 57.72, 32.87, 55.91, 67.74, 61.03, 62.35,
+        38.21, 46.94, 61.67, 61.65, 61.61, 60.36, 59.54, 59.32, 58.50, 33.41, 66.31, 60.07, 60.92,
+        28.66, 52.46, 76.15, 75.68, 75.27, 75.14, 73.95, 72.57, 71.49, 70.53, 35.71, 68.99, 70.55,
+        42.30, 55.84, 65.91, 65.28, 65.32, 65.10, 65.81, 64.81, 63.67, 62.32, 72.92, 48.07, 64.73,
+        42.79, 56.07, 66.24, 66.27, 65.54, 65.22, 65.44, 65.73, 65.22, 63.97, 74.51, 67.17, 49.68,
+    ],
+    // K3s
+    [
+        15.45, 26.62, 36.45, 36.33, 35.66, 37.19, 36.99, 37.38, 37.04, 35.83, 36.12, 27.50, 39.20,
+        26.96, 11.26, 28.20, 29.55, 30.73, 32.83, 35.46, 38.63, 41.67, 44.89, 47.28, 50.00, 52.75,
+        38.43, 29.50, 31.34, 54.80, 55.12, 56.39, 57.27, 58.37, 58.71, 58.89, 59.32, 71.80, 62.82,
+        38.63, 30.73, 57.64, 31.34, 53.76, 55.39, 56.21, 57.01, 58.79, 58.80, 58.79, 71.41, 62.67,
+        38.83, 31.99, 57.80, 56.72, 31.14, 54.60, 54.93, 56.43, 57.48, 58.85, 58.50, 71.35, 62.50,
+        39.87, 34.39, 59.42, 57.92, 56.72, 31.86, 54.50, 55.20, 56.40, 57.80, 58.97, 71.16, 62.30,
+        39.59, 37.17, 60.04, 58.81, 57.82, 57.06, 32.38, 54.48, 55.10, 56.27, 57.86, 70.95, 62.47,
+        39.82, 40.10, 61.83, 60.71, 59.15, 57.90, 56.72, 32.78, 54.78, 55.83, 57.14, 69.98, 62.62,
+        39.81, 43.59, 61.48, 61.44, 59.90, 59.06, 57.79, 57.18, 33.06, 55.00, 56.30, 68.99, 61.57,
+        38.60, 47.00, 61.78, 61.50, 61.57, 60.54, 59.61, 58.77, 58.04, 33.44, 55.43, 67.65, 60.75,
+        38.34, 49.86, 61.95, 61.70, 61.88, 61.34, 60.85, 59.91, 59.49, 58.03, 34.06, 67.81, 60.89,
+        28.39, 52.53, 76.35, 75.58, 75.35, 75.09, 75.48, 73.96, 72.80, 71.49, 71.99, 36.38, 70.58,
+        42.07, 55.46, 66.06, 65.66, 65.60, 64.79, 65.60, 65.75, 64.81, 63.90, 64.04, 74.44, 48.70,
+    ],
+    // K2s
+    [
+        15.17, 25.76, 35.78, 36.09, 35.58, 36.86, 37.00, 36.84, 36.94, 36.18, 35.47, 35.90, 27.02,
+        26.60, 10.70, 27.83, 29.09, 30.06, 32.30, 35.66, 37.94, 41.65, 44.24, 47.00, 47.25, 50.00,
+        38.70, 29.36, 30.83, 55.78, 54.99, 56.70, 57.26, 57.74, 58.18, 58.44, 58.71, 59.32, 72.04,
+        38.36, 30.10, 58.06, 30.81, 53.67, 55.01, 56.45, 57.17, 57.92, 58.29, 58.85, 59.06, 71.39,
+        37.80, 31.08, 57.55, 56.60, 31.07, 54.06, 55.12, 55.98, 57.19, 58.45, 58.58, 58.96, 71.41,
+        39.85, 33.77, 58.78, 58.02, 56.38, 31.40, 54.29, 54.78, 55.77, 57.36, 58.37, 58.68, 71.15,
+        39.66, 36.91, 59.80, 59.38, 57.84, 57.18, 32.50, 54.19, 55.47, 56.99, 57.62, 58.98, 71.38,
+        39.03, 39.90, 61.07, 60.07, 58.56, 57.63, 56.81, 32.47, 54.09, 54.94, 56.28, 57.29, 71.09,
+        39.26, 43.42, 60.84, 60.84, 59.95, 58.93, 58.06, 57.11, 32.46, 54.37, 55.70, 57.10, 69.79,
+        38.43, 46.54, 61.61, 60.69, 61.08, 60.25, 59.35, 57.80, 57.31, 32.77, 55.11, 56.16, 69.04,
+        38.44, 49.18, 61.37, 61.28, 61.31, 61.27, 60.52, 58.66, 58.58, 58.18, 33.76, 56.36, 69.29,
+        38.53, 49.58, 62.18, 61.65, 61.24, 61.55, 62.02, 60.24, 59.63, 58.26, 59.83, 34.32, 69.69,
+        27.94, 52.45, 76.32, 75.85, 75.65, 75.67, 75.27, 75.17, 74.25, 72.79, 73.23, 73.61, 36.55,
+    ],
+    // AQo
+    [
+        7.47, 24.59, 47.59, 69.66, 69.51, 69.43, 69.03, 69.22, 69.43, 67.98, 68.43, 68.49, 68.97,
+        25.78, 27.95, 70.37, 59.09, 58.74, 59.84, 60.60, 60.76, 60.43, 60.48, 60.92, 61.57, 61.30,
+        50.00, 74.71, 30.83, 68.71, 68.55, 70.33, 70.09, 71.78, 71.53, 71.61, 72.16, 72.95, 73.25,
+        73.83, 62.93, 72.90, 43.02, 60.07, 60.98, 61.84, 62.68, 63.71, 63.76, 64.45, 64.31, 64.71,
+        73.55, 62.27, 72.73, 63.09, 42.96, 59.89, 60.27, 61.95, 62.38, 63.61, 63.73, 63.74, 64.32,
+        73.77, 63.56, 74.96, 64.56, 63.22, 44.65, 59.13, 60.56, 60.88, 62.26, 63.67, 63.43, 64.18,
+        73.08, 64.35, 74.50, 65.43, 64.07, 62.55, 44.79, 59.02, 59.69, 60.63, 61.95, 63.51, 63.53,
+        73.54, 64.38, 76.08, 66.87, 65.07, 63.85, 62.71, 45.16, 59.19, 60.12, 61.36, 62.82, 63.97,
+        73.45, 63.97, 75.66, 67.95, 66.63, 64.63, 63.27, 62.28, 45.65, 58.76, 60.07, 61.44, 62.22,
+        72.15, 64.46, 75.99, 67.78, 67.69, 66.26, 64.61, 63.48, 62.31, 45.74, 58.88, 60.19, 61.44,
+        72.43, 64.49, 76.60, 68.08, 67.51, 67.12, 65.89, 65.00, 63.98, 62.59, 46.57, 60.78, 61.72,
+        72.94, 65.12, 77.23, 68.27, 67.23, 67.47, 67.18, 66.38, 64.80, 63.43, 64.33, 47.33, 61.65,
+        73.60, 65.59, 77.36, 68.81, 68.48, 67.76, 67.44, 68.01, 66.04, 65.38, 65.41, 65.56, 47.70,
+    ],
+    // KQo
+    [
+        12.74, 24.46, 24.67, 38.43, 38.63, 39.59, 40.28, 40.37, 40.07, 39.44, 39.82, 40.11, 40.00,
+        25.15, 8.81, 47.58, 69.84, 69.27, 69.24, 70.04, 69.41, 69.48, 69.67, 69.79, 70.50, 70.64,
+        25.29, 50.00, 31.48, 69.83, 69.79, 70.07, 71.26, 71.29, 70.73, 70.96, 71.61, 71.90, 72.72,
+        39.83, 74.07, 74.63, 43.60, 60.97, 61.99, 63.12, 64.10, 65.08, 64.48, 64.57, 65.05, 65.71,
+        40.15, 73.66, 74.06, 64.45, 43.66, 60.61, 61.65, 62.36, 63.29, 64.33, 64.68, 64.72, 65.31,
+        42.11, 73.73, 74.35, 65.75, 64.05, 45.01, 60.21, 60.99, 61.39, 62.37, 63.60, 63.93, 64.05,
+        42.70, 74.47, 76.01, 66.65, 64.90, 64.09, 46.28, 61.01, 60.19, 61.43, 62.95, 63.70, 64.17,
+        42.45, 73.67, 75.68, 67.32, 65.97, 64.44, 64.51, 46.44, 59.10, 60.30, 61.27, 62.41, 64.22,
+        42.41, 73.63, 75.49, 68.77, 67.38, 65.36, 64.42, 63.23, 46.38, 59.00, 60.38, 61.50, 63.20,
+        41.82, 73.77, 75.52, 68.08, 68.04, 66.18, 65.28, 63.82, 62.56, 46.80, 59.07, 60.41, 61.80,
+        42.17, 74.12, 76.03, 68.67, 68.20, 67.89, 66.66, 65.25, 64.28, 62.74, 47.58, 60.71, 61.86,
+        42.39, 74.35, 76.79, 69.01, 68.79, 67.46, 68.04, 66.90, 65.21, 63.82, 64.44, 47.82, 62.39,
+        42.19, 75.12, 76.93, 69.58, 69.18, 67.97, 67.96, 68.00, 66.83, 65.35, 66.10, 65.97, 48.50,
+    ],
+    // QQ
+    [
+        18.43, 54.18, 66.15, 67.91, 67.78, 68.24, 68.27, 68.13, 68.22, 66.68, 67.13, 67.70, 67.55,
+        56.67, 18.49, 64.84, 67.81, 67.55, 68.38, 69.03, 68.33, 68.25, 67.93, 68.87, 68.66, 69.17,
+        69.17, 68.52, 50.00, 84.12, 83.64, 85.67, 86.74, 88.22, 87.66, 87.64, 88.38, 88.50, 88.82,
+        71.74, 71.81, 89.27, 81.93, 81.66, 81.87, 82.14, 82.29, 83.90, 83.50, 83.68, 83.67, 84.64,
+        71.38, 71.17, 88.79, 85.85, 81.46, 80.24, 80.20, 81.08, 81.69, 83.37, 83.39, 83.69, 84.35,
+        72.08, 71.41, 90.76, 86.15, 84.42, 81.00, 79.24, 79.47, 80.58, 81.62, 83.28, 83.33, 83.72,
+        71.74, 72.42, 92.16, 86.02, 84.82, 82.96, 80.92, 78.44, 79.15, 80.14, 81.98, 83.64, 83.43,
+        71.55, 72.20, 93.27, 86.53, 84.53, 83.35, 81.99, 80.99, 77.99, 79.24, 80.61, 82.26, 83.91,
+        71.52, 71.76, 93.04, 88.05, 86.35, 84.53, 83.16, 81.70, 80.64, 77.67, 79.08, 80.53, 82.34,
+        70.21, 71.84, 93.20, 87.57, 87.54, 85.65, 84.48, 83.09, 81.48, 80.54, 77.86, 79.27, 81.05,
+        70.61, 72.59, 93.38, 87.81, 87.35, 87.47, 86.19, 84.76, 82.92, 81.36, 81.01, 79.70, 80.96,
+        70.74, 72.46, 93.90, 88.33, 87.78, 87.70, 87.57, 86.69, 85.02, 82.90, 83.68, 81.12, 81.75,
+        71.31, 72.85, 94.30, 88.85, 88.12, 87.93, 87.86, 88.24, 86.73, 84.85, 85.24, 85.49, 81.82,
+    ],
+    // QJs
+    [
+        19.03, 36.23, 29.90, 30.20, 41.90, 43.31, 43.88, 44.49, 44.76, 43.93, 44.22, 44.47, 44.56,
+        39.13, 17.82, 29.52, 29.06, 40.76, 42.49, 43.83, 44.66, 44.68, 44.33, 44.91, 45.20, 44.22,
+        31.29, 30.17, 15.88, 50.00, 69.91, 70.06, 70.10, 70.53, 70.45, 69.59, 70.22, 71.10, 71.39,
+        31.28, 30.30, 52.51, 37.01, 70.29, 70.53, 70.94, 71.98, 72.21, 71.65, 72.20, 72.79, 73.04,
+        44.51, 43.66, 73.92, 74.32, 47.51, 64.76, 65.45, 66.35, 67.50, 68.05, 68.18, 68.38, 68.46,
+        46.33, 45.52, 73.96, 74.13, 67.24, 48.16, 63.55, 64.34, 65.35, 65.80, 67.08, 67.53, 67.87,
+        46.63, 46.68, 73.81, 74.98, 67.71, 66.35, 49.30, 63.22, 64.14, 64.64, 65.40, 67.13, 67.20,
+        47.34, 47.55, 74.46, 76.47, 68.89, 67.08, 66.10, 50.13, 63.47, 63.31, 64.44, 66.01, 67.44,
+        47.34, 47.38, 74.54, 76.22, 70.09, 67.81, 67.19, 66.41, 50.88, 62.25, 63.69, 64.91, 66.07,
+        46.47, 47.24, 73.82, 76.01, 70.68, 69.22, 67.57, 66.30, 64.98, 51.04, 61.92, 63.06, 64.24,
+        46.92, 47.76, 74.24, 76.20, 70.81, 70.30, 68.54, 67.84, 66.50, 64.94, 51.20, 63.91, 64.74,
+        46.90, 47.70, 74.90, 77.10, 71.37, 70.31, 70.21, 69.16, 67.45, 66.38, 66.54, 51.90, 65.44,
+        46.96, 47.93, 75.28, 77.52, 71.76, 70.89, 70.31, 70.58, 69.18, 67.21, 68.06, 68.56, 52.50,
+    ],
+    // QTs
+    [
+        19.43, 36.82, 30.05, 38.91, 30.33, 43.42, 43.50, 44.53, 44.59, 44.52, 44.47, 44.66, 44.15,
+        39.32, 18.12, 29.50, 38.15, 29.36, 43.09, 44.26, 44.86, 44.55, 44.79, 44.76, 44.88, 45.01,
+        31.45, 30.21, 16.36, 30.09, 50.00, 69.16, 68.78, 69.43, 68.94, 69.02, 69.19, 69.85, 70.26,
+        41.86, 40.80, 31.40, 32.68, 70.12, 63.01, 63.55, 64.76, 65.61, 65.58, 65.53, 65.68, 65.91,
+        31.31, 30.06, 52.41, 74.33, 37.08, 69.22, 69.30, 71.29, 70.52, 72.02, 71.93, 72.37, 73.03,
+        46.44, 45.90, 72.94, 65.92, 73.38, 48.15, 63.49, 64.67, 64.93, 66.53, 67.27, 67.16, 67.67,
+        46.79, 46.62, 73.17, 66.96, 73.55, 66.54, 49.02, 63.04, 63.90, 64.58, 65.93, 67.17, 66.93,
+        47.45, 47.33, 73.55, 67.58, 75.23, 67.64, 65.82, 50.53, 62.91, 64.20, 64.53, 66.00, 67.19,
+        47.55, 47.26, 72.96, 68.23, 74.94, 68.31, 66.50, 65.43, 50.37, 62.48, 63.15, 64.69, 65.84,
+        47.12, 47.47, 73.07, 68.68, 76.43, 69.48, 67.64, 66.50, 65.60, 51.52, 62.76, 63.76, 64.86,
+        46.65, 47.49, 73.65, 68.33, 76.18, 70.58, 69.16, 67.96, 66.36, 65.42, 51.90, 63.94, 65.29,
+        47.54, 48.19, 73.79, 69.07, 76.86, 70.50, 70.31, 68.53, 67.91, 66.10, 66.81, 52.26, 65.49,
+        47.57, 47.54, 74.19, 69.15, 77.47, 71.27, 70.74, 70.36, 69.13, 67.90, 68.18, 68.40, 52.79,
+    ],
+    // Q9s
+    [
+        18.68, 35.64, 28.83, 38.64, 37.74, 30.51, 42.22, 43.24, 42.82, 42.85, 42.71, 43.17, 43.03,
+        38.24, 18.15, 28.68, 37.16, 36.88, 29.15, 42.27, 42.97, 42.63, 43.08, 44.02, 43.61, 43.30,
+        29.67, 29.93, 14.33, 29.94, 30.84, 50.00, 67.15, 67.21, 66.86, 66.86, 67.12, 67.59, 67.87,
+        40.49, 39.70, 30.89, 32.27, 57.14, 70.22, 62.68, 63.36, 64.17, 63.81, 64.26, 64.39, 64.80,
+        40.23, 39.01, 32.22, 59.35, 32.34, 68.53, 61.86, 62.56, 63.28, 63.98, 64.15, 64.52, 65.06,
+        31.63, 30.59, 52.60, 74.19, 72.57, 36.16, 68.42, 69.88, 69.41, 70.63, 71.82, 72.12, 72.31,
+        44.72, 44.88, 71.22, 65.76, 64.33, 71.90, 47.62, 63.08, 63.55, 64.40, 65.59, 67.20, 67.17,
+        45.82, 45.51, 71.34, 66.74, 65.52, 73.55, 65.81, 48.92, 62.39, 63.25, 64.40, 65.24, 66.66,
+        46.02, 45.67, 71.07, 68.04, 66.45, 72.82, 66.22, 64.96, 48.61, 61.89, 63.20, 64.46, 65.93,
+        45.77, 45.98, 70.80, 66.73, 67.16, 74.83, 67.29, 65.62, 64.61, 49.46, 61.92, 62.88, 64.25,
+        45.84, 46.51, 71.07, 67.03, 67.21, 76.31, 68.69, 67.29, 65.94, 65.07, 50.52, 63.24, 64.86,
+        45.71, 46.86, 71.56, 67.04, 67.48, 76.66, 69.99, 68.47, 67.20, 66.05, 66.82, 50.66, 64.76,
+        45.81, 46.31, 71.99, 67.74, 67.45, 76.97, 70.43, 69.77, 68.69, 67.34, 67.92, 67.99, 51.29,
+    ],
+    // Q8s
+    [
+        18.05, 34.71, 28.67, 37.53, 37.14, 38.69, 29.29, 41.65, 41.44, 41.39, 41.64, 42.64, 42.22,
+        36.78, 17.53, 27.64, 36.28, 35.80, 36.94, 29.25, 41.57, 41.69, 41.85, 41.93, 42.73, 42.74,
+        29.91, 28.74, 13.26, 29.90, 31.22, 32.85, 50.00, 64.97, 65.05, 64.48, 64.27, 64.96, 65.27,
+        39.99, 39.10, 31.27, 31.84, 55.86, 57.22, 70.18, 62.49, 63.87, 62.64, 63.01, 63.47, 63.47,
+        39.81, 38.82, 32.14, 58.45, 31.34, 56.43, 68.83, 61.63, 62.60, 63.07, 62.91, 63.62, 63.85,
+        41.08, 39.65, 34.27, 59.70, 59.12, 32.35, 67.44, 59.94, 61.66, 62.36, 63.32, 63.76, 63.53,
+        30.14, 30.49, 52.43, 74.26, 72.48, 71.35, 35.03, 68.36, 68.00, 69.46, 70.83, 72.50, 72.31,
+        44.61, 44.65, 68.61, 65.79, 64.46, 63.66, 72.56, 47.21, 61.72, 62.88, 63.90, 64.98, 66.77,
+        44.35, 44.27, 68.44, 66.49, 65.22, 64.31, 72.26, 64.88, 47.11, 61.45, 63.04, 64.53, 65.51,
+        44.33, 44.41, 68.04, 66.32, 65.80, 64.94, 73.35, 65.46, 64.32, 48.65, 61.52, 62.74, 63.93,
+        44.93, 44.86, 68.27, 65.91, 66.52, 66.27, 74.86, 66.91, 65.92, 64.47, 49.55, 63.29, 64.41,
+        45.56, 45.11, 68.55, 66.29, 66.66, 66.74, 76.56, 68.10, 67.08, 65.72, 66.25, 50.30, 64.47,
+        45.52, 45.64, 69.25, 66.55, 66.53, 66.72, 76.36, 69.99, 68.65, 67.08, 67.09, 68.00, 50.27,
+    ],
+    // Q7s
+    [
+        16.39, 33.65, 27.51, 36.03, 36.05, 37.54, 36.61, 27.82, 40.73, 40.35, 40.87, 41.01, 41.79,
+        36.12, 16.21, 27.62, 35.32, 35.33, 36.17, 37.12, 28.13, 40.32, 40.50, 41.04, 41.63, 42.26,
+        28.22, 28.71, 11.78, 29.47, 30.57, 32.79, 35.03, 50.00, 61.17, 61.25, 61.34, 61.54, 61.87,
+        38.89, 37.82, 30.64, 31.12, 54.41, 56.26, 58.04, 69.98, 62.85, 62.07, 62.41, 62.98, 63.20,
+        38.61, 37.67, 32.27, 57.19, 31.08, 55.26, 56.77, 68.57, 61.65, 62.44, 62.33, 62.46, 62.28,
+        40.19, 39.06, 33.85, 58.82, 57.71, 31.90, 55.70, 67.13, 60.43, 61.60, 62.62, 62.28, 62.86,
+        39.56, 39.88, 36.58, 60.27, 58.75, 58.76, 32.80, 66.34, 59.46, 60.32, 61.49, 62.61, 63.18,
+        29.46, 29.17, 52.55, 74.31, 72.68, 71.49, 70.23, 34.60, 66.48, 67.92, 69.19, 71.00, 72.05,
+        43.19, 43.17, 64.73, 65.66, 64.51, 63.69, 62.12, 70.82, 46.90, 61.12, 62.49, 63.87, 65.22,
+        43.40, 43.29, 64.94, 65.33, 64.94, 64.28, 63.28, 71.68, 64.17, 47.13, 61.41, 62.60, 63.86,
+        43.40, 43.83, 64.88, 65.41, 65.03, 65.16, 64.11, 73.42, 65.39, 63.99, 48.04, 62.66, 64.03,
+        44.08, 44.08, 64.92, 65.54, 65.07, 65.37, 66.07, 74.82, 67.10, 65.40, 65.89, 49.15, 64.54,
+        44.41, 44.58, 65.91, 65.69, 65.35, 66.18, 65.67, 76.66, 68.31, 66.97, 67.20, 67.63, 50.35,
+    ],
+    // Q6s
+    [
+        16.95, 34.01, 28.01, 36.90, 36.60, 37.75, 37.81, 37.49, 27.98, 40.81, 40.97, 41.14, 41.50,
+        36.74, 16.32, 27.44, 35.64, 35.81, 36.78, 37.86, 37.80, 28.26, 40.65, 41.05, 41.29, 41.82,
+        28.47, 29.27, 12.34, 29.55, 31.06, 33.14, 34.95, 38.83, 50.00, 58.00, 58.22, 58.09, 58.50,
+        39.49, 38.45, 30.96, 31.16, 54.20, 55.58, 56.76, 58.28, 71.35, 62.49, 62.60, 62.93, 62.89,
+        39.29, 38.48, 32.35, 56.39, 31.20, 54.59, 56.53, 57.46, 70.10, 62.66, 62.34, 62.50, 62.79,
+        40.79, 39.58, 34.25, 58.30, 57.46, 32.08, 55.29, 56.92, 68.71, 61.85, 63.03, 62.82, 62.60,
+        40.52, 40.33, 36.81, 59.91, 59.20, 58.23, 33.09, 56.42, 67.56, 60.83, 61.87, 63.43, 63.06,
+        40.53, 40.18, 40.44, 61.15, 60.50, 59.55, 58.73, 33.34, 66.31, 60.17, 60.92, 61.98, 63.43,
+        29.15, 29.30, 52.40, 75.49, 73.87, 72.67, 71.32, 70.19, 35.06, 66.46, 68.08, 69.82, 71.11,
+        43.19, 43.38, 61.49, 65.58, 65.63, 64.47, 63.61, 62.59, 70.37, 47.28, 61.61, 62.29, 64.41,
+        44.12, 43.69, 61.70, 65.54, 65.59, 66.08, 64.50, 63.80, 72.11, 63.79, 48.38, 63.19, 64.32,
+        44.24, 44.30, 61.81, 65.51, 65.69, 65.54, 66.10, 65.47, 73.77, 65.50, 65.49, 49.06, 64.62,
+        44.58, 44.52, 61.95, 65.62, 66.08, 65.50, 65.84, 66.63, 75.32, 67.04, 67.18, 67.44, 49.94,
+    ],
+    // Q5s
+    [
+        16.58, 34.03, 27.73, 36.64, 36.63, 37.61, 38.06, 37.95, 37.48, 27.77, 39.91, 40.03, 40.32,
+        36.11, 16.73, 28.06, 35.92, 35.21, 36.67, 38.04, 37.66, 37.53, 28.10, 40.93, 41.11, 41.56,
+        28.39, 29.04, 12.36, 30.41, 30.98, 33.14, 35.52, 38.75, 42.00, 50.00, 55.27, 55.32, 55.47,
+        39.50, 38.79, 31.40, 31.42, 54.01, 55.28, 56.81, 58.40, 59.62, 71.08, 62.93, 62.90, 62.75,
+        38.97, 38.30, 32.21, 56.79, 31.31, 54.13, 55.59, 57.08, 58.16, 71.12, 62.50, 62.75, 62.99,
+        40.18, 39.58, 34.66, 58.29, 56.98, 32.45, 55.03, 55.95, 57.07, 69.22, 63.01, 62.32, 62.70,
+        40.53, 40.51, 37.01, 59.34, 58.26, 57.43, 32.85, 55.60, 56.59, 68.55, 61.56, 63.23, 62.72,
+        40.34, 40.10, 40.73, 61.09, 59.05, 58.85, 58.43, 33.28, 55.98, 67.42, 61.40, 62.28, 63.24,
+        40.16, 40.09, 43.79, 62.44, 60.88, 60.27, 59.08, 58.64, 33.79, 65.81, 60.36, 61.61, 62.64,
+        28.81, 29.49, 52.55, 75.16, 74.96, 73.75, 72.07, 71.08, 69.69, 35.27, 67.02, 68.16, 70.02,
+        42.70, 43.53, 58.12, 65.64, 65.20, 65.71, 64.61, 63.90, 63.26, 70.93, 47.80, 62.92, 64.65,
+        42.89, 43.93, 58.57, 65.62, 65.05, 65.61, 66.01, 64.97, 64.10, 72.09, 65.81, 48.91, 64.75,
+        43.42, 43.96, 58.60, 66.30, 65.74, 65.66, 65.79, 66.36, 65.48, 73.92, 67.42, 67.72, 49.68,
+    ],
+    // Q4s
+    [
+        16.21, 33.46, 26.86, 36.86, 36.24, 37.27, 37.62, 37.34, 37.32, 36.41, 27.63, 39.87, 40.10,
+        35.75, 15.79, 27.95, 35.74, 35.24, 36.94, 38.01, 37.50, 37.09, 36.87, 28.37, 40.68, 41.29,
+        27.84, 28.39, 11.62, 29.78, 30.81, 32.88, 35.73, 38.66, 41.78, 44.73, 50.00, 53.02, 53.04,
+        38.98, 38.01, 30.81, 31.26, 53.88, 54.93, 56.94, 58.05, 59.14, 59.29, 71.54, 63.28, 62.93,
+        39.03, 37.58, 32.04, 56.40, 31.45, 54.16, 55.82, 56.83, 58.14, 59.46, 71.19, 62.56, 62.60,
+        40.35, 39.18, 34.04, 57.96, 56.12, 31.80, 53.75, 55.44, 56.59, 57.72, 70.82, 62.21, 62.41,
+        39.96, 40.37, 37.16, 59.38, 58.25, 56.63, 32.74, 55.24, 55.96, 57.05, 69.62, 62.48, 62.23,
+        40.06, 39.86, 40.40, 61.01, 59.51, 58.13, 57.78, 33.25, 55.42, 56.87, 68.27, 62.12, 63.42,
+        39.95, 39.76, 43.74, 62.15, 60.74, 59.55, 58.90, 58.60, 33.38, 55.61, 67.47, 61.24, 61.92,
+        38.35, 39.75, 47.05, 62.04, 61.60, 60.36, 59.66, 59.32, 58.07, 33.88, 66.11, 59.52, 60.92,
+        28.72, 29.38, 52.51, 75.43, 75.13, 75.31, 73.39, 72.72, 71.18, 69.76, 35.92, 68.26, 70.18,
+        42.36, 43.50, 55.62, 65.80, 65.83, 65.41, 65.82, 64.97, 63.76, 62.63, 72.45, 48.33, 64.35,
+        42.73, 43.83, 56.07, 66.14, 65.94, 65.58, 65.43, 66.13, 65.21, 63.76, 73.56, 67.46, 49.31,
+    ],
+    // Q3s
+    [
+        15.82, 33.42, 26.61, 36.38, 35.91, 37.31, 37.00, 37.34, 37.34, 36.13, 36.06, 27.67, 39.88,
+        35.56, 15.75, 27.19, 35.35, 34.85, 36.50, 37.38, 37.54, 37.31, 37.12, 37.11, 28.20, 40.68,
+        27.05, 28.10, 11.50, 28.90, 30.15, 32.41, 35.04, 38.46, 41.91, 44.68, 46.98, 50.00, 52.58,
+        38.44, 37.29, 30.53, 31.35, 53.98, 55.17, 56.24, 57.79, 58.88, 58.89, 58.97, 71.17, 63.20,
+        38.61, 37.58, 31.84, 56.52, 31.15, 53.85, 54.69, 56.62, 57.79, 58.66, 59.25, 71.28, 62.67,
+        40.09, 39.17, 33.93, 58.45, 56.92, 31.69, 54.01, 55.30, 56.20, 57.83, 58.69, 70.85, 62.43,
+        39.61, 39.80, 36.29, 59.26, 57.79, 56.73, 31.89, 54.17, 54.99, 56.25, 57.74, 70.46, 62.53,
+        39.41, 40.06, 40.40, 60.54, 59.16, 58.40, 57.31, 33.22, 55.03, 56.11, 57.11, 69.91, 62.84,
+        39.96, 39.33, 43.72, 62.03, 60.04, 59.52, 57.97, 57.91, 33.26, 54.97, 56.29, 68.40, 61.61,
+        38.39, 39.81, 46.74, 61.79, 61.53, 60.42, 59.01, 58.40, 57.68, 33.53, 55.75, 67.35, 60.76,
+        38.76, 39.62, 49.74, 61.97, 61.37, 62.06, 60.34, 60.13, 59.29, 57.78, 34.12, 67.53, 60.95,
+        28.53, 29.26, 52.47, 75.74, 75.62, 75.35, 74.96, 73.91, 72.31, 71.29, 71.55, 36.77, 69.91,
+        42.41, 43.56, 55.71, 65.63, 65.54, 65.41, 65.23, 65.47, 64.79, 64.00, 63.60, 74.18, 49.11,
+    ],
+    // Q2s
+    [
+        15.28, 32.50, 25.76, 36.20, 35.84, 36.85, 37.24, 37.19, 36.77, 35.76, 36.41, 36.25, 27.27,
+        35.00, 15.39, 26.86, 35.35, 35.22, 36.06, 37.14, 37.22, 37.24, 36.86, 36.79, 37.18, 27.96,
+        26.75, 27.28, 11.18, 28.61, 29.74, 32.13, 34.73, 38.13, 41.50, 44.53, 46.96, 47.42, 50.00,
+        37.93, 37.17, 29.60, 30.71, 54.09, 54.81, 56.57, 57.58, 58.38, 58.53, 58.93, 59.28, 71.84,
+        37.77, 37.08, 31.25, 56.35, 30.39, 54.19, 55.28, 55.87, 57.53, 58.51, 58.59, 58.71, 71.25,
+        39.52, 38.71, 33.54, 57.73, 56.54, 32.05, 54.35, 54.98, 56.55, 57.72, 58.62, 58.63, 71.29,
+        39.81, 39.73, 36.68, 58.97, 58.05, 56.61, 31.99, 54.00, 55.01, 56.34, 57.57, 58.97, 70.72,
+        39.67, 39.22, 39.73, 60.39, 58.87, 57.77, 57.11, 32.89, 54.24, 55.20, 56.32, 57.63, 71.26,
+        40.24, 39.58, 43.64, 60.96, 59.96, 59.06, 57.29, 56.89, 32.93, 54.40, 56.16, 56.81, 69.86,
+        38.50, 39.23, 46.70, 61.39, 61.33, 60.26, 59.16, 57.63, 57.09, 33.29, 55.12, 56.33, 68.61,
+        38.45, 39.41, 49.47, 61.39, 61.42, 61.31, 60.28, 59.29, 58.75, 57.54, 34.01, 56.76, 69.10,
+        38.71, 39.45, 49.78, 61.93, 61.92, 61.35, 62.10, 60.50, 60.11, 58.86, 58.93, 34.82, 69.18,
+        28.27, 28.48, 52.50, 76.23, 75.27, 75.31, 74.95, 75.29, 74.09, 72.47, 72.81, 73.18, 37.00,
+    ],
+    // AJo
+    [
+        7.58, 24.73, 25.16, 47.40, 68.80, 68.67, 68.42, 68.36, 68.29, 67.01, 67.41, 67.90, 68.02,
+        25.68, 28.77, 56.01, 70.34, 59.06, 60.60, 61.48, 60.74, 60.84, 60.55, 61.12, 61.37, 61.64,
+        26.17, 60.17, 28.26, 68.72, 58.14, 59.51, 60.01, 61.11, 60.51, 60.50, 61.02, 61.56, 62.07,
+        50.00, 74.16, 72.79, 30.38, 66.84, 69.17, 69.50, 70.40, 70.79, 71.16, 72.28, 72.47, 73.23,
+        73.00, 62.93, 61.67, 71.42, 42.88, 59.88, 61.01, 61.55, 62.95, 63.89, 63.85, 64.67, 64.73,
+        73.27, 64.15, 62.90, 73.42, 63.70, 44.88, 59.15, 60.28, 61.38, 62.55, 63.86, 63.95, 63.92,
+        72.67, 65.29, 63.82, 73.44, 64.49, 62.30, 44.74, 59.15, 59.95, 61.61, 62.69, 63.79, 64.27,
+        72.55, 64.51, 64.60, 74.30, 65.65, 63.68, 62.22, 45.21, 59.50, 60.28, 61.22, 62.43, 64.15,
+        72.76, 64.39, 64.97, 75.56, 66.44, 65.02, 63.74, 63.00, 46.43, 58.81, 60.20, 61.61, 62.67,
+        70.91, 64.68, 64.75, 75.96, 67.63, 65.94, 65.34, 63.60, 62.61, 46.26, 58.28, 60.03, 61.37,
+        71.47, 65.02, 64.65, 76.28, 68.02, 67.99, 66.30, 65.30, 64.06, 62.62, 46.88, 60.79, 62.08,
+        72.21, 65.24, 65.43, 76.75, 68.86, 67.92, 67.41, 66.40, 64.87, 63.66, 64.41, 46.90, 62.27,
+        72.83, 66.19, 65.88, 77.47, 69.07, 68.18, 67.94, 67.78, 66.72, 65.18, 65.63, 65.89, 48.07,
+    ],
+    // KJo
+    [
+        13.34, 24.43, 35.32, 24.65, 38.35, 39.99, 40.30, 40.12, 40.60, 40.16, 40.12, 40.66, 39.89,
+        25.35, 9.12, 25.09, 47.56, 68.69, 68.43, 68.80, 68.97, 68.80, 68.26, 68.88, 69.27, 69.90,
+        37.07, 25.93, 28.19, 69.70, 59.20, 60.30, 60.90, 62.18, 61.55, 61.21, 61.99, 62.71, 62.83,
+        25.84, 50.00, 73.59, 31.80, 67.92, 68.65, 69.99, 69.79, 71.06, 70.86, 71.43, 71.85, 72.28,
+        40.10, 72.65, 62.39, 72.83, 43.27, 60.50, 62.01, 62.52, 63.59, 64.56, 64.88, 64.85, 65.43,
+        42.07, 73.40, 63.67, 72.88, 65.15, 44.95, 60.13, 60.94, 62.28, 62.82, 64.01, 64.33, 64.42,
+        42.73, 72.99, 64.79, 73.93, 65.43, 63.58, 46.06, 59.52, 60.68, 60.85, 62.57, 63.86, 64.31,
+        42.78, 72.96, 65.78, 74.11, 66.48, 64.69, 63.49, 45.64, 59.56, 60.19, 61.38, 62.80, 64.25,
+        42.94, 72.80, 65.50, 75.42, 67.54, 65.92, 64.69, 63.16, 46.69, 59.13, 60.46, 62.13, 63.15,
+        42.19, 72.52, 65.37, 75.43, 68.40, 66.89, 64.70, 64.18, 62.78, 47.58, 59.21, 59.92, 62.13,
+        42.53, 73.44, 65.55, 76.03, 69.08, 68.30, 66.57, 65.06, 64.09, 62.50, 48.14, 60.88, 61.95,
+        42.50, 73.50, 66.30, 76.26, 68.68, 68.24, 67.74, 66.65, 65.70, 64.15, 64.68, 48.15, 62.48,
+        42.60, 74.01, 66.70, 76.71, 69.36, 68.43, 68.06, 68.08, 67.53, 65.82, 65.83, 66.50, 49.04,
+    ],
+    // QJo
+    [
+        15.30, 33.58, 26.10, 25.77, 39.07, 41.01, 41.26, 42.05, 41.91, 41.34, 41.57, 41.74, 41.40,
+        34.96, 13.81, 25.05, 24.91, 38.76, 40.36, 41.49, 42.30, 41.60, 41.70, 41.63, 42.36, 41.94,
+        27.10, 25.37, 10.73, 47.49, 68.60, 69.11, 68.73, 69.36, 69.04, 68.60, 69.19, 69.47, 70.40,
+        27.21, 26.41, 50.00, 33.35, 69.11, 69.34, 69.59, 70.98, 70.83, 70.74, 71.06, 71.56, 72.21,
+        41.34, 40.31, 72.60, 73.59, 43.92, 62.31, 63.07, 63.83, 65.18, 65.86, 65.99, 66.12, 66.56,
+        43.02, 42.38, 72.96, 73.83, 65.67, 45.63, 61.06, 62.12, 62.36, 63.75, 64.99, 64.80, 65.35,
+        43.33, 43.35, 73.05, 74.15, 66.82, 64.60, 46.80, 61.14, 61.32, 62.13, 63.49, 64.55, 64.36,
+        44.25, 43.78, 73.69, 75.60, 67.69, 65.62, 64.68, 47.72, 61.38, 60.82, 61.92, 63.62, 64.78,
+        44.01, 43.81, 73.33, 75.31, 68.35, 66.47, 65.27, 64.78, 48.53, 59.76, 60.93, 62.41, 63.70,
+        43.79, 44.17, 72.82, 75.03, 69.37, 67.69, 65.67, 64.39, 63.03, 48.32, 59.62, 60.82, 61.92,
+        43.80, 44.34, 73.14, 75.59, 70.22, 68.94, 67.10, 66.28, 64.97, 63.09, 48.94, 61.27, 62.08,
+        43.85, 44.13, 73.66, 75.94, 70.21, 68.81, 68.56, 67.16, 66.30, 64.57, 64.67, 49.42, 63.25,
+        43.96, 44.56, 73.97, 77.00, 70.17, 69.32, 68.19, 68.92, 67.66, 65.57, 66.32, 67.06, 49.81,
+    ],
+    // JJ
+    [
+        18.68, 54.17, 54.37, 65.60, 67.68, 68.03, 67.96, 68.22, 68.18, 66.41, 67.19, 67.40, 68.03,
+        56.85, 18.68, 53.40, 64.15, 67.55, 68.44, 68.54, 68.73, 68.39, 68.04, 68.61, 68.66, 69.19,
+        56.98, 56.40, 18.07, 62.99, 67.32, 67.73, 68.16, 68.88, 68.84, 68.58, 68.74, 68.65, 69.29,
+        69.62, 68.20, 66.65, 50.00, 82.39, 83.92, 85.17, 86.52, 87.69, 87.59, 87.93, 88.29, 88.97,
+        71.78, 71.21, 70.52, 86.75, 81.94, 81.62, 81.99, 81.94, 82.35, 83.24, 83.41, 83.85, 84.24,
+        71.90, 71.88, 71.65, 88.85, 85.92, 81.72, 80.21, 80.66, 80.89, 81.97, 83.66, 83.58, 84.23,
+        71.69, 72.49, 72.07, 90.42, 85.53, 84.50, 81.24, 79.35, 79.53, 80.77, 82.10, 83.56, 83.90,
+        71.94, 72.11, 72.20, 91.52, 86.30, 84.24, 82.77, 80.71, 77.99, 78.85, 80.96, 82.45, 83.56,
+        72.00, 71.97, 72.39, 93.13, 86.41, 84.86, 83.33, 82.22, 80.94, 77.69, 79.22, 81.22, 83.08,
+        69.92, 71.94, 71.75, 92.82, 87.76, 86.00, 84.56, 83.29, 81.36, 80.72, 77.50, 79.13, 80.71,
+        70.68, 72.16, 72.25, 93.21, 87.51, 87.85, 85.91, 84.79, 83.71, 81.10, 80.95, 79.42, 81.17,
+        70.96, 72.74, 72.78, 93.51, 88.05, 87.69, 88.09, 86.58, 85.21, 82.78, 83.63, 81.38, 81.14,
+        71.06, 72.59, 72.81, 94.05, 88.51, 88.23, 88.00, 87.98, 87.03, 84.54, 85.29, 85.60, 81.86,
+    ],
+    // JTs
+    [
+        21.06, 38.02, 37.91, 31.43, 31.47, 44.16, 44.69, 45.00, 46.34, 45.46, 45.35, 45.59, 45.33,
+        40.56, 19.61, 36.84, 30.55, 30.88, 43.98, 45.18, 45.41, 45.69, 45.62, 45.97, 46.24, 46.33,
+        39.93, 39.03, 18.34, 29.71, 29.88, 42.86, 44.14, 45.59, 45.80, 45.99, 46.12, 46.02, 45.91,
+        33.16, 32.08, 30.89, 17.61, 50.00, 69.02, 69.22, 69.01, 69.68, 69.46, 69.18, 69.42, 70.22,
+        32.88, 31.88, 30.79, 52.52, 38.76, 70.19, 70.03, 70.52, 72.22, 71.64, 71.67, 72.26, 73.12,
+        47.16, 46.32, 45.90, 72.93, 74.18, 48.53, 64.80, 65.59, 66.37, 67.42, 68.16, 68.18, 68.55,
+        47.59, 48.14, 47.02, 73.20, 74.01, 67.48, 49.72, 63.94, 64.64, 65.86, 66.58, 68.37, 68.12,
+        48.01, 48.21, 48.23, 73.37, 74.57, 68.32, 66.72, 51.08, 63.55, 64.35, 65.27, 66.34, 67.88,
+        49.20, 49.04, 48.75, 73.16, 76.18, 69.20, 67.85, 66.79, 52.35, 64.17, 64.64, 65.19, 66.80,
+        48.26, 48.40, 48.55, 73.55, 76.28, 70.15, 68.41, 67.36, 66.99, 52.29, 62.64, 64.15, 65.24,
+        48.24, 48.66, 49.07, 73.79, 76.14, 71.18, 69.52, 68.42, 67.36, 65.77, 53.00, 64.16, 65.19,
+        48.13, 48.84, 49.18, 74.05, 76.63, 71.23, 71.06, 69.19, 68.74, 67.02, 66.98, 53.04, 65.32,
+        48.83, 49.73, 48.91, 73.96, 77.18, 72.13, 70.96, 70.61, 70.01, 68.16, 68.38, 68.71, 53.59,
+    ],
+    // J9s
+    [
+        20.50, 36.65, 37.11, 29.64, 38.79, 31.53, 43.10, 43.33, 44.14, 43.98, 44.17, 44.22, 44.23,
+        39.14, 19.75, 35.69, 30.18, 37.75, 30.51, 43.06, 44.00, 44.42, 44.02, 44.75, 44.61, 44.99,
+        39.02, 38.01, 18.13, 29.47, 36.99, 29.78, 42.78, 43.74, 44.42, 44.72, 45.07, 44.83, 45.19,
+        30.83, 31.35, 30.66, 16.08, 30.98, 50.00, 67.07, 67.31, 66.97, 66.86, 67.91, 67.72, 68.07,
+        41.40, 40.67, 39.73, 32.21, 32.35, 69.61, 62.13, 63.16, 64.15, 65.03, 65.20, 65.17, 65.55,
+        32.67, 32.14, 31.33, 52.43, 73.61, 37.31, 68.70, 69.05, 70.28, 70.47, 71.24, 71.60, 72.22,
+        45.90, 46.15, 45.39, 71.13, 65.42, 73.03, 48.23, 63.06, 64.24, 65.08, 66.26, 67.83, 68.10,
+        46.51, 46.67, 46.35, 70.99, 66.13, 73.39, 66.30, 49.12, 63.05, 63.40, 64.76, 66.07, 67.13,
+        47.23, 47.31, 46.99, 71.85, 67.16, 74.41, 67.25, 65.78, 50.36, 62.57, 63.62, 65.19, 66.08,
+        46.85, 47.39, 47.14, 70.97, 67.87, 74.07, 68.10, 66.58, 65.11, 50.75, 62.61, 63.28, 64.87,
+        47.54, 48.04, 47.09, 71.45, 68.19, 76.06, 69.53, 68.53, 66.94, 65.68, 51.78, 63.64, 64.72,
+        47.27, 47.79, 47.64, 71.83, 68.41, 75.87, 70.67, 69.23, 67.70, 66.16, 66.77, 52.55, 65.06,
+        47.13, 47.57, 47.59, 71.75, 68.51, 76.56, 71.01, 70.57, 69.45, 67.76, 68.01, 68.34, 52.36,
+    ],
+    // J8s
+    [
+        19.54, 36.11, 35.54, 29.76, 38.37, 38.95, 30.22, 42.52, 43.08, 42.76, 42.92, 43.26, 43.36,
+        38.59, 19.21, 34.88, 29.22, 36.89, 38.16, 30.60, 42.02, 43.32, 42.97, 43.58, 43.79, 43.55,
+        38.16, 36.88, 17.86, 29.06, 36.45, 37.32, 29.82, 41.96, 43.24, 43.19, 43.06, 43.76, 43.43,
+        30.50, 30.01, 30.41, 14.83, 30.78, 32.93, 50.00, 65.01, 65.16, 64.80, 64.76, 65.66, 65.69,
+        40.72, 39.87, 38.40, 31.98, 32.48, 57.38, 69.37, 62.48, 63.13, 64.35, 63.94, 64.32, 64.14,
+        41.92, 41.32, 39.60, 34.27, 60.06, 32.59, 67.80, 61.23, 62.22, 63.36, 64.36, 64.29, 64.15,
+        31.64, 32.02, 30.89, 52.53, 73.84, 72.18, 36.69, 67.54, 69.00, 68.32, 70.56, 71.91, 72.00,
+        45.42, 45.19, 45.38, 68.41, 65.11, 64.38, 71.31, 48.10, 62.58, 63.37, 64.49, 66.03, 67.22,
+        46.20, 45.41, 45.72, 69.05, 65.90, 65.15, 73.21, 65.63, 49.70, 61.67, 63.48, 65.03, 66.07,
+        45.57, 45.64, 45.67, 68.63, 67.16, 65.56, 73.02, 65.86, 64.71, 49.46, 61.86, 63.33, 64.49,
+        46.04, 45.94, 45.94, 68.69, 67.15, 67.58, 74.72, 67.52, 66.15, 64.89, 50.40, 63.50, 64.57,
+        46.22, 46.87, 46.42, 68.96, 67.58, 67.52, 76.21, 69.10, 68.15, 66.04, 66.45, 51.44, 65.24,
+        46.14, 46.02, 46.42, 69.50, 67.75, 67.54, 76.23, 70.44, 69.05, 67.13, 67.63, 68.24, 52.05,
+    ],
+    // J7s
+    [
+        18.02, 34.92, 34.67, 28.52, 37.05, 38.38, 38.36, 29.26, 42.40, 41.53, 41.97, 42.66, 42.89,
+        37.54, 17.81, 33.86, 29.08, 36.14, 37.23, 38.40, 29.70, 42.30, 41.83, 42.36, 42.99, 42.83,
+        37.32, 35.90, 17.71, 28.02, 35.24, 36.64, 37.51, 30.02, 41.72, 41.60, 41.95, 42.21, 42.42,
+        29.60, 30.21, 29.02, 13.48, 30.99, 32.69, 34.99, 50.00, 61.91, 61.61, 61.93, 62.25, 62.89,
+        40.13, 38.88, 37.58, 31.79, 31.70, 56.43, 57.44, 69.59, 62.58, 63.96, 63.21, 63.05, 63.55,
+        41.53, 39.90, 38.72, 33.93, 58.51, 32.08, 56.67, 68.22, 61.20, 62.35, 63.65, 63.58, 63.71,
+        40.98, 40.90, 39.78, 36.53, 59.99, 58.80, 32.89, 66.95, 60.48, 61.22, 62.60, 63.39, 63.69,
+        30.25, 30.88, 31.12, 52.43, 73.80, 71.97, 70.76, 35.36, 67.52, 67.39, 68.99, 70.32, 72.16,
+        45.01, 44.47, 44.46, 65.41, 65.45, 64.40, 63.47, 71.65, 48.42, 61.56, 63.37, 64.36, 65.38,
+        44.16, 44.75, 44.57, 65.32, 66.19, 65.34, 63.98, 71.60, 64.40, 48.55, 61.43, 63.05, 64.44,
+        44.59, 44.80, 45.13, 65.28, 66.04, 66.71, 65.65, 73.49, 66.20, 64.42, 49.01, 63.10, 64.47,
+        44.95, 45.60, 45.37, 65.71, 66.41, 66.01, 66.79, 74.35, 67.24, 65.80, 65.75, 49.79, 65.12,
+        45.95, 46.13, 45.39, 66.00, 66.97, 66.83, 66.93, 76.32, 68.75, 67.15, 67.66, 68.33, 51.57,
+    ],
+    // J6s
+    [
+        16.87, 34.19, 33.69, 27.56, 36.52, 37.51, 37.63, 37.76, 28.11, 40.85, 40.60, 41.20, 41.50,
+        36.76, 16.83, 33.20, 27.93, 34.99, 36.96, 37.36, 37.39, 28.45, 41.01, 40.76, 41.21, 42.08,
+        36.29, 34.92, 16.10, 27.79, 34.39, 35.83, 36.13, 37.15, 28.65, 40.38, 40.86, 41.12, 41.62,
+        29.21, 28.94, 29.17, 12.31, 30.32, 33.03, 34.84, 38.09, 50.00, 57.90, 57.86, 58.04, 58.40,
+        38.98, 37.87, 36.74, 31.54, 31.48, 54.72, 55.78, 57.08, 69.33, 62.56, 62.28, 62.55, 62.33,
+        40.26, 38.93, 38.36, 34.12, 57.39, 31.76, 55.35, 56.53, 68.55, 61.31, 62.32, 62.68, 62.75,
+        39.87, 40.00, 39.15, 36.41, 58.53, 57.57, 32.44, 55.55, 66.83, 60.31, 61.69, 62.64, 62.69,
+        40.39, 39.78, 40.13, 39.71, 60.16, 59.32, 58.42, 32.88, 65.64, 59.38, 60.65, 61.61, 62.88,
+        29.43, 29.62, 29.94, 52.47, 73.88, 72.57, 70.58, 69.79, 34.96, 66.32, 67.96, 69.37, 70.61,
+        42.95, 43.48, 43.34, 60.90, 65.34, 64.46, 63.47, 62.32, 69.76, 47.50, 61.53, 62.66, 64.56,
+        43.71, 43.80, 43.78, 61.16, 65.25, 65.50, 64.44, 63.24, 71.64, 63.90, 48.22, 62.45, 63.82,
+        44.28, 44.31, 44.01, 61.27, 65.78, 65.45, 65.69, 64.71, 73.59, 65.44, 65.47, 49.07, 64.35,
+        44.42, 44.46, 44.22, 61.35, 65.70, 65.68, 65.42, 66.08, 74.99, 66.89, 67.15, 67.26, 50.22,
+    ],
+    // J5s
+    [
+        17.06, 34.22, 34.21, 27.57, 36.63, 37.61, 37.87, 37.47, 38.16, 28.40, 40.32, 40.24, 40.96,
+        36.50, 17.00, 32.81, 27.77, 35.60, 36.98, 37.92, 37.57, 37.79, 29.09, 41.04, 41.20, 41.71,
+        36.24, 35.52, 16.50, 28.35, 34.42, 36.19, 37.36, 37.93, 37.51, 28.92, 40.71, 41.11, 41.47,
+        28.84, 29.14, 29.26, 12.41, 30.54, 33.14, 35.20, 38.39, 42.10, 50.00, 55.32, 55.45, 55.76,
+        38.91, 38.15, 37.12, 32.38, 31.14, 54.20, 54.98, 57.05, 57.80, 70.70, 62.71, 62.48, 62.74,
+        40.91, 40.03, 38.65, 34.70, 56.59, 32.03, 54.86, 56.25, 57.39, 69.19, 63.11, 62.31, 62.92,
+        40.51, 40.54, 39.40, 36.89, 58.21, 57.62, 32.98, 55.06, 56.90, 67.89, 61.95, 63.29, 62.86,
+        40.07, 40.20, 40.14, 39.91, 59.32, 58.62, 58.09, 33.21, 56.37, 66.96, 60.91, 62.67, 63.36,
+        40.34, 40.53, 40.48, 44.23, 60.85, 60.13, 59.13, 58.86, 34.10, 65.69, 60.40, 61.40, 63.34,
+        29.25, 29.62, 30.01, 52.39, 74.92, 73.79, 72.18, 70.75, 69.53, 35.91, 66.02, 67.55, 69.22,
+        42.61, 43.68, 44.00, 58.41, 65.73, 65.74, 64.46, 63.95, 63.00, 70.34, 47.76, 62.61, 64.36,
+        43.02, 44.18, 44.07, 58.28, 65.47, 65.98, 66.36, 64.63, 64.43, 71.67, 66.31, 48.43, 64.83,
+        43.35, 44.47, 43.93, 59.10, 65.97, 65.90, 65.94, 66.39, 65.74, 73.30, 67.21, 67.50, 49.98,
+    ],
+    // J4s
+    [
+        16.63, 34.22, 33.45, 27.47, 36.59, 37.64, 37.49, 37.19, 37.90, 36.62, 27.94, 40.24, 40.40,
+        36.34, 16.36, 33.25, 27.73, 35.86, 36.85, 37.65, 37.50, 37.82, 37.57, 28.72, 41.21, 41.15,
+        35.55, 35.43, 16.32, 27.80, 34.47, 35.74, 36.99, 37.59, 37.40, 37.07, 28.46, 41.03, 41.07,
+        27.72, 28.57, 28.94, 12.07, 30.82, 32.09, 35.24, 38.07, 42.14, 44.68, 50.00, 52.97, 53.11,
+        38.82, 37.82, 37.37, 31.74, 31.10, 54.02, 55.74, 56.90, 57.74, 59.05, 70.46, 62.78, 63.11,
+        40.34, 39.49, 38.42, 33.66, 57.17, 31.72, 53.96, 55.08, 56.77, 57.99, 70.59, 62.53, 62.40,
+        40.21, 40.75, 39.30, 36.31, 58.11, 56.57, 32.47, 54.81, 56.12, 57.36, 69.18, 62.43, 62.49,
+        40.46, 40.39, 40.47, 39.74, 59.33, 58.06, 56.91, 33.03, 55.48, 56.21, 68.12, 61.62, 63.01,
+        40.33, 40.33, 40.26, 44.17, 60.77, 59.37, 59.10, 58.50, 34.16, 56.10, 67.10, 61.01, 62.29,
+        39.16, 39.56, 39.70, 47.06, 61.88, 60.92, 59.67, 58.99, 58.60, 34.01, 65.27, 59.45, 61.17,
+        29.39, 29.71, 30.01, 52.42, 75.04, 74.68, 73.68, 72.16, 70.91, 69.52, 36.14, 67.88, 69.31,
+        42.69, 43.96, 43.55, 55.72, 65.33, 65.64, 65.50, 64.81, 63.73, 62.96, 71.90, 48.95, 64.07,
+        43.12, 44.12, 43.88, 55.81, 65.74, 65.58, 65.65, 66.17, 65.31, 63.63, 73.62, 67.15, 49.43,
+    ],
+    // J3s
+    [
+        16.35, 33.72, 33.29, 26.79, 36.03, 37.37, 36.93, 37.64, 37.70, 36.91, 36.62, 27.88, 40.30,
+        35.67, 16.23, 32.86, 27.06, 35.24, 36.63, 37.46, 37.03, 37.76, 37.08, 37.19, 28.59, 40.94,
+        35.69, 34.95, 16.33, 27.21, 34.32, 35.61, 36.53, 37.02, 37.07, 37.10, 36.72, 28.83, 40.72,
+        27.53, 28.15, 28.44, 11.71, 30.58, 32.28, 34.34, 37.75, 41.96, 44.55, 47.03, 50.00, 52.75,
+        38.44, 37.95, 36.92, 31.72, 30.53, 54.33, 54.93, 56.44, 57.77, 59.21, 58.98, 70.98, 62.69,
+        40.02, 39.50, 38.14, 34.03, 56.49, 31.78, 54.38, 55.27, 57.21, 58.10, 59.05, 70.75, 62.55,
+        39.55, 40.12, 39.05, 36.09, 57.80, 56.78, 32.04, 54.76, 55.29, 56.55, 57.35, 70.45, 62.54,
+        40.20, 40.19, 40.42, 39.72, 59.14, 58.22, 56.55, 33.15, 55.29, 56.05, 57.23, 69.16, 62.86,
+        40.51, 40.07, 40.07, 44.10, 60.67, 59.48, 58.07, 58.02, 33.79, 55.49, 56.23, 68.23, 62.10,
+        39.43, 39.74, 39.71, 46.92, 61.77, 60.86, 59.09, 58.34, 58.19, 33.65, 55.37, 66.35, 60.30,
+        39.06, 40.29, 39.82, 49.65, 61.58, 62.18, 60.37, 60.22, 59.26, 57.94, 34.25, 66.98, 61.28,
+        28.79, 29.67, 29.52, 52.43, 74.97, 74.59, 74.82, 73.28, 72.40, 70.63, 70.86, 36.76, 69.36,
+        42.67, 44.26, 43.53, 56.14, 65.91, 65.17, 65.27, 65.34, 64.74, 63.75, 63.62, 73.72, 48.87,
+    ],
+    // J2s
+    [
+        16.08, 33.22, 32.85, 26.06, 35.67, 37.37, 37.20, 36.89, 37.55, 36.41, 36.51, 36.37, 27.65,
+        35.95, 15.83, 32.36, 26.92, 34.77, 36.39, 37.31, 36.76, 37.34, 37.07, 37.14, 37.33, 28.61,
+        35.29, 34.29, 15.36, 26.96, 34.09, 35.20, 36.53, 36.80, 37.11, 37.25, 37.07, 36.80, 28.16,
+        26.77, 27.72, 27.79, 11.03, 29.78, 31.93, 34.31, 37.11, 41.60, 44.24, 46.89, 47.25, 50.00,
+        38.06, 37.09, 36.36, 30.92, 30.48, 54.45, 54.99, 56.10, 57.41, 58.17, 58.76, 59.29, 70.90,
+        39.77, 39.28, 37.64, 33.24, 56.40, 31.56, 53.92, 55.13, 56.12, 57.80, 59.06, 58.99, 70.79,
+        39.78, 40.21, 38.76, 36.20, 58.24, 56.63, 32.14, 53.84, 55.28, 56.75, 57.48, 58.59, 70.70,
+        39.85, 39.96, 39.76, 39.73, 59.03, 57.48, 56.58, 32.84, 54.04, 55.18, 56.45, 57.74, 70.19,
+        40.46, 39.76, 40.14, 43.73, 60.30, 59.22, 57.82, 57.02, 33.34, 54.78, 56.22, 56.92, 69.56,
+        38.85, 39.58, 39.66, 46.53, 61.46, 60.37, 59.29, 58.00, 57.68, 33.65, 55.13, 55.87, 67.99,
+        39.27, 39.73, 39.49, 49.51, 61.33, 61.17, 60.50, 59.06, 58.92, 57.49, 33.94, 56.57, 68.27,
+        39.04, 40.23, 39.71, 49.69, 62.05, 62.11, 61.70, 60.27, 59.85, 58.55, 59.18, 34.63, 68.69,
+        29.13, 29.31, 29.44, 52.58, 75.19, 74.74, 74.48, 74.44, 73.41, 71.74, 72.37, 72.81, 36.82,
+    ],
+    // ATo
+    [
+        8.13, 25.00, 25.00, 25.85, 47.52, 67.71, 67.65, 67.26, 67.38, 66.15, 66.34, 66.48, 67.01,
+        26.21, 29.23, 56.29, 56.28, 70.15, 60.27, 62.06, 61.13, 61.06, 61.11, 61.27, 61.17, 62.20,
+        26.45, 59.85, 28.62, 55.49, 68.69, 59.77, 60.19, 61.39, 60.71, 61.03, 60.97, 61.39, 62.23,
+        27.00, 59.90, 58.66, 28.22, 67.12, 58.60, 59.28, 59.87, 61.02, 61.09, 61.18, 61.56, 61.94,
+        50.00, 74.25, 72.88, 71.01, 30.61, 67.90, 67.60, 68.87, 70.10, 71.79, 71.90, 72.22, 72.87,
+        72.03, 64.69, 63.01, 62.05, 72.26, 44.27, 59.46, 60.10, 61.20, 63.14, 64.15, 63.62, 64.46,
+        71.58, 65.66, 64.08, 63.26, 71.54, 62.87, 44.29, 59.13, 60.15, 61.59, 62.35, 64.17, 63.93,
+        71.69, 64.58, 64.63, 63.96, 73.13, 64.56, 63.05, 45.35, 59.47, 60.83, 61.56, 62.94, 64.45,
+        71.28, 64.87, 64.53, 64.55, 74.46, 65.19, 63.39, 62.63, 45.53, 59.22, 60.25, 61.68, 62.85,
+        70.37, 65.26, 64.85, 64.87, 76.04, 66.78, 65.05, 64.23, 63.23, 46.43, 59.17, 60.98, 61.88,
+        70.32, 65.17, 64.82, 64.99, 76.44, 68.04, 66.79, 65.22, 64.05, 63.20, 47.38, 61.13, 61.42,
+        70.97, 65.76, 65.26, 65.16, 76.78, 68.11, 68.44, 67.11, 65.50, 64.11, 64.17, 47.92, 62.58,
+        71.12, 66.17, 65.60, 66.01, 77.36, 68.43, 67.80, 68.06, 66.72, 65.54, 65.97, 65.84, 48.67,
+    ],
+    // KTo
+    [
+        13.61, 25.06, 35.90, 35.64, 24.63, 40.06, 40.04, 40.58, 40.36, 40.25, 40.16, 40.93, 40.74,
+        25.79, 9.57, 25.33, 26.26, 47.48, 67.71, 67.70, 67.86, 67.23, 67.60, 67.80, 68.01, 68.92,
+        37.73, 26.34, 28.83, 56.34, 69.94, 60.99, 61.18, 62.33, 61.52, 61.70, 62.42, 62.42, 62.92,
+        37.07, 27.35, 59.69, 28.79, 68.12, 59.33, 60.13, 61.12, 62.13, 61.85, 62.18, 62.05, 62.91,
+        25.75, 50.00, 73.49, 72.00, 31.90, 67.51, 69.19, 68.87, 69.89, 70.92, 71.09, 71.64, 72.42,
+        41.91, 71.98, 64.33, 63.24, 71.83, 44.67, 60.61, 60.65, 61.69, 63.11, 64.36, 64.38, 64.75,
+        42.86, 72.44, 65.58, 63.99, 73.48, 64.65, 45.87, 59.82, 60.49, 61.42, 62.71, 64.76, 64.28,
+        42.49, 71.84, 66.06, 64.90, 72.77, 64.62, 63.00, 45.95, 59.48, 60.14, 61.82, 62.83, 64.68,
+        42.68, 71.46, 65.45, 65.82, 74.22, 65.99, 64.48, 62.99, 47.31, 59.59, 60.38, 61.76, 63.10,
+        42.67, 71.49, 65.71, 65.89, 75.54, 66.45, 65.73, 64.12, 63.19, 47.49, 59.96, 60.48, 62.00,
+        43.12, 71.84, 65.46, 65.40, 75.55, 68.27, 66.82, 65.19, 64.25, 63.21, 48.27, 61.08, 62.30,
+        42.90, 72.26, 66.21, 66.28, 76.23, 68.39, 68.14, 67.18, 66.03, 64.28, 65.20, 48.59, 62.45,
+        43.19, 73.03, 66.41, 66.36, 76.34, 68.50, 68.22, 68.27, 67.22, 65.67, 65.91, 66.40, 49.25,
+    ],
+    // QTo
+    [
+        15.58, 33.54, 26.49, 36.59, 26.36, 40.98, 41.31, 41.89, 41.96, 41.67, 42.28, 41.87, 42.31,
+        35.30, 13.97, 25.15, 35.45, 25.64, 39.93, 41.23, 41.95, 41.82, 42.41, 42.57, 42.20, 42.45,
+        27.27, 25.94, 11.21, 26.08, 47.59, 67.78, 67.86, 67.73, 67.65, 67.79, 67.96, 68.16, 68.75,
+        38.33, 37.61, 27.40, 29.48, 69.21, 60.27, 61.60, 62.42, 63.26, 62.88, 62.63, 63.08, 63.64,
+        27.12, 26.51, 50.00, 72.85, 33.63, 68.08, 68.53, 70.01, 69.27, 70.72, 71.20, 71.17, 71.86,
+        42.62, 42.26, 71.95, 64.42, 72.40, 45.19, 61.45, 61.97, 62.91, 64.07, 65.01, 65.53, 65.22,
+        43.12, 43.75, 72.17, 64.91, 72.52, 65.05, 46.16, 61.08, 61.21, 62.37, 63.74, 65.18, 64.81,
+        43.54, 43.83, 72.18, 65.71, 74.16, 65.77, 64.38, 48.00, 60.27, 61.10, 62.36, 63.34, 64.51,
+        44.81, 43.88, 71.72, 66.91, 73.75, 66.64, 65.07, 63.99, 48.02, 59.92, 60.69, 62.39, 63.62,
+        43.98, 44.75, 71.98, 66.72, 75.20, 67.85, 66.24, 64.90, 63.62, 48.79, 59.85, 61.16, 62.42,
+        43.86, 44.60, 72.01, 66.29, 75.51, 69.22, 67.55, 66.20, 64.55, 63.39, 49.38, 61.23, 62.51,
+        43.96, 44.52, 72.01, 67.62, 75.73, 69.28, 68.63, 67.56, 66.05, 64.48, 65.29, 49.59, 62.80,
+        44.06, 44.25, 72.88, 67.56, 76.22, 69.69, 69.18, 68.63, 67.75, 66.58, 66.15, 66.95, 50.53,
+    ],
+    // JTo
+    [
+        17.41, 35.25, 34.99, 27.89, 27.64, 41.92, 42.03, 42.73, 43.49, 42.57, 43.13, 42.55, 43.00,
+        36.68, 15.35, 33.78, 26.88, 27.07, 41.41, 42.34, 42.91, 43.83, 43.21, 42.83, 43.28, 43.40,
+        36.91, 35.55, 14.15, 25.68, 25.67, 40.65, 41.55, 42.81, 43.61, 43.21, 43.60, 43.48, 43.65,
+        28.58, 27.17, 26.41, 13.25, 47.48, 67.79, 68.02, 68.21, 68.46, 67.62, 68.26, 68.28, 69.08,
+        28.99, 28.00, 27.15, 50.00, 35.07, 69.08, 69.43, 69.13, 71.13, 70.65, 70.77, 71.39, 71.68,
+        43.93, 43.32, 42.26, 71.82, 73.50, 46.26, 62.39, 62.95, 64.00, 64.64, 66.07, 66.27, 66.60,
+        44.46, 44.79, 43.49, 71.81, 73.10, 66.14, 46.99, 61.54, 62.48, 62.99, 64.25, 65.95, 65.61,
+        45.14, 45.21, 44.70, 72.10, 73.94, 66.63, 64.79, 48.12, 61.27, 61.64, 62.93, 64.19, 65.53,
+        45.86, 45.83, 45.76, 72.57, 75.53, 68.03, 66.37, 64.87, 49.87, 62.33, 61.89, 63.29, 64.37,
+        45.10, 45.68, 45.57, 72.40, 75.17, 68.69, 66.83, 65.64, 65.37, 49.91, 60.32, 61.33, 62.93,
+        45.35, 45.76, 45.74, 72.32, 75.35, 70.21, 68.35, 66.48, 65.49, 63.77, 50.27, 61.30, 63.05,
+        45.47, 45.94, 45.71, 72.65, 75.48, 70.42, 69.94, 68.09, 67.06, 65.41, 65.27, 51.22, 63.28,
+        45.60, 46.02, 45.82, 73.31, 76.42, 70.66, 70.28, 69.36, 68.42, 66.71, 66.96, 67.02, 51.54,
+    ],
+    // TT
+    [
+        19.19, 54.25, 54.17, 54.40, 65.24, 68.58, 67.86, 68.02, 67.84, 66.97, 66.97, 67.49, 67.61,
+        56.80, 19.01, 53.34, 53.55, 64.35, 68.42, 68.68, 68.49, 68.42, 68.62, 68.73, 68.86, 68.93,
+        57.04, 56.34, 18.54, 52.49, 62.92, 67.66, 68.66, 68.92, 68.80, 68.69, 68.55, 68.85, 69.61,
+        57.12, 56.73, 56.08, 18.06, 61.24, 67.65, 67.52, 68.30, 68.52, 68.86, 68.90, 69.47, 69.52,
+        69.39, 68.10, 66.37, 64.93, 50.00, 82.15, 83.45, 84.75, 86.43, 87.43, 87.43, 88.30, 88.55,
+        72.51, 71.92, 71.21, 71.10, 87.36, 81.78, 81.65, 81.95, 82.00, 82.24, 83.51, 83.95, 84.53,
+        71.83, 72.49, 71.89, 71.67, 88.38, 85.84, 81.78, 80.47, 80.41, 80.52, 82.30, 84.28, 83.97,
+        71.46, 71.98, 72.75, 71.90, 90.23, 86.16, 84.40, 81.25, 78.79, 79.42, 80.77, 82.42, 84.41,
+        71.61, 72.04, 72.36, 72.45, 91.53, 86.05, 84.46, 83.08, 80.98, 78.08, 79.29, 81.13, 82.73,
+        70.59, 72.05, 72.02, 71.92, 92.98, 86.64, 84.93, 83.44, 81.74, 80.84, 78.11, 79.37, 81.38,
+        70.08, 72.29, 72.37, 72.36, 92.76, 88.31, 86.56, 84.91, 83.61, 81.58, 80.95, 79.46, 81.09,
+        70.59, 71.99, 72.59, 72.97, 93.26, 88.07, 87.93, 86.81, 85.27, 83.64, 83.37, 81.59, 81.98,
+        71.34, 72.82, 72.87, 73.38, 93.80, 88.64, 88.51, 88.32, 86.84, 85.14, 85.02, 85.62, 82.08,
+    ],
+    // T9s
+    [
+        21.93, 38.57, 37.53, 37.92, 31.06, 32.90, 43.96, 44.23, 45.12, 45.50, 45.10, 45.85, 45.14,
+        40.32, 21.34, 37.07, 36.50, 31.73, 32.08, 43.85, 44.63, 44.84, 45.77, 45.58, 45.40, 45.94,
+        40.11, 39.39, 19.76, 35.24, 30.78, 31.47, 43.57, 44.74, 45.41, 45.87, 45.84, 46.15, 45.81,
+        40.12, 39.50, 37.69, 18.38, 29.81, 30.39, 42.62, 43.57, 45.28, 45.80, 45.98, 45.67, 45.55,
+        32.10, 32.49, 31.92, 30.92, 17.85, 50.00, 67.13, 67.31, 67.08, 67.44, 67.66, 67.94, 68.04,
+        34.07, 33.42, 32.56, 31.21, 52.41, 38.86, 68.78, 69.29, 70.01, 71.44, 71.36, 71.28, 72.34,
+        46.72, 47.09, 46.46, 45.12, 70.85, 73.36, 49.08, 64.54, 65.54, 66.17, 67.23, 68.81, 68.54,
+        47.61, 47.42, 47.48, 46.89, 70.47, 73.45, 67.36, 49.90, 63.49, 64.58, 65.70, 66.60, 68.36,
+        47.63, 47.96, 47.69, 47.79, 70.87, 73.81, 68.40, 66.40, 51.23, 63.40, 64.39, 65.27, 66.49,
+        47.82, 48.78, 48.97, 48.35, 71.70, 75.49, 68.58, 67.75, 66.42, 52.24, 63.72, 63.99, 65.42,
+        48.07, 48.63, 48.55, 48.59, 71.68, 75.64, 70.04, 68.20, 67.56, 67.13, 52.34, 64.07, 65.58,
+        48.32, 49.28, 48.56, 48.48, 71.78, 75.60, 71.79, 69.47, 68.29, 66.60, 66.67, 52.71, 65.14,
+        48.30, 48.69, 48.77, 48.80, 72.33, 75.96, 71.71, 71.19, 69.51, 68.08, 68.11, 67.74, 54.02,
+    ],
+    // T8s
+    [
+        21.15, 36.93, 37.14, 36.77, 31.11, 40.38, 31.73, 43.18, 44.03, 44.39, 44.34, 44.51, 44.70,
+        39.79, 20.57, 36.08, 35.34, 29.76, 39.35, 32.28, 43.35, 44.28, 44.40, 44.59, 45.07, 44.88,
+        39.73, 38.35, 19.80, 34.55, 30.70, 38.14, 31.17, 43.23, 43.47, 44.41, 44.18, 45.31, 44.72,
+        38.99, 37.99, 36.93, 18.01, 29.97, 37.87, 30.63, 42.56, 44.22, 45.02, 44.26, 45.07, 45.01,
+        32.40, 30.81, 31.47, 30.57, 16.55, 32.87, 50.00, 64.52, 64.83, 65.37, 65.08, 65.63, 65.47,
+        43.21, 42.18, 40.96, 39.77, 34.05, 33.12, 68.60, 62.21, 62.93, 64.30, 65.09, 65.66, 65.23,
+        33.53, 34.19, 32.54, 31.53, 52.48, 72.53, 38.15, 68.06, 68.41, 69.64, 69.64, 71.38, 71.62,
+        46.40, 45.97, 46.51, 45.24, 68.18, 64.87, 71.58, 48.45, 63.25, 64.23, 65.44, 66.59, 67.84,
+        46.90, 46.67, 46.81, 46.90, 68.60, 66.00, 72.19, 66.38, 49.74, 62.74, 63.65, 65.13, 66.44,
+        46.96, 47.66, 47.13, 47.56, 68.81, 67.01, 73.42, 67.53, 65.74, 51.38, 62.23, 63.41, 64.53,
+        47.02, 46.80, 47.11, 47.01, 69.13, 68.13, 73.90, 68.28, 66.66, 65.21, 51.36, 63.66, 64.92,
+        47.63, 48.00, 47.81, 47.58, 69.29, 68.28, 75.41, 69.94, 68.32, 66.97, 66.63, 52.49, 65.16,
+        47.54, 47.83, 47.43, 47.49, 69.33, 68.98, 75.87, 71.00, 69.33, 67.97, 67.63, 68.41, 52.70,
+    ],
+    // T7s
+    [
+        19.58, 36.44, 35.60, 35.62, 29.81, 39.49, 39.30, 30.82, 42.63, 42.87, 43.13, 43.36, 43.72,
+        38.38, 19.39, 34.96, 34.81, 29.96, 38.64, 39.46, 30.84, 42.86, 43.39, 43.38, 43.57, 44.02,
+        38.05, 37.64, 18.92, 33.65, 28.71, 37.44, 38.37, 31.43, 42.54, 42.92, 43.17, 43.38, 44.13,
+        38.45, 37.48, 36.17, 18.06, 29.48, 36.84, 37.52, 30.41, 42.92, 42.95, 43.10, 43.56, 43.90,
+        31.13, 31.13, 29.99, 30.87, 15.25, 32.69, 35.48, 50.00, 61.76, 62.06, 62.32, 62.30, 62.62,
+        41.76, 40.99, 40.04, 39.02, 34.19, 32.52, 57.27, 68.36, 62.44, 63.60, 64.21, 64.25, 64.53,
+        41.49, 41.75, 41.51, 40.00, 36.65, 59.92, 33.30, 67.17, 61.12, 61.79, 63.36, 64.69, 64.53,
+        32.23, 32.22, 32.92, 31.66, 52.49, 72.64, 71.67, 37.46, 67.11, 68.34, 68.28, 70.03, 71.82,
+        45.53, 45.09, 45.25, 45.36, 65.34, 64.97, 63.96, 70.75, 48.35, 62.43, 63.42, 64.54, 66.17,
+        45.97, 46.08, 46.19, 46.00, 65.81, 66.27, 64.82, 72.15, 65.30, 49.77, 61.92, 62.79, 64.72,
+        45.57, 46.10, 45.73, 46.10, 65.59, 67.16, 66.49, 72.36, 66.79, 64.86, 50.53, 63.34, 65.04,
+        46.17, 46.50, 46.10, 46.07, 66.03, 67.40, 67.45, 74.02, 67.74, 66.04, 66.09, 51.13, 64.88,
+        46.24, 47.44, 46.77, 46.90, 66.36, 67.51, 68.09, 75.81, 69.39, 67.35, 67.65, 68.05, 51.56,
+    ],
+    // T6s
+    [
+        18.93, 35.65, 34.88, 34.84, 28.77, 38.71, 38.11, 38.71, 29.34, 42.13, 42.09, 42.47, 43.00,
+        37.95, 18.15, 34.14, 34.25, 29.07, 37.74, 38.29, 38.53, 29.94, 42.21, 41.84, 42.52, 42.81,
+        37.62, 36.71, 18.31, 32.50, 29.48, 36.72, 37.40, 38.35, 29.90, 41.84, 41.86, 42.21, 42.47,
+        37.05, 36.41, 34.82, 17.65, 27.78, 35.85, 36.87, 37.42, 30.67, 42.20, 42.26, 42.23, 42.59,
+        29.90, 30.11, 30.73, 28.87, 13.57, 32.92, 35.17, 38.24, 50.00, 58.68, 58.77, 59.03, 59.25,
+        41.48, 40.28, 39.53, 38.20, 33.71, 32.41, 55.48, 57.71, 68.59, 62.23, 63.63, 63.32, 63.87,
+        41.29, 40.89, 40.28, 39.48, 36.52, 58.62, 32.41, 56.08, 67.12, 61.40, 62.37, 63.91, 64.15,
+        41.39, 41.05, 41.09, 39.73, 39.61, 60.14, 58.88, 33.20, 66.29, 60.65, 61.86, 62.52, 64.03,
+        30.72, 31.00, 31.44, 31.76, 52.46, 72.47, 71.67, 70.11, 36.77, 66.89, 67.25, 68.59, 70.43,
+        44.77, 44.94, 44.75, 44.47, 61.90, 65.31, 64.03, 63.34, 70.70, 48.30, 61.65, 62.84, 64.18,
+        44.84, 44.71, 44.70, 44.18, 62.14, 66.46, 65.81, 64.18, 71.12, 64.30, 48.80, 62.74, 64.75,
+        45.50, 45.43, 45.45, 44.99, 62.28, 66.14, 66.98, 65.77, 72.80, 65.74, 66.14, 49.70, 64.48,
+        45.98, 45.43, 45.40, 45.53, 62.41, 66.51, 66.65, 66.82, 74.16, 67.30, 67.35, 67.46, 50.79,
+    ],
+    // T5s
+    [
+        16.98, 34.09, 33.63, 32.92, 27.42, 37.90, 37.38, 37.17, 37.58, 28.32, 40.23, 40.63, 40.95,
+        36.48, 17.06, 33.18, 33.15, 27.76, 36.91, 37.51, 37.16, 37.47, 28.89, 40.60, 41.15, 41.55,
+        36.39, 35.67, 16.63, 31.95, 27.98, 36.02, 36.93, 37.56, 37.34, 28.88, 40.54, 41.34, 41.49,
+        36.11, 35.44, 34.14, 16.76, 28.36, 34.97, 35.65, 36.04, 37.44, 29.30, 40.95, 40.79, 41.83,
+        28.21, 29.08, 29.28, 29.35, 12.57, 32.56, 34.63, 37.94, 41.32, 50.00, 54.72, 55.08, 55.16,
+        40.22, 39.34, 38.65, 37.40, 33.87, 31.97, 54.53, 56.13, 57.32, 68.38, 62.99, 62.21, 62.59,
+        39.84, 40.20, 39.66, 38.40, 36.71, 56.76, 32.08, 55.07, 56.14, 67.65, 61.86, 62.85, 62.67,
+        40.01, 40.06, 40.57, 39.15, 39.54, 58.29, 57.21, 33.19, 55.52, 66.21, 60.45, 61.59, 63.19,
+        40.32, 40.35, 39.57, 39.84, 43.59, 59.71, 58.88, 57.84, 33.77, 65.44, 59.28, 60.72, 62.16,
+        29.40, 29.84, 30.06, 30.40, 52.46, 72.73, 71.57, 70.15, 68.68, 35.75, 65.35, 67.22, 68.26,
+        42.31, 43.82, 43.52, 43.25, 57.94, 65.62, 64.27, 63.57, 62.25, 69.19, 47.43, 62.86, 64.25,
+        42.88, 44.42, 43.89, 43.92, 58.30, 65.30, 66.13, 64.84, 63.33, 70.80, 65.52, 48.60, 64.13,
+        44.02, 44.52, 44.58, 44.04, 58.16, 65.49, 65.87, 65.77, 64.65, 72.66, 67.49, 67.49, 49.31,
+    ],
+    // T4s
+    [
+        16.95, 34.06, 34.05, 33.62, 27.09, 37.35, 37.52, 37.85, 37.80, 36.92, 28.36, 40.43, 40.90,
+        36.76, 16.78, 33.04, 33.32, 27.86, 36.97, 38.06, 38.11, 37.76, 37.84, 29.00, 41.50, 41.42,
+        36.27, 35.32, 16.61, 31.82, 28.07, 35.85, 37.09, 37.67, 37.66, 37.50, 28.81, 40.75, 41.41,
+        36.15, 35.12, 34.01, 16.59, 28.33, 34.80, 36.06, 36.79, 37.72, 37.29, 29.54, 41.02, 41.24,
+        28.10, 28.91, 28.80, 29.23, 12.57, 32.34, 34.92, 37.68, 41.23, 45.28, 50.00, 52.83, 53.17,
+        40.11, 39.38, 38.17, 37.32, 33.88, 31.73, 54.06, 55.32, 56.67, 57.69, 70.14, 62.37, 62.69,
+        40.35, 40.75, 39.39, 38.24, 36.54, 56.75, 32.35, 54.51, 55.99, 57.14, 68.84, 63.24, 61.97,
+        40.56, 40.27, 40.41, 39.33, 39.61, 57.96, 57.55, 33.65, 54.89, 56.88, 67.35, 61.65, 62.78,
+        40.64, 40.12, 39.98, 40.10, 43.55, 59.13, 58.43, 58.08, 33.82, 55.93, 65.97, 60.59, 61.99,
+        40.22, 40.31, 39.97, 39.92, 47.39, 60.86, 60.04, 59.26, 58.73, 34.81, 64.80, 59.49, 60.79,
+        28.92, 30.04, 30.32, 30.34, 52.51, 73.89, 72.74, 71.32, 69.76, 69.00, 36.30, 66.70, 68.38,
+        43.39, 44.08, 43.65, 43.81, 55.46, 65.03, 65.87, 64.46, 63.60, 62.98, 71.04, 48.56, 64.38,
+        43.71, 44.68, 44.29, 44.55, 56.31, 65.32, 65.77, 66.08, 65.55, 63.83, 72.45, 67.01, 49.47,
+    ],
+    // T3s
+    [
+        16.49, 33.80, 33.38, 33.01, 26.80, 37.44, 37.72, 38.18, 37.59, 36.98, 36.67, 28.00, 40.36,
+        36.06, 16.83, 32.90, 33.09, 26.91, 36.42, 37.44, 37.63, 37.60, 38.09, 37.57, 28.65, 41.04,
+        36.26, 35.28, 16.31, 31.62, 27.63, 35.48, 36.38, 37.54, 37.50, 37.25, 37.44, 28.72, 41.29,
+        35.33, 35.15, 33.88, 16.15, 27.74, 34.83, 35.68, 36.95, 37.45, 37.52, 37.22, 29.02, 40.71,
+        27.78, 28.36, 28.83, 28.61, 11.70, 32.06, 34.37, 37.70, 40.97, 44.92, 47.17, 50.00, 53.09,
+        40.24, 39.30, 37.90, 36.98, 33.73, 31.75, 53.86, 55.16, 56.68, 58.12, 59.45, 69.86, 62.26,
+        39.67, 40.32, 38.60, 38.12, 35.89, 56.44, 31.72, 53.60, 54.83, 56.86, 57.29, 70.02, 62.41,
+        39.94, 40.19, 40.25, 39.17, 39.46, 57.92, 56.80, 32.69, 54.47, 55.91, 57.09, 68.62, 62.66,
+        40.56, 40.10, 40.00, 39.99, 43.39, 59.46, 57.54, 57.41, 33.44, 55.54, 56.28, 67.35, 61.78,
+        39.22, 40.33, 40.06, 40.14, 46.76, 60.72, 59.10, 58.58, 58.18, 34.40, 55.27, 66.22, 60.90,
+        39.00, 40.09, 40.35, 39.90, 49.64, 62.09, 60.10, 59.91, 59.03, 57.85, 34.57, 65.85, 61.17,
+        29.63, 29.77, 29.87, 30.25, 52.47, 74.29, 74.21, 72.58, 71.25, 70.07, 70.34, 36.85, 68.61,
+        43.10, 43.88, 43.92, 43.86, 55.98, 65.44, 65.26, 65.55, 64.49, 63.50, 63.57, 72.52, 49.25,
+    ],
+    // T2s
+    [
+        16.30, 33.10, 33.15, 32.90, 26.74, 36.70, 37.35, 37.32, 37.81, 36.17, 36.77, 37.02, 28.27,
+        35.81, 16.23, 32.30, 32.76, 26.50, 36.35, 37.51, 37.69, 37.65, 38.08, 37.68, 37.50, 28.59,
+        35.68, 34.69, 15.65, 31.54, 26.97, 34.94, 36.15, 37.72, 37.21, 37.01, 37.40, 37.33, 28.75,
+        35.27, 34.57, 33.44, 15.76, 26.88, 34.45, 35.86, 36.45, 37.67, 37.26, 36.89, 37.31, 29.10,
+        27.13, 27.58, 28.14, 28.32, 11.45, 31.96, 34.53, 37.38, 40.75, 44.84, 46.83, 46.91, 50.00,
+        39.54, 38.90, 38.03, 36.50, 32.82, 31.14, 53.41, 54.68, 55.99, 57.87, 58.79, 58.88, 69.90,
+        39.72, 40.11, 39.02, 37.77, 36.12, 56.61, 31.79, 53.42, 55.11, 56.61, 57.94, 58.83, 70.25,
+        40.04, 39.30, 39.77, 38.87, 38.88, 57.51, 56.32, 32.13, 53.54, 55.14, 56.34, 57.62, 69.48,
+        40.55, 40.39, 39.36, 39.92, 42.84, 58.72, 57.86, 56.58, 33.02, 55.57, 55.55, 57.15, 68.34,
+        39.81, 39.74, 39.55, 39.61, 46.90, 60.47, 58.98, 58.04, 57.57, 34.37, 55.02, 56.06, 67.62,
+        39.07, 40.10, 40.00, 39.66, 49.77, 61.62, 60.52, 59.29, 58.63, 57.76, 34.77, 56.49, 67.56,
+        39.28, 39.82, 40.11, 40.16, 49.64, 61.76, 61.87, 60.13, 59.60, 58.46, 59.21, 35.04, 67.51,
+        29.01, 29.65, 29.79, 30.26, 52.49, 74.52, 74.32, 73.87, 72.75, 71.41, 71.22, 72.18, 37.36,
+    ],
+    // A9o
+    [
+        6.13, 24.61, 24.85, 25.89, 27.02, 47.59, 65.38, 65.16, 64.91, 63.56, 64.23, 63.76, 64.43,
+        25.56, 28.09, 54.52, 54.54, 54.57, 70.11, 60.43, 59.76, 59.79, 59.82, 59.90, 60.13, 60.15,
+        26.23, 57.89, 27.92, 53.67, 53.56, 68.37, 58.92, 59.81, 59.21, 59.82, 59.65, 59.91, 60.48,
+        26.73, 57.93, 56.98, 28.10, 52.84, 67.33, 58.08, 58.47, 59.74, 59.09, 59.66, 59.98, 60.23,
+        27.97, 58.09, 57.38, 56.07, 27.49, 65.93, 56.79, 58.24, 58.52, 59.78, 59.89, 59.76, 60.46,
+        50.00, 73.90, 72.69, 70.80, 69.38, 29.52, 66.43, 67.44, 68.35, 70.14, 71.74, 72.20, 72.54,
+        69.47, 63.85, 62.97, 61.65, 60.32, 70.05, 42.72, 58.75, 59.51, 61.06, 62.65, 64.10, 63.88,
+        69.06, 63.60, 63.89, 62.48, 61.53, 71.78, 62.13, 43.13, 58.63, 60.09, 61.33, 62.59, 63.73,
+        68.90, 63.13, 63.19, 63.81, 62.30, 72.89, 63.45, 62.35, 43.97, 58.94, 60.26, 61.40, 62.65,
+        67.16, 63.75, 63.41, 63.07, 63.37, 74.73, 64.62, 63.79, 62.25, 44.68, 59.16, 60.27, 61.63,
+        67.73, 63.43, 63.58, 63.87, 63.75, 75.98, 66.47, 65.12, 63.57, 62.55, 45.80, 60.83, 61.79,
+        68.16, 63.76, 63.89, 63.63, 63.26, 76.38, 67.98, 66.47, 65.46, 63.41, 63.97, 46.14, 61.72,
+        68.07, 64.23, 64.16, 63.98, 63.94, 76.93, 67.89, 67.91, 66.32, 65.40, 65.13, 65.96, 47.34,
+    ],
+    // K9o
+    [
+        13.07, 22.85, 34.29, 34.12, 34.16, 24.41, 38.66, 38.59, 38.32, 38.60, 38.67, 39.04, 39.26,
+        23.81, 7.80, 25.35, 25.62, 26.66, 47.52, 66.13, 65.55, 65.38, 65.18, 65.44, 65.61, 66.23,
+        36.44, 26.27, 28.59, 54.48, 54.10, 69.41, 60.35, 60.94, 60.42, 60.42, 60.82, 60.83, 61.29,
+        35.85, 26.60, 57.62, 28.12, 53.68, 67.86, 58.68, 60.10, 61.07, 59.97, 60.51, 60.50, 60.72,
+        35.31, 28.02, 57.74, 56.68, 28.08, 66.58, 57.82, 59.01, 59.72, 60.66, 60.62, 60.70, 61.10,
+        26.10, 50.00, 73.46, 72.17, 70.45, 30.83, 67.72, 67.18, 68.23, 69.42, 71.45, 71.44, 71.80,
+        40.83, 69.83, 63.87, 62.73, 61.53, 71.45, 44.48, 59.34, 60.40, 61.38, 62.70, 64.17, 64.01,
+        40.82, 69.85, 64.69, 63.57, 61.80, 71.45, 63.11, 44.25, 59.15, 60.28, 61.46, 62.83, 63.97,
+        41.09, 69.63, 64.13, 64.35, 63.41, 72.49, 63.71, 62.58, 45.11, 59.24, 60.62, 61.87, 62.90,
+        40.61, 68.82, 64.50, 63.97, 64.07, 73.86, 65.05, 63.67, 62.40, 46.14, 59.14, 60.50, 61.34,
+        41.48, 69.60, 64.40, 64.89, 64.39, 75.37, 66.55, 65.70, 64.05, 62.59, 47.24, 60.64, 61.99,
+        40.94, 70.08, 64.86, 64.81, 64.73, 75.97, 68.08, 66.23, 65.39, 63.94, 64.85, 47.49, 62.11,
+        41.40, 69.93, 64.95, 64.97, 64.88, 76.26, 68.13, 68.12, 67.03, 64.99, 65.71, 65.76, 48.23,
+    ],
+    // Q9o
+    [
+        14.93, 32.44, 24.28, 35.08, 34.86, 26.38, 39.51, 40.10, 39.85, 40.05, 40.51, 40.08, 40.34,
+        34.42, 13.65, 24.73, 34.18, 34.05, 25.26, 39.63, 40.09, 40.50, 40.29, 40.81, 40.58, 41.22,
+        25.04, 25.65, 9.24, 26.04, 27.06, 47.40, 65.73, 66.15, 65.75, 65.34, 65.96, 66.07, 66.46,
+        37.10, 36.33, 27.04, 28.35, 54.10, 68.67, 60.40, 61.28, 61.64, 61.35, 61.58, 61.86, 62.36,
+        36.99, 35.67, 28.05, 57.74, 28.79, 67.44, 59.04, 59.96, 60.47, 61.35, 61.83, 62.10, 61.97,
+        27.31, 26.54, 50.00, 73.19, 71.24, 32.07, 67.38, 68.57, 68.13, 69.30, 71.38, 71.34, 71.43,
+        41.84, 41.72, 69.84, 63.99, 62.40, 71.25, 44.46, 60.39, 60.57, 62.07, 63.50, 65.00, 65.07,
+        42.52, 42.29, 70.21, 64.72, 63.36, 72.48, 64.12, 46.05, 59.74, 60.57, 62.07, 63.37, 64.26,
+        42.32, 42.40, 69.66, 65.51, 64.51, 72.51, 64.76, 62.94, 46.18, 59.49, 60.94, 61.94, 63.68,
+        41.97, 42.59, 69.51, 65.16, 65.40, 73.67, 65.66, 64.01, 62.76, 47.03, 59.40, 60.66, 61.98,
+        42.80, 43.04, 70.02, 65.80, 65.57, 75.48, 66.96, 65.74, 64.47, 63.51, 48.38, 61.20, 62.08,
+        42.63, 42.95, 69.91, 65.47, 65.79, 75.64, 68.60, 67.38, 65.56, 64.55, 64.67, 48.62, 62.83,
+        42.72, 42.94, 70.62, 66.17, 66.00, 75.76, 68.65, 68.57, 67.14, 65.32, 66.71, 66.32, 49.27,
+    ],
+    // J9o
+    [
+        16.82, 33.88, 33.61, 25.75, 36.10, 27.62, 40.31, 40.79, 41.71, 41.33, 41.47, 41.18, 41.74,
+        35.63, 15.43, 32.84, 26.40, 34.80, 27.03, 40.64, 41.27, 41.59, 41.68, 41.91, 42.08, 41.98,
+        35.44, 34.25, 13.85, 25.87, 34.08, 25.81, 40.30, 41.18, 41.70, 41.71, 42.04, 41.55, 42.27,
+        26.58, 27.12, 26.17, 11.15, 27.07, 47.57, 65.73, 66.07, 65.88, 65.30, 66.34, 65.97, 66.76,
+        37.95, 36.76, 35.58, 28.18, 28.90, 68.79, 60.23, 60.98, 61.80, 62.60, 62.68, 63.02, 63.50,
+        29.20, 27.83, 26.81, 50.00, 72.39, 33.71, 67.66, 68.09, 69.21, 69.22, 70.64, 70.50, 71.21,
+        42.98, 42.77, 41.85, 69.91, 63.69, 71.43, 45.06, 60.80, 61.76, 62.87, 63.99, 65.43, 65.34,
+        43.81, 43.15, 43.26, 69.69, 64.30, 72.30, 64.69, 46.93, 60.42, 61.43, 62.22, 63.68, 65.25,
+        44.04, 44.24, 43.68, 69.93, 65.29, 73.85, 66.22, 64.55, 47.94, 60.07, 61.71, 62.16, 63.48,
+        43.69, 43.75, 43.77, 69.50, 66.48, 73.48, 66.52, 64.86, 63.74, 48.42, 59.98, 60.76, 62.22,
+        43.72, 44.44, 44.40, 70.26, 66.48, 75.08, 68.09, 66.83, 65.28, 63.57, 49.26, 61.66, 62.99,
+        43.44, 44.01, 44.19, 69.85, 66.78, 75.15, 69.48, 67.75, 66.26, 64.66, 65.29, 49.48, 62.80,
+        44.33, 44.56, 44.28, 70.69, 66.81, 76.00, 69.27, 69.30, 68.04, 65.82, 66.68, 66.67, 50.23,
+    ],
+    // T9o
+    [
+        18.19, 35.02, 35.00, 34.91, 27.19, 28.90, 41.46, 41.64, 42.39, 42.48, 42.81, 42.91, 42.75,
+        36.94, 17.37, 34.29, 33.81, 27.90, 28.20, 41.79, 41.63, 42.64, 42.69, 43.24, 43.28, 43.62,
+        36.78, 35.95, 15.58, 32.76, 26.62, 27.43, 40.88, 42.29, 42.54, 43.02, 43.88, 43.08, 43.46,
+        36.30, 34.85, 34.33, 14.08, 25.82, 26.39, 39.94, 41.49, 42.61, 43.41, 42.83, 43.51, 43.60,
+        27.74, 28.17, 27.60, 26.50, 12.64, 47.59, 65.95, 65.81, 66.29, 66.13, 66.12, 66.27, 67.18,
+        30.62, 29.55, 28.76, 27.61, 50.00, 34.83, 68.16, 68.21, 68.65, 70.09, 70.56, 70.35, 70.76,
+        43.41, 43.89, 42.85, 42.15, 69.64, 72.25, 46.31, 61.88, 62.97, 63.63, 64.63, 66.28, 66.59,
+        44.38, 44.43, 44.27, 43.42, 69.63, 72.90, 66.14, 47.18, 61.54, 62.25, 63.24, 64.44, 65.67,
+        44.84, 44.64, 44.79, 44.94, 69.46, 72.76, 66.73, 64.72, 48.16, 60.80, 62.34, 63.14, 64.35,
+        45.14, 45.58, 45.30, 45.46, 70.38, 74.72, 67.58, 66.16, 64.75, 49.75, 61.46, 61.93, 62.83,
+        45.42, 45.72, 45.49, 45.66, 70.35, 74.76, 69.02, 67.19, 66.20, 65.40, 50.29, 61.74, 63.15,
+        44.99, 45.46, 45.89, 45.89, 70.35, 74.72, 70.21, 68.60, 66.60, 65.37, 65.77, 51.21, 62.67,
+        44.93, 45.30, 45.86, 46.20, 70.67, 75.11, 70.35, 69.83, 68.15, 66.89, 66.65, 66.75, 51.65,
+    ],
+    // 99
+    [
+        19.50, 52.85, 52.74, 53.07, 52.59, 66.32, 67.43, 67.39, 67.28, 66.10, 66.40, 66.59, 66.97,
+        54.85, 19.45, 53.05, 52.56, 52.82, 64.97, 68.05, 67.64, 67.89, 67.74, 68.05, 68.14, 68.60,
+        55.35, 54.99, 19.00, 51.84, 51.85, 63.84, 67.65, 68.10, 67.92, 67.55, 68.20, 68.31, 67.95,
+        55.12, 55.05, 54.37, 18.28, 51.47, 62.69, 67.41, 67.92, 68.24, 67.97, 68.28, 68.22, 68.44,
+        55.73, 55.33, 54.81, 53.74, 18.22, 61.14, 66.88, 67.48, 67.59, 68.03, 68.27, 68.25, 68.86,
+        70.48, 69.17, 67.93, 66.29, 65.17, 50.00, 81.99, 83.38, 84.89, 86.14, 87.77, 87.98, 88.19,
+        70.75, 72.01, 71.61, 70.89, 70.25, 86.78, 82.01, 81.69, 81.42, 81.75, 82.70, 84.64, 84.37,
+        70.76, 71.72, 71.80, 71.27, 70.69, 88.39, 85.67, 81.41, 80.26, 80.05, 81.09, 82.80, 84.50,
+        71.42, 71.43, 71.67, 71.94, 71.03, 90.00, 85.75, 84.13, 81.23, 79.13, 79.66, 81.00, 83.08,
+        69.43, 71.24, 71.51, 71.55, 71.75, 91.24, 85.86, 84.20, 82.58, 81.19, 78.05, 79.40, 81.36,
+        70.25, 71.46, 71.75, 72.12, 72.23, 93.13, 86.72, 85.01, 83.71, 81.76, 81.21, 79.87, 81.64,
+        69.78, 71.54, 71.48, 71.70, 72.15, 93.14, 88.55, 86.98, 85.26, 83.24, 83.74, 81.15, 81.55,
+        70.53, 72.10, 71.93, 72.33, 72.91, 93.57, 88.54, 88.44, 87.00, 85.13, 85.56, 85.59, 81.99,
+    ],
+    // 98s
+    [
+        21.86, 36.97, 38.24, 38.26, 37.76, 32.47, 32.95, 43.73, 44.52, 44.37, 45.20, 45.40, 45.02,
+        39.71, 21.58, 36.97, 37.49, 37.30, 31.26, 32.99, 43.60, 44.24, 45.00, 45.63, 45.50, 45.71,
+        40.87, 39.79, 20.76, 36.45, 36.51, 31.58, 32.56, 44.30, 44.71, 44.97, 46.25, 45.99, 45.65,
+        40.85, 39.87, 38.94, 19.79, 35.20, 31.30, 32.20, 43.33, 44.65, 45.14, 46.04, 45.62, 46.08,
+        40.54, 39.39, 38.55, 37.61, 18.35, 31.22, 31.40, 42.73, 44.52, 45.47, 45.94, 46.14, 46.59,
+        33.57, 32.28, 32.62, 32.34, 31.84, 18.01, 50.00, 64.62, 64.94, 64.65, 65.86, 65.94, 66.02,
+        34.46, 34.20, 33.72, 32.76, 32.19, 52.40, 39.01, 67.92, 68.29, 68.62, 70.33, 70.32, 70.54,
+        47.05, 46.75, 47.00, 46.18, 46.17, 68.33, 71.76, 49.29, 64.31, 64.74, 66.05, 66.98, 68.62,
+        47.20, 47.06, 47.62, 47.77, 46.98, 68.43, 72.47, 66.86, 49.88, 63.23, 64.15, 65.24, 66.69,
+        47.09, 47.46, 47.76, 47.91, 47.82, 68.67, 72.80, 67.56, 66.10, 51.33, 63.40, 64.15, 64.50,
+        48.41, 48.60, 48.47, 48.65, 49.37, 69.27, 74.87, 68.73, 67.38, 66.14, 52.60, 65.14, 65.14,
+        47.91, 48.60, 48.61, 48.36, 48.64, 69.63, 74.68, 69.94, 68.55, 66.76, 68.02, 53.17, 65.02,
+        47.95, 48.88, 48.71, 49.21, 48.86, 69.57, 74.85, 71.67, 70.01, 67.94, 68.28, 68.28, 53.25,
+    ],
+    // 97s
+    [
+        21.09, 37.47, 36.61, 37.24, 37.08, 31.38, 40.48, 31.58, 43.28, 43.16, 44.21, 44.29, 44.34,
+        40.04, 21.11, 37.12, 36.82, 36.87, 31.28, 40.29, 32.24, 43.18, 44.13, 44.29, 44.80, 45.22,
+        39.44, 39.01, 20.53, 35.66, 35.33, 30.12, 40.06, 32.87, 43.08, 44.05, 44.56, 44.70, 45.02,
+        39.72, 39.06, 37.88, 19.34, 34.41, 30.95, 38.77, 31.78, 43.47, 43.75, 44.92, 44.73, 44.87,
+        39.90, 39.35, 38.03, 37.05, 18.05, 30.71, 37.79, 31.64, 42.29, 43.87, 44.68, 44.84, 45.32,
+        32.56, 32.82, 31.43, 31.91, 31.79, 16.62, 35.38, 50.00, 61.86, 62.45, 62.84, 62.68, 63.02,
+        42.55, 43.18, 42.06, 41.20, 40.02, 36.84, 32.97, 67.51, 62.19, 63.15, 64.13, 65.04, 65.12,
+        32.82, 33.86, 34.09, 33.06, 32.54, 52.46, 71.40, 38.21, 66.49, 67.05, 68.85, 69.14, 70.41,
+        45.76, 46.16, 46.12, 46.67, 45.61, 64.74, 64.65, 70.49, 48.47, 62.58, 63.87, 64.89, 66.85,
+        45.94, 46.99, 46.75, 46.28, 46.77, 65.76, 65.80, 70.97, 65.13, 49.90, 62.46, 63.38, 64.45,
+        47.03, 47.51, 47.54, 47.57, 47.65, 66.47, 66.50, 73.19, 66.78, 65.05, 51.33, 63.27, 64.96,
+        47.03, 47.76, 47.57, 47.83, 47.70, 65.89, 68.14, 73.17, 68.63, 66.01, 66.61, 52.18, 64.87,
+        47.09, 48.07, 47.64, 47.75, 47.67, 66.69, 68.45, 74.96, 69.34, 67.72, 67.58, 68.04, 52.75,
+    ],
+    // 96s
+    [
+        19.54, 36.53, 36.64, 35.77, 36.46, 30.53, 39.02, 39.38, 30.74, 42.31, 43.16, 43.40, 43.73,
+        39.08, 19.56, 35.72, 35.29, 35.43, 30.49, 39.61, 39.03, 31.08, 42.44, 43.68, 43.60, 44.23,
+        39.12, 38.61, 19.42, 34.65, 35.07, 30.59, 38.34, 39.57, 31.29, 42.93, 43.41, 43.80, 43.45,
+        38.62, 37.72, 37.64, 19.11, 33.63, 29.72, 37.78, 38.80, 31.45, 42.61, 43.23, 42.79, 43.88,
+        38.80, 38.31, 37.09, 36.00, 18.00, 29.99, 37.07, 37.56, 31.41, 42.68, 43.33, 43.32, 44.01,
+        31.65, 31.77, 31.87, 30.79, 31.35, 15.11, 35.06, 38.14, 50.00, 58.55, 59.31, 59.42, 59.67,
+        41.86, 41.99, 41.06, 40.29, 38.84, 36.67, 32.95, 56.24, 67.62, 62.04, 63.68, 64.73, 64.20,
+        41.94, 42.32, 41.95, 41.16, 40.13, 39.92, 58.98, 33.14, 66.29, 60.68, 62.24, 63.53, 64.40,
+        31.95, 32.37, 32.64, 33.12, 32.32, 52.60, 71.56, 70.20, 37.22, 65.74, 67.43, 67.70, 69.20,
+        44.97, 45.27, 45.43, 45.33, 45.64, 61.53, 64.63, 64.01, 69.67, 48.39, 62.29, 63.43, 64.63,
+        46.02, 46.31, 46.20, 46.44, 45.98, 62.70, 65.94, 64.55, 71.39, 65.16, 50.23, 62.96, 64.01,
+        45.84, 46.29, 46.34, 46.33, 46.03, 62.54, 67.21, 66.05, 71.62, 66.11, 65.55, 50.80, 64.54,
+        46.38, 46.85, 46.48, 47.02, 46.81, 63.15, 67.25, 67.28, 73.38, 67.10, 67.19, 67.14, 51.29,
+    ],
+    // 95s
+    [
+        17.91, 35.40, 35.59, 35.32, 34.43, 28.54, 38.25, 38.36, 38.68, 29.07, 40.87, 41.31, 41.37,
+        37.86, 18.32, 35.10, 34.36, 34.50, 29.41, 38.45, 38.37, 38.10, 29.95, 42.51, 42.20, 42.64,
+        37.74, 37.63, 18.38, 34.20, 33.47, 29.37, 37.64, 38.40, 38.15, 30.78, 42.28, 42.17, 42.28,
+        37.45, 37.18, 36.25, 18.03, 32.58, 29.53, 36.64, 37.65, 38.69, 30.81, 42.01, 41.90, 42.20,
+        36.86, 36.89, 35.93, 35.36, 17.76, 28.56, 35.70, 36.40, 37.77, 31.62, 42.31, 41.88, 42.13,
+        29.86, 30.58, 30.70, 30.78, 29.91, 13.86, 35.35, 37.55, 41.45, 50.00, 55.96, 55.82, 55.90,
+        40.63, 41.51, 40.32, 39.42, 38.24, 36.55, 32.28, 55.24, 56.49, 67.76, 62.47, 63.44, 63.27,
+        40.88, 40.79, 41.13, 40.07, 39.37, 39.67, 57.68, 33.27, 55.64, 66.24, 60.86, 62.34, 63.13,
+        41.15, 40.96, 40.69, 41.29, 40.23, 43.73, 59.24, 58.35, 33.70, 65.01, 60.13, 61.02, 62.92,
+        30.37, 31.62, 31.64, 31.42, 32.63, 52.65, 71.61, 69.77, 68.62, 36.70, 65.73, 66.10, 67.26,
+        43.80, 45.07, 44.60, 44.70, 45.18, 58.96, 64.72, 64.20, 63.06, 69.65, 49.03, 63.24, 64.06,
+        43.61, 45.14, 44.89, 45.05, 45.00, 58.98, 66.65, 65.32, 63.94, 69.85, 65.59, 49.11, 63.79,
+        44.50, 45.33, 45.42, 45.47, 45.01, 58.87, 66.11, 66.57, 65.38, 71.18, 66.77, 66.89, 50.36,
+    ],
+    // 94s
+    [
+        16.63, 33.96, 34.10, 33.93, 33.58, 27.03, 37.03, 37.07, 37.16, 36.14, 28.23, 40.13, 39.87,
+        36.47, 17.09, 33.76, 33.89, 33.55, 27.84, 37.51, 37.30, 37.28, 37.47, 29.14, 41.03, 41.63,
+        36.33, 36.40, 16.72, 32.92, 32.73, 28.18, 36.68, 37.38, 36.97, 36.99, 29.18, 41.31, 41.38,
+        36.14, 35.99, 35.01, 16.34, 31.84, 28.76, 35.64, 36.35, 37.68, 36.89, 29.41, 40.95, 40.94,
+        35.85, 35.64, 34.99, 33.93, 16.49, 28.64, 34.91, 35.79, 36.37, 37.01, 29.86, 40.55, 41.21,
+        28.26, 28.55, 28.62, 29.36, 29.44, 12.23, 34.14, 37.16, 40.69, 44.04, 50.00, 52.94, 52.95,
+        39.44, 40.17, 39.62, 38.10, 37.09, 36.02, 32.04, 53.96, 55.10, 56.22, 67.28, 62.23, 62.23,
+        39.60, 40.23, 40.16, 38.82, 37.97, 39.12, 56.37, 32.32, 54.47, 55.81, 66.08, 61.39, 62.62,
+        39.79, 39.98, 39.78, 39.34, 39.60, 42.45, 58.12, 57.36, 32.77, 55.02, 65.07, 60.25, 61.64,
+        38.58, 39.64, 39.17, 39.24, 39.89, 46.28, 58.94, 58.52, 57.66, 33.47, 63.96, 59.26, 60.52,
+        29.32, 29.84, 30.22, 30.43, 30.83, 52.53, 71.71, 70.44, 68.86, 67.16, 35.78, 66.07, 66.98,
+        42.66, 43.51, 43.40, 43.65, 43.75, 55.70, 65.04, 63.95, 63.28, 61.79, 69.82, 48.22, 63.77,
+        42.98, 44.13, 44.10, 44.03, 43.61, 55.50, 65.14, 65.23, 64.50, 62.86, 71.28, 66.48, 49.08,
+    ],
+    // 93s
+    [
+        16.35, 34.58, 34.37, 33.70, 33.70, 26.89, 36.51, 37.42, 37.50, 36.45, 36.80, 28.66, 40.32,
+        36.77, 16.89, 33.76, 33.45, 33.06, 27.74, 37.19, 36.96, 37.87, 37.65, 37.80, 28.84, 41.32,
+        36.57, 36.07, 16.67, 32.47, 32.84, 27.88, 36.24, 37.72, 37.18, 37.68, 37.79, 29.15, 41.37,
+        36.05, 35.67, 35.20, 16.42, 31.82, 28.40, 35.71, 36.42, 37.32, 37.69, 37.47, 29.25, 41.01,
+        36.38, 35.62, 34.47, 33.73, 16.05, 28.72, 34.34, 35.75, 36.68, 37.79, 37.63, 30.14, 41.12,
+        27.80, 28.56, 28.66, 29.50, 29.65, 12.02, 34.06, 37.32, 40.58, 44.18, 47.06, 50.00, 52.73,
+        39.19, 40.10, 39.40, 38.01, 37.15, 35.98, 31.61, 53.47, 54.70, 55.99, 57.52, 68.94, 61.76,
+        39.72, 40.03, 40.10, 39.59, 38.43, 39.34, 55.81, 32.44, 54.25, 55.76, 56.87, 67.31, 62.46,
+        39.96, 39.84, 40.35, 40.12, 39.32, 42.69, 57.49, 56.80, 32.82, 54.98, 55.55, 66.22, 61.20,
+        38.62, 40.16, 39.68, 39.62, 40.02, 46.20, 58.59, 57.57, 57.33, 33.36, 55.13, 64.81, 60.17,
+        39.56, 40.59, 40.31, 40.32, 40.42, 49.98, 60.05, 59.49, 58.68, 57.86, 34.40, 65.20, 60.76,
+        28.90, 29.86, 30.05, 30.53, 30.91, 52.53, 72.70, 71.94, 70.04, 68.77, 69.46, 36.47, 67.23,
+        42.80, 44.40, 44.36, 43.83, 43.90, 55.47, 65.13, 65.29, 64.35, 62.52, 63.51, 71.62, 49.39,
+    ],
+    // 92s
+    [
+        15.95, 33.38, 33.81, 33.65, 33.05, 26.34, 36.96, 36.50, 37.14, 36.21, 36.72, 36.71, 28.28,
+        35.98, 16.18, 33.37, 33.07, 32.97, 26.75, 37.07, 37.16, 37.44, 37.49, 37.75, 37.70, 28.85,
+        35.82, 35.95, 16.28, 32.13, 32.33, 27.69, 36.47, 37.14, 37.40, 37.30, 37.59, 37.57, 28.71,
+        36.08, 35.58, 34.65, 15.77, 31.45, 27.78, 35.85, 36.29, 37.25, 37.08, 37.60, 37.45, 29.21,
+        35.54, 35.25, 34.78, 33.40, 15.47, 27.66, 34.77, 35.47, 36.13, 37.41, 37.31, 37.74, 30.10,
+        27.46, 28.20, 28.57, 28.79, 29.24, 11.81, 33.98, 36.98, 40.33, 44.10, 47.05, 47.27, 50.00,
+        39.48, 40.02, 38.57, 38.23, 36.89, 35.74, 31.22, 53.06, 54.65, 55.61, 57.68, 58.94, 69.10,
+        39.15, 39.66, 39.62, 39.14, 37.41, 38.70, 55.76, 31.68, 52.78, 54.95, 56.05, 56.92, 68.76,
+        39.94, 40.03, 40.10, 40.11, 38.59, 42.46, 57.30, 55.70, 32.64, 53.86, 55.58, 56.96, 67.37,
+        38.60, 40.24, 39.92, 39.50, 40.03, 46.06, 59.09, 57.14, 56.80, 33.44, 54.65, 55.74, 65.81,
+        38.98, 40.19, 40.49, 40.38, 39.71, 49.48, 60.12, 59.10, 57.76, 57.30, 33.77, 56.39, 66.50,
+        39.26, 40.41, 39.88, 39.96, 40.02, 49.64, 61.58, 60.19, 59.34, 58.03, 58.47, 34.72, 66.58,
+        29.20, 30.12, 29.82, 30.20, 30.90, 52.67, 73.11, 73.16, 71.48, 69.89, 70.56, 70.47, 37.35,
+    ],
+    // A8o
+    [
+        6.66, 25.11, 25.26, 26.20, 26.85, 29.52, 47.50, 62.55, 63.15, 61.48, 61.21, 61.70, 62.04,
+        26.04, 28.30, 53.71, 54.06, 54.27, 55.98, 71.34, 60.41, 59.45, 59.79, 59.85, 60.41, 60.34,
+        26.92, 57.30, 28.26, 53.37, 53.21, 55.28, 69.86, 60.44, 59.48, 59.47, 60.04, 60.39, 60.19,
+        27.33, 57.27, 56.67, 28.31, 52.41, 54.10, 68.36, 59.02, 60.13, 59.49, 59.79, 60.45, 60.22,
+        28.42, 57.14, 56.88, 55.54, 28.17, 53.28, 66.47, 58.51, 58.71, 60.16, 59.65, 60.33, 60.28,
+        30.53, 59.17, 58.16, 57.02, 56.59, 29.25, 65.54, 57.45, 58.14, 59.37, 60.56, 60.81, 60.52,
+        50.00, 75.32, 74.02, 72.76, 70.57, 69.74, 29.86, 66.10, 67.57, 68.97, 70.54, 71.89, 72.11,
+        66.50, 63.82, 64.05, 62.85, 61.81, 60.80, 70.17, 43.41, 58.94, 60.00, 61.51, 62.83, 64.14,
+        66.41, 63.52, 63.50, 63.95, 62.57, 61.99, 71.33, 62.69, 43.64, 59.11, 60.65, 61.40, 63.23,
+        64.99, 63.67, 63.47, 63.89, 63.52, 62.96, 73.01, 63.72, 62.19, 45.21, 59.26, 60.24, 61.70,
+        65.22, 63.57, 63.63, 64.14, 63.71, 64.45, 74.78, 65.98, 63.82, 62.73, 45.75, 61.18, 61.74,
+        65.32, 64.60, 64.55, 64.17, 64.14, 64.25, 76.79, 67.03, 65.54, 64.19, 64.69, 46.99, 62.21,
+        65.82, 64.33, 64.45, 63.72, 64.20, 64.34, 76.86, 68.40, 66.86, 65.32, 65.87, 66.00, 47.52,
+    ],
+    // K8o
+    [
+        11.74, 23.22, 33.08, 32.90, 32.93, 34.15, 23.14, 37.35, 37.81, 37.32, 37.42, 38.29, 38.38,
+        24.09, 6.27, 24.74, 25.66, 26.55, 28.52, 47.49, 62.45, 62.32, 62.39, 62.29, 62.83, 63.09,
+        35.65, 25.53, 27.58, 53.32, 53.38, 55.12, 69.51, 60.12, 59.67, 59.49, 59.63, 60.20, 60.27,
+        34.71, 27.01, 56.65, 27.51, 51.86, 53.85, 67.98, 59.10, 60.00, 59.46, 59.25, 59.88, 59.79,
+        34.34, 27.56, 56.25, 55.21, 27.51, 52.91, 65.81, 58.25, 59.11, 59.80, 59.25, 59.68, 59.89,
+        36.15, 30.17, 58.28, 57.23, 56.11, 27.99, 65.80, 56.82, 58.01, 58.49, 59.83, 59.90, 59.98,
+        24.68, 50.00, 73.71, 71.67, 70.27, 69.41, 29.62, 65.43, 67.42, 68.09, 70.18, 71.35, 71.39,
+        39.36, 66.66, 64.02, 62.70, 61.36, 60.72, 69.70, 43.45, 58.77, 60.14, 61.52, 62.49, 64.03,
+        40.12, 66.16, 63.30, 63.51, 62.60, 61.04, 71.09, 62.41, 43.79, 58.40, 59.68, 61.40, 63.02,
+        39.45, 65.79, 62.79, 63.71, 63.11, 62.44, 72.34, 63.66, 62.56, 45.01, 59.01, 59.83, 61.57,
+        40.15, 65.89, 63.44, 63.00, 63.12, 63.71, 74.15, 65.12, 63.59, 62.43, 45.63, 60.20, 61.49,
+        40.32, 66.69, 63.76, 64.03, 63.65, 63.93, 75.79, 66.52, 65.29, 63.94, 64.56, 46.71, 61.76,
+        40.59, 66.85, 63.47, 63.46, 64.18, 64.17, 76.01, 67.67, 66.83, 65.23, 65.36, 65.75, 47.18,
+    ],
+    // Q8o
+    [
+        13.51, 31.55, 24.48, 34.68, 34.02, 34.95, 24.71, 38.79, 38.94, 38.58, 39.08, 39.56, 39.30,
+        33.31, 13.25, 23.33, 33.62, 33.10, 34.26, 25.27, 38.88, 38.81, 38.89, 39.26, 39.96, 40.20,
+        25.50, 23.99, 7.84, 26.19, 26.83, 28.78, 47.57, 63.42, 63.19, 62.99, 62.84, 63.71, 63.32,
+        36.18, 35.21, 26.95, 27.93, 52.98, 54.61, 69.11, 60.22, 60.85, 60.60, 60.70, 60.95, 61.24,
+        35.92, 34.42, 27.83, 56.51, 28.11, 53.54, 67.46, 58.49, 59.72, 60.34, 60.61, 61.40, 60.98,
+        37.03, 36.13, 30.16, 58.15, 57.15, 28.39, 66.28, 57.94, 58.94, 59.68, 60.38, 60.60, 61.43,
+        25.98, 26.29, 50.00, 73.19, 71.43, 69.94, 30.83, 67.09, 66.93, 67.88, 69.40, 71.24, 71.21,
+        40.90, 40.87, 67.09, 63.92, 62.62, 61.82, 71.25, 45.27, 59.02, 60.16, 61.63, 63.07, 64.50,
+        41.51, 40.69, 67.23, 64.41, 63.57, 62.34, 70.74, 63.09, 44.48, 59.20, 60.31, 61.95, 63.17,
+        40.94, 41.24, 66.42, 64.36, 64.51, 62.98, 71.94, 64.09, 63.20, 45.84, 59.24, 60.40, 61.39,
+        41.02, 41.41, 67.16, 64.19, 64.52, 64.42, 73.94, 65.74, 63.92, 62.46, 46.55, 60.71, 62.06,
+        41.87, 42.03, 67.73, 64.68, 64.78, 64.68, 75.69, 66.95, 65.55, 64.08, 64.74, 47.92, 62.31,
+        41.79, 42.04, 67.46, 65.10, 65.07, 64.74, 75.81, 68.22, 66.90, 65.46, 65.74, 66.09, 49.06,
+    ],
+    // J8o
+    [
+        15.29, 32.65, 33.03, 25.70, 35.73, 36.56, 26.22, 39.27, 40.46, 40.08, 40.36, 40.21, 40.84,
+        34.98, 14.99, 31.91, 24.84, 34.14, 35.23, 26.85, 39.99, 40.21, 40.24, 40.80, 41.19, 40.62,
+        34.57, 33.35, 13.98, 25.02, 33.04, 34.24, 25.74, 39.73, 40.09, 40.66, 40.62, 40.74, 41.03,
+        26.56, 26.07, 25.85, 9.58, 26.80, 28.87, 47.47, 63.47, 63.59, 63.11, 63.69, 63.91, 63.80,
+        36.74, 36.01, 35.09, 28.19, 28.33, 54.88, 68.47, 60.00, 60.52, 61.60, 61.76, 61.88, 62.23,
+        38.35, 37.27, 36.01, 30.09, 57.85, 29.11, 67.24, 58.80, 59.71, 60.58, 61.90, 61.99, 61.77,
+        27.24, 28.33, 26.81, 50.00, 72.36, 71.27, 32.77, 66.50, 67.76, 67.62, 68.76, 70.90, 70.96,
+        41.84, 41.60, 41.69, 67.03, 64.18, 62.60, 70.70, 45.09, 60.64, 60.42, 61.87, 63.90, 64.81,
+        42.70, 42.64, 42.18, 67.40, 64.26, 63.56, 72.23, 63.88, 46.80, 59.41, 60.84, 62.13, 64.01,
+        42.32, 42.48, 42.66, 66.81, 65.62, 64.39, 71.87, 64.78, 63.30, 47.17, 59.57, 60.68, 61.97,
+        42.84, 43.00, 42.69, 67.45, 65.51, 65.86, 73.61, 66.23, 64.68, 62.93, 48.10, 61.46, 61.66,
+        42.98, 43.20, 42.79, 67.55, 66.47, 65.80, 75.41, 66.99, 66.15, 64.15, 65.08, 49.04, 62.79,
+        42.87, 43.13, 43.13, 67.69, 65.75, 66.01, 75.36, 69.07, 67.54, 65.66, 66.10, 66.63, 49.19,
+    ],
+    // T8o
+    [
+        17.15, 34.65, 33.57, 34.07, 27.20, 37.29, 27.72, 40.49, 41.17, 41.34, 41.54, 41.86, 41.69,
+        36.32, 16.97, 32.94, 33.01, 25.67, 36.28, 28.34, 40.36, 41.11, 42.14, 41.76, 42.18, 42.16,
+        35.93, 35.10, 15.18, 32.29, 26.45, 35.67, 27.52, 41.25, 40.80, 41.74, 41.75, 42.21, 41.95,
+        35.51, 34.57, 33.18, 14.47, 25.99, 34.58, 26.16, 40.01, 41.47, 41.79, 41.89, 42.20, 41.76,
+        28.46, 26.52, 27.48, 26.90, 11.62, 29.15, 47.52, 63.35, 63.48, 63.29, 63.46, 64.11, 63.88,
+        39.68, 38.47, 37.60, 36.31, 30.36, 29.75, 67.81, 59.98, 61.16, 61.76, 62.91, 62.85, 63.11,
+        29.43, 29.73, 28.57, 27.64, 50.00, 71.64, 34.13, 66.91, 67.20, 68.64, 69.06, 70.06, 70.57,
+        43.24, 43.04, 43.03, 42.18, 67.19, 63.32, 70.82, 46.01, 60.94, 61.74, 62.79, 64.27, 65.69,
+        43.63, 43.16, 43.31, 43.09, 67.35, 64.60, 71.31, 64.33, 46.70, 60.37, 61.42, 62.62, 64.03,
+        43.58, 44.13, 43.94, 43.99, 67.09, 65.42, 73.01, 65.29, 64.12, 48.92, 60.09, 61.75, 62.28,
+        43.91, 43.92, 44.16, 44.12, 67.54, 66.38, 73.22, 66.62, 65.09, 63.15, 48.94, 61.43, 62.28,
+        44.40, 44.84, 45.11, 44.65, 67.56, 66.87, 74.82, 68.16, 66.59, 65.09, 65.19, 50.31, 63.00,
+        44.45, 44.33, 44.62, 44.83, 68.16, 67.27, 74.64, 69.23, 68.08, 66.01, 65.97, 66.51, 50.35,
+    ],
+    // 98o
+    [
+        18.07, 33.93, 35.31, 35.50, 35.26, 28.60, 29.12, 40.98, 42.06, 42.06, 42.55, 42.33, 42.75,
+        35.87, 17.85, 34.03, 34.25, 33.90, 27.23, 28.71, 41.24, 41.30, 42.13, 43.00, 42.94, 42.82,
+        37.45, 35.91, 17.04, 33.65, 33.46, 28.10, 28.65, 41.24, 41.77, 42.57, 43.37, 43.27, 43.39,
+        37.70, 36.42, 35.40, 15.50, 32.52, 26.97, 27.82, 41.20, 42.43, 42.38, 43.43, 43.22, 43.37,
+        37.13, 35.35, 34.95, 33.86, 14.16, 26.64, 27.47, 40.08, 41.38, 43.24, 43.25, 43.56, 43.39,
+        29.95, 28.55, 28.75, 28.57, 27.75, 13.22, 47.60, 63.16, 63.33, 63.45, 63.98, 64.02, 64.26,
+        30.26, 30.59, 30.06, 28.73, 28.36, 50.00, 35.58, 67.16, 67.27, 67.63, 69.30, 69.59, 69.43,
+        43.42, 43.10, 43.54, 42.85, 42.33, 66.74, 71.23, 46.30, 61.13, 62.55, 63.89, 64.66, 66.11,
+        43.93, 44.13, 44.83, 44.61, 43.86, 67.01, 71.26, 65.35, 47.61, 60.47, 61.80, 63.03, 64.57,
+        44.13, 44.65, 44.37, 45.05, 45.24, 66.89, 71.92, 66.13, 64.62, 48.58, 60.72, 61.76, 62.47,
+        44.61, 45.56, 45.50, 45.79, 45.72, 68.10, 73.83, 67.87, 65.79, 63.97, 50.16, 62.48, 62.56,
+        45.33, 45.45, 45.59, 45.93, 45.68, 68.15, 74.22, 69.11, 66.83, 65.41, 66.57, 50.80, 63.02,
+        45.29, 45.43, 45.83, 45.72, 46.07, 68.32, 73.97, 70.14, 68.69, 66.49, 66.10, 66.27, 51.34,
+    ],
+    // 88
+    [
+        19.48, 52.56, 52.64, 52.71, 52.34, 54.34, 65.97, 67.37, 67.62, 66.05, 66.45, 66.87, 66.37,
+        55.28, 19.34, 51.39, 51.40, 51.61, 53.00, 66.22, 67.44, 66.83, 66.87, 67.12, 67.62, 67.50,
+        55.21, 53.72, 19.08, 50.70, 50.98, 52.38, 64.97, 67.20, 66.91, 67.15, 67.26, 68.11, 68.01,
+        55.26, 53.94, 53.20, 18.76, 50.28, 51.77, 63.31, 67.11, 67.56, 67.02, 67.53, 67.96, 67.86,
+        55.71, 54.13, 53.84, 53.01, 18.22, 50.92, 61.85, 66.70, 67.59, 67.92, 67.65, 68.28, 68.21,
+        57.28, 55.52, 55.54, 54.94, 53.69, 17.99, 60.99, 67.03, 67.05, 67.72, 67.96, 68.39, 68.78,
+        70.14, 70.38, 69.17, 67.23, 65.87, 64.42, 50.00, 81.76, 82.83, 84.71, 86.18, 88.09, 87.92,
+        71.27, 70.66, 71.64, 70.86, 70.47, 70.53, 86.84, 82.03, 81.61, 81.72, 82.12, 82.72, 84.53,
+        71.08, 70.86, 70.97, 71.06, 71.18, 70.89, 88.08, 85.51, 81.62, 80.09, 80.56, 81.31, 82.73,
+        69.50, 70.66, 70.71, 70.74, 70.85, 71.37, 89.59, 85.26, 84.13, 81.12, 78.17, 79.52, 81.34,
+        69.56, 70.40, 70.62, 71.35, 71.43, 71.78, 91.29, 86.12, 84.60, 82.73, 81.43, 79.94, 81.04,
+        70.00, 71.29, 71.17, 71.54, 71.78, 72.23, 93.25, 87.07, 85.06, 83.53, 84.29, 81.49, 81.54,
+        70.46, 70.65, 71.65, 71.56, 72.23, 72.43, 93.19, 88.92, 87.21, 85.34, 85.20, 85.80, 81.71,
+    ],
+    // 87s
+    [
+        22.45, 38.72, 38.02, 38.48, 38.82, 38.77, 32.11, 33.10, 44.47, 44.25, 44.95, 45.38, 45.98,
+        41.07, 21.92, 36.80, 37.90, 37.61, 38.39, 32.55, 33.46, 44.17, 44.11, 45.33, 45.52, 45.81,
+        40.98, 38.99, 21.56, 36.78, 36.96, 36.92, 31.64, 33.66, 43.58, 44.40, 44.76, 45.83, 46.00,
+        40.85, 40.48, 38.86, 20.65, 36.06, 36.94, 32.46, 33.05, 44.45, 44.94, 45.19, 45.24, 46.16,
+        40.87, 40.18, 38.92, 38.46, 19.53, 35.46, 31.94, 32.83, 43.92, 44.93, 45.49, 46.40, 46.58,
+        41.25, 40.66, 39.61, 39.20, 38.12, 18.31, 32.08, 32.49, 43.76, 44.76, 46.04, 46.53, 46.94,
+        33.90, 34.57, 32.91, 33.50, 33.09, 32.84, 18.24, 50.00, 61.79, 61.72, 62.45, 63.24, 62.93,
+        34.48, 34.23, 35.31, 34.26, 33.71, 33.61, 52.45, 39.38, 67.14, 66.24, 67.35, 69.16, 69.39,
+        47.12, 46.76, 47.02, 47.05, 46.37, 46.12, 65.04, 70.55, 49.21, 63.18, 64.60, 65.89, 66.60,
+        46.85, 47.02, 46.86, 47.27, 48.22, 47.46, 65.27, 71.08, 66.01, 50.16, 62.43, 63.56, 64.83,
+        47.57, 48.00, 47.44, 47.78, 48.28, 49.24, 66.02, 71.60, 66.95, 65.19, 51.22, 63.76, 65.10,
+        48.63, 48.66, 48.72, 48.73, 49.23, 49.69, 66.76, 73.59, 68.48, 66.37, 66.70, 53.12, 65.98,
+        48.06, 48.65, 48.31, 49.03, 49.25, 49.74, 66.81, 73.49, 69.48, 67.55, 67.77, 69.01, 53.63,
+    ],
+    // 86s
+    [
+        21.34, 37.80, 37.79, 37.32, 37.51, 37.85, 31.59, 40.30, 32.33, 43.55, 43.97, 44.53, 44.86,
+        40.08, 20.76, 36.95, 36.60, 36.66, 37.23, 31.56, 39.95, 31.92, 43.17, 44.03, 44.90, 44.53,
+        40.31, 39.81, 20.85, 35.86, 36.10, 36.45, 32.00, 40.54, 32.44, 43.41, 44.04, 45.01, 44.99,
+        40.05, 39.32, 38.68, 20.47, 35.36, 35.76, 31.00, 39.52, 33.17, 43.10, 43.88, 44.71, 44.72,
+        39.85, 39.51, 38.79, 37.52, 19.59, 34.46, 31.59, 38.88, 32.88, 43.86, 44.01, 45.17, 44.89,
+        40.49, 39.60, 39.43, 38.24, 37.03, 18.58, 31.71, 37.81, 32.38, 43.51, 44.90, 45.30, 45.35,
+        32.43, 32.58, 33.07, 32.24, 32.80, 32.73, 17.17, 38.21, 50.00, 58.30, 59.50, 59.90, 59.54,
+        43.31, 42.92, 43.18, 42.10, 41.23, 40.26, 39.95, 33.24, 65.62, 60.75, 62.50, 63.43, 64.69,
+        33.21, 33.78, 34.10, 34.23, 34.01, 33.60, 52.68, 69.80, 38.04, 64.82, 65.81, 67.51, 67.49,
+        45.97, 45.84, 46.43, 45.97, 46.38, 45.97, 61.47, 63.39, 69.08, 49.28, 61.79, 63.46, 64.56,
+        46.57, 46.42, 46.63, 46.59, 46.76, 47.33, 62.49, 65.35, 69.69, 65.08, 49.90, 63.22, 64.19,
+        48.17, 47.60, 47.71, 47.15, 47.19, 48.21, 63.19, 66.02, 71.17, 65.86, 66.13, 51.62, 64.05,
+        47.60, 47.37, 47.53, 48.16, 47.72, 48.14, 63.29, 67.86, 71.73, 67.53, 67.32, 67.43, 52.14,
+    ],
+    // 85s
+    [
+        19.58, 37.02, 36.86, 36.11, 35.85, 36.09, 30.12, 39.54, 39.42, 30.49, 41.67, 42.67, 42.68,
+        39.18, 19.54, 36.46, 36.17, 35.93, 36.05, 30.65, 39.05, 39.42, 31.41, 42.61, 43.73, 43.01,
+        39.37, 38.57, 19.86, 35.36, 35.42, 35.60, 30.54, 39.68, 39.17, 31.45, 42.95, 43.75, 43.66,
+        38.39, 39.15, 37.87, 19.23, 34.14, 34.92, 31.68, 38.78, 39.69, 32.11, 42.64, 43.45, 43.25,
+        38.41, 38.58, 37.63, 37.01, 19.48, 33.83, 30.36, 38.21, 38.60, 32.35, 42.86, 43.14, 43.39,
+        38.94, 38.62, 37.93, 37.13, 36.37, 18.25, 31.38, 36.85, 37.96, 32.24, 43.78, 44.01, 44.39,
+        31.03, 31.91, 32.12, 32.38, 31.36, 32.37, 15.29, 38.28, 41.70, 50.00, 55.88, 56.56, 56.54,
+        41.55, 41.58, 42.43, 41.09, 40.30, 40.09, 39.76, 33.47, 55.77, 65.76, 61.64, 62.70, 63.80,
+        41.88, 41.51, 42.20, 42.03, 41.24, 40.84, 43.31, 58.50, 33.78, 64.47, 60.30, 61.68, 62.46,
+        31.64, 32.08, 32.84, 33.23, 33.91, 33.51, 52.43, 69.76, 68.24, 37.95, 64.16, 65.68, 65.94,
+        44.64, 45.36, 45.50, 45.36, 45.47, 46.13, 58.79, 64.10, 62.96, 67.33, 49.11, 62.79, 64.04,
+        45.74, 46.25, 46.52, 46.24, 46.23, 46.85, 59.87, 65.79, 64.51, 69.43, 65.67, 50.81, 63.63,
+        44.95, 46.16, 46.51, 46.48, 46.68, 46.67, 60.02, 67.11, 65.87, 69.98, 66.83, 66.86, 50.70,
+    ],
+    // 84s
+    [
+        18.16, 35.45, 35.15, 35.17, 34.80, 34.98, 28.41, 38.09, 38.19, 37.53, 29.66, 41.43, 41.60,
+        37.94, 18.12, 34.55, 34.73, 34.58, 34.77, 29.18, 37.86, 38.48, 38.19, 30.00, 42.14, 42.38,
+        38.05, 37.05, 18.02, 34.60, 34.07, 34.41, 29.17, 38.51, 38.13, 38.44, 30.38, 42.26, 42.43,
+        37.31, 37.43, 36.51, 17.90, 33.42, 33.74, 29.44, 37.40, 38.31, 38.05, 30.82, 42.65, 42.52,
+        37.65, 37.29, 36.26, 35.75, 17.70, 32.77, 30.36, 36.64, 37.63, 38.14, 31.16, 42.71, 42.06,
+        37.35, 37.30, 36.50, 36.01, 35.37, 17.30, 29.67, 35.87, 36.32, 37.53, 32.72, 42.48, 42.32,
+        29.46, 29.82, 30.60, 31.24, 30.94, 30.70, 13.82, 37.55, 40.50, 44.12, 50.00, 53.47, 53.73,
+        40.32, 40.60, 41.04, 39.99, 38.76, 38.46, 39.12, 32.84, 54.63, 56.16, 65.55, 61.72, 62.62,
+        40.90, 40.55, 41.18, 40.46, 39.99, 39.03, 42.68, 56.96, 33.15, 55.05, 64.63, 60.58, 61.69,
+        39.96, 40.77, 41.00, 40.41, 40.84, 40.40, 46.58, 58.36, 57.58, 33.56, 62.84, 59.23, 60.17,
+        30.51, 31.09, 31.65, 31.77, 32.48, 33.85, 52.55, 69.90, 68.56, 67.22, 36.68, 65.91, 66.23,
+        43.92, 45.11, 45.22, 44.85, 44.67, 45.57, 56.75, 64.44, 63.42, 61.97, 69.72, 49.21, 63.46,
+        43.66, 44.88, 45.45, 44.95, 45.17, 45.50, 56.58, 65.42, 64.85, 63.10, 69.87, 66.00, 50.03,
+    ],
+    // 83s
+    [
+        16.33, 33.88, 34.12, 33.91, 33.53, 34.17, 27.23, 37.06, 37.19, 36.16, 36.53, 27.88, 40.27,
+        36.44, 16.19, 33.76, 33.12, 33.28, 33.55, 27.59, 36.89, 36.88, 36.65, 37.22, 29.05, 41.02,
+        36.49, 36.30, 16.36, 32.87, 32.83, 32.80, 27.50, 37.39, 36.57, 36.77, 37.52, 29.54, 41.03,
+        36.21, 36.14, 35.45, 16.44, 31.63, 32.17, 28.09, 36.61, 37.36, 36.71, 37.57, 29.55, 41.41,
+        35.83, 35.24, 34.82, 34.05, 15.72, 31.19, 28.62, 35.31, 36.09, 37.15, 36.76, 29.98, 41.17,
+        35.90, 35.83, 35.00, 34.57, 33.72, 15.36, 29.68, 34.96, 35.27, 36.56, 37.77, 31.06, 41.06,
+        28.11, 28.65, 28.76, 29.10, 29.94, 30.41, 11.91, 36.76, 40.10, 43.44, 46.53, 50.00, 52.74,
+        39.58, 39.74, 39.57, 38.72, 37.40, 37.19, 38.63, 31.28, 53.15, 54.56, 55.66, 66.25, 61.54,
+        39.67, 39.27, 39.26, 39.78, 38.81, 38.05, 42.13, 55.46, 32.08, 53.58, 54.99, 64.67, 60.49,
+        38.73, 39.21, 39.60, 39.52, 39.59, 38.96, 45.69, 56.78, 55.94, 33.16, 53.67, 63.39, 59.51,
+        39.33, 39.44, 40.23, 40.00, 39.87, 40.19, 49.06, 58.28, 57.97, 56.57, 34.01, 63.78, 59.46,
+        29.17, 29.62, 30.43, 30.54, 30.92, 32.25, 52.42, 70.26, 68.78, 67.10, 67.70, 36.30, 65.81,
+        42.87, 44.09, 44.13, 44.01, 43.80, 44.26, 55.48, 63.96, 63.04, 62.23, 61.87, 69.55, 48.59,
+    ],
+    // 82s
+    [
+        16.52, 34.01, 34.19, 33.84, 33.46, 33.76, 26.92, 36.72, 37.09, 36.32, 36.66, 36.96, 28.25,
+        36.38, 15.93, 33.42, 33.71, 33.66, 33.62, 27.48, 36.83, 36.78, 37.50, 37.07, 37.53, 28.62,
+        36.47, 35.83, 16.57, 32.80, 33.07, 32.83, 27.69, 36.82, 36.94, 37.28, 37.77, 37.47, 29.28,
+        35.73, 35.69, 35.64, 16.10, 31.88, 31.90, 28.00, 36.31, 37.31, 37.14, 37.51, 37.46, 29.30,
+        36.07, 35.72, 35.19, 34.39, 16.03, 31.46, 28.38, 35.47, 35.85, 37.33, 38.03, 37.59, 29.75,
+        36.12, 35.99, 34.93, 34.66, 33.41, 15.63, 29.46, 34.88, 35.80, 36.73, 37.77, 38.24, 30.90,
+        27.89, 28.61, 28.79, 29.04, 29.43, 30.57, 12.08, 37.07, 40.46, 43.46, 46.27, 47.26, 50.00,
+        39.14, 39.43, 39.58, 38.66, 37.96, 37.53, 38.49, 31.13, 52.20, 54.24, 55.01, 56.71, 67.31,
+        39.67, 39.43, 39.66, 40.12, 39.03, 38.59, 42.34, 55.15, 32.13, 52.91, 54.99, 56.17, 66.13,
+        38.88, 39.74, 39.52, 39.47, 39.84, 39.36, 45.45, 56.36, 55.61, 32.72, 53.41, 54.97, 64.70,
+        38.87, 39.89, 40.28, 39.63, 39.98, 40.58, 48.91, 57.92, 57.22, 55.98, 34.00, 55.37, 64.69,
+        39.77, 40.62, 40.42, 40.43, 40.47, 40.43, 50.00, 59.36, 58.72, 57.77, 57.89, 34.58, 65.02,
+        29.26, 30.37, 30.11, 30.77, 31.11, 32.28, 52.40, 71.00, 70.16, 68.11, 68.56, 69.18, 36.96,
+    ],
+    // A7o
+    [
+        6.51, 25.28, 25.32, 26.32, 26.99, 29.90, 31.90, 47.44, 59.48, 58.02, 58.59, 58.24, 58.73,
+        26.23, 28.85, 54.46, 54.42, 54.22, 56.03, 57.05, 71.42, 60.15, 59.93, 60.19, 60.18, 60.97,
+        26.46, 57.55, 28.45, 52.66, 52.55, 54.18, 55.39, 70.54, 59.47, 59.66, 59.94, 60.59, 60.33,
+        27.45, 57.22, 55.75, 28.06, 51.99, 53.49, 54.58, 69.75, 59.61, 59.93, 59.54, 59.80, 60.15,
+        28.31, 57.51, 56.46, 54.86, 28.54, 52.39, 53.60, 67.77, 58.61, 59.99, 59.44, 60.06, 59.96,
+        30.94, 59.18, 57.48, 56.19, 55.62, 29.24, 52.95, 67.18, 58.06, 59.12, 60.40, 60.28, 60.85,
+        33.50, 60.64, 59.10, 58.16, 56.76, 56.58, 28.73, 65.52, 56.69, 58.45, 59.68, 60.42, 60.86,
+        50.00, 75.91, 75.12, 73.18, 72.05, 71.05, 69.22, 30.21, 65.98, 67.89, 69.00, 71.40, 72.54,
+        63.17, 63.85, 63.00, 63.73, 62.87, 62.07, 60.60, 70.32, 43.20, 58.96, 60.43, 61.83, 62.99,
+        61.75, 63.87, 62.97, 63.40, 63.92, 63.25, 61.70, 71.88, 62.33, 44.36, 59.38, 59.82, 62.11,
+        61.68, 64.30, 63.53, 63.36, 63.48, 64.50, 62.62, 73.79, 64.35, 62.54, 45.01, 60.52, 61.99,
+        61.71, 64.21, 63.52, 63.93, 64.19, 64.02, 64.10, 75.32, 65.67, 64.11, 64.41, 46.34, 62.43,
+        62.87, 64.52, 64.43, 64.13, 64.26, 64.64, 64.61, 77.17, 67.16, 65.68, 65.90, 66.06, 47.38,
+    ],
+    // K7o
+    [
+        12.27, 23.39, 33.87, 33.88, 33.38, 34.41, 34.33, 23.40, 37.98, 37.29, 37.95, 38.34, 38.22,
+        24.37, 6.63, 25.08, 25.99, 27.01, 29.10, 31.78, 47.44, 60.04, 59.76, 59.69, 59.90, 60.10,
+        35.62, 26.33, 27.80, 52.45, 52.67, 54.49, 55.35, 70.83, 59.82, 59.90, 60.14, 59.94, 60.78,
+        35.49, 27.04, 56.22, 27.89, 51.79, 53.33, 54.81, 69.12, 60.22, 59.80, 59.61, 59.81, 60.04,
+        35.42, 28.16, 56.17, 54.79, 28.02, 52.58, 54.03, 67.78, 58.95, 59.94, 59.73, 59.81, 60.70,
+        36.40, 30.15, 57.71, 56.85, 55.57, 28.28, 53.25, 66.14, 57.68, 59.21, 59.77, 59.97, 60.34,
+        36.18, 33.34, 59.13, 58.40, 56.96, 56.90, 29.34, 65.77, 57.08, 58.42, 59.40, 60.26, 60.57,
+        24.09, 50.00, 74.80, 73.47, 72.02, 70.16, 68.99, 30.77, 66.05, 66.64, 68.50, 70.22, 71.77,
+        39.56, 63.17, 63.77, 64.03, 62.82, 61.76, 61.06, 69.78, 44.02, 58.91, 60.47, 61.52, 63.06,
+        39.76, 62.81, 63.46, 63.21, 63.70, 62.48, 61.86, 71.04, 62.35, 44.46, 58.77, 60.01, 61.60,
+        40.09, 63.59, 63.42, 63.43, 63.52, 63.62, 63.45, 72.62, 63.67, 62.51, 45.74, 60.41, 61.72,
+        40.35, 63.23, 63.82, 63.50, 63.48, 63.74, 64.31, 74.63, 65.49, 64.09, 64.18, 46.95, 62.15,
+        40.84, 63.80, 64.18, 64.24, 63.69, 64.31, 64.77, 76.12, 66.78, 65.52, 65.63, 65.93, 47.33,
+    ],
+    // Q7o
+    [
+        12.69, 30.88, 22.94, 33.66, 33.47, 34.55, 33.90, 23.49, 37.51, 37.56, 37.95, 38.10, 38.65,
+        32.00, 12.01, 23.32, 32.31, 32.09, 33.46, 34.28, 23.72, 37.66, 37.52, 37.98, 38.17, 38.93,
+        23.92, 24.32, 6.73, 25.54, 26.45, 28.66, 31.39, 47.45, 59.56, 59.27, 59.60, 59.60, 60.27,
+        35.40, 34.22, 26.31, 27.80, 51.77, 53.65, 54.62, 68.88, 59.87, 59.86, 59.53, 59.58, 60.24,
+        35.37, 33.94, 27.82, 55.30, 27.25, 52.52, 53.49, 67.08, 58.91, 59.43, 59.59, 59.75, 60.23,
+        36.11, 35.31, 29.79, 56.74, 55.73, 28.20, 53.00, 65.91, 58.05, 58.87, 59.84, 59.90, 60.38,
+        35.95, 35.98, 32.91, 58.31, 56.97, 56.46, 28.36, 64.69, 56.82, 57.57, 58.96, 60.43, 60.42,
+        24.88, 25.20, 50.00, 72.98, 71.42, 70.37, 68.70, 30.24, 65.31, 66.74, 67.61, 69.59, 71.58,
+        39.53, 39.87, 63.28, 63.79, 62.49, 61.61, 60.39, 69.19, 43.92, 58.55, 60.20, 61.70, 62.91,
+        39.84, 39.81, 62.63, 63.23, 63.94, 62.08, 61.25, 70.70, 62.68, 44.43, 58.79, 59.90, 61.26,
+        40.18, 40.29, 63.26, 63.28, 63.50, 63.89, 62.78, 72.41, 63.76, 62.07, 46.19, 60.34, 61.68,
+        40.36, 40.87, 63.21, 63.80, 63.51, 63.88, 64.14, 74.02, 65.50, 63.85, 64.07, 46.58, 62.14,
+        40.98, 41.30, 63.69, 64.18, 63.62, 63.68, 64.30, 75.81, 66.78, 65.28, 65.17, 66.21, 47.86,
+    ],
+    // J7o
+    [
+        14.11, 31.66, 32.17, 24.47, 34.59, 35.58, 35.16, 25.17, 38.88, 38.88, 38.96, 39.81, 39.81,
+        33.36, 13.74, 31.11, 24.78, 33.70, 34.46, 35.69, 25.04, 39.22, 39.02, 39.44, 39.29, 39.93,
+        33.13, 32.68, 13.47, 23.53, 32.42, 33.26, 34.21, 25.69, 38.85, 38.91, 38.99, 39.46, 39.61,
+        25.70, 25.89, 24.40, 8.48, 26.63, 29.01, 31.59, 47.57, 60.29, 60.09, 60.26, 60.28, 60.27,
+        36.04, 35.10, 34.29, 27.90, 28.10, 53.11, 54.76, 68.34, 60.27, 60.85, 60.67, 60.83, 61.13,
+        37.52, 36.43, 35.28, 30.31, 56.58, 28.73, 53.82, 66.94, 58.84, 59.93, 61.18, 60.41, 60.86,
+        37.15, 37.30, 36.08, 32.97, 57.82, 57.15, 29.14, 65.74, 57.90, 58.91, 60.01, 61.28, 61.34,
+        26.82, 26.53, 27.02, 50.00, 72.08, 70.93, 69.23, 32.10, 66.52, 66.11, 67.75, 69.39, 70.96,
+        41.15, 41.23, 41.36, 64.14, 63.69, 62.52, 61.29, 70.58, 45.27, 59.23, 60.38, 62.07, 63.34,
+        41.13, 41.17, 41.07, 63.55, 64.27, 63.49, 62.12, 69.78, 62.85, 45.88, 58.70, 60.42, 61.82,
+        41.10, 41.38, 41.96, 63.69, 64.54, 64.76, 63.81, 72.29, 64.34, 62.75, 46.79, 60.51, 61.94,
+        42.11, 42.09, 41.81, 64.21, 65.02, 64.60, 65.34, 73.85, 65.48, 64.08, 64.17, 47.87, 62.61,
+        42.20, 42.51, 42.07, 64.19, 65.25, 65.36, 65.01, 75.46, 67.71, 65.52, 66.56, 66.26, 48.97,
+    ],
+    // T7o
+    [
+        15.72, 33.28, 33.04, 32.72, 25.82, 36.78, 36.15, 26.90, 40.21, 40.25, 40.72, 40.37, 41.07,
+        35.24, 15.79, 32.56, 31.76, 25.99, 35.63, 36.78, 26.90, 39.72, 40.50, 40.70, 40.85, 41.44,
+        34.93, 34.03, 15.47, 31.11, 24.77, 34.48, 35.54, 27.32, 39.50, 40.95, 40.49, 40.84, 41.13,
+        34.35, 33.52, 32.31, 13.70, 25.43, 33.87, 34.89, 26.20, 39.84, 40.68, 40.67, 40.86, 40.97,
+        26.87, 27.23, 25.84, 26.06, 9.77, 29.53, 31.82, 47.51, 60.39, 60.46, 60.39, 60.54, 61.12,
+        38.47, 38.20, 36.64, 35.70, 30.37, 29.31, 53.83, 67.46, 59.87, 60.63, 62.03, 61.57, 62.59,
+        38.19, 38.64, 37.38, 35.82, 32.81, 57.67, 29.53, 66.29, 58.77, 59.70, 61.24, 62.60, 62.04,
+        27.95, 27.98, 28.58, 27.92, 50.00, 71.89, 70.25, 32.99, 65.45, 67.07, 66.90, 68.87, 70.06,
+        41.88, 42.16, 42.09, 41.48, 63.67, 63.31, 62.00, 69.59, 45.74, 60.16, 61.12, 62.31, 63.93,
+        42.50, 42.73, 42.57, 42.67, 64.39, 64.19, 63.27, 71.31, 63.65, 47.39, 59.23, 60.86, 62.25,
+        42.69, 42.97, 43.15, 42.47, 64.19, 66.04, 64.50, 71.84, 64.87, 62.88, 47.95, 60.52, 62.03,
+        43.28, 43.09, 43.41, 43.15, 64.29, 65.74, 65.60, 72.88, 65.99, 64.45, 64.16, 49.07, 62.38,
+        43.10, 43.53, 43.07, 43.08, 64.55, 66.04, 65.89, 75.12, 67.93, 66.07, 66.10, 66.14, 50.25,
+    ],
+    // 97o
+    [
+        16.92, 34.51, 34.02, 34.61, 34.25, 26.96, 37.19, 27.77, 40.73, 40.47, 41.35, 41.25, 41.72,
+        36.01, 17.12, 33.56, 33.52, 33.56, 27.37, 37.75, 28.09, 41.13, 41.00, 41.92, 42.10, 42.37,
+        36.15, 35.56, 16.65, 32.92, 32.36, 26.45, 36.34, 28.51, 40.45, 41.15, 41.87, 41.60, 42.23,
+        36.32, 35.31, 34.38, 15.76, 31.68, 26.61, 35.62, 28.03, 40.68, 41.38, 41.94, 41.78, 42.52,
+        35.44, 35.38, 34.23, 33.37, 13.84, 26.55, 35.13, 27.36, 39.86, 41.71, 42.04, 42.08, 42.49,
+        28.22, 28.55, 27.52, 27.70, 27.10, 11.61, 31.67, 47.54, 60.08, 60.33, 60.88, 60.66, 61.30,
+        39.20, 39.28, 38.18, 37.40, 36.68, 33.26, 29.47, 66.39, 59.74, 59.91, 61.54, 62.81, 62.47,
+        28.95, 29.84, 29.63, 29.07, 28.11, 50.00, 70.36, 34.69, 65.27, 65.52, 67.65, 67.71, 69.58,
+        42.64, 42.64, 42.80, 43.09, 42.64, 63.83, 63.13, 69.76, 46.31, 60.89, 61.47, 62.93, 64.23,
+        42.63, 43.08, 43.30, 43.58, 43.59, 64.03, 63.87, 70.23, 64.28, 47.30, 60.17, 60.95, 62.79,
+        43.79, 44.31, 44.19, 44.27, 44.82, 64.41, 65.30, 72.10, 65.45, 63.59, 48.65, 60.81, 62.59,
+        43.35, 44.24, 44.13, 44.34, 44.26, 64.62, 66.47, 72.00, 66.72, 64.58, 65.17, 49.77, 62.52,
+        44.41, 44.83, 44.94, 44.59, 45.08, 65.15, 66.46, 73.62, 67.96, 66.17, 65.85, 66.56, 50.08,
+    ],
+    // 87o
+    [
+        18.59, 35.79, 35.52, 35.52, 35.38, 36.15, 28.93, 29.27, 41.51, 41.81, 41.78, 43.25, 42.88,
+        37.38, 17.85, 33.50, 34.86, 34.87, 35.25, 29.04, 29.14, 41.28, 41.94, 42.15, 43.28, 43.19,
+        37.29, 35.49, 18.01, 33.90, 34.18, 34.19, 27.44, 29.77, 41.27, 41.57, 42.22, 42.69, 42.89,
+        37.78, 36.51, 35.32, 17.23, 33.28, 33.70, 28.69, 29.24, 41.58, 41.91, 43.09, 43.45, 43.42,
+        36.95, 37.00, 35.62, 35.21, 15.60, 32.64, 28.42, 28.33, 41.12, 42.79, 42.45, 43.20, 43.68,
+        37.87, 36.89, 35.88, 35.31, 33.86, 14.33, 28.24, 28.60, 41.02, 42.32, 43.63, 44.19, 44.24,
+        29.83, 30.30, 28.75, 29.30, 29.18, 28.77, 13.16, 47.55, 60.05, 60.24, 60.88, 61.37, 61.51,
+        30.78, 31.01, 31.30, 30.77, 29.75, 29.64, 50.00, 35.76, 65.48, 65.80, 66.42, 67.89, 68.33,
+        43.89, 43.51, 43.27, 43.35, 43.21, 42.93, 63.49, 69.49, 46.32, 60.61, 61.40, 63.25, 64.44,
+        43.70, 44.08, 43.92, 44.00, 44.83, 44.49, 63.67, 69.49, 64.29, 47.77, 59.95, 61.13, 62.69,
+        44.44, 44.66, 44.34, 45.41, 45.30, 45.72, 64.24, 70.62, 65.91, 63.37, 49.18, 61.52, 62.45,
+        45.37, 45.73, 45.38, 45.50, 46.32, 46.70, 65.44, 72.29, 67.21, 65.16, 65.49, 50.84, 63.40,
+        45.22, 45.69, 45.77, 46.08, 45.72, 46.42, 65.15, 72.92, 68.38, 66.13, 66.01, 67.38, 51.16,
+    ],
+    // 77
+    [
+        19.37, 52.15, 51.76, 51.99, 51.88, 53.48, 53.61, 65.35, 67.36, 66.23, 65.83, 66.13, 66.46,
+        55.10, 19.42, 51.58, 51.08, 51.62, 53.07, 54.04, 65.59, 67.24, 66.90, 66.90, 67.22, 67.53,
+        54.84, 53.56, 19.01, 49.87, 49.47, 51.08, 52.79, 65.40, 66.66, 66.72, 66.75, 66.78, 67.11,
+        54.79, 54.36, 52.28, 19.29, 48.92, 50.88, 51.90, 64.64, 67.12, 66.79, 66.97, 66.85, 67.16,
+        54.65, 54.05, 52.00, 51.88, 18.75, 50.10, 51.55, 62.54, 66.80, 66.81, 66.35, 67.31, 67.87,
+        56.87, 55.75, 53.95, 53.07, 52.82, 18.59, 50.71, 61.79, 66.86, 66.73, 67.68, 67.56, 68.32,
+        56.59, 56.55, 54.73, 54.91, 53.99, 53.70, 17.97, 60.62, 66.76, 66.53, 67.16, 68.72, 68.87,
+        69.79, 69.23, 69.76, 67.90, 67.01, 65.31, 64.24, 50.00, 81.91, 83.11, 84.90, 86.60, 88.04,
+        71.12, 70.64, 70.64, 70.52, 70.11, 70.33, 70.06, 86.30, 81.69, 81.32, 81.71, 82.32, 83.30,
+        69.31, 70.34, 70.36, 70.01, 70.73, 70.51, 70.50, 87.99, 85.42, 81.19, 79.60, 80.68, 81.20,
+        69.46, 70.59, 70.28, 70.04, 70.88, 70.95, 70.79, 89.84, 86.04, 83.72, 81.46, 80.46, 81.61,
+        70.06, 70.85, 70.62, 70.79, 70.52, 71.07, 72.21, 91.78, 86.46, 84.39, 84.70, 81.39, 82.02,
+        70.67, 71.34, 71.30, 71.00, 71.33, 71.72, 72.79, 93.66, 87.33, 85.22, 85.40, 86.09, 82.02,
+    ],
+    // 76s
+    [
+        22.11, 39.03, 38.72, 37.95, 38.50, 38.71, 38.37, 32.60, 33.09, 43.97, 44.52, 45.18, 46.23,
+        41.42, 22.55, 37.97, 37.96, 38.09, 38.75, 38.03, 32.71, 33.46, 44.16, 44.89, 45.22, 45.91,
+        40.81, 40.90, 22.01, 36.53, 37.09, 37.61, 38.28, 33.52, 33.69, 44.02, 44.58, 44.97, 45.76,
+        40.50, 40.44, 38.62, 22.01, 36.45, 36.95, 37.42, 32.48, 34.36, 43.63, 44.52, 44.71, 45.96,
+        40.53, 40.52, 39.73, 38.73, 21.21, 36.51, 36.75, 32.89, 33.71, 44.48, 45.11, 45.53, 46.46,
+        41.37, 40.85, 40.26, 39.58, 38.46, 19.74, 35.69, 33.51, 33.71, 44.36, 45.53, 45.75, 47.22,
+        41.06, 41.23, 40.98, 39.36, 39.06, 38.87, 18.39, 32.86, 34.38, 44.23, 45.37, 46.85, 47.80,
+        34.02, 33.95, 34.69, 33.48, 34.55, 34.73, 34.52, 18.09, 50.00, 58.19, 59.18, 59.79, 60.18,
+        34.35, 35.12, 35.38, 35.85, 35.22, 34.89, 35.23, 52.46, 39.01, 64.60, 65.33, 65.54, 67.82,
+        46.78, 47.12, 46.61, 46.72, 46.95, 47.42, 47.13, 61.67, 68.11, 49.36, 61.93, 63.44, 63.93,
+        47.19, 47.60, 47.19, 47.41, 47.84, 48.43, 48.23, 62.17, 68.74, 64.54, 50.51, 62.87, 64.23,
+        47.86, 48.17, 48.09, 47.95, 47.88, 48.89, 49.75, 63.18, 69.50, 65.69, 65.62, 51.64, 63.93,
+        49.41, 48.88, 48.50, 48.50, 48.98, 49.70, 50.64, 63.49, 71.66, 67.14, 67.07, 66.77, 53.34,
+    ],
+    // 75s
+    [
+        21.11, 37.94, 37.69, 37.02, 37.38, 37.32, 37.06, 31.08, 40.00, 31.54, 42.31, 43.21, 43.14,
+        40.34, 21.14, 37.04, 37.26, 37.02, 37.60, 37.71, 31.22, 40.65, 32.54, 43.96, 44.17, 45.06,
+        39.88, 39.70, 20.76, 36.69, 35.80, 36.75, 37.12, 32.08, 39.83, 32.58, 43.13, 43.89, 44.80,
+        39.72, 39.81, 39.18, 21.15, 35.65, 36.60, 36.63, 32.61, 40.62, 33.04, 43.79, 43.95, 44.82,
+        39.17, 39.86, 38.90, 38.36, 20.58, 35.42, 35.77, 31.66, 39.35, 33.79, 43.12, 44.09, 44.86,
+        39.91, 39.72, 39.43, 38.57, 37.75, 19.95, 35.26, 32.95, 39.32, 33.76, 44.19, 44.24, 45.05,
+        40.00, 39.86, 39.84, 39.58, 38.26, 37.45, 18.28, 33.76, 39.25, 34.24, 43.84, 45.44, 45.76,
+        32.11, 33.36, 33.26, 33.89, 32.93, 34.48, 34.20, 16.89, 41.81, 50.00, 55.54, 56.24, 56.78,
+        42.78, 42.94, 42.57, 42.75, 41.96, 41.48, 41.37, 43.40, 34.20, 63.98, 60.12, 61.46, 62.70,
+        33.22, 33.52, 33.70, 34.28, 35.38, 35.36, 35.38, 52.53, 67.43, 38.70, 63.24, 63.78, 65.55,
+        45.46, 46.69, 45.97, 45.87, 46.25, 46.75, 46.90, 58.59, 63.13, 66.75, 49.10, 62.60, 63.70,
+        45.81, 47.16, 47.26, 47.05, 47.11, 47.30, 48.25, 59.59, 63.74, 67.63, 64.83, 50.30, 63.34,
+        46.65, 48.03, 47.22, 47.68, 48.07, 48.22, 49.10, 59.92, 65.28, 69.21, 66.39, 66.83, 52.29,
+    ],
+    // 74s
+    [
+        19.35, 36.05, 36.01, 35.89, 35.76, 36.32, 35.78, 29.31, 38.66, 37.94, 30.50, 41.97, 42.59,
+        39.00, 19.53, 36.01, 36.21, 35.74, 35.75, 35.97, 29.96, 38.87, 38.71, 31.14, 42.86, 43.72,
+        38.64, 38.73, 19.39, 35.56, 35.47, 35.60, 36.10, 30.81, 39.08, 38.60, 31.73, 42.89, 43.68,
+        38.78, 38.62, 38.08, 19.04, 34.73, 35.24, 35.51, 31.01, 39.35, 39.09, 31.88, 42.77, 43.55,
+        38.44, 38.18, 37.64, 37.07, 19.23, 34.30, 34.56, 31.72, 38.14, 39.55, 32.65, 42.91, 43.66,
+        38.67, 38.54, 37.93, 37.78, 36.76, 18.91, 33.95, 31.15, 37.76, 39.14, 33.92, 43.13, 43.95,
+        38.49, 38.48, 38.37, 38.13, 37.21, 36.11, 17.88, 32.65, 37.50, 38.36, 34.45, 44.34, 44.99,
+        31.00, 31.50, 32.39, 32.25, 33.10, 32.35, 33.58, 15.10, 40.82, 44.46, 50.00, 53.43, 54.29,
+        41.75, 41.75, 41.54, 42.04, 41.29, 40.40, 40.49, 42.81, 32.67, 54.45, 63.88, 60.18, 61.46,
+        40.53, 41.60, 41.47, 41.61, 42.12, 41.41, 40.81, 46.65, 56.95, 33.73, 62.34, 59.11, 60.15,
+        31.57, 32.62, 32.35, 33.38, 33.77, 34.75, 35.53, 52.55, 67.35, 65.79, 37.81, 63.61, 65.35,
+        44.05, 45.79, 45.50, 45.49, 45.87, 46.33, 47.10, 56.30, 62.90, 61.26, 67.40, 49.59, 63.01,
+        45.56, 46.37, 46.24, 46.90, 46.74, 46.81, 47.50, 57.16, 64.59, 63.21, 69.08, 66.12, 50.67,
+    ],
+    // 73s
+    [
+        17.80, 35.40, 34.76, 35.17, 34.75, 34.60, 34.70, 28.02, 37.66, 37.07, 36.97, 28.87, 41.36,
+        37.70, 17.87, 35.05, 34.76, 34.84, 35.04, 35.06, 28.83, 37.95, 37.79, 38.10, 30.02, 42.71,
+        37.18, 37.59, 17.74, 33.99, 34.00, 34.76, 35.02, 29.00, 38.02, 37.72, 37.88, 30.09, 42.37,
+        37.57, 37.20, 36.38, 17.55, 33.66, 33.93, 33.97, 29.68, 38.39, 37.33, 38.38, 30.84, 42.26,
+        37.06, 37.17, 36.66, 35.81, 17.58, 33.40, 33.41, 29.97, 37.48, 38.41, 38.35, 31.38, 42.38,
+        37.41, 37.17, 36.63, 36.32, 35.56, 17.20, 33.02, 30.86, 36.47, 37.66, 38.61, 32.69, 43.08,
+        37.17, 37.51, 36.93, 36.10, 35.73, 35.34, 17.28, 30.84, 36.57, 37.30, 38.28, 33.75, 43.29,
+        28.60, 29.78, 30.41, 30.61, 31.13, 32.29, 32.11, 13.40, 40.21, 43.76, 46.57, 50.00, 53.51,
+        40.26, 40.65, 40.39, 40.30, 39.86, 39.49, 39.06, 42.39, 32.75, 53.28, 54.57, 63.40, 60.06,
+        38.97, 40.13, 40.31, 40.08, 40.94, 40.13, 40.29, 45.71, 55.25, 32.62, 53.07, 62.31, 58.56,
+        40.01, 40.69, 40.57, 40.32, 41.17, 41.19, 41.12, 48.99, 57.16, 55.60, 33.63, 62.71, 58.99,
+        30.71, 31.10, 31.03, 32.14, 32.48, 33.64, 35.20, 52.42, 67.81, 66.18, 66.44, 37.44, 65.04,
+        44.08, 45.33, 45.09, 45.08, 45.11, 45.64, 46.53, 56.44, 62.64, 61.57, 61.78, 68.89, 49.87,
+    ],
+    // 72s
+    [
+        16.27, 33.91, 33.66, 33.48, 33.51, 33.89, 32.86, 26.51, 36.92, 35.42, 36.14, 36.47, 28.24,
+        36.42, 16.07, 33.60, 34.06, 33.43, 33.92, 33.82, 27.17, 36.39, 36.91, 37.08, 37.38, 28.91,
+        36.03, 35.78, 16.09, 32.56, 32.81, 33.34, 33.23, 27.95, 36.57, 36.76, 36.58, 37.16, 28.74,
+        35.85, 35.75, 35.22, 16.44, 32.12, 32.87, 32.78, 27.84, 37.12, 36.64, 36.99, 37.14, 29.81,
+        35.55, 35.32, 35.49, 34.47, 15.59, 31.64, 32.16, 28.18, 35.97, 36.81, 37.22, 37.34, 30.52,
+        36.27, 36.03, 35.74, 34.75, 34.33, 15.50, 31.38, 29.59, 35.60, 36.87, 37.38, 37.54, 31.24,
+        35.86, 35.97, 35.50, 35.19, 34.31, 33.89, 15.47, 30.61, 35.31, 36.20, 37.38, 38.46, 32.69,
+        27.46, 28.23, 28.42, 29.04, 29.94, 30.42, 31.67, 11.96, 39.82, 43.22, 45.71, 46.49, 50.00,
+        39.26, 39.39, 38.74, 39.82, 38.95, 38.20, 37.50, 41.49, 31.34, 51.94, 53.04, 54.69, 64.09,
+        38.03, 39.43, 38.83, 39.40, 39.34, 38.59, 38.71, 45.18, 53.65, 32.22, 51.95, 53.32, 62.24,
+        38.51, 39.77, 39.17, 39.20, 39.27, 40.01, 39.97, 48.33, 55.48, 54.82, 33.15, 53.57, 62.63,
+        38.99, 39.95, 39.85, 40.09, 39.88, 40.13, 41.24, 48.98, 57.33, 55.83, 56.37, 34.22, 63.34,
+        29.18, 30.25, 30.13, 30.82, 31.09, 31.95, 33.74, 52.52, 67.77, 66.49, 66.27, 66.84, 36.21,
+    ],
+    // A6o
+    [
+        6.52, 25.26, 25.13, 25.90, 27.40, 29.64, 31.96, 34.56, 47.46, 54.70, 54.60, 54.96, 55.51,
+        25.84, 28.02, 54.48, 53.86, 53.75, 55.42, 56.81, 56.93, 71.30, 60.01, 60.79, 60.19, 60.74,
+        26.55, 57.59, 28.48, 52.66, 52.45, 53.98, 55.65, 56.81, 70.85, 59.84, 60.05, 60.04, 59.76,
+        27.24, 57.06, 55.99, 28.00, 50.80, 52.77, 53.80, 54.99, 70.57, 59.66, 59.67, 59.49, 59.54,
+        28.72, 57.32, 55.19, 54.14, 28.39, 52.37, 53.10, 54.47, 69.28, 59.68, 59.36, 59.44, 59.45,
+        31.10, 58.91, 57.68, 55.96, 55.16, 28.58, 52.80, 54.24, 68.05, 58.85, 60.21, 60.04, 60.06,
+        33.59, 59.88, 58.49, 57.30, 56.37, 56.07, 28.92, 52.88, 66.79, 58.12, 59.10, 60.33, 60.33,
+        36.83, 60.44, 60.47, 58.85, 58.12, 57.36, 56.11, 28.88, 65.65, 57.22, 58.25, 59.74, 60.74,
+        50.00, 75.26, 75.24, 74.90, 73.08, 71.95, 70.66, 69.24, 30.40, 66.75, 67.92, 69.72, 71.40,
+        57.76, 63.89, 63.73, 63.45, 63.48, 62.60, 61.75, 60.35, 70.09, 43.61, 58.60, 60.40, 61.74,
+        57.87, 64.16, 63.79, 63.35, 63.27, 64.24, 62.76, 61.73, 71.75, 62.46, 44.51, 60.22, 61.95,
+        58.04, 64.47, 63.72, 63.49, 63.41, 63.44, 64.48, 63.50, 73.57, 64.11, 64.27, 45.30, 62.15,
+        58.33, 64.24, 64.07, 63.68, 63.75, 63.68, 63.92, 64.60, 75.41, 65.42, 65.97, 65.65, 46.76,
+    ],
+    // K6o
+    [
+        12.27, 23.65, 34.06, 33.67, 33.94, 35.09, 34.66, 34.71, 23.14, 37.43, 37.93, 38.36, 38.56,
+        24.17, 6.53, 25.17, 26.04, 26.82, 29.37, 32.31, 35.36, 47.52, 55.92, 56.36, 56.41, 56.58,
+        36.03, 26.37, 28.24, 52.62, 52.74, 54.33, 55.73, 56.83, 70.70, 59.91, 60.24, 60.67, 60.42,
+        35.61, 27.20, 56.19, 28.03, 50.96, 52.69, 54.59, 55.53, 70.38, 59.47, 59.67, 59.93, 60.24,
+        35.13, 28.54, 56.12, 54.17, 27.96, 52.04, 53.33, 54.91, 69.00, 59.65, 59.88, 59.90, 59.61,
+        36.87, 30.37, 57.60, 55.76, 55.36, 28.57, 52.94, 53.84, 67.63, 59.04, 60.02, 60.16, 59.97,
+        36.48, 33.84, 59.31, 57.36, 56.84, 55.87, 29.14, 53.24, 66.22, 58.49, 59.45, 60.73, 60.57,
+        36.15, 36.83, 60.13, 58.77, 57.84, 57.36, 56.49, 29.36, 64.88, 57.06, 58.25, 59.35, 60.61,
+        24.74, 50.00, 75.40, 74.44, 73.04, 71.44, 70.33, 68.73, 30.88, 66.27, 67.30, 68.83, 70.74,
+        39.57, 59.04, 63.37, 63.23, 63.85, 62.53, 61.38, 60.15, 69.67, 44.19, 58.54, 60.30, 61.11,
+        39.92, 59.32, 64.00, 63.47, 63.24, 63.91, 62.98, 61.98, 71.30, 62.22, 44.81, 60.86, 61.86,
+        39.93, 59.62, 63.92, 63.61, 63.60, 63.44, 64.30, 63.39, 73.04, 63.71, 64.02, 46.38, 62.33,
+        40.83, 59.80, 63.97, 63.61, 63.63, 63.68, 64.16, 64.84, 74.89, 65.79, 65.57, 65.99, 47.92,
+    ],
+    // Q6o
+    [
+        12.32, 31.01, 23.46, 33.73, 34.07, 34.89, 34.58, 34.92, 24.00, 37.85, 37.87, 38.65, 39.00,
+        32.71, 12.40, 24.52, 32.57, 32.40, 33.58, 35.16, 34.61, 24.15, 38.15, 37.81, 38.52, 39.16,
+        24.34, 24.51, 6.96, 25.46, 27.04, 28.93, 31.56, 35.27, 47.60, 56.21, 56.26, 56.28, 56.36,
+        35.03, 34.50, 26.67, 27.61, 51.25, 53.01, 54.28, 55.54, 70.06, 59.52, 59.74, 59.93, 59.86,
+        35.47, 34.55, 28.28, 54.24, 27.64, 52.31, 53.19, 54.75, 68.56, 60.43, 60.02, 60.00, 60.64,
+        36.81, 35.87, 30.34, 56.32, 55.21, 28.33, 52.38, 53.88, 67.36, 59.31, 60.22, 59.65, 59.90,
+        36.50, 36.70, 32.77, 57.82, 56.69, 55.17, 29.03, 52.98, 65.90, 57.80, 58.82, 60.74, 60.34,
+        37.00, 36.23, 36.72, 58.64, 57.91, 57.20, 56.73, 29.36, 64.62, 57.43, 58.46, 59.61, 61.26,
+        24.76, 24.60, 50.00, 74.49, 73.01, 71.31, 70.03, 68.66, 31.47, 65.13, 66.88, 68.55, 70.17,
+        40.02, 39.95, 59.29, 63.52, 64.03, 62.89, 61.62, 60.85, 69.01, 44.57, 59.03, 59.80, 61.67,
+        40.06, 40.76, 59.44, 63.77, 63.84, 64.37, 62.86, 62.35, 70.74, 62.83, 45.34, 60.63, 61.86,
+        40.70, 40.74, 59.91, 63.41, 63.78, 63.72, 64.16, 63.26, 72.80, 64.00, 64.10, 46.87, 62.05,
+        41.17, 41.12, 59.80, 63.98, 63.93, 64.04, 63.81, 64.82, 74.63, 65.24, 65.84, 65.63, 47.51,
+    ],
+    // J6o
+    [
+        12.71, 31.36, 30.73, 23.31, 33.77, 34.74, 34.30, 34.46, 24.16, 37.70, 38.21, 38.53, 38.95,
+        32.99, 12.47, 29.74, 23.38, 32.34, 33.82, 34.38, 34.41, 24.05, 37.88, 38.39, 38.56, 39.16,
+        32.05, 31.23, 11.95, 23.78, 31.77, 31.96, 33.51, 34.34, 24.51, 37.56, 37.85, 37.97, 39.04,
+        24.44, 24.58, 24.69, 6.87, 26.84, 28.15, 30.95, 34.59, 47.53, 55.77, 55.83, 55.90, 56.27,
+        35.45, 34.18, 33.09, 27.43, 27.55, 52.21, 53.10, 54.64, 68.24, 60.16, 59.90, 60.01, 60.08,
+        36.19, 35.65, 34.49, 30.07, 55.06, 28.06, 52.23, 53.33, 66.88, 58.71, 60.66, 59.88, 59.89,
+        36.05, 36.49, 35.59, 32.60, 56.91, 55.39, 28.94, 52.95, 65.77, 57.97, 59.54, 60.22, 59.88,
+        36.27, 35.97, 36.21, 35.86, 58.52, 56.91, 56.65, 29.48, 64.15, 57.25, 57.96, 59.70, 60.18,
+        25.10, 25.56, 25.51, 50.00, 72.02, 70.78, 69.44, 68.22, 31.25, 64.87, 66.66, 67.85, 70.01,
+        39.84, 40.09, 39.63, 59.38, 63.56, 62.32, 61.41, 60.31, 68.49, 43.96, 58.81, 60.00, 61.73,
+        40.29, 40.47, 40.01, 59.52, 63.47, 63.53, 62.64, 61.88, 70.67, 62.32, 45.49, 60.37, 61.80,
+        40.49, 40.74, 40.90, 59.44, 63.38, 63.54, 63.76, 62.78, 72.36, 64.10, 63.93, 46.77, 61.55,
+        41.27, 41.07, 40.93, 59.74, 63.59, 63.25, 63.66, 64.76, 74.10, 65.20, 65.64, 65.72, 47.30,
+    ],
+    // T6o
+    [
+        14.55, 32.45, 32.25, 31.58, 24.69, 35.95, 35.90, 35.59, 25.60, 39.58, 39.19, 39.72, 39.83,
+        33.78, 14.34, 31.66, 30.70, 25.03, 34.57, 35.50, 35.44, 26.03, 39.61, 39.53, 40.10, 40.05,
+        33.37, 32.62, 13.65, 29.91, 25.06, 33.55, 34.78, 35.49, 26.13, 39.12, 39.26, 39.96, 40.04,
+        33.56, 32.46, 31.65, 13.59, 23.82, 32.84, 34.10, 34.55, 26.12, 39.15, 39.23, 39.33, 39.70,
+        25.54, 25.78, 26.25, 24.47, 8.47, 29.13, 31.40, 34.66, 47.54, 56.41, 56.45, 56.61, 57.16,
+        37.70, 36.59, 35.49, 34.71, 30.54, 28.97, 53.02, 54.39, 67.68, 59.77, 60.40, 60.68, 61.41,
+        37.43, 37.40, 36.43, 35.74, 32.65, 56.14, 28.82, 53.63, 65.99, 58.76, 60.01, 61.19, 60.97,
+        37.13, 37.18, 37.51, 36.31, 36.33, 57.36, 56.79, 29.89, 64.78, 58.04, 58.71, 60.14, 61.05,
+        26.92, 26.96, 26.99, 27.98, 50.00, 71.56, 69.97, 68.84, 32.63, 65.74, 65.73, 67.48, 69.01,
+        41.91, 41.91, 41.32, 41.46, 60.09, 63.67, 62.02, 61.42, 69.61, 46.38, 58.72, 60.59, 61.88,
+        41.78, 41.74, 41.78, 41.13, 59.82, 64.89, 63.52, 62.55, 69.58, 62.85, 46.41, 60.43, 61.99,
+        41.91, 41.82, 41.86, 41.57, 60.11, 64.87, 65.40, 63.77, 71.67, 64.14, 63.87, 47.84, 62.27,
+        42.64, 42.42, 42.10, 42.19, 60.24, 64.83, 64.37, 65.38, 73.51, 65.78, 65.84, 66.11, 48.62,
+    ],
+    // 96o
+    [
+        15.95, 33.96, 33.42, 33.54, 33.42, 25.84, 36.43, 36.08, 26.51, 40.04, 40.47, 40.56, 40.68,
+        35.31, 15.79, 33.45, 32.63, 32.91, 26.29, 36.65, 36.29, 26.98, 39.97, 41.31, 40.94, 41.07,
+        35.37, 34.64, 15.47, 32.19, 31.69, 27.18, 35.69, 36.31, 27.33, 39.73, 40.45, 40.48, 40.94,
+        34.98, 34.08, 33.53, 15.14, 30.80, 25.59, 34.85, 35.60, 27.43, 39.87, 40.63, 40.52, 40.78,
+        34.81, 34.01, 33.36, 31.97, 13.95, 26.19, 34.00, 35.03, 27.53, 40.29, 40.87, 40.54, 41.28,
+        27.11, 27.51, 27.49, 26.15, 27.24, 10.00, 31.57, 35.26, 47.40, 56.27, 57.55, 57.31, 57.54,
+        38.01, 38.96, 37.66, 36.44, 35.40, 32.99, 29.11, 53.88, 66.40, 59.16, 60.97, 61.95, 61.41,
+        37.93, 38.24, 38.39, 37.48, 36.69, 36.17, 57.07, 29.67, 65.11, 58.52, 59.60, 60.51, 61.80,
+        28.05, 28.56, 28.69, 29.22, 28.44, 50.00, 70.53, 68.57, 33.85, 64.11, 65.97, 66.42, 67.96,
+        41.93, 42.13, 42.36, 41.87, 42.25, 59.72, 63.15, 62.07, 68.50, 46.12, 59.96, 60.64, 61.78,
+        42.69, 43.47, 42.94, 42.41, 42.81, 60.61, 64.25, 62.98, 70.23, 63.04, 47.56, 60.56, 62.23,
+        42.71, 43.33, 43.35, 43.12, 42.65, 60.82, 65.19, 64.42, 70.88, 63.98, 64.01, 48.41, 61.94,
+        43.63, 43.52, 43.44, 43.43, 43.27, 60.90, 65.55, 65.94, 72.24, 65.37, 65.44, 65.30, 48.95,
+    ],
+    // 86o
+    [
+        17.32, 34.77, 35.02, 34.40, 35.05, 34.87, 27.42, 37.33, 28.15, 40.68, 41.01, 42.00, 42.17,
+        36.59, 17.11, 34.33, 33.32, 33.99, 34.01, 27.66, 37.39, 28.30, 40.53, 41.12, 42.21, 41.94,
+        36.73, 35.58, 16.84, 32.81, 33.50, 33.78, 27.74, 37.88, 28.68, 40.92, 41.10, 42.03, 42.71,
+        36.26, 35.31, 34.73, 16.67, 32.15, 32.75, 26.79, 36.53, 29.42, 40.87, 40.90, 41.93, 42.18,
+        36.61, 35.52, 34.93, 33.63, 15.54, 31.60, 27.81, 36.04, 28.33, 41.12, 41.57, 42.46, 42.14,
+        36.55, 36.29, 35.24, 33.78, 33.27, 14.25, 27.53, 35.35, 28.44, 40.76, 41.88, 42.51, 42.70,
+        28.67, 28.91, 29.26, 27.77, 28.69, 28.74, 11.92, 34.96, 47.32, 56.69, 57.32, 57.87, 57.66,
+        39.40, 38.94, 39.61, 38.71, 38.00, 36.87, 36.51, 29.94, 64.77, 58.63, 59.51, 60.94, 62.50,
+        29.34, 29.67, 29.97, 30.56, 30.03, 29.47, 50.00, 68.24, 34.47, 64.05, 64.74, 66.16, 66.41,
+        43.11, 42.56, 43.14, 42.44, 43.08, 42.50, 59.63, 62.09, 67.91, 46.47, 59.50, 60.94, 62.24,
+        43.49, 43.27, 43.72, 43.14, 43.49, 44.43, 60.51, 63.26, 68.36, 63.16, 48.00, 60.77, 61.80,
+        44.33, 44.23, 44.06, 44.58, 44.85, 45.15, 60.95, 64.72, 70.75, 64.46, 64.66, 49.58, 61.66,
+        44.11, 44.53, 44.28, 44.31, 44.28, 45.17, 61.03, 65.88, 70.76, 65.99, 65.83, 65.72, 49.79,
+    ],
+    // 76o
+    [
+        18.54, 35.96, 36.22, 35.48, 35.30, 35.93, 35.74, 28.52, 29.35, 41.01, 42.25, 42.37, 43.69,
+        37.98, 18.69, 35.59, 35.02, 35.17, 35.40, 36.14, 28.91, 29.99, 41.58, 42.28, 42.82, 42.89,
+        37.72, 36.77, 18.30, 33.59, 34.57, 35.04, 35.12, 29.18, 29.81, 41.36, 41.40, 42.09, 43.11,
+        37.00, 36.84, 35.22, 17.78, 33.21, 34.22, 34.37, 28.35, 30.21, 41.14, 41.50, 41.98, 42.98,
+        37.37, 37.01, 36.01, 35.13, 16.92, 33.60, 33.62, 29.25, 29.89, 42.16, 41.92, 42.59, 43.42,
+        37.65, 37.42, 37.06, 35.45, 35.28, 15.87, 33.14, 29.51, 29.80, 41.65, 42.64, 43.20, 44.30,
+        37.31, 37.59, 36.91, 36.12, 35.67, 34.65, 14.49, 29.45, 30.20, 41.50, 43.04, 44.54, 44.85,
+        29.68, 30.22, 30.81, 29.42, 30.41, 30.24, 30.51, 13.70, 47.54, 56.60, 57.19, 57.61, 58.51,
+        30.76, 31.27, 31.34, 31.78, 31.16, 31.43, 31.76, 50.00, 35.76, 63.28, 63.80, 64.31, 66.51,
+        43.75, 43.63, 43.23, 42.92, 44.11, 43.48, 43.59, 59.62, 66.88, 46.88, 59.42, 61.04, 61.87,
+        44.15, 44.70, 43.96, 43.86, 44.67, 45.61, 45.10, 60.77, 67.46, 63.19, 48.15, 60.39, 61.50,
+        45.10, 45.02, 44.83, 44.71, 45.43, 45.59, 46.76, 60.87, 68.61, 64.39, 64.24, 49.65, 62.46,
+        46.09, 45.99, 45.79, 45.70, 46.21, 46.73, 47.56, 61.83, 70.29, 65.76, 64.98, 65.67, 51.01,
+    ],
+    // 66
+    [
+        19.57, 52.13, 51.64, 51.51, 51.27, 53.18, 53.72, 54.04, 65.74, 65.50, 65.61, 66.19, 66.66,
+        54.83, 19.41, 51.23, 50.59, 50.59, 52.08, 53.40, 53.90, 65.36, 66.33, 67.13, 66.94, 67.54,
+        54.35, 53.62, 19.36, 49.12, 49.63, 51.39, 52.89, 53.10, 64.94, 66.21, 66.62, 66.74, 67.07,
+        53.57, 53.31, 51.47, 19.06, 47.65, 49.64, 50.30, 51.58, 65.04, 65.90, 65.84, 66.21, 66.66,
+        54.47, 52.69, 51.98, 50.13, 19.02, 48.77, 50.26, 51.65, 63.23, 66.23, 66.18, 66.56, 66.98,
+        56.03, 54.89, 53.82, 52.06, 51.84, 18.77, 50.12, 51.53, 62.78, 66.30, 67.23, 67.18, 67.36,
+        56.36, 56.21, 55.52, 53.20, 53.30, 52.39, 18.38, 50.79, 61.96, 66.22, 66.85, 67.92, 67.87,
+        56.80, 55.98, 56.08, 54.73, 54.26, 53.69, 53.68, 18.31, 60.99, 65.80, 67.33, 67.25, 68.66,
+        69.60, 69.12, 68.53, 68.75, 67.37, 66.15, 65.53, 64.24, 50.00, 81.39, 83.34, 85.14, 86.20,
+        69.32, 69.81, 69.62, 69.55, 69.85, 70.14, 69.71, 69.63, 86.27, 80.98, 80.90, 81.59, 82.11,
+        69.41, 70.32, 70.17, 70.27, 69.86, 70.57, 70.94, 70.74, 88.08, 85.07, 81.50, 81.59, 82.11,
+        69.44, 70.54, 69.97, 69.93, 70.01, 70.69, 71.20, 71.57, 89.93, 85.38, 85.98, 81.55, 82.22,
+        70.00, 70.57, 70.31, 69.96, 70.36, 70.89, 71.47, 72.52, 91.82, 86.23, 86.24, 86.51, 81.69,
+    ],
+    // 65s
+    [
+        22.71, 38.78, 38.90, 38.20, 37.96, 38.56, 38.38, 38.45, 32.35, 33.22, 43.35, 44.18, 44.69,
+        41.51, 22.80, 38.44, 38.25, 37.63, 38.32, 38.84, 38.30, 32.81, 33.61, 44.09, 45.00, 45.63,
+        41.24, 41.00, 22.33, 37.75, 37.52, 38.11, 38.55, 38.88, 33.54, 34.19, 44.39, 45.03, 45.60,
+        41.19, 40.87, 40.24, 22.31, 35.83, 37.43, 38.33, 38.44, 33.68, 34.31, 43.90, 44.51, 45.22,
+        40.78, 40.41, 40.08, 37.67, 21.92, 36.60, 37.26, 37.57, 33.11, 34.56, 44.07, 44.46, 44.43,
+        41.06, 40.76, 40.51, 39.93, 39.20, 20.87, 36.77, 37.42, 34.26, 34.99, 44.98, 45.02, 46.14,
+        40.89, 41.60, 40.80, 40.59, 39.63, 39.53, 19.91, 36.82, 35.18, 35.53, 44.95, 46.42, 47.09,
+        41.04, 41.09, 41.45, 40.77, 39.84, 39.11, 39.39, 18.68, 35.40, 36.02, 45.55, 46.72, 48.06,
+        33.25, 33.73, 34.87, 35.13, 34.26, 35.89, 35.95, 36.72, 18.61, 50.00, 55.53, 56.27, 56.78,
+        34.39, 34.89, 35.36, 35.94, 36.60, 36.23, 37.18, 37.77, 52.59, 39.81, 61.77, 62.09, 62.99,
+        46.18, 47.34, 47.03, 46.78, 47.02, 47.50, 47.77, 48.39, 58.37, 65.10, 49.68, 61.26, 62.64,
+        46.24, 47.64, 47.82, 47.23, 47.24, 48.24, 49.13, 50.04, 59.41, 65.62, 64.29, 50.16, 62.24,
+        47.26, 48.11, 48.08, 48.13, 47.87, 48.72, 49.63, 51.43, 60.15, 66.36, 65.17, 64.87, 52.20,
+    ],
+    // 64s
+    [
+        21.06, 37.94, 37.56, 37.18, 36.91, 37.56, 37.06, 37.27, 31.35, 39.00, 31.71, 42.15, 43.14,
+        40.20, 21.17, 37.37, 36.80, 36.56, 36.88, 37.22, 36.98, 31.10, 40.02, 32.26, 43.70, 44.30,
+        39.93, 39.62, 20.92, 36.31, 36.85, 36.80, 36.96, 37.51, 31.92, 39.64, 32.53, 43.71, 43.84,
+        39.80, 39.54, 39.07, 20.78, 35.36, 36.38, 36.52, 36.63, 32.04, 39.60, 32.90, 43.77, 43.78,
+        39.75, 39.62, 39.31, 38.11, 20.71, 35.61, 36.35, 36.58, 32.75, 40.72, 34.03, 43.72, 44.45,
+        39.74, 39.38, 39.06, 38.29, 37.66, 20.34, 35.85, 36.13, 32.57, 39.87, 34.93, 44.45, 44.42,
+        39.35, 40.32, 39.69, 39.16, 38.58, 38.20, 19.44, 35.40, 34.19, 39.70, 35.37, 45.01, 45.01,
+        39.57, 39.53, 39.80, 39.62, 38.88, 38.53, 38.60, 18.29, 34.67, 39.88, 36.12, 45.43, 46.96,
+        32.08, 32.70, 33.12, 33.34, 34.27, 34.03, 35.26, 36.20, 16.66, 44.47, 50.00, 53.22, 54.18,
+        41.43, 42.58, 42.71, 42.10, 43.01, 41.87, 41.98, 42.51, 46.55, 33.54, 60.70, 57.43, 58.81,
+        33.30, 33.76, 34.23, 34.24, 35.48, 36.76, 37.09, 37.46, 52.48, 63.90, 39.07, 61.51, 62.33,
+        45.30, 46.50, 46.29, 46.01, 46.46, 46.87, 47.73, 48.52, 56.11, 60.24, 64.93, 49.60, 61.49,
+        46.05, 46.95, 46.98, 47.15, 47.04, 47.76, 48.15, 50.05, 57.12, 61.66, 66.02, 64.35, 50.98,
+    ],
+    // 63s
+    [
+        19.17, 36.38, 36.62, 35.79, 36.04, 36.27, 35.68, 35.62, 29.27, 37.64, 38.48, 30.45, 42.32,
+        38.92, 19.07, 35.97, 35.51, 35.32, 36.00, 36.12, 36.30, 29.94, 38.78, 38.97, 31.01, 42.90,
+        38.56, 38.50, 19.47, 35.09, 35.31, 35.54, 35.47, 36.13, 30.18, 38.39, 38.76, 31.60, 43.19,
+        38.39, 37.87, 37.59, 18.78, 34.81, 34.81, 34.97, 35.64, 30.63, 38.60, 38.99, 31.77, 43.08,
+        38.32, 38.24, 37.61, 36.71, 18.87, 34.73, 34.87, 35.46, 31.41, 39.28, 39.41, 32.65, 42.85,
+        38.60, 38.13, 38.06, 37.84, 36.86, 19.00, 34.76, 35.11, 32.30, 38.98, 39.75, 33.78, 43.04,
+        38.60, 38.60, 38.05, 37.87, 37.38, 36.97, 18.69, 34.11, 32.49, 38.32, 39.42, 35.33, 43.83,
+        38.17, 38.48, 38.30, 37.93, 37.69, 37.07, 36.75, 17.68, 34.46, 38.54, 39.82, 36.60, 45.31,
+        30.28, 31.17, 31.45, 32.15, 32.52, 33.58, 33.84, 35.69, 14.86, 43.73, 46.78, 50.00, 53.18,
+        40.07, 41.45, 41.25, 40.82, 41.61, 41.20, 40.99, 41.49, 45.56, 32.59, 51.95, 60.57, 57.81,
+        40.64, 41.91, 41.81, 41.31, 41.85, 42.61, 42.58, 42.49, 48.97, 54.39, 33.80, 60.86, 57.86,
+        31.33, 32.10, 32.68, 32.77, 34.01, 35.07, 36.37, 37.31, 52.50, 64.13, 64.01, 37.85, 61.99,
+        44.94, 45.88, 46.17, 45.79, 45.89, 46.35, 47.16, 47.96, 56.20, 60.19, 60.52, 65.79, 49.82,
+    ],
+    // 62s
+    [
+        17.57, 35.59, 34.94, 34.84, 34.44, 34.89, 34.68, 34.44, 27.72, 36.44, 37.38, 37.12, 29.77,
+        37.54, 17.50, 34.98, 34.63, 34.34, 34.91, 34.58, 34.41, 28.59, 37.42, 37.65, 38.43, 30.21,
+        37.78, 36.80, 17.66, 33.93, 34.16, 34.07, 34.49, 34.78, 28.89, 37.36, 38.08, 38.39, 30.14,
+        37.33, 36.85, 36.30, 16.92, 33.20, 33.92, 33.93, 34.62, 29.39, 36.66, 37.71, 37.90, 30.44,
+        37.15, 36.90, 36.38, 35.63, 17.27, 33.51, 33.56, 33.83, 29.57, 37.84, 38.01, 38.22, 31.66,
+        37.35, 37.10, 36.32, 36.52, 35.65, 16.92, 33.31, 33.15, 30.80, 37.08, 38.36, 38.80, 32.63,
+        36.77, 36.98, 36.83, 35.99, 35.97, 35.43, 17.27, 33.40, 32.51, 37.54, 38.31, 39.51, 33.87,
+        37.01, 36.94, 37.09, 36.66, 36.07, 35.77, 35.56, 16.70, 32.18, 37.30, 38.54, 39.94, 35.91,
+        28.60, 29.26, 29.83, 29.99, 30.99, 32.04, 33.59, 33.49, 13.80, 43.22, 45.82, 46.82, 50.00,
+        39.09, 39.89, 40.00, 40.10, 40.79, 40.17, 39.51, 39.98, 45.32, 32.13, 50.67, 52.21, 61.03,
+        40.01, 40.41, 40.70, 40.80, 40.48, 41.75, 40.81, 41.52, 48.04, 53.11, 32.81, 52.26, 61.03,
+        39.58, 40.71, 40.64, 40.93, 40.70, 41.45, 42.04, 43.00, 49.16, 54.59, 54.42, 34.16, 61.30,
+        30.76, 30.80, 31.69, 31.74, 32.59, 33.80, 35.30, 37.51, 52.63, 64.15, 64.59, 64.54, 36.93,
+    ],
+    // A5o
+    [
+        8.15, 26.22, 26.59, 27.48, 28.22, 31.03, 33.69, 36.11, 40.47, 47.42, 52.92, 53.14, 53.49,
+        27.17, 29.85, 54.80, 53.78, 53.76, 55.82, 57.15, 56.85, 56.93, 71.25, 61.79, 61.40, 61.57,
+        27.85, 58.18, 29.79, 53.53, 52.88, 54.23, 55.67, 56.60, 56.81, 71.19, 61.65, 61.61, 61.50,
+        29.09, 57.81, 56.21, 30.08, 51.74, 53.15, 54.43, 55.84, 57.05, 70.75, 60.84, 60.57, 61.15,
+        29.63, 57.33, 56.02, 54.90, 29.41, 52.18, 53.04, 54.03, 55.23, 70.60, 59.78, 60.78, 60.19,
+        32.84, 59.39, 58.03, 56.31, 54.86, 30.57, 52.91, 54.06, 55.03, 69.63, 61.42, 61.38, 61.40,
+        35.01, 60.55, 59.06, 57.68, 56.42, 55.87, 30.50, 53.15, 54.03, 68.36, 60.04, 61.27, 61.12,
+        38.25, 60.24, 60.16, 58.87, 57.50, 57.37, 56.30, 30.69, 53.22, 66.78, 59.47, 61.03, 61.97,
+        42.24, 60.43, 59.98, 60.16, 58.09, 58.07, 56.89, 56.25, 30.68, 65.61, 58.57, 59.93, 60.91,
+        50.00, 75.64, 75.01, 74.56, 74.75, 73.64, 71.94, 70.74, 69.02, 32.57, 68.00, 69.37, 71.49,
+        55.73, 65.43, 64.70, 64.74, 63.90, 65.52, 62.97, 62.82, 61.78, 72.18, 45.18, 62.16, 63.35,
+        56.24, 65.33, 64.83, 64.47, 64.21, 64.91, 65.19, 64.44, 63.48, 74.26, 65.77, 46.40, 63.61,
+        56.38, 65.22, 65.05, 64.68, 64.32, 65.00, 64.86, 65.49, 64.42, 75.55, 67.21, 67.90, 47.55,
+    ],
+    // K5o
+    [
+        12.03, 22.91, 33.81, 33.50, 33.29, 35.04, 34.40, 34.28, 34.17, 23.25, 36.70, 37.02, 37.38,
+        23.96, 6.76, 25.53, 26.05, 26.97, 29.46, 32.35, 34.90, 38.85, 47.52, 53.06, 53.00, 53.46,
+        35.54, 26.23, 28.16, 52.76, 52.53, 54.02, 55.59, 56.71, 56.62, 70.51, 60.25, 60.19, 60.77,
+        35.32, 27.48, 55.83, 28.06, 51.60, 52.61, 54.36, 55.25, 56.52, 70.38, 60.44, 60.26, 60.42,
+        34.74, 28.51, 55.25, 54.32, 27.95, 51.22, 52.34, 53.92, 55.06, 70.16, 59.69, 59.67, 60.26,
+        36.25, 31.18, 57.41, 56.25, 54.42, 28.76, 52.54, 53.01, 54.73, 68.38, 60.36, 59.84, 59.76,
+        36.33, 34.21, 58.76, 57.52, 55.87, 55.35, 29.34, 52.98, 54.16, 67.92, 59.23, 60.79, 60.26,
+        36.13, 37.19, 60.19, 58.83, 57.27, 56.92, 55.92, 29.66, 52.88, 66.48, 58.40, 59.87, 60.57,
+        36.11, 40.96, 60.05, 59.91, 58.09, 57.87, 57.44, 56.37, 30.19, 65.11, 57.42, 58.55, 60.11,
+        24.36, 50.00, 74.63, 74.34, 74.46, 72.79, 71.68, 70.10, 68.76, 31.04, 65.87, 67.57, 69.17,
+        38.70, 55.71, 63.88, 63.83, 63.46, 63.67, 63.32, 62.18, 60.99, 70.33, 44.76, 60.77, 61.98,
+        38.91, 56.13, 64.29, 64.20, 63.52, 63.56, 64.54, 62.69, 62.14, 71.69, 64.51, 45.96, 62.05,
+        39.37, 56.22, 64.42, 64.21, 63.79, 63.75, 64.08, 64.77, 63.65, 73.51, 66.24, 66.29, 47.24,
+    ],
+    // Q5o
+    [
+        12.72, 30.80, 22.99, 33.89, 33.48, 35.08, 34.65, 34.20, 34.35, 24.21, 37.17, 37.24, 37.64,
+        32.11, 12.57, 24.01, 32.74, 32.19, 33.90, 35.04, 34.47, 34.40, 24.08, 38.33, 38.22, 38.39,
+        24.01, 24.48, 6.80, 26.18, 26.93, 29.20, 31.96, 35.06, 38.51, 47.45, 52.95, 53.26, 53.30,
+        35.25, 34.63, 27.18, 28.25, 51.45, 52.86, 54.33, 55.43, 56.66, 69.99, 60.30, 60.29, 60.34,
+        35.15, 34.29, 28.02, 54.43, 27.98, 51.03, 52.87, 53.81, 55.25, 69.94, 60.03, 59.94, 60.45,
+        36.59, 35.50, 30.49, 56.23, 54.70, 28.49, 52.24, 53.25, 54.57, 68.36, 60.83, 60.32, 60.08,
+        36.53, 37.21, 33.58, 57.34, 56.06, 55.63, 29.29, 53.14, 53.57, 67.16, 59.00, 60.40, 60.48,
+        37.03, 36.54, 37.37, 58.93, 57.43, 56.70, 56.08, 29.64, 53.39, 66.30, 58.53, 59.69, 61.17,
+        36.27, 36.63, 40.71, 60.37, 58.68, 57.64, 56.86, 56.77, 30.38, 64.64, 57.29, 58.75, 60.00,
+        24.99, 25.37, 50.00, 74.05, 74.04, 72.71, 71.07, 70.00, 67.91, 31.31, 65.56, 67.10, 68.86,
+        38.92, 39.82, 55.63, 64.01, 63.58, 63.65, 63.05, 62.50, 61.23, 69.50, 45.10, 60.42, 61.99,
+        39.35, 40.56, 56.26, 64.63, 63.55, 63.84, 64.04, 63.51, 62.15, 71.16, 64.35, 46.41, 62.47,
+        39.78, 41.01, 56.36, 64.12, 63.53, 64.08, 64.10, 64.37, 63.44, 72.92, 66.18, 66.16, 47.03,
+    ],
+    // J5o
+    [
+        12.91, 31.40, 30.65, 23.42, 33.54, 35.03, 35.09, 34.52, 34.63, 24.11, 37.27, 37.59, 37.70,
+        32.91, 12.79, 29.96, 23.62, 32.80, 34.14, 35.39, 34.71, 34.81, 24.45, 38.35, 38.50, 39.31,
+        32.22, 31.92, 12.43, 23.99, 31.32, 33.27, 33.68, 34.67, 34.42, 24.84, 37.96, 38.21, 38.61,
+        24.04, 24.57, 24.97, 7.18, 26.45, 29.03, 31.37, 34.68, 39.10, 47.61, 52.94, 53.08, 53.47,
+        35.13, 34.11, 33.28, 27.60, 28.08, 51.65, 52.44, 54.00, 55.53, 69.60, 60.08, 59.86, 60.39,
+        36.93, 36.03, 34.84, 30.50, 54.54, 28.45, 52.09, 53.72, 54.67, 68.58, 60.76, 60.38, 60.50,
+        36.11, 36.29, 35.64, 33.19, 56.01, 54.95, 29.26, 52.73, 54.03, 66.77, 59.59, 60.48, 60.53,
+        36.60, 36.79, 36.77, 36.45, 57.33, 56.42, 56.00, 29.99, 53.28, 65.72, 58.39, 59.92, 60.60,
+        36.55, 36.77, 36.48, 40.62, 58.54, 58.13, 57.56, 57.08, 30.45, 64.06, 57.90, 59.18, 59.90,
+        25.44, 25.66, 25.95, 50.00, 73.88, 72.51, 70.69, 69.34, 68.29, 31.97, 64.39, 66.73, 68.19,
+        39.51, 40.19, 39.99, 56.13, 64.06, 64.50, 63.62, 62.24, 61.01, 68.66, 45.01, 60.50, 61.74,
+        39.84, 40.76, 40.29, 56.10, 63.97, 63.83, 64.31, 63.23, 62.43, 70.47, 64.24, 45.92, 62.59,
+        40.32, 40.87, 40.51, 56.01, 63.91, 64.54, 63.97, 64.55, 63.61, 72.13, 65.62, 65.77, 47.46,
+    ],
+    // T5o
+    [
+        13.03, 31.09, 31.00, 30.45, 23.02, 34.64, 34.65, 34.53, 34.94, 24.28, 37.63, 37.47, 38.06,
+        32.69, 12.98, 30.23, 30.28, 23.70, 33.81, 34.60, 34.64, 34.30, 24.54, 38.39, 38.43, 38.92,
+        32.31, 31.96, 12.46, 29.32, 23.57, 32.84, 34.20, 35.06, 34.37, 25.04, 38.40, 38.47, 38.67,
+        32.37, 31.60, 30.63, 12.24, 23.72, 32.13, 32.84, 33.81, 34.66, 25.08, 38.12, 38.23, 38.54,
+        23.96, 24.46, 24.80, 24.83, 7.02, 28.30, 31.19, 34.19, 38.10, 47.54, 52.61, 53.24, 53.10,
+        36.63, 35.93, 34.60, 33.52, 29.62, 28.25, 52.18, 53.23, 54.36, 67.37, 60.11, 59.98, 59.97,
+        36.48, 36.89, 35.49, 34.38, 32.91, 54.76, 29.15, 51.78, 53.62, 66.09, 59.16, 60.41, 60.16,
+        36.08, 36.30, 36.06, 35.73, 35.61, 56.41, 55.17, 29.27, 53.05, 64.62, 57.88, 59.06, 60.66,
+        36.52, 36.15, 35.97, 36.44, 39.91, 57.75, 56.92, 55.89, 30.15, 63.40, 56.99, 58.39, 59.21,
+        25.25, 25.54, 25.96, 26.12, 50.00, 71.57, 69.67, 68.40, 67.15, 31.82, 64.47, 65.47, 67.26,
+        39.23, 40.11, 39.77, 39.85, 55.90, 63.58, 62.67, 61.62, 60.77, 67.97, 44.83, 60.07, 61.62,
+        39.91, 40.98, 40.38, 40.78, 55.92, 63.91, 63.88, 63.08, 62.01, 69.80, 64.36, 46.18, 61.98,
+        40.02, 41.43, 40.81, 40.76, 55.86, 64.17, 63.75, 64.18, 63.14, 71.70, 65.19, 65.40, 47.35,
+    ],
+    // 95o
+    [
+        13.88, 32.78, 32.45, 32.21, 31.82, 24.36, 35.19, 35.24, 35.18, 25.30, 38.20, 38.49, 38.80,
+        34.06, 14.12, 32.66, 31.62, 31.36, 25.44, 35.54, 35.66, 35.44, 26.23, 39.64, 39.46, 39.75,
+        33.74, 33.82, 14.35, 30.78, 30.52, 25.17, 35.06, 35.72, 35.53, 26.25, 39.64, 39.58, 39.74,
+        34.06, 33.11, 32.31, 14.00, 29.85, 25.93, 34.44, 34.66, 35.54, 26.21, 39.08, 39.14, 39.63,
+        33.22, 33.55, 32.15, 31.31, 13.36, 24.51, 32.99, 33.73, 34.69, 27.27, 39.14, 39.28, 39.53,
+        25.27, 26.14, 26.33, 26.52, 25.28, 8.76, 31.33, 34.24, 38.47, 47.35, 53.72, 53.80, 53.94,
+        37.04, 37.56, 37.02, 35.61, 34.58, 33.11, 28.63, 52.54, 54.03, 66.49, 59.60, 61.04, 60.64,
+        36.75, 37.52, 37.92, 36.51, 35.81, 35.97, 55.51, 29.49, 52.58, 64.64, 58.59, 59.87, 61.41,
+        37.40, 37.47, 37.11, 37.68, 36.33, 40.28, 57.50, 56.52, 29.86, 63.77, 58.13, 58.80, 59.83,
+        26.36, 27.21, 27.29, 27.49, 28.43, 50.00, 70.28, 68.74, 67.22, 33.07, 64.18, 64.76, 66.28,
+        40.78, 42.36, 41.74, 41.36, 41.59, 56.80, 63.26, 62.50, 61.00, 68.34, 46.14, 60.07, 61.30,
+        40.35, 41.69, 41.94, 41.34, 41.34, 56.85, 64.49, 63.41, 62.56, 68.66, 63.89, 47.10, 61.47,
+        41.11, 42.18, 41.80, 41.68, 41.84, 56.86, 64.44, 65.05, 63.88, 70.25, 65.24, 65.52, 47.46,
+    ],
+    // 85o
+    [
+        15.75, 33.68, 33.61, 33.28, 33.15, 33.48, 25.84, 36.49, 36.26, 26.54, 38.93, 39.92, 40.19,
+        35.32, 15.59, 33.23, 33.38, 33.02, 33.29, 26.66, 36.56, 36.57, 27.32, 40.46, 40.39, 40.65,
+        35.39, 34.72, 15.52, 32.43, 32.36, 32.71, 26.65, 36.72, 36.39, 27.93, 40.34, 40.99, 40.84,
+        34.66, 35.30, 34.33, 15.44, 31.59, 31.90, 26.98, 36.02, 36.53, 27.82, 40.33, 40.91, 40.71,
+        34.95, 34.27, 33.76, 33.17, 15.07, 31.42, 26.58, 35.18, 35.97, 28.43, 39.96, 40.90, 41.02,
+        35.38, 34.95, 34.34, 33.48, 32.42, 14.14, 27.20, 34.20, 35.37, 28.39, 41.06, 41.41, 40.91,
+        26.99, 27.66, 28.06, 28.13, 26.99, 28.08, 10.41, 34.73, 38.53, 47.57, 53.42, 54.31, 54.55,
+        38.30, 38.14, 38.75, 37.88, 36.73, 36.13, 36.33, 29.50, 52.87, 64.62, 59.19, 59.71, 61.29,
+        38.25, 38.62, 38.38, 38.59, 37.98, 36.85, 40.37, 56.41, 30.29, 62.82, 58.02, 59.01, 60.49,
+        28.06, 28.32, 28.93, 29.31, 30.33, 29.72, 50.00, 68.43, 66.64, 34.24, 62.81, 64.37, 64.43,
+        40.89, 41.80, 42.06, 42.27, 42.03, 43.07, 56.53, 62.21, 61.34, 66.67, 46.53, 60.49, 61.70,
+        42.60, 42.55, 42.91, 43.20, 43.21, 43.66, 57.45, 63.57, 62.78, 68.77, 64.06, 48.10, 61.04,
+        42.18, 42.86, 43.52, 43.22, 43.04, 43.34, 57.58, 65.21, 63.94, 68.71, 65.46, 64.62, 48.56,
+    ],
+    // 75o
+    [
+        16.98, 34.96, 34.37, 34.22, 34.01, 34.64, 34.08, 26.87, 37.17, 27.90, 39.48, 40.22, 40.91,
+        36.71, 17.12, 34.49, 34.24, 34.38, 34.56, 35.34, 27.36, 37.61, 28.44, 40.68, 41.23, 42.20,
+        36.52, 36.18, 16.91, 33.70, 33.50, 34.38, 34.54, 28.32, 37.41, 28.92, 40.68, 41.60, 42.37,
+        36.40, 35.82, 35.61, 16.71, 32.64, 33.42, 34.14, 28.40, 37.68, 29.25, 41.01, 41.66, 42.00,
+        35.77, 35.88, 35.10, 34.36, 16.56, 32.25, 32.47, 27.85, 36.66, 29.85, 40.74, 41.42, 41.96,
+        36.21, 36.33, 35.99, 35.14, 33.84, 15.80, 32.44, 29.03, 35.99, 30.23, 41.48, 42.43, 42.86,
+        36.28, 36.34, 35.91, 35.22, 34.71, 33.87, 14.74, 28.92, 36.61, 30.24, 41.64, 43.22, 43.64,
+        28.12, 28.96, 29.30, 30.22, 28.69, 29.77, 30.51, 12.01, 38.33, 47.47, 53.35, 54.29, 54.82,
+        39.65, 39.85, 39.15, 39.69, 38.58, 37.93, 37.91, 40.38, 30.37, 62.23, 57.49, 58.51, 60.02,
+        29.26, 29.90, 30.00, 30.66, 31.60, 31.26, 31.57, 50.00, 65.73, 35.30, 61.67, 62.41, 64.28,
+        41.73, 42.98, 43.11, 43.60, 42.78, 43.55, 43.46, 56.39, 60.68, 65.18, 46.72, 59.88, 61.40,
+        42.66, 43.38, 43.63, 43.98, 43.53, 44.50, 45.16, 57.10, 62.00, 66.44, 63.86, 47.98, 61.28,
+        43.77, 44.64, 44.18, 44.69, 44.99, 45.43, 46.18, 58.20, 63.91, 67.94, 64.33, 64.92, 49.38,
+    ],
+    // 65o
+    [
+        18.66, 35.86, 35.93, 35.62, 35.32, 35.95, 36.07, 35.64, 28.54, 29.57, 40.67, 41.07, 41.84,
+        37.88, 18.53, 35.21, 34.99, 34.82, 35.46, 36.12, 35.31, 28.87, 29.82, 41.50, 41.96, 42.69,
+        37.69, 37.44, 18.52, 35.02, 34.40, 35.39, 35.68, 35.83, 29.63, 30.31, 41.93, 42.32, 42.91,
+        37.39, 37.22, 36.97, 18.64, 33.01, 34.89, 35.29, 35.60, 30.24, 30.47, 41.40, 41.81, 42.32,
+        36.77, 36.81, 36.38, 34.63, 18.26, 33.58, 34.26, 34.70, 29.30, 31.32, 41.27, 41.82, 42.43,
+        37.75, 37.60, 37.24, 36.26, 35.25, 17.42, 33.90, 34.87, 30.33, 31.38, 42.34, 42.67, 43.20,
+        37.81, 37.44, 36.80, 36.70, 35.88, 35.38, 15.87, 33.99, 30.92, 31.76, 42.42, 44.06, 44.39,
+        37.67, 37.65, 37.32, 37.15, 36.35, 35.72, 35.71, 14.58, 31.89, 32.57, 43.05, 44.75, 46.35,
+        29.91, 30.33, 30.99, 31.51, 30.39, 31.50, 32.09, 33.12, 13.73, 47.41, 53.45, 54.44, 54.68,
+        30.98, 31.24, 32.09, 31.71, 32.85, 32.78, 33.36, 34.27, 50.00, 35.85, 60.13, 60.19, 61.23,
+        42.73, 43.97, 43.59, 43.45, 43.68, 44.61, 44.78, 45.83, 56.36, 63.66, 46.73, 59.06, 60.40,
+        43.42, 44.42, 44.59, 44.28, 44.22, 45.25, 46.08, 47.29, 57.56, 64.08, 62.32, 48.32, 59.79,
+        44.13, 45.27, 45.45, 44.58, 45.20, 45.55, 46.68, 48.29, 57.95, 65.08, 63.83, 63.54, 50.18,
+    ],
+    // 55
+    [
+        19.23, 51.84, 51.30, 51.18, 50.90, 52.53, 52.24, 53.13, 53.61, 63.41, 66.33, 66.83, 66.39,
+        54.51, 19.81, 50.27, 50.22, 49.72, 51.31, 52.66, 52.15, 53.06, 64.59, 66.59, 66.56, 67.23,
+        54.26, 53.20, 19.46, 48.96, 48.48, 50.54, 51.35, 52.87, 52.72, 64.73, 66.12, 66.47, 66.71,
+        53.74, 52.42, 51.68, 19.28, 47.71, 49.25, 50.54, 51.45, 52.50, 64.09, 65.99, 66.35, 66.35,
+        53.57, 52.51, 51.21, 50.09, 19.16, 47.76, 48.62, 50.23, 51.70, 64.25, 65.19, 65.60, 65.63,
+        55.32, 53.86, 52.97, 51.58, 50.25, 18.81, 48.67, 50.10, 51.61, 63.30, 66.53, 66.64, 66.56,
+        54.79, 54.99, 54.16, 52.83, 51.08, 51.42, 18.88, 49.84, 50.72, 62.05, 66.44, 66.84, 67.28,
+        55.64, 55.54, 55.57, 54.12, 52.61, 52.70, 52.23, 18.81, 50.64, 61.30, 66.27, 67.38, 67.78,
+        56.39, 55.81, 55.43, 56.04, 53.62, 53.88, 53.53, 53.12, 19.02, 60.19, 66.46, 67.41, 67.87,
+        67.43, 68.96, 68.69, 68.03, 68.18, 66.93, 65.76, 64.70, 64.15, 50.00, 81.05, 82.81, 84.36,
+        69.97, 70.16, 69.59, 69.45, 69.69, 69.62, 69.87, 69.90, 70.13, 85.98, 81.40, 82.94, 83.62,
+        70.10, 70.36, 69.99, 69.72, 69.17, 70.25, 70.76, 71.19, 70.64, 87.65, 87.19, 81.78, 83.69,
+        70.42, 70.50, 70.12, 69.44, 69.59, 70.11, 70.80, 71.55, 71.83, 89.54, 87.69, 88.11, 81.52,
+    ],
+    // 54s
+    [
+        21.33, 38.83, 38.88, 38.11, 38.06, 38.58, 38.40, 38.09, 38.18, 30.87, 31.43, 43.71, 44.07,
+        41.21, 22.70, 38.34, 38.64, 37.77, 38.43, 38.67, 38.49, 38.90, 32.64, 33.69, 44.57, 44.89,
+        41.12, 40.93, 22.14, 38.08, 37.24, 38.08, 38.48, 38.59, 38.39, 32.98, 33.89, 44.25, 44.88,
+        41.72, 40.79, 40.38, 22.50, 37.36, 37.39, 38.14, 38.57, 38.47, 33.98, 34.73, 44.63, 44.87,
+        40.83, 40.04, 40.15, 39.68, 21.89, 36.28, 37.77, 38.08, 38.35, 34.65, 35.20, 44.73, 44.98,
+        40.84, 40.86, 40.60, 40.02, 38.54, 21.95, 36.60, 37.54, 37.71, 34.27, 36.04, 44.87, 45.35,
+        40.74, 40.99, 40.76, 40.43, 39.91, 39.28, 21.83, 37.57, 38.21, 35.84, 37.16, 46.33, 46.59,
+        40.62, 41.23, 41.21, 41.30, 40.77, 39.83, 40.05, 20.40, 38.07, 36.76, 37.66, 46.93, 48.05,
+        41.40, 41.46, 40.97, 41.19, 41.28, 40.04, 40.50, 40.58, 19.10, 38.23, 39.30, 48.05, 49.33,
+        32.00, 34.13, 34.44, 35.61, 35.53, 35.82, 37.19, 38.33, 39.87, 18.95, 50.00, 53.28, 53.99,
+        32.80, 34.68, 35.42, 35.89, 36.39, 37.49, 38.16, 39.37, 41.01, 52.43, 40.22, 59.49, 59.59,
+        46.45, 47.09, 47.43, 47.17, 47.45, 47.38, 48.96, 49.47, 50.71, 56.08, 62.43, 50.77, 59.97,
+        46.68, 47.96, 47.88, 47.91, 47.74, 48.06, 49.53, 51.36, 52.28, 57.15, 63.00, 62.49, 51.08,
+    ],
+    // 53s
+    [
+        19.19, 37.63, 37.11, 37.04, 36.59, 37.52, 37.29, 37.44, 37.27, 29.49, 39.37, 30.44, 42.84,
+        39.90, 21.08, 37.21, 37.21, 36.79, 37.33, 37.24, 37.20, 37.13, 31.45, 39.93, 32.35, 43.84,
+        39.81, 39.59, 20.73, 36.94, 36.24, 37.12, 37.26, 37.40, 37.71, 31.84, 40.48, 32.65, 43.67,
+        39.97, 40.08, 39.18, 20.87, 35.85, 36.72, 36.67, 36.95, 37.34, 32.45, 40.55, 33.65, 44.13,
+        39.02, 39.52, 38.84, 38.67, 20.63, 36.01, 36.59, 37.21, 37.16, 32.78, 40.51, 33.78, 43.94,
+        39.73, 39.50, 39.34, 39.24, 38.07, 20.60, 35.85, 36.62, 36.57, 33.90, 40.74, 35.19, 44.26,
+        39.76, 40.17, 39.60, 39.32, 38.25, 38.24, 20.48, 36.44, 36.54, 34.32, 40.77, 36.61, 45.03,
+        40.18, 39.99, 40.10, 39.58, 39.14, 39.05, 38.87, 19.32, 36.56, 36.22, 40.89, 37.69, 46.68,
+        39.60, 39.70, 40.20, 40.00, 39.41, 39.36, 39.06, 38.96, 18.41, 37.91, 42.57, 39.43, 47.79,
+        30.63, 32.43, 32.90, 33.27, 34.53, 35.24, 35.63, 37.59, 39.81, 17.19, 46.72, 50.00, 53.26,
+        41.35, 42.52, 42.85, 42.98, 42.51, 43.97, 43.46, 44.08, 44.95, 49.29, 33.22, 58.06, 55.59,
+        31.45, 33.50, 34.32, 34.63, 34.88, 36.65, 37.92, 38.98, 41.26, 52.50, 61.58, 39.06, 59.02,
+        45.19, 46.89, 46.50, 46.88, 46.83, 47.01, 47.90, 49.08, 51.14, 56.15, 57.87, 62.36, 50.46,
+    ],
+    // 52s
+    [
+        17.78, 35.98, 36.44, 36.41, 35.75, 36.41, 36.29, 35.84, 36.03, 27.94, 37.84, 38.13, 29.42,
+        38.97, 19.22, 35.81, 36.18, 35.11, 36.23, 36.15, 35.64, 35.92, 29.76, 39.08, 39.25, 30.96,
+        38.56, 38.20, 18.95, 35.76, 35.14, 35.75, 36.07, 36.14, 35.59, 29.98, 39.08, 39.24, 31.39,
+        38.63, 37.87, 38.08, 19.29, 34.76, 35.13, 35.51, 35.56, 35.44, 30.78, 38.83, 39.70, 32.01,
+        38.12, 38.00, 37.58, 37.07, 18.62, 34.58, 35.47, 35.28, 35.82, 31.74, 39.21, 39.10, 32.38,
+        38.37, 38.66, 38.02, 37.78, 37.17, 18.64, 35.50, 35.55, 35.37, 32.74, 39.48, 39.83, 34.19,
+        38.30, 38.43, 38.61, 38.03, 37.72, 37.53, 18.66, 35.17, 35.44, 34.06, 39.83, 40.49, 35.30,
+        37.89, 38.40, 38.74, 38.18, 37.75, 37.21, 37.31, 18.80, 36.07, 34.45, 39.85, 41.44, 37.76,
+        38.26, 38.89, 38.33, 38.27, 38.12, 38.22, 37.76, 38.13, 17.89, 37.01, 41.19, 42.19, 38.97,
+        28.51, 30.83, 31.14, 31.81, 32.74, 33.72, 35.57, 35.72, 38.77, 15.64, 46.01, 46.74, 50.00,
+        40.16, 41.29, 41.67, 41.85, 41.90, 42.76, 41.94, 42.63, 44.27, 48.58, 33.25, 49.48, 58.06,
+        40.42, 41.44, 41.87, 42.17, 42.06, 43.01, 43.52, 44.33, 45.17, 49.20, 51.76, 33.86, 58.48,
+        30.17, 32.25, 32.88, 33.33, 34.08, 35.33, 36.76, 38.66, 40.80, 52.42, 61.90, 62.16, 38.35,
+    ],
+    // A4o
+    [
+        7.67, 25.40, 26.12, 27.43, 28.14, 31.27, 32.74, 36.24, 39.94, 42.08, 47.52, 49.99, 50.41,
+        26.80, 29.57, 54.12, 54.40, 54.04, 55.29, 56.29, 56.49, 56.56, 58.08, 71.34, 61.66, 61.56,
+        27.57, 57.83, 29.39, 53.08, 53.35, 54.16, 55.07, 56.60, 55.88, 57.30, 71.28, 61.24, 61.55,
+        28.53, 57.47, 56.20, 29.32, 51.76, 52.46, 53.96, 55.41, 56.29, 57.39, 70.61, 60.94, 60.73,
+        29.68, 56.88, 56.14, 54.65, 29.92, 51.93, 52.98, 54.43, 55.16, 57.69, 71.08, 61.00, 60.93,
+        32.27, 58.52, 57.20, 56.28, 54.58, 29.75, 51.59, 52.97, 53.98, 56.20, 70.68, 60.44, 61.02,
+        34.78, 59.85, 58.98, 57.16, 56.09, 55.39, 30.44, 52.43, 53.43, 55.36, 69.49, 60.67, 61.13,
+        38.32, 59.91, 59.82, 58.90, 57.31, 56.21, 55.56, 30.54, 52.81, 54.54, 68.43, 59.99, 61.49,
+        42.13, 60.08, 59.94, 59.71, 58.22, 57.31, 56.51, 55.85, 30.59, 53.82, 66.70, 59.36, 59.99,
+        44.27, 61.30, 61.08, 60.49, 60.77, 59.22, 59.11, 58.27, 57.27, 30.03, 67.20, 58.65, 59.84,
+        50.00, 76.09, 75.32, 75.15, 74.66, 74.77, 73.49, 72.37, 70.59, 70.68, 32.88, 69.55, 71.35,
+        53.20, 65.15, 65.26, 65.14, 64.45, 64.86, 64.77, 63.81, 62.99, 62.15, 73.72, 46.08, 63.39,
+        53.52, 65.40, 65.27, 64.52, 64.42, 64.54, 64.48, 65.35, 64.58, 63.56, 75.58, 67.27, 47.69,
+    ],
+    // K4o
+    [
+        11.77, 22.70, 33.52, 33.34, 33.42, 34.17, 34.42, 34.61, 34.12, 33.00, 22.92, 36.69, 37.28,
+        23.26, 6.25, 24.76, 25.66, 26.79, 29.09, 32.38, 35.27, 38.21, 41.71, 47.54, 50.14, 50.82,
+        35.51, 25.88, 27.41, 52.24, 52.51, 53.49, 55.14, 56.17, 56.31, 56.47, 70.62, 60.38, 60.59,
+        34.98, 26.56, 55.66, 27.84, 51.34, 51.96, 54.06, 55.20, 56.20, 56.32, 70.29, 59.71, 60.27,
+        34.83, 28.16, 55.40, 54.24, 27.71, 51.37, 53.20, 53.90, 55.29, 56.18, 69.96, 59.91, 59.90,
+        36.57, 30.40, 56.96, 55.56, 54.28, 28.54, 51.40, 52.49, 53.69, 54.93, 70.16, 59.41, 59.81,
+        36.43, 34.11, 58.59, 57.00, 56.08, 54.44, 29.60, 52.00, 53.58, 54.64, 68.91, 60.56, 60.11,
+        35.70, 36.41, 59.71, 58.62, 57.03, 55.69, 55.34, 29.41, 52.40, 53.31, 67.38, 59.31, 60.23,
+        35.84, 40.68, 59.24, 59.53, 58.26, 56.53, 56.73, 55.30, 29.68, 52.66, 66.24, 58.09, 59.59,
+        34.57, 44.29, 60.18, 59.81, 59.89, 57.64, 58.20, 57.02, 56.03, 29.84, 65.32, 57.48, 58.71,
+        23.91, 50.00, 75.30, 74.67, 74.63, 74.45, 72.68, 71.43, 70.55, 68.42, 31.56, 67.68, 69.57,
+        38.75, 53.22, 64.20, 63.76, 63.45, 63.17, 63.85, 62.66, 61.56, 60.80, 71.61, 45.35, 62.07,
+        38.99, 53.65, 64.59, 63.93, 63.85, 63.15, 63.79, 63.81, 63.33, 62.18, 73.37, 66.00, 46.87,
+    ],
+    // Q4o
+    [
+        12.04, 30.36, 22.26, 33.42, 33.20, 34.83, 34.76, 34.55, 34.69, 33.25, 23.45, 36.77, 37.45,
+        32.08, 12.14, 23.09, 32.81, 32.46, 33.64, 35.06, 34.59, 34.68, 34.03, 23.85, 38.05, 38.63,
+        23.40, 23.97, 6.62, 25.76, 26.35, 28.93, 31.73, 35.12, 38.30, 41.88, 47.49, 50.26, 50.53,
+        35.35, 34.45, 26.86, 27.75, 50.93, 52.91, 54.06, 54.87, 56.22, 56.00, 69.99, 60.18, 60.51,
+        35.18, 34.54, 27.99, 54.26, 27.63, 51.45, 52.89, 54.27, 55.30, 56.48, 69.68, 59.65, 60.00,
+        36.42, 35.60, 29.98, 55.60, 54.51, 28.25, 51.53, 52.46, 53.80, 55.40, 69.78, 59.69, 59.51,
+        36.37, 36.56, 32.84, 57.31, 55.84, 54.50, 29.38, 52.56, 53.37, 54.50, 68.35, 59.77, 59.72,
+        36.47, 36.58, 36.74, 58.04, 56.85, 55.81, 55.66, 29.72, 52.81, 54.03, 67.65, 59.43, 60.83,
+        36.21, 36.00, 40.56, 59.99, 58.22, 57.06, 56.28, 56.04, 29.83, 52.97, 65.77, 58.19, 59.30,
+        35.30, 36.12, 44.37, 60.01, 60.23, 58.26, 57.94, 56.89, 56.41, 30.41, 64.58, 57.15, 58.33,
+        24.68, 24.70, 50.00, 74.00, 73.75, 74.02, 72.79, 71.35, 69.76, 68.15, 31.67, 67.06, 68.81,
+        38.76, 40.13, 53.11, 64.13, 63.88, 63.04, 63.98, 62.76, 61.86, 60.99, 71.35, 45.69, 61.96,
+        39.65, 40.42, 53.39, 64.19, 63.62, 63.74, 63.54, 64.36, 62.95, 61.63, 72.89, 66.13, 46.83,
+    ],
+    // J4o
+    [
+        12.58, 30.81, 30.34, 22.75, 33.73, 34.60, 34.42, 34.41, 34.84, 33.45, 23.84, 37.04, 37.74,
+        32.19, 12.49, 29.94, 23.39, 32.50, 33.61, 34.53, 34.89, 34.48, 34.49, 24.32, 38.30, 38.72,
+        31.92, 31.33, 12.19, 23.80, 31.67, 32.97, 34.09, 34.59, 34.46, 34.36, 24.57, 38.03, 38.61,
+        23.72, 23.97, 24.41, 6.79, 26.21, 28.55, 31.31, 34.72, 38.84, 41.59, 47.58, 50.35, 50.49,
+        35.01, 34.60, 33.71, 27.68, 27.64, 51.41, 52.99, 53.90, 55.82, 56.75, 69.66, 60.10, 60.34,
+        36.13, 35.11, 34.20, 29.74, 54.34, 27.88, 51.35, 52.43, 53.56, 55.30, 69.57, 59.68, 59.62,
+        35.86, 37.00, 35.81, 32.55, 55.88, 54.21, 28.65, 52.22, 53.41, 54.64, 68.23, 60.00, 60.37,
+        36.64, 36.57, 36.72, 36.31, 57.53, 55.73, 54.59, 29.96, 52.59, 54.13, 66.62, 59.68, 60.80,
+        36.65, 36.53, 36.23, 40.48, 58.87, 57.59, 56.86, 56.14, 29.73, 53.22, 65.76, 58.69, 59.20,
+        35.26, 36.17, 35.99, 43.87, 60.15, 58.64, 57.73, 56.40, 56.55, 30.55, 64.11, 57.02, 58.15,
+        24.85, 25.33, 26.00, 50.00, 73.94, 73.66, 72.19, 70.50, 69.70, 67.88, 32.17, 66.62, 68.23,
+        39.46, 40.30, 39.89, 53.33, 63.99, 63.50, 63.81, 62.72, 61.97, 60.97, 70.66, 46.04, 61.90,
+        39.81, 41.02, 40.63, 53.49, 63.99, 63.38, 63.84, 64.55, 63.61, 62.32, 72.48, 65.89, 46.66,
+    ],
+    // T4o
+    [
+        12.86, 31.04, 31.00, 30.26, 23.10, 34.75, 34.38, 34.55, 35.19, 34.17, 24.40, 37.75, 38.15,
+        32.50, 12.93, 30.49, 30.42, 23.55, 33.72, 34.87, 35.05, 35.01, 34.59, 24.73, 38.12, 38.69,
+        32.49, 31.80, 12.65, 29.19, 23.82, 32.79, 33.48, 34.97, 34.41, 34.80, 24.87, 38.63, 38.58,
+        31.98, 30.92, 29.78, 12.49, 23.86, 31.81, 32.85, 33.96, 34.75, 34.27, 24.96, 38.42, 38.67,
+        23.56, 24.45, 24.49, 24.65, 7.24, 28.32, 30.87, 34.41, 37.86, 42.06, 47.49, 50.36, 50.23,
+        36.25, 35.61, 34.43, 33.52, 29.65, 27.77, 50.63, 52.35, 54.02, 54.82, 69.17, 59.58, 60.29,
+        36.29, 36.88, 35.48, 34.49, 32.46, 54.28, 28.57, 51.72, 53.24, 54.53, 67.52, 60.13, 60.02,
+        36.52, 36.48, 36.50, 35.46, 35.81, 55.18, 54.70, 29.12, 52.16, 53.75, 66.23, 58.83, 60.73,
+        36.73, 36.76, 36.16, 36.53, 40.18, 57.19, 56.51, 55.33, 30.14, 52.98, 64.52, 58.15, 59.52,
+        36.10, 36.54, 36.42, 35.94, 44.10, 58.41, 57.97, 57.22, 56.32, 30.31, 63.61, 57.49, 58.10,
+        25.34, 25.37, 26.25, 26.06, 50.00, 73.29, 71.85, 69.98, 68.71, 67.20, 32.82, 65.84, 67.05,
+        39.65, 40.37, 40.32, 40.65, 53.27, 64.01, 64.07, 62.89, 62.02, 60.64, 69.38, 46.28, 61.74,
+        40.08, 40.63, 41.06, 40.63, 53.62, 63.90, 64.11, 64.05, 62.84, 62.27, 71.87, 65.58, 47.39,
+    ],
+    // 94o
+    [
+        11.98, 31.02, 31.25, 30.94, 30.66, 22.90, 34.14, 33.78, 34.55, 33.00, 24.10, 37.13, 37.50,
+        32.80, 12.66, 31.03, 30.66, 30.11, 23.53, 35.16, 34.21, 34.64, 34.09, 24.86, 38.66, 38.73,
+        32.88, 32.11, 12.53, 29.70, 29.42, 23.69, 33.73, 34.84, 33.92, 34.29, 24.69, 37.94, 38.69,
+        32.01, 31.70, 31.06, 12.15, 28.82, 23.94, 32.42, 33.29, 34.50, 34.26, 25.32, 37.82, 38.83,
+        31.96, 31.73, 30.78, 29.79, 11.69, 24.36, 31.87, 32.84, 33.54, 34.38, 26.11, 37.91, 38.38,
+        24.02, 24.63, 24.52, 24.92, 25.24, 6.87, 30.73, 33.53, 37.30, 41.04, 47.47, 50.02, 50.52,
+        35.55, 36.29, 35.58, 34.14, 33.62, 31.90, 28.22, 50.76, 52.67, 53.87, 66.15, 59.81, 59.42,
+        35.50, 36.38, 36.11, 35.24, 33.96, 35.59, 54.28, 29.05, 51.57, 53.25, 65.25, 58.81, 59.99,
+        35.76, 36.09, 35.63, 36.47, 35.11, 39.39, 55.57, 54.39, 29.43, 52.50, 63.24, 57.39, 58.25,
+        34.48, 36.33, 36.35, 35.50, 36.42, 43.20, 56.93, 56.45, 55.39, 30.38, 62.51, 56.03, 57.24,
+        25.23, 25.55, 25.98, 26.34, 26.71, 50.00, 70.36, 68.79, 67.49, 66.09, 32.25, 64.92, 66.02,
+        38.92, 40.78, 40.03, 40.16, 39.98, 53.12, 63.50, 62.11, 61.10, 60.06, 68.37, 45.53, 60.93,
+        39.70, 40.74, 40.42, 40.89, 40.53, 53.52, 63.26, 63.27, 62.82, 60.95, 70.30, 65.02, 46.23,
+    ],
+    // 84o
+    [
+        13.86, 32.28, 32.11, 31.79, 31.63, 32.15, 23.76, 35.10, 35.28, 34.33, 25.61, 38.98, 38.66,
+        33.96, 14.00, 31.51, 31.72, 31.70, 31.58, 25.08, 35.36, 35.31, 35.04, 26.05, 39.15, 39.48,
+        34.11, 33.34, 13.81, 31.46, 30.84, 31.31, 25.14, 35.89, 35.50, 35.39, 26.61, 39.66, 39.72,
+        33.70, 33.43, 32.90, 14.09, 30.48, 30.47, 25.28, 34.35, 35.56, 35.54, 26.32, 39.63, 39.50,
+        33.21, 33.18, 32.45, 31.65, 13.44, 29.96, 26.10, 33.51, 34.19, 35.73, 27.26, 39.90, 39.48,
+        33.53, 33.45, 33.04, 31.91, 30.98, 13.28, 25.13, 33.50, 34.06, 35.28, 28.29, 39.95, 39.88,
+        25.22, 25.85, 26.06, 26.39, 26.78, 26.17, 8.71, 33.98, 37.51, 41.21, 47.45, 50.94, 51.09,
+        37.38, 36.55, 37.22, 36.19, 35.50, 34.70, 35.76, 29.21, 51.77, 53.10, 64.47, 58.88, 60.03,
+        37.24, 37.02, 37.14, 37.36, 36.48, 35.75, 39.49, 54.90, 29.06, 52.23, 62.91, 57.42, 59.19,
+        37.03, 36.68, 36.95, 36.38, 37.33, 36.74, 43.47, 56.54, 55.22, 30.13, 61.84, 56.54, 58.06,
+        26.51, 27.32, 27.21, 27.81, 28.15, 29.64, 50.00, 68.37, 67.05, 65.45, 33.26, 64.59, 64.23,
+        40.81, 41.79, 42.00, 41.43, 41.94, 42.42, 54.17, 62.64, 61.58, 60.27, 68.62, 46.69, 60.77,
+        40.80, 41.78, 41.52, 41.61, 41.35, 41.94, 53.84, 64.16, 62.81, 61.44, 68.38, 64.28, 46.87,
+    ],
+    // 74o
+    [
+        15.40, 33.68, 33.20, 33.28, 32.99, 33.38, 33.33, 25.15, 35.99, 35.91, 26.27, 38.92, 39.76,
+        34.94, 15.55, 33.34, 33.04, 32.95, 33.06, 33.26, 26.04, 36.21, 36.45, 27.43, 40.09, 41.34,
+        35.00, 34.75, 15.24, 32.16, 32.04, 32.71, 33.09, 26.58, 36.20, 36.10, 27.28, 39.87, 40.71,
+        34.70, 34.94, 33.72, 15.21, 31.58, 31.47, 32.48, 26.51, 36.76, 36.05, 27.84, 39.78, 40.94,
+        34.78, 34.81, 33.80, 33.52, 15.09, 31.80, 31.72, 27.64, 35.82, 36.43, 28.68, 40.09, 40.71,
+        34.88, 34.30, 34.26, 33.17, 32.81, 14.99, 31.27, 26.81, 35.45, 35.80, 29.56, 40.51, 40.90,
+        34.02, 34.88, 34.26, 33.77, 33.38, 32.13, 13.88, 28.40, 34.65, 35.90, 30.10, 41.72, 42.08,
+        26.21, 27.38, 27.59, 27.71, 28.16, 27.90, 29.38, 10.16, 37.83, 41.41, 47.45, 51.01, 51.67,
+        38.27, 38.02, 37.65, 38.12, 37.45, 37.02, 36.74, 39.23, 29.26, 51.61, 62.54, 57.51, 58.48,
+        37.18, 37.82, 37.50, 37.76, 38.38, 37.50, 37.79, 43.61, 54.17, 30.10, 60.63, 55.92, 57.37,
+        27.63, 28.57, 28.65, 29.50, 30.02, 31.21, 31.63, 50.00, 66.10, 64.35, 34.44, 62.10, 63.48,
+        40.94, 42.76, 42.35, 42.02, 42.41, 42.75, 43.69, 53.98, 61.18, 59.87, 65.27, 46.97, 60.73,
+        41.95, 43.05, 43.21, 43.19, 43.62, 43.82, 44.70, 55.22, 62.08, 61.06, 67.65, 64.29, 48.44,
+    ],
+    // 64o
+    [
+        16.54, 34.91, 34.90, 34.30, 34.85, 34.50, 34.61, 33.90, 26.91, 35.81, 27.82, 39.75, 40.63,
+        36.46, 17.04, 34.37, 34.00, 33.65, 34.18, 34.66, 34.19, 27.40, 37.55, 28.51, 40.51, 41.42,
+        36.02, 35.72, 17.08, 33.50, 33.64, 34.06, 34.08, 34.61, 27.89, 36.74, 28.82, 40.71, 41.25,
+        35.94, 35.91, 35.03, 16.29, 32.64, 33.06, 33.85, 33.80, 28.36, 37.00, 29.09, 40.74, 41.08,
+        35.95, 35.75, 35.45, 34.51, 16.39, 32.44, 33.34, 33.21, 28.88, 37.75, 30.24, 40.97, 41.37,
+        36.43, 35.95, 35.53, 34.72, 33.80, 16.29, 32.62, 33.22, 28.61, 36.94, 31.14, 41.32, 42.24,
+        36.18, 36.41, 36.08, 35.32, 34.91, 34.21, 15.40, 33.05, 30.31, 37.04, 31.44, 42.03, 42.78,
+        35.65, 36.33, 36.24, 35.66, 35.13, 34.55, 34.09, 13.96, 31.26, 36.87, 32.65, 42.84, 44.52,
+        28.25, 28.70, 29.26, 29.33, 30.42, 29.77, 31.64, 32.54, 11.92, 41.63, 47.52, 51.03, 51.96,
+        38.22, 39.01, 38.77, 38.99, 39.23, 39.00, 38.66, 39.32, 43.64, 29.87, 58.99, 55.05, 55.73,
+        29.41, 29.45, 30.24, 30.30, 31.29, 32.51, 32.95, 33.90, 50.00, 62.38, 35.47, 60.16, 60.64,
+        41.84, 43.13, 43.09, 42.78, 43.16, 43.56, 43.98, 45.23, 53.78, 58.22, 63.66, 47.49, 59.07,
+        43.39, 43.34, 44.28, 43.36, 43.79, 44.27, 45.28, 46.53, 55.30, 59.45, 64.49, 62.38, 48.15,
+    ],
+    // 54o
+    [
+        17.20, 35.69, 35.66, 35.62, 35.18, 35.64, 35.22, 35.80, 35.77, 26.80, 27.92, 40.30, 41.16,
+        37.57, 18.77, 35.48, 35.57, 35.39, 35.23, 36.32, 35.68, 35.44, 29.04, 29.47, 41.97, 41.82,
+        37.41, 37.26, 18.64, 35.06, 34.58, 34.93, 35.53, 36.01, 36.21, 29.07, 30.24, 42.22, 42.46,
+        37.38, 37.50, 36.91, 18.90, 34.23, 34.32, 35.11, 35.58, 36.10, 29.66, 30.48, 42.06, 42.51,
+        36.80, 36.79, 36.61, 36.23, 18.42, 32.87, 34.79, 35.14, 35.70, 30.81, 31.00, 42.15, 42.24,
+        37.45, 37.41, 36.49, 36.43, 34.60, 18.24, 33.86, 34.95, 34.84, 30.35, 32.84, 42.14, 42.70,
+        37.27, 37.57, 37.54, 37.07, 36.85, 36.03, 17.27, 34.81, 34.92, 32.67, 32.78, 43.43, 44.02,
+        37.46, 37.49, 37.93, 37.25, 37.12, 36.41, 36.63, 16.28, 35.46, 33.25, 34.21, 44.40, 45.18,
+        37.54, 37.78, 37.17, 37.68, 37.15, 36.96, 36.84, 36.81, 14.93, 34.90, 36.10, 45.61, 46.89,
+        27.82, 29.67, 30.50, 31.34, 32.03, 31.66, 33.33, 34.82, 36.34, 14.02, 47.57, 50.71, 51.42,
+        29.32, 31.58, 31.85, 32.12, 32.80, 33.91, 34.55, 35.65, 37.62, 50.00, 36.65, 57.78, 58.01,
+        42.98, 44.12, 44.07, 44.10, 44.35, 44.11, 45.75, 46.45, 48.32, 53.59, 61.06, 47.41, 57.36,
+        43.45, 44.34, 44.75, 45.27, 44.60, 44.53, 45.97, 47.86, 49.80, 54.56, 61.78, 60.52, 49.08,
+    ],
+    // 44
+    [
+        18.79, 51.24, 51.02, 50.93, 50.57, 51.59, 51.75, 52.30, 52.30, 51.95, 63.30, 66.10, 65.85,
+        54.19, 19.12, 49.75, 49.49, 49.22, 50.42, 51.46, 51.58, 52.13, 52.39, 64.29, 65.94, 66.24,
+        53.43, 52.42, 18.99, 48.80, 48.10, 49.48, 50.45, 51.96, 51.62, 52.20, 64.08, 65.88, 65.99,
+        53.12, 51.86, 51.06, 19.05, 47.00, 48.22, 49.60, 50.99, 51.78, 52.24, 63.86, 65.75, 66.06,
+        52.62, 51.73, 50.62, 49.73, 19.05, 47.66, 48.64, 49.47, 51.20, 52.57, 63.70, 65.43, 65.23,
+        54.20, 52.76, 51.62, 50.74, 49.71, 18.79, 47.40, 48.67, 49.77, 50.97, 64.22, 65.60, 66.23,
+        54.25, 54.37, 53.45, 51.90, 51.06, 49.84, 18.57, 48.78, 50.10, 50.89, 63.32, 65.99, 66.00,
+        54.99, 54.26, 53.81, 53.21, 52.05, 51.35, 50.82, 18.54, 49.49, 50.90, 62.19, 66.37, 66.85,
+        55.49, 55.19, 54.66, 54.51, 53.59, 52.44, 52.00, 51.85, 18.50, 50.32, 60.93, 66.20, 67.19,
+        54.82, 55.24, 54.90, 54.99, 55.17, 53.86, 53.47, 53.28, 53.27, 18.60, 59.78, 66.78, 66.75,
+        67.12, 68.44, 68.33, 67.83, 67.18, 67.75, 66.74, 65.56, 64.53, 63.35, 50.00, 82.63, 84.36,
+        69.42, 69.70, 68.94, 69.23, 68.45, 68.69, 69.82, 69.56, 69.91, 69.64, 87.61, 81.10, 83.21,
+        69.49, 70.09, 69.88, 69.45, 69.18, 69.11, 69.72, 70.63, 70.40, 70.42, 89.41, 87.22, 81.31,
+    ],
+    // 43s
+    [
+        19.08, 37.39, 37.20, 36.78, 36.88, 36.84, 36.54, 36.41, 37.11, 35.40, 28.94, 30.00, 42.33,
+        40.03, 20.38, 37.07, 36.66, 36.84, 37.05, 36.79, 36.96, 36.73, 36.94, 31.01, 32.19, 43.64,
+        39.22, 39.29, 20.30, 36.09, 36.06, 36.76, 36.71, 37.34, 36.81, 37.08, 31.74, 32.47, 43.24,
+        39.21, 39.12, 38.73, 20.58, 35.84, 36.36, 36.50, 36.90, 37.55, 37.39, 32.12, 33.02, 43.43,
+        38.87, 38.92, 38.77, 38.70, 20.54, 35.93, 36.34, 36.66, 37.26, 37.14, 33.30, 34.15, 43.51,
+        39.17, 39.36, 38.80, 38.34, 38.26, 20.13, 34.86, 36.73, 37.04, 36.76, 33.93, 34.80, 43.61,
+        38.82, 39.80, 39.29, 38.54, 38.57, 37.52, 20.06, 36.24, 36.78, 37.21, 34.09, 36.22, 44.63,
+        39.48, 39.59, 39.66, 39.49, 39.48, 39.19, 38.48, 19.54, 37.13, 37.40, 36.39, 37.29, 46.43,
+        39.78, 39.14, 39.37, 39.63, 39.57, 39.44, 39.23, 39.61, 18.41, 38.74, 38.49, 39.14, 47.74,
+        37.84, 39.23, 39.58, 39.50, 39.93, 39.93, 39.51, 40.12, 40.94, 17.06, 40.51, 41.94, 50.52,
+        30.45, 32.32, 32.94, 33.38, 34.16, 35.08, 35.41, 37.90, 39.84, 42.22, 17.37, 50.00, 53.14,
+        31.31, 33.37, 33.71, 34.21, 35.02, 35.81, 37.66, 38.65, 41.10, 43.80, 52.47, 38.74, 54.13,
+        45.14, 46.00, 46.52, 46.23, 46.23, 46.58, 47.14, 49.27, 50.51, 53.17, 56.02, 56.91, 49.71,
+    ],
+    // 42s
+    [
+        17.44, 35.82, 35.33, 35.88, 35.27, 35.39, 35.87, 35.48, 35.80, 34.63, 27.75, 37.63, 28.39,
+        38.25, 19.04, 35.67, 35.72, 34.94, 35.34, 35.83, 35.76, 35.78, 35.41, 29.45, 39.11, 30.71,
+        38.28, 38.14, 19.04, 35.26, 34.71, 35.14, 35.59, 35.97, 35.68, 35.35, 29.82, 39.05, 30.90,
+        37.92, 38.05, 37.92, 18.83, 34.81, 35.28, 35.43, 35.53, 36.18, 35.64, 30.69, 38.72, 31.73,
+        38.58, 37.70, 37.49, 36.95, 18.91, 34.42, 35.08, 34.96, 35.25, 35.75, 31.62, 38.83, 32.44,
+        38.21, 38.01, 37.92, 37.01, 36.85, 18.36, 34.86, 35.04, 35.99, 35.94, 33.02, 39.24, 33.50,
+        38.26, 38.51, 37.94, 38.34, 37.72, 37.44, 18.96, 34.90, 35.81, 35.96, 33.77, 40.54, 35.31,
+        38.01, 38.28, 38.32, 38.06, 37.97, 37.41, 37.55, 18.39, 35.77, 36.30, 34.65, 41.01, 37.37,
+        38.05, 38.14, 38.14, 38.20, 38.01, 37.77, 38.20, 38.50, 17.89, 37.36, 37.67, 42.14, 38.97,
+        36.65, 38.02, 38.01, 38.26, 38.38, 38.70, 38.30, 38.60, 39.60, 16.38, 40.41, 44.41, 41.94,
+        28.65, 30.43, 31.19, 31.77, 32.95, 33.98, 35.77, 36.52, 39.36, 41.99, 15.64, 46.86, 50.00,
+        40.13, 41.64, 41.75, 41.78, 41.63, 42.18, 43.26, 43.90, 44.97, 47.28, 49.37, 33.32, 52.77,
+        30.01, 31.83, 31.98, 33.16, 33.54, 34.59, 36.57, 38.77, 40.89, 43.22, 52.53, 55.69, 37.89,
+    ],
+    // A3o
+    [
+        7.23, 25.42, 25.87, 26.65, 28.21, 30.86, 32.66, 35.82, 39.96, 41.51, 44.33, 47.46, 50.39,
+        26.04, 28.96, 54.31, 54.20, 53.86, 54.98, 55.91, 56.17, 56.19, 57.13, 57.70, 71.61, 61.47,
+        27.06, 57.61, 29.26, 53.10, 52.46, 54.29, 54.44, 55.92, 55.76, 57.11, 57.64, 71.47, 61.29,
+        27.79, 57.50, 56.15, 29.04, 51.87, 52.73, 53.78, 55.05, 55.72, 56.98, 57.31, 71.21, 60.96,
+        29.03, 57.10, 56.04, 54.53, 29.41, 51.68, 52.37, 53.83, 54.50, 57.12, 56.61, 70.37, 60.72,
+        31.84, 59.06, 57.37, 56.56, 55.01, 30.22, 52.09, 52.97, 54.16, 56.39, 57.34, 71.10, 60.74,
+        34.68, 59.68, 58.13, 57.02, 55.60, 54.67, 30.00, 51.37, 51.83, 54.26, 56.08, 70.83, 60.23,
+        38.29, 59.65, 59.64, 57.89, 56.72, 56.65, 54.63, 29.94, 52.14, 54.19, 55.95, 69.29, 61.01,
+        41.96, 60.07, 59.30, 59.51, 58.09, 57.29, 55.67, 54.90, 30.56, 53.76, 54.70, 68.67, 60.42,
+        43.76, 61.09, 60.65, 60.16, 60.09, 59.65, 57.40, 57.34, 56.58, 29.90, 53.55, 68.55, 59.58,
+        46.80, 61.25, 61.24, 60.54, 60.35, 61.08, 59.19, 59.06, 58.16, 57.02, 30.58, 68.69, 59.87,
+        50.00, 75.89, 75.77, 74.89, 74.94, 74.81, 74.70, 73.51, 72.15, 72.02, 72.70, 33.51, 71.68,
+        52.94, 65.50, 64.78, 64.89, 64.44, 64.78, 63.68, 64.67, 63.70, 63.37, 63.60, 75.87, 46.92,
+    ],
+    // K3o
+    [
+        11.02, 22.32, 32.95, 32.98, 32.81, 34.66, 34.37, 34.29, 33.99, 33.14, 33.04, 22.65, 36.69,
+        22.80, 5.77, 24.20, 25.04, 26.32, 29.14, 31.52, 34.70, 38.30, 41.40, 44.16, 47.47, 50.42,
+        34.88, 25.65, 27.54, 52.30, 51.81, 53.14, 54.89, 55.92, 55.70, 56.07, 56.50, 70.74, 60.55,
+        34.76, 26.50, 55.87, 27.26, 51.16, 52.21, 53.13, 54.40, 55.69, 55.82, 56.04, 70.33, 59.77,
+        34.24, 27.74, 55.48, 54.06, 28.01, 50.72, 52.00, 53.50, 54.57, 55.58, 55.92, 70.23, 60.18,
+        36.24, 29.92, 57.05, 55.99, 54.54, 28.46, 51.40, 52.24, 53.71, 54.86, 56.49, 70.14, 59.59,
+        35.40, 33.31, 57.97, 56.80, 55.16, 54.55, 28.71, 51.34, 52.40, 53.75, 54.89, 70.38, 59.38,
+        35.79, 36.77, 59.13, 57.91, 56.91, 55.76, 54.27, 29.15, 51.83, 52.84, 54.21, 68.90, 60.05,
+        35.53, 40.38, 59.26, 59.26, 58.18, 56.67, 55.77, 54.98, 29.46, 52.36, 53.50, 67.90, 59.29,
+        34.67, 43.87, 59.44, 59.24, 59.02, 58.31, 57.45, 56.62, 55.58, 29.64, 52.91, 66.50, 58.56,
+        34.85, 46.78, 59.87, 59.70, 59.63, 59.22, 58.21, 57.24, 56.87, 55.88, 30.30, 66.63, 58.36,
+        24.11, 50.00, 75.14, 74.82, 74.22, 74.31, 74.16, 72.60, 71.50, 70.46, 70.62, 32.08, 69.40,
+        38.49, 53.16, 64.07, 64.05, 63.58, 63.66, 63.31, 63.79, 62.64, 61.46, 62.44, 73.98, 46.18,
+    ],
+    // Q3o
+    [
+        11.41, 30.40, 21.92, 33.03, 33.02, 34.18, 34.12, 34.41, 33.89, 33.13, 33.13, 23.47, 36.68,
+        31.53, 11.49, 22.78, 31.80, 32.23, 33.34, 34.46, 34.64, 34.31, 33.84, 34.09, 23.65, 37.82,
+        22.77, 23.21, 6.10, 25.10, 26.21, 28.44, 31.45, 35.08, 38.19, 41.43, 44.38, 47.53, 50.22,
+        34.57, 33.70, 26.34, 27.22, 50.82, 52.36, 53.58, 54.63, 55.99, 55.93, 56.45, 70.48, 60.29,
+        34.74, 33.79, 27.99, 54.29, 27.41, 51.44, 52.19, 53.90, 54.55, 56.11, 56.35, 70.13, 59.89,
+        36.11, 35.14, 30.09, 55.81, 54.11, 28.52, 51.39, 52.43, 53.66, 55.11, 56.60, 69.95, 60.12,
+        35.45, 36.24, 32.27, 57.21, 54.89, 54.41, 28.83, 51.28, 52.29, 53.48, 54.78, 69.57, 59.58,
+        36.48, 36.18, 36.79, 58.19, 56.59, 55.87, 54.62, 29.38, 51.91, 52.74, 54.50, 68.97, 60.15,
+        36.28, 36.08, 40.09, 59.10, 58.14, 56.65, 55.94, 55.17, 30.03, 52.18, 53.71, 67.32, 59.36,
+        35.17, 35.71, 43.74, 59.71, 59.62, 58.06, 57.09, 56.37, 55.41, 30.01, 52.57, 65.68, 58.13,
+        34.74, 35.80, 46.89, 60.11, 59.68, 59.97, 58.00, 57.65, 56.91, 55.93, 31.06, 66.29, 58.25,
+        24.23, 24.86, 50.00, 74.84, 74.36, 74.08, 73.66, 72.70, 70.91, 69.71, 70.37, 32.15, 69.03,
+        38.91, 40.02, 53.13, 63.83, 63.80, 63.50, 63.42, 64.09, 62.83, 61.54, 62.26, 73.06, 46.43,
+    ],
+    // J3o
+    [
+        12.10, 30.27, 30.08, 22.37, 33.10, 34.33, 34.46, 34.20, 34.82, 33.60, 33.26, 23.66, 37.52,
+        32.24, 12.12, 29.59, 22.91, 32.21, 33.36, 34.32, 34.52, 34.54, 34.45, 34.72, 24.42, 38.35,
+        31.73, 30.99, 11.67, 22.90, 30.93, 32.96, 33.71, 34.46, 34.49, 34.38, 34.20, 24.26, 38.07,
+        23.25, 23.74, 24.06, 6.49, 25.95, 28.17, 31.04, 34.29, 38.73, 41.72, 44.28, 47.57, 50.31,
+        34.84, 33.72, 32.38, 27.35, 27.03, 51.52, 52.42, 53.93, 55.01, 56.08, 56.19, 69.75, 59.84,
+        36.37, 35.19, 34.53, 30.15, 54.11, 28.30, 51.64, 52.17, 53.67, 54.95, 56.35, 69.47, 60.04,
+        35.83, 35.97, 35.32, 32.45, 55.35, 54.07, 28.46, 51.27, 52.85, 53.76, 55.15, 69.46, 59.57,
+        36.07, 36.50, 36.20, 35.79, 56.85, 55.66, 54.50, 29.21, 52.05, 52.95, 54.51, 67.86, 59.91,
+        36.51, 36.39, 36.59, 40.56, 58.43, 56.88, 55.42, 55.29, 30.07, 52.77, 53.99, 67.23, 59.07,
+        35.53, 35.80, 35.37, 43.90, 59.22, 58.66, 56.80, 56.02, 55.72, 30.28, 52.83, 65.37, 57.83,
+        34.86, 36.24, 35.87, 46.67, 59.35, 59.84, 58.57, 57.98, 57.22, 55.90, 30.77, 65.79, 58.22,
+        25.11, 25.18, 25.16, 50.00, 73.81, 73.27, 73.62, 71.73, 71.02, 69.13, 69.97, 32.73, 67.92,
+        39.18, 40.12, 39.95, 53.17, 64.06, 63.50, 63.50, 63.92, 63.03, 61.52, 61.93, 72.85, 46.22,
+    ],
+    // T3o
+    [
+        12.46, 30.58, 30.41, 29.98, 22.48, 34.40, 34.04, 34.71, 34.46, 33.85, 33.74, 23.96, 37.56,
+        32.35, 12.85, 29.91, 29.49, 22.81, 33.91, 34.49, 34.68, 34.44, 34.53, 34.68, 24.65, 38.76,
+        32.77, 31.21, 12.22, 28.63, 23.14, 32.52, 33.34, 34.93, 34.31, 34.95, 34.17, 24.38, 38.08,
+        31.14, 31.32, 29.79, 11.95, 23.37, 31.59, 32.42, 33.59, 34.22, 34.53, 34.67, 25.03, 37.95,
+        23.22, 23.77, 24.27, 24.52, 6.74, 28.22, 30.71, 33.97, 37.72, 41.70, 44.54, 47.53, 50.36,
+        36.74, 35.27, 34.21, 33.22, 29.65, 27.85, 51.36, 52.30, 53.97, 55.00, 56.25, 69.09, 59.98,
+        35.86, 36.35, 35.22, 33.53, 32.44, 54.32, 28.22, 50.77, 52.81, 53.77, 55.33, 69.08, 59.53,
+        35.81, 36.52, 36.49, 34.98, 35.71, 55.74, 53.68, 29.48, 52.12, 52.89, 54.13, 67.52, 60.12,
+        36.59, 36.40, 36.22, 36.62, 39.89, 57.35, 55.15, 54.57, 29.99, 52.76, 53.54, 65.99, 59.30,
+        35.79, 36.48, 36.45, 36.03, 44.08, 58.66, 56.79, 56.47, 55.78, 30.83, 52.55, 65.12, 57.94,
+        35.55, 36.55, 36.12, 36.01, 46.73, 60.02, 58.06, 57.59, 56.84, 55.65, 31.55, 64.98, 58.37,
+        25.06, 25.78, 25.64, 26.19, 50.00, 73.06, 72.62, 71.26, 69.77, 68.67, 68.25, 33.12, 67.36,
+        39.62, 40.55, 40.13, 40.63, 53.07, 63.78, 63.60, 64.22, 63.01, 61.54, 61.57, 71.46, 46.74,
+    ],
+    // 93o
+    [
+        12.11, 31.05, 31.37, 30.83, 30.70, 22.96, 34.08, 34.20, 34.46, 33.20, 33.86, 24.08, 37.53,
+        32.82, 12.47, 30.88, 30.27, 30.46, 23.39, 34.69, 34.47, 34.14, 34.15, 34.90, 24.91, 38.45,
+        32.53, 32.54, 12.30, 29.69, 29.50, 23.34, 33.26, 34.63, 34.46, 34.39, 34.59, 24.65, 38.65,
+        32.08, 31.76, 31.19, 12.31, 28.77, 24.13, 32.48, 33.99, 34.55, 34.02, 34.36, 25.41, 37.89,
+        31.89, 31.61, 30.72, 29.58, 11.93, 24.40, 31.72, 32.60, 33.86, 34.70, 34.97, 25.71, 38.24,
+        23.62, 24.03, 24.36, 24.85, 25.28, 6.86, 30.37, 34.11, 37.46, 41.02, 44.30, 47.47, 50.36,
+        35.75, 36.07, 35.32, 34.20, 33.13, 31.85, 27.77, 50.31, 51.79, 53.15, 54.43, 67.75, 59.57,
+        35.98, 36.26, 36.12, 35.40, 34.26, 35.38, 53.30, 28.93, 51.11, 52.70, 53.67, 66.36, 59.87,
+        36.56, 36.56, 36.28, 36.46, 35.13, 39.18, 54.85, 54.41, 29.31, 51.76, 53.13, 64.93, 58.55,
+        35.09, 36.44, 36.16, 36.17, 36.09, 43.15, 56.34, 55.50, 54.75, 29.75, 52.62, 63.35, 56.99,
+        35.14, 36.83, 36.96, 36.50, 35.99, 46.88, 57.58, 57.25, 56.44, 55.89, 31.31, 64.19, 57.82,
+        25.19, 25.69, 25.92, 26.73, 26.94, 50.00, 71.68, 70.50, 68.67, 67.25, 67.49, 32.72, 66.05,
+        39.63, 40.94, 40.39, 40.85, 40.51, 53.19, 62.83, 63.64, 62.41, 61.19, 61.40, 70.40, 46.71,
+    ],
+    // 83o
+    [
+        12.32, 31.07, 30.61, 30.91, 30.33, 30.58, 22.61, 34.11, 34.24, 33.21, 34.04, 24.59, 37.75,
+        32.88, 11.94, 30.37, 30.62, 30.10, 30.73, 23.18, 33.98, 33.88, 33.89, 34.19, 24.52, 37.98,
+        32.82, 31.96, 12.43, 29.79, 29.69, 30.01, 23.44, 33.93, 33.90, 33.99, 34.18, 25.04, 37.90,
+        32.59, 32.26, 31.44, 11.91, 28.94, 29.33, 23.79, 33.21, 34.31, 33.64, 34.50, 25.18, 38.30,
+        31.56, 31.86, 31.37, 30.06, 12.07, 28.21, 24.59, 32.55, 33.02, 33.87, 34.13, 25.79, 38.13,
+        32.02, 31.92, 31.40, 30.52, 29.79, 11.45, 25.32, 31.86, 32.79, 33.35, 34.96, 27.30, 38.42,
+        23.21, 24.21, 24.31, 24.59, 25.18, 25.78, 6.75, 33.24, 36.81, 40.13, 43.25, 47.58, 50.00,
+        35.90, 35.69, 35.86, 34.66, 34.40, 33.53, 34.56, 27.79, 50.25, 51.75, 52.90, 64.80, 58.76,
+        35.52, 35.70, 35.84, 36.24, 34.60, 34.81, 39.05, 53.24, 28.80, 50.87, 52.27, 63.63, 57.96,
+        34.81, 35.46, 35.96, 35.69, 36.12, 35.51, 42.55, 54.84, 53.92, 29.24, 51.04, 62.08, 56.48,
+        35.23, 36.15, 36.02, 36.19, 35.93, 36.50, 45.83, 56.31, 56.02, 54.25, 30.18, 62.34, 56.74,
+        25.30, 25.84, 26.34, 26.38, 27.38, 28.32, 50.00, 68.60, 67.26, 65.46, 65.80, 32.26, 64.34,
+        39.69, 40.16, 40.09, 40.52, 40.47, 40.67, 52.86, 62.41, 61.45, 60.02, 59.86, 68.43, 46.15,
+    ],
+    // 73o
+    [
+        13.50, 32.21, 31.75, 31.95, 31.61, 31.59, 31.78, 23.77, 34.95, 34.10, 34.37, 25.57, 38.61,
+        33.85, 13.90, 31.78, 31.77, 31.37, 31.67, 31.55, 24.36, 35.12, 35.19, 35.19, 26.04, 39.76,
+        33.62, 33.10, 13.31, 30.84, 31.47, 31.53, 31.90, 25.18, 34.53, 35.03, 35.03, 26.09, 39.50,
+        33.60, 33.35, 32.84, 13.42, 30.81, 30.77, 30.90, 25.65, 35.29, 35.37, 35.19, 26.72, 39.73,
+        32.89, 32.82, 32.44, 31.91, 13.19, 30.53, 30.06, 25.98, 34.23, 35.16, 35.54, 27.42, 39.87,
+        33.53, 33.77, 32.62, 32.25, 31.40, 13.02, 30.06, 26.83, 33.95, 34.68, 36.05, 28.06, 39.81,
+        32.97, 33.48, 33.05, 33.01, 31.84, 30.89, 12.93, 26.41, 33.98, 34.21, 35.56, 29.74, 40.64,
+        24.68, 25.37, 25.98, 26.15, 27.12, 28.00, 27.71, 8.22, 36.82, 40.41, 43.70, 47.58, 51.02,
+        36.50, 36.61, 36.74, 37.22, 36.23, 35.58, 35.28, 39.13, 28.43, 49.96, 51.48, 62.69, 57.00,
+        35.56, 37.31, 36.49, 36.77, 36.92, 36.59, 36.43, 42.90, 52.71, 28.81, 50.53, 61.02, 55.67,
+        36.19, 37.34, 37.24, 37.28, 37.11, 37.89, 37.36, 46.02, 54.77, 53.55, 30.44, 61.35, 56.10,
+        26.49, 27.40, 27.30, 28.27, 28.74, 29.50, 31.40, 50.00, 66.44, 64.31, 64.86, 33.15, 63.97,
+        41.15, 41.80, 41.96, 42.06, 41.82, 42.22, 42.93, 53.81, 60.89, 59.46, 59.90, 67.70, 47.36,
+    ],
+    // 63o
+    [
+        15.26, 33.62, 33.39, 33.26, 32.97, 33.27, 33.07, 33.09, 25.26, 35.11, 35.47, 26.48, 39.25,
+        34.81, 15.07, 32.81, 32.33, 32.90, 33.37, 33.03, 32.70, 25.61, 35.73, 36.33, 27.20, 40.37,
+        35.20, 34.79, 14.98, 32.55, 32.09, 32.80, 32.92, 32.90, 26.23, 35.90, 36.24, 27.69, 39.89,
+        35.13, 34.30, 33.70, 14.79, 31.26, 32.30, 31.85, 32.76, 26.41, 35.57, 36.27, 27.60, 40.15,
+        34.50, 33.97, 33.95, 32.94, 14.73, 31.71, 31.68, 32.26, 27.20, 36.67, 36.40, 28.75, 40.40,
+        34.54, 34.61, 34.44, 33.74, 33.40, 14.74, 31.45, 31.37, 28.38, 36.06, 36.72, 29.96, 40.66,
+        34.46, 34.71, 34.45, 33.85, 33.41, 33.17, 14.94, 31.52, 28.83, 35.49, 36.58, 31.22, 41.28,
+        34.33, 34.51, 34.50, 34.52, 34.01, 33.28, 32.79, 13.54, 30.50, 36.26, 37.10, 32.19, 42.67,
+        26.43, 26.96, 27.20, 27.64, 28.33, 29.12, 29.25, 31.39, 10.07, 40.59, 43.89, 47.50, 50.84,
+        36.52, 37.86, 37.85, 37.57, 37.99, 37.44, 37.22, 38.00, 42.44, 29.36, 49.29, 58.74, 54.83,
+        37.01, 38.44, 38.14, 38.03, 37.98, 38.90, 38.42, 38.82, 46.22, 51.68, 30.09, 58.90, 55.03,
+        27.85, 28.50, 29.09, 28.98, 30.23, 31.33, 32.74, 33.56, 50.00, 62.27, 62.77, 34.14, 60.48,
+        41.40, 42.58, 41.95, 42.35, 42.50, 43.11, 43.18, 45.18, 53.79, 58.43, 58.45, 64.21, 47.07,
+    ],
+    // 53o
+    [
+        15.35, 34.74, 34.91, 34.37, 33.83, 34.43, 34.54, 34.15, 34.07, 25.19, 36.27, 26.62, 40.18,
+        36.35, 16.68, 34.26, 34.23, 33.72, 34.37, 34.67, 34.36, 34.23, 26.98, 37.68, 28.51, 41.74,
+        36.57, 36.18, 17.10, 33.62, 33.90, 33.95, 34.28, 34.60, 34.50, 27.91, 37.37, 28.71, 41.14,
+        36.34, 35.85, 35.43, 17.22, 32.98, 33.84, 33.96, 34.20, 34.56, 28.33, 37.04, 29.37, 41.45,
+        35.89, 35.72, 35.52, 34.59, 16.36, 33.40, 33.03, 33.96, 34.26, 29.20, 37.02, 29.93, 41.54,
+        36.59, 36.06, 35.45, 35.34, 34.63, 16.76, 33.24, 33.99, 33.89, 30.15, 38.21, 31.23, 41.97,
+        35.81, 36.06, 35.92, 35.85, 34.91, 34.59, 16.47, 33.63, 34.14, 30.57, 38.03, 32.90, 42.23,
+        35.89, 35.91, 36.15, 35.92, 35.55, 35.42, 34.84, 15.61, 34.31, 32.37, 38.74, 33.82, 44.17,
+        35.89, 36.29, 36.00, 35.90, 35.86, 36.02, 35.54, 35.61, 14.62, 34.38, 39.76, 35.87, 45.41,
+        25.74, 28.31, 28.84, 29.53, 30.20, 31.34, 31.23, 33.56, 35.92, 12.35, 43.92, 47.50, 50.80,
+        37.85, 39.20, 39.01, 39.03, 39.36, 39.94, 39.73, 40.13, 41.78, 46.41, 30.36, 56.20, 52.72,
+        27.98, 29.54, 30.29, 30.87, 31.33, 32.75, 34.54, 35.69, 37.73, 50.00, 59.82, 35.42, 57.54,
+        42.48, 43.16, 43.29, 43.06, 43.37, 43.97, 44.42, 46.52, 47.95, 53.72, 56.03, 61.22, 47.63,
+    ],
+    // 43o
+    [
+        15.01, 34.52, 34.54, 33.96, 33.89, 34.01, 33.51, 33.97, 34.13, 32.66, 25.09, 26.03, 39.43,
+        36.17, 16.87, 34.13, 33.99, 33.84, 33.93, 33.87, 34.30, 34.09, 33.73, 27.08, 28.01, 40.17,
+        35.67, 35.56, 16.32, 33.46, 33.19, 33.18, 33.75, 34.11, 34.51, 34.19, 27.55, 28.45, 41.07,
+        35.59, 35.32, 35.33, 16.37, 33.02, 33.23, 33.55, 34.25, 34.53, 33.69, 28.10, 29.14, 40.82,
+        35.83, 34.80, 34.71, 34.73, 16.63, 33.33, 33.37, 33.91, 33.86, 34.48, 28.96, 29.66, 40.79,
+        36.03, 35.15, 35.33, 34.71, 34.23, 16.26, 31.98, 33.39, 34.45, 34.41, 30.18, 30.54, 41.53,
+        35.31, 35.44, 35.26, 34.92, 34.81, 33.43, 15.71, 33.30, 33.87, 34.33, 30.28, 32.30, 42.11,
+        35.59, 35.82, 35.93, 35.83, 35.84, 34.83, 34.51, 15.30, 34.38, 35.17, 32.60, 33.56, 43.63,
+        35.73, 35.98, 35.90, 36.07, 36.13, 35.99, 35.34, 35.76, 14.02, 35.71, 35.07, 35.99, 45.58,
+        34.23, 35.49, 35.65, 35.76, 35.64, 36.11, 35.94, 36.14, 37.68, 12.81, 37.57, 38.42, 48.24,
+        26.28, 28.39, 28.65, 29.34, 30.62, 31.63, 31.38, 34.73, 36.34, 38.94, 12.39, 47.53, 50.63,
+        27.30, 29.38, 29.63, 30.03, 31.75, 32.51, 34.20, 35.14, 37.23, 40.18, 50.00, 35.09, 51.79,
+        41.89, 42.61, 42.85, 43.16, 43.28, 43.32, 44.38, 46.70, 47.96, 50.94, 53.62, 54.95, 47.08,
+    ],
+    // 33
+    [
+        18.26, 51.00, 50.38, 50.38, 50.01, 50.90, 50.60, 50.52, 51.72, 51.29, 51.10, 62.50, 65.65,
+        53.67, 18.49, 48.83, 48.92, 48.55, 50.14, 50.30, 51.14, 51.30, 51.90, 51.93, 63.62, 65.68,
+        52.67, 52.18, 18.88, 48.10, 47.74, 49.34, 49.70, 50.85, 50.94, 51.09, 51.67, 63.23, 65.18,
+        53.10, 51.85, 50.58, 18.62, 46.96, 47.45, 48.56, 50.21, 50.93, 51.57, 51.05, 63.24, 65.37,
+        52.08, 51.41, 50.41, 48.78, 18.41, 47.29, 47.51, 48.87, 50.30, 51.40, 51.44, 63.15, 64.96,
+        53.86, 52.51, 51.38, 50.52, 48.79, 18.85, 46.83, 47.82, 49.20, 50.89, 51.78, 63.53, 65.28,
+        53.01, 53.29, 52.08, 50.96, 49.69, 49.20, 18.51, 46.88, 48.38, 49.19, 50.79, 63.70, 65.42,
+        53.66, 53.05, 53.42, 52.13, 50.93, 50.23, 49.16, 18.61, 48.36, 49.70, 50.41, 62.56, 65.78,
+        54.70, 53.62, 53.13, 53.23, 52.16, 51.59, 50.42, 50.35, 18.45, 49.84, 50.40, 62.15, 65.84,
+        53.60, 54.04, 53.59, 54.08, 53.82, 52.90, 51.90, 52.02, 51.68, 18.22, 49.23, 60.94, 66.14,
+        53.92, 54.65, 54.31, 53.96, 53.72, 54.47, 53.31, 53.03, 52.51, 52.59, 18.90, 61.26, 66.68,
+        66.49, 67.92, 67.85, 67.27, 66.88, 67.28, 67.74, 66.85, 65.86, 64.58, 64.91, 50.00, 83.81,
+        68.60, 69.20, 68.99, 68.36, 68.39, 68.59, 69.11, 69.59, 69.70, 69.45, 70.03, 89.37, 80.40,
+    ],
+    // 32s
+    [
+        16.80, 35.56, 35.44, 35.04, 35.27, 35.44, 35.48, 35.34, 35.60, 34.08, 34.00, 27.52, 28.46,
+        37.69, 18.55, 35.21, 34.78, 34.95, 35.17, 35.95, 35.08, 35.54, 35.31, 35.27, 29.42, 30.31,
+        38.35, 37.61, 18.25, 34.56, 34.51, 35.24, 35.53, 35.46, 35.38, 35.25, 35.65, 30.09, 30.82,
+        37.73, 37.52, 36.75, 18.86, 34.68, 34.94, 34.76, 34.88, 35.65, 35.17, 35.93, 30.64, 31.31,
+        37.42, 37.55, 37.20, 36.72, 18.02, 34.86, 34.84, 35.12, 35.52, 35.87, 35.62, 31.39, 32.49,
+        38.28, 37.89, 37.17, 37.20, 37.33, 18.45, 34.98, 35.13, 35.46, 36.21, 36.23, 32.77, 33.42,
+        37.79, 38.24, 37.69, 37.21, 37.00, 36.98, 18.46, 34.02, 35.95, 36.37, 36.54, 34.19, 34.98,
+        37.57, 37.85, 37.86, 37.39, 37.62, 37.48, 36.60, 17.98, 36.07, 36.66, 36.99, 34.96, 36.66,
+        37.85, 37.67, 37.95, 38.45, 37.73, 38.06, 38.34, 37.54, 17.78, 37.76, 38.51, 38.01, 38.70,
+        36.39, 37.95, 37.53, 37.41, 38.02, 38.53, 38.96, 38.72, 40.21, 16.31, 40.03, 40.98, 41.52,
+        36.61, 37.93, 38.04, 38.10, 38.26, 39.07, 39.23, 39.27, 40.93, 42.64, 16.79, 45.87, 47.23,
+        28.32, 30.60, 30.97, 32.08, 32.64, 33.95, 35.66, 36.03, 39.52, 42.46, 48.21, 16.19, 50.00,
+        29.19, 31.48, 32.05, 32.60, 33.09, 34.67, 36.34, 38.62, 40.34, 43.45, 49.48, 52.54, 37.15,
+    ],
+    // A2o
+    [
+        6.84, 24.93, 24.73, 26.24, 27.46, 30.04, 32.45, 35.43, 39.25, 41.74, 44.07, 44.34, 47.55,
+        25.97, 28.82, 54.34, 53.89, 53.69, 55.35, 56.16, 55.93, 55.69, 56.81, 57.21, 57.93, 72.06,
+        26.40, 57.81, 28.69, 53.04, 52.43, 54.19, 54.48, 55.59, 55.42, 56.58, 57.27, 57.59, 71.73,
+        27.17, 57.40, 56.04, 28.94, 51.17, 52.87, 53.86, 54.05, 55.58, 56.65, 56.88, 57.33, 70.87,
+        28.88, 56.81, 55.94, 54.40, 28.66, 51.70, 52.46, 53.76, 54.02, 55.98, 56.29, 56.90, 70.99,
+        31.93, 58.60, 57.28, 55.67, 55.07, 29.47, 52.05, 52.91, 53.62, 55.50, 57.02, 57.20, 70.80,
+        34.18, 59.41, 58.21, 57.13, 55.55, 54.71, 29.54, 51.94, 52.40, 55.05, 56.34, 57.13, 70.74,
+        37.13, 59.16, 59.02, 57.80, 56.90, 55.59, 54.78, 29.33, 50.59, 53.35, 54.44, 55.92, 70.82,
+        41.67, 59.17, 58.83, 58.73, 57.36, 56.37, 55.89, 53.91, 30.00, 52.74, 53.95, 55.06, 69.24,
+        43.62, 60.63, 60.22, 59.68, 59.98, 58.89, 57.82, 56.23, 55.87, 29.58, 53.32, 54.81, 69.83,
+        46.48, 61.01, 60.35, 60.19, 59.92, 60.30, 59.20, 58.05, 56.61, 56.55, 30.51, 54.86, 69.99,
+        47.06, 61.51, 61.09, 60.82, 60.38, 60.37, 60.31, 58.85, 58.60, 57.52, 58.11, 31.40, 70.81,
+        50.00, 76.43, 75.85, 75.44, 75.26, 75.41, 75.01, 75.14, 73.27, 73.66, 74.13, 75.00, 33.91,
+    ],
+    // K2o
+    [
+        10.66, 21.48, 32.47, 32.44, 32.38, 33.84, 33.75, 33.81, 33.76, 32.33, 32.74, 33.30, 22.91,
+        22.59, 5.59, 23.96, 24.75, 26.40, 28.39, 31.22, 34.62, 38.27, 41.43, 43.93, 44.54, 47.55,
+        34.41, 24.88, 27.15, 52.07, 52.46, 53.69, 54.36, 55.42, 55.48, 56.04, 56.17, 56.44, 71.52,
+        33.81, 25.99, 55.44, 27.41, 50.27, 52.43, 53.98, 53.87, 55.54, 55.53, 55.88, 55.74, 70.69,
+        33.83, 26.97, 55.75, 53.98, 27.18, 51.31, 52.17, 52.56, 54.57, 55.48, 55.32, 56.12, 70.35,
+        35.77, 30.07, 57.06, 55.44, 54.70, 27.90, 51.12, 51.93, 53.15, 54.67, 55.87, 55.60, 69.88,
+        35.67, 33.15, 57.96, 56.87, 55.67, 54.57, 29.35, 51.35, 52.63, 53.84, 55.12, 55.91, 69.63,
+        35.48, 36.20, 58.70, 57.49, 56.47, 55.17, 54.31, 28.66, 51.12, 51.97, 53.63, 54.67, 69.75,
+        35.76, 40.20, 58.88, 58.93, 57.58, 56.48, 55.47, 54.01, 29.43, 51.89, 53.05, 54.12, 69.20,
+        34.78, 43.78, 58.99, 59.13, 58.57, 57.82, 57.14, 55.36, 54.73, 29.50, 52.04, 53.11, 67.75,
+        34.60, 46.35, 59.58, 58.98, 59.37, 59.26, 58.22, 56.95, 56.66, 55.66, 29.91, 54.00, 68.17,
+        34.50, 46.84, 59.98, 59.88, 59.45, 59.06, 59.84, 58.20, 57.42, 56.84, 57.39, 30.80, 68.52,
+        23.57, 50.00, 74.98, 75.28, 74.89, 74.39, 74.29, 74.16, 73.13, 71.52, 71.80, 72.66, 33.16,
+    ],
+    // Q2o
+    [
+        11.45, 29.61, 22.23, 33.06, 32.40, 34.34, 33.88, 33.87, 33.97, 32.80, 33.05, 33.34, 23.05,
+        31.56, 10.98, 22.20, 31.68, 31.64, 33.04, 34.29, 34.05, 33.87, 33.53, 33.76, 33.94, 23.68,
+        22.64, 23.07, 5.70, 24.72, 25.81, 28.01, 30.75, 34.09, 38.05, 41.40, 43.93, 44.29, 47.50,
+        34.12, 33.30, 26.03, 27.19, 51.09, 52.41, 53.58, 54.61, 55.78, 56.07, 56.12, 56.47, 70.56,
+        34.40, 33.59, 27.12, 54.18, 27.13, 51.23, 52.57, 53.23, 54.60, 55.42, 55.71, 56.08, 70.21,
+        35.84, 35.05, 29.38, 55.72, 54.14, 28.07, 51.29, 52.36, 53.52, 54.58, 55.90, 55.64, 70.18,
+        35.55, 36.53, 32.54, 56.87, 55.38, 54.17, 28.35, 51.69, 52.47, 53.49, 54.55, 55.87, 69.89,
+        35.57, 35.82, 36.31, 57.93, 56.93, 55.06, 54.23, 28.70, 51.50, 52.78, 53.76, 54.91, 69.87,
+        35.93, 36.03, 40.20, 59.07, 57.90, 56.56, 55.72, 54.21, 29.69, 51.92, 53.02, 53.83, 68.31,
+        34.95, 35.58, 43.64, 59.49, 59.19, 58.20, 56.48, 55.82, 54.55, 29.88, 52.12, 53.50, 67.12,
+        34.73, 35.41, 46.61, 59.37, 58.94, 59.58, 58.48, 56.79, 55.72, 55.25, 30.12, 53.48, 68.02,
+        35.22, 35.93, 46.87, 60.05, 59.87, 59.61, 59.91, 58.04, 58.05, 56.71, 57.15, 31.01, 67.95,
+        24.15, 25.02, 50.00, 74.76, 74.64, 73.79, 73.85, 74.11, 72.19, 71.33, 71.40, 72.29, 33.24,
+    ],
+    // J2o
+    [
+        11.51, 30.16, 29.88, 21.95, 33.03, 33.88, 33.88, 33.86, 34.40, 33.31, 33.53, 33.46, 23.40,
+        31.64, 11.66, 29.00, 22.18, 31.83, 33.19, 34.45, 33.96, 34.29, 33.87, 33.73, 34.34, 24.15,
+        31.19, 30.42, 11.15, 22.48, 30.85, 32.26, 33.45, 34.31, 34.38, 33.70, 33.86, 34.37, 23.77,
+        22.53, 23.29, 23.00, 5.95, 26.04, 28.25, 30.50, 34.00, 38.65, 40.90, 44.19, 43.86, 47.42,
+        33.99, 33.64, 32.44, 26.69, 26.62, 51.20, 52.51, 53.10, 54.47, 55.96, 55.45, 56.14, 69.74,
+        36.02, 35.03, 33.83, 29.31, 53.80, 27.67, 50.79, 52.25, 52.98, 54.53, 55.97, 56.17, 69.80,
+        36.28, 36.54, 34.90, 32.31, 55.17, 54.28, 28.44, 50.97, 51.84, 53.52, 55.05, 55.99, 69.23,
+        35.87, 35.76, 35.82, 35.81, 56.92, 55.41, 53.92, 29.00, 51.50, 52.32, 53.10, 54.92, 69.18,
+        36.32, 36.39, 36.02, 40.26, 57.81, 56.57, 55.69, 54.30, 30.04, 51.87, 52.85, 54.21, 68.26,
+        35.32, 35.79, 35.88, 43.99, 59.24, 58.32, 56.78, 55.31, 55.42, 30.56, 52.09, 53.12, 66.67,
+        35.48, 36.07, 35.81, 46.51, 59.37, 59.11, 58.39, 56.81, 56.64, 54.73, 30.55, 53.77, 66.84,
+        35.11, 35.95, 36.17, 46.83, 59.37, 59.15, 59.48, 57.94, 57.65, 56.94, 56.84, 31.64, 67.40,
+        24.56, 24.72, 25.24, 50.00, 74.11, 74.21, 73.54, 73.13, 72.30, 70.27, 71.28, 71.25, 33.33,
+    ],
+    // T2o
+    [
+        12.16, 30.36, 30.07, 29.83, 22.05, 34.14, 33.89, 33.90, 34.57, 33.71, 33.54, 33.98, 24.02,
+        31.93, 12.04, 29.89, 29.12, 22.75, 33.52, 34.56, 34.41, 34.47, 34.42, 34.46, 34.40, 24.35,
+        31.52, 30.82, 11.88, 28.24, 22.53, 32.55, 33.47, 34.65, 33.92, 34.26, 34.06, 34.46, 24.73,
+        30.93, 30.64, 29.83, 11.49, 22.82, 31.49, 32.25, 33.03, 34.30, 34.03, 34.26, 34.09, 24.81,
+        22.64, 23.66, 23.78, 23.58, 6.20, 27.67, 30.67, 33.64, 37.59, 41.84, 43.69, 44.02, 47.51,
+        36.06, 35.12, 34.00, 33.19, 29.33, 27.09, 51.14, 52.33, 53.19, 54.99, 56.39, 56.10, 69.10,
+        35.80, 35.82, 34.93, 34.25, 31.84, 53.93, 27.77, 50.75, 52.28, 53.32, 54.83, 56.20, 68.89,
+        35.74, 36.31, 36.38, 34.75, 35.45, 54.92, 54.28, 28.67, 51.02, 51.93, 53.26, 54.89, 68.91,
+        36.25, 36.37, 36.07, 36.41, 39.76, 56.73, 55.72, 53.79, 29.64, 52.13, 52.96, 54.11, 67.41,
+        35.68, 36.21, 36.47, 36.09, 44.14, 58.16, 56.96, 55.01, 54.80, 30.41, 52.26, 53.17, 65.92,
+        35.58, 36.15, 36.38, 36.01, 46.38, 59.47, 58.65, 56.38, 56.21, 55.40, 30.82, 53.77, 66.46,
+        35.56, 36.42, 36.20, 35.94, 46.93, 59.49, 59.53, 58.18, 57.50, 56.63, 56.72, 31.61, 66.91,
+        24.74, 25.11, 25.36, 25.89, 50.00, 73.25, 73.02, 72.78, 71.38, 69.80, 69.63, 70.65, 33.07,
+    ],
+    // 92o
+    [
+        11.83, 30.37, 30.57, 30.50, 30.04, 22.49, 34.22, 33.64, 33.91, 33.37, 33.78, 33.82, 23.85,
+        32.37, 12.26, 30.30, 30.38, 29.75, 22.72, 34.42, 34.16, 34.54, 34.10, 34.78, 35.21, 24.33,
+        32.24, 32.03, 12.07, 29.11, 28.73, 23.03, 33.28, 33.82, 34.50, 34.34, 34.42, 34.59, 24.69,
+        31.82, 31.57, 30.68, 11.77, 27.87, 23.44, 32.46, 33.17, 34.32, 34.10, 34.42, 34.83, 25.26,
+        31.57, 31.50, 30.31, 29.34, 11.36, 24.04, 31.02, 32.49, 33.49, 34.51, 34.68, 34.56, 25.48,
+        23.07, 23.74, 24.24, 24.00, 24.89, 6.43, 30.43, 33.31, 36.85, 41.13, 44.50, 44.53, 47.33,
+        35.66, 35.83, 35.26, 33.99, 32.73, 31.68, 27.57, 50.26, 51.86, 53.33, 54.50, 55.74, 67.72,
+        35.36, 35.69, 36.32, 34.64, 33.96, 34.85, 53.58, 28.28, 50.30, 51.78, 53.19, 54.36, 68.05,
+        36.32, 36.32, 35.96, 36.75, 35.17, 39.10, 54.83, 53.27, 29.11, 51.28, 52.24, 53.65, 66.20,
+        35.00, 36.25, 35.92, 35.46, 35.83, 43.14, 56.66, 54.57, 54.45, 29.89, 51.94, 52.99, 64.67,
+        35.46, 36.85, 36.26, 36.62, 36.10, 46.48, 58.06, 56.18, 55.73, 55.47, 30.89, 53.42, 65.41,
+        35.22, 36.34, 36.50, 36.50, 36.22, 46.81, 59.33, 57.78, 56.89, 56.03, 56.68, 31.41, 65.33,
+        24.59, 25.61, 26.21, 25.79, 26.75, 50.00, 71.94, 71.94, 70.39, 68.84, 69.12, 69.22, 33.63,
+    ],
+    // 82o
+    [
+        12.39, 31.13, 30.89, 30.72, 30.43, 30.49, 22.73, 33.73, 34.28, 33.17, 33.54, 34.25, 24.26,
+        32.50, 12.07, 30.18, 30.96, 30.10, 30.30, 23.08, 34.04, 34.06, 33.82, 34.56, 34.40, 24.73,
+        32.56, 32.04, 12.14, 29.69, 29.26, 29.57, 23.64, 34.33, 34.16, 34.21, 34.57, 34.77, 25.05,
+        32.06, 31.94, 31.81, 12.00, 29.04, 28.99, 23.77, 33.07, 34.58, 34.06, 34.35, 34.73, 25.52,
+        32.20, 31.78, 30.82, 29.72, 11.49, 28.29, 24.13, 31.91, 33.35, 34.13, 34.23, 34.74, 25.68,
+        32.11, 31.87, 31.35, 30.73, 29.65, 11.46, 25.15, 31.55, 32.75, 33.89, 34.86, 34.87, 26.89,
+        23.14, 23.99, 24.19, 24.64, 25.36, 26.03, 6.81, 33.19, 36.71, 39.98, 43.42, 44.52, 47.60,
+        35.39, 35.23, 35.70, 34.99, 34.11, 33.54, 34.85, 27.21, 49.36, 50.90, 52.50, 53.47, 66.26,
+        36.08, 35.84, 36.19, 36.34, 35.63, 34.45, 38.97, 52.44, 28.53, 50.37, 51.85, 52.84, 64.70,
+        35.14, 35.92, 35.90, 36.03, 36.25, 35.56, 42.42, 53.82, 53.32, 29.20, 50.47, 52.10, 63.24,
+        35.52, 36.21, 36.46, 36.16, 35.89, 36.74, 46.16, 55.30, 54.72, 54.03, 30.28, 52.86, 63.43,
+        36.32, 36.69, 36.58, 36.50, 36.40, 37.17, 47.14, 57.07, 56.82, 55.58, 55.62, 30.89, 63.66,
+        24.99, 25.71, 26.15, 26.46, 26.98, 28.06, 50.00, 70.05, 68.41, 66.97, 67.14, 67.63, 33.42,
+    ],
+    // 72o
+    [
+        11.93, 31.08, 30.66, 30.49, 30.34, 30.39, 30.29, 22.04, 33.57, 32.24, 32.87, 33.29, 23.91,
+        32.77, 12.15, 30.47, 30.27, 30.44, 30.61, 30.32, 22.92, 33.71, 33.66, 34.27, 34.25, 24.83,
+        31.99, 32.00, 11.76, 29.42, 29.64, 30.23, 30.01, 23.34, 33.37, 33.64, 33.87, 34.53, 24.71,
+        32.22, 31.92, 31.08, 12.02, 29.39, 29.43, 29.56, 23.68, 33.92, 33.61, 33.83, 34.66, 25.56,
+        31.94, 31.73, 31.37, 30.64, 11.68, 28.81, 29.00, 24.19, 33.18, 34.23, 33.92, 34.45, 26.13,
+        32.09, 31.88, 31.43, 30.70, 30.17, 11.56, 28.33, 25.04, 32.72, 33.43, 34.77, 34.71, 26.84,
+        31.60, 32.33, 31.78, 30.93, 30.77, 29.86, 11.08, 26.51, 32.14, 32.89, 34.58, 36.04, 29.00,
+        22.83, 23.88, 24.19, 24.54, 24.88, 26.38, 27.08, 6.34, 36.51, 40.08, 42.84, 43.56, 47.48,
+        35.40, 35.16, 35.18, 35.24, 34.62, 34.06, 34.12, 38.17, 27.48, 48.57, 49.95, 52.04, 62.49,
+        34.51, 35.23, 35.63, 35.45, 35.82, 34.95, 34.79, 41.80, 51.71, 28.45, 48.64, 50.92, 61.34,
+        34.65, 36.19, 35.64, 35.45, 35.95, 36.73, 35.84, 44.78, 53.47, 52.14, 29.37, 50.73, 61.23,
+        35.33, 36.21, 35.91, 36.08, 35.78, 36.36, 37.59, 46.19, 54.82, 53.48, 53.30, 30.41, 61.38,
+        24.86, 25.84, 25.89, 26.87, 27.22, 28.06, 29.95, 50.00, 66.56, 64.13, 64.74, 65.03, 32.54,
+    ],
+    // 62o
+    [
+        13.57, 32.26, 32.23, 31.97, 31.52, 31.70, 31.97, 31.02, 23.61, 33.53, 34.24, 33.74, 24.85,
+        33.69, 13.50, 31.75, 31.27, 31.61, 31.68, 31.50, 31.56, 24.06, 34.70, 34.78, 35.19, 25.75,
+        33.96, 33.17, 13.27, 30.82, 30.87, 31.31, 31.35, 31.69, 24.68, 34.52, 34.79, 35.21, 25.91,
+        33.28, 32.47, 32.34, 12.97, 29.99, 30.55, 30.95, 31.25, 25.01, 34.26, 34.69, 35.26, 26.59,
+        33.28, 32.78, 32.25, 31.58, 13.16, 30.49, 30.67, 30.61, 25.84, 35.35, 34.45, 35.51, 27.25,
+        33.68, 32.97, 32.86, 31.96, 31.85, 13.00, 29.99, 30.66, 26.62, 34.62, 35.50, 35.65, 28.52,
+        33.14, 33.17, 33.10, 32.46, 31.92, 31.31, 12.79, 30.52, 28.27, 34.13, 35.15, 36.96, 29.84,
+        32.84, 33.22, 33.22, 32.29, 32.07, 32.04, 31.62, 12.67, 28.34, 34.72, 35.41, 37.36, 32.23,
+        24.59, 25.11, 25.37, 25.90, 26.49, 27.76, 29.24, 29.71, 8.18, 39.85, 42.88, 43.80, 47.37,
+        35.58, 36.35, 36.56, 36.39, 36.86, 36.12, 36.06, 36.09, 42.05, 28.17, 47.72, 48.86, 59.20,
+        35.42, 36.67, 37.05, 36.39, 37.16, 37.18, 37.19, 37.92, 44.70, 50.20, 29.60, 49.49, 59.11,
+        36.30, 37.36, 37.17, 36.97, 36.99, 37.59, 38.55, 39.11, 46.21, 52.05, 52.04, 30.30, 59.66,
+        26.73, 26.87, 27.81, 27.70, 28.62, 29.61, 31.59, 33.44, 50.00, 62.83, 62.50, 62.67, 33.33,
+    ],
+    // 52o
+    [
+        13.88, 33.14, 33.15, 33.01, 32.88, 33.89, 32.60, 32.86, 32.78, 23.30, 35.26, 35.23, 25.15,
+        34.71, 15.15, 33.13, 32.77, 32.39, 32.63, 32.97, 32.74, 33.30, 25.44, 36.03, 36.10, 27.21,
+        34.62, 34.65, 15.15, 32.79, 32.10, 32.66, 32.92, 33.03, 32.96, 26.08, 36.24, 36.00, 27.53,
+        34.82, 34.18, 34.43, 15.46, 31.84, 32.24, 32.87, 32.85, 33.11, 26.70, 36.37, 36.25, 28.26,
+        34.46, 34.33, 33.42, 33.29, 14.86, 31.92, 32.03, 32.65, 32.70, 27.34, 36.17, 36.50, 28.59,
+        34.60, 35.01, 34.68, 34.18, 33.11, 14.87, 32.06, 32.28, 32.90, 28.82, 37.14, 37.48, 30.11,
+        34.68, 34.77, 34.54, 34.34, 33.99, 33.51, 14.66, 32.45, 32.47, 30.02, 36.90, 37.77, 31.89,
+        34.32, 34.48, 34.72, 34.48, 33.93, 33.83, 33.87, 14.78, 32.86, 30.79, 36.79, 38.43, 33.51,
+        34.58, 34.21, 34.76, 34.80, 34.22, 34.63, 34.01, 34.24, 13.77, 33.64, 38.34, 39.81, 35.85,
+        24.45, 26.49, 27.08, 27.87, 28.30, 29.75, 31.29, 32.06, 34.92, 10.46, 42.85, 43.85, 47.58,
+        36.44, 37.82, 38.37, 37.68, 37.73, 39.05, 38.56, 38.94, 40.55, 45.44, 29.58, 46.83, 56.78,
+        36.63, 38.54, 38.46, 38.48, 38.46, 38.81, 39.98, 40.54, 41.57, 46.28, 49.06, 30.55, 56.55,
+        26.34, 28.48, 28.67, 29.73, 30.20, 31.16, 33.03, 35.87, 37.17, 50.00, 59.55, 59.97, 34.69,
+    ],
+    // 42o
+    [
+        13.29, 32.59, 32.63, 32.63, 32.69, 32.87, 32.98, 32.60, 32.60, 31.02, 23.87, 34.83, 24.65,
+        34.69, 14.70, 32.75, 32.24, 32.07, 32.52, 33.13, 32.70, 32.58, 32.42, 25.49, 35.96, 26.77,
+        34.59, 33.90, 14.76, 31.94, 31.82, 32.08, 32.91, 32.80, 32.82, 32.58, 26.44, 36.40, 27.19,
+        34.37, 34.17, 33.68, 14.71, 31.62, 31.99, 32.37, 32.34, 32.85, 32.79, 26.38, 36.38, 27.63,
+        34.03, 34.09, 33.85, 33.04, 14.98, 31.89, 32.37, 32.35, 32.65, 32.51, 27.55, 36.43, 28.78,
+        34.87, 34.29, 33.29, 33.32, 33.35, 14.44, 31.72, 32.42, 32.81, 33.23, 28.72, 36.49, 29.44,
+        34.13, 34.64, 34.26, 33.90, 34.03, 33.90, 14.80, 32.23, 32.68, 33.17, 30.13, 38.13, 31.44,
+        34.10, 34.37, 34.83, 33.44, 33.90, 34.15, 33.99, 14.60, 32.93, 33.61, 30.92, 38.22, 33.73,
+        34.03, 34.43, 34.16, 34.36, 34.16, 34.56, 34.17, 35.02, 13.76, 34.83, 33.98, 39.48, 35.41,
+        32.79, 33.76, 33.82, 34.38, 34.81, 34.76, 34.54, 35.67, 36.17, 12.31, 37.00, 42.13, 38.10,
+        24.42, 26.63, 27.11, 27.52, 28.13, 29.70, 31.62, 32.35, 35.51, 38.22, 10.59, 43.98, 47.47,
+        36.40, 37.56, 37.74, 38.07, 38.43, 38.60, 40.14, 40.10, 41.55, 43.97, 46.38, 29.97, 50.52,
+        25.87, 28.20, 28.60, 28.72, 30.37, 30.88, 32.86, 35.26, 37.50, 40.45, 50.00, 53.26, 34.14,
+    ],
+    // 32o
+    [
+        12.97, 32.25, 32.36, 32.44, 32.12, 32.61, 32.42, 31.84, 32.22, 31.25, 31.04, 23.55, 24.16,
+        34.24, 14.25, 32.27, 31.90, 31.65, 32.49, 32.34, 32.22, 32.48, 32.23, 32.83, 25.56, 26.39,
+        34.44, 34.03, 14.51, 31.44, 31.60, 32.01, 32.00, 32.37, 32.56, 32.28, 32.54, 25.82, 26.82,
+        34.11, 33.50, 32.94, 14.40, 31.29, 31.66, 31.76, 31.67, 32.74, 32.50, 32.85, 26.28, 27.19,
+        34.16, 33.60, 33.05, 32.98, 14.38, 32.26, 31.59, 31.95, 32.54, 32.51, 32.99, 27.48, 27.82,
+        34.04, 34.24, 33.68, 33.33, 33.25, 14.41, 31.72, 31.96, 32.86, 33.11, 33.52, 28.38, 29.53,
+        34.00, 34.25, 33.91, 33.37, 33.49, 33.73, 14.20, 30.99, 32.57, 33.14, 34.00, 30.45, 30.82,
+        33.94, 34.07, 33.79, 33.74, 33.86, 33.44, 32.62, 13.91, 33.23, 33.17, 33.88, 31.11, 33.16,
+        34.35, 34.01, 34.37, 34.28, 33.89, 34.70, 34.28, 34.33, 13.49, 35.13, 35.65, 34.21, 35.46,
+        32.10, 33.71, 33.84, 34.23, 34.60, 34.48, 35.38, 35.08, 36.46, 11.89, 37.51, 37.64, 37.84,
+        32.73, 34.00, 33.87, 34.11, 34.42, 34.98, 35.72, 35.71, 37.62, 39.48, 12.78, 43.09, 44.31,
+        24.13, 26.02, 26.94, 27.15, 28.54, 29.60, 31.57, 32.30, 35.79, 38.78, 45.05, 10.63, 47.46,
+        25.00, 27.34, 27.71, 28.75, 29.35, 30.78, 32.37, 34.97, 37.33, 40.03, 46.74, 50.00, 33.00,
+    ],
+    // 22
+    [
+        17.73, 50.23, 49.73, 49.65, 48.93, 50.77, 50.37, 49.80, 50.61, 49.97, 50.17, 50.44, 62.33,
+        52.49, 18.40, 48.46, 48.65, 47.80, 49.34, 49.87, 49.68, 50.48, 50.50, 50.32, 51.30, 63.45,
+        52.30, 51.50, 18.18, 47.50, 47.21, 48.71, 49.73, 49.65, 50.06, 50.32, 50.69, 50.89, 63.00,
+        51.93, 50.96, 50.19, 18.14, 46.41, 47.64, 47.95, 48.43, 49.78, 50.02, 50.57, 51.13, 63.18,
+        51.33, 50.75, 49.47, 48.46, 17.92, 45.98, 47.30, 48.44, 49.21, 50.69, 50.53, 50.75, 62.64,
+        52.66, 51.77, 50.73, 49.77, 48.35, 18.01, 46.75, 47.25, 48.71, 49.64, 50.92, 50.61, 62.65,
+        52.48, 52.82, 50.94, 50.81, 49.65, 48.66, 18.29, 46.37, 47.86, 49.30, 49.97, 51.41, 63.04,
+        52.62, 52.67, 52.14, 51.03, 49.75, 49.92, 48.84, 17.98, 46.66, 47.71, 49.33, 50.13, 63.79,
+        53.24, 52.08, 52.49, 52.70, 51.38, 51.05, 50.21, 48.99, 18.31, 47.80, 49.02, 50.18, 63.07,
+        52.45, 52.76, 52.97, 52.54, 52.65, 52.54, 51.44, 50.62, 49.82, 18.48, 48.92, 49.54, 61.65,
+        52.31, 53.13, 53.17, 53.34, 52.61, 53.77, 53.13, 51.56, 51.85, 50.92, 18.69, 50.29, 62.11,
+        53.08, 53.82, 53.57, 53.78, 53.26, 53.29, 53.85, 52.64, 52.93, 52.37, 52.92, 19.60, 62.85,
+        66.09, 66.84, 66.76, 66.67, 66.93, 66.37, 66.58, 67.46, 66.67, 65.31, 65.86, 67.00, 50.00,
+    ],
+];
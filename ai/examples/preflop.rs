@@ -0,0 +1,130 @@
+//! Generates the preflop equity table of the 169 starting hands, run with
+//! `cargo run --release --example preflop > data/preflop.rs`
+
+extern crate rand;
+extern crate tendeuce_ai;
+
+use std::env;
+use std::io::{self, Write};
+
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+use tendeuce_ai::util::cardset::*;
+use tendeuce_ai::util::evaluator::*;
+use tendeuce_ai::util::preflop::*;
+
+const SEED: u64 = 0x169;
+
+/// Deals the first n cards of the deck at random, moving them to the front
+fn deal(deck: &mut [usize], n: usize, rng: &mut StdRng) {
+    for i in 0..n {
+        let j = rng.gen_range(i..deck.len());
+        deck.swap(i, j);
+    }
+}
+
+/// The share of the pot the first hand wins on one board
+fn share(board: CardSet, holes: &[CardSet]) -> f64 {
+    let board = Partial::from(board);
+    let ranks: Vec<usize> = holes.iter().map(|&hole| board.with_set(hole).eval()).collect();
+    let best = *ranks.iter().min().unwrap();
+    if ranks[0] == best {
+        1.0 / ranks.iter().filter(|&&rank| rank == best).count() as f64
+    } else {
+        0.0
+    }
+}
+
+/// The equity of a starting hand against random hole cards, the combos of the hand taken in turn
+fn versus_random(hand: StartingHand, opponents: usize, samples: usize, rng: &mut StdRng) -> f64 {
+    let combos = hand.combos();
+    let mut total = 0.0;
+    for i in 0..samples {
+        let hole: CardSet = combos[i % combos.len()].iter().collect();
+        let mut deck: Vec<usize> = (CardSet::full() - hole).ids().collect();
+        deal(&mut deck, 2 * opponents + 5, rng);
+        let mut holes = vec![hole];
+        holes.extend(deck[5..5 + 2 * opponents].chunks(2).map(|cards| cards.iter().cloned().collect::<CardSet>()));
+        total += share(deck[..5].iter().cloned().collect(), &holes);
+    }
+    100.0 * total / samples as f64
+}
+
+/// The equity of one starting hand against another, the pairs of combos that do not share a card taken in turn
+fn head_to_head(hand: StartingHand, other: StartingHand, samples: usize, rng: &mut StdRng) -> f64 {
+    let mut pairs: Vec<[CardSet; 2]> = Vec::new();
+    for first in hand.combos() {
+        for second in other.combos() {
+            let (first, second): (CardSet, CardSet) = (first.iter().collect(), second.iter().collect());
+            if (first & second).is_empty() {
+                pairs.push([first, second]);
+            }
+        }
+    }
+    let mut total = 0.0;
+    for i in 0..samples {
+        let holes = pairs[i % pairs.len()];
+        let mut deck: Vec<usize> = (CardSet::full() - holes[0] - holes[1]).ids().collect();
+        deal(&mut deck, 5, rng);
+        total += share(deck[..5].iter().cloned().collect(), &holes);
+    }
+    100.0 * total / samples as f64
+}
+
+/// Writes a row of the table, 13 values to a line
+fn row(out: &mut dyn Write, hand: StartingHand, values: &[f64]) -> io::Result<()> {
+    writeln!(out, "    // {}", hand)?;
+    writeln!(out, "    [")?;
+    for line in values.chunks(13) {
+        let line: Vec<String> = line.iter().map(|value| format!("{:.2}", value)).collect();
+        writeln!(out, "        {},", line.join(", "))?;
+    }
+    writeln!(out, "    ],")
+}
+
+fn main() -> io::Result<()> {
+    let samples: usize = env::args().nth(1).map_or(50_000, |arg| arg.parse().expect("samples must be a number"));
+    let mut rng = StdRng::seed_from_u64(SEED);
+    let hands: Vec<StartingHand> = StartingHand::all().collect();
+
+    let mut random = vec![[0.0; 9]; hands.len()];
+    for (i, &hand) in hands.iter().enumerate() {
+        eprintln!("{} against random hands", hand);
+        for opponents in 1..=9 {
+            random[i][opponents - 1] = versus_random(hand, opponents, samples, &mut rng);
+        }
+    }
+
+    // A hand against itself is even, and the second of a pair of hands wins what the first does not
+    let mut against = vec![vec![50.0; hands.len()]; hands.len()];
+    for (i, &hand) in hands.iter().enumerate() {
+        eprintln!("{} head to head", hand);
+        for (j, &other) in hands.iter().enumerate().skip(i + 1) {
+            let equity = (head_to_head(hand, other, samples, &mut rng) * 100.0).round() / 100.0;
+            against[i][j] = equity;
+            against[j][i] = 100.0 - equity;
+        }
+    }
+
+    let stdout = io::stdout();
+    let out = &mut stdout.lock();
+    writeln!(out, "// Preflop equity of the 169 starting hands, in the order of StartingHand::index.")?;
+    writeln!(out, "// Generated by `cargo run --release --example preflop > data/preflop.rs`,")?;
+    writeln!(out, "// {} random boards to an entry, seed {:#x}", samples, SEED)?;
+    writeln!(out)?;
+    writeln!(out, "/// The equity of each starting hand against 1..9 random hands, in percent")?;
+    writeln!(out, "#[allow(clippy::approx_constant)]")?;
+    writeln!(out, "static VERSUS_RANDOM: [[f32; 9]; {}] = [", hands.len())?;
+    for (&hand, values) in hands.iter().zip(random.iter()) {
+        row(out, hand, values)?;
+    }
+    writeln!(out, "];")?;
+    writeln!(out)?;
+    writeln!(out, "/// The equity of each starting hand against each other, in percent")?;
+    writeln!(out, "#[allow(clippy::approx_constant)]")?;
+    writeln!(out, "static HEAD_TO_HEAD: [[f32; {0}]; {0}] = [", hands.len())?;
+    for (&hand, values) in hands.iter().zip(against.iter()) {
+        row(out, hand, values)?;
+    }
+    writeln!(out, "];")
+}
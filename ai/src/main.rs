@@ -1,10 +1,30 @@
+extern crate tendeuce_ai;
+
 use std::*;
 use std::io::BufReader;
 use std::io::prelude::*;
 use std::net::TcpStream;
-use std::thread::*;
 
-mod util;
+use tendeuce_ai::util::card::*;
+
+/// Rewrites the cards of a protocol line in the given notation
+/// # Param line: the line, without its line break
+/// # Param notation: the notation to write the cards in
+/// # Returns: the line, other words left as they are
+fn notate(line: &str, notation: Notation) -> String {
+    let words: Vec<&str> = line.split(' ').collect();
+    // The words before the cards: the command, and the player name and money of some
+    let skip = match words[0] {
+        "CARDS" | "CARD" => 1,
+        "ENDCARDS" => 2,
+        "WON" => 3,
+        _ => return line.to_string(),
+    };
+    words.iter().enumerate().map(|(i, word)| match word.parse::<Card>() {
+        Ok(card) if i >= skip => card.notation(notation).to_string(),
+        _ => word.to_string(),
+    }).collect::<Vec<String>>().join(" ")
+}
 
 fn main() {
     print!("IP: ");
@@ -12,21 +32,49 @@ fn main() {
     let mut ip = String::new();
     io::stdin().read_line(&mut ip).unwrap();
     if ip == "\n" { ip = "127.0.0.1:9001".to_string(); }
+    print!("Notation, 0 = unicode, 1 = ascii <default = 0>: ");
+    io::stdout().flush().unwrap();
+    let mut notation = String::new();
+    io::stdin().read_line(&mut notation).unwrap();
+    let notation = Notation::from_id(notation.trim().parse().unwrap_or(0));
     let mut stream = TcpStream::connect(ip.trim()).unwrap();
     {
         let stream_clone = stream.try_clone().unwrap();
-        thread::spawn(|| {
+        thread::spawn(move || {
             let mut reader = BufReader::new(stream_clone);
             loop {
                 let mut line = String::new();
                 reader.read_line(&mut line).unwrap();
-                print!("{}", line);
+                println!("{}", notate(line.trim_end(), notation));
             }
         });
     }
     loop {
         let mut line = String::new();
         io::stdin().read_line(&mut line).unwrap();
-        stream.write_all(line.as_bytes()).unwrap();
+        // Cards can be typed in either notation, and are sent in the one the server uses
+        let line = match line.trim_end().split(' ').collect::<Vec<&str>>()[..] {
+            ["DRAW", ref cards @ ..] => {
+                match cards.iter().map(|card| card.parse::<Card>()).collect::<Result<Vec<Card>, ParseCardError>>() {
+                    Ok(cards) => format!("DRAW {}", cards.iter().map(|card| card.notation(notation).to_string()).collect::<Vec<String>>().join(" ")),
+                    Err(err) => {
+                        println!("{}", err);
+                        continue;
+                    }
+                }
+            }
+            _ => line.trim_end().to_string(),
+        };
+        stream.write_all(format!("{}\n", line).as_bytes()).unwrap();
     }
 }
+
+#[test]
+fn test_notate() {
+    assert_eq!(notate("CARDS A♠ T♥", Notation::Ascii), "CARDS As Th");
+    assert_eq!(notate("CARD 2c", Notation::Unicode), "CARD 2♣");
+    assert_eq!(notate("ENDCARDS As K♦ Q♦", Notation::Ascii), "ENDCARDS As Kd Qd");
+    assert_eq!(notate("WON Ks 100 A♠ A♥ K♦ K♣ 2♠ Two pair, Aces and Kings", Notation::Ascii),
+               "WON Ks 100 As Ah Kd Kc 2s Two pair, Aces and Kings");
+    assert_eq!(notate("MOVE Ah", Notation::Unicode), "MOVE Ah");
+}
@@ -0,0 +1,182 @@
+use super::card::*;
+use super::cardset::*;
+use super::combinatorics::*;
+use super::evaluator::*;
+
+/// Game is the way the five cards of a hand are picked and ranked
+#[derive(Eq, PartialEq)]
+#[derive(Copy, Clone, Debug)]
+pub enum Game {
+    /// Any five of the hole and board cards, the best high hand
+    Holdem,
+    /// Exactly two of the hole cards and three of the board cards
+    Omaha,
+    /// Any five, ranked for a short (6+) deck
+    ShortDeck,
+    /// Any five, the best deuce-to-seven low
+    DeuceSeven,
+}
+
+impl Game {
+    /// The rank of five cards, compare ranks with `order`
+    pub fn rank(&self, cards: CardSet) -> usize {
+        match *self {
+            Game::Holdem | Game::Omaha => eval_set(cards),
+            Game::ShortDeck => eval_short_deck(cards),
+            Game::DeuceSeven => eval_deuce_seven(cards),
+        }
+    }
+
+    /// Orders ranks, lower is better
+    pub fn order(&self, rank: usize) -> usize {
+        match *self {
+            Game::Holdem | Game::Omaha => rank,
+            Game::ShortDeck => short_deck_order(&rank),
+            Game::DeuceSeven => deuce_seven_order(&rank),
+        }
+    }
+
+    /// The cards and name that describe a rank, e.g. ("A A A K K", "Aces Full over Kings")
+    pub fn description(&self, rank: usize) -> (&'static str, &'static str) {
+        match *self {
+            Game::Holdem | Game::Omaha => description(&rank),
+            Game::ShortDeck => short_deck_description(&rank),
+            Game::DeuceSeven => deuce_seven_description(&rank),
+        }
+    }
+
+    /// Calls `f` with every five cards a hand may be made of
+    fn for_each_five(&self, hole: CardSet, board: CardSet, f: &mut dyn FnMut(CardSet)) {
+        if *self == Game::Omaha {
+            if hole.len() < 2 || board.len() < 3 {
+                panic!("Cannot pick an Omaha hand of {} hole and {} board cards", hole.len(), board.len());
+            }
+            let board: Vec<usize> = board.ids().collect();
+            for_each_combination(&hole.ids().collect::<Vec<usize>>(), 2, &mut |hole| {
+                for_each_combination(&board, 3, &mut |board| f(hole.iter().chain(board.iter()).cloned().collect()));
+            });
+        } else {
+            let cards = hole | board;
+            if !(5..=9).contains(&cards.len()) {
+                panic!("Cannot pick the best five of {} cards. Only 5 to 9 cards", cards.len());
+            }
+            for_each_combination(&cards.ids().collect::<Vec<usize>>(), 5, &mut |five| f(five.iter().cloned().collect()));
+        }
+    }
+}
+
+/// BestFive is the five cards a hand is made of and where they came from
+#[derive(Eq, PartialEq)]
+#[derive(Clone, Debug)]
+pub struct BestFive {
+    /// The rank of the hand, or of the low for `best_low_five`
+    pub rank: usize,
+    /// The cards in the order of the description of the hand, e.g. the trips before the pair
+    pub cards: Vec<Card>,
+    /// The cards that came from the hole
+    pub hole: CardSet,
+    /// The cards that came from the board
+    pub board: CardSet,
+}
+
+impl BestFive {
+    fn new(rank: usize, five: CardSet, figures: &str, hole: CardSet) -> BestFive {
+        // Cards of the same rank go from the highest suit down
+        let mut left: Vec<Card> = five.iter().collect();
+        left.reverse();
+        let mut cards = Vec::new();
+        for figure in figures.split(' ') {
+            let pos = left.iter().position(|card| Some(card.rank) == Rank::parse(figure)).unwrap();
+            cards.push(left.remove(pos));
+        }
+        BestFive {
+            rank,
+            cards,
+            hole: five & hole,
+            board: five - hole,
+        }
+    }
+}
+
+/// The best five card hand of the hole and board cards
+/// # Param game: how the cards are picked and ranked
+/// # Param hole: the hole cards
+/// # Param board: the board cards, 5 to 9 cards in all
+/// # Returns: the best five cards, when several make the same rank the first found
+pub fn best_five(game: Game, hole: CardSet, board: CardSet) -> BestFive {
+    let mut best: Option<(usize, CardSet)> = None;
+    game.for_each_five(hole, board, &mut |five| {
+        let rank = game.rank(five);
+        if best.is_none_or(|best| game.order(rank) < game.order(best.0)) {
+            best = Some((rank, five));
+        }
+    });
+    let (rank, five) = best.unwrap();
+    BestFive::new(rank, five, game.description(rank).0, hole)
+}
+
+/// The best qualifying eight-or-better low of the hole and board cards
+/// # Returns: the five cards from the highest down, or None if there is no low
+pub fn best_low_five(game: Game, hole: CardSet, board: CardSet) -> Option<BestFive> {
+    let mut best: Option<(usize, CardSet)> = None;
+    game.for_each_five(hole, board, &mut |five| {
+        if let Some(low) = eval_low(five) {
+            if best.is_none_or(|best| low < best.0) {
+                best = Some((low, five));
+            }
+        }
+    });
+    best.map(|(low, five)| BestFive::new(low, five, &low_description(&low).0, hole))
+}
+
+#[cfg(test)]
+fn set(names: &str) -> CardSet {
+    names.split(' ').map(|name| name.parse::<Card>().unwrap()).collect()
+}
+
+#[cfg(test)]
+fn names(cards: &[Card]) -> String {
+    cards.iter().map(|card| card.to_string()).collect::<Vec<String>>().join(" ")
+}
+
+#[test]
+fn test_best_five() {
+    let best = best_five(Game::Holdem, set("A♥ K♣"), set("A♠ A♦ K♠ 7♦ 2♣"));
+    assert_eq!(names(&best.cards), "A♠ A♥ A♦ K♠ K♣");
+    assert_eq!(best.hole, set("A♥ K♣"));
+    assert_eq!(best.board, set("A♠ A♦ K♠"));
+    assert_eq!(best.rank, eval_set(set("A♥ K♣ A♠ A♦ K♠ 7♦ 2♣")));
+
+    // Kickers are part of the five, and a wheel is ordered from the five down
+    let best = best_five(Game::Holdem, set("Q♥ 3♣"), set("Q♠ 9♦ 7♠ 4♣ 2♦"));
+    assert_eq!(names(&best.cards), "Q♠ Q♥ 9♦ 7♠ 4♣");
+    assert_eq!(best.hole, set("Q♥"));
+    let wheel = best_five(Game::Holdem, set("A♠ 3♦"), set("4♥ 5♦ 2♣ K♣ K♠"));
+    assert_eq!(names(&wheel.cards), "5♦ 4♥ 3♦ 2♣ A♠");
+
+    // Nine cards, and a board that plays
+    let best = best_five(Game::Holdem, set("2♥ 3♣ 4♦ 7♥"), set("A♠ K♠ Q♠ J♠ T♠"));
+    assert_eq!(description(&best.rank).1, "Royal Flush");
+    assert!(best.hole.is_empty());
+}
+
+#[test]
+fn test_best_five_games() {
+    let omaha = best_five(Game::Omaha, set("K♠ 9♦ 8♣ 7♥"), set("K♥ K♦ 5♣ 5♠ 2♥"));
+    assert_eq!(omaha.rank, eval_omaha(set("K♠ 9♦ 8♣ 7♥"), set("K♥ K♦ 5♣ 5♠ 2♥")));
+    assert_eq!(omaha.hole.len(), 2);
+    assert_eq!(omaha.board.len(), 3);
+    assert_eq!(names(&omaha.cards), "K♠ K♥ K♦ 9♦ 5♣");
+
+    let short = best_five(Game::ShortDeck, set("A♠ 8♦"), set("K♥ K♦ 9♥ 6♥ 7♣"));
+    assert_eq!(names(&short.cards), "9♥ 8♦ 7♣ 6♥ A♠");
+    assert_eq!(Game::ShortDeck.description(short.rank).1, "Nine-High Straight");
+
+    let lowball = best_five(Game::DeuceSeven, set("A♠ 5♥ 4♦ 3♣ 2♠"), CardSet::new());
+    assert_eq!(names(&lowball.cards), "A♠ 5♥ 4♦ 3♣ 2♠");
+
+    let low = best_low_five(Game::Omaha, set("A♥ 2♣ K♦ Q♣"), set("3♠ 4♥ 8♦ K♣ Q♠")).unwrap();
+    assert_eq!(names(&low.cards), "8♦ 4♥ 3♠ 2♣ A♥");
+    assert_eq!(low.hole, set("A♥ 2♣"));
+    assert_eq!(best_low_five(Game::Omaha, set("K♥ K♦ Q♦ J♣"), set("3♠ 4♥ 8♦ K♣ Q♠")), None);
+}
@@ -0,0 +1,268 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// Rank is an enum representing the value of a card, deuce to ace.
+/// The discriminant is the rank id used by the evaluator, 0..12
+#[derive(Eq, PartialEq)]
+#[derive(Ord, PartialOrd)]
+#[derive(Copy, Clone, Debug, Hash)]
+pub enum Rank {
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+    Ace,
+}
+
+/// Suit is an enum representing the suit of a card.
+/// The discriminant is the suit id used by the evaluator, 0..3
+#[derive(Eq, PartialEq)]
+#[derive(Ord, PartialOrd)]
+#[derive(Copy, Clone, Debug, Hash)]
+pub enum Suit {
+    Clubs,
+    Diamonds,
+    Hearts,
+    Spades,
+}
+
+/// Notation is an enum of the ways a card can be written.
+/// Unicode is the notation of the server protocol, e.g. `Q♠`.
+/// Ascii uses suit letters, e.g. `Qs`.
+#[derive(Eq, PartialEq)]
+#[derive(Copy, Clone, Debug, Default)]
+pub enum Notation {
+    #[default]
+    Unicode,
+    Ascii,
+}
+
+impl Notation {
+    pub fn from_id(id: i32) -> Notation {
+        match id {
+            1 => Notation::Ascii,
+            _ => Notation::Unicode,
+        }
+    }
+}
+
+impl Rank {
+    pub const ALL: [Rank; 13] = [
+        Rank::Two, Rank::Three, Rank::Four, Rank::Five, Rank::Six, Rank::Seven, Rank::Eight,
+        Rank::Nine, Rank::Ten, Rank::Jack, Rank::Queen, Rank::King, Rank::Ace,
+    ];
+
+    /// The rank with the given id
+    /// # Param id: the rank id, 0..12
+    /// # Returns: the rank
+    pub fn from_id(id: usize) -> Rank {
+        Rank::ALL[id]
+    }
+
+    /// The rank id, 0..12
+    pub fn id(self) -> usize {
+        self as usize
+    }
+
+    /// The next lower rank, if any. The ace is never below the deuce.
+    pub fn pred(self) -> Option<Rank> {
+        match self {
+            Rank::Two => None,
+            rank => Some(Rank::from_id(rank.id() - 1)),
+        }
+    }
+
+    /// The character of the rank as used by the descriptors, e.g. `Q` or `T`
+    pub fn to_char(self) -> char {
+        "23456789TJQKA".chars().nth(self.id()).unwrap()
+    }
+
+    /// Parses a rank, accepting `T` or `10` for tens and `Q` or `D` for queens
+    /// # Param s: the rank as a string
+    /// # Returns: the rank, or None if the string is not a rank
+    pub fn parse(s: &str) -> Option<Rank> {
+        match s {
+            "2" => Some(Rank::Two),
+            "3" => Some(Rank::Three),
+            "4" => Some(Rank::Four),
+            "5" => Some(Rank::Five),
+            "6" => Some(Rank::Six),
+            "7" => Some(Rank::Seven),
+            "8" => Some(Rank::Eight),
+            "9" => Some(Rank::Nine),
+            "T" | "t" | "10" => Some(Rank::Ten),
+            "J" | "j" => Some(Rank::Jack),
+            "Q" | "q" | "D" | "d" => Some(Rank::Queen),
+            "K" | "k" => Some(Rank::King),
+            "A" | "a" => Some(Rank::Ace),
+            _ => None,
+        }
+    }
+}
+
+impl Suit {
+    pub const ALL: [Suit; 4] = [Suit::Clubs, Suit::Diamonds, Suit::Hearts, Suit::Spades];
+
+    /// The suit with the given id
+    /// # Param id: the suit id, 0..3
+    /// # Returns: the suit
+    pub fn from_id(id: usize) -> Suit {
+        Suit::ALL[id]
+    }
+
+    /// The suit id, 0..3
+    pub fn id(self) -> usize {
+        self as usize
+    }
+
+    /// The character of the suit in the given notation
+    pub fn to_char(self, notation: Notation) -> char {
+        match notation {
+            Notation::Unicode => "♣♦♥♠".chars().nth(self.id()).unwrap(),
+            Notation::Ascii => "cdhs".chars().nth(self.id()).unwrap(),
+        }
+    }
+
+    /// Parses a suit from either its unicode symbol or its letter
+    /// # Param c: the suit character
+    /// # Returns: the suit, or None if the character is not a suit
+    pub fn parse(c: char) -> Option<Suit> {
+        match c {
+            '♣' | '♧' | 'c' | 'C' => Some(Suit::Clubs),
+            '♦' | '♢' | 'd' | 'D' => Some(Suit::Diamonds),
+            '♥' | '♡' | 'h' | 'H' => Some(Suit::Hearts),
+            '♠' | '♤' | 's' | 'S' => Some(Suit::Spades),
+            _ => None,
+        }
+    }
+}
+
+/// Card is a playing card. Cards order by rank first and suit second.
+#[derive(Eq, PartialEq)]
+#[derive(Ord, PartialOrd)]
+#[derive(Copy, Clone, Debug, Hash)]
+pub struct Card {
+    pub rank: Rank,
+    pub suit: Suit,
+}
+
+impl Card {
+    pub fn new(rank: Rank, suit: Suit) -> Card {
+        Card { rank, suit }
+    }
+
+    /// The card with the given evaluator id
+    /// # Param id: the id of the card, 0..52
+    /// # Returns: the card
+    pub fn from_id(id: usize) -> Card {
+        Card::new(Rank::from_id(id >> 2), Suit::from_id(id & 0x3))
+    }
+
+    /// The evaluator id of the card, 0..52
+    pub fn id(&self) -> usize {
+        self.rank.id() << 2 | self.suit.id()
+    }
+
+    /// The card written in the given notation
+    pub fn notation(self, notation: Notation) -> Notated {
+        Notated { card: self, notation }
+    }
+}
+
+/// A card paired with the notation it is displayed in
+pub struct Notated {
+    card: Card,
+    notation: Notation,
+}
+
+impl fmt::Display for Notated {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}", self.card.rank.to_char(), self.card.suit.to_char(self.notation))
+    }
+}
+
+impl fmt::Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.notation(Notation::default()).fmt(f)
+    }
+}
+
+/// ParseCardError is the reason a string could not be read as a card
+#[derive(Eq, PartialEq)]
+#[derive(Clone, Debug)]
+pub enum ParseCardError {
+    Rank(String),
+    Suit(String),
+}
+
+impl fmt::Display for ParseCardError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ParseCardError::Rank(ref s) => write!(f, "invalid rank in card '{}'", s),
+            ParseCardError::Suit(ref s) => write!(f, "invalid suit in card '{}'", s),
+        }
+    }
+}
+
+impl Error for ParseCardError {}
+
+impl FromStr for Card {
+    type Err = ParseCardError;
+
+    fn from_str(s: &str) -> Result<Card, ParseCardError> {
+        let suit_char = match s.chars().last() {
+            Some(c) => c,
+            None => return Err(ParseCardError::Rank(s.to_string())),
+        };
+        let rank = match Rank::parse(&s[..s.len() - suit_char.len_utf8()]) {
+            Some(rank) => rank,
+            None => return Err(ParseCardError::Rank(s.to_string())),
+        };
+        let suit = match Suit::parse(suit_char) {
+            Some(suit) => suit,
+            None => return Err(ParseCardError::Suit(s.to_string())),
+        };
+        Ok(Card::new(rank, suit))
+    }
+}
+
+#[test]
+fn test_card_parse() {
+    assert_eq!("Q♠".parse(), Ok(Card::new(Rank::Queen, Suit::Spades)));
+    assert_eq!("Ds".parse(), Ok(Card::new(Rank::Queen, Suit::Spades)));
+    assert_eq!("10♥".parse(), Ok(Card::new(Rank::Ten, Suit::Hearts)));
+    assert_eq!("Th".parse(), Ok(Card::new(Rank::Ten, Suit::Hearts)));
+    assert_eq!("2c".parse(), Ok(Card::new(Rank::Two, Suit::Clubs)));
+    assert_eq!("A♦".parse(), Ok(Card::new(Rank::Ace, Suit::Diamonds)));
+
+    assert_eq!("".parse::<Card>(), Err(ParseCardError::Rank("".to_string())));
+    assert_eq!("A".parse::<Card>(), Err(ParseCardError::Rank("A".to_string())));
+    assert_eq!("♠".parse::<Card>(), Err(ParseCardError::Rank("♠".to_string())));
+    assert_eq!("1♠".parse::<Card>(), Err(ParseCardError::Rank("1♠".to_string())));
+    assert_eq!("Ax".parse::<Card>(), Err(ParseCardError::Suit("Ax".to_string())));
+    assert_eq!("A♠♠".parse::<Card>(), Err(ParseCardError::Rank("A♠♠".to_string())));
+}
+
+#[test]
+fn test_card_notation() {
+    let card = Card::new(Rank::Queen, Suit::Spades);
+    assert_eq!(card.to_string(), "Q♠");
+    assert_eq!(card.notation(Notation::Unicode).to_string(), "Q♠");
+    assert_eq!(card.notation(Notation::Ascii).to_string(), "Qs");
+
+    for id in 0..52 {
+        let card = Card::from_id(id);
+        assert_eq!(card.id(), id);
+        assert_eq!(card.to_string().parse(), Ok(card));
+        assert_eq!(card.notation(Notation::Ascii).to_string().parse(), Ok(card));
+    }
+}
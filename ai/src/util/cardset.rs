@@ -0,0 +1,228 @@
+use std::fmt;
+use std::iter::FromIterator;
+use std::ops::{BitAnd, BitOr, Sub};
+
+use super::card::*;
+
+/// CardSet is a set of cards packed in the low 52 bits of a u64.
+/// Bit `id` is set when the card with evaluator id `id` is in the set.
+#[derive(Eq, PartialEq)]
+#[derive(Copy, Clone, Debug, Default, Hash)]
+pub struct CardSet {
+    bits: u64,
+}
+
+impl CardSet {
+    /// The set of all 52 cards
+    pub fn full() -> CardSet {
+        CardSet { bits: (1 << 52) - 1 }
+    }
+
+    pub fn new() -> CardSet {
+        CardSet { bits: 0 }
+    }
+
+    /// The set with the given bits
+    /// # Param bits: a mask of evaluator ids, only the low 52 bits are used
+    /// # Returns: the set
+    pub fn from_bits(bits: u64) -> CardSet {
+        CardSet { bits: bits & CardSet::full().bits }
+    }
+
+    pub fn bits(&self) -> u64 {
+        self.bits
+    }
+
+    pub fn len(&self) -> usize {
+        self.bits.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits == 0
+    }
+
+    pub fn contains(&self, card: Card) -> bool {
+        self.contains_id(card.id())
+    }
+
+    pub fn contains_id(&self, id: usize) -> bool {
+        self.bits & CardSet::bit(id) != 0
+    }
+
+    pub fn insert(&mut self, card: Card) {
+        self.insert_id(card.id());
+    }
+
+    pub fn insert_id(&mut self, id: usize) {
+        self.bits |= CardSet::bit(id);
+    }
+
+    pub fn remove(&mut self, card: Card) {
+        self.remove_id(card.id());
+    }
+
+    pub fn remove_id(&mut self, id: usize) {
+        self.bits &= !CardSet::bit(id);
+    }
+
+    /// The bit of a card id, which must be one of the 52 cards
+    fn bit(id: usize) -> u64 {
+        debug_assert!(id < 52, "{} is not a card id", id);
+        1 << id
+    }
+
+    pub fn union(self, other: CardSet) -> CardSet {
+        CardSet { bits: self.bits | other.bits }
+    }
+
+    pub fn intersection(self, other: CardSet) -> CardSet {
+        CardSet { bits: self.bits & other.bits }
+    }
+
+    /// The cards of this set that are not in the other set
+    pub fn difference(self, other: CardSet) -> CardSet {
+        CardSet { bits: self.bits & !other.bits }
+    }
+
+    /// The evaluator ids of the cards, in increasing order
+    pub fn ids(&self) -> Ids {
+        Ids { bits: self.bits }
+    }
+
+    /// The cards of the set, in increasing order
+    pub fn iter(&self) -> impl Iterator<Item=Card> {
+        self.ids().map(Card::from_id)
+    }
+}
+
+/// Iterator over the evaluator ids of a CardSet
+pub struct Ids {
+    bits: u64,
+}
+
+impl Iterator for Ids {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        if self.bits == 0 {
+            return None;
+        }
+        let id = self.bits.trailing_zeros() as usize;
+        self.bits &= self.bits - 1;
+        Some(id)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.bits.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+impl From<Card> for CardSet {
+    fn from(card: Card) -> CardSet {
+        CardSet { bits: 1 << card.id() }
+    }
+}
+
+impl FromIterator<Card> for CardSet {
+    fn from_iter<I: IntoIterator<Item=Card>>(cards: I) -> CardSet {
+        let mut set = CardSet::new();
+        for card in cards {
+            set.insert(card);
+        }
+        set
+    }
+}
+
+impl<'a> FromIterator<&'a Card> for CardSet {
+    fn from_iter<I: IntoIterator<Item=&'a Card>>(cards: I) -> CardSet {
+        cards.into_iter().cloned().collect()
+    }
+}
+
+impl FromIterator<usize> for CardSet {
+    fn from_iter<I: IntoIterator<Item=usize>>(ids: I) -> CardSet {
+        let mut set = CardSet::new();
+        for id in ids {
+            set.insert_id(id);
+        }
+        set
+    }
+}
+
+impl BitOr for CardSet {
+    type Output = CardSet;
+    fn bitor(self, other: CardSet) -> CardSet {
+        self.union(other)
+    }
+}
+
+impl BitAnd for CardSet {
+    type Output = CardSet;
+    fn bitand(self, other: CardSet) -> CardSet {
+        self.intersection(other)
+    }
+}
+
+impl Sub for CardSet {
+    type Output = CardSet;
+    fn sub(self, other: CardSet) -> CardSet {
+        self.difference(other)
+    }
+}
+
+impl fmt::Display for CardSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let cards: Vec<String> = self.iter().map(|card| card.to_string()).collect();
+        write!(f, "{}", cards.join(" "))
+    }
+}
+
+#[test]
+fn test_cardset_ops() {
+    let ace: Card = "A♠".parse().unwrap();
+    let king: Card = "K♠".parse().unwrap();
+    let deuce: Card = "2♣".parse().unwrap();
+
+    let mut set = CardSet::new();
+    assert!(set.is_empty());
+    set.insert(ace);
+    set.insert(king);
+    set.insert(ace);
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(ace) && set.contains(king) && !set.contains(deuce));
+
+    let other: CardSet = [king, deuce].iter().collect();
+    assert_eq!((set | other).len(), 3);
+    assert_eq!(set & other, CardSet::from(king));
+    assert_eq!(set - other, CardSet::from(ace));
+    assert_eq!(CardSet::full().len(), 52);
+    assert_eq!((CardSet::full() - set).len(), 50);
+
+    set.remove(ace);
+    assert_eq!(set, CardSet::from(king));
+    assert_eq!(CardSet::from_bits(u64::MAX), CardSet::full());
+}
+
+#[test]
+fn test_cardset_conversions() {
+    let full = CardSet::full();
+    assert_eq!(full.ids().collect::<Vec<usize>>(), (0..52).collect::<Vec<usize>>());
+    assert_eq!(full.iter().collect::<CardSet>(), full);
+    assert_eq!(full.ids().collect::<CardSet>(), full);
+    for id in 0..52 {
+        let card = Card::from_id(id);
+        let set = CardSet::from(card);
+        assert_eq!(set.bits(), 1 << id);
+        assert_eq!(set.iter().next(), Some(card));
+        assert_eq!(set.ids().next(), Some(id));
+    }
+    let set: CardSet = ["A♠", "2♣", "T♥"].iter().map(|name| name.parse::<Card>().unwrap()).collect();
+    assert_eq!(set.to_string(), "2♣ T♥ A♠");
+}
+
+#[test]
+#[should_panic(expected = "52 is not a card id")]
+fn test_cardset_id_range() {
+    CardSet::new().insert_id(52);
+}
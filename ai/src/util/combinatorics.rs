@@ -0,0 +1,47 @@
+/// The number of ways to choose k of n items
+pub fn combinations(n: usize, k: usize) -> usize {
+    if k > n {
+        return 0;
+    }
+    (0..k).fold(1, |acc, i| acc * (n - i) / (i + 1))
+}
+
+/// Calls f with every k-item combination of items, in lexicographic order
+pub fn for_each_combination<T: Copy>(items: &[T], k: usize, f: &mut dyn FnMut(&[T])) {
+    let mut chosen = Vec::with_capacity(k);
+    choose(items, k, &mut chosen, f);
+}
+
+fn choose<T: Copy>(items: &[T], k: usize, chosen: &mut Vec<T>, f: &mut dyn FnMut(&[T])) {
+    if chosen.len() == k {
+        f(chosen);
+        return;
+    }
+    let needed = k - chosen.len();
+    if items.len() < needed {
+        return;
+    }
+    for i in 0..=items.len() - needed {
+        chosen.push(items[i]);
+        choose(&items[i + 1..], k, chosen, f);
+        chosen.pop();
+    }
+}
+
+#[test]
+fn test_combinations() {
+    assert_eq!(combinations(52, 5), 2598960);
+    assert_eq!(combinations(45, 2), 990);
+    assert_eq!(combinations(3, 4), 0);
+    assert_eq!(combinations(13, 0), 1);
+
+    let mut count = 0;
+    for_each_combination(&[1, 2, 3, 4, 5], 3, &mut |chosen| {
+        assert_eq!(chosen.len(), 3);
+        count += 1;
+    });
+    assert_eq!(count, 10);
+    let mut empty = 0;
+    for_each_combination(&[1, 2], 0, &mut |_| empty += 1);
+    assert_eq!(empty, 1);
+}
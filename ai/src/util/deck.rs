@@ -0,0 +1,145 @@
+use rand::Rng;
+use rand::seq::SliceRandom;
+
+use super::card::*;
+use super::cardset::*;
+
+/// Deck is an ordered stack of cards that are dealt from the top.
+pub struct Deck {
+    cards: Vec<Card>,
+    position: usize,
+    burned: CardSet,
+}
+
+impl Deck {
+    /// An unshuffled deck of all 52 cards
+    pub fn new() -> Deck {
+        Deck::with_ranks(&Rank::ALL)
+    }
+
+    /// An unshuffled deck of every given rank in every suit
+    pub fn with_ranks(ranks: &[Rank]) -> Deck {
+        let mut cards = Vec::new();
+        for &rank in ranks {
+            for &suit in Suit::ALL.iter() {
+                cards.push(Card::new(rank, suit));
+            }
+        }
+        Deck::from_cards(cards)
+    }
+
+    /// A deck that deals the given cards in order, first card first
+    pub fn from_cards(cards: Vec<Card>) -> Deck {
+        Deck {
+            cards,
+            position: 0,
+            burned: CardSet::new(),
+        }
+    }
+
+    /// Shuffles the cards that have not been dealt yet
+    pub fn shuffle<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        self.cards[self.position..].shuffle(rng);
+    }
+
+    /// Deals the top card
+    /// # Returns: the card, or None if the deck is empty
+    pub fn deal(&mut self) -> Option<Card> {
+        let card = self.cards.get(self.position).cloned();
+        if card.is_some() {
+            self.position += 1;
+        }
+        card
+    }
+
+    /// Deals the top card face down, out of play
+    /// # Returns: the card, or None if the deck is empty
+    pub fn burn(&mut self) -> Option<Card> {
+        let card = self.deal();
+        if let Some(card) = card {
+            self.burned.insert(card);
+        }
+        card
+    }
+
+    /// Takes the given cards out of the deck, e.g. known dead cards. Cards already dealt
+    /// are out of the deck already
+    pub fn remove(&mut self, cards: CardSet) {
+        let undealt = self.cards.split_off(self.position);
+        self.cards.extend(undealt.into_iter().filter(|card| !cards.contains(*card)));
+    }
+
+    pub fn burned(&self) -> CardSet {
+        self.burned
+    }
+
+    /// The cards that are left to be dealt
+    pub fn remaining(&self) -> CardSet {
+        self.cards[self.position..].iter().collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.cards.len() - self.position
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Default for Deck {
+    fn default() -> Deck {
+        Deck::new()
+    }
+}
+
+#[test]
+fn test_deck_deal() {
+    let mut deck = Deck::new();
+    assert_eq!(deck.len(), 52);
+    assert_eq!(deck.remaining(), CardSet::full());
+    assert_eq!(deck.deal(), Some(Card::from_id(0)));
+    assert_eq!(deck.burn(), Some(Card::from_id(1)));
+    assert_eq!(deck.burned(), CardSet::from(Card::from_id(1)));
+    assert_eq!(deck.len(), 50);
+
+    let mut dealt = CardSet::new();
+    while let Some(card) = deck.deal() {
+        dealt.insert(card);
+    }
+    assert_eq!(dealt.len(), 50);
+    assert!(deck.is_empty());
+    assert_eq!(deck.burn(), None);
+}
+
+#[test]
+fn test_deck_shuffle() {
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    let mut deck = Deck::with_ranks(&Rank::ALL[4..]);
+    assert_eq!(deck.len(), 36);
+    deck.remove("A♠ K♠".split(' ').map(|name| name.parse::<Card>().unwrap()).collect());
+    deck.shuffle(&mut StdRng::seed_from_u64(7));
+    assert_eq!(deck.len(), 34);
+
+    let mut dealt = CardSet::new();
+    while let Some(card) = deck.deal() {
+        assert!(card.rank >= Rank::Six);
+        dealt.insert(card);
+    }
+    assert_eq!(dealt.len(), 34);
+    assert!(!dealt.contains("A♠".parse().unwrap()));
+}
+
+#[test]
+fn test_deck_remove_dealt() {
+    let mut deck = Deck::new();
+    let first = deck.deal().unwrap();
+    let second = deck.deal().unwrap();
+    let last = Card::from_id(51);
+    deck.remove(CardSet::from(first) | CardSet::from(last));
+    assert_eq!(deck.len(), 49);
+    assert_eq!(deck.remaining(), CardSet::full() - CardSet::from(first) - CardSet::from(second) - CardSet::from(last));
+    assert_eq!(deck.deal(), Some(Card::from_id(2)));
+}
@@ -1,7466 +1,2 @@
-// Citation: [http://suffe.cool/poker/7462.html]
-pub const DESCRIPTORS: [(&str, &str); 7463] = [
-    ("", ""),
-    ("A K Q J T", "Royal Flush"),
-    ("K Q J T 9", "King-High Straight Flush"),
-    ("Q J T 9 8", "Queen-High Straight Flush"),
-    ("J T 9 8 7", "Jack-High Straight Flush"),
-    ("T 9 8 7 6", "Ten-High Straight Flush"),
-    ("9 8 7 6 5", "Nine-High Straight Flush"),
-    ("8 7 6 5 4", "Eight-High Straight Flush"),
-    ("7 6 5 4 3", "Seven-High Straight Flush"),
-    ("6 5 4 3 2", "Six-High Straight Flush"),
-    ("5 4 3 2 A", "Five-High Straight Flush"),
-    ("A A A A K", "Four Aces"),
-    ("A A A A Q", "Four Aces"),
-    ("A A A A J", "Four Aces"),
-    ("A A A A T", "Four Aces"),
-    ("A A A A 9", "Four Aces"),
-    ("A A A A 8", "Four Aces"),
-    ("A A A A 7", "Four Aces"),
-    ("A A A A 6", "Four Aces"),
-    ("A A A A 5", "Four Aces"),
-    ("A A A A 4", "Four Aces"),
-    ("A A A A 3", "Four Aces"),
-    ("A A A A 2", "Four Aces"),
-    ("K K K K A", "Four Kings"),
-    ("K K K K Q", "Four Kings"),
-    ("K K K K J", "Four Kings"),
-    ("K K K K T", "Four Kings"),
-    ("K K K K 9", "Four Kings"),
-    ("K K K K 8", "Four Kings"),
-    ("K K K K 7", "Four Kings"),
-    ("K K K K 6", "Four Kings"),
-    ("K K K K 5", "Four Kings"),
-    ("K K K K 4", "Four Kings"),
-    ("K K K K 3", "Four Kings"),
-    ("K K K K 2", "Four Kings"),
-    ("Q Q Q Q A", "Four Queens"),
-    ("Q Q Q Q K", "Four Queens"),
-    ("Q Q Q Q J", "Four Queens"),
-    ("Q Q Q Q T", "Four Queens"),
-    ("Q Q Q Q 9", "Four Queens"),
-    ("Q Q Q Q 8", "Four Queens"),
-    ("Q Q Q Q 7", "Four Queens"),
-    ("Q Q Q Q 6", "Four Queens"),
-    ("Q Q Q Q 5", "Four Queens"),
-    ("Q Q Q Q 4", "Four Queens"),
-    ("Q Q Q Q 3", "Four Queens"),
-    ("Q Q Q Q 2", "Four Queens"),
-    ("J J J J A", "Four Jacks"),
-    ("J J J J K", "Four Jacks"),
-    ("J J J J Q", "Four Jacks"),
-    ("J J J J T", "Four Jacks"),
-    ("J J J J 9", "Four Jacks"),
-    ("J J J J 8", "Four Jacks"),
-    ("J J J J 7", "Four Jacks"),
-    ("J J J J 6", "Four Jacks"),
-    ("J J J J 5", "Four Jacks"),
-    ("J J J J 4", "Four Jacks"),
-    ("J J J J 3", "Four Jacks"),
-    ("J J J J 2", "Four Jacks"),
-    ("T T T T A", "Four Tens"),
-    ("T T T T K", "Four Tens"),
-    ("T T T T Q", "Four Tens"),
-    ("T T T T J", "Four Tens"),
-    ("T T T T 9", "Four Tens"),
-    ("T T T T 8", "Four Tens"),
-    ("T T T T 7", "Four Tens"),
-    ("T T T T 6", "Four Tens"),
-    ("T T T T 5", "Four Tens"),
-    ("T T T T 4", "Four Tens"),
-    ("T T T T 3", "Four Tens"),
-    ("T T T T 2", "Four Tens"),
-    ("9 9 9 9 A", "Four Nines"),
-    ("9 9 9 9 K", "Four Nines"),
-    ("9 9 9 9 Q", "Four Nines"),
-    ("9 9 9 9 J", "Four Nines"),
-    ("9 9 9 9 T", "Four Nines"),
-    ("9 9 9 9 8", "Four Nines"),
-    ("9 9 9 9 7", "Four Nines"),
-    ("9 9 9 9 6", "Four Nines"),
-    ("9 9 9 9 5", "Four Nines"),
-    ("9 9 9 9 4", "Four Nines"),
-    ("9 9 9 9 3", "Four Nines"),
-    ("9 9 9 9 2", "Four Nines"),
-    ("8 8 8 8 A", "Four Eights"),
-    ("8 8 8 8 K", "Four Eights"),
-    ("8 8 8 8 Q", "Four Eights"),
-    ("8 8 8 8 J", "Four Eights"),
-    ("8 8 8 8 T", "Four Eights"),
-    ("8 8 8 8 9", "Four Eights"),
-    ("8 8 8 8 7", "Four Eights"),
-    ("8 8 8 8 6", "Four Eights"),
-    ("8 8 8 8 5", "Four Eights"),
-    ("8 8 8 8 4", "Four Eights"),
-    ("8 8 8 8 3", "Four Eights"),
-    ("8 8 8 8 2", "Four Eights"),
-    ("7 7 7 7 A", "Four Sevens"),
-    ("7 7 7 7 K", "Four Sevens"),
-    ("7 7 7 7 Q", "Four Sevens"),
-    ("7 7 7 7 J", "Four Sevens"),
-    ("7 7 7 7 T", "Four Sevens"),
-    ("7 7 7 7 9", "Four Sevens"),
-    ("7 7 7 7 8", "Four Sevens"),
-    ("7 7 7 7 6", "Four Sevens"),
-    ("7 7 7 7 5", "Four Sevens"),
-    ("7 7 7 7 4", "Four Sevens"),
-    ("7 7 7 7 3", "Four Sevens"),
-    ("7 7 7 7 2", "Four Sevens"),
-    ("6 6 6 6 A", "Four Sixes"),
-    ("6 6 6 6 K", "Four Sixes"),
-    ("6 6 6 6 Q", "Four Sixes"),
-    ("6 6 6 6 J", "Four Sixes"),
-    ("6 6 6 6 T", "Four Sixes"),
-    ("6 6 6 6 9", "Four Sixes"),
-    ("6 6 6 6 8", "Four Sixes"),
-    ("6 6 6 6 7", "Four Sixes"),
-    ("6 6 6 6 5", "Four Sixes"),
-    ("6 6 6 6 4", "Four Sixes"),
-    ("6 6 6 6 3", "Four Sixes"),
-    ("6 6 6 6 2", "Four Sixes"),
-    ("5 5 5 5 A", "Four Fives"),
-    ("5 5 5 5 K", "Four Fives"),
-    ("5 5 5 5 Q", "Four Fives"),
-    ("5 5 5 5 J", "Four Fives"),
-    ("5 5 5 5 T", "Four Fives"),
-    ("5 5 5 5 9", "Four Fives"),
-    ("5 5 5 5 8", "Four Fives"),
-    ("5 5 5 5 7", "Four Fives"),
-    ("5 5 5 5 6", "Four Fives"),
-    ("5 5 5 5 4", "Four Fives"),
-    ("5 5 5 5 3", "Four Fives"),
-    ("5 5 5 5 2", "Four Fives"),
-    ("4 4 4 4 A", "Four Fours"),
-    ("4 4 4 4 K", "Four Fours"),
-    ("4 4 4 4 Q", "Four Fours"),
-    ("4 4 4 4 J", "Four Fours"),
-    ("4 4 4 4 T", "Four Fours"),
-    ("4 4 4 4 9", "Four Fours"),
-    ("4 4 4 4 8", "Four Fours"),
-    ("4 4 4 4 7", "Four Fours"),
-    ("4 4 4 4 6", "Four Fours"),
-    ("4 4 4 4 5", "Four Fours"),
-    ("4 4 4 4 3", "Four Fours"),
-    ("4 4 4 4 2", "Four Fours"),
-    ("3 3 3 3 A", "Four Treys"),
-    ("3 3 3 3 K", "Four Treys"),
-    ("3 3 3 3 Q", "Four Treys"),
-    ("3 3 3 3 J", "Four Treys"),
-    ("3 3 3 3 T", "Four Treys"),
-    ("3 3 3 3 9", "Four Treys"),
-    ("3 3 3 3 8", "Four Treys"),
-    ("3 3 3 3 7", "Four Treys"),
-    ("3 3 3 3 6", "Four Treys"),
-    ("3 3 3 3 5", "Four Treys"),
-    ("3 3 3 3 4", "Four Treys"),
-    ("3 3 3 3 2", "Four Treys"),
-    ("2 2 2 2 A", "Four Deuces"),
-    ("2 2 2 2 K", "Four Deuces"),
-    ("2 2 2 2 Q", "Four Deuces"),
-    ("2 2 2 2 J", "Four Deuces"),
-    ("2 2 2 2 T", "Four Deuces"),
-    ("2 2 2 2 9", "Four Deuces"),
-    ("2 2 2 2 8", "Four Deuces"),
-    ("2 2 2 2 7", "Four Deuces"),
-    ("2 2 2 2 6", "Four Deuces"),
-    ("2 2 2 2 5", "Four Deuces"),
-    ("2 2 2 2 4", "Four Deuces"),
-    ("2 2 2 2 3", "Four Deuces"),
-    ("A A A K K", "Aces Full over Kings"),
-    ("A A A Q Q", "Aces Full over Queens"),
-    ("A A A J J", "Aces Full over Jacks"),
-    ("A A A T T", "Aces Full over Tens"),
-    ("A A A 9 9", "Aces Full over Nines"),
-    ("A A A 8 8", "Aces Full over Eights"),
-    ("A A A 7 7", "Aces Full over Sevens"),
-    ("A A A 6 6", "Aces Full over Sixes"),
-    ("A A A 5 5", "Aces Full over Fives"),
-    ("A A A 4 4", "Aces Full over Fours"),
-    ("A A A 3 3", "Aces Full over Treys"),
-    ("A A A 2 2", "Aces Full over Deuces"),
-    ("K K K A A", "Kings Full over Aces"),
-    ("K K K Q Q", "Kings Full over Queens"),
-    ("K K K J J", "Kings Full over Jacks"),
-    ("K K K T T", "Kings Full over Tens"),
-    ("K K K 9 9", "Kings Full over Nines"),
-    ("K K K 8 8", "Kings Full over Eights"),
-    ("K K K 7 7", "Kings Full over Sevens"),
-    ("K K K 6 6", "Kings Full over Sixes"),
-    ("K K K 5 5", "Kings Full over Fives"),
-    ("K K K 4 4", "Kings Full over Fours"),
-    ("K K K 3 3", "Kings Full over Treys"),
-    ("K K K 2 2", "Kings Full over Deuces"),
-    ("Q Q Q A A", "Queens Full over Aces"),
-    ("Q Q Q K K", "Queens Full over Kings"),
-    ("Q Q Q J J", "Queens Full over Jacks"),
-    ("Q Q Q T T", "Queens Full over Tens"),
-    ("Q Q Q 9 9", "Queens Full over Nines"),
-    ("Q Q Q 8 8", "Queens Full over Eights"),
-    ("Q Q Q 7 7", "Queens Full over Sevens"),
-    ("Q Q Q 6 6", "Queens Full over Sixes"),
-    ("Q Q Q 5 5", "Queens Full over Fives"),
-    ("Q Q Q 4 4", "Queens Full over Fours"),
-    ("Q Q Q 3 3", "Queens Full over Treys"),
-    ("Q Q Q 2 2", "Queens Full over Deuces"),
-    ("J J J A A", "Jacks Full over Aces"),
-    ("J J J K K", "Jacks Full over Kings"),
-    ("J J J Q Q", "Jacks Full over Queens"),
-    ("J J J T T", "Jacks Full over Tens"),
-    ("J J J 9 9", "Jacks Full over Nines"),
-    ("J J J 8 8", "Jacks Full over Eights"),
-    ("J J J 7 7", "Jacks Full over Sevens"),
-    ("J J J 6 6", "Jacks Full over Sixes"),
-    ("J J J 5 5", "Jacks Full over Fives"),
-    ("J J J 4 4", "Jacks Full over Fours"),
-    ("J J J 3 3", "Jacks Full over Treys"),
-    ("J J J 2 2", "Jacks Full over Deuces"),
-    ("T T T A A", "Tens Full over Aces"),
-    ("T T T K K", "Tens Full over Kings"),
-    ("T T T Q Q", "Tens Full over Queens"),
-    ("T T T J J", "Tens Full over Jacks"),
-    ("T T T 9 9", "Tens Full over Nines"),
-    ("T T T 8 8", "Tens Full over Eights"),
-    ("T T T 7 7", "Tens Full over Sevens"),
-    ("T T T 6 6", "Tens Full over Sixes"),
-    ("T T T 5 5", "Tens Full over Fives"),
-    ("T T T 4 4", "Tens Full over Fours"),
-    ("T T T 3 3", "Tens Full over Treys"),
-    ("T T T 2 2", "Tens Full over Deuces"),
-    ("9 9 9 A A", "Nines Full over Aces"),
-    ("9 9 9 K K", "Nines Full over Kings"),
-    ("9 9 9 Q Q", "Nines Full over Queens"),
-    ("9 9 9 J J", "Nines Full over Jacks"),
-    ("9 9 9 T T", "Nines Full over Tens"),
-    ("9 9 9 8 8", "Nines Full over Eights"),
-    ("9 9 9 7 7", "Nines Full over Sevens"),
-    ("9 9 9 6 6", "Nines Full over Sixes"),
-    ("9 9 9 5 5", "Nines Full over Fives"),
-    ("9 9 9 4 4", "Nines Full over Fours"),
-    ("9 9 9 3 3", "Nines Full over Treys"),
-    ("9 9 9 2 2", "Nines Full over Deuces"),
-    ("8 8 8 A A", "Eights Full over Aces"),
-    ("8 8 8 K K", "Eights Full over Kings"),
-    ("8 8 8 Q Q", "Eights Full over Queens"),
-    ("8 8 8 J J", "Eights Full over Jacks"),
-    ("8 8 8 T T", "Eights Full over Tens"),
-    ("8 8 8 9 9", "Eights Full over Nines"),
-    ("8 8 8 7 7", "Eights Full over Sevens"),
-    ("8 8 8 6 6", "Eights Full over Sixes"),
-    ("8 8 8 5 5", "Eights Full over Fives"),
-    ("8 8 8 4 4", "Eights Full over Fours"),
-    ("8 8 8 3 3", "Eights Full over Treys"),
-    ("8 8 8 2 2", "Eights Full over Deuces"),
-    ("7 7 7 A A", "Sevens Full over Aces"),
-    ("7 7 7 K K", "Sevens Full over Kings"),
-    ("7 7 7 Q Q", "Sevens Full over Queens"),
-    ("7 7 7 J J", "Sevens Full over Jacks"),
-    ("7 7 7 T T", "Sevens Full over Tens"),
-    ("7 7 7 9 9", "Sevens Full over Nines"),
-    ("7 7 7 8 8", "Sevens Full over Eights"),
-    ("7 7 7 6 6", "Sevens Full over Sixes"),
-    ("7 7 7 5 5", "Sevens Full over Fives"),
-    ("7 7 7 4 4", "Sevens Full over Fours"),
-    ("7 7 7 3 3", "Sevens Full over Treys"),
-    ("7 7 7 2 2", "Sevens Full over Deuces"),
-    ("6 6 6 A A", "Sixes Full over Aces"),
-    ("6 6 6 K K", "Sixes Full over Kings"),
-    ("6 6 6 Q Q", "Sixes Full over Queens"),
-    ("6 6 6 J J", "Sixes Full over Jacks"),
-    ("6 6 6 T T", "Sixes Full over Tens"),
-    ("6 6 6 9 9", "Sixes Full over Nines"),
-    ("6 6 6 8 8", "Sixes Full over Eights"),
-    ("6 6 6 7 7", "Sixes Full over Sevens"),
-    ("6 6 6 5 5", "Sixes Full over Fives"),
-    ("6 6 6 4 4", "Sixes Full over Fours"),
-    ("6 6 6 3 3", "Sixes Full over Treys"),
-    ("6 6 6 2 2", "Sixes Full over Deuces"),
-    ("5 5 5 A A", "Fives Full over Aces"),
-    ("5 5 5 K K", "Fives Full over Kings"),
-    ("5 5 5 Q Q", "Fives Full over Queens"),
-    ("5 5 5 J J", "Fives Full over Jacks"),
-    ("5 5 5 T T", "Fives Full over Tens"),
-    ("5 5 5 9 9", "Fives Full over Nines"),
-    ("5 5 5 8 8", "Fives Full over Eights"),
-    ("5 5 5 7 7", "Fives Full over Sevens"),
-    ("5 5 5 6 6", "Fives Full over Sixes"),
-    ("5 5 5 4 4", "Fives Full over Fours"),
-    ("5 5 5 3 3", "Fives Full over Treys"),
-    ("5 5 5 2 2", "Fives Full over Deuces"),
-    ("4 4 4 A A", "Fours Full over Aces"),
-    ("4 4 4 K K", "Fours Full over Kings"),
-    ("4 4 4 Q Q", "Fours Full over Queens"),
-    ("4 4 4 J J", "Fours Full over Jacks"),
-    ("4 4 4 T T", "Fours Full over Tens"),
-    ("4 4 4 9 9", "Fours Full over Nines"),
-    ("4 4 4 8 8", "Fours Full over Eights"),
-    ("4 4 4 7 7", "Fours Full over Sevens"),
-    ("4 4 4 6 6", "Fours Full over Sixes"),
-    ("4 4 4 5 5", "Fours Full over Fives"),
-    ("4 4 4 3 3", "Fours Full over Treys"),
-    ("4 4 4 2 2", "Fours Full over Deuces"),
-    ("3 3 3 A A", "Treys Full over Aces"),
-    ("3 3 3 K K", "Treys Full over Kings"),
-    ("3 3 3 Q Q", "Treys Full over Queens"),
-    ("3 3 3 J J", "Treys Full over Jacks"),
-    ("3 3 3 T T", "Treys Full over Tens"),
-    ("3 3 3 9 9", "Treys Full over Nines"),
-    ("3 3 3 8 8", "Treys Full over Eights"),
-    ("3 3 3 7 7", "Treys Full over Sevens"),
-    ("3 3 3 6 6", "Treys Full over Sixes"),
-    ("3 3 3 5 5", "Treys Full over Fives"),
-    ("3 3 3 4 4", "Treys Full over Fours"),
-    ("3 3 3 2 2", "Treys Full over Deuces"),
-    ("2 2 2 A A", "Deuces Full over Aces"),
-    ("2 2 2 K K", "Deuces Full over Kings"),
-    ("2 2 2 Q Q", "Deuces Full over Queens"),
-    ("2 2 2 J J", "Deuces Full over Jacks"),
-    ("2 2 2 T T", "Deuces Full over Tens"),
-    ("2 2 2 9 9", "Deuces Full over Nines"),
-    ("2 2 2 8 8", "Deuces Full over Eights"),
-    ("2 2 2 7 7", "Deuces Full over Sevens"),
-    ("2 2 2 6 6", "Deuces Full over Sixes"),
-    ("2 2 2 5 5", "Deuces Full over Fives"),
-    ("2 2 2 4 4", "Deuces Full over Fours"),
-    ("2 2 2 3 3", "Deuces Full over Treys"),
-    ("A K Q J 9", "Ace-High Flush"),
-    ("A K Q J 8", "Ace-High Flush"),
-    ("A K Q J 7", "Ace-High Flush"),
-    ("A K Q J 6", "Ace-High Flush"),
-    ("A K Q J 5", "Ace-High Flush"),
-    ("A K Q J 4", "Ace-High Flush"),
-    ("A K Q J 3", "Ace-High Flush"),
-    ("A K Q J 2", "Ace-High Flush"),
-    ("A K Q T 9", "Ace-High Flush"),
-    ("A K Q T 8", "Ace-High Flush"),
-    ("A K Q T 7", "Ace-High Flush"),
-    ("A K Q T 6", "Ace-High Flush"),
-    ("A K Q T 5", "Ace-High Flush"),
-    ("A K Q T 4", "Ace-High Flush"),
-    ("A K Q T 3", "Ace-High Flush"),
-    ("A K Q T 2", "Ace-High Flush"),
-    ("A K Q 9 8", "Ace-High Flush"),
-    ("A K Q 9 7", "Ace-High Flush"),
-    ("A K Q 9 6", "Ace-High Flush"),
-    ("A K Q 9 5", "Ace-High Flush"),
-    ("A K Q 9 4", "Ace-High Flush"),
-    ("A K Q 9 3", "Ace-High Flush"),
-    ("A K Q 9 2", "Ace-High Flush"),
-    ("A K Q 8 7", "Ace-High Flush"),
-    ("A K Q 8 6", "Ace-High Flush"),
-    ("A K Q 8 5", "Ace-High Flush"),
-    ("A K Q 8 4", "Ace-High Flush"),
-    ("A K Q 8 3", "Ace-High Flush"),
-    ("A K Q 8 2", "Ace-High Flush"),
-    ("A K Q 7 6", "Ace-High Flush"),
-    ("A K Q 7 5", "Ace-High Flush"),
-    ("A K Q 7 4", "Ace-High Flush"),
-    ("A K Q 7 3", "Ace-High Flush"),
-    ("A K Q 7 2", "Ace-High Flush"),
-    ("A K Q 6 5", "Ace-High Flush"),
-    ("A K Q 6 4", "Ace-High Flush"),
-    ("A K Q 6 3", "Ace-High Flush"),
-    ("A K Q 6 2", "Ace-High Flush"),
-    ("A K Q 5 4", "Ace-High Flush"),
-    ("A K Q 5 3", "Ace-High Flush"),
-    ("A K Q 5 2", "Ace-High Flush"),
-    ("A K Q 4 3", "Ace-High Flush"),
-    ("A K Q 4 2", "Ace-High Flush"),
-    ("A K Q 3 2", "Ace-High Flush"),
-    ("A K J T 9", "Ace-High Flush"),
-    ("A K J T 8", "Ace-High Flush"),
-    ("A K J T 7", "Ace-High Flush"),
-    ("A K J T 6", "Ace-High Flush"),
-    ("A K J T 5", "Ace-High Flush"),
-    ("A K J T 4", "Ace-High Flush"),
-    ("A K J T 3", "Ace-High Flush"),
-    ("A K J T 2", "Ace-High Flush"),
-    ("A K J 9 8", "Ace-High Flush"),
-    ("A K J 9 7", "Ace-High Flush"),
-    ("A K J 9 6", "Ace-High Flush"),
-    ("A K J 9 5", "Ace-High Flush"),
-    ("A K J 9 4", "Ace-High Flush"),
-    ("A K J 9 3", "Ace-High Flush"),
-    ("A K J 9 2", "Ace-High Flush"),
-    ("A K J 8 7", "Ace-High Flush"),
-    ("A K J 8 6", "Ace-High Flush"),
-    ("A K J 8 5", "Ace-High Flush"),
-    ("A K J 8 4", "Ace-High Flush"),
-    ("A K J 8 3", "Ace-High Flush"),
-    ("A K J 8 2", "Ace-High Flush"),
-    ("A K J 7 6", "Ace-High Flush"),
-    ("A K J 7 5", "Ace-High Flush"),
-    ("A K J 7 4", "Ace-High Flush"),
-    ("A K J 7 3", "Ace-High Flush"),
-    ("A K J 7 2", "Ace-High Flush"),
-    ("A K J 6 5", "Ace-High Flush"),
-    ("A K J 6 4", "Ace-High Flush"),
-    ("A K J 6 3", "Ace-High Flush"),
-    ("A K J 6 2", "Ace-High Flush"),
-    ("A K J 5 4", "Ace-High Flush"),
-    ("A K J 5 3", "Ace-High Flush"),
-    ("A K J 5 2", "Ace-High Flush"),
-    ("A K J 4 3", "Ace-High Flush"),
-    ("A K J 4 2", "Ace-High Flush"),
-    ("A K J 3 2", "Ace-High Flush"),
-    ("A K T 9 8", "Ace-High Flush"),
-    ("A K T 9 7", "Ace-High Flush"),
-    ("A K T 9 6", "Ace-High Flush"),
-    ("A K T 9 5", "Ace-High Flush"),
-    ("A K T 9 4", "Ace-High Flush"),
-    ("A K T 9 3", "Ace-High Flush"),
-    ("A K T 9 2", "Ace-High Flush"),
-    ("A K T 8 7", "Ace-High Flush"),
-    ("A K T 8 6", "Ace-High Flush"),
-    ("A K T 8 5", "Ace-High Flush"),
-    ("A K T 8 4", "Ace-High Flush"),
-    ("A K T 8 3", "Ace-High Flush"),
-    ("A K T 8 2", "Ace-High Flush"),
-    ("A K T 7 6", "Ace-High Flush"),
-    ("A K T 7 5", "Ace-High Flush"),
-    ("A K T 7 4", "Ace-High Flush"),
-    ("A K T 7 3", "Ace-High Flush"),
-    ("A K T 7 2", "Ace-High Flush"),
-    ("A K T 6 5", "Ace-High Flush"),
-    ("A K T 6 4", "Ace-High Flush"),
-    ("A K T 6 3", "Ace-High Flush"),
-    ("A K T 6 2", "Ace-High Flush"),
-    ("A K T 5 4", "Ace-High Flush"),
-    ("A K T 5 3", "Ace-High Flush"),
-    ("A K T 5 2", "Ace-High Flush"),
-    ("A K T 4 3", "Ace-High Flush"),
-    ("A K T 4 2", "Ace-High Flush"),
-    ("A K T 3 2", "Ace-High Flush"),
-    ("A K 9 8 7", "Ace-High Flush"),
-    ("A K 9 8 6", "Ace-High Flush"),
-    ("A K 9 8 5", "Ace-High Flush"),
-    ("A K 9 8 4", "Ace-High Flush"),
-    ("A K 9 8 3", "Ace-High Flush"),
-    ("A K 9 8 2", "Ace-High Flush"),
-    ("A K 9 7 6", "Ace-High Flush"),
-    ("A K 9 7 5", "Ace-High Flush"),
-    ("A K 9 7 4", "Ace-High Flush"),
-    ("A K 9 7 3", "Ace-High Flush"),
-    ("A K 9 7 2", "Ace-High Flush"),
-    ("A K 9 6 5", "Ace-High Flush"),
-    ("A K 9 6 4", "Ace-High Flush"),
-    ("A K 9 6 3", "Ace-High Flush"),
-    ("A K 9 6 2", "Ace-High Flush"),
-    ("A K 9 5 4", "Ace-High Flush"),
-    ("A K 9 5 3", "Ace-High Flush"),
-    ("A K 9 5 2", "Ace-High Flush"),
-    ("A K 9 4 3", "Ace-High Flush"),
-    ("A K 9 4 2", "Ace-High Flush"),
-    ("A K 9 3 2", "Ace-High Flush"),
-    ("A K 8 7 6", "Ace-High Flush"),
-    ("A K 8 7 5", "Ace-High Flush"),
-    ("A K 8 7 4", "Ace-High Flush"),
-    ("A K 8 7 3", "Ace-High Flush"),
-    ("A K 8 7 2", "Ace-High Flush"),
-    ("A K 8 6 5", "Ace-High Flush"),
-    ("A K 8 6 4", "Ace-High Flush"),
-    ("A K 8 6 3", "Ace-High Flush"),
-    ("A K 8 6 2", "Ace-High Flush"),
-    ("A K 8 5 4", "Ace-High Flush"),
-    ("A K 8 5 3", "Ace-High Flush"),
-    ("A K 8 5 2", "Ace-High Flush"),
-    ("A K 8 4 3", "Ace-High Flush"),
-    ("A K 8 4 2", "Ace-High Flush"),
-    ("A K 8 3 2", "Ace-High Flush"),
-    ("A K 7 6 5", "Ace-High Flush"),
-    ("A K 7 6 4", "Ace-High Flush"),
-    ("A K 7 6 3", "Ace-High Flush"),
-    ("A K 7 6 2", "Ace-High Flush"),
-    ("A K 7 5 4", "Ace-High Flush"),
-    ("A K 7 5 3", "Ace-High Flush"),
-    ("A K 7 5 2", "Ace-High Flush"),
-    ("A K 7 4 3", "Ace-High Flush"),
-    ("A K 7 4 2", "Ace-High Flush"),
-    ("A K 7 3 2", "Ace-High Flush"),
-    ("A K 6 5 4", "Ace-High Flush"),
-    ("A K 6 5 3", "Ace-High Flush"),
-    ("A K 6 5 2", "Ace-High Flush"),
-    ("A K 6 4 3", "Ace-High Flush"),
-    ("A K 6 4 2", "Ace-High Flush"),
-    ("A K 6 3 2", "Ace-High Flush"),
-    ("A K 5 4 3", "Ace-High Flush"),
-    ("A K 5 4 2", "Ace-High Flush"),
-    ("A K 5 3 2", "Ace-High Flush"),
-    ("A K 4 3 2", "Ace-High Flush"),
-    ("A Q J T 9", "Ace-High Flush"),
-    ("A Q J T 8", "Ace-High Flush"),
-    ("A Q J T 7", "Ace-High Flush"),
-    ("A Q J T 6", "Ace-High Flush"),
-    ("A Q J T 5", "Ace-High Flush"),
-    ("A Q J T 4", "Ace-High Flush"),
-    ("A Q J T 3", "Ace-High Flush"),
-    ("A Q J T 2", "Ace-High Flush"),
-    ("A Q J 9 8", "Ace-High Flush"),
-    ("A Q J 9 7", "Ace-High Flush"),
-    ("A Q J 9 6", "Ace-High Flush"),
-    ("A Q J 9 5", "Ace-High Flush"),
-    ("A Q J 9 4", "Ace-High Flush"),
-    ("A Q J 9 3", "Ace-High Flush"),
-    ("A Q J 9 2", "Ace-High Flush"),
-    ("A Q J 8 7", "Ace-High Flush"),
-    ("A Q J 8 6", "Ace-High Flush"),
-    ("A Q J 8 5", "Ace-High Flush"),
-    ("A Q J 8 4", "Ace-High Flush"),
-    ("A Q J 8 3", "Ace-High Flush"),
-    ("A Q J 8 2", "Ace-High Flush"),
-    ("A Q J 7 6", "Ace-High Flush"),
-    ("A Q J 7 5", "Ace-High Flush"),
-    ("A Q J 7 4", "Ace-High Flush"),
-    ("A Q J 7 3", "Ace-High Flush"),
-    ("A Q J 7 2", "Ace-High Flush"),
-    ("A Q J 6 5", "Ace-High Flush"),
-    ("A Q J 6 4", "Ace-High Flush"),
-    ("A Q J 6 3", "Ace-High Flush"),
-    ("A Q J 6 2", "Ace-High Flush"),
-    ("A Q J 5 4", "Ace-High Flush"),
-    ("A Q J 5 3", "Ace-High Flush"),
-    ("A Q J 5 2", "Ace-High Flush"),
-    ("A Q J 4 3", "Ace-High Flush"),
-    ("A Q J 4 2", "Ace-High Flush"),
-    ("A Q J 3 2", "Ace-High Flush"),
-    ("A Q T 9 8", "Ace-High Flush"),
-    ("A Q T 9 7", "Ace-High Flush"),
-    ("A Q T 9 6", "Ace-High Flush"),
-    ("A Q T 9 5", "Ace-High Flush"),
-    ("A Q T 9 4", "Ace-High Flush"),
-    ("A Q T 9 3", "Ace-High Flush"),
-    ("A Q T 9 2", "Ace-High Flush"),
-    ("A Q T 8 7", "Ace-High Flush"),
-    ("A Q T 8 6", "Ace-High Flush"),
-    ("A Q T 8 5", "Ace-High Flush"),
-    ("A Q T 8 4", "Ace-High Flush"),
-    ("A Q T 8 3", "Ace-High Flush"),
-    ("A Q T 8 2", "Ace-High Flush"),
-    ("A Q T 7 6", "Ace-High Flush"),
-    ("A Q T 7 5", "Ace-High Flush"),
-    ("A Q T 7 4", "Ace-High Flush"),
-    ("A Q T 7 3", "Ace-High Flush"),
-    ("A Q T 7 2", "Ace-High Flush"),
-    ("A Q T 6 5", "Ace-High Flush"),
-    ("A Q T 6 4", "Ace-High Flush"),
-    ("A Q T 6 3", "Ace-High Flush"),
-    ("A Q T 6 2", "Ace-High Flush"),
-    ("A Q T 5 4", "Ace-High Flush"),
-    ("A Q T 5 3", "Ace-High Flush"),
-    ("A Q T 5 2", "Ace-High Flush"),
-    ("A Q T 4 3", "Ace-High Flush"),
-    ("A Q T 4 2", "Ace-High Flush"),
-    ("A Q T 3 2", "Ace-High Flush"),
-    ("A Q 9 8 7", "Ace-High Flush"),
-    ("A Q 9 8 6", "Ace-High Flush"),
-    ("A Q 9 8 5", "Ace-High Flush"),
-    ("A Q 9 8 4", "Ace-High Flush"),
-    ("A Q 9 8 3", "Ace-High Flush"),
-    ("A Q 9 8 2", "Ace-High Flush"),
-    ("A Q 9 7 6", "Ace-High Flush"),
-    ("A Q 9 7 5", "Ace-High Flush"),
-    ("A Q 9 7 4", "Ace-High Flush"),
-    ("A Q 9 7 3", "Ace-High Flush"),
-    ("A Q 9 7 2", "Ace-High Flush"),
-    ("A Q 9 6 5", "Ace-High Flush"),
-    ("A Q 9 6 4", "Ace-High Flush"),
-    ("A Q 9 6 3", "Ace-High Flush"),
-    ("A Q 9 6 2", "Ace-High Flush"),
-    ("A Q 9 5 4", "Ace-High Flush"),
-    ("A Q 9 5 3", "Ace-High Flush"),
-    ("A Q 9 5 2", "Ace-High Flush"),
-    ("A Q 9 4 3", "Ace-High Flush"),
-    ("A Q 9 4 2", "Ace-High Flush"),
-    ("A Q 9 3 2", "Ace-High Flush"),
-    ("A Q 8 7 6", "Ace-High Flush"),
-    ("A Q 8 7 5", "Ace-High Flush"),
-    ("A Q 8 7 4", "Ace-High Flush"),
-    ("A Q 8 7 3", "Ace-High Flush"),
-    ("A Q 8 7 2", "Ace-High Flush"),
-    ("A Q 8 6 5", "Ace-High Flush"),
-    ("A Q 8 6 4", "Ace-High Flush"),
-    ("A Q 8 6 3", "Ace-High Flush"),
-    ("A Q 8 6 2", "Ace-High Flush"),
-    ("A Q 8 5 4", "Ace-High Flush"),
-    ("A Q 8 5 3", "Ace-High Flush"),
-    ("A Q 8 5 2", "Ace-High Flush"),
-    ("A Q 8 4 3", "Ace-High Flush"),
-    ("A Q 8 4 2", "Ace-High Flush"),
-    ("A Q 8 3 2", "Ace-High Flush"),
-    ("A Q 7 6 5", "Ace-High Flush"),
-    ("A Q 7 6 4", "Ace-High Flush"),
-    ("A Q 7 6 3", "Ace-High Flush"),
-    ("A Q 7 6 2", "Ace-High Flush"),
-    ("A Q 7 5 4", "Ace-High Flush"),
-    ("A Q 7 5 3", "Ace-High Flush"),
-    ("A Q 7 5 2", "Ace-High Flush"),
-    ("A Q 7 4 3", "Ace-High Flush"),
-    ("A Q 7 4 2", "Ace-High Flush"),
-    ("A Q 7 3 2", "Ace-High Flush"),
-    ("A Q 6 5 4", "Ace-High Flush"),
-    ("A Q 6 5 3", "Ace-High Flush"),
-    ("A Q 6 5 2", "Ace-High Flush"),
-    ("A Q 6 4 3", "Ace-High Flush"),
-    ("A Q 6 4 2", "Ace-High Flush"),
-    ("A Q 6 3 2", "Ace-High Flush"),
-    ("A Q 5 4 3", "Ace-High Flush"),
-    ("A Q 5 4 2", "Ace-High Flush"),
-    ("A Q 5 3 2", "Ace-High Flush"),
-    ("A Q 4 3 2", "Ace-High Flush"),
-    ("A J T 9 8", "Ace-High Flush"),
-    ("A J T 9 7", "Ace-High Flush"),
-    ("A J T 9 6", "Ace-High Flush"),
-    ("A J T 9 5", "Ace-High Flush"),
-    ("A J T 9 4", "Ace-High Flush"),
-    ("A J T 9 3", "Ace-High Flush"),
-    ("A J T 9 2", "Ace-High Flush"),
-    ("A J T 8 7", "Ace-High Flush"),
-    ("A J T 8 6", "Ace-High Flush"),
-    ("A J T 8 5", "Ace-High Flush"),
-    ("A J T 8 4", "Ace-High Flush"),
-    ("A J T 8 3", "Ace-High Flush"),
-    ("A J T 8 2", "Ace-High Flush"),
-    ("A J T 7 6", "Ace-High Flush"),
-    ("A J T 7 5", "Ace-High Flush"),
-    ("A J T 7 4", "Ace-High Flush"),
-    ("A J T 7 3", "Ace-High Flush"),
-    ("A J T 7 2", "Ace-High Flush"),
-    ("A J T 6 5", "Ace-High Flush"),
-    ("A J T 6 4", "Ace-High Flush"),
-    ("A J T 6 3", "Ace-High Flush"),
-    ("A J T 6 2", "Ace-High Flush"),
-    ("A J T 5 4", "Ace-High Flush"),
-    ("A J T 5 3", "Ace-High Flush"),
-    ("A J T 5 2", "Ace-High Flush"),
-    ("A J T 4 3", "Ace-High Flush"),
-    ("A J T 4 2", "Ace-High Flush"),
-    ("A J T 3 2", "Ace-High Flush"),
-    ("A J 9 8 7", "Ace-High Flush"),
-    ("A J 9 8 6", "Ace-High Flush"),
-    ("A J 9 8 5", "Ace-High Flush"),
-    ("A J 9 8 4", "Ace-High Flush"),
-    ("A J 9 8 3", "Ace-High Flush"),
-    ("A J 9 8 2", "Ace-High Flush"),
-    ("A J 9 7 6", "Ace-High Flush"),
-    ("A J 9 7 5", "Ace-High Flush"),
-    ("A J 9 7 4", "Ace-High Flush"),
-    ("A J 9 7 3", "Ace-High Flush"),
-    ("A J 9 7 2", "Ace-High Flush"),
-    ("A J 9 6 5", "Ace-High Flush"),
-    ("A J 9 6 4", "Ace-High Flush"),
-    ("A J 9 6 3", "Ace-High Flush"),
-    ("A J 9 6 2", "Ace-High Flush"),
-    ("A J 9 5 4", "Ace-High Flush"),
-    ("A J 9 5 3", "Ace-High Flush"),
-    ("A J 9 5 2", "Ace-High Flush"),
-    ("A J 9 4 3", "Ace-High Flush"),
-    ("A J 9 4 2", "Ace-High Flush"),
-    ("A J 9 3 2", "Ace-High Flush"),
-    ("A J 8 7 6", "Ace-High Flush"),
-    ("A J 8 7 5", "Ace-High Flush"),
-    ("A J 8 7 4", "Ace-High Flush"),
-    ("A J 8 7 3", "Ace-High Flush"),
-    ("A J 8 7 2", "Ace-High Flush"),
-    ("A J 8 6 5", "Ace-High Flush"),
-    ("A J 8 6 4", "Ace-High Flush"),
-    ("A J 8 6 3", "Ace-High Flush"),
-    ("A J 8 6 2", "Ace-High Flush"),
-    ("A J 8 5 4", "Ace-High Flush"),
-    ("A J 8 5 3", "Ace-High Flush"),
-    ("A J 8 5 2", "Ace-High Flush"),
-    ("A J 8 4 3", "Ace-High Flush"),
-    ("A J 8 4 2", "Ace-High Flush"),
-    ("A J 8 3 2", "Ace-High Flush"),
-    ("A J 7 6 5", "Ace-High Flush"),
-    ("A J 7 6 4", "Ace-High Flush"),
-    ("A J 7 6 3", "Ace-High Flush"),
-    ("A J 7 6 2", "Ace-High Flush"),
-    ("A J 7 5 4", "Ace-High Flush"),
-    ("A J 7 5 3", "Ace-High Flush"),
-    ("A J 7 5 2", "Ace-High Flush"),
-    ("A J 7 4 3", "Ace-High Flush"),
-    ("A J 7 4 2", "Ace-High Flush"),
-    ("A J 7 3 2", "Ace-High Flush"),
-    ("A J 6 5 4", "Ace-High Flush"),
-    ("A J 6 5 3", "Ace-High Flush"),
-    ("A J 6 5 2", "Ace-High Flush"),
-    ("A J 6 4 3", "Ace-High Flush"),
-    ("A J 6 4 2", "Ace-High Flush"),
-    ("A J 6 3 2", "Ace-High Flush"),
-    ("A J 5 4 3", "Ace-High Flush"),
-    ("A J 5 4 2", "Ace-High Flush"),
-    ("A J 5 3 2", "Ace-High Flush"),
-    ("A J 4 3 2", "Ace-High Flush"),
-    ("A T 9 8 7", "Ace-High Flush"),
-    ("A T 9 8 6", "Ace-High Flush"),
-    ("A T 9 8 5", "Ace-High Flush"),
-    ("A T 9 8 4", "Ace-High Flush"),
-    ("A T 9 8 3", "Ace-High Flush"),
-    ("A T 9 8 2", "Ace-High Flush"),
-    ("A T 9 7 6", "Ace-High Flush"),
-    ("A T 9 7 5", "Ace-High Flush"),
-    ("A T 9 7 4", "Ace-High Flush"),
-    ("A T 9 7 3", "Ace-High Flush"),
-    ("A T 9 7 2", "Ace-High Flush"),
-    ("A T 9 6 5", "Ace-High Flush"),
-    ("A T 9 6 4", "Ace-High Flush"),
-    ("A T 9 6 3", "Ace-High Flush"),
-    ("A T 9 6 2", "Ace-High Flush"),
-    ("A T 9 5 4", "Ace-High Flush"),
-    ("A T 9 5 3", "Ace-High Flush"),
-    ("A T 9 5 2", "Ace-High Flush"),
-    ("A T 9 4 3", "Ace-High Flush"),
-    ("A T 9 4 2", "Ace-High Flush"),
-    ("A T 9 3 2", "Ace-High Flush"),
-    ("A T 8 7 6", "Ace-High Flush"),
-    ("A T 8 7 5", "Ace-High Flush"),
-    ("A T 8 7 4", "Ace-High Flush"),
-    ("A T 8 7 3", "Ace-High Flush"),
-    ("A T 8 7 2", "Ace-High Flush"),
-    ("A T 8 6 5", "Ace-High Flush"),
-    ("A T 8 6 4", "Ace-High Flush"),
-    ("A T 8 6 3", "Ace-High Flush"),
-    ("A T 8 6 2", "Ace-High Flush"),
-    ("A T 8 5 4", "Ace-High Flush"),
-    ("A T 8 5 3", "Ace-High Flush"),
-    ("A T 8 5 2", "Ace-High Flush"),
-    ("A T 8 4 3", "Ace-High Flush"),
-    ("A T 8 4 2", "Ace-High Flush"),
-    ("A T 8 3 2", "Ace-High Flush"),
-    ("A T 7 6 5", "Ace-High Flush"),
-    ("A T 7 6 4", "Ace-High Flush"),
-    ("A T 7 6 3", "Ace-High Flush"),
-    ("A T 7 6 2", "Ace-High Flush"),
-    ("A T 7 5 4", "Ace-High Flush"),
-    ("A T 7 5 3", "Ace-High Flush"),
-    ("A T 7 5 2", "Ace-High Flush"),
-    ("A T 7 4 3", "Ace-High Flush"),
-    ("A T 7 4 2", "Ace-High Flush"),
-    ("A T 7 3 2", "Ace-High Flush"),
-    ("A T 6 5 4", "Ace-High Flush"),
-    ("A T 6 5 3", "Ace-High Flush"),
-    ("A T 6 5 2", "Ace-High Flush"),
-    ("A T 6 4 3", "Ace-High Flush"),
-    ("A T 6 4 2", "Ace-High Flush"),
-    ("A T 6 3 2", "Ace-High Flush"),
-    ("A T 5 4 3", "Ace-High Flush"),
-    ("A T 5 4 2", "Ace-High Flush"),
-    ("A T 5 3 2", "Ace-High Flush"),
-    ("A T 4 3 2", "Ace-High Flush"),
-    ("A 9 8 7 6", "Ace-High Flush"),
-    ("A 9 8 7 5", "Ace-High Flush"),
-    ("A 9 8 7 4", "Ace-High Flush"),
-    ("A 9 8 7 3", "Ace-High Flush"),
-    ("A 9 8 7 2", "Ace-High Flush"),
-    ("A 9 8 6 5", "Ace-High Flush"),
-    ("A 9 8 6 4", "Ace-High Flush"),
-    ("A 9 8 6 3", "Ace-High Flush"),
-    ("A 9 8 6 2", "Ace-High Flush"),
-    ("A 9 8 5 4", "Ace-High Flush"),
-    ("A 9 8 5 3", "Ace-High Flush"),
-    ("A 9 8 5 2", "Ace-High Flush"),
-    ("A 9 8 4 3", "Ace-High Flush"),
-    ("A 9 8 4 2", "Ace-High Flush"),
-    ("A 9 8 3 2", "Ace-High Flush"),
-    ("A 9 7 6 5", "Ace-High Flush"),
-    ("A 9 7 6 4", "Ace-High Flush"),
-    ("A 9 7 6 3", "Ace-High Flush"),
-    ("A 9 7 6 2", "Ace-High Flush"),
-    ("A 9 7 5 4", "Ace-High Flush"),
-    ("A 9 7 5 3", "Ace-High Flush"),
-    ("A 9 7 5 2", "Ace-High Flush"),
-    ("A 9 7 4 3", "Ace-High Flush"),
-    ("A 9 7 4 2", "Ace-High Flush"),
-    ("A 9 7 3 2", "Ace-High Flush"),
-    ("A 9 6 5 4", "Ace-High Flush"),
-    ("A 9 6 5 3", "Ace-High Flush"),
-    ("A 9 6 5 2", "Ace-High Flush"),
-    ("A 9 6 4 3", "Ace-High Flush"),
-    ("A 9 6 4 2", "Ace-High Flush"),
-    ("A 9 6 3 2", "Ace-High Flush"),
-    ("A 9 5 4 3", "Ace-High Flush"),
-    ("A 9 5 4 2", "Ace-High Flush"),
-    ("A 9 5 3 2", "Ace-High Flush"),
-    ("A 9 4 3 2", "Ace-High Flush"),
-    ("A 8 7 6 5", "Ace-High Flush"),
-    ("A 8 7 6 4", "Ace-High Flush"),
-    ("A 8 7 6 3", "Ace-High Flush"),
-    ("A 8 7 6 2", "Ace-High Flush"),
-    ("A 8 7 5 4", "Ace-High Flush"),
-    ("A 8 7 5 3", "Ace-High Flush"),
-    ("A 8 7 5 2", "Ace-High Flush"),
-    ("A 8 7 4 3", "Ace-High Flush"),
-    ("A 8 7 4 2", "Ace-High Flush"),
-    ("A 8 7 3 2", "Ace-High Flush"),
-    ("A 8 6 5 4", "Ace-High Flush"),
-    ("A 8 6 5 3", "Ace-High Flush"),
-    ("A 8 6 5 2", "Ace-High Flush"),
-    ("A 8 6 4 3", "Ace-High Flush"),
-    ("A 8 6 4 2", "Ace-High Flush"),
-    ("A 8 6 3 2", "Ace-High Flush"),
-    ("A 8 5 4 3", "Ace-High Flush"),
-    ("A 8 5 4 2", "Ace-High Flush"),
-    ("A 8 5 3 2", "Ace-High Flush"),
-    ("A 8 4 3 2", "Ace-High Flush"),
-    ("A 7 6 5 4", "Ace-High Flush"),
-    ("A 7 6 5 3", "Ace-High Flush"),
-    ("A 7 6 5 2", "Ace-High Flush"),
-    ("A 7 6 4 3", "Ace-High Flush"),
-    ("A 7 6 4 2", "Ace-High Flush"),
-    ("A 7 6 3 2", "Ace-High Flush"),
-    ("A 7 5 4 3", "Ace-High Flush"),
-    ("A 7 5 4 2", "Ace-High Flush"),
-    ("A 7 5 3 2", "Ace-High Flush"),
-    ("A 7 4 3 2", "Ace-High Flush"),
-    ("A 6 5 4 3", "Ace-High Flush"),
-    ("A 6 5 4 2", "Ace-High Flush"),
-    ("A 6 5 3 2", "Ace-High Flush"),
-    ("A 6 4 3 2", "Ace-High Flush"),
-    ("K Q J T 8", "King-High Flush"),
-    ("K Q J T 7", "King-High Flush"),
-    ("K Q J T 6", "King-High Flush"),
-    ("K Q J T 5", "King-High Flush"),
-    ("K Q J T 4", "King-High Flush"),
-    ("K Q J T 3", "King-High Flush"),
-    ("K Q J T 2", "King-High Flush"),
-    ("K Q J 9 8", "King-High Flush"),
-    ("K Q J 9 7", "King-High Flush"),
-    ("K Q J 9 6", "King-High Flush"),
-    ("K Q J 9 5", "King-High Flush"),
-    ("K Q J 9 4", "King-High Flush"),
-    ("K Q J 9 3", "King-High Flush"),
-    ("K Q J 9 2", "King-High Flush"),
-    ("K Q J 8 7", "King-High Flush"),
-    ("K Q J 8 6", "King-High Flush"),
-    ("K Q J 8 5", "King-High Flush"),
-    ("K Q J 8 4", "King-High Flush"),
-    ("K Q J 8 3", "King-High Flush"),
-    ("K Q J 8 2", "King-High Flush"),
-    ("K Q J 7 6", "King-High Flush"),
-    ("K Q J 7 5", "King-High Flush"),
-    ("K Q J 7 4", "King-High Flush"),
-    ("K Q J 7 3", "King-High Flush"),
-    ("K Q J 7 2", "King-High Flush"),
-    ("K Q J 6 5", "King-High Flush"),
-    ("K Q J 6 4", "King-High Flush"),
-    ("K Q J 6 3", "King-High Flush"),
-    ("K Q J 6 2", "King-High Flush"),
-    ("K Q J 5 4", "King-High Flush"),
-    ("K Q J 5 3", "King-High Flush"),
-    ("K Q J 5 2", "King-High Flush"),
-    ("K Q J 4 3", "King-High Flush"),
-    ("K Q J 4 2", "King-High Flush"),
-    ("K Q J 3 2", "King-High Flush"),
-    ("K Q T 9 8", "King-High Flush"),
-    ("K Q T 9 7", "King-High Flush"),
-    ("K Q T 9 6", "King-High Flush"),
-    ("K Q T 9 5", "King-High Flush"),
-    ("K Q T 9 4", "King-High Flush"),
-    ("K Q T 9 3", "King-High Flush"),
-    ("K Q T 9 2", "King-High Flush"),
-    ("K Q T 8 7", "King-High Flush"),
-    ("K Q T 8 6", "King-High Flush"),
-    ("K Q T 8 5", "King-High Flush"),
-    ("K Q T 8 4", "King-High Flush"),
-    ("K Q T 8 3", "King-High Flush"),
-    ("K Q T 8 2", "King-High Flush"),
-    ("K Q T 7 6", "King-High Flush"),
-    ("K Q T 7 5", "King-High Flush"),
-    ("K Q T 7 4", "King-High Flush"),
-    ("K Q T 7 3", "King-High Flush"),
-    ("K Q T 7 2", "King-High Flush"),
-    ("K Q T 6 5", "King-High Flush"),
-    ("K Q T 6 4", "King-High Flush"),
-    ("K Q T 6 3", "King-High Flush"),
-    ("K Q T 6 2", "King-High Flush"),
-    ("K Q T 5 4", "King-High Flush"),
-    ("K Q T 5 3", "King-High Flush"),
-    ("K Q T 5 2", "King-High Flush"),
-    ("K Q T 4 3", "King-High Flush"),
-    ("K Q T 4 2", "King-High Flush"),
-    ("K Q T 3 2", "King-High Flush"),
-    ("K Q 9 8 7", "King-High Flush"),
-    ("K Q 9 8 6", "King-High Flush"),
-    ("K Q 9 8 5", "King-High Flush"),
-    ("K Q 9 8 4", "King-High Flush"),
-    ("K Q 9 8 3", "King-High Flush"),
-    ("K Q 9 8 2", "King-High Flush"),
-    ("K Q 9 7 6", "King-High Flush"),
-    ("K Q 9 7 5", "King-High Flush"),
-    ("K Q 9 7 4", "King-High Flush"),
-    ("K Q 9 7 3", "King-High Flush"),
-    ("K Q 9 7 2", "King-High Flush"),
-    ("K Q 9 6 5", "King-High Flush"),
-    ("K Q 9 6 4", "King-High Flush"),
-    ("K Q 9 6 3", "King-High Flush"),
-    ("K Q 9 6 2", "King-High Flush"),
-    ("K Q 9 5 4", "King-High Flush"),
-    ("K Q 9 5 3", "King-High Flush"),
-    ("K Q 9 5 2", "King-High Flush"),
-    ("K Q 9 4 3", "King-High Flush"),
-    ("K Q 9 4 2", "King-High Flush"),
-    ("K Q 9 3 2", "King-High Flush"),
-    ("K Q 8 7 6", "King-High Flush"),
-    ("K Q 8 7 5", "King-High Flush"),
-    ("K Q 8 7 4", "King-High Flush"),
-    ("K Q 8 7 3", "King-High Flush"),
-    ("K Q 8 7 2", "King-High Flush"),
-    ("K Q 8 6 5", "King-High Flush"),
-    ("K Q 8 6 4", "King-High Flush"),
-    ("K Q 8 6 3", "King-High Flush"),
-    ("K Q 8 6 2", "King-High Flush"),
-    ("K Q 8 5 4", "King-High Flush"),
-    ("K Q 8 5 3", "King-High Flush"),
-    ("K Q 8 5 2", "King-High Flush"),
-    ("K Q 8 4 3", "King-High Flush"),
-    ("K Q 8 4 2", "King-High Flush"),
-    ("K Q 8 3 2", "King-High Flush"),
-    ("K Q 7 6 5", "King-High Flush"),
-    ("K Q 7 6 4", "King-High Flush"),
-    ("K Q 7 6 3", "King-High Flush"),
-    ("K Q 7 6 2", "King-High Flush"),
-    ("K Q 7 5 4", "King-High Flush"),
-    ("K Q 7 5 3", "King-High Flush"),
-    ("K Q 7 5 2", "King-High Flush"),
-    ("K Q 7 4 3", "King-High Flush"),
-    ("K Q 7 4 2", "King-High Flush"),
-    ("K Q 7 3 2", "King-High Flush"),
-    ("K Q 6 5 4", "King-High Flush"),
-    ("K Q 6 5 3", "King-High Flush"),
-    ("K Q 6 5 2", "King-High Flush"),
-    ("K Q 6 4 3", "King-High Flush"),
-    ("K Q 6 4 2", "King-High Flush"),
-    ("K Q 6 3 2", "King-High Flush"),
-    ("K Q 5 4 3", "King-High Flush"),
-    ("K Q 5 4 2", "King-High Flush"),
-    ("K Q 5 3 2", "King-High Flush"),
-    ("K Q 4 3 2", "King-High Flush"),
-    ("K J T 9 8", "King-High Flush"),
-    ("K J T 9 7", "King-High Flush"),
-    ("K J T 9 6", "King-High Flush"),
-    ("K J T 9 5", "King-High Flush"),
-    ("K J T 9 4", "King-High Flush"),
-    ("K J T 9 3", "King-High Flush"),
-    ("K J T 9 2", "King-High Flush"),
-    ("K J T 8 7", "King-High Flush"),
-    ("K J T 8 6", "King-High Flush"),
-    ("K J T 8 5", "King-High Flush"),
-    ("K J T 8 4", "King-High Flush"),
-    ("K J T 8 3", "King-High Flush"),
-    ("K J T 8 2", "King-High Flush"),
-    ("K J T 7 6", "King-High Flush"),
-    ("K J T 7 5", "King-High Flush"),
-    ("K J T 7 4", "King-High Flush"),
-    ("K J T 7 3", "King-High Flush"),
-    ("K J T 7 2", "King-High Flush"),
-    ("K J T 6 5", "King-High Flush"),
-    ("K J T 6 4", "King-High Flush"),
-    ("K J T 6 3", "King-High Flush"),
-    ("K J T 6 2", "King-High Flush"),
-    ("K J T 5 4", "King-High Flush"),
-    ("K J T 5 3", "King-High Flush"),
-    ("K J T 5 2", "King-High Flush"),
-    ("K J T 4 3", "King-High Flush"),
-    ("K J T 4 2", "King-High Flush"),
-    ("K J T 3 2", "King-High Flush"),
-    ("K J 9 8 7", "King-High Flush"),
-    ("K J 9 8 6", "King-High Flush"),
-    ("K J 9 8 5", "King-High Flush"),
-    ("K J 9 8 4", "King-High Flush"),
-    ("K J 9 8 3", "King-High Flush"),
-    ("K J 9 8 2", "King-High Flush"),
-    ("K J 9 7 6", "King-High Flush"),
-    ("K J 9 7 5", "King-High Flush"),
-    ("K J 9 7 4", "King-High Flush"),
-    ("K J 9 7 3", "King-High Flush"),
-    ("K J 9 7 2", "King-High Flush"),
-    ("K J 9 6 5", "King-High Flush"),
-    ("K J 9 6 4", "King-High Flush"),
-    ("K J 9 6 3", "King-High Flush"),
-    ("K J 9 6 2", "King-High Flush"),
-    ("K J 9 5 4", "King-High Flush"),
-    ("K J 9 5 3", "King-High Flush"),
-    ("K J 9 5 2", "King-High Flush"),
-    ("K J 9 4 3", "King-High Flush"),
-    ("K J 9 4 2", "King-High Flush"),
-    ("K J 9 3 2", "King-High Flush"),
-    ("K J 8 7 6", "King-High Flush"),
-    ("K J 8 7 5", "King-High Flush"),
-    ("K J 8 7 4", "King-High Flush"),
-    ("K J 8 7 3", "King-High Flush"),
-    ("K J 8 7 2", "King-High Flush"),
-    ("K J 8 6 5", "King-High Flush"),
-    ("K J 8 6 4", "King-High Flush"),
-    ("K J 8 6 3", "King-High Flush"),
-    ("K J 8 6 2", "King-High Flush"),
-    ("K J 8 5 4", "King-High Flush"),
-    ("K J 8 5 3", "King-High Flush"),
-    ("K J 8 5 2", "King-High Flush"),
-    ("K J 8 4 3", "King-High Flush"),
-    ("K J 8 4 2", "King-High Flush"),
-    ("K J 8 3 2", "King-High Flush"),
-    ("K J 7 6 5", "King-High Flush"),
-    ("K J 7 6 4", "King-High Flush"),
-    ("K J 7 6 3", "King-High Flush"),
-    ("K J 7 6 2", "King-High Flush"),
-    ("K J 7 5 4", "King-High Flush"),
-    ("K J 7 5 3", "King-High Flush"),
-    ("K J 7 5 2", "King-High Flush"),
-    ("K J 7 4 3", "King-High Flush"),
-    ("K J 7 4 2", "King-High Flush"),
-    ("K J 7 3 2", "King-High Flush"),
-    ("K J 6 5 4", "King-High Flush"),
-    ("K J 6 5 3", "King-High Flush"),
-    ("K J 6 5 2", "King-High Flush"),
-    ("K J 6 4 3", "King-High Flush"),
-    ("K J 6 4 2", "King-High Flush"),
-    ("K J 6 3 2", "King-High Flush"),
-    ("K J 5 4 3", "King-High Flush"),
-    ("K J 5 4 2", "King-High Flush"),
-    ("K J 5 3 2", "King-High Flush"),
-    ("K J 4 3 2", "King-High Flush"),
-    ("K T 9 8 7", "King-High Flush"),
-    ("K T 9 8 6", "King-High Flush"),
-    ("K T 9 8 5", "King-High Flush"),
-    ("K T 9 8 4", "King-High Flush"),
-    ("K T 9 8 3", "King-High Flush"),
-    ("K T 9 8 2", "King-High Flush"),
-    ("K T 9 7 6", "King-High Flush"),
-    ("K T 9 7 5", "King-High Flush"),
-    ("K T 9 7 4", "King-High Flush"),
-    ("K T 9 7 3", "King-High Flush"),
-    ("K T 9 7 2", "King-High Flush"),
-    ("K T 9 6 5", "King-High Flush"),
-    ("K T 9 6 4", "King-High Flush"),
-    ("K T 9 6 3", "King-High Flush"),
-    ("K T 9 6 2", "King-High Flush"),
-    ("K T 9 5 4", "King-High Flush"),
-    ("K T 9 5 3", "King-High Flush"),
-    ("K T 9 5 2", "King-High Flush"),
-    ("K T 9 4 3", "King-High Flush"),
-    ("K T 9 4 2", "King-High Flush"),
-    ("K T 9 3 2", "King-High Flush"),
-    ("K T 8 7 6", "King-High Flush"),
-    ("K T 8 7 5", "King-High Flush"),
-    ("K T 8 7 4", "King-High Flush"),
-    ("K T 8 7 3", "King-High Flush"),
-    ("K T 8 7 2", "King-High Flush"),
-    ("K T 8 6 5", "King-High Flush"),
-    ("K T 8 6 4", "King-High Flush"),
-    ("K T 8 6 3", "King-High Flush"),
-    ("K T 8 6 2", "King-High Flush"),
-    ("K T 8 5 4", "King-High Flush"),
-    ("K T 8 5 3", "King-High Flush"),
-    ("K T 8 5 2", "King-High Flush"),
-    ("K T 8 4 3", "King-High Flush"),
-    ("K T 8 4 2", "King-High Flush"),
-    ("K T 8 3 2", "King-High Flush"),
-    ("K T 7 6 5", "King-High Flush"),
-    ("K T 7 6 4", "King-High Flush"),
-    ("K T 7 6 3", "King-High Flush"),
-    ("K T 7 6 2", "King-High Flush"),
-    ("K T 7 5 4", "King-High Flush"),
-    ("K T 7 5 3", "King-High Flush"),
-    ("K T 7 5 2", "King-High Flush"),
-    ("K T 7 4 3", "King-High Flush"),
-    ("K T 7 4 2", "King-High Flush"),
-    ("K T 7 3 2", "King-High Flush"),
-    ("K T 6 5 4", "King-High Flush"),
-    ("K T 6 5 3", "King-High Flush"),
-    ("K T 6 5 2", "King-High Flush"),
-    ("K T 6 4 3", "King-High Flush"),
-    ("K T 6 4 2", "King-High Flush"),
-    ("K T 6 3 2", "King-High Flush"),
-    ("K T 5 4 3", "King-High Flush"),
-    ("K T 5 4 2", "King-High Flush"),
-    ("K T 5 3 2", "King-High Flush"),
-    ("K T 4 3 2", "King-High Flush"),
-    ("K 9 8 7 6", "King-High Flush"),
-    ("K 9 8 7 5", "King-High Flush"),
-    ("K 9 8 7 4", "King-High Flush"),
-    ("K 9 8 7 3", "King-High Flush"),
-    ("K 9 8 7 2", "King-High Flush"),
-    ("K 9 8 6 5", "King-High Flush"),
-    ("K 9 8 6 4", "King-High Flush"),
-    ("K 9 8 6 3", "King-High Flush"),
-    ("K 9 8 6 2", "King-High Flush"),
-    ("K 9 8 5 4", "King-High Flush"),
-    ("K 9 8 5 3", "King-High Flush"),
-    ("K 9 8 5 2", "King-High Flush"),
-    ("K 9 8 4 3", "King-High Flush"),
-    ("K 9 8 4 2", "King-High Flush"),
-    ("K 9 8 3 2", "King-High Flush"),
-    ("K 9 7 6 5", "King-High Flush"),
-    ("K 9 7 6 4", "King-High Flush"),
-    ("K 9 7 6 3", "King-High Flush"),
-    ("K 9 7 6 2", "King-High Flush"),
-    ("K 9 7 5 4", "King-High Flush"),
-    ("K 9 7 5 3", "King-High Flush"),
-    ("K 9 7 5 2", "King-High Flush"),
-    ("K 9 7 4 3", "King-High Flush"),
-    ("K 9 7 4 2", "King-High Flush"),
-    ("K 9 7 3 2", "King-High Flush"),
-    ("K 9 6 5 4", "King-High Flush"),
-    ("K 9 6 5 3", "King-High Flush"),
-    ("K 9 6 5 2", "King-High Flush"),
-    ("K 9 6 4 3", "King-High Flush"),
-    ("K 9 6 4 2", "King-High Flush"),
-    ("K 9 6 3 2", "King-High Flush"),
-    ("K 9 5 4 3", "King-High Flush"),
-    ("K 9 5 4 2", "King-High Flush"),
-    ("K 9 5 3 2", "King-High Flush"),
-    ("K 9 4 3 2", "King-High Flush"),
-    ("K 8 7 6 5", "King-High Flush"),
-    ("K 8 7 6 4", "King-High Flush"),
-    ("K 8 7 6 3", "King-High Flush"),
-    ("K 8 7 6 2", "King-High Flush"),
-    ("K 8 7 5 4", "King-High Flush"),
-    ("K 8 7 5 3", "King-High Flush"),
-    ("K 8 7 5 2", "King-High Flush"),
-    ("K 8 7 4 3", "King-High Flush"),
-    ("K 8 7 4 2", "King-High Flush"),
-    ("K 8 7 3 2", "King-High Flush"),
-    ("K 8 6 5 4", "King-High Flush"),
-    ("K 8 6 5 3", "King-High Flush"),
-    ("K 8 6 5 2", "King-High Flush"),
-    ("K 8 6 4 3", "King-High Flush"),
-    ("K 8 6 4 2", "King-High Flush"),
-    ("K 8 6 3 2", "King-High Flush"),
-    ("K 8 5 4 3", "King-High Flush"),
-    ("K 8 5 4 2", "King-High Flush"),
-    ("K 8 5 3 2", "King-High Flush"),
-    ("K 8 4 3 2", "King-High Flush"),
-    ("K 7 6 5 4", "King-High Flush"),
-    ("K 7 6 5 3", "King-High Flush"),
-    ("K 7 6 5 2", "King-High Flush"),
-    ("K 7 6 4 3", "King-High Flush"),
-    ("K 7 6 4 2", "King-High Flush"),
-    ("K 7 6 3 2", "King-High Flush"),
-    ("K 7 5 4 3", "King-High Flush"),
-    ("K 7 5 4 2", "King-High Flush"),
-    ("K 7 5 3 2", "King-High Flush"),
-    ("K 7 4 3 2", "King-High Flush"),
-    ("K 6 5 4 3", "King-High Flush"),
-    ("K 6 5 4 2", "King-High Flush"),
-    ("K 6 5 3 2", "King-High Flush"),
-    ("K 6 4 3 2", "King-High Flush"),
-    ("K 5 4 3 2", "King-High Flush"),
-    ("Q J T 9 7", "Queen-High Flush"),
-    ("Q J T 9 6", "Queen-High Flush"),
-    ("Q J T 9 5", "Queen-High Flush"),
-    ("Q J T 9 4", "Queen-High Flush"),
-    ("Q J T 9 3", "Queen-High Flush"),
-    ("Q J T 9 2", "Queen-High Flush"),
-    ("Q J T 8 7", "Queen-High Flush"),
-    ("Q J T 8 6", "Queen-High Flush"),
-    ("Q J T 8 5", "Queen-High Flush"),
-    ("Q J T 8 4", "Queen-High Flush"),
-    ("Q J T 8 3", "Queen-High Flush"),
-    ("Q J T 8 2", "Queen-High Flush"),
-    ("Q J T 7 6", "Queen-High Flush"),
-    ("Q J T 7 5", "Queen-High Flush"),
-    ("Q J T 7 4", "Queen-High Flush"),
-    ("Q J T 7 3", "Queen-High Flush"),
-    ("Q J T 7 2", "Queen-High Flush"),
-    ("Q J T 6 5", "Queen-High Flush"),
-    ("Q J T 6 4", "Queen-High Flush"),
-    ("Q J T 6 3", "Queen-High Flush"),
-    ("Q J T 6 2", "Queen-High Flush"),
-    ("Q J T 5 4", "Queen-High Flush"),
-    ("Q J T 5 3", "Queen-High Flush"),
-    ("Q J T 5 2", "Queen-High Flush"),
-    ("Q J T 4 3", "Queen-High Flush"),
-    ("Q J T 4 2", "Queen-High Flush"),
-    ("Q J T 3 2", "Queen-High Flush"),
-    ("Q J 9 8 7", "Queen-High Flush"),
-    ("Q J 9 8 6", "Queen-High Flush"),
-    ("Q J 9 8 5", "Queen-High Flush"),
-    ("Q J 9 8 4", "Queen-High Flush"),
-    ("Q J 9 8 3", "Queen-High Flush"),
-    ("Q J 9 8 2", "Queen-High Flush"),
-    ("Q J 9 7 6", "Queen-High Flush"),
-    ("Q J 9 7 5", "Queen-High Flush"),
-    ("Q J 9 7 4", "Queen-High Flush"),
-    ("Q J 9 7 3", "Queen-High Flush"),
-    ("Q J 9 7 2", "Queen-High Flush"),
-    ("Q J 9 6 5", "Queen-High Flush"),
-    ("Q J 9 6 4", "Queen-High Flush"),
-    ("Q J 9 6 3", "Queen-High Flush"),
-    ("Q J 9 6 2", "Queen-High Flush"),
-    ("Q J 9 5 4", "Queen-High Flush"),
-    ("Q J 9 5 3", "Queen-High Flush"),
-    ("Q J 9 5 2", "Queen-High Flush"),
-    ("Q J 9 4 3", "Queen-High Flush"),
-    ("Q J 9 4 2", "Queen-High Flush"),
-    ("Q J 9 3 2", "Queen-High Flush"),
-    ("Q J 8 7 6", "Queen-High Flush"),
-    ("Q J 8 7 5", "Queen-High Flush"),
-    ("Q J 8 7 4", "Queen-High Flush"),
-    ("Q J 8 7 3", "Queen-High Flush"),
-    ("Q J 8 7 2", "Queen-High Flush"),
-    ("Q J 8 6 5", "Queen-High Flush"),
-    ("Q J 8 6 4", "Queen-High Flush"),
-    ("Q J 8 6 3", "Queen-High Flush"),
-    ("Q J 8 6 2", "Queen-High Flush"),
-    ("Q J 8 5 4", "Queen-High Flush"),
-    ("Q J 8 5 3", "Queen-High Flush"),
-    ("Q J 8 5 2", "Queen-High Flush"),
-    ("Q J 8 4 3", "Queen-High Flush"),
-    ("Q J 8 4 2", "Queen-High Flush"),
-    ("Q J 8 3 2", "Queen-High Flush"),
-    ("Q J 7 6 5", "Queen-High Flush"),
-    ("Q J 7 6 4", "Queen-High Flush"),
-    ("Q J 7 6 3", "Queen-High Flush"),
-    ("Q J 7 6 2", "Queen-High Flush"),
-    ("Q J 7 5 4", "Queen-High Flush"),
-    ("Q J 7 5 3", "Queen-High Flush"),
-    ("Q J 7 5 2", "Queen-High Flush"),
-    ("Q J 7 4 3", "Queen-High Flush"),
-    ("Q J 7 4 2", "Queen-High Flush"),
-    ("Q J 7 3 2", "Queen-High Flush"),
-    ("Q J 6 5 4", "Queen-High Flush"),
-    ("Q J 6 5 3", "Queen-High Flush"),
-    ("Q J 6 5 2", "Queen-High Flush"),
-    ("Q J 6 4 3", "Queen-High Flush"),
-    ("Q J 6 4 2", "Queen-High Flush"),
-    ("Q J 6 3 2", "Queen-High Flush"),
-    ("Q J 5 4 3", "Queen-High Flush"),
-    ("Q J 5 4 2", "Queen-High Flush"),
-    ("Q J 5 3 2", "Queen-High Flush"),
-    ("Q J 4 3 2", "Queen-High Flush"),
-    ("Q T 9 8 7", "Queen-High Flush"),
-    ("Q T 9 8 6", "Queen-High Flush"),
-    ("Q T 9 8 5", "Queen-High Flush"),
-    ("Q T 9 8 4", "Queen-High Flush"),
-    ("Q T 9 8 3", "Queen-High Flush"),
-    ("Q T 9 8 2", "Queen-High Flush"),
-    ("Q T 9 7 6", "Queen-High Flush"),
-    ("Q T 9 7 5", "Queen-High Flush"),
-    ("Q T 9 7 4", "Queen-High Flush"),
-    ("Q T 9 7 3", "Queen-High Flush"),
-    ("Q T 9 7 2", "Queen-High Flush"),
-    ("Q T 9 6 5", "Queen-High Flush"),
-    ("Q T 9 6 4", "Queen-High Flush"),
-    ("Q T 9 6 3", "Queen-High Flush"),
-    ("Q T 9 6 2", "Queen-High Flush"),
-    ("Q T 9 5 4", "Queen-High Flush"),
-    ("Q T 9 5 3", "Queen-High Flush"),
-    ("Q T 9 5 2", "Queen-High Flush"),
-    ("Q T 9 4 3", "Queen-High Flush"),
-    ("Q T 9 4 2", "Queen-High Flush"),
-    ("Q T 9 3 2", "Queen-High Flush"),
-    ("Q T 8 7 6", "Queen-High Flush"),
-    ("Q T 8 7 5", "Queen-High Flush"),
-    ("Q T 8 7 4", "Queen-High Flush"),
-    ("Q T 8 7 3", "Queen-High Flush"),
-    ("Q T 8 7 2", "Queen-High Flush"),
-    ("Q T 8 6 5", "Queen-High Flush"),
-    ("Q T 8 6 4", "Queen-High Flush"),
-    ("Q T 8 6 3", "Queen-High Flush"),
-    ("Q T 8 6 2", "Queen-High Flush"),
-    ("Q T 8 5 4", "Queen-High Flush"),
-    ("Q T 8 5 3", "Queen-High Flush"),
-    ("Q T 8 5 2", "Queen-High Flush"),
-    ("Q T 8 4 3", "Queen-High Flush"),
-    ("Q T 8 4 2", "Queen-High Flush"),
-    ("Q T 8 3 2", "Queen-High Flush"),
-    ("Q T 7 6 5", "Queen-High Flush"),
-    ("Q T 7 6 4", "Queen-High Flush"),
-    ("Q T 7 6 3", "Queen-High Flush"),
-    ("Q T 7 6 2", "Queen-High Flush"),
-    ("Q T 7 5 4", "Queen-High Flush"),
-    ("Q T 7 5 3", "Queen-High Flush"),
-    ("Q T 7 5 2", "Queen-High Flush"),
-    ("Q T 7 4 3", "Queen-High Flush"),
-    ("Q T 7 4 2", "Queen-High Flush"),
-    ("Q T 7 3 2", "Queen-High Flush"),
-    ("Q T 6 5 4", "Queen-High Flush"),
-    ("Q T 6 5 3", "Queen-High Flush"),
-    ("Q T 6 5 2", "Queen-High Flush"),
-    ("Q T 6 4 3", "Queen-High Flush"),
-    ("Q T 6 4 2", "Queen-High Flush"),
-    ("Q T 6 3 2", "Queen-High Flush"),
-    ("Q T 5 4 3", "Queen-High Flush"),
-    ("Q T 5 4 2", "Queen-High Flush"),
-    ("Q T 5 3 2", "Queen-High Flush"),
-    ("Q T 4 3 2", "Queen-High Flush"),
-    ("Q 9 8 7 6", "Queen-High Flush"),
-    ("Q 9 8 7 5", "Queen-High Flush"),
-    ("Q 9 8 7 4", "Queen-High Flush"),
-    ("Q 9 8 7 3", "Queen-High Flush"),
-    ("Q 9 8 7 2", "Queen-High Flush"),
-    ("Q 9 8 6 5", "Queen-High Flush"),
-    ("Q 9 8 6 4", "Queen-High Flush"),
-    ("Q 9 8 6 3", "Queen-High Flush"),
-    ("Q 9 8 6 2", "Queen-High Flush"),
-    ("Q 9 8 5 4", "Queen-High Flush"),
-    ("Q 9 8 5 3", "Queen-High Flush"),
-    ("Q 9 8 5 2", "Queen-High Flush"),
-    ("Q 9 8 4 3", "Queen-High Flush"),
-    ("Q 9 8 4 2", "Queen-High Flush"),
-    ("Q 9 8 3 2", "Queen-High Flush"),
-    ("Q 9 7 6 5", "Queen-High Flush"),
-    ("Q 9 7 6 4", "Queen-High Flush"),
-    ("Q 9 7 6 3", "Queen-High Flush"),
-    ("Q 9 7 6 2", "Queen-High Flush"),
-    ("Q 9 7 5 4", "Queen-High Flush"),
-    ("Q 9 7 5 3", "Queen-High Flush"),
-    ("Q 9 7 5 2", "Queen-High Flush"),
-    ("Q 9 7 4 3", "Queen-High Flush"),
-    ("Q 9 7 4 2", "Queen-High Flush"),
-    ("Q 9 7 3 2", "Queen-High Flush"),
-    ("Q 9 6 5 4", "Queen-High Flush"),
-    ("Q 9 6 5 3", "Queen-High Flush"),
-    ("Q 9 6 5 2", "Queen-High Flush"),
-    ("Q 9 6 4 3", "Queen-High Flush"),
-    ("Q 9 6 4 2", "Queen-High Flush"),
-    ("Q 9 6 3 2", "Queen-High Flush"),
-    ("Q 9 5 4 3", "Queen-High Flush"),
-    ("Q 9 5 4 2", "Queen-High Flush"),
-    ("Q 9 5 3 2", "Queen-High Flush"),
-    ("Q 9 4 3 2", "Queen-High Flush"),
-    ("Q 8 7 6 5", "Queen-High Flush"),
-    ("Q 8 7 6 4", "Queen-High Flush"),
-    ("Q 8 7 6 3", "Queen-High Flush"),
-    ("Q 8 7 6 2", "Queen-High Flush"),
-    ("Q 8 7 5 4", "Queen-High Flush"),
-    ("Q 8 7 5 3", "Queen-High Flush"),
-    ("Q 8 7 5 2", "Queen-High Flush"),
-    ("Q 8 7 4 3", "Queen-High Flush"),
-    ("Q 8 7 4 2", "Queen-High Flush"),
-    ("Q 8 7 3 2", "Queen-High Flush"),
-    ("Q 8 6 5 4", "Queen-High Flush"),
-    ("Q 8 6 5 3", "Queen-High Flush"),
-    ("Q 8 6 5 2", "Queen-High Flush"),
-    ("Q 8 6 4 3", "Queen-High Flush"),
-    ("Q 8 6 4 2", "Queen-High Flush"),
-    ("Q 8 6 3 2", "Queen-High Flush"),
-    ("Q 8 5 4 3", "Queen-High Flush"),
-    ("Q 8 5 4 2", "Queen-High Flush"),
-    ("Q 8 5 3 2", "Queen-High Flush"),
-    ("Q 8 4 3 2", "Queen-High Flush"),
-    ("Q 7 6 5 4", "Queen-High Flush"),
-    ("Q 7 6 5 3", "Queen-High Flush"),
-    ("Q 7 6 5 2", "Queen-High Flush"),
-    ("Q 7 6 4 3", "Queen-High Flush"),
-    ("Q 7 6 4 2", "Queen-High Flush"),
-    ("Q 7 6 3 2", "Queen-High Flush"),
-    ("Q 7 5 4 3", "Queen-High Flush"),
-    ("Q 7 5 4 2", "Queen-High Flush"),
-    ("Q 7 5 3 2", "Queen-High Flush"),
-    ("Q 7 4 3 2", "Queen-High Flush"),
-    ("Q 6 5 4 3", "Queen-High Flush"),
-    ("Q 6 5 4 2", "Queen-High Flush"),
-    ("Q 6 5 3 2", "Queen-High Flush"),
-    ("Q 6 4 3 2", "Queen-High Flush"),
-    ("Q 5 4 3 2", "Queen-High Flush"),
-    ("J T 9 8 6", "Jack-High Flush"),
-    ("J T 9 8 5", "Jack-High Flush"),
-    ("J T 9 8 4", "Jack-High Flush"),
-    ("J T 9 8 3", "Jack-High Flush"),
-    ("J T 9 8 2", "Jack-High Flush"),
-    ("J T 9 7 6", "Jack-High Flush"),
-    ("J T 9 7 5", "Jack-High Flush"),
-    ("J T 9 7 4", "Jack-High Flush"),
-    ("J T 9 7 3", "Jack-High Flush"),
-    ("J T 9 7 2", "Jack-High Flush"),
-    ("J T 9 6 5", "Jack-High Flush"),
-    ("J T 9 6 4", "Jack-High Flush"),
-    ("J T 9 6 3", "Jack-High Flush"),
-    ("J T 9 6 2", "Jack-High Flush"),
-    ("J T 9 5 4", "Jack-High Flush"),
-    ("J T 9 5 3", "Jack-High Flush"),
-    ("J T 9 5 2", "Jack-High Flush"),
-    ("J T 9 4 3", "Jack-High Flush"),
-    ("J T 9 4 2", "Jack-High Flush"),
-    ("J T 9 3 2", "Jack-High Flush"),
-    ("J T 8 7 6", "Jack-High Flush"),
-    ("J T 8 7 5", "Jack-High Flush"),
-    ("J T 8 7 4", "Jack-High Flush"),
-    ("J T 8 7 3", "Jack-High Flush"),
-    ("J T 8 7 2", "Jack-High Flush"),
-    ("J T 8 6 5", "Jack-High Flush"),
-    ("J T 8 6 4", "Jack-High Flush"),
-    ("J T 8 6 3", "Jack-High Flush"),
-    ("J T 8 6 2", "Jack-High Flush"),
-    ("J T 8 5 4", "Jack-High Flush"),
-    ("J T 8 5 3", "Jack-High Flush"),
-    ("J T 8 5 2", "Jack-High Flush"),
-    ("J T 8 4 3", "Jack-High Flush"),
-    ("J T 8 4 2", "Jack-High Flush"),
-    ("J T 8 3 2", "Jack-High Flush"),
-    ("J T 7 6 5", "Jack-High Flush"),
-    ("J T 7 6 4", "Jack-High Flush"),
-    ("J T 7 6 3", "Jack-High Flush"),
-    ("J T 7 6 2", "Jack-High Flush"),
-    ("J T 7 5 4", "Jack-High Flush"),
-    ("J T 7 5 3", "Jack-High Flush"),
-    ("J T 7 5 2", "Jack-High Flush"),
-    ("J T 7 4 3", "Jack-High Flush"),
-    ("J T 7 4 2", "Jack-High Flush"),
-    ("J T 7 3 2", "Jack-High Flush"),
-    ("J T 6 5 4", "Jack-High Flush"),
-    ("J T 6 5 3", "Jack-High Flush"),
-    ("J T 6 5 2", "Jack-High Flush"),
-    ("J T 6 4 3", "Jack-High Flush"),
-    ("J T 6 4 2", "Jack-High Flush"),
-    ("J T 6 3 2", "Jack-High Flush"),
-    ("J T 5 4 3", "Jack-High Flush"),
-    ("J T 5 4 2", "Jack-High Flush"),
-    ("J T 5 3 2", "Jack-High Flush"),
-    ("J T 4 3 2", "Jack-High Flush"),
-    ("J 9 8 7 6", "Jack-High Flush"),
-    ("J 9 8 7 5", "Jack-High Flush"),
-    ("J 9 8 7 4", "Jack-High Flush"),
-    ("J 9 8 7 3", "Jack-High Flush"),
-    ("J 9 8 7 2", "Jack-High Flush"),
-    ("J 9 8 6 5", "Jack-High Flush"),
-    ("J 9 8 6 4", "Jack-High Flush"),
-    ("J 9 8 6 3", "Jack-High Flush"),
-    ("J 9 8 6 2", "Jack-High Flush"),
-    ("J 9 8 5 4", "Jack-High Flush"),
-    ("J 9 8 5 3", "Jack-High Flush"),
-    ("J 9 8 5 2", "Jack-High Flush"),
-    ("J 9 8 4 3", "Jack-High Flush"),
-    ("J 9 8 4 2", "Jack-High Flush"),
-    ("J 9 8 3 2", "Jack-High Flush"),
-    ("J 9 7 6 5", "Jack-High Flush"),
-    ("J 9 7 6 4", "Jack-High Flush"),
-    ("J 9 7 6 3", "Jack-High Flush"),
-    ("J 9 7 6 2", "Jack-High Flush"),
-    ("J 9 7 5 4", "Jack-High Flush"),
-    ("J 9 7 5 3", "Jack-High Flush"),
-    ("J 9 7 5 2", "Jack-High Flush"),
-    ("J 9 7 4 3", "Jack-High Flush"),
-    ("J 9 7 4 2", "Jack-High Flush"),
-    ("J 9 7 3 2", "Jack-High Flush"),
-    ("J 9 6 5 4", "Jack-High Flush"),
-    ("J 9 6 5 3", "Jack-High Flush"),
-    ("J 9 6 5 2", "Jack-High Flush"),
-    ("J 9 6 4 3", "Jack-High Flush"),
-    ("J 9 6 4 2", "Jack-High Flush"),
-    ("J 9 6 3 2", "Jack-High Flush"),
-    ("J 9 5 4 3", "Jack-High Flush"),
-    ("J 9 5 4 2", "Jack-High Flush"),
-    ("J 9 5 3 2", "Jack-High Flush"),
-    ("J 9 4 3 2", "Jack-High Flush"),
-    ("J 8 7 6 5", "Jack-High Flush"),
-    ("J 8 7 6 4", "Jack-High Flush"),
-    ("J 8 7 6 3", "Jack-High Flush"),
-    ("J 8 7 6 2", "Jack-High Flush"),
-    ("J 8 7 5 4", "Jack-High Flush"),
-    ("J 8 7 5 3", "Jack-High Flush"),
-    ("J 8 7 5 2", "Jack-High Flush"),
-    ("J 8 7 4 3", "Jack-High Flush"),
-    ("J 8 7 4 2", "Jack-High Flush"),
-    ("J 8 7 3 2", "Jack-High Flush"),
-    ("J 8 6 5 4", "Jack-High Flush"),
-    ("J 8 6 5 3", "Jack-High Flush"),
-    ("J 8 6 5 2", "Jack-High Flush"),
-    ("J 8 6 4 3", "Jack-High Flush"),
-    ("J 8 6 4 2", "Jack-High Flush"),
-    ("J 8 6 3 2", "Jack-High Flush"),
-    ("J 8 5 4 3", "Jack-High Flush"),
-    ("J 8 5 4 2", "Jack-High Flush"),
-    ("J 8 5 3 2", "Jack-High Flush"),
-    ("J 8 4 3 2", "Jack-High Flush"),
-    ("J 7 6 5 4", "Jack-High Flush"),
-    ("J 7 6 5 3", "Jack-High Flush"),
-    ("J 7 6 5 2", "Jack-High Flush"),
-    ("J 7 6 4 3", "Jack-High Flush"),
-    ("J 7 6 4 2", "Jack-High Flush"),
-    ("J 7 6 3 2", "Jack-High Flush"),
-    ("J 7 5 4 3", "Jack-High Flush"),
-    ("J 7 5 4 2", "Jack-High Flush"),
-    ("J 7 5 3 2", "Jack-High Flush"),
-    ("J 7 4 3 2", "Jack-High Flush"),
-    ("J 6 5 4 3", "Jack-High Flush"),
-    ("J 6 5 4 2", "Jack-High Flush"),
-    ("J 6 5 3 2", "Jack-High Flush"),
-    ("J 6 4 3 2", "Jack-High Flush"),
-    ("J 5 4 3 2", "Jack-High Flush"),
-    ("T 9 8 7 5", "Ten-High Flush"),
-    ("T 9 8 7 4", "Ten-High Flush"),
-    ("T 9 8 7 3", "Ten-High Flush"),
-    ("T 9 8 7 2", "Ten-High Flush"),
-    ("T 9 8 6 5", "Ten-High Flush"),
-    ("T 9 8 6 4", "Ten-High Flush"),
-    ("T 9 8 6 3", "Ten-High Flush"),
-    ("T 9 8 6 2", "Ten-High Flush"),
-    ("T 9 8 5 4", "Ten-High Flush"),
-    ("T 9 8 5 3", "Ten-High Flush"),
-    ("T 9 8 5 2", "Ten-High Flush"),
-    ("T 9 8 4 3", "Ten-High Flush"),
-    ("T 9 8 4 2", "Ten-High Flush"),
-    ("T 9 8 3 2", "Ten-High Flush"),
-    ("T 9 7 6 5", "Ten-High Flush"),
-    ("T 9 7 6 4", "Ten-High Flush"),
-    ("T 9 7 6 3", "Ten-High Flush"),
-    ("T 9 7 6 2", "Ten-High Flush"),
-    ("T 9 7 5 4", "Ten-High Flush"),
-    ("T 9 7 5 3", "Ten-High Flush"),
-    ("T 9 7 5 2", "Ten-High Flush"),
-    ("T 9 7 4 3", "Ten-High Flush"),
-    ("T 9 7 4 2", "Ten-High Flush"),
-    ("T 9 7 3 2", "Ten-High Flush"),
-    ("T 9 6 5 4", "Ten-High Flush"),
-    ("T 9 6 5 3", "Ten-High Flush"),
-    ("T 9 6 5 2", "Ten-High Flush"),
-    ("T 9 6 4 3", "Ten-High Flush"),
-    ("T 9 6 4 2", "Ten-High Flush"),
-    ("T 9 6 3 2", "Ten-High Flush"),
-    ("T 9 5 4 3", "Ten-High Flush"),
-    ("T 9 5 4 2", "Ten-High Flush"),
-    ("T 9 5 3 2", "Ten-High Flush"),
-    ("T 9 4 3 2", "Ten-High Flush"),
-    ("T 8 7 6 5", "Ten-High Flush"),
-    ("T 8 7 6 4", "Ten-High Flush"),
-    ("T 8 7 6 3", "Ten-High Flush"),
-    ("T 8 7 6 2", "Ten-High Flush"),
-    ("T 8 7 5 4", "Ten-High Flush"),
-    ("T 8 7 5 3", "Ten-High Flush"),
-    ("T 8 7 5 2", "Ten-High Flush"),
-    ("T 8 7 4 3", "Ten-High Flush"),
-    ("T 8 7 4 2", "Ten-High Flush"),
-    ("T 8 7 3 2", "Ten-High Flush"),
-    ("T 8 6 5 4", "Ten-High Flush"),
-    ("T 8 6 5 3", "Ten-High Flush"),
-    ("T 8 6 5 2", "Ten-High Flush"),
-    ("T 8 6 4 3", "Ten-High Flush"),
-    ("T 8 6 4 2", "Ten-High Flush"),
-    ("T 8 6 3 2", "Ten-High Flush"),
-    ("T 8 5 4 3", "Ten-High Flush"),
-    ("T 8 5 4 2", "Ten-High Flush"),
-    ("T 8 5 3 2", "Ten-High Flush"),
-    ("T 8 4 3 2", "Ten-High Flush"),
-    ("T 7 6 5 4", "Ten-High Flush"),
-    ("T 7 6 5 3", "Ten-High Flush"),
-    ("T 7 6 5 2", "Ten-High Flush"),
-    ("T 7 6 4 3", "Ten-High Flush"),
-    ("T 7 6 4 2", "Ten-High Flush"),
-    ("T 7 6 3 2", "Ten-High Flush"),
-    ("T 7 5 4 3", "Ten-High Flush"),
-    ("T 7 5 4 2", "Ten-High Flush"),
-    ("T 7 5 3 2", "Ten-High Flush"),
-    ("T 7 4 3 2", "Ten-High Flush"),
-    ("T 6 5 4 3", "Ten-High Flush"),
-    ("T 6 5 4 2", "Ten-High Flush"),
-    ("T 6 5 3 2", "Ten-High Flush"),
-    ("T 6 4 3 2", "Ten-High Flush"),
-    ("T 5 4 3 2", "Ten-High Flush"),
-    ("9 8 7 6 4", "Nine-High Flush"),
-    ("9 8 7 6 3", "Nine-High Flush"),
-    ("9 8 7 6 2", "Nine-High Flush"),
-    ("9 8 7 5 4", "Nine-High Flush"),
-    ("9 8 7 5 3", "Nine-High Flush"),
-    ("9 8 7 5 2", "Nine-High Flush"),
-    ("9 8 7 4 3", "Nine-High Flush"),
-    ("9 8 7 4 2", "Nine-High Flush"),
-    ("9 8 7 3 2", "Nine-High Flush"),
-    ("9 8 6 5 4", "Nine-High Flush"),
-    ("9 8 6 5 3", "Nine-High Flush"),
-    ("9 8 6 5 2", "Nine-High Flush"),
-    ("9 8 6 4 3", "Nine-High Flush"),
-    ("9 8 6 4 2", "Nine-High Flush"),
-    ("9 8 6 3 2", "Nine-High Flush"),
-    ("9 8 5 4 3", "Nine-High Flush"),
-    ("9 8 5 4 2", "Nine-High Flush"),
-    ("9 8 5 3 2", "Nine-High Flush"),
-    ("9 8 4 3 2", "Nine-High Flush"),
-    ("9 7 6 5 4", "Nine-High Flush"),
-    ("9 7 6 5 3", "Nine-High Flush"),
-    ("9 7 6 5 2", "Nine-High Flush"),
-    ("9 7 6 4 3", "Nine-High Flush"),
-    ("9 7 6 4 2", "Nine-High Flush"),
-    ("9 7 6 3 2", "Nine-High Flush"),
-    ("9 7 5 4 3", "Nine-High Flush"),
-    ("9 7 5 4 2", "Nine-High Flush"),
-    ("9 7 5 3 2", "Nine-High Flush"),
-    ("9 7 4 3 2", "Nine-High Flush"),
-    ("9 6 5 4 3", "Nine-High Flush"),
-    ("9 6 5 4 2", "Nine-High Flush"),
-    ("9 6 5 3 2", "Nine-High Flush"),
-    ("9 6 4 3 2", "Nine-High Flush"),
-    ("9 5 4 3 2", "Nine-High Flush"),
-    ("8 7 6 5 3", "Eight-High Flush"),
-    ("8 7 6 5 2", "Eight-High Flush"),
-    ("8 7 6 4 3", "Eight-High Flush"),
-    ("8 7 6 4 2", "Eight-High Flush"),
-    ("8 7 6 3 2", "Eight-High Flush"),
-    ("8 7 5 4 3", "Eight-High Flush"),
-    ("8 7 5 4 2", "Eight-High Flush"),
-    ("8 7 5 3 2", "Eight-High Flush"),
-    ("8 7 4 3 2", "Eight-High Flush"),
-    ("8 6 5 4 3", "Eight-High Flush"),
-    ("8 6 5 4 2", "Eight-High Flush"),
-    ("8 6 5 3 2", "Eight-High Flush"),
-    ("8 6 4 3 2", "Eight-High Flush"),
-    ("8 5 4 3 2", "Eight-High Flush"),
-    ("7 6 5 4 2", "Seven-High Flush"),
-    ("7 6 5 3 2", "Seven-High Flush"),
-    ("7 6 4 3 2", "Seven-High Flush"),
-    ("7 5 4 3 2", "Seven-High Flush"),
-    ("A K Q J T", "Ace-High Straight"),
-    ("K Q J T 9", "King-High Straight"),
-    ("Q J T 9 8", "Queen-High Straight"),
-    ("J T 9 8 7", "Jack-High Straight"),
-    ("T 9 8 7 6", "Ten-High Straight"),
-    ("9 8 7 6 5", "Nine-High Straight"),
-    ("8 7 6 5 4", "Eight-High Straight"),
-    ("7 6 5 4 3", "Seven-High Straight"),
-    ("6 5 4 3 2", "Six-High Straight"),
-    ("5 4 3 2 A", "Five-High Straight"),
-    ("A A A K Q", "Three Aces"),
-    ("A A A K J", "Three Aces"),
-    ("A A A K T", "Three Aces"),
-    ("A A A K 9", "Three Aces"),
-    ("A A A K 8", "Three Aces"),
-    ("A A A K 7", "Three Aces"),
-    ("A A A K 6", "Three Aces"),
-    ("A A A K 5", "Three Aces"),
-    ("A A A K 4", "Three Aces"),
-    ("A A A K 3", "Three Aces"),
-    ("A A A K 2", "Three Aces"),
-    ("A A A Q J", "Three Aces"),
-    ("A A A Q T", "Three Aces"),
-    ("A A A Q 9", "Three Aces"),
-    ("A A A Q 8", "Three Aces"),
-    ("A A A Q 7", "Three Aces"),
-    ("A A A Q 6", "Three Aces"),
-    ("A A A Q 5", "Three Aces"),
-    ("A A A Q 4", "Three Aces"),
-    ("A A A Q 3", "Three Aces"),
-    ("A A A Q 2", "Three Aces"),
-    ("A A A J T", "Three Aces"),
-    ("A A A J 9", "Three Aces"),
-    ("A A A J 8", "Three Aces"),
-    ("A A A J 7", "Three Aces"),
-    ("A A A J 6", "Three Aces"),
-    ("A A A J 5", "Three Aces"),
-    ("A A A J 4", "Three Aces"),
-    ("A A A J 3", "Three Aces"),
-    ("A A A J 2", "Three Aces"),
-    ("A A A T 9", "Three Aces"),
-    ("A A A T 8", "Three Aces"),
-    ("A A A T 7", "Three Aces"),
-    ("A A A T 6", "Three Aces"),
-    ("A A A T 5", "Three Aces"),
-    ("A A A T 4", "Three Aces"),
-    ("A A A T 3", "Three Aces"),
-    ("A A A T 2", "Three Aces"),
-    ("A A A 9 8", "Three Aces"),
-    ("A A A 9 7", "Three Aces"),
-    ("A A A 9 6", "Three Aces"),
-    ("A A A 9 5", "Three Aces"),
-    ("A A A 9 4", "Three Aces"),
-    ("A A A 9 3", "Three Aces"),
-    ("A A A 9 2", "Three Aces"),
-    ("A A A 8 7", "Three Aces"),
-    ("A A A 8 6", "Three Aces"),
-    ("A A A 8 5", "Three Aces"),
-    ("A A A 8 4", "Three Aces"),
-    ("A A A 8 3", "Three Aces"),
-    ("A A A 8 2", "Three Aces"),
-    ("A A A 7 6", "Three Aces"),
-    ("A A A 7 5", "Three Aces"),
-    ("A A A 7 4", "Three Aces"),
-    ("A A A 7 3", "Three Aces"),
-    ("A A A 7 2", "Three Aces"),
-    ("A A A 6 5", "Three Aces"),
-    ("A A A 6 4", "Three Aces"),
-    ("A A A 6 3", "Three Aces"),
-    ("A A A 6 2", "Three Aces"),
-    ("A A A 5 4", "Three Aces"),
-    ("A A A 5 3", "Three Aces"),
-    ("A A A 5 2", "Three Aces"),
-    ("A A A 4 3", "Three Aces"),
-    ("A A A 4 2", "Three Aces"),
-    ("A A A 3 2", "Three Aces"),
-    ("K K K A Q", "Three Kings"),
-    ("K K K A J", "Three Kings"),
-    ("K K K A T", "Three Kings"),
-    ("K K K A 9", "Three Kings"),
-    ("K K K A 8", "Three Kings"),
-    ("K K K A 7", "Three Kings"),
-    ("K K K A 6", "Three Kings"),
-    ("K K K A 5", "Three Kings"),
-    ("K K K A 4", "Three Kings"),
-    ("K K K A 3", "Three Kings"),
-    ("K K K A 2", "Three Kings"),
-    ("K K K Q J", "Three Kings"),
-    ("K K K Q T", "Three Kings"),
-    ("K K K Q 9", "Three Kings"),
-    ("K K K Q 8", "Three Kings"),
-    ("K K K Q 7", "Three Kings"),
-    ("K K K Q 6", "Three Kings"),
-    ("K K K Q 5", "Three Kings"),
-    ("K K K Q 4", "Three Kings"),
-    ("K K K Q 3", "Three Kings"),
-    ("K K K Q 2", "Three Kings"),
-    ("K K K J T", "Three Kings"),
-    ("K K K J 9", "Three Kings"),
-    ("K K K J 8", "Three Kings"),
-    ("K K K J 7", "Three Kings"),
-    ("K K K J 6", "Three Kings"),
-    ("K K K J 5", "Three Kings"),
-    ("K K K J 4", "Three Kings"),
-    ("K K K J 3", "Three Kings"),
-    ("K K K J 2", "Three Kings"),
-    ("K K K T 9", "Three Kings"),
-    ("K K K T 8", "Three Kings"),
-    ("K K K T 7", "Three Kings"),
-    ("K K K T 6", "Three Kings"),
-    ("K K K T 5", "Three Kings"),
-    ("K K K T 4", "Three Kings"),
-    ("K K K T 3", "Three Kings"),
-    ("K K K T 2", "Three Kings"),
-    ("K K K 9 8", "Three Kings"),
-    ("K K K 9 7", "Three Kings"),
-    ("K K K 9 6", "Three Kings"),
-    ("K K K 9 5", "Three Kings"),
-    ("K K K 9 4", "Three Kings"),
-    ("K K K 9 3", "Three Kings"),
-    ("K K K 9 2", "Three Kings"),
-    ("K K K 8 7", "Three Kings"),
-    ("K K K 8 6", "Three Kings"),
-    ("K K K 8 5", "Three Kings"),
-    ("K K K 8 4", "Three Kings"),
-    ("K K K 8 3", "Three Kings"),
-    ("K K K 8 2", "Three Kings"),
-    ("K K K 7 6", "Three Kings"),
-    ("K K K 7 5", "Three Kings"),
-    ("K K K 7 4", "Three Kings"),
-    ("K K K 7 3", "Three Kings"),
-    ("K K K 7 2", "Three Kings"),
-    ("K K K 6 5", "Three Kings"),
-    ("K K K 6 4", "Three Kings"),
-    ("K K K 6 3", "Three Kings"),
-    ("K K K 6 2", "Three Kings"),
-    ("K K K 5 4", "Three Kings"),
-    ("K K K 5 3", "Three Kings"),
-    ("K K K 5 2", "Three Kings"),
-    ("K K K 4 3", "Three Kings"),
-    ("K K K 4 2", "Three Kings"),
-    ("K K K 3 2", "Three Kings"),
-    ("Q Q Q A K", "Three Queens"),
-    ("Q Q Q A J", "Three Queens"),
-    ("Q Q Q A T", "Three Queens"),
-    ("Q Q Q A 9", "Three Queens"),
-    ("Q Q Q A 8", "Three Queens"),
-    ("Q Q Q A 7", "Three Queens"),
-    ("Q Q Q A 6", "Three Queens"),
-    ("Q Q Q A 5", "Three Queens"),
-    ("Q Q Q A 4", "Three Queens"),
-    ("Q Q Q A 3", "Three Queens"),
-    ("Q Q Q A 2", "Three Queens"),
-    ("Q Q Q K J", "Three Queens"),
-    ("Q Q Q K T", "Three Queens"),
-    ("Q Q Q K 9", "Three Queens"),
-    ("Q Q Q K 8", "Three Queens"),
-    ("Q Q Q K 7", "Three Queens"),
-    ("Q Q Q K 6", "Three Queens"),
-    ("Q Q Q K 5", "Three Queens"),
-    ("Q Q Q K 4", "Three Queens"),
-    ("Q Q Q K 3", "Three Queens"),
-    ("Q Q Q K 2", "Three Queens"),
-    ("Q Q Q J T", "Three Queens"),
-    ("Q Q Q J 9", "Three Queens"),
-    ("Q Q Q J 8", "Three Queens"),
-    ("Q Q Q J 7", "Three Queens"),
-    ("Q Q Q J 6", "Three Queens"),
-    ("Q Q Q J 5", "Three Queens"),
-    ("Q Q Q J 4", "Three Queens"),
-    ("Q Q Q J 3", "Three Queens"),
-    ("Q Q Q J 2", "Three Queens"),
-    ("Q Q Q T 9", "Three Queens"),
-    ("Q Q Q T 8", "Three Queens"),
-    ("Q Q Q T 7", "Three Queens"),
-    ("Q Q Q T 6", "Three Queens"),
-    ("Q Q Q T 5", "Three Queens"),
-    ("Q Q Q T 4", "Three Queens"),
-    ("Q Q Q T 3", "Three Queens"),
-    ("Q Q Q T 2", "Three Queens"),
-    ("Q Q Q 9 8", "Three Queens"),
-    ("Q Q Q 9 7", "Three Queens"),
-    ("Q Q Q 9 6", "Three Queens"),
-    ("Q Q Q 9 5", "Three Queens"),
-    ("Q Q Q 9 4", "Three Queens"),
-    ("Q Q Q 9 3", "Three Queens"),
-    ("Q Q Q 9 2", "Three Queens"),
-    ("Q Q Q 8 7", "Three Queens"),
-    ("Q Q Q 8 6", "Three Queens"),
-    ("Q Q Q 8 5", "Three Queens"),
-    ("Q Q Q 8 4", "Three Queens"),
-    ("Q Q Q 8 3", "Three Queens"),
-    ("Q Q Q 8 2", "Three Queens"),
-    ("Q Q Q 7 6", "Three Queens"),
-    ("Q Q Q 7 5", "Three Queens"),
-    ("Q Q Q 7 4", "Three Queens"),
-    ("Q Q Q 7 3", "Three Queens"),
-    ("Q Q Q 7 2", "Three Queens"),
-    ("Q Q Q 6 5", "Three Queens"),
-    ("Q Q Q 6 4", "Three Queens"),
-    ("Q Q Q 6 3", "Three Queens"),
-    ("Q Q Q 6 2", "Three Queens"),
-    ("Q Q Q 5 4", "Three Queens"),
-    ("Q Q Q 5 3", "Three Queens"),
-    ("Q Q Q 5 2", "Three Queens"),
-    ("Q Q Q 4 3", "Three Queens"),
-    ("Q Q Q 4 2", "Three Queens"),
-    ("Q Q Q 3 2", "Three Queens"),
-    ("J J J A K", "Three Jacks"),
-    ("J J J A Q", "Three Jacks"),
-    ("J J J A T", "Three Jacks"),
-    ("J J J A 9", "Three Jacks"),
-    ("J J J A 8", "Three Jacks"),
-    ("J J J A 7", "Three Jacks"),
-    ("J J J A 6", "Three Jacks"),
-    ("J J J A 5", "Three Jacks"),
-    ("J J J A 4", "Three Jacks"),
-    ("J J J A 3", "Three Jacks"),
-    ("J J J A 2", "Three Jacks"),
-    ("J J J K Q", "Three Jacks"),
-    ("J J J K T", "Three Jacks"),
-    ("J J J K 9", "Three Jacks"),
-    ("J J J K 8", "Three Jacks"),
-    ("J J J K 7", "Three Jacks"),
-    ("J J J K 6", "Three Jacks"),
-    ("J J J K 5", "Three Jacks"),
-    ("J J J K 4", "Three Jacks"),
-    ("J J J K 3", "Three Jacks"),
-    ("J J J K 2", "Three Jacks"),
-    ("J J J Q T", "Three Jacks"),
-    ("J J J Q 9", "Three Jacks"),
-    ("J J J Q 8", "Three Jacks"),
-    ("J J J Q 7", "Three Jacks"),
-    ("J J J Q 6", "Three Jacks"),
-    ("J J J Q 5", "Three Jacks"),
-    ("J J J Q 4", "Three Jacks"),
-    ("J J J Q 3", "Three Jacks"),
-    ("J J J Q 2", "Three Jacks"),
-    ("J J J T 9", "Three Jacks"),
-    ("J J J T 8", "Three Jacks"),
-    ("J J J T 7", "Three Jacks"),
-    ("J J J T 6", "Three Jacks"),
-    ("J J J T 5", "Three Jacks"),
-    ("J J J T 4", "Three Jacks"),
-    ("J J J T 3", "Three Jacks"),
-    ("J J J T 2", "Three Jacks"),
-    ("J J J 9 8", "Three Jacks"),
-    ("J J J 9 7", "Three Jacks"),
-    ("J J J 9 6", "Three Jacks"),
-    ("J J J 9 5", "Three Jacks"),
-    ("J J J 9 4", "Three Jacks"),
-    ("J J J 9 3", "Three Jacks"),
-    ("J J J 9 2", "Three Jacks"),
-    ("J J J 8 7", "Three Jacks"),
-    ("J J J 8 6", "Three Jacks"),
-    ("J J J 8 5", "Three Jacks"),
-    ("J J J 8 4", "Three Jacks"),
-    ("J J J 8 3", "Three Jacks"),
-    ("J J J 8 2", "Three Jacks"),
-    ("J J J 7 6", "Three Jacks"),
-    ("J J J 7 5", "Three Jacks"),
-    ("J J J 7 4", "Three Jacks"),
-    ("J J J 7 3", "Three Jacks"),
-    ("J J J 7 2", "Three Jacks"),
-    ("J J J 6 5", "Three Jacks"),
-    ("J J J 6 4", "Three Jacks"),
-    ("J J J 6 3", "Three Jacks"),
-    ("J J J 6 2", "Three Jacks"),
-    ("J J J 5 4", "Three Jacks"),
-    ("J J J 5 3", "Three Jacks"),
-    ("J J J 5 2", "Three Jacks"),
-    ("J J J 4 3", "Three Jacks"),
-    ("J J J 4 2", "Three Jacks"),
-    ("J J J 3 2", "Three Jacks"),
-    ("T T T A K", "Three Tens"),
-    ("T T T A Q", "Three Tens"),
-    ("T T T A J", "Three Tens"),
-    ("T T T A 9", "Three Tens"),
-    ("T T T A 8", "Three Tens"),
-    ("T T T A 7", "Three Tens"),
-    ("T T T A 6", "Three Tens"),
-    ("T T T A 5", "Three Tens"),
-    ("T T T A 4", "Three Tens"),
-    ("T T T A 3", "Three Tens"),
-    ("T T T A 2", "Three Tens"),
-    ("T T T K Q", "Three Tens"),
-    ("T T T K J", "Three Tens"),
-    ("T T T K 9", "Three Tens"),
-    ("T T T K 8", "Three Tens"),
-    ("T T T K 7", "Three Tens"),
-    ("T T T K 6", "Three Tens"),
-    ("T T T K 5", "Three Tens"),
-    ("T T T K 4", "Three Tens"),
-    ("T T T K 3", "Three Tens"),
-    ("T T T K 2", "Three Tens"),
-    ("T T T Q J", "Three Tens"),
-    ("T T T Q 9", "Three Tens"),
-    ("T T T Q 8", "Three Tens"),
-    ("T T T Q 7", "Three Tens"),
-    ("T T T Q 6", "Three Tens"),
-    ("T T T Q 5", "Three Tens"),
-    ("T T T Q 4", "Three Tens"),
-    ("T T T Q 3", "Three Tens"),
-    ("T T T Q 2", "Three Tens"),
-    ("T T T J 9", "Three Tens"),
-    ("T T T J 8", "Three Tens"),
-    ("T T T J 7", "Three Tens"),
-    ("T T T J 6", "Three Tens"),
-    ("T T T J 5", "Three Tens"),
-    ("T T T J 4", "Three Tens"),
-    ("T T T J 3", "Three Tens"),
-    ("T T T J 2", "Three Tens"),
-    ("T T T 9 8", "Three Tens"),
-    ("T T T 9 7", "Three Tens"),
-    ("T T T 9 6", "Three Tens"),
-    ("T T T 9 5", "Three Tens"),
-    ("T T T 9 4", "Three Tens"),
-    ("T T T 9 3", "Three Tens"),
-    ("T T T 9 2", "Three Tens"),
-    ("T T T 8 7", "Three Tens"),
-    ("T T T 8 6", "Three Tens"),
-    ("T T T 8 5", "Three Tens"),
-    ("T T T 8 4", "Three Tens"),
-    ("T T T 8 3", "Three Tens"),
-    ("T T T 8 2", "Three Tens"),
-    ("T T T 7 6", "Three Tens"),
-    ("T T T 7 5", "Three Tens"),
-    ("T T T 7 4", "Three Tens"),
-    ("T T T 7 3", "Three Tens"),
-    ("T T T 7 2", "Three Tens"),
-    ("T T T 6 5", "Three Tens"),
-    ("T T T 6 4", "Three Tens"),
-    ("T T T 6 3", "Three Tens"),
-    ("T T T 6 2", "Three Tens"),
-    ("T T T 5 4", "Three Tens"),
-    ("T T T 5 3", "Three Tens"),
-    ("T T T 5 2", "Three Tens"),
-    ("T T T 4 3", "Three Tens"),
-    ("T T T 4 2", "Three Tens"),
-    ("T T T 3 2", "Three Tens"),
-    ("9 9 9 A K", "Three Nines"),
-    ("9 9 9 A Q", "Three Nines"),
-    ("9 9 9 A J", "Three Nines"),
-    ("9 9 9 A T", "Three Nines"),
-    ("9 9 9 A 8", "Three Nines"),
-    ("9 9 9 A 7", "Three Nines"),
-    ("9 9 9 A 6", "Three Nines"),
-    ("9 9 9 A 5", "Three Nines"),
-    ("9 9 9 A 4", "Three Nines"),
-    ("9 9 9 A 3", "Three Nines"),
-    ("9 9 9 A 2", "Three Nines"),
-    ("9 9 9 K Q", "Three Nines"),
-    ("9 9 9 K J", "Three Nines"),
-    ("9 9 9 K T", "Three Nines"),
-    ("9 9 9 K 8", "Three Nines"),
-    ("9 9 9 K 7", "Three Nines"),
-    ("9 9 9 K 6", "Three Nines"),
-    ("9 9 9 K 5", "Three Nines"),
-    ("9 9 9 K 4", "Three Nines"),
-    ("9 9 9 K 3", "Three Nines"),
-    ("9 9 9 K 2", "Three Nines"),
-    ("9 9 9 Q J", "Three Nines"),
-    ("9 9 9 Q T", "Three Nines"),
-    ("9 9 9 Q 8", "Three Nines"),
-    ("9 9 9 Q 7", "Three Nines"),
-    ("9 9 9 Q 6", "Three Nines"),
-    ("9 9 9 Q 5", "Three Nines"),
-    ("9 9 9 Q 4", "Three Nines"),
-    ("9 9 9 Q 3", "Three Nines"),
-    ("9 9 9 Q 2", "Three Nines"),
-    ("9 9 9 J T", "Three Nines"),
-    ("9 9 9 J 8", "Three Nines"),
-    ("9 9 9 J 7", "Three Nines"),
-    ("9 9 9 J 6", "Three Nines"),
-    ("9 9 9 J 5", "Three Nines"),
-    ("9 9 9 J 4", "Three Nines"),
-    ("9 9 9 J 3", "Three Nines"),
-    ("9 9 9 J 2", "Three Nines"),
-    ("9 9 9 T 8", "Three Nines"),
-    ("9 9 9 T 7", "Three Nines"),
-    ("9 9 9 T 6", "Three Nines"),
-    ("9 9 9 T 5", "Three Nines"),
-    ("9 9 9 T 4", "Three Nines"),
-    ("9 9 9 T 3", "Three Nines"),
-    ("9 9 9 T 2", "Three Nines"),
-    ("9 9 9 8 7", "Three Nines"),
-    ("9 9 9 8 6", "Three Nines"),
-    ("9 9 9 8 5", "Three Nines"),
-    ("9 9 9 8 4", "Three Nines"),
-    ("9 9 9 8 3", "Three Nines"),
-    ("9 9 9 8 2", "Three Nines"),
-    ("9 9 9 7 6", "Three Nines"),
-    ("9 9 9 7 5", "Three Nines"),
-    ("9 9 9 7 4", "Three Nines"),
-    ("9 9 9 7 3", "Three Nines"),
-    ("9 9 9 7 2", "Three Nines"),
-    ("9 9 9 6 5", "Three Nines"),
-    ("9 9 9 6 4", "Three Nines"),
-    ("9 9 9 6 3", "Three Nines"),
-    ("9 9 9 6 2", "Three Nines"),
-    ("9 9 9 5 4", "Three Nines"),
-    ("9 9 9 5 3", "Three Nines"),
-    ("9 9 9 5 2", "Three Nines"),
-    ("9 9 9 4 3", "Three Nines"),
-    ("9 9 9 4 2", "Three Nines"),
-    ("9 9 9 3 2", "Three Nines"),
-    ("8 8 8 A K", "Three Eights"),
-    ("8 8 8 A Q", "Three Eights"),
-    ("8 8 8 A J", "Three Eights"),
-    ("8 8 8 A T", "Three Eights"),
-    ("8 8 8 A 9", "Three Eights"),
-    ("8 8 8 A 7", "Three Eights"),
-    ("8 8 8 A 6", "Three Eights"),
-    ("8 8 8 A 5", "Three Eights"),
-    ("8 8 8 A 4", "Three Eights"),
-    ("8 8 8 A 3", "Three Eights"),
-    ("8 8 8 A 2", "Three Eights"),
-    ("8 8 8 K Q", "Three Eights"),
-    ("8 8 8 K J", "Three Eights"),
-    ("8 8 8 K T", "Three Eights"),
-    ("8 8 8 K 9", "Three Eights"),
-    ("8 8 8 K 7", "Three Eights"),
-    ("8 8 8 K 6", "Three Eights"),
-    ("8 8 8 K 5", "Three Eights"),
-    ("8 8 8 K 4", "Three Eights"),
-    ("8 8 8 K 3", "Three Eights"),
-    ("8 8 8 K 2", "Three Eights"),
-    ("8 8 8 Q J", "Three Eights"),
-    ("8 8 8 Q T", "Three Eights"),
-    ("8 8 8 Q 9", "Three Eights"),
-    ("8 8 8 Q 7", "Three Eights"),
-    ("8 8 8 Q 6", "Three Eights"),
-    ("8 8 8 Q 5", "Three Eights"),
-    ("8 8 8 Q 4", "Three Eights"),
-    ("8 8 8 Q 3", "Three Eights"),
-    ("8 8 8 Q 2", "Three Eights"),
-    ("8 8 8 J T", "Three Eights"),
-    ("8 8 8 J 9", "Three Eights"),
-    ("8 8 8 J 7", "Three Eights"),
-    ("8 8 8 J 6", "Three Eights"),
-    ("8 8 8 J 5", "Three Eights"),
-    ("8 8 8 J 4", "Three Eights"),
-    ("8 8 8 J 3", "Three Eights"),
-    ("8 8 8 J 2", "Three Eights"),
-    ("8 8 8 T 9", "Three Eights"),
-    ("8 8 8 T 7", "Three Eights"),
-    ("8 8 8 T 6", "Three Eights"),
-    ("8 8 8 T 5", "Three Eights"),
-    ("8 8 8 T 4", "Three Eights"),
-    ("8 8 8 T 3", "Three Eights"),
-    ("8 8 8 T 2", "Three Eights"),
-    ("8 8 8 9 7", "Three Eights"),
-    ("8 8 8 9 6", "Three Eights"),
-    ("8 8 8 9 5", "Three Eights"),
-    ("8 8 8 9 4", "Three Eights"),
-    ("8 8 8 9 3", "Three Eights"),
-    ("8 8 8 9 2", "Three Eights"),
-    ("8 8 8 7 6", "Three Eights"),
-    ("8 8 8 7 5", "Three Eights"),
-    ("8 8 8 7 4", "Three Eights"),
-    ("8 8 8 7 3", "Three Eights"),
-    ("8 8 8 7 2", "Three Eights"),
-    ("8 8 8 6 5", "Three Eights"),
-    ("8 8 8 6 4", "Three Eights"),
-    ("8 8 8 6 3", "Three Eights"),
-    ("8 8 8 6 2", "Three Eights"),
-    ("8 8 8 5 4", "Three Eights"),
-    ("8 8 8 5 3", "Three Eights"),
-    ("8 8 8 5 2", "Three Eights"),
-    ("8 8 8 4 3", "Three Eights"),
-    ("8 8 8 4 2", "Three Eights"),
-    ("8 8 8 3 2", "Three Eights"),
-    ("7 7 7 A K", "Three Sevens"),
-    ("7 7 7 A Q", "Three Sevens"),
-    ("7 7 7 A J", "Three Sevens"),
-    ("7 7 7 A T", "Three Sevens"),
-    ("7 7 7 A 9", "Three Sevens"),
-    ("7 7 7 A 8", "Three Sevens"),
-    ("7 7 7 A 6", "Three Sevens"),
-    ("7 7 7 A 5", "Three Sevens"),
-    ("7 7 7 A 4", "Three Sevens"),
-    ("7 7 7 A 3", "Three Sevens"),
-    ("7 7 7 A 2", "Three Sevens"),
-    ("7 7 7 K Q", "Three Sevens"),
-    ("7 7 7 K J", "Three Sevens"),
-    ("7 7 7 K T", "Three Sevens"),
-    ("7 7 7 K 9", "Three Sevens"),
-    ("7 7 7 K 8", "Three Sevens"),
-    ("7 7 7 K 6", "Three Sevens"),
-    ("7 7 7 K 5", "Three Sevens"),
-    ("7 7 7 K 4", "Three Sevens"),
-    ("7 7 7 K 3", "Three Sevens"),
-    ("7 7 7 K 2", "Three Sevens"),
-    ("7 7 7 Q J", "Three Sevens"),
-    ("7 7 7 Q T", "Three Sevens"),
-    ("7 7 7 Q 9", "Three Sevens"),
-    ("7 7 7 Q 8", "Three Sevens"),
-    ("7 7 7 Q 6", "Three Sevens"),
-    ("7 7 7 Q 5", "Three Sevens"),
-    ("7 7 7 Q 4", "Three Sevens"),
-    ("7 7 7 Q 3", "Three Sevens"),
-    ("7 7 7 Q 2", "Three Sevens"),
-    ("7 7 7 J T", "Three Sevens"),
-    ("7 7 7 J 9", "Three Sevens"),
-    ("7 7 7 J 8", "Three Sevens"),
-    ("7 7 7 J 6", "Three Sevens"),
-    ("7 7 7 J 5", "Three Sevens"),
-    ("7 7 7 J 4", "Three Sevens"),
-    ("7 7 7 J 3", "Three Sevens"),
-    ("7 7 7 J 2", "Three Sevens"),
-    ("7 7 7 T 9", "Three Sevens"),
-    ("7 7 7 T 8", "Three Sevens"),
-    ("7 7 7 T 6", "Three Sevens"),
-    ("7 7 7 T 5", "Three Sevens"),
-    ("7 7 7 T 4", "Three Sevens"),
-    ("7 7 7 T 3", "Three Sevens"),
-    ("7 7 7 T 2", "Three Sevens"),
-    ("7 7 7 9 8", "Three Sevens"),
-    ("7 7 7 9 6", "Three Sevens"),
-    ("7 7 7 9 5", "Three Sevens"),
-    ("7 7 7 9 4", "Three Sevens"),
-    ("7 7 7 9 3", "Three Sevens"),
-    ("7 7 7 9 2", "Three Sevens"),
-    ("7 7 7 8 6", "Three Sevens"),
-    ("7 7 7 8 5", "Three Sevens"),
-    ("7 7 7 8 4", "Three Sevens"),
-    ("7 7 7 8 3", "Three Sevens"),
-    ("7 7 7 8 2", "Three Sevens"),
-    ("7 7 7 6 5", "Three Sevens"),
-    ("7 7 7 6 4", "Three Sevens"),
-    ("7 7 7 6 3", "Three Sevens"),
-    ("7 7 7 6 2", "Three Sevens"),
-    ("7 7 7 5 4", "Three Sevens"),
-    ("7 7 7 5 3", "Three Sevens"),
-    ("7 7 7 5 2", "Three Sevens"),
-    ("7 7 7 4 3", "Three Sevens"),
-    ("7 7 7 4 2", "Three Sevens"),
-    ("7 7 7 3 2", "Three Sevens"),
-    ("6 6 6 A K", "Three Sixes"),
-    ("6 6 6 A Q", "Three Sixes"),
-    ("6 6 6 A J", "Three Sixes"),
-    ("6 6 6 A T", "Three Sixes"),
-    ("6 6 6 A 9", "Three Sixes"),
-    ("6 6 6 A 8", "Three Sixes"),
-    ("6 6 6 A 7", "Three Sixes"),
-    ("6 6 6 A 5", "Three Sixes"),
-    ("6 6 6 A 4", "Three Sixes"),
-    ("6 6 6 A 3", "Three Sixes"),
-    ("6 6 6 A 2", "Three Sixes"),
-    ("6 6 6 K Q", "Three Sixes"),
-    ("6 6 6 K J", "Three Sixes"),
-    ("6 6 6 K T", "Three Sixes"),
-    ("6 6 6 K 9", "Three Sixes"),
-    ("6 6 6 K 8", "Three Sixes"),
-    ("6 6 6 K 7", "Three Sixes"),
-    ("6 6 6 K 5", "Three Sixes"),
-    ("6 6 6 K 4", "Three Sixes"),
-    ("6 6 6 K 3", "Three Sixes"),
-    ("6 6 6 K 2", "Three Sixes"),
-    ("6 6 6 Q J", "Three Sixes"),
-    ("6 6 6 Q T", "Three Sixes"),
-    ("6 6 6 Q 9", "Three Sixes"),
-    ("6 6 6 Q 8", "Three Sixes"),
-    ("6 6 6 Q 7", "Three Sixes"),
-    ("6 6 6 Q 5", "Three Sixes"),
-    ("6 6 6 Q 4", "Three Sixes"),
-    ("6 6 6 Q 3", "Three Sixes"),
-    ("6 6 6 Q 2", "Three Sixes"),
-    ("6 6 6 J T", "Three Sixes"),
-    ("6 6 6 J 9", "Three Sixes"),
-    ("6 6 6 J 8", "Three Sixes"),
-    ("6 6 6 J 7", "Three Sixes"),
-    ("6 6 6 J 5", "Three Sixes"),
-    ("6 6 6 J 4", "Three Sixes"),
-    ("6 6 6 J 3", "Three Sixes"),
-    ("6 6 6 J 2", "Three Sixes"),
-    ("6 6 6 T 9", "Three Sixes"),
-    ("6 6 6 T 8", "Three Sixes"),
-    ("6 6 6 T 7", "Three Sixes"),
-    ("6 6 6 T 5", "Three Sixes"),
-    ("6 6 6 T 4", "Three Sixes"),
-    ("6 6 6 T 3", "Three Sixes"),
-    ("6 6 6 T 2", "Three Sixes"),
-    ("6 6 6 9 8", "Three Sixes"),
-    ("6 6 6 9 7", "Three Sixes"),
-    ("6 6 6 9 5", "Three Sixes"),
-    ("6 6 6 9 4", "Three Sixes"),
-    ("6 6 6 9 3", "Three Sixes"),
-    ("6 6 6 9 2", "Three Sixes"),
-    ("6 6 6 8 7", "Three Sixes"),
-    ("6 6 6 8 5", "Three Sixes"),
-    ("6 6 6 8 4", "Three Sixes"),
-    ("6 6 6 8 3", "Three Sixes"),
-    ("6 6 6 8 2", "Three Sixes"),
-    ("6 6 6 7 5", "Three Sixes"),
-    ("6 6 6 7 4", "Three Sixes"),
-    ("6 6 6 7 3", "Three Sixes"),
-    ("6 6 6 7 2", "Three Sixes"),
-    ("6 6 6 5 4", "Three Sixes"),
-    ("6 6 6 5 3", "Three Sixes"),
-    ("6 6 6 5 2", "Three Sixes"),
-    ("6 6 6 4 3", "Three Sixes"),
-    ("6 6 6 4 2", "Three Sixes"),
-    ("6 6 6 3 2", "Three Sixes"),
-    ("5 5 5 A K", "Three Fives"),
-    ("5 5 5 A Q", "Three Fives"),
-    ("5 5 5 A J", "Three Fives"),
-    ("5 5 5 A T", "Three Fives"),
-    ("5 5 5 A 9", "Three Fives"),
-    ("5 5 5 A 8", "Three Fives"),
-    ("5 5 5 A 7", "Three Fives"),
-    ("5 5 5 A 6", "Three Fives"),
-    ("5 5 5 A 4", "Three Fives"),
-    ("5 5 5 A 3", "Three Fives"),
-    ("5 5 5 A 2", "Three Fives"),
-    ("5 5 5 K Q", "Three Fives"),
-    ("5 5 5 K J", "Three Fives"),
-    ("5 5 5 K T", "Three Fives"),
-    ("5 5 5 K 9", "Three Fives"),
-    ("5 5 5 K 8", "Three Fives"),
-    ("5 5 5 K 7", "Three Fives"),
-    ("5 5 5 K 6", "Three Fives"),
-    ("5 5 5 K 4", "Three Fives"),
-    ("5 5 5 K 3", "Three Fives"),
-    ("5 5 5 K 2", "Three Fives"),
-    ("5 5 5 Q J", "Three Fives"),
-    ("5 5 5 Q T", "Three Fives"),
-    ("5 5 5 Q 9", "Three Fives"),
-    ("5 5 5 Q 8", "Three Fives"),
-    ("5 5 5 Q 7", "Three Fives"),
-    ("5 5 5 Q 6", "Three Fives"),
-    ("5 5 5 Q 4", "Three Fives"),
-    ("5 5 5 Q 3", "Three Fives"),
-    ("5 5 5 Q 2", "Three Fives"),
-    ("5 5 5 J T", "Three Fives"),
-    ("5 5 5 J 9", "Three Fives"),
-    ("5 5 5 J 8", "Three Fives"),
-    ("5 5 5 J 7", "Three Fives"),
-    ("5 5 5 J 6", "Three Fives"),
-    ("5 5 5 J 4", "Three Fives"),
-    ("5 5 5 J 3", "Three Fives"),
-    ("5 5 5 J 2", "Three Fives"),
-    ("5 5 5 T 9", "Three Fives"),
-    ("5 5 5 T 8", "Three Fives"),
-    ("5 5 5 T 7", "Three Fives"),
-    ("5 5 5 T 6", "Three Fives"),
-    ("5 5 5 T 4", "Three Fives"),
-    ("5 5 5 T 3", "Three Fives"),
-    ("5 5 5 T 2", "Three Fives"),
-    ("5 5 5 9 8", "Three Fives"),
-    ("5 5 5 9 7", "Three Fives"),
-    ("5 5 5 9 6", "Three Fives"),
-    ("5 5 5 9 4", "Three Fives"),
-    ("5 5 5 9 3", "Three Fives"),
-    ("5 5 5 9 2", "Three Fives"),
-    ("5 5 5 8 7", "Three Fives"),
-    ("5 5 5 8 6", "Three Fives"),
-    ("5 5 5 8 4", "Three Fives"),
-    ("5 5 5 8 3", "Three Fives"),
-    ("5 5 5 8 2", "Three Fives"),
-    ("5 5 5 7 6", "Three Fives"),
-    ("5 5 5 7 4", "Three Fives"),
-    ("5 5 5 7 3", "Three Fives"),
-    ("5 5 5 7 2", "Three Fives"),
-    ("5 5 5 6 4", "Three Fives"),
-    ("5 5 5 6 3", "Three Fives"),
-    ("5 5 5 6 2", "Three Fives"),
-    ("5 5 5 4 3", "Three Fives"),
-    ("5 5 5 4 2", "Three Fives"),
-    ("5 5 5 3 2", "Three Fives"),
-    ("4 4 4 A K", "Three Fours"),
-    ("4 4 4 A Q", "Three Fours"),
-    ("4 4 4 A J", "Three Fours"),
-    ("4 4 4 A T", "Three Fours"),
-    ("4 4 4 A 9", "Three Fours"),
-    ("4 4 4 A 8", "Three Fours"),
-    ("4 4 4 A 7", "Three Fours"),
-    ("4 4 4 A 6", "Three Fours"),
-    ("4 4 4 A 5", "Three Fours"),
-    ("4 4 4 A 3", "Three Fours"),
-    ("4 4 4 A 2", "Three Fours"),
-    ("4 4 4 K Q", "Three Fours"),
-    ("4 4 4 K J", "Three Fours"),
-    ("4 4 4 K T", "Three Fours"),
-    ("4 4 4 K 9", "Three Fours"),
-    ("4 4 4 K 8", "Three Fours"),
-    ("4 4 4 K 7", "Three Fours"),
-    ("4 4 4 K 6", "Three Fours"),
-    ("4 4 4 K 5", "Three Fours"),
-    ("4 4 4 K 3", "Three Fours"),
-    ("4 4 4 K 2", "Three Fours"),
-    ("4 4 4 Q J", "Three Fours"),
-    ("4 4 4 Q T", "Three Fours"),
-    ("4 4 4 Q 9", "Three Fours"),
-    ("4 4 4 Q 8", "Three Fours"),
-    ("4 4 4 Q 7", "Three Fours"),
-    ("4 4 4 Q 6", "Three Fours"),
-    ("4 4 4 Q 5", "Three Fours"),
-    ("4 4 4 Q 3", "Three Fours"),
-    ("4 4 4 Q 2", "Three Fours"),
-    ("4 4 4 J T", "Three Fours"),
-    ("4 4 4 J 9", "Three Fours"),
-    ("4 4 4 J 8", "Three Fours"),
-    ("4 4 4 J 7", "Three Fours"),
-    ("4 4 4 J 6", "Three Fours"),
-    ("4 4 4 J 5", "Three Fours"),
-    ("4 4 4 J 3", "Three Fours"),
-    ("4 4 4 J 2", "Three Fours"),
-    ("4 4 4 T 9", "Three Fours"),
-    ("4 4 4 T 8", "Three Fours"),
-    ("4 4 4 T 7", "Three Fours"),
-    ("4 4 4 T 6", "Three Fours"),
-    ("4 4 4 T 5", "Three Fours"),
-    ("4 4 4 T 3", "Three Fours"),
-    ("4 4 4 T 2", "Three Fours"),
-    ("4 4 4 9 8", "Three Fours"),
-    ("4 4 4 9 7", "Three Fours"),
-    ("4 4 4 9 6", "Three Fours"),
-    ("4 4 4 9 5", "Three Fours"),
-    ("4 4 4 9 3", "Three Fours"),
-    ("4 4 4 9 2", "Three Fours"),
-    ("4 4 4 8 7", "Three Fours"),
-    ("4 4 4 8 6", "Three Fours"),
-    ("4 4 4 8 5", "Three Fours"),
-    ("4 4 4 8 3", "Three Fours"),
-    ("4 4 4 8 2", "Three Fours"),
-    ("4 4 4 7 6", "Three Fours"),
-    ("4 4 4 7 5", "Three Fours"),
-    ("4 4 4 7 3", "Three Fours"),
-    ("4 4 4 7 2", "Three Fours"),
-    ("4 4 4 6 5", "Three Fours"),
-    ("4 4 4 6 3", "Three Fours"),
-    ("4 4 4 6 2", "Three Fours"),
-    ("4 4 4 5 3", "Three Fours"),
-    ("4 4 4 5 2", "Three Fours"),
-    ("4 4 4 3 2", "Three Fours"),
-    ("3 3 3 A K", "Three Treys"),
-    ("3 3 3 A Q", "Three Treys"),
-    ("3 3 3 A J", "Three Treys"),
-    ("3 3 3 A T", "Three Treys"),
-    ("3 3 3 A 9", "Three Treys"),
-    ("3 3 3 A 8", "Three Treys"),
-    ("3 3 3 A 7", "Three Treys"),
-    ("3 3 3 A 6", "Three Treys"),
-    ("3 3 3 A 5", "Three Treys"),
-    ("3 3 3 A 4", "Three Treys"),
-    ("3 3 3 A 2", "Three Treys"),
-    ("3 3 3 K Q", "Three Treys"),
-    ("3 3 3 K J", "Three Treys"),
-    ("3 3 3 K T", "Three Treys"),
-    ("3 3 3 K 9", "Three Treys"),
-    ("3 3 3 K 8", "Three Treys"),
-    ("3 3 3 K 7", "Three Treys"),
-    ("3 3 3 K 6", "Three Treys"),
-    ("3 3 3 K 5", "Three Treys"),
-    ("3 3 3 K 4", "Three Treys"),
-    ("3 3 3 K 2", "Three Treys"),
-    ("3 3 3 Q J", "Three Treys"),
-    ("3 3 3 Q T", "Three Treys"),
-    ("3 3 3 Q 9", "Three Treys"),
-    ("3 3 3 Q 8", "Three Treys"),
-    ("3 3 3 Q 7", "Three Treys"),
-    ("3 3 3 Q 6", "Three Treys"),
-    ("3 3 3 Q 5", "Three Treys"),
-    ("3 3 3 Q 4", "Three Treys"),
-    ("3 3 3 Q 2", "Three Treys"),
-    ("3 3 3 J T", "Three Treys"),
-    ("3 3 3 J 9", "Three Treys"),
-    ("3 3 3 J 8", "Three Treys"),
-    ("3 3 3 J 7", "Three Treys"),
-    ("3 3 3 J 6", "Three Treys"),
-    ("3 3 3 J 5", "Three Treys"),
-    ("3 3 3 J 4", "Three Treys"),
-    ("3 3 3 J 2", "Three Treys"),
-    ("3 3 3 T 9", "Three Treys"),
-    ("3 3 3 T 8", "Three Treys"),
-    ("3 3 3 T 7", "Three Treys"),
-    ("3 3 3 T 6", "Three Treys"),
-    ("3 3 3 T 5", "Three Treys"),
-    ("3 3 3 T 4", "Three Treys"),
-    ("3 3 3 T 2", "Three Treys"),
-    ("3 3 3 9 8", "Three Treys"),
-    ("3 3 3 9 7", "Three Treys"),
-    ("3 3 3 9 6", "Three Treys"),
-    ("3 3 3 9 5", "Three Treys"),
-    ("3 3 3 9 4", "Three Treys"),
-    ("3 3 3 9 2", "Three Treys"),
-    ("3 3 3 8 7", "Three Treys"),
-    ("3 3 3 8 6", "Three Treys"),
-    ("3 3 3 8 5", "Three Treys"),
-    ("3 3 3 8 4", "Three Treys"),
-    ("3 3 3 8 2", "Three Treys"),
-    ("3 3 3 7 6", "Three Treys"),
-    ("3 3 3 7 5", "Three Treys"),
-    ("3 3 3 7 4", "Three Treys"),
-    ("3 3 3 7 2", "Three Treys"),
-    ("3 3 3 6 5", "Three Treys"),
-    ("3 3 3 6 4", "Three Treys"),
-    ("3 3 3 6 2", "Three Treys"),
-    ("3 3 3 5 4", "Three Treys"),
-    ("3 3 3 5 2", "Three Treys"),
-    ("3 3 3 4 2", "Three Treys"),
-    ("2 2 2 A K", "Three Deuces"),
-    ("2 2 2 A Q", "Three Deuces"),
-    ("2 2 2 A J", "Three Deuces"),
-    ("2 2 2 A T", "Three Deuces"),
-    ("2 2 2 A 9", "Three Deuces"),
-    ("2 2 2 A 8", "Three Deuces"),
-    ("2 2 2 A 7", "Three Deuces"),
-    ("2 2 2 A 6", "Three Deuces"),
-    ("2 2 2 A 5", "Three Deuces"),
-    ("2 2 2 A 4", "Three Deuces"),
-    ("2 2 2 A 3", "Three Deuces"),
-    ("2 2 2 K Q", "Three Deuces"),
-    ("2 2 2 K J", "Three Deuces"),
-    ("2 2 2 K T", "Three Deuces"),
-    ("2 2 2 K 9", "Three Deuces"),
-    ("2 2 2 K 8", "Three Deuces"),
-    ("2 2 2 K 7", "Three Deuces"),
-    ("2 2 2 K 6", "Three Deuces"),
-    ("2 2 2 K 5", "Three Deuces"),
-    ("2 2 2 K 4", "Three Deuces"),
-    ("2 2 2 K 3", "Three Deuces"),
-    ("2 2 2 Q J", "Three Deuces"),
-    ("2 2 2 Q T", "Three Deuces"),
-    ("2 2 2 Q 9", "Three Deuces"),
-    ("2 2 2 Q 8", "Three Deuces"),
-    ("2 2 2 Q 7", "Three Deuces"),
-    ("2 2 2 Q 6", "Three Deuces"),
-    ("2 2 2 Q 5", "Three Deuces"),
-    ("2 2 2 Q 4", "Three Deuces"),
-    ("2 2 2 Q 3", "Three Deuces"),
-    ("2 2 2 J T", "Three Deuces"),
-    ("2 2 2 J 9", "Three Deuces"),
-    ("2 2 2 J 8", "Three Deuces"),
-    ("2 2 2 J 7", "Three Deuces"),
-    ("2 2 2 J 6", "Three Deuces"),
-    ("2 2 2 J 5", "Three Deuces"),
-    ("2 2 2 J 4", "Three Deuces"),
-    ("2 2 2 J 3", "Three Deuces"),
-    ("2 2 2 T 9", "Three Deuces"),
-    ("2 2 2 T 8", "Three Deuces"),
-    ("2 2 2 T 7", "Three Deuces"),
-    ("2 2 2 T 6", "Three Deuces"),
-    ("2 2 2 T 5", "Three Deuces"),
-    ("2 2 2 T 4", "Three Deuces"),
-    ("2 2 2 T 3", "Three Deuces"),
-    ("2 2 2 9 8", "Three Deuces"),
-    ("2 2 2 9 7", "Three Deuces"),
-    ("2 2 2 9 6", "Three Deuces"),
-    ("2 2 2 9 5", "Three Deuces"),
-    ("2 2 2 9 4", "Three Deuces"),
-    ("2 2 2 9 3", "Three Deuces"),
-    ("2 2 2 8 7", "Three Deuces"),
-    ("2 2 2 8 6", "Three Deuces"),
-    ("2 2 2 8 5", "Three Deuces"),
-    ("2 2 2 8 4", "Three Deuces"),
-    ("2 2 2 8 3", "Three Deuces"),
-    ("2 2 2 7 6", "Three Deuces"),
-    ("2 2 2 7 5", "Three Deuces"),
-    ("2 2 2 7 4", "Three Deuces"),
-    ("2 2 2 7 3", "Three Deuces"),
-    ("2 2 2 6 5", "Three Deuces"),
-    ("2 2 2 6 4", "Three Deuces"),
-    ("2 2 2 6 3", "Three Deuces"),
-    ("2 2 2 5 4", "Three Deuces"),
-    ("2 2 2 5 3", "Three Deuces"),
-    ("2 2 2 4 3", "Three Deuces"),
-    ("A A K K Q", "Aces and Kings"),
-    ("A A K K J", "Aces and Kings"),
-    ("A A K K T", "Aces and Kings"),
-    ("A A K K 9", "Aces and Kings"),
-    ("A A K K 8", "Aces and Kings"),
-    ("A A K K 7", "Aces and Kings"),
-    ("A A K K 6", "Aces and Kings"),
-    ("A A K K 5", "Aces and Kings"),
-    ("A A K K 4", "Aces and Kings"),
-    ("A A K K 3", "Aces and Kings"),
-    ("A A K K 2", "Aces and Kings"),
-    ("A A Q Q K", "Aces and Queens"),
-    ("A A Q Q J", "Aces and Queens"),
-    ("A A Q Q T", "Aces and Queens"),
-    ("A A Q Q 9", "Aces and Queens"),
-    ("A A Q Q 8", "Aces and Queens"),
-    ("A A Q Q 7", "Aces and Queens"),
-    ("A A Q Q 6", "Aces and Queens"),
-    ("A A Q Q 5", "Aces and Queens"),
-    ("A A Q Q 4", "Aces and Queens"),
-    ("A A Q Q 3", "Aces and Queens"),
-    ("A A Q Q 2", "Aces and Queens"),
-    ("A A J J K", "Aces and Jacks"),
-    ("A A J J Q", "Aces and Jacks"),
-    ("A A J J T", "Aces and Jacks"),
-    ("A A J J 9", "Aces and Jacks"),
-    ("A A J J 8", "Aces and Jacks"),
-    ("A A J J 7", "Aces and Jacks"),
-    ("A A J J 6", "Aces and Jacks"),
-    ("A A J J 5", "Aces and Jacks"),
-    ("A A J J 4", "Aces and Jacks"),
-    ("A A J J 3", "Aces and Jacks"),
-    ("A A J J 2", "Aces and Jacks"),
-    ("A A T T K", "Aces and Tens"),
-    ("A A T T Q", "Aces and Tens"),
-    ("A A T T J", "Aces and Tens"),
-    ("A A T T 9", "Aces and Tens"),
-    ("A A T T 8", "Aces and Tens"),
-    ("A A T T 7", "Aces and Tens"),
-    ("A A T T 6", "Aces and Tens"),
-    ("A A T T 5", "Aces and Tens"),
-    ("A A T T 4", "Aces and Tens"),
-    ("A A T T 3", "Aces and Tens"),
-    ("A A T T 2", "Aces and Tens"),
-    ("A A 9 9 K", "Aces and Nines"),
-    ("A A 9 9 Q", "Aces and Nines"),
-    ("A A 9 9 J", "Aces and Nines"),
-    ("A A 9 9 T", "Aces and Nines"),
-    ("A A 9 9 8", "Aces and Nines"),
-    ("A A 9 9 7", "Aces and Nines"),
-    ("A A 9 9 6", "Aces and Nines"),
-    ("A A 9 9 5", "Aces and Nines"),
-    ("A A 9 9 4", "Aces and Nines"),
-    ("A A 9 9 3", "Aces and Nines"),
-    ("A A 9 9 2", "Aces and Nines"),
-    ("A A 8 8 K", "Aces and Eights"),
-    ("A A 8 8 Q", "Aces and Eights"),
-    ("A A 8 8 J", "Aces and Eights"),
-    ("A A 8 8 T", "Aces and Eights"),
-    ("A A 8 8 9", "Aces and Eights"),
-    ("A A 8 8 7", "Aces and Eights"),
-    ("A A 8 8 6", "Aces and Eights"),
-    ("A A 8 8 5", "Aces and Eights"),
-    ("A A 8 8 4", "Aces and Eights"),
-    ("A A 8 8 3", "Aces and Eights"),
-    ("A A 8 8 2", "Aces and Eights"),
-    ("A A 7 7 K", "Aces and Sevens"),
-    ("A A 7 7 Q", "Aces and Sevens"),
-    ("A A 7 7 J", "Aces and Sevens"),
-    ("A A 7 7 T", "Aces and Sevens"),
-    ("A A 7 7 9", "Aces and Sevens"),
-    ("A A 7 7 8", "Aces and Sevens"),
-    ("A A 7 7 6", "Aces and Sevens"),
-    ("A A 7 7 5", "Aces and Sevens"),
-    ("A A 7 7 4", "Aces and Sevens"),
-    ("A A 7 7 3", "Aces and Sevens"),
-    ("A A 7 7 2", "Aces and Sevens"),
-    ("A A 6 6 K", "Aces and Sixes"),
-    ("A A 6 6 Q", "Aces and Sixes"),
-    ("A A 6 6 J", "Aces and Sixes"),
-    ("A A 6 6 T", "Aces and Sixes"),
-    ("A A 6 6 9", "Aces and Sixes"),
-    ("A A 6 6 8", "Aces and Sixes"),
-    ("A A 6 6 7", "Aces and Sixes"),
-    ("A A 6 6 5", "Aces and Sixes"),
-    ("A A 6 6 4", "Aces and Sixes"),
-    ("A A 6 6 3", "Aces and Sixes"),
-    ("A A 6 6 2", "Aces and Sixes"),
-    ("A A 5 5 K", "Aces and Fives"),
-    ("A A 5 5 Q", "Aces and Fives"),
-    ("A A 5 5 J", "Aces and Fives"),
-    ("A A 5 5 T", "Aces and Fives"),
-    ("A A 5 5 9", "Aces and Fives"),
-    ("A A 5 5 8", "Aces and Fives"),
-    ("A A 5 5 7", "Aces and Fives"),
-    ("A A 5 5 6", "Aces and Fives"),
-    ("A A 5 5 4", "Aces and Fives"),
-    ("A A 5 5 3", "Aces and Fives"),
-    ("A A 5 5 2", "Aces and Fives"),
-    ("A A 4 4 K", "Aces and Fours"),
-    ("A A 4 4 Q", "Aces and Fours"),
-    ("A A 4 4 J", "Aces and Fours"),
-    ("A A 4 4 T", "Aces and Fours"),
-    ("A A 4 4 9", "Aces and Fours"),
-    ("A A 4 4 8", "Aces and Fours"),
-    ("A A 4 4 7", "Aces and Fours"),
-    ("A A 4 4 6", "Aces and Fours"),
-    ("A A 4 4 5", "Aces and Fours"),
-    ("A A 4 4 3", "Aces and Fours"),
-    ("A A 4 4 2", "Aces and Fours"),
-    ("A A 3 3 K", "Aces and Treys"),
-    ("A A 3 3 Q", "Aces and Treys"),
-    ("A A 3 3 J", "Aces and Treys"),
-    ("A A 3 3 T", "Aces and Treys"),
-    ("A A 3 3 9", "Aces and Treys"),
-    ("A A 3 3 8", "Aces and Treys"),
-    ("A A 3 3 7", "Aces and Treys"),
-    ("A A 3 3 6", "Aces and Treys"),
-    ("A A 3 3 5", "Aces and Treys"),
-    ("A A 3 3 4", "Aces and Treys"),
-    ("A A 3 3 2", "Aces and Treys"),
-    ("A A 2 2 K", "Aces and Deuces"),
-    ("A A 2 2 Q", "Aces and Deuces"),
-    ("A A 2 2 J", "Aces and Deuces"),
-    ("A A 2 2 T", "Aces and Deuces"),
-    ("A A 2 2 9", "Aces and Deuces"),
-    ("A A 2 2 8", "Aces and Deuces"),
-    ("A A 2 2 7", "Aces and Deuces"),
-    ("A A 2 2 6", "Aces and Deuces"),
-    ("A A 2 2 5", "Aces and Deuces"),
-    ("A A 2 2 4", "Aces and Deuces"),
-    ("A A 2 2 3", "Aces and Deuces"),
-    ("K K Q Q A", "Kings and Queens"),
-    ("K K Q Q J", "Kings and Queens"),
-    ("K K Q Q T", "Kings and Queens"),
-    ("K K Q Q 9", "Kings and Queens"),
-    ("K K Q Q 8", "Kings and Queens"),
-    ("K K Q Q 7", "Kings and Queens"),
-    ("K K Q Q 6", "Kings and Queens"),
-    ("K K Q Q 5", "Kings and Queens"),
-    ("K K Q Q 4", "Kings and Queens"),
-    ("K K Q Q 3", "Kings and Queens"),
-    ("K K Q Q 2", "Kings and Queens"),
-    ("K K J J A", "Kings and Jacks"),
-    ("K K J J Q", "Kings and Jacks"),
-    ("K K J J T", "Kings and Jacks"),
-    ("K K J J 9", "Kings and Jacks"),
-    ("K K J J 8", "Kings and Jacks"),
-    ("K K J J 7", "Kings and Jacks"),
-    ("K K J J 6", "Kings and Jacks"),
-    ("K K J J 5", "Kings and Jacks"),
-    ("K K J J 4", "Kings and Jacks"),
-    ("K K J J 3", "Kings and Jacks"),
-    ("K K J J 2", "Kings and Jacks"),
-    ("K K T T A", "Kings and Tens"),
-    ("K K T T Q", "Kings and Tens"),
-    ("K K T T J", "Kings and Tens"),
-    ("K K T T 9", "Kings and Tens"),
-    ("K K T T 8", "Kings and Tens"),
-    ("K K T T 7", "Kings and Tens"),
-    ("K K T T 6", "Kings and Tens"),
-    ("K K T T 5", "Kings and Tens"),
-    ("K K T T 4", "Kings and Tens"),
-    ("K K T T 3", "Kings and Tens"),
-    ("K K T T 2", "Kings and Tens"),
-    ("K K 9 9 A", "Kings and Nines"),
-    ("K K 9 9 Q", "Kings and Nines"),
-    ("K K 9 9 J", "Kings and Nines"),
-    ("K K 9 9 T", "Kings and Nines"),
-    ("K K 9 9 8", "Kings and Nines"),
-    ("K K 9 9 7", "Kings and Nines"),
-    ("K K 9 9 6", "Kings and Nines"),
-    ("K K 9 9 5", "Kings and Nines"),
-    ("K K 9 9 4", "Kings and Nines"),
-    ("K K 9 9 3", "Kings and Nines"),
-    ("K K 9 9 2", "Kings and Nines"),
-    ("K K 8 8 A", "Kings and Eights"),
-    ("K K 8 8 Q", "Kings and Eights"),
-    ("K K 8 8 J", "Kings and Eights"),
-    ("K K 8 8 T", "Kings and Eights"),
-    ("K K 8 8 9", "Kings and Eights"),
-    ("K K 8 8 7", "Kings and Eights"),
-    ("K K 8 8 6", "Kings and Eights"),
-    ("K K 8 8 5", "Kings and Eights"),
-    ("K K 8 8 4", "Kings and Eights"),
-    ("K K 8 8 3", "Kings and Eights"),
-    ("K K 8 8 2", "Kings and Eights"),
-    ("K K 7 7 A", "Kings and Sevens"),
-    ("K K 7 7 Q", "Kings and Sevens"),
-    ("K K 7 7 J", "Kings and Sevens"),
-    ("K K 7 7 T", "Kings and Sevens"),
-    ("K K 7 7 9", "Kings and Sevens"),
-    ("K K 7 7 8", "Kings and Sevens"),
-    ("K K 7 7 6", "Kings and Sevens"),
-    ("K K 7 7 5", "Kings and Sevens"),
-    ("K K 7 7 4", "Kings and Sevens"),
-    ("K K 7 7 3", "Kings and Sevens"),
-    ("K K 7 7 2", "Kings and Sevens"),
-    ("K K 6 6 A", "Kings and Sixes"),
-    ("K K 6 6 Q", "Kings and Sixes"),
-    ("K K 6 6 J", "Kings and Sixes"),
-    ("K K 6 6 T", "Kings and Sixes"),
-    ("K K 6 6 9", "Kings and Sixes"),
-    ("K K 6 6 8", "Kings and Sixes"),
-    ("K K 6 6 7", "Kings and Sixes"),
-    ("K K 6 6 5", "Kings and Sixes"),
-    ("K K 6 6 4", "Kings and Sixes"),
-    ("K K 6 6 3", "Kings and Sixes"),
-    ("K K 6 6 2", "Kings and Sixes"),
-    ("K K 5 5 A", "Kings and Fives"),
-    ("K K 5 5 Q", "Kings and Fives"),
-    ("K K 5 5 J", "Kings and Fives"),
-    ("K K 5 5 T", "Kings and Fives"),
-    ("K K 5 5 9", "Kings and Fives"),
-    ("K K 5 5 8", "Kings and Fives"),
-    ("K K 5 5 7", "Kings and Fives"),
-    ("K K 5 5 6", "Kings and Fives"),
-    ("K K 5 5 4", "Kings and Fives"),
-    ("K K 5 5 3", "Kings and Fives"),
-    ("K K 5 5 2", "Kings and Fives"),
-    ("K K 4 4 A", "Kings and Fours"),
-    ("K K 4 4 Q", "Kings and Fours"),
-    ("K K 4 4 J", "Kings and Fours"),
-    ("K K 4 4 T", "Kings and Fours"),
-    ("K K 4 4 9", "Kings and Fours"),
-    ("K K 4 4 8", "Kings and Fours"),
-    ("K K 4 4 7", "Kings and Fours"),
-    ("K K 4 4 6", "Kings and Fours"),
-    ("K K 4 4 5", "Kings and Fours"),
-    ("K K 4 4 3", "Kings and Fours"),
-    ("K K 4 4 2", "Kings and Fours"),
-    ("K K 3 3 A", "Kings and Treys"),
-    ("K K 3 3 Q", "Kings and Treys"),
-    ("K K 3 3 J", "Kings and Treys"),
-    ("K K 3 3 T", "Kings and Treys"),
-    ("K K 3 3 9", "Kings and Treys"),
-    ("K K 3 3 8", "Kings and Treys"),
-    ("K K 3 3 7", "Kings and Treys"),
-    ("K K 3 3 6", "Kings and Treys"),
-    ("K K 3 3 5", "Kings and Treys"),
-    ("K K 3 3 4", "Kings and Treys"),
-    ("K K 3 3 2", "Kings and Treys"),
-    ("K K 2 2 A", "Kings and Deuces"),
-    ("K K 2 2 Q", "Kings and Deuces"),
-    ("K K 2 2 J", "Kings and Deuces"),
-    ("K K 2 2 T", "Kings and Deuces"),
-    ("K K 2 2 9", "Kings and Deuces"),
-    ("K K 2 2 8", "Kings and Deuces"),
-    ("K K 2 2 7", "Kings and Deuces"),
-    ("K K 2 2 6", "Kings and Deuces"),
-    ("K K 2 2 5", "Kings and Deuces"),
-    ("K K 2 2 4", "Kings and Deuces"),
-    ("K K 2 2 3", "Kings and Deuces"),
-    ("Q Q J J A", "Queens and Jacks"),
-    ("Q Q J J K", "Queens and Jacks"),
-    ("Q Q J J T", "Queens and Jacks"),
-    ("Q Q J J 9", "Queens and Jacks"),
-    ("Q Q J J 8", "Queens and Jacks"),
-    ("Q Q J J 7", "Queens and Jacks"),
-    ("Q Q J J 6", "Queens and Jacks"),
-    ("Q Q J J 5", "Queens and Jacks"),
-    ("Q Q J J 4", "Queens and Jacks"),
-    ("Q Q J J 3", "Queens and Jacks"),
-    ("Q Q J J 2", "Queens and Jacks"),
-    ("Q Q T T A", "Queens and Tens"),
-    ("Q Q T T K", "Queens and Tens"),
-    ("Q Q T T J", "Queens and Tens"),
-    ("Q Q T T 9", "Queens and Tens"),
-    ("Q Q T T 8", "Queens and Tens"),
-    ("Q Q T T 7", "Queens and Tens"),
-    ("Q Q T T 6", "Queens and Tens"),
-    ("Q Q T T 5", "Queens and Tens"),
-    ("Q Q T T 4", "Queens and Tens"),
-    ("Q Q T T 3", "Queens and Tens"),
-    ("Q Q T T 2", "Queens and Tens"),
-    ("Q Q 9 9 A", "Queens and Nines"),
-    ("Q Q 9 9 K", "Queens and Nines"),
-    ("Q Q 9 9 J", "Queens and Nines"),
-    ("Q Q 9 9 T", "Queens and Nines"),
-    ("Q Q 9 9 8", "Queens and Nines"),
-    ("Q Q 9 9 7", "Queens and Nines"),
-    ("Q Q 9 9 6", "Queens and Nines"),
-    ("Q Q 9 9 5", "Queens and Nines"),
-    ("Q Q 9 9 4", "Queens and Nines"),
-    ("Q Q 9 9 3", "Queens and Nines"),
-    ("Q Q 9 9 2", "Queens and Nines"),
-    ("Q Q 8 8 A", "Queens and Eights"),
-    ("Q Q 8 8 K", "Queens and Eights"),
-    ("Q Q 8 8 J", "Queens and Eights"),
-    ("Q Q 8 8 T", "Queens and Eights"),
-    ("Q Q 8 8 9", "Queens and Eights"),
-    ("Q Q 8 8 7", "Queens and Eights"),
-    ("Q Q 8 8 6", "Queens and Eights"),
-    ("Q Q 8 8 5", "Queens and Eights"),
-    ("Q Q 8 8 4", "Queens and Eights"),
-    ("Q Q 8 8 3", "Queens and Eights"),
-    ("Q Q 8 8 2", "Queens and Eights"),
-    ("Q Q 7 7 A", "Queens and Sevens"),
-    ("Q Q 7 7 K", "Queens and Sevens"),
-    ("Q Q 7 7 J", "Queens and Sevens"),
-    ("Q Q 7 7 T", "Queens and Sevens"),
-    ("Q Q 7 7 9", "Queens and Sevens"),
-    ("Q Q 7 7 8", "Queens and Sevens"),
-    ("Q Q 7 7 6", "Queens and Sevens"),
-    ("Q Q 7 7 5", "Queens and Sevens"),
-    ("Q Q 7 7 4", "Queens and Sevens"),
-    ("Q Q 7 7 3", "Queens and Sevens"),
-    ("Q Q 7 7 2", "Queens and Sevens"),
-    ("Q Q 6 6 A", "Queens and Sixes"),
-    ("Q Q 6 6 K", "Queens and Sixes"),
-    ("Q Q 6 6 J", "Queens and Sixes"),
-    ("Q Q 6 6 T", "Queens and Sixes"),
-    ("Q Q 6 6 9", "Queens and Sixes"),
-    ("Q Q 6 6 8", "Queens and Sixes"),
-    ("Q Q 6 6 7", "Queens and Sixes"),
-    ("Q Q 6 6 5", "Queens and Sixes"),
-    ("Q Q 6 6 4", "Queens and Sixes"),
-    ("Q Q 6 6 3", "Queens and Sixes"),
-    ("Q Q 6 6 2", "Queens and Sixes"),
-    ("Q Q 5 5 A", "Queens and Fives"),
-    ("Q Q 5 5 K", "Queens and Fives"),
-    ("Q Q 5 5 J", "Queens and Fives"),
-    ("Q Q 5 5 T", "Queens and Fives"),
-    ("Q Q 5 5 9", "Queens and Fives"),
-    ("Q Q 5 5 8", "Queens and Fives"),
-    ("Q Q 5 5 7", "Queens and Fives"),
-    ("Q Q 5 5 6", "Queens and Fives"),
-    ("Q Q 5 5 4", "Queens and Fives"),
-    ("Q Q 5 5 3", "Queens and Fives"),
-    ("Q Q 5 5 2", "Queens and Fives"),
-    ("Q Q 4 4 A", "Queens and Fours"),
-    ("Q Q 4 4 K", "Queens and Fours"),
-    ("Q Q 4 4 J", "Queens and Fours"),
-    ("Q Q 4 4 T", "Queens and Fours"),
-    ("Q Q 4 4 9", "Queens and Fours"),
-    ("Q Q 4 4 8", "Queens and Fours"),
-    ("Q Q 4 4 7", "Queens and Fours"),
-    ("Q Q 4 4 6", "Queens and Fours"),
-    ("Q Q 4 4 5", "Queens and Fours"),
-    ("Q Q 4 4 3", "Queens and Fours"),
-    ("Q Q 4 4 2", "Queens and Fours"),
-    ("Q Q 3 3 A", "Queens and Treys"),
-    ("Q Q 3 3 K", "Queens and Treys"),
-    ("Q Q 3 3 J", "Queens and Treys"),
-    ("Q Q 3 3 T", "Queens and Treys"),
-    ("Q Q 3 3 9", "Queens and Treys"),
-    ("Q Q 3 3 8", "Queens and Treys"),
-    ("Q Q 3 3 7", "Queens and Treys"),
-    ("Q Q 3 3 6", "Queens and Treys"),
-    ("Q Q 3 3 5", "Queens and Treys"),
-    ("Q Q 3 3 4", "Queens and Treys"),
-    ("Q Q 3 3 2", "Queens and Treys"),
-    ("Q Q 2 2 A", "Queens and Deuces"),
-    ("Q Q 2 2 K", "Queens and Deuces"),
-    ("Q Q 2 2 J", "Queens and Deuces"),
-    ("Q Q 2 2 T", "Queens and Deuces"),
-    ("Q Q 2 2 9", "Queens and Deuces"),
-    ("Q Q 2 2 8", "Queens and Deuces"),
-    ("Q Q 2 2 7", "Queens and Deuces"),
-    ("Q Q 2 2 6", "Queens and Deuces"),
-    ("Q Q 2 2 5", "Queens and Deuces"),
-    ("Q Q 2 2 4", "Queens and Deuces"),
-    ("Q Q 2 2 3", "Queens and Deuces"),
-    ("J J T T A", "Jacks and Tens"),
-    ("J J T T K", "Jacks and Tens"),
-    ("J J T T Q", "Jacks and Tens"),
-    ("J J T T 9", "Jacks and Tens"),
-    ("J J T T 8", "Jacks and Tens"),
-    ("J J T T 7", "Jacks and Tens"),
-    ("J J T T 6", "Jacks and Tens"),
-    ("J J T T 5", "Jacks and Tens"),
-    ("J J T T 4", "Jacks and Tens"),
-    ("J J T T 3", "Jacks and Tens"),
-    ("J J T T 2", "Jacks and Tens"),
-    ("J J 9 9 A", "Jacks and Nines"),
-    ("J J 9 9 K", "Jacks and Nines"),
-    ("J J 9 9 Q", "Jacks and Nines"),
-    ("J J 9 9 T", "Jacks and Nines"),
-    ("J J 9 9 8", "Jacks and Nines"),
-    ("J J 9 9 7", "Jacks and Nines"),
-    ("J J 9 9 6", "Jacks and Nines"),
-    ("J J 9 9 5", "Jacks and Nines"),
-    ("J J 9 9 4", "Jacks and Nines"),
-    ("J J 9 9 3", "Jacks and Nines"),
-    ("J J 9 9 2", "Jacks and Nines"),
-    ("J J 8 8 A", "Jacks and Eights"),
-    ("J J 8 8 K", "Jacks and Eights"),
-    ("J J 8 8 Q", "Jacks and Eights"),
-    ("J J 8 8 T", "Jacks and Eights"),
-    ("J J 8 8 9", "Jacks and Eights"),
-    ("J J 8 8 7", "Jacks and Eights"),
-    ("J J 8 8 6", "Jacks and Eights"),
-    ("J J 8 8 5", "Jacks and Eights"),
-    ("J J 8 8 4", "Jacks and Eights"),
-    ("J J 8 8 3", "Jacks and Eights"),
-    ("J J 8 8 2", "Jacks and Eights"),
-    ("J J 7 7 A", "Jacks and Sevens"),
-    ("J J 7 7 K", "Jacks and Sevens"),
-    ("J J 7 7 Q", "Jacks and Sevens"),
-    ("J J 7 7 T", "Jacks and Sevens"),
-    ("J J 7 7 9", "Jacks and Sevens"),
-    ("J J 7 7 8", "Jacks and Sevens"),
-    ("J J 7 7 6", "Jacks and Sevens"),
-    ("J J 7 7 5", "Jacks and Sevens"),
-    ("J J 7 7 4", "Jacks and Sevens"),
-    ("J J 7 7 3", "Jacks and Sevens"),
-    ("J J 7 7 2", "Jacks and Sevens"),
-    ("J J 6 6 A", "Jacks and Sixes"),
-    ("J J 6 6 K", "Jacks and Sixes"),
-    ("J J 6 6 Q", "Jacks and Sixes"),
-    ("J J 6 6 T", "Jacks and Sixes"),
-    ("J J 6 6 9", "Jacks and Sixes"),
-    ("J J 6 6 8", "Jacks and Sixes"),
-    ("J J 6 6 7", "Jacks and Sixes"),
-    ("J J 6 6 5", "Jacks and Sixes"),
-    ("J J 6 6 4", "Jacks and Sixes"),
-    ("J J 6 6 3", "Jacks and Sixes"),
-    ("J J 6 6 2", "Jacks and Sixes"),
-    ("J J 5 5 A", "Jacks and Fives"),
-    ("J J 5 5 K", "Jacks and Fives"),
-    ("J J 5 5 Q", "Jacks and Fives"),
-    ("J J 5 5 T", "Jacks and Fives"),
-    ("J J 5 5 9", "Jacks and Fives"),
-    ("J J 5 5 8", "Jacks and Fives"),
-    ("J J 5 5 7", "Jacks and Fives"),
-    ("J J 5 5 6", "Jacks and Fives"),
-    ("J J 5 5 4", "Jacks and Fives"),
-    ("J J 5 5 3", "Jacks and Fives"),
-    ("J J 5 5 2", "Jacks and Fives"),
-    ("J J 4 4 A", "Jacks and Fours"),
-    ("J J 4 4 K", "Jacks and Fours"),
-    ("J J 4 4 Q", "Jacks and Fours"),
-    ("J J 4 4 T", "Jacks and Fours"),
-    ("J J 4 4 9", "Jacks and Fours"),
-    ("J J 4 4 8", "Jacks and Fours"),
-    ("J J 4 4 7", "Jacks and Fours"),
-    ("J J 4 4 6", "Jacks and Fours"),
-    ("J J 4 4 5", "Jacks and Fours"),
-    ("J J 4 4 3", "Jacks and Fours"),
-    ("J J 4 4 2", "Jacks and Fours"),
-    ("J J 3 3 A", "Jacks and Treys"),
-    ("J J 3 3 K", "Jacks and Treys"),
-    ("J J 3 3 Q", "Jacks and Treys"),
-    ("J J 3 3 T", "Jacks and Treys"),
-    ("J J 3 3 9", "Jacks and Treys"),
-    ("J J 3 3 8", "Jacks and Treys"),
-    ("J J 3 3 7", "Jacks and Treys"),
-    ("J J 3 3 6", "Jacks and Treys"),
-    ("J J 3 3 5", "Jacks and Treys"),
-    ("J J 3 3 4", "Jacks and Treys"),
-    ("J J 3 3 2", "Jacks and Treys"),
-    ("J J 2 2 A", "Jacks and Deuces"),
-    ("J J 2 2 K", "Jacks and Deuces"),
-    ("J J 2 2 Q", "Jacks and Deuces"),
-    ("J J 2 2 T", "Jacks and Deuces"),
-    ("J J 2 2 9", "Jacks and Deuces"),
-    ("J J 2 2 8", "Jacks and Deuces"),
-    ("J J 2 2 7", "Jacks and Deuces"),
-    ("J J 2 2 6", "Jacks and Deuces"),
-    ("J J 2 2 5", "Jacks and Deuces"),
-    ("J J 2 2 4", "Jacks and Deuces"),
-    ("J J 2 2 3", "Jacks and Deuces"),
-    ("T T 9 9 A", "Tens and Nines"),
-    ("T T 9 9 K", "Tens and Nines"),
-    ("T T 9 9 Q", "Tens and Nines"),
-    ("T T 9 9 J", "Tens and Nines"),
-    ("T T 9 9 8", "Tens and Nines"),
-    ("T T 9 9 7", "Tens and Nines"),
-    ("T T 9 9 6", "Tens and Nines"),
-    ("T T 9 9 5", "Tens and Nines"),
-    ("T T 9 9 4", "Tens and Nines"),
-    ("T T 9 9 3", "Tens and Nines"),
-    ("T T 9 9 2", "Tens and Nines"),
-    ("T T 8 8 A", "Tens and Eights"),
-    ("T T 8 8 K", "Tens and Eights"),
-    ("T T 8 8 Q", "Tens and Eights"),
-    ("T T 8 8 J", "Tens and Eights"),
-    ("T T 8 8 9", "Tens and Eights"),
-    ("T T 8 8 7", "Tens and Eights"),
-    ("T T 8 8 6", "Tens and Eights"),
-    ("T T 8 8 5", "Tens and Eights"),
-    ("T T 8 8 4", "Tens and Eights"),
-    ("T T 8 8 3", "Tens and Eights"),
-    ("T T 8 8 2", "Tens and Eights"),
-    ("T T 7 7 A", "Tens and Sevens"),
-    ("T T 7 7 K", "Tens and Sevens"),
-    ("T T 7 7 Q", "Tens and Sevens"),
-    ("T T 7 7 J", "Tens and Sevens"),
-    ("T T 7 7 9", "Tens and Sevens"),
-    ("T T 7 7 8", "Tens and Sevens"),
-    ("T T 7 7 6", "Tens and Sevens"),
-    ("T T 7 7 5", "Tens and Sevens"),
-    ("T T 7 7 4", "Tens and Sevens"),
-    ("T T 7 7 3", "Tens and Sevens"),
-    ("T T 7 7 2", "Tens and Sevens"),
-    ("T T 6 6 A", "Tens and Sixes"),
-    ("T T 6 6 K", "Tens and Sixes"),
-    ("T T 6 6 Q", "Tens and Sixes"),
-    ("T T 6 6 J", "Tens and Sixes"),
-    ("T T 6 6 9", "Tens and Sixes"),
-    ("T T 6 6 8", "Tens and Sixes"),
-    ("T T 6 6 7", "Tens and Sixes"),
-    ("T T 6 6 5", "Tens and Sixes"),
-    ("T T 6 6 4", "Tens and Sixes"),
-    ("T T 6 6 3", "Tens and Sixes"),
-    ("T T 6 6 2", "Tens and Sixes"),
-    ("T T 5 5 A", "Tens and Fives"),
-    ("T T 5 5 K", "Tens and Fives"),
-    ("T T 5 5 Q", "Tens and Fives"),
-    ("T T 5 5 J", "Tens and Fives"),
-    ("T T 5 5 9", "Tens and Fives"),
-    ("T T 5 5 8", "Tens and Fives"),
-    ("T T 5 5 7", "Tens and Fives"),
-    ("T T 5 5 6", "Tens and Fives"),
-    ("T T 5 5 4", "Tens and Fives"),
-    ("T T 5 5 3", "Tens and Fives"),
-    ("T T 5 5 2", "Tens and Fives"),
-    ("T T 4 4 A", "Tens and Fours"),
-    ("T T 4 4 K", "Tens and Fours"),
-    ("T T 4 4 Q", "Tens and Fours"),
-    ("T T 4 4 J", "Tens and Fours"),
-    ("T T 4 4 9", "Tens and Fours"),
-    ("T T 4 4 8", "Tens and Fours"),
-    ("T T 4 4 7", "Tens and Fours"),
-    ("T T 4 4 6", "Tens and Fours"),
-    ("T T 4 4 5", "Tens and Fours"),
-    ("T T 4 4 3", "Tens and Fours"),
-    ("T T 4 4 2", "Tens and Fours"),
-    ("T T 3 3 A", "Tens and Treys"),
-    ("T T 3 3 K", "Tens and Treys"),
-    ("T T 3 3 Q", "Tens and Treys"),
-    ("T T 3 3 J", "Tens and Treys"),
-    ("T T 3 3 9", "Tens and Treys"),
-    ("T T 3 3 8", "Tens and Treys"),
-    ("T T 3 3 7", "Tens and Treys"),
-    ("T T 3 3 6", "Tens and Treys"),
-    ("T T 3 3 5", "Tens and Treys"),
-    ("T T 3 3 4", "Tens and Treys"),
-    ("T T 3 3 2", "Tens and Treys"),
-    ("T T 2 2 A", "Tens and Deuces"),
-    ("T T 2 2 K", "Tens and Deuces"),
-    ("T T 2 2 Q", "Tens and Deuces"),
-    ("T T 2 2 J", "Tens and Deuces"),
-    ("T T 2 2 9", "Tens and Deuces"),
-    ("T T 2 2 8", "Tens and Deuces"),
-    ("T T 2 2 7", "Tens and Deuces"),
-    ("T T 2 2 6", "Tens and Deuces"),
-    ("T T 2 2 5", "Tens and Deuces"),
-    ("T T 2 2 4", "Tens and Deuces"),
-    ("T T 2 2 3", "Tens and Deuces"),
-    ("9 9 8 8 A", "Nines and Eights"),
-    ("9 9 8 8 K", "Nines and Eights"),
-    ("9 9 8 8 Q", "Nines and Eights"),
-    ("9 9 8 8 J", "Nines and Eights"),
-    ("9 9 8 8 T", "Nines and Eights"),
-    ("9 9 8 8 7", "Nines and Eights"),
-    ("9 9 8 8 6", "Nines and Eights"),
-    ("9 9 8 8 5", "Nines and Eights"),
-    ("9 9 8 8 4", "Nines and Eights"),
-    ("9 9 8 8 3", "Nines and Eights"),
-    ("9 9 8 8 2", "Nines and Eights"),
-    ("9 9 7 7 A", "Nines and Sevens"),
-    ("9 9 7 7 K", "Nines and Sevens"),
-    ("9 9 7 7 Q", "Nines and Sevens"),
-    ("9 9 7 7 J", "Nines and Sevens"),
-    ("9 9 7 7 T", "Nines and Sevens"),
-    ("9 9 7 7 8", "Nines and Sevens"),
-    ("9 9 7 7 6", "Nines and Sevens"),
-    ("9 9 7 7 5", "Nines and Sevens"),
-    ("9 9 7 7 4", "Nines and Sevens"),
-    ("9 9 7 7 3", "Nines and Sevens"),
-    ("9 9 7 7 2", "Nines and Sevens"),
-    ("9 9 6 6 A", "Nines and Sixes"),
-    ("9 9 6 6 K", "Nines and Sixes"),
-    ("9 9 6 6 Q", "Nines and Sixes"),
-    ("9 9 6 6 J", "Nines and Sixes"),
-    ("9 9 6 6 T", "Nines and Sixes"),
-    ("9 9 6 6 8", "Nines and Sixes"),
-    ("9 9 6 6 7", "Nines and Sixes"),
-    ("9 9 6 6 5", "Nines and Sixes"),
-    ("9 9 6 6 4", "Nines and Sixes"),
-    ("9 9 6 6 3", "Nines and Sixes"),
-    ("9 9 6 6 2", "Nines and Sixes"),
-    ("9 9 5 5 A", "Nines and Fives"),
-    ("9 9 5 5 K", "Nines and Fives"),
-    ("9 9 5 5 Q", "Nines and Fives"),
-    ("9 9 5 5 J", "Nines and Fives"),
-    ("9 9 5 5 T", "Nines and Fives"),
-    ("9 9 5 5 8", "Nines and Fives"),
-    ("9 9 5 5 7", "Nines and Fives"),
-    ("9 9 5 5 6", "Nines and Fives"),
-    ("9 9 5 5 4", "Nines and Fives"),
-    ("9 9 5 5 3", "Nines and Fives"),
-    ("9 9 5 5 2", "Nines and Fives"),
-    ("9 9 4 4 A", "Nines and Fours"),
-    ("9 9 4 4 K", "Nines and Fours"),
-    ("9 9 4 4 Q", "Nines and Fours"),
-    ("9 9 4 4 J", "Nines and Fours"),
-    ("9 9 4 4 T", "Nines and Fours"),
-    ("9 9 4 4 8", "Nines and Fours"),
-    ("9 9 4 4 7", "Nines and Fours"),
-    ("9 9 4 4 6", "Nines and Fours"),
-    ("9 9 4 4 5", "Nines and Fours"),
-    ("9 9 4 4 3", "Nines and Fours"),
-    ("9 9 4 4 2", "Nines and Fours"),
-    ("9 9 3 3 A", "Nines and Treys"),
-    ("9 9 3 3 K", "Nines and Treys"),
-    ("9 9 3 3 Q", "Nines and Treys"),
-    ("9 9 3 3 J", "Nines and Treys"),
-    ("9 9 3 3 T", "Nines and Treys"),
-    ("9 9 3 3 8", "Nines and Treys"),
-    ("9 9 3 3 7", "Nines and Treys"),
-    ("9 9 3 3 6", "Nines and Treys"),
-    ("9 9 3 3 5", "Nines and Treys"),
-    ("9 9 3 3 4", "Nines and Treys"),
-    ("9 9 3 3 2", "Nines and Treys"),
-    ("9 9 2 2 A", "Nines and Deuces"),
-    ("9 9 2 2 K", "Nines and Deuces"),
-    ("9 9 2 2 Q", "Nines and Deuces"),
-    ("9 9 2 2 J", "Nines and Deuces"),
-    ("9 9 2 2 T", "Nines and Deuces"),
-    ("9 9 2 2 8", "Nines and Deuces"),
-    ("9 9 2 2 7", "Nines and Deuces"),
-    ("9 9 2 2 6", "Nines and Deuces"),
-    ("9 9 2 2 5", "Nines and Deuces"),
-    ("9 9 2 2 4", "Nines and Deuces"),
-    ("9 9 2 2 3", "Nines and Deuces"),
-    ("8 8 7 7 A", "Eights and Sevens"),
-    ("8 8 7 7 K", "Eights and Sevens"),
-    ("8 8 7 7 Q", "Eights and Sevens"),
-    ("8 8 7 7 J", "Eights and Sevens"),
-    ("8 8 7 7 T", "Eights and Sevens"),
-    ("8 8 7 7 9", "Eights and Sevens"),
-    ("8 8 7 7 6", "Eights and Sevens"),
-    ("8 8 7 7 5", "Eights and Sevens"),
-    ("8 8 7 7 4", "Eights and Sevens"),
-    ("8 8 7 7 3", "Eights and Sevens"),
-    ("8 8 7 7 2", "Eights and Sevens"),
-    ("8 8 6 6 A", "Eights and Sixes"),
-    ("8 8 6 6 K", "Eights and Sixes"),
-    ("8 8 6 6 Q", "Eights and Sixes"),
-    ("8 8 6 6 J", "Eights and Sixes"),
-    ("8 8 6 6 T", "Eights and Sixes"),
-    ("8 8 6 6 9", "Eights and Sixes"),
-    ("8 8 6 6 7", "Eights and Sixes"),
-    ("8 8 6 6 5", "Eights and Sixes"),
-    ("8 8 6 6 4", "Eights and Sixes"),
-    ("8 8 6 6 3", "Eights and Sixes"),
-    ("8 8 6 6 2", "Eights and Sixes"),
-    ("8 8 5 5 A", "Eights and Fives"),
-    ("8 8 5 5 K", "Eights and Fives"),
-    ("8 8 5 5 Q", "Eights and Fives"),
-    ("8 8 5 5 J", "Eights and Fives"),
-    ("8 8 5 5 T", "Eights and Fives"),
-    ("8 8 5 5 9", "Eights and Fives"),
-    ("8 8 5 5 7", "Eights and Fives"),
-    ("8 8 5 5 6", "Eights and Fives"),
-    ("8 8 5 5 4", "Eights and Fives"),
-    ("8 8 5 5 3", "Eights and Fives"),
-    ("8 8 5 5 2", "Eights and Fives"),
-    ("8 8 4 4 A", "Eights and Fours"),
-    ("8 8 4 4 K", "Eights and Fours"),
-    ("8 8 4 4 Q", "Eights and Fours"),
-    ("8 8 4 4 J", "Eights and Fours"),
-    ("8 8 4 4 T", "Eights and Fours"),
-    ("8 8 4 4 9", "Eights and Fours"),
-    ("8 8 4 4 7", "Eights and Fours"),
-    ("8 8 4 4 6", "Eights and Fours"),
-    ("8 8 4 4 5", "Eights and Fours"),
-    ("8 8 4 4 3", "Eights and Fours"),
-    ("8 8 4 4 2", "Eights and Fours"),
-    ("8 8 3 3 A", "Eights and Treys"),
-    ("8 8 3 3 K", "Eights and Treys"),
-    ("8 8 3 3 Q", "Eights and Treys"),
-    ("8 8 3 3 J", "Eights and Treys"),
-    ("8 8 3 3 T", "Eights and Treys"),
-    ("8 8 3 3 9", "Eights and Treys"),
-    ("8 8 3 3 7", "Eights and Treys"),
-    ("8 8 3 3 6", "Eights and Treys"),
-    ("8 8 3 3 5", "Eights and Treys"),
-    ("8 8 3 3 4", "Eights and Treys"),
-    ("8 8 3 3 2", "Eights and Treys"),
-    ("8 8 2 2 A", "Eights and Deuces"),
-    ("8 8 2 2 K", "Eights and Deuces"),
-    ("8 8 2 2 Q", "Eights and Deuces"),
-    ("8 8 2 2 J", "Eights and Deuces"),
-    ("8 8 2 2 T", "Eights and Deuces"),
-    ("8 8 2 2 9", "Eights and Deuces"),
-    ("8 8 2 2 7", "Eights and Deuces"),
-    ("8 8 2 2 6", "Eights and Deuces"),
-    ("8 8 2 2 5", "Eights and Deuces"),
-    ("8 8 2 2 4", "Eights and Deuces"),
-    ("8 8 2 2 3", "Eights and Deuces"),
-    ("7 7 6 6 A", "Sevens and Sixes"),
-    ("7 7 6 6 K", "Sevens and Sixes"),
-    ("7 7 6 6 Q", "Sevens and Sixes"),
-    ("7 7 6 6 J", "Sevens and Sixes"),
-    ("7 7 6 6 T", "Sevens and Sixes"),
-    ("7 7 6 6 9", "Sevens and Sixes"),
-    ("7 7 6 6 8", "Sevens and Sixes"),
-    ("7 7 6 6 5", "Sevens and Sixes"),
-    ("7 7 6 6 4", "Sevens and Sixes"),
-    ("7 7 6 6 3", "Sevens and Sixes"),
-    ("7 7 6 6 2", "Sevens and Sixes"),
-    ("7 7 5 5 A", "Sevens and Fives"),
-    ("7 7 5 5 K", "Sevens and Fives"),
-    ("7 7 5 5 Q", "Sevens and Fives"),
-    ("7 7 5 5 J", "Sevens and Fives"),
-    ("7 7 5 5 T", "Sevens and Fives"),
-    ("7 7 5 5 9", "Sevens and Fives"),
-    ("7 7 5 5 8", "Sevens and Fives"),
-    ("7 7 5 5 6", "Sevens and Fives"),
-    ("7 7 5 5 4", "Sevens and Fives"),
-    ("7 7 5 5 3", "Sevens and Fives"),
-    ("7 7 5 5 2", "Sevens and Fives"),
-    ("7 7 4 4 A", "Sevens and Fours"),
-    ("7 7 4 4 K", "Sevens and Fours"),
-    ("7 7 4 4 Q", "Sevens and Fours"),
-    ("7 7 4 4 J", "Sevens and Fours"),
-    ("7 7 4 4 T", "Sevens and Fours"),
-    ("7 7 4 4 9", "Sevens and Fours"),
-    ("7 7 4 4 8", "Sevens and Fours"),
-    ("7 7 4 4 6", "Sevens and Fours"),
-    ("7 7 4 4 5", "Sevens and Fours"),
-    ("7 7 4 4 3", "Sevens and Fours"),
-    ("7 7 4 4 2", "Sevens and Fours"),
-    ("7 7 3 3 A", "Sevens and Treys"),
-    ("7 7 3 3 K", "Sevens and Treys"),
-    ("7 7 3 3 Q", "Sevens and Treys"),
-    ("7 7 3 3 J", "Sevens and Treys"),
-    ("7 7 3 3 T", "Sevens and Treys"),
-    ("7 7 3 3 9", "Sevens and Treys"),
-    ("7 7 3 3 8", "Sevens and Treys"),
-    ("7 7 3 3 6", "Sevens and Treys"),
-    ("7 7 3 3 5", "Sevens and Treys"),
-    ("7 7 3 3 4", "Sevens and Treys"),
-    ("7 7 3 3 2", "Sevens and Treys"),
-    ("7 7 2 2 A", "Sevens and Deuces"),
-    ("7 7 2 2 K", "Sevens and Deuces"),
-    ("7 7 2 2 Q", "Sevens and Deuces"),
-    ("7 7 2 2 J", "Sevens and Deuces"),
-    ("7 7 2 2 T", "Sevens and Deuces"),
-    ("7 7 2 2 9", "Sevens and Deuces"),
-    ("7 7 2 2 8", "Sevens and Deuces"),
-    ("7 7 2 2 6", "Sevens and Deuces"),
-    ("7 7 2 2 5", "Sevens and Deuces"),
-    ("7 7 2 2 4", "Sevens and Deuces"),
-    ("7 7 2 2 3", "Sevens and Deuces"),
-    ("6 6 5 5 A", "Sixes and Fives"),
-    ("6 6 5 5 K", "Sixes and Fives"),
-    ("6 6 5 5 Q", "Sixes and Fives"),
-    ("6 6 5 5 J", "Sixes and Fives"),
-    ("6 6 5 5 T", "Sixes and Fives"),
-    ("6 6 5 5 9", "Sixes and Fives"),
-    ("6 6 5 5 8", "Sixes and Fives"),
-    ("6 6 5 5 7", "Sixes and Fives"),
-    ("6 6 5 5 4", "Sixes and Fives"),
-    ("6 6 5 5 3", "Sixes and Fives"),
-    ("6 6 5 5 2", "Sixes and Fives"),
-    ("6 6 4 4 A", "Sixes and Fours"),
-    ("6 6 4 4 K", "Sixes and Fours"),
-    ("6 6 4 4 Q", "Sixes and Fours"),
-    ("6 6 4 4 J", "Sixes and Fours"),
-    ("6 6 4 4 T", "Sixes and Fours"),
-    ("6 6 4 4 9", "Sixes and Fours"),
-    ("6 6 4 4 8", "Sixes and Fours"),
-    ("6 6 4 4 7", "Sixes and Fours"),
-    ("6 6 4 4 5", "Sixes and Fours"),
-    ("6 6 4 4 3", "Sixes and Fours"),
-    ("6 6 4 4 2", "Sixes and Fours"),
-    ("6 6 3 3 A", "Sixes and Treys"),
-    ("6 6 3 3 K", "Sixes and Treys"),
-    ("6 6 3 3 Q", "Sixes and Treys"),
-    ("6 6 3 3 J", "Sixes and Treys"),
-    ("6 6 3 3 T", "Sixes and Treys"),
-    ("6 6 3 3 9", "Sixes and Treys"),
-    ("6 6 3 3 8", "Sixes and Treys"),
-    ("6 6 3 3 7", "Sixes and Treys"),
-    ("6 6 3 3 5", "Sixes and Treys"),
-    ("6 6 3 3 4", "Sixes and Treys"),
-    ("6 6 3 3 2", "Sixes and Treys"),
-    ("6 6 2 2 A", "Sixes and Deuces"),
-    ("6 6 2 2 K", "Sixes and Deuces"),
-    ("6 6 2 2 Q", "Sixes and Deuces"),
-    ("6 6 2 2 J", "Sixes and Deuces"),
-    ("6 6 2 2 T", "Sixes and Deuces"),
-    ("6 6 2 2 9", "Sixes and Deuces"),
-    ("6 6 2 2 8", "Sixes and Deuces"),
-    ("6 6 2 2 7", "Sixes and Deuces"),
-    ("6 6 2 2 5", "Sixes and Deuces"),
-    ("6 6 2 2 4", "Sixes and Deuces"),
-    ("6 6 2 2 3", "Sixes and Deuces"),
-    ("5 5 4 4 A", "Fives and Fours"),
-    ("5 5 4 4 K", "Fives and Fours"),
-    ("5 5 4 4 Q", "Fives and Fours"),
-    ("5 5 4 4 J", "Fives and Fours"),
-    ("5 5 4 4 T", "Fives and Fours"),
-    ("5 5 4 4 9", "Fives and Fours"),
-    ("5 5 4 4 8", "Fives and Fours"),
-    ("5 5 4 4 7", "Fives and Fours"),
-    ("5 5 4 4 6", "Fives and Fours"),
-    ("5 5 4 4 3", "Fives and Fours"),
-    ("5 5 4 4 2", "Fives and Fours"),
-    ("5 5 3 3 A", "Fives and Treys"),
-    ("5 5 3 3 K", "Fives and Treys"),
-    ("5 5 3 3 Q", "Fives and Treys"),
-    ("5 5 3 3 J", "Fives and Treys"),
-    ("5 5 3 3 T", "Fives and Treys"),
-    ("5 5 3 3 9", "Fives and Treys"),
-    ("5 5 3 3 8", "Fives and Treys"),
-    ("5 5 3 3 7", "Fives and Treys"),
-    ("5 5 3 3 6", "Fives and Treys"),
-    ("5 5 3 3 4", "Fives and Treys"),
-    ("5 5 3 3 2", "Fives and Treys"),
-    ("5 5 2 2 A", "Fives and Deuces"),
-    ("5 5 2 2 K", "Fives and Deuces"),
-    ("5 5 2 2 Q", "Fives and Deuces"),
-    ("5 5 2 2 J", "Fives and Deuces"),
-    ("5 5 2 2 T", "Fives and Deuces"),
-    ("5 5 2 2 9", "Fives and Deuces"),
-    ("5 5 2 2 8", "Fives and Deuces"),
-    ("5 5 2 2 7", "Fives and Deuces"),
-    ("5 5 2 2 6", "Fives and Deuces"),
-    ("5 5 2 2 4", "Fives and Deuces"),
-    ("5 5 2 2 3", "Fives and Deuces"),
-    ("4 4 3 3 A", "Fours and Treys"),
-    ("4 4 3 3 K", "Fours and Treys"),
-    ("4 4 3 3 Q", "Fours and Treys"),
-    ("4 4 3 3 J", "Fours and Treys"),
-    ("4 4 3 3 T", "Fours and Treys"),
-    ("4 4 3 3 9", "Fours and Treys"),
-    ("4 4 3 3 8", "Fours and Treys"),
-    ("4 4 3 3 7", "Fours and Treys"),
-    ("4 4 3 3 6", "Fours and Treys"),
-    ("4 4 3 3 5", "Fours and Treys"),
-    ("4 4 3 3 2", "Fours and Treys"),
-    ("4 4 2 2 A", "Fours and Deuces"),
-    ("4 4 2 2 K", "Fours and Deuces"),
-    ("4 4 2 2 Q", "Fours and Deuces"),
-    ("4 4 2 2 J", "Fours and Deuces"),
-    ("4 4 2 2 T", "Fours and Deuces"),
-    ("4 4 2 2 9", "Fours and Deuces"),
-    ("4 4 2 2 8", "Fours and Deuces"),
-    ("4 4 2 2 7", "Fours and Deuces"),
-    ("4 4 2 2 6", "Fours and Deuces"),
-    ("4 4 2 2 5", "Fours and Deuces"),
-    ("4 4 2 2 3", "Fours and Deuces"),
-    ("3 3 2 2 A", "Treys and Deuces"),
-    ("3 3 2 2 K", "Treys and Deuces"),
-    ("3 3 2 2 Q", "Treys and Deuces"),
-    ("3 3 2 2 J", "Treys and Deuces"),
-    ("3 3 2 2 T", "Treys and Deuces"),
-    ("3 3 2 2 9", "Treys and Deuces"),
-    ("3 3 2 2 8", "Treys and Deuces"),
-    ("3 3 2 2 7", "Treys and Deuces"),
-    ("3 3 2 2 6", "Treys and Deuces"),
-    ("3 3 2 2 5", "Treys and Deuces"),
-    ("3 3 2 2 4", "Treys and Deuces"),
-    ("A A K Q J", "Pair of Aces"),
-    ("A A K Q T", "Pair of Aces"),
-    ("A A K Q 9", "Pair of Aces"),
-    ("A A K Q 8", "Pair of Aces"),
-    ("A A K Q 7", "Pair of Aces"),
-    ("A A K Q 6", "Pair of Aces"),
-    ("A A K Q 5", "Pair of Aces"),
-    ("A A K Q 4", "Pair of Aces"),
-    ("A A K Q 3", "Pair of Aces"),
-    ("A A K Q 2", "Pair of Aces"),
-    ("A A K J T", "Pair of Aces"),
-    ("A A K J 9", "Pair of Aces"),
-    ("A A K J 8", "Pair of Aces"),
-    ("A A K J 7", "Pair of Aces"),
-    ("A A K J 6", "Pair of Aces"),
-    ("A A K J 5", "Pair of Aces"),
-    ("A A K J 4", "Pair of Aces"),
-    ("A A K J 3", "Pair of Aces"),
-    ("A A K J 2", "Pair of Aces"),
-    ("A A K T 9", "Pair of Aces"),
-    ("A A K T 8", "Pair of Aces"),
-    ("A A K T 7", "Pair of Aces"),
-    ("A A K T 6", "Pair of Aces"),
-    ("A A K T 5", "Pair of Aces"),
-    ("A A K T 4", "Pair of Aces"),
-    ("A A K T 3", "Pair of Aces"),
-    ("A A K T 2", "Pair of Aces"),
-    ("A A K 9 8", "Pair of Aces"),
-    ("A A K 9 7", "Pair of Aces"),
-    ("A A K 9 6", "Pair of Aces"),
-    ("A A K 9 5", "Pair of Aces"),
-    ("A A K 9 4", "Pair of Aces"),
-    ("A A K 9 3", "Pair of Aces"),
-    ("A A K 9 2", "Pair of Aces"),
-    ("A A K 8 7", "Pair of Aces"),
-    ("A A K 8 6", "Pair of Aces"),
-    ("A A K 8 5", "Pair of Aces"),
-    ("A A K 8 4", "Pair of Aces"),
-    ("A A K 8 3", "Pair of Aces"),
-    ("A A K 8 2", "Pair of Aces"),
-    ("A A K 7 6", "Pair of Aces"),
-    ("A A K 7 5", "Pair of Aces"),
-    ("A A K 7 4", "Pair of Aces"),
-    ("A A K 7 3", "Pair of Aces"),
-    ("A A K 7 2", "Pair of Aces"),
-    ("A A K 6 5", "Pair of Aces"),
-    ("A A K 6 4", "Pair of Aces"),
-    ("A A K 6 3", "Pair of Aces"),
-    ("A A K 6 2", "Pair of Aces"),
-    ("A A K 5 4", "Pair of Aces"),
-    ("A A K 5 3", "Pair of Aces"),
-    ("A A K 5 2", "Pair of Aces"),
-    ("A A K 4 3", "Pair of Aces"),
-    ("A A K 4 2", "Pair of Aces"),
-    ("A A K 3 2", "Pair of Aces"),
-    ("A A Q J T", "Pair of Aces"),
-    ("A A Q J 9", "Pair of Aces"),
-    ("A A Q J 8", "Pair of Aces"),
-    ("A A Q J 7", "Pair of Aces"),
-    ("A A Q J 6", "Pair of Aces"),
-    ("A A Q J 5", "Pair of Aces"),
-    ("A A Q J 4", "Pair of Aces"),
-    ("A A Q J 3", "Pair of Aces"),
-    ("A A Q J 2", "Pair of Aces"),
-    ("A A Q T 9", "Pair of Aces"),
-    ("A A Q T 8", "Pair of Aces"),
-    ("A A Q T 7", "Pair of Aces"),
-    ("A A Q T 6", "Pair of Aces"),
-    ("A A Q T 5", "Pair of Aces"),
-    ("A A Q T 4", "Pair of Aces"),
-    ("A A Q T 3", "Pair of Aces"),
-    ("A A Q T 2", "Pair of Aces"),
-    ("A A Q 9 8", "Pair of Aces"),
-    ("A A Q 9 7", "Pair of Aces"),
-    ("A A Q 9 6", "Pair of Aces"),
-    ("A A Q 9 5", "Pair of Aces"),
-    ("A A Q 9 4", "Pair of Aces"),
-    ("A A Q 9 3", "Pair of Aces"),
-    ("A A Q 9 2", "Pair of Aces"),
-    ("A A Q 8 7", "Pair of Aces"),
-    ("A A Q 8 6", "Pair of Aces"),
-    ("A A Q 8 5", "Pair of Aces"),
-    ("A A Q 8 4", "Pair of Aces"),
-    ("A A Q 8 3", "Pair of Aces"),
-    ("A A Q 8 2", "Pair of Aces"),
-    ("A A Q 7 6", "Pair of Aces"),
-    ("A A Q 7 5", "Pair of Aces"),
-    ("A A Q 7 4", "Pair of Aces"),
-    ("A A Q 7 3", "Pair of Aces"),
-    ("A A Q 7 2", "Pair of Aces"),
-    ("A A Q 6 5", "Pair of Aces"),
-    ("A A Q 6 4", "Pair of Aces"),
-    ("A A Q 6 3", "Pair of Aces"),
-    ("A A Q 6 2", "Pair of Aces"),
-    ("A A Q 5 4", "Pair of Aces"),
-    ("A A Q 5 3", "Pair of Aces"),
-    ("A A Q 5 2", "Pair of Aces"),
-    ("A A Q 4 3", "Pair of Aces"),
-    ("A A Q 4 2", "Pair of Aces"),
-    ("A A Q 3 2", "Pair of Aces"),
-    ("A A J T 9", "Pair of Aces"),
-    ("A A J T 8", "Pair of Aces"),
-    ("A A J T 7", "Pair of Aces"),
-    ("A A J T 6", "Pair of Aces"),
-    ("A A J T 5", "Pair of Aces"),
-    ("A A J T 4", "Pair of Aces"),
-    ("A A J T 3", "Pair of Aces"),
-    ("A A J T 2", "Pair of Aces"),
-    ("A A J 9 8", "Pair of Aces"),
-    ("A A J 9 7", "Pair of Aces"),
-    ("A A J 9 6", "Pair of Aces"),
-    ("A A J 9 5", "Pair of Aces"),
-    ("A A J 9 4", "Pair of Aces"),
-    ("A A J 9 3", "Pair of Aces"),
-    ("A A J 9 2", "Pair of Aces"),
-    ("A A J 8 7", "Pair of Aces"),
-    ("A A J 8 6", "Pair of Aces"),
-    ("A A J 8 5", "Pair of Aces"),
-    ("A A J 8 4", "Pair of Aces"),
-    ("A A J 8 3", "Pair of Aces"),
-    ("A A J 8 2", "Pair of Aces"),
-    ("A A J 7 6", "Pair of Aces"),
-    ("A A J 7 5", "Pair of Aces"),
-    ("A A J 7 4", "Pair of Aces"),
-    ("A A J 7 3", "Pair of Aces"),
-    ("A A J 7 2", "Pair of Aces"),
-    ("A A J 6 5", "Pair of Aces"),
-    ("A A J 6 4", "Pair of Aces"),
-    ("A A J 6 3", "Pair of Aces"),
-    ("A A J 6 2", "Pair of Aces"),
-    ("A A J 5 4", "Pair of Aces"),
-    ("A A J 5 3", "Pair of Aces"),
-    ("A A J 5 2", "Pair of Aces"),
-    ("A A J 4 3", "Pair of Aces"),
-    ("A A J 4 2", "Pair of Aces"),
-    ("A A J 3 2", "Pair of Aces"),
-    ("A A T 9 8", "Pair of Aces"),
-    ("A A T 9 7", "Pair of Aces"),
-    ("A A T 9 6", "Pair of Aces"),
-    ("A A T 9 5", "Pair of Aces"),
-    ("A A T 9 4", "Pair of Aces"),
-    ("A A T 9 3", "Pair of Aces"),
-    ("A A T 9 2", "Pair of Aces"),
-    ("A A T 8 7", "Pair of Aces"),
-    ("A A T 8 6", "Pair of Aces"),
-    ("A A T 8 5", "Pair of Aces"),
-    ("A A T 8 4", "Pair of Aces"),
-    ("A A T 8 3", "Pair of Aces"),
-    ("A A T 8 2", "Pair of Aces"),
-    ("A A T 7 6", "Pair of Aces"),
-    ("A A T 7 5", "Pair of Aces"),
-    ("A A T 7 4", "Pair of Aces"),
-    ("A A T 7 3", "Pair of Aces"),
-    ("A A T 7 2", "Pair of Aces"),
-    ("A A T 6 5", "Pair of Aces"),
-    ("A A T 6 4", "Pair of Aces"),
-    ("A A T 6 3", "Pair of Aces"),
-    ("A A T 6 2", "Pair of Aces"),
-    ("A A T 5 4", "Pair of Aces"),
-    ("A A T 5 3", "Pair of Aces"),
-    ("A A T 5 2", "Pair of Aces"),
-    ("A A T 4 3", "Pair of Aces"),
-    ("A A T 4 2", "Pair of Aces"),
-    ("A A T 3 2", "Pair of Aces"),
-    ("A A 9 8 7", "Pair of Aces"),
-    ("A A 9 8 6", "Pair of Aces"),
-    ("A A 9 8 5", "Pair of Aces"),
-    ("A A 9 8 4", "Pair of Aces"),
-    ("A A 9 8 3", "Pair of Aces"),
-    ("A A 9 8 2", "Pair of Aces"),
-    ("A A 9 7 6", "Pair of Aces"),
-    ("A A 9 7 5", "Pair of Aces"),
-    ("A A 9 7 4", "Pair of Aces"),
-    ("A A 9 7 3", "Pair of Aces"),
-    ("A A 9 7 2", "Pair of Aces"),
-    ("A A 9 6 5", "Pair of Aces"),
-    ("A A 9 6 4", "Pair of Aces"),
-    ("A A 9 6 3", "Pair of Aces"),
-    ("A A 9 6 2", "Pair of Aces"),
-    ("A A 9 5 4", "Pair of Aces"),
-    ("A A 9 5 3", "Pair of Aces"),
-    ("A A 9 5 2", "Pair of Aces"),
-    ("A A 9 4 3", "Pair of Aces"),
-    ("A A 9 4 2", "Pair of Aces"),
-    ("A A 9 3 2", "Pair of Aces"),
-    ("A A 8 7 6", "Pair of Aces"),
-    ("A A 8 7 5", "Pair of Aces"),
-    ("A A 8 7 4", "Pair of Aces"),
-    ("A A 8 7 3", "Pair of Aces"),
-    ("A A 8 7 2", "Pair of Aces"),
-    ("A A 8 6 5", "Pair of Aces"),
-    ("A A 8 6 4", "Pair of Aces"),
-    ("A A 8 6 3", "Pair of Aces"),
-    ("A A 8 6 2", "Pair of Aces"),
-    ("A A 8 5 4", "Pair of Aces"),
-    ("A A 8 5 3", "Pair of Aces"),
-    ("A A 8 5 2", "Pair of Aces"),
-    ("A A 8 4 3", "Pair of Aces"),
-    ("A A 8 4 2", "Pair of Aces"),
-    ("A A 8 3 2", "Pair of Aces"),
-    ("A A 7 6 5", "Pair of Aces"),
-    ("A A 7 6 4", "Pair of Aces"),
-    ("A A 7 6 3", "Pair of Aces"),
-    ("A A 7 6 2", "Pair of Aces"),
-    ("A A 7 5 4", "Pair of Aces"),
-    ("A A 7 5 3", "Pair of Aces"),
-    ("A A 7 5 2", "Pair of Aces"),
-    ("A A 7 4 3", "Pair of Aces"),
-    ("A A 7 4 2", "Pair of Aces"),
-    ("A A 7 3 2", "Pair of Aces"),
-    ("A A 6 5 4", "Pair of Aces"),
-    ("A A 6 5 3", "Pair of Aces"),
-    ("A A 6 5 2", "Pair of Aces"),
-    ("A A 6 4 3", "Pair of Aces"),
-    ("A A 6 4 2", "Pair of Aces"),
-    ("A A 6 3 2", "Pair of Aces"),
-    ("A A 5 4 3", "Pair of Aces"),
-    ("A A 5 4 2", "Pair of Aces"),
-    ("A A 5 3 2", "Pair of Aces"),
-    ("A A 4 3 2", "Pair of Aces"),
-    ("K K A Q J", "Pair of Kings"),
-    ("K K A Q T", "Pair of Kings"),
-    ("K K A Q 9", "Pair of Kings"),
-    ("K K A Q 8", "Pair of Kings"),
-    ("K K A Q 7", "Pair of Kings"),
-    ("K K A Q 6", "Pair of Kings"),
-    ("K K A Q 5", "Pair of Kings"),
-    ("K K A Q 4", "Pair of Kings"),
-    ("K K A Q 3", "Pair of Kings"),
-    ("K K A Q 2", "Pair of Kings"),
-    ("K K A J T", "Pair of Kings"),
-    ("K K A J 9", "Pair of Kings"),
-    ("K K A J 8", "Pair of Kings"),
-    ("K K A J 7", "Pair of Kings"),
-    ("K K A J 6", "Pair of Kings"),
-    ("K K A J 5", "Pair of Kings"),
-    ("K K A J 4", "Pair of Kings"),
-    ("K K A J 3", "Pair of Kings"),
-    ("K K A J 2", "Pair of Kings"),
-    ("K K A T 9", "Pair of Kings"),
-    ("K K A T 8", "Pair of Kings"),
-    ("K K A T 7", "Pair of Kings"),
-    ("K K A T 6", "Pair of Kings"),
-    ("K K A T 5", "Pair of Kings"),
-    ("K K A T 4", "Pair of Kings"),
-    ("K K A T 3", "Pair of Kings"),
-    ("K K A T 2", "Pair of Kings"),
-    ("K K A 9 8", "Pair of Kings"),
-    ("K K A 9 7", "Pair of Kings"),
-    ("K K A 9 6", "Pair of Kings"),
-    ("K K A 9 5", "Pair of Kings"),
-    ("K K A 9 4", "Pair of Kings"),
-    ("K K A 9 3", "Pair of Kings"),
-    ("K K A 9 2", "Pair of Kings"),
-    ("K K A 8 7", "Pair of Kings"),
-    ("K K A 8 6", "Pair of Kings"),
-    ("K K A 8 5", "Pair of Kings"),
-    ("K K A 8 4", "Pair of Kings"),
-    ("K K A 8 3", "Pair of Kings"),
-    ("K K A 8 2", "Pair of Kings"),
-    ("K K A 7 6", "Pair of Kings"),
-    ("K K A 7 5", "Pair of Kings"),
-    ("K K A 7 4", "Pair of Kings"),
-    ("K K A 7 3", "Pair of Kings"),
-    ("K K A 7 2", "Pair of Kings"),
-    ("K K A 6 5", "Pair of Kings"),
-    ("K K A 6 4", "Pair of Kings"),
-    ("K K A 6 3", "Pair of Kings"),
-    ("K K A 6 2", "Pair of Kings"),
-    ("K K A 5 4", "Pair of Kings"),
-    ("K K A 5 3", "Pair of Kings"),
-    ("K K A 5 2", "Pair of Kings"),
-    ("K K A 4 3", "Pair of Kings"),
-    ("K K A 4 2", "Pair of Kings"),
-    ("K K A 3 2", "Pair of Kings"),
-    ("K K Q J T", "Pair of Kings"),
-    ("K K Q J 9", "Pair of Kings"),
-    ("K K Q J 8", "Pair of Kings"),
-    ("K K Q J 7", "Pair of Kings"),
-    ("K K Q J 6", "Pair of Kings"),
-    ("K K Q J 5", "Pair of Kings"),
-    ("K K Q J 4", "Pair of Kings"),
-    ("K K Q J 3", "Pair of Kings"),
-    ("K K Q J 2", "Pair of Kings"),
-    ("K K Q T 9", "Pair of Kings"),
-    ("K K Q T 8", "Pair of Kings"),
-    ("K K Q T 7", "Pair of Kings"),
-    ("K K Q T 6", "Pair of Kings"),
-    ("K K Q T 5", "Pair of Kings"),
-    ("K K Q T 4", "Pair of Kings"),
-    ("K K Q T 3", "Pair of Kings"),
-    ("K K Q T 2", "Pair of Kings"),
-    ("K K Q 9 8", "Pair of Kings"),
-    ("K K Q 9 7", "Pair of Kings"),
-    ("K K Q 9 6", "Pair of Kings"),
-    ("K K Q 9 5", "Pair of Kings"),
-    ("K K Q 9 4", "Pair of Kings"),
-    ("K K Q 9 3", "Pair of Kings"),
-    ("K K Q 9 2", "Pair of Kings"),
-    ("K K Q 8 7", "Pair of Kings"),
-    ("K K Q 8 6", "Pair of Kings"),
-    ("K K Q 8 5", "Pair of Kings"),
-    ("K K Q 8 4", "Pair of Kings"),
-    ("K K Q 8 3", "Pair of Kings"),
-    ("K K Q 8 2", "Pair of Kings"),
-    ("K K Q 7 6", "Pair of Kings"),
-    ("K K Q 7 5", "Pair of Kings"),
-    ("K K Q 7 4", "Pair of Kings"),
-    ("K K Q 7 3", "Pair of Kings"),
-    ("K K Q 7 2", "Pair of Kings"),
-    ("K K Q 6 5", "Pair of Kings"),
-    ("K K Q 6 4", "Pair of Kings"),
-    ("K K Q 6 3", "Pair of Kings"),
-    ("K K Q 6 2", "Pair of Kings"),
-    ("K K Q 5 4", "Pair of Kings"),
-    ("K K Q 5 3", "Pair of Kings"),
-    ("K K Q 5 2", "Pair of Kings"),
-    ("K K Q 4 3", "Pair of Kings"),
-    ("K K Q 4 2", "Pair of Kings"),
-    ("K K Q 3 2", "Pair of Kings"),
-    ("K K J T 9", "Pair of Kings"),
-    ("K K J T 8", "Pair of Kings"),
-    ("K K J T 7", "Pair of Kings"),
-    ("K K J T 6", "Pair of Kings"),
-    ("K K J T 5", "Pair of Kings"),
-    ("K K J T 4", "Pair of Kings"),
-    ("K K J T 3", "Pair of Kings"),
-    ("K K J T 2", "Pair of Kings"),
-    ("K K J 9 8", "Pair of Kings"),
-    ("K K J 9 7", "Pair of Kings"),
-    ("K K J 9 6", "Pair of Kings"),
-    ("K K J 9 5", "Pair of Kings"),
-    ("K K J 9 4", "Pair of Kings"),
-    ("K K J 9 3", "Pair of Kings"),
-    ("K K J 9 2", "Pair of Kings"),
-    ("K K J 8 7", "Pair of Kings"),
-    ("K K J 8 6", "Pair of Kings"),
-    ("K K J 8 5", "Pair of Kings"),
-    ("K K J 8 4", "Pair of Kings"),
-    ("K K J 8 3", "Pair of Kings"),
-    ("K K J 8 2", "Pair of Kings"),
-    ("K K J 7 6", "Pair of Kings"),
-    ("K K J 7 5", "Pair of Kings"),
-    ("K K J 7 4", "Pair of Kings"),
-    ("K K J 7 3", "Pair of Kings"),
-    ("K K J 7 2", "Pair of Kings"),
-    ("K K J 6 5", "Pair of Kings"),
-    ("K K J 6 4", "Pair of Kings"),
-    ("K K J 6 3", "Pair of Kings"),
-    ("K K J 6 2", "Pair of Kings"),
-    ("K K J 5 4", "Pair of Kings"),
-    ("K K J 5 3", "Pair of Kings"),
-    ("K K J 5 2", "Pair of Kings"),
-    ("K K J 4 3", "Pair of Kings"),
-    ("K K J 4 2", "Pair of Kings"),
-    ("K K J 3 2", "Pair of Kings"),
-    ("K K T 9 8", "Pair of Kings"),
-    ("K K T 9 7", "Pair of Kings"),
-    ("K K T 9 6", "Pair of Kings"),
-    ("K K T 9 5", "Pair of Kings"),
-    ("K K T 9 4", "Pair of Kings"),
-    ("K K T 9 3", "Pair of Kings"),
-    ("K K T 9 2", "Pair of Kings"),
-    ("K K T 8 7", "Pair of Kings"),
-    ("K K T 8 6", "Pair of Kings"),
-    ("K K T 8 5", "Pair of Kings"),
-    ("K K T 8 4", "Pair of Kings"),
-    ("K K T 8 3", "Pair of Kings"),
-    ("K K T 8 2", "Pair of Kings"),
-    ("K K T 7 6", "Pair of Kings"),
-    ("K K T 7 5", "Pair of Kings"),
-    ("K K T 7 4", "Pair of Kings"),
-    ("K K T 7 3", "Pair of Kings"),
-    ("K K T 7 2", "Pair of Kings"),
-    ("K K T 6 5", "Pair of Kings"),
-    ("K K T 6 4", "Pair of Kings"),
-    ("K K T 6 3", "Pair of Kings"),
-    ("K K T 6 2", "Pair of Kings"),
-    ("K K T 5 4", "Pair of Kings"),
-    ("K K T 5 3", "Pair of Kings"),
-    ("K K T 5 2", "Pair of Kings"),
-    ("K K T 4 3", "Pair of Kings"),
-    ("K K T 4 2", "Pair of Kings"),
-    ("K K T 3 2", "Pair of Kings"),
-    ("K K 9 8 7", "Pair of Kings"),
-    ("K K 9 8 6", "Pair of Kings"),
-    ("K K 9 8 5", "Pair of Kings"),
-    ("K K 9 8 4", "Pair of Kings"),
-    ("K K 9 8 3", "Pair of Kings"),
-    ("K K 9 8 2", "Pair of Kings"),
-    ("K K 9 7 6", "Pair of Kings"),
-    ("K K 9 7 5", "Pair of Kings"),
-    ("K K 9 7 4", "Pair of Kings"),
-    ("K K 9 7 3", "Pair of Kings"),
-    ("K K 9 7 2", "Pair of Kings"),
-    ("K K 9 6 5", "Pair of Kings"),
-    ("K K 9 6 4", "Pair of Kings"),
-    ("K K 9 6 3", "Pair of Kings"),
-    ("K K 9 6 2", "Pair of Kings"),
-    ("K K 9 5 4", "Pair of Kings"),
-    ("K K 9 5 3", "Pair of Kings"),
-    ("K K 9 5 2", "Pair of Kings"),
-    ("K K 9 4 3", "Pair of Kings"),
-    ("K K 9 4 2", "Pair of Kings"),
-    ("K K 9 3 2", "Pair of Kings"),
-    ("K K 8 7 6", "Pair of Kings"),
-    ("K K 8 7 5", "Pair of Kings"),
-    ("K K 8 7 4", "Pair of Kings"),
-    ("K K 8 7 3", "Pair of Kings"),
-    ("K K 8 7 2", "Pair of Kings"),
-    ("K K 8 6 5", "Pair of Kings"),
-    ("K K 8 6 4", "Pair of Kings"),
-    ("K K 8 6 3", "Pair of Kings"),
-    ("K K 8 6 2", "Pair of Kings"),
-    ("K K 8 5 4", "Pair of Kings"),
-    ("K K 8 5 3", "Pair of Kings"),
-    ("K K 8 5 2", "Pair of Kings"),
-    ("K K 8 4 3", "Pair of Kings"),
-    ("K K 8 4 2", "Pair of Kings"),
-    ("K K 8 3 2", "Pair of Kings"),
-    ("K K 7 6 5", "Pair of Kings"),
-    ("K K 7 6 4", "Pair of Kings"),
-    ("K K 7 6 3", "Pair of Kings"),
-    ("K K 7 6 2", "Pair of Kings"),
-    ("K K 7 5 4", "Pair of Kings"),
-    ("K K 7 5 3", "Pair of Kings"),
-    ("K K 7 5 2", "Pair of Kings"),
-    ("K K 7 4 3", "Pair of Kings"),
-    ("K K 7 4 2", "Pair of Kings"),
-    ("K K 7 3 2", "Pair of Kings"),
-    ("K K 6 5 4", "Pair of Kings"),
-    ("K K 6 5 3", "Pair of Kings"),
-    ("K K 6 5 2", "Pair of Kings"),
-    ("K K 6 4 3", "Pair of Kings"),
-    ("K K 6 4 2", "Pair of Kings"),
-    ("K K 6 3 2", "Pair of Kings"),
-    ("K K 5 4 3", "Pair of Kings"),
-    ("K K 5 4 2", "Pair of Kings"),
-    ("K K 5 3 2", "Pair of Kings"),
-    ("K K 4 3 2", "Pair of Kings"),
-    ("Q Q A K J", "Pair of Queens"),
-    ("Q Q A K T", "Pair of Queens"),
-    ("Q Q A K 9", "Pair of Queens"),
-    ("Q Q A K 8", "Pair of Queens"),
-    ("Q Q A K 7", "Pair of Queens"),
-    ("Q Q A K 6", "Pair of Queens"),
-    ("Q Q A K 5", "Pair of Queens"),
-    ("Q Q A K 4", "Pair of Queens"),
-    ("Q Q A K 3", "Pair of Queens"),
-    ("Q Q A K 2", "Pair of Queens"),
-    ("Q Q A J T", "Pair of Queens"),
-    ("Q Q A J 9", "Pair of Queens"),
-    ("Q Q A J 8", "Pair of Queens"),
-    ("Q Q A J 7", "Pair of Queens"),
-    ("Q Q A J 6", "Pair of Queens"),
-    ("Q Q A J 5", "Pair of Queens"),
-    ("Q Q A J 4", "Pair of Queens"),
-    ("Q Q A J 3", "Pair of Queens"),
-    ("Q Q A J 2", "Pair of Queens"),
-    ("Q Q A T 9", "Pair of Queens"),
-    ("Q Q A T 8", "Pair of Queens"),
-    ("Q Q A T 7", "Pair of Queens"),
-    ("Q Q A T 6", "Pair of Queens"),
-    ("Q Q A T 5", "Pair of Queens"),
-    ("Q Q A T 4", "Pair of Queens"),
-    ("Q Q A T 3", "Pair of Queens"),
-    ("Q Q A T 2", "Pair of Queens"),
-    ("Q Q A 9 8", "Pair of Queens"),
-    ("Q Q A 9 7", "Pair of Queens"),
-    ("Q Q A 9 6", "Pair of Queens"),
-    ("Q Q A 9 5", "Pair of Queens"),
-    ("Q Q A 9 4", "Pair of Queens"),
-    ("Q Q A 9 3", "Pair of Queens"),
-    ("Q Q A 9 2", "Pair of Queens"),
-    ("Q Q A 8 7", "Pair of Queens"),
-    ("Q Q A 8 6", "Pair of Queens"),
-    ("Q Q A 8 5", "Pair of Queens"),
-    ("Q Q A 8 4", "Pair of Queens"),
-    ("Q Q A 8 3", "Pair of Queens"),
-    ("Q Q A 8 2", "Pair of Queens"),
-    ("Q Q A 7 6", "Pair of Queens"),
-    ("Q Q A 7 5", "Pair of Queens"),
-    ("Q Q A 7 4", "Pair of Queens"),
-    ("Q Q A 7 3", "Pair of Queens"),
-    ("Q Q A 7 2", "Pair of Queens"),
-    ("Q Q A 6 5", "Pair of Queens"),
-    ("Q Q A 6 4", "Pair of Queens"),
-    ("Q Q A 6 3", "Pair of Queens"),
-    ("Q Q A 6 2", "Pair of Queens"),
-    ("Q Q A 5 4", "Pair of Queens"),
-    ("Q Q A 5 3", "Pair of Queens"),
-    ("Q Q A 5 2", "Pair of Queens"),
-    ("Q Q A 4 3", "Pair of Queens"),
-    ("Q Q A 4 2", "Pair of Queens"),
-    ("Q Q A 3 2", "Pair of Queens"),
-    ("Q Q K J T", "Pair of Queens"),
-    ("Q Q K J 9", "Pair of Queens"),
-    ("Q Q K J 8", "Pair of Queens"),
-    ("Q Q K J 7", "Pair of Queens"),
-    ("Q Q K J 6", "Pair of Queens"),
-    ("Q Q K J 5", "Pair of Queens"),
-    ("Q Q K J 4", "Pair of Queens"),
-    ("Q Q K J 3", "Pair of Queens"),
-    ("Q Q K J 2", "Pair of Queens"),
-    ("Q Q K T 9", "Pair of Queens"),
-    ("Q Q K T 8", "Pair of Queens"),
-    ("Q Q K T 7", "Pair of Queens"),
-    ("Q Q K T 6", "Pair of Queens"),
-    ("Q Q K T 5", "Pair of Queens"),
-    ("Q Q K T 4", "Pair of Queens"),
-    ("Q Q K T 3", "Pair of Queens"),
-    ("Q Q K T 2", "Pair of Queens"),
-    ("Q Q K 9 8", "Pair of Queens"),
-    ("Q Q K 9 7", "Pair of Queens"),
-    ("Q Q K 9 6", "Pair of Queens"),
-    ("Q Q K 9 5", "Pair of Queens"),
-    ("Q Q K 9 4", "Pair of Queens"),
-    ("Q Q K 9 3", "Pair of Queens"),
-    ("Q Q K 9 2", "Pair of Queens"),
-    ("Q Q K 8 7", "Pair of Queens"),
-    ("Q Q K 8 6", "Pair of Queens"),
-    ("Q Q K 8 5", "Pair of Queens"),
-    ("Q Q K 8 4", "Pair of Queens"),
-    ("Q Q K 8 3", "Pair of Queens"),
-    ("Q Q K 8 2", "Pair of Queens"),
-    ("Q Q K 7 6", "Pair of Queens"),
-    ("Q Q K 7 5", "Pair of Queens"),
-    ("Q Q K 7 4", "Pair of Queens"),
-    ("Q Q K 7 3", "Pair of Queens"),
-    ("Q Q K 7 2", "Pair of Queens"),
-    ("Q Q K 6 5", "Pair of Queens"),
-    ("Q Q K 6 4", "Pair of Queens"),
-    ("Q Q K 6 3", "Pair of Queens"),
-    ("Q Q K 6 2", "Pair of Queens"),
-    ("Q Q K 5 4", "Pair of Queens"),
-    ("Q Q K 5 3", "Pair of Queens"),
-    ("Q Q K 5 2", "Pair of Queens"),
-    ("Q Q K 4 3", "Pair of Queens"),
-    ("Q Q K 4 2", "Pair of Queens"),
-    ("Q Q K 3 2", "Pair of Queens"),
-    ("Q Q J T 9", "Pair of Queens"),
-    ("Q Q J T 8", "Pair of Queens"),
-    ("Q Q J T 7", "Pair of Queens"),
-    ("Q Q J T 6", "Pair of Queens"),
-    ("Q Q J T 5", "Pair of Queens"),
-    ("Q Q J T 4", "Pair of Queens"),
-    ("Q Q J T 3", "Pair of Queens"),
-    ("Q Q J T 2", "Pair of Queens"),
-    ("Q Q J 9 8", "Pair of Queens"),
-    ("Q Q J 9 7", "Pair of Queens"),
-    ("Q Q J 9 6", "Pair of Queens"),
-    ("Q Q J 9 5", "Pair of Queens"),
-    ("Q Q J 9 4", "Pair of Queens"),
-    ("Q Q J 9 3", "Pair of Queens"),
-    ("Q Q J 9 2", "Pair of Queens"),
-    ("Q Q J 8 7", "Pair of Queens"),
-    ("Q Q J 8 6", "Pair of Queens"),
-    ("Q Q J 8 5", "Pair of Queens"),
-    ("Q Q J 8 4", "Pair of Queens"),
-    ("Q Q J 8 3", "Pair of Queens"),
-    ("Q Q J 8 2", "Pair of Queens"),
-    ("Q Q J 7 6", "Pair of Queens"),
-    ("Q Q J 7 5", "Pair of Queens"),
-    ("Q Q J 7 4", "Pair of Queens"),
-    ("Q Q J 7 3", "Pair of Queens"),
-    ("Q Q J 7 2", "Pair of Queens"),
-    ("Q Q J 6 5", "Pair of Queens"),
-    ("Q Q J 6 4", "Pair of Queens"),
-    ("Q Q J 6 3", "Pair of Queens"),
-    ("Q Q J 6 2", "Pair of Queens"),
-    ("Q Q J 5 4", "Pair of Queens"),
-    ("Q Q J 5 3", "Pair of Queens"),
-    ("Q Q J 5 2", "Pair of Queens"),
-    ("Q Q J 4 3", "Pair of Queens"),
-    ("Q Q J 4 2", "Pair of Queens"),
-    ("Q Q J 3 2", "Pair of Queens"),
-    ("Q Q T 9 8", "Pair of Queens"),
-    ("Q Q T 9 7", "Pair of Queens"),
-    ("Q Q T 9 6", "Pair of Queens"),
-    ("Q Q T 9 5", "Pair of Queens"),
-    ("Q Q T 9 4", "Pair of Queens"),
-    ("Q Q T 9 3", "Pair of Queens"),
-    ("Q Q T 9 2", "Pair of Queens"),
-    ("Q Q T 8 7", "Pair of Queens"),
-    ("Q Q T 8 6", "Pair of Queens"),
-    ("Q Q T 8 5", "Pair of Queens"),
-    ("Q Q T 8 4", "Pair of Queens"),
-    ("Q Q T 8 3", "Pair of Queens"),
-    ("Q Q T 8 2", "Pair of Queens"),
-    ("Q Q T 7 6", "Pair of Queens"),
-    ("Q Q T 7 5", "Pair of Queens"),
-    ("Q Q T 7 4", "Pair of Queens"),
-    ("Q Q T 7 3", "Pair of Queens"),
-    ("Q Q T 7 2", "Pair of Queens"),
-    ("Q Q T 6 5", "Pair of Queens"),
-    ("Q Q T 6 4", "Pair of Queens"),
-    ("Q Q T 6 3", "Pair of Queens"),
-    ("Q Q T 6 2", "Pair of Queens"),
-    ("Q Q T 5 4", "Pair of Queens"),
-    ("Q Q T 5 3", "Pair of Queens"),
-    ("Q Q T 5 2", "Pair of Queens"),
-    ("Q Q T 4 3", "Pair of Queens"),
-    ("Q Q T 4 2", "Pair of Queens"),
-    ("Q Q T 3 2", "Pair of Queens"),
-    ("Q Q 9 8 7", "Pair of Queens"),
-    ("Q Q 9 8 6", "Pair of Queens"),
-    ("Q Q 9 8 5", "Pair of Queens"),
-    ("Q Q 9 8 4", "Pair of Queens"),
-    ("Q Q 9 8 3", "Pair of Queens"),
-    ("Q Q 9 8 2", "Pair of Queens"),
-    ("Q Q 9 7 6", "Pair of Queens"),
-    ("Q Q 9 7 5", "Pair of Queens"),
-    ("Q Q 9 7 4", "Pair of Queens"),
-    ("Q Q 9 7 3", "Pair of Queens"),
-    ("Q Q 9 7 2", "Pair of Queens"),
-    ("Q Q 9 6 5", "Pair of Queens"),
-    ("Q Q 9 6 4", "Pair of Queens"),
-    ("Q Q 9 6 3", "Pair of Queens"),
-    ("Q Q 9 6 2", "Pair of Queens"),
-    ("Q Q 9 5 4", "Pair of Queens"),
-    ("Q Q 9 5 3", "Pair of Queens"),
-    ("Q Q 9 5 2", "Pair of Queens"),
-    ("Q Q 9 4 3", "Pair of Queens"),
-    ("Q Q 9 4 2", "Pair of Queens"),
-    ("Q Q 9 3 2", "Pair of Queens"),
-    ("Q Q 8 7 6", "Pair of Queens"),
-    ("Q Q 8 7 5", "Pair of Queens"),
-    ("Q Q 8 7 4", "Pair of Queens"),
-    ("Q Q 8 7 3", "Pair of Queens"),
-    ("Q Q 8 7 2", "Pair of Queens"),
-    ("Q Q 8 6 5", "Pair of Queens"),
-    ("Q Q 8 6 4", "Pair of Queens"),
-    ("Q Q 8 6 3", "Pair of Queens"),
-    ("Q Q 8 6 2", "Pair of Queens"),
-    ("Q Q 8 5 4", "Pair of Queens"),
-    ("Q Q 8 5 3", "Pair of Queens"),
-    ("Q Q 8 5 2", "Pair of Queens"),
-    ("Q Q 8 4 3", "Pair of Queens"),
-    ("Q Q 8 4 2", "Pair of Queens"),
-    ("Q Q 8 3 2", "Pair of Queens"),
-    ("Q Q 7 6 5", "Pair of Queens"),
-    ("Q Q 7 6 4", "Pair of Queens"),
-    ("Q Q 7 6 3", "Pair of Queens"),
-    ("Q Q 7 6 2", "Pair of Queens"),
-    ("Q Q 7 5 4", "Pair of Queens"),
-    ("Q Q 7 5 3", "Pair of Queens"),
-    ("Q Q 7 5 2", "Pair of Queens"),
-    ("Q Q 7 4 3", "Pair of Queens"),
-    ("Q Q 7 4 2", "Pair of Queens"),
-    ("Q Q 7 3 2", "Pair of Queens"),
-    ("Q Q 6 5 4", "Pair of Queens"),
-    ("Q Q 6 5 3", "Pair of Queens"),
-    ("Q Q 6 5 2", "Pair of Queens"),
-    ("Q Q 6 4 3", "Pair of Queens"),
-    ("Q Q 6 4 2", "Pair of Queens"),
-    ("Q Q 6 3 2", "Pair of Queens"),
-    ("Q Q 5 4 3", "Pair of Queens"),
-    ("Q Q 5 4 2", "Pair of Queens"),
-    ("Q Q 5 3 2", "Pair of Queens"),
-    ("Q Q 4 3 2", "Pair of Queens"),
-    ("J J A K Q", "Pair of Jacks"),
-    ("J J A K T", "Pair of Jacks"),
-    ("J J A K 9", "Pair of Jacks"),
-    ("J J A K 8", "Pair of Jacks"),
-    ("J J A K 7", "Pair of Jacks"),
-    ("J J A K 6", "Pair of Jacks"),
-    ("J J A K 5", "Pair of Jacks"),
-    ("J J A K 4", "Pair of Jacks"),
-    ("J J A K 3", "Pair of Jacks"),
-    ("J J A K 2", "Pair of Jacks"),
-    ("J J A Q T", "Pair of Jacks"),
-    ("J J A Q 9", "Pair of Jacks"),
-    ("J J A Q 8", "Pair of Jacks"),
-    ("J J A Q 7", "Pair of Jacks"),
-    ("J J A Q 6", "Pair of Jacks"),
-    ("J J A Q 5", "Pair of Jacks"),
-    ("J J A Q 4", "Pair of Jacks"),
-    ("J J A Q 3", "Pair of Jacks"),
-    ("J J A Q 2", "Pair of Jacks"),
-    ("J J A T 9", "Pair of Jacks"),
-    ("J J A T 8", "Pair of Jacks"),
-    ("J J A T 7", "Pair of Jacks"),
-    ("J J A T 6", "Pair of Jacks"),
-    ("J J A T 5", "Pair of Jacks"),
-    ("J J A T 4", "Pair of Jacks"),
-    ("J J A T 3", "Pair of Jacks"),
-    ("J J A T 2", "Pair of Jacks"),
-    ("J J A 9 8", "Pair of Jacks"),
-    ("J J A 9 7", "Pair of Jacks"),
-    ("J J A 9 6", "Pair of Jacks"),
-    ("J J A 9 5", "Pair of Jacks"),
-    ("J J A 9 4", "Pair of Jacks"),
-    ("J J A 9 3", "Pair of Jacks"),
-    ("J J A 9 2", "Pair of Jacks"),
-    ("J J A 8 7", "Pair of Jacks"),
-    ("J J A 8 6", "Pair of Jacks"),
-    ("J J A 8 5", "Pair of Jacks"),
-    ("J J A 8 4", "Pair of Jacks"),
-    ("J J A 8 3", "Pair of Jacks"),
-    ("J J A 8 2", "Pair of Jacks"),
-    ("J J A 7 6", "Pair of Jacks"),
-    ("J J A 7 5", "Pair of Jacks"),
-    ("J J A 7 4", "Pair of Jacks"),
-    ("J J A 7 3", "Pair of Jacks"),
-    ("J J A 7 2", "Pair of Jacks"),
-    ("J J A 6 5", "Pair of Jacks"),
-    ("J J A 6 4", "Pair of Jacks"),
-    ("J J A 6 3", "Pair of Jacks"),
-    ("J J A 6 2", "Pair of Jacks"),
-    ("J J A 5 4", "Pair of Jacks"),
-    ("J J A 5 3", "Pair of Jacks"),
-    ("J J A 5 2", "Pair of Jacks"),
-    ("J J A 4 3", "Pair of Jacks"),
-    ("J J A 4 2", "Pair of Jacks"),
-    ("J J A 3 2", "Pair of Jacks"),
-    ("J J K Q T", "Pair of Jacks"),
-    ("J J K Q 9", "Pair of Jacks"),
-    ("J J K Q 8", "Pair of Jacks"),
-    ("J J K Q 7", "Pair of Jacks"),
-    ("J J K Q 6", "Pair of Jacks"),
-    ("J J K Q 5", "Pair of Jacks"),
-    ("J J K Q 4", "Pair of Jacks"),
-    ("J J K Q 3", "Pair of Jacks"),
-    ("J J K Q 2", "Pair of Jacks"),
-    ("J J K T 9", "Pair of Jacks"),
-    ("J J K T 8", "Pair of Jacks"),
-    ("J J K T 7", "Pair of Jacks"),
-    ("J J K T 6", "Pair of Jacks"),
-    ("J J K T 5", "Pair of Jacks"),
-    ("J J K T 4", "Pair of Jacks"),
-    ("J J K T 3", "Pair of Jacks"),
-    ("J J K T 2", "Pair of Jacks"),
-    ("J J K 9 8", "Pair of Jacks"),
-    ("J J K 9 7", "Pair of Jacks"),
-    ("J J K 9 6", "Pair of Jacks"),
-    ("J J K 9 5", "Pair of Jacks"),
-    ("J J K 9 4", "Pair of Jacks"),
-    ("J J K 9 3", "Pair of Jacks"),
-    ("J J K 9 2", "Pair of Jacks"),
-    ("J J K 8 7", "Pair of Jacks"),
-    ("J J K 8 6", "Pair of Jacks"),
-    ("J J K 8 5", "Pair of Jacks"),
-    ("J J K 8 4", "Pair of Jacks"),
-    ("J J K 8 3", "Pair of Jacks"),
-    ("J J K 8 2", "Pair of Jacks"),
-    ("J J K 7 6", "Pair of Jacks"),
-    ("J J K 7 5", "Pair of Jacks"),
-    ("J J K 7 4", "Pair of Jacks"),
-    ("J J K 7 3", "Pair of Jacks"),
-    ("J J K 7 2", "Pair of Jacks"),
-    ("J J K 6 5", "Pair of Jacks"),
-    ("J J K 6 4", "Pair of Jacks"),
-    ("J J K 6 3", "Pair of Jacks"),
-    ("J J K 6 2", "Pair of Jacks"),
-    ("J J K 5 4", "Pair of Jacks"),
-    ("J J K 5 3", "Pair of Jacks"),
-    ("J J K 5 2", "Pair of Jacks"),
-    ("J J K 4 3", "Pair of Jacks"),
-    ("J J K 4 2", "Pair of Jacks"),
-    ("J J K 3 2", "Pair of Jacks"),
-    ("J J Q T 9", "Pair of Jacks"),
-    ("J J Q T 8", "Pair of Jacks"),
-    ("J J Q T 7", "Pair of Jacks"),
-    ("J J Q T 6", "Pair of Jacks"),
-    ("J J Q T 5", "Pair of Jacks"),
-    ("J J Q T 4", "Pair of Jacks"),
-    ("J J Q T 3", "Pair of Jacks"),
-    ("J J Q T 2", "Pair of Jacks"),
-    ("J J Q 9 8", "Pair of Jacks"),
-    ("J J Q 9 7", "Pair of Jacks"),
-    ("J J Q 9 6", "Pair of Jacks"),
-    ("J J Q 9 5", "Pair of Jacks"),
-    ("J J Q 9 4", "Pair of Jacks"),
-    ("J J Q 9 3", "Pair of Jacks"),
-    ("J J Q 9 2", "Pair of Jacks"),
-    ("J J Q 8 7", "Pair of Jacks"),
-    ("J J Q 8 6", "Pair of Jacks"),
-    ("J J Q 8 5", "Pair of Jacks"),
-    ("J J Q 8 4", "Pair of Jacks"),
-    ("J J Q 8 3", "Pair of Jacks"),
-    ("J J Q 8 2", "Pair of Jacks"),
-    ("J J Q 7 6", "Pair of Jacks"),
-    ("J J Q 7 5", "Pair of Jacks"),
-    ("J J Q 7 4", "Pair of Jacks"),
-    ("J J Q 7 3", "Pair of Jacks"),
-    ("J J Q 7 2", "Pair of Jacks"),
-    ("J J Q 6 5", "Pair of Jacks"),
-    ("J J Q 6 4", "Pair of Jacks"),
-    ("J J Q 6 3", "Pair of Jacks"),
-    ("J J Q 6 2", "Pair of Jacks"),
-    ("J J Q 5 4", "Pair of Jacks"),
-    ("J J Q 5 3", "Pair of Jacks"),
-    ("J J Q 5 2", "Pair of Jacks"),
-    ("J J Q 4 3", "Pair of Jacks"),
-    ("J J Q 4 2", "Pair of Jacks"),
-    ("J J Q 3 2", "Pair of Jacks"),
-    ("J J T 9 8", "Pair of Jacks"),
-    ("J J T 9 7", "Pair of Jacks"),
-    ("J J T 9 6", "Pair of Jacks"),
-    ("J J T 9 5", "Pair of Jacks"),
-    ("J J T 9 4", "Pair of Jacks"),
-    ("J J T 9 3", "Pair of Jacks"),
-    ("J J T 9 2", "Pair of Jacks"),
-    ("J J T 8 7", "Pair of Jacks"),
-    ("J J T 8 6", "Pair of Jacks"),
-    ("J J T 8 5", "Pair of Jacks"),
-    ("J J T 8 4", "Pair of Jacks"),
-    ("J J T 8 3", "Pair of Jacks"),
-    ("J J T 8 2", "Pair of Jacks"),
-    ("J J T 7 6", "Pair of Jacks"),
-    ("J J T 7 5", "Pair of Jacks"),
-    ("J J T 7 4", "Pair of Jacks"),
-    ("J J T 7 3", "Pair of Jacks"),
-    ("J J T 7 2", "Pair of Jacks"),
-    ("J J T 6 5", "Pair of Jacks"),
-    ("J J T 6 4", "Pair of Jacks"),
-    ("J J T 6 3", "Pair of Jacks"),
-    ("J J T 6 2", "Pair of Jacks"),
-    ("J J T 5 4", "Pair of Jacks"),
-    ("J J T 5 3", "Pair of Jacks"),
-    ("J J T 5 2", "Pair of Jacks"),
-    ("J J T 4 3", "Pair of Jacks"),
-    ("J J T 4 2", "Pair of Jacks"),
-    ("J J T 3 2", "Pair of Jacks"),
-    ("J J 9 8 7", "Pair of Jacks"),
-    ("J J 9 8 6", "Pair of Jacks"),
-    ("J J 9 8 5", "Pair of Jacks"),
-    ("J J 9 8 4", "Pair of Jacks"),
-    ("J J 9 8 3", "Pair of Jacks"),
-    ("J J 9 8 2", "Pair of Jacks"),
-    ("J J 9 7 6", "Pair of Jacks"),
-    ("J J 9 7 5", "Pair of Jacks"),
-    ("J J 9 7 4", "Pair of Jacks"),
-    ("J J 9 7 3", "Pair of Jacks"),
-    ("J J 9 7 2", "Pair of Jacks"),
-    ("J J 9 6 5", "Pair of Jacks"),
-    ("J J 9 6 4", "Pair of Jacks"),
-    ("J J 9 6 3", "Pair of Jacks"),
-    ("J J 9 6 2", "Pair of Jacks"),
-    ("J J 9 5 4", "Pair of Jacks"),
-    ("J J 9 5 3", "Pair of Jacks"),
-    ("J J 9 5 2", "Pair of Jacks"),
-    ("J J 9 4 3", "Pair of Jacks"),
-    ("J J 9 4 2", "Pair of Jacks"),
-    ("J J 9 3 2", "Pair of Jacks"),
-    ("J J 8 7 6", "Pair of Jacks"),
-    ("J J 8 7 5", "Pair of Jacks"),
-    ("J J 8 7 4", "Pair of Jacks"),
-    ("J J 8 7 3", "Pair of Jacks"),
-    ("J J 8 7 2", "Pair of Jacks"),
-    ("J J 8 6 5", "Pair of Jacks"),
-    ("J J 8 6 4", "Pair of Jacks"),
-    ("J J 8 6 3", "Pair of Jacks"),
-    ("J J 8 6 2", "Pair of Jacks"),
-    ("J J 8 5 4", "Pair of Jacks"),
-    ("J J 8 5 3", "Pair of Jacks"),
-    ("J J 8 5 2", "Pair of Jacks"),
-    ("J J 8 4 3", "Pair of Jacks"),
-    ("J J 8 4 2", "Pair of Jacks"),
-    ("J J 8 3 2", "Pair of Jacks"),
-    ("J J 7 6 5", "Pair of Jacks"),
-    ("J J 7 6 4", "Pair of Jacks"),
-    ("J J 7 6 3", "Pair of Jacks"),
-    ("J J 7 6 2", "Pair of Jacks"),
-    ("J J 7 5 4", "Pair of Jacks"),
-    ("J J 7 5 3", "Pair of Jacks"),
-    ("J J 7 5 2", "Pair of Jacks"),
-    ("J J 7 4 3", "Pair of Jacks"),
-    ("J J 7 4 2", "Pair of Jacks"),
-    ("J J 7 3 2", "Pair of Jacks"),
-    ("J J 6 5 4", "Pair of Jacks"),
-    ("J J 6 5 3", "Pair of Jacks"),
-    ("J J 6 5 2", "Pair of Jacks"),
-    ("J J 6 4 3", "Pair of Jacks"),
-    ("J J 6 4 2", "Pair of Jacks"),
-    ("J J 6 3 2", "Pair of Jacks"),
-    ("J J 5 4 3", "Pair of Jacks"),
-    ("J J 5 4 2", "Pair of Jacks"),
-    ("J J 5 3 2", "Pair of Jacks"),
-    ("J J 4 3 2", "Pair of Jacks"),
-    ("T T A K Q", "Pair of Tens"),
-    ("T T A K J", "Pair of Tens"),
-    ("T T A K 9", "Pair of Tens"),
-    ("T T A K 8", "Pair of Tens"),
-    ("T T A K 7", "Pair of Tens"),
-    ("T T A K 6", "Pair of Tens"),
-    ("T T A K 5", "Pair of Tens"),
-    ("T T A K 4", "Pair of Tens"),
-    ("T T A K 3", "Pair of Tens"),
-    ("T T A K 2", "Pair of Tens"),
-    ("T T A Q J", "Pair of Tens"),
-    ("T T A Q 9", "Pair of Tens"),
-    ("T T A Q 8", "Pair of Tens"),
-    ("T T A Q 7", "Pair of Tens"),
-    ("T T A Q 6", "Pair of Tens"),
-    ("T T A Q 5", "Pair of Tens"),
-    ("T T A Q 4", "Pair of Tens"),
-    ("T T A Q 3", "Pair of Tens"),
-    ("T T A Q 2", "Pair of Tens"),
-    ("T T A J 9", "Pair of Tens"),
-    ("T T A J 8", "Pair of Tens"),
-    ("T T A J 7", "Pair of Tens"),
-    ("T T A J 6", "Pair of Tens"),
-    ("T T A J 5", "Pair of Tens"),
-    ("T T A J 4", "Pair of Tens"),
-    ("T T A J 3", "Pair of Tens"),
-    ("T T A J 2", "Pair of Tens"),
-    ("T T A 9 8", "Pair of Tens"),
-    ("T T A 9 7", "Pair of Tens"),
-    ("T T A 9 6", "Pair of Tens"),
-    ("T T A 9 5", "Pair of Tens"),
-    ("T T A 9 4", "Pair of Tens"),
-    ("T T A 9 3", "Pair of Tens"),
-    ("T T A 9 2", "Pair of Tens"),
-    ("T T A 8 7", "Pair of Tens"),
-    ("T T A 8 6", "Pair of Tens"),
-    ("T T A 8 5", "Pair of Tens"),
-    ("T T A 8 4", "Pair of Tens"),
-    ("T T A 8 3", "Pair of Tens"),
-    ("T T A 8 2", "Pair of Tens"),
-    ("T T A 7 6", "Pair of Tens"),
-    ("T T A 7 5", "Pair of Tens"),
-    ("T T A 7 4", "Pair of Tens"),
-    ("T T A 7 3", "Pair of Tens"),
-    ("T T A 7 2", "Pair of Tens"),
-    ("T T A 6 5", "Pair of Tens"),
-    ("T T A 6 4", "Pair of Tens"),
-    ("T T A 6 3", "Pair of Tens"),
-    ("T T A 6 2", "Pair of Tens"),
-    ("T T A 5 4", "Pair of Tens"),
-    ("T T A 5 3", "Pair of Tens"),
-    ("T T A 5 2", "Pair of Tens"),
-    ("T T A 4 3", "Pair of Tens"),
-    ("T T A 4 2", "Pair of Tens"),
-    ("T T A 3 2", "Pair of Tens"),
-    ("T T K Q J", "Pair of Tens"),
-    ("T T K Q 9", "Pair of Tens"),
-    ("T T K Q 8", "Pair of Tens"),
-    ("T T K Q 7", "Pair of Tens"),
-    ("T T K Q 6", "Pair of Tens"),
-    ("T T K Q 5", "Pair of Tens"),
-    ("T T K Q 4", "Pair of Tens"),
-    ("T T K Q 3", "Pair of Tens"),
-    ("T T K Q 2", "Pair of Tens"),
-    ("T T K J 9", "Pair of Tens"),
-    ("T T K J 8", "Pair of Tens"),
-    ("T T K J 7", "Pair of Tens"),
-    ("T T K J 6", "Pair of Tens"),
-    ("T T K J 5", "Pair of Tens"),
-    ("T T K J 4", "Pair of Tens"),
-    ("T T K J 3", "Pair of Tens"),
-    ("T T K J 2", "Pair of Tens"),
-    ("T T K 9 8", "Pair of Tens"),
-    ("T T K 9 7", "Pair of Tens"),
-    ("T T K 9 6", "Pair of Tens"),
-    ("T T K 9 5", "Pair of Tens"),
-    ("T T K 9 4", "Pair of Tens"),
-    ("T T K 9 3", "Pair of Tens"),
-    ("T T K 9 2", "Pair of Tens"),
-    ("T T K 8 7", "Pair of Tens"),
-    ("T T K 8 6", "Pair of Tens"),
-    ("T T K 8 5", "Pair of Tens"),
-    ("T T K 8 4", "Pair of Tens"),
-    ("T T K 8 3", "Pair of Tens"),
-    ("T T K 8 2", "Pair of Tens"),
-    ("T T K 7 6", "Pair of Tens"),
-    ("T T K 7 5", "Pair of Tens"),
-    ("T T K 7 4", "Pair of Tens"),
-    ("T T K 7 3", "Pair of Tens"),
-    ("T T K 7 2", "Pair of Tens"),
-    ("T T K 6 5", "Pair of Tens"),
-    ("T T K 6 4", "Pair of Tens"),
-    ("T T K 6 3", "Pair of Tens"),
-    ("T T K 6 2", "Pair of Tens"),
-    ("T T K 5 4", "Pair of Tens"),
-    ("T T K 5 3", "Pair of Tens"),
-    ("T T K 5 2", "Pair of Tens"),
-    ("T T K 4 3", "Pair of Tens"),
-    ("T T K 4 2", "Pair of Tens"),
-    ("T T K 3 2", "Pair of Tens"),
-    ("T T Q J 9", "Pair of Tens"),
-    ("T T Q J 8", "Pair of Tens"),
-    ("T T Q J 7", "Pair of Tens"),
-    ("T T Q J 6", "Pair of Tens"),
-    ("T T Q J 5", "Pair of Tens"),
-    ("T T Q J 4", "Pair of Tens"),
-    ("T T Q J 3", "Pair of Tens"),
-    ("T T Q J 2", "Pair of Tens"),
-    ("T T Q 9 8", "Pair of Tens"),
-    ("T T Q 9 7", "Pair of Tens"),
-    ("T T Q 9 6", "Pair of Tens"),
-    ("T T Q 9 5", "Pair of Tens"),
-    ("T T Q 9 4", "Pair of Tens"),
-    ("T T Q 9 3", "Pair of Tens"),
-    ("T T Q 9 2", "Pair of Tens"),
-    ("T T Q 8 7", "Pair of Tens"),
-    ("T T Q 8 6", "Pair of Tens"),
-    ("T T Q 8 5", "Pair of Tens"),
-    ("T T Q 8 4", "Pair of Tens"),
-    ("T T Q 8 3", "Pair of Tens"),
-    ("T T Q 8 2", "Pair of Tens"),
-    ("T T Q 7 6", "Pair of Tens"),
-    ("T T Q 7 5", "Pair of Tens"),
-    ("T T Q 7 4", "Pair of Tens"),
-    ("T T Q 7 3", "Pair of Tens"),
-    ("T T Q 7 2", "Pair of Tens"),
-    ("T T Q 6 5", "Pair of Tens"),
-    ("T T Q 6 4", "Pair of Tens"),
-    ("T T Q 6 3", "Pair of Tens"),
-    ("T T Q 6 2", "Pair of Tens"),
-    ("T T Q 5 4", "Pair of Tens"),
-    ("T T Q 5 3", "Pair of Tens"),
-    ("T T Q 5 2", "Pair of Tens"),
-    ("T T Q 4 3", "Pair of Tens"),
-    ("T T Q 4 2", "Pair of Tens"),
-    ("T T Q 3 2", "Pair of Tens"),
-    ("T T J 9 8", "Pair of Tens"),
-    ("T T J 9 7", "Pair of Tens"),
-    ("T T J 9 6", "Pair of Tens"),
-    ("T T J 9 5", "Pair of Tens"),
-    ("T T J 9 4", "Pair of Tens"),
-    ("T T J 9 3", "Pair of Tens"),
-    ("T T J 9 2", "Pair of Tens"),
-    ("T T J 8 7", "Pair of Tens"),
-    ("T T J 8 6", "Pair of Tens"),
-    ("T T J 8 5", "Pair of Tens"),
-    ("T T J 8 4", "Pair of Tens"),
-    ("T T J 8 3", "Pair of Tens"),
-    ("T T J 8 2", "Pair of Tens"),
-    ("T T J 7 6", "Pair of Tens"),
-    ("T T J 7 5", "Pair of Tens"),
-    ("T T J 7 4", "Pair of Tens"),
-    ("T T J 7 3", "Pair of Tens"),
-    ("T T J 7 2", "Pair of Tens"),
-    ("T T J 6 5", "Pair of Tens"),
-    ("T T J 6 4", "Pair of Tens"),
-    ("T T J 6 3", "Pair of Tens"),
-    ("T T J 6 2", "Pair of Tens"),
-    ("T T J 5 4", "Pair of Tens"),
-    ("T T J 5 3", "Pair of Tens"),
-    ("T T J 5 2", "Pair of Tens"),
-    ("T T J 4 3", "Pair of Tens"),
-    ("T T J 4 2", "Pair of Tens"),
-    ("T T J 3 2", "Pair of Tens"),
-    ("T T 9 8 7", "Pair of Tens"),
-    ("T T 9 8 6", "Pair of Tens"),
-    ("T T 9 8 5", "Pair of Tens"),
-    ("T T 9 8 4", "Pair of Tens"),
-    ("T T 9 8 3", "Pair of Tens"),
-    ("T T 9 8 2", "Pair of Tens"),
-    ("T T 9 7 6", "Pair of Tens"),
-    ("T T 9 7 5", "Pair of Tens"),
-    ("T T 9 7 4", "Pair of Tens"),
-    ("T T 9 7 3", "Pair of Tens"),
-    ("T T 9 7 2", "Pair of Tens"),
-    ("T T 9 6 5", "Pair of Tens"),
-    ("T T 9 6 4", "Pair of Tens"),
-    ("T T 9 6 3", "Pair of Tens"),
-    ("T T 9 6 2", "Pair of Tens"),
-    ("T T 9 5 4", "Pair of Tens"),
-    ("T T 9 5 3", "Pair of Tens"),
-    ("T T 9 5 2", "Pair of Tens"),
-    ("T T 9 4 3", "Pair of Tens"),
-    ("T T 9 4 2", "Pair of Tens"),
-    ("T T 9 3 2", "Pair of Tens"),
-    ("T T 8 7 6", "Pair of Tens"),
-    ("T T 8 7 5", "Pair of Tens"),
-    ("T T 8 7 4", "Pair of Tens"),
-    ("T T 8 7 3", "Pair of Tens"),
-    ("T T 8 7 2", "Pair of Tens"),
-    ("T T 8 6 5", "Pair of Tens"),
-    ("T T 8 6 4", "Pair of Tens"),
-    ("T T 8 6 3", "Pair of Tens"),
-    ("T T 8 6 2", "Pair of Tens"),
-    ("T T 8 5 4", "Pair of Tens"),
-    ("T T 8 5 3", "Pair of Tens"),
-    ("T T 8 5 2", "Pair of Tens"),
-    ("T T 8 4 3", "Pair of Tens"),
-    ("T T 8 4 2", "Pair of Tens"),
-    ("T T 8 3 2", "Pair of Tens"),
-    ("T T 7 6 5", "Pair of Tens"),
-    ("T T 7 6 4", "Pair of Tens"),
-    ("T T 7 6 3", "Pair of Tens"),
-    ("T T 7 6 2", "Pair of Tens"),
-    ("T T 7 5 4", "Pair of Tens"),
-    ("T T 7 5 3", "Pair of Tens"),
-    ("T T 7 5 2", "Pair of Tens"),
-    ("T T 7 4 3", "Pair of Tens"),
-    ("T T 7 4 2", "Pair of Tens"),
-    ("T T 7 3 2", "Pair of Tens"),
-    ("T T 6 5 4", "Pair of Tens"),
-    ("T T 6 5 3", "Pair of Tens"),
-    ("T T 6 5 2", "Pair of Tens"),
-    ("T T 6 4 3", "Pair of Tens"),
-    ("T T 6 4 2", "Pair of Tens"),
-    ("T T 6 3 2", "Pair of Tens"),
-    ("T T 5 4 3", "Pair of Tens"),
-    ("T T 5 4 2", "Pair of Tens"),
-    ("T T 5 3 2", "Pair of Tens"),
-    ("T T 4 3 2", "Pair of Tens"),
-    ("9 9 A K Q", "Pair of Nines"),
-    ("9 9 A K J", "Pair of Nines"),
-    ("9 9 A K T", "Pair of Nines"),
-    ("9 9 A K 8", "Pair of Nines"),
-    ("9 9 A K 7", "Pair of Nines"),
-    ("9 9 A K 6", "Pair of Nines"),
-    ("9 9 A K 5", "Pair of Nines"),
-    ("9 9 A K 4", "Pair of Nines"),
-    ("9 9 A K 3", "Pair of Nines"),
-    ("9 9 A K 2", "Pair of Nines"),
-    ("9 9 A Q J", "Pair of Nines"),
-    ("9 9 A Q T", "Pair of Nines"),
-    ("9 9 A Q 8", "Pair of Nines"),
-    ("9 9 A Q 7", "Pair of Nines"),
-    ("9 9 A Q 6", "Pair of Nines"),
-    ("9 9 A Q 5", "Pair of Nines"),
-    ("9 9 A Q 4", "Pair of Nines"),
-    ("9 9 A Q 3", "Pair of Nines"),
-    ("9 9 A Q 2", "Pair of Nines"),
-    ("9 9 A J T", "Pair of Nines"),
-    ("9 9 A J 8", "Pair of Nines"),
-    ("9 9 A J 7", "Pair of Nines"),
-    ("9 9 A J 6", "Pair of Nines"),
-    ("9 9 A J 5", "Pair of Nines"),
-    ("9 9 A J 4", "Pair of Nines"),
-    ("9 9 A J 3", "Pair of Nines"),
-    ("9 9 A J 2", "Pair of Nines"),
-    ("9 9 A T 8", "Pair of Nines"),
-    ("9 9 A T 7", "Pair of Nines"),
-    ("9 9 A T 6", "Pair of Nines"),
-    ("9 9 A T 5", "Pair of Nines"),
-    ("9 9 A T 4", "Pair of Nines"),
-    ("9 9 A T 3", "Pair of Nines"),
-    ("9 9 A T 2", "Pair of Nines"),
-    ("9 9 A 8 7", "Pair of Nines"),
-    ("9 9 A 8 6", "Pair of Nines"),
-    ("9 9 A 8 5", "Pair of Nines"),
-    ("9 9 A 8 4", "Pair of Nines"),
-    ("9 9 A 8 3", "Pair of Nines"),
-    ("9 9 A 8 2", "Pair of Nines"),
-    ("9 9 A 7 6", "Pair of Nines"),
-    ("9 9 A 7 5", "Pair of Nines"),
-    ("9 9 A 7 4", "Pair of Nines"),
-    ("9 9 A 7 3", "Pair of Nines"),
-    ("9 9 A 7 2", "Pair of Nines"),
-    ("9 9 A 6 5", "Pair of Nines"),
-    ("9 9 A 6 4", "Pair of Nines"),
-    ("9 9 A 6 3", "Pair of Nines"),
-    ("9 9 A 6 2", "Pair of Nines"),
-    ("9 9 A 5 4", "Pair of Nines"),
-    ("9 9 A 5 3", "Pair of Nines"),
-    ("9 9 A 5 2", "Pair of Nines"),
-    ("9 9 A 4 3", "Pair of Nines"),
-    ("9 9 A 4 2", "Pair of Nines"),
-    ("9 9 A 3 2", "Pair of Nines"),
-    ("9 9 K Q J", "Pair of Nines"),
-    ("9 9 K Q T", "Pair of Nines"),
-    ("9 9 K Q 8", "Pair of Nines"),
-    ("9 9 K Q 7", "Pair of Nines"),
-    ("9 9 K Q 6", "Pair of Nines"),
-    ("9 9 K Q 5", "Pair of Nines"),
-    ("9 9 K Q 4", "Pair of Nines"),
-    ("9 9 K Q 3", "Pair of Nines"),
-    ("9 9 K Q 2", "Pair of Nines"),
-    ("9 9 K J T", "Pair of Nines"),
-    ("9 9 K J 8", "Pair of Nines"),
-    ("9 9 K J 7", "Pair of Nines"),
-    ("9 9 K J 6", "Pair of Nines"),
-    ("9 9 K J 5", "Pair of Nines"),
-    ("9 9 K J 4", "Pair of Nines"),
-    ("9 9 K J 3", "Pair of Nines"),
-    ("9 9 K J 2", "Pair of Nines"),
-    ("9 9 K T 8", "Pair of Nines"),
-    ("9 9 K T 7", "Pair of Nines"),
-    ("9 9 K T 6", "Pair of Nines"),
-    ("9 9 K T 5", "Pair of Nines"),
-    ("9 9 K T 4", "Pair of Nines"),
-    ("9 9 K T 3", "Pair of Nines"),
-    ("9 9 K T 2", "Pair of Nines"),
-    ("9 9 K 8 7", "Pair of Nines"),
-    ("9 9 K 8 6", "Pair of Nines"),
-    ("9 9 K 8 5", "Pair of Nines"),
-    ("9 9 K 8 4", "Pair of Nines"),
-    ("9 9 K 8 3", "Pair of Nines"),
-    ("9 9 K 8 2", "Pair of Nines"),
-    ("9 9 K 7 6", "Pair of Nines"),
-    ("9 9 K 7 5", "Pair of Nines"),
-    ("9 9 K 7 4", "Pair of Nines"),
-    ("9 9 K 7 3", "Pair of Nines"),
-    ("9 9 K 7 2", "Pair of Nines"),
-    ("9 9 K 6 5", "Pair of Nines"),
-    ("9 9 K 6 4", "Pair of Nines"),
-    ("9 9 K 6 3", "Pair of Nines"),
-    ("9 9 K 6 2", "Pair of Nines"),
-    ("9 9 K 5 4", "Pair of Nines"),
-    ("9 9 K 5 3", "Pair of Nines"),
-    ("9 9 K 5 2", "Pair of Nines"),
-    ("9 9 K 4 3", "Pair of Nines"),
-    ("9 9 K 4 2", "Pair of Nines"),
-    ("9 9 K 3 2", "Pair of Nines"),
-    ("9 9 Q J T", "Pair of Nines"),
-    ("9 9 Q J 8", "Pair of Nines"),
-    ("9 9 Q J 7", "Pair of Nines"),
-    ("9 9 Q J 6", "Pair of Nines"),
-    ("9 9 Q J 5", "Pair of Nines"),
-    ("9 9 Q J 4", "Pair of Nines"),
-    ("9 9 Q J 3", "Pair of Nines"),
-    ("9 9 Q J 2", "Pair of Nines"),
-    ("9 9 Q T 8", "Pair of Nines"),
-    ("9 9 Q T 7", "Pair of Nines"),
-    ("9 9 Q T 6", "Pair of Nines"),
-    ("9 9 Q T 5", "Pair of Nines"),
-    ("9 9 Q T 4", "Pair of Nines"),
-    ("9 9 Q T 3", "Pair of Nines"),
-    ("9 9 Q T 2", "Pair of Nines"),
-    ("9 9 Q 8 7", "Pair of Nines"),
-    ("9 9 Q 8 6", "Pair of Nines"),
-    ("9 9 Q 8 5", "Pair of Nines"),
-    ("9 9 Q 8 4", "Pair of Nines"),
-    ("9 9 Q 8 3", "Pair of Nines"),
-    ("9 9 Q 8 2", "Pair of Nines"),
-    ("9 9 Q 7 6", "Pair of Nines"),
-    ("9 9 Q 7 5", "Pair of Nines"),
-    ("9 9 Q 7 4", "Pair of Nines"),
-    ("9 9 Q 7 3", "Pair of Nines"),
-    ("9 9 Q 7 2", "Pair of Nines"),
-    ("9 9 Q 6 5", "Pair of Nines"),
-    ("9 9 Q 6 4", "Pair of Nines"),
-    ("9 9 Q 6 3", "Pair of Nines"),
-    ("9 9 Q 6 2", "Pair of Nines"),
-    ("9 9 Q 5 4", "Pair of Nines"),
-    ("9 9 Q 5 3", "Pair of Nines"),
-    ("9 9 Q 5 2", "Pair of Nines"),
-    ("9 9 Q 4 3", "Pair of Nines"),
-    ("9 9 Q 4 2", "Pair of Nines"),
-    ("9 9 Q 3 2", "Pair of Nines"),
-    ("9 9 J T 8", "Pair of Nines"),
-    ("9 9 J T 7", "Pair of Nines"),
-    ("9 9 J T 6", "Pair of Nines"),
-    ("9 9 J T 5", "Pair of Nines"),
-    ("9 9 J T 4", "Pair of Nines"),
-    ("9 9 J T 3", "Pair of Nines"),
-    ("9 9 J T 2", "Pair of Nines"),
-    ("9 9 J 8 7", "Pair of Nines"),
-    ("9 9 J 8 6", "Pair of Nines"),
-    ("9 9 J 8 5", "Pair of Nines"),
-    ("9 9 J 8 4", "Pair of Nines"),
-    ("9 9 J 8 3", "Pair of Nines"),
-    ("9 9 J 8 2", "Pair of Nines"),
-    ("9 9 J 7 6", "Pair of Nines"),
-    ("9 9 J 7 5", "Pair of Nines"),
-    ("9 9 J 7 4", "Pair of Nines"),
-    ("9 9 J 7 3", "Pair of Nines"),
-    ("9 9 J 7 2", "Pair of Nines"),
-    ("9 9 J 6 5", "Pair of Nines"),
-    ("9 9 J 6 4", "Pair of Nines"),
-    ("9 9 J 6 3", "Pair of Nines"),
-    ("9 9 J 6 2", "Pair of Nines"),
-    ("9 9 J 5 4", "Pair of Nines"),
-    ("9 9 J 5 3", "Pair of Nines"),
-    ("9 9 J 5 2", "Pair of Nines"),
-    ("9 9 J 4 3", "Pair of Nines"),
-    ("9 9 J 4 2", "Pair of Nines"),
-    ("9 9 J 3 2", "Pair of Nines"),
-    ("9 9 T 8 7", "Pair of Nines"),
-    ("9 9 T 8 6", "Pair of Nines"),
-    ("9 9 T 8 5", "Pair of Nines"),
-    ("9 9 T 8 4", "Pair of Nines"),
-    ("9 9 T 8 3", "Pair of Nines"),
-    ("9 9 T 8 2", "Pair of Nines"),
-    ("9 9 T 7 6", "Pair of Nines"),
-    ("9 9 T 7 5", "Pair of Nines"),
-    ("9 9 T 7 4", "Pair of Nines"),
-    ("9 9 T 7 3", "Pair of Nines"),
-    ("9 9 T 7 2", "Pair of Nines"),
-    ("9 9 T 6 5", "Pair of Nines"),
-    ("9 9 T 6 4", "Pair of Nines"),
-    ("9 9 T 6 3", "Pair of Nines"),
-    ("9 9 T 6 2", "Pair of Nines"),
-    ("9 9 T 5 4", "Pair of Nines"),
-    ("9 9 T 5 3", "Pair of Nines"),
-    ("9 9 T 5 2", "Pair of Nines"),
-    ("9 9 T 4 3", "Pair of Nines"),
-    ("9 9 T 4 2", "Pair of Nines"),
-    ("9 9 T 3 2", "Pair of Nines"),
-    ("9 9 8 7 6", "Pair of Nines"),
-    ("9 9 8 7 5", "Pair of Nines"),
-    ("9 9 8 7 4", "Pair of Nines"),
-    ("9 9 8 7 3", "Pair of Nines"),
-    ("9 9 8 7 2", "Pair of Nines"),
-    ("9 9 8 6 5", "Pair of Nines"),
-    ("9 9 8 6 4", "Pair of Nines"),
-    ("9 9 8 6 3", "Pair of Nines"),
-    ("9 9 8 6 2", "Pair of Nines"),
-    ("9 9 8 5 4", "Pair of Nines"),
-    ("9 9 8 5 3", "Pair of Nines"),
-    ("9 9 8 5 2", "Pair of Nines"),
-    ("9 9 8 4 3", "Pair of Nines"),
-    ("9 9 8 4 2", "Pair of Nines"),
-    ("9 9 8 3 2", "Pair of Nines"),
-    ("9 9 7 6 5", "Pair of Nines"),
-    ("9 9 7 6 4", "Pair of Nines"),
-    ("9 9 7 6 3", "Pair of Nines"),
-    ("9 9 7 6 2", "Pair of Nines"),
-    ("9 9 7 5 4", "Pair of Nines"),
-    ("9 9 7 5 3", "Pair of Nines"),
-    ("9 9 7 5 2", "Pair of Nines"),
-    ("9 9 7 4 3", "Pair of Nines"),
-    ("9 9 7 4 2", "Pair of Nines"),
-    ("9 9 7 3 2", "Pair of Nines"),
-    ("9 9 6 5 4", "Pair of Nines"),
-    ("9 9 6 5 3", "Pair of Nines"),
-    ("9 9 6 5 2", "Pair of Nines"),
-    ("9 9 6 4 3", "Pair of Nines"),
-    ("9 9 6 4 2", "Pair of Nines"),
-    ("9 9 6 3 2", "Pair of Nines"),
-    ("9 9 5 4 3", "Pair of Nines"),
-    ("9 9 5 4 2", "Pair of Nines"),
-    ("9 9 5 3 2", "Pair of Nines"),
-    ("9 9 4 3 2", "Pair of Nines"),
-    ("8 8 A K Q", "Pair of Eights"),
-    ("8 8 A K J", "Pair of Eights"),
-    ("8 8 A K T", "Pair of Eights"),
-    ("8 8 A K 9", "Pair of Eights"),
-    ("8 8 A K 7", "Pair of Eights"),
-    ("8 8 A K 6", "Pair of Eights"),
-    ("8 8 A K 5", "Pair of Eights"),
-    ("8 8 A K 4", "Pair of Eights"),
-    ("8 8 A K 3", "Pair of Eights"),
-    ("8 8 A K 2", "Pair of Eights"),
-    ("8 8 A Q J", "Pair of Eights"),
-    ("8 8 A Q T", "Pair of Eights"),
-    ("8 8 A Q 9", "Pair of Eights"),
-    ("8 8 A Q 7", "Pair of Eights"),
-    ("8 8 A Q 6", "Pair of Eights"),
-    ("8 8 A Q 5", "Pair of Eights"),
-    ("8 8 A Q 4", "Pair of Eights"),
-    ("8 8 A Q 3", "Pair of Eights"),
-    ("8 8 A Q 2", "Pair of Eights"),
-    ("8 8 A J T", "Pair of Eights"),
-    ("8 8 A J 9", "Pair of Eights"),
-    ("8 8 A J 7", "Pair of Eights"),
-    ("8 8 A J 6", "Pair of Eights"),
-    ("8 8 A J 5", "Pair of Eights"),
-    ("8 8 A J 4", "Pair of Eights"),
-    ("8 8 A J 3", "Pair of Eights"),
-    ("8 8 A J 2", "Pair of Eights"),
-    ("8 8 A T 9", "Pair of Eights"),
-    ("8 8 A T 7", "Pair of Eights"),
-    ("8 8 A T 6", "Pair of Eights"),
-    ("8 8 A T 5", "Pair of Eights"),
-    ("8 8 A T 4", "Pair of Eights"),
-    ("8 8 A T 3", "Pair of Eights"),
-    ("8 8 A T 2", "Pair of Eights"),
-    ("8 8 A 9 7", "Pair of Eights"),
-    ("8 8 A 9 6", "Pair of Eights"),
-    ("8 8 A 9 5", "Pair of Eights"),
-    ("8 8 A 9 4", "Pair of Eights"),
-    ("8 8 A 9 3", "Pair of Eights"),
-    ("8 8 A 9 2", "Pair of Eights"),
-    ("8 8 A 7 6", "Pair of Eights"),
-    ("8 8 A 7 5", "Pair of Eights"),
-    ("8 8 A 7 4", "Pair of Eights"),
-    ("8 8 A 7 3", "Pair of Eights"),
-    ("8 8 A 7 2", "Pair of Eights"),
-    ("8 8 A 6 5", "Pair of Eights"),
-    ("8 8 A 6 4", "Pair of Eights"),
-    ("8 8 A 6 3", "Pair of Eights"),
-    ("8 8 A 6 2", "Pair of Eights"),
-    ("8 8 A 5 4", "Pair of Eights"),
-    ("8 8 A 5 3", "Pair of Eights"),
-    ("8 8 A 5 2", "Pair of Eights"),
-    ("8 8 A 4 3", "Pair of Eights"),
-    ("8 8 A 4 2", "Pair of Eights"),
-    ("8 8 A 3 2", "Pair of Eights"),
-    ("8 8 K Q J", "Pair of Eights"),
-    ("8 8 K Q T", "Pair of Eights"),
-    ("8 8 K Q 9", "Pair of Eights"),
-    ("8 8 K Q 7", "Pair of Eights"),
-    ("8 8 K Q 6", "Pair of Eights"),
-    ("8 8 K Q 5", "Pair of Eights"),
-    ("8 8 K Q 4", "Pair of Eights"),
-    ("8 8 K Q 3", "Pair of Eights"),
-    ("8 8 K Q 2", "Pair of Eights"),
-    ("8 8 K J T", "Pair of Eights"),
-    ("8 8 K J 9", "Pair of Eights"),
-    ("8 8 K J 7", "Pair of Eights"),
-    ("8 8 K J 6", "Pair of Eights"),
-    ("8 8 K J 5", "Pair of Eights"),
-    ("8 8 K J 4", "Pair of Eights"),
-    ("8 8 K J 3", "Pair of Eights"),
-    ("8 8 K J 2", "Pair of Eights"),
-    ("8 8 K T 9", "Pair of Eights"),
-    ("8 8 K T 7", "Pair of Eights"),
-    ("8 8 K T 6", "Pair of Eights"),
-    ("8 8 K T 5", "Pair of Eights"),
-    ("8 8 K T 4", "Pair of Eights"),
-    ("8 8 K T 3", "Pair of Eights"),
-    ("8 8 K T 2", "Pair of Eights"),
-    ("8 8 K 9 7", "Pair of Eights"),
-    ("8 8 K 9 6", "Pair of Eights"),
-    ("8 8 K 9 5", "Pair of Eights"),
-    ("8 8 K 9 4", "Pair of Eights"),
-    ("8 8 K 9 3", "Pair of Eights"),
-    ("8 8 K 9 2", "Pair of Eights"),
-    ("8 8 K 7 6", "Pair of Eights"),
-    ("8 8 K 7 5", "Pair of Eights"),
-    ("8 8 K 7 4", "Pair of Eights"),
-    ("8 8 K 7 3", "Pair of Eights"),
-    ("8 8 K 7 2", "Pair of Eights"),
-    ("8 8 K 6 5", "Pair of Eights"),
-    ("8 8 K 6 4", "Pair of Eights"),
-    ("8 8 K 6 3", "Pair of Eights"),
-    ("8 8 K 6 2", "Pair of Eights"),
-    ("8 8 K 5 4", "Pair of Eights"),
-    ("8 8 K 5 3", "Pair of Eights"),
-    ("8 8 K 5 2", "Pair of Eights"),
-    ("8 8 K 4 3", "Pair of Eights"),
-    ("8 8 K 4 2", "Pair of Eights"),
-    ("8 8 K 3 2", "Pair of Eights"),
-    ("8 8 Q J T", "Pair of Eights"),
-    ("8 8 Q J 9", "Pair of Eights"),
-    ("8 8 Q J 7", "Pair of Eights"),
-    ("8 8 Q J 6", "Pair of Eights"),
-    ("8 8 Q J 5", "Pair of Eights"),
-    ("8 8 Q J 4", "Pair of Eights"),
-    ("8 8 Q J 3", "Pair of Eights"),
-    ("8 8 Q J 2", "Pair of Eights"),
-    ("8 8 Q T 9", "Pair of Eights"),
-    ("8 8 Q T 7", "Pair of Eights"),
-    ("8 8 Q T 6", "Pair of Eights"),
-    ("8 8 Q T 5", "Pair of Eights"),
-    ("8 8 Q T 4", "Pair of Eights"),
-    ("8 8 Q T 3", "Pair of Eights"),
-    ("8 8 Q T 2", "Pair of Eights"),
-    ("8 8 Q 9 7", "Pair of Eights"),
-    ("8 8 Q 9 6", "Pair of Eights"),
-    ("8 8 Q 9 5", "Pair of Eights"),
-    ("8 8 Q 9 4", "Pair of Eights"),
-    ("8 8 Q 9 3", "Pair of Eights"),
-    ("8 8 Q 9 2", "Pair of Eights"),
-    ("8 8 Q 7 6", "Pair of Eights"),
-    ("8 8 Q 7 5", "Pair of Eights"),
-    ("8 8 Q 7 4", "Pair of Eights"),
-    ("8 8 Q 7 3", "Pair of Eights"),
-    ("8 8 Q 7 2", "Pair of Eights"),
-    ("8 8 Q 6 5", "Pair of Eights"),
-    ("8 8 Q 6 4", "Pair of Eights"),
-    ("8 8 Q 6 3", "Pair of Eights"),
-    ("8 8 Q 6 2", "Pair of Eights"),
-    ("8 8 Q 5 4", "Pair of Eights"),
-    ("8 8 Q 5 3", "Pair of Eights"),
-    ("8 8 Q 5 2", "Pair of Eights"),
-    ("8 8 Q 4 3", "Pair of Eights"),
-    ("8 8 Q 4 2", "Pair of Eights"),
-    ("8 8 Q 3 2", "Pair of Eights"),
-    ("8 8 J T 9", "Pair of Eights"),
-    ("8 8 J T 7", "Pair of Eights"),
-    ("8 8 J T 6", "Pair of Eights"),
-    ("8 8 J T 5", "Pair of Eights"),
-    ("8 8 J T 4", "Pair of Eights"),
-    ("8 8 J T 3", "Pair of Eights"),
-    ("8 8 J T 2", "Pair of Eights"),
-    ("8 8 J 9 7", "Pair of Eights"),
-    ("8 8 J 9 6", "Pair of Eights"),
-    ("8 8 J 9 5", "Pair of Eights"),
-    ("8 8 J 9 4", "Pair of Eights"),
-    ("8 8 J 9 3", "Pair of Eights"),
-    ("8 8 J 9 2", "Pair of Eights"),
-    ("8 8 J 7 6", "Pair of Eights"),
-    ("8 8 J 7 5", "Pair of Eights"),
-    ("8 8 J 7 4", "Pair of Eights"),
-    ("8 8 J 7 3", "Pair of Eights"),
-    ("8 8 J 7 2", "Pair of Eights"),
-    ("8 8 J 6 5", "Pair of Eights"),
-    ("8 8 J 6 4", "Pair of Eights"),
-    ("8 8 J 6 3", "Pair of Eights"),
-    ("8 8 J 6 2", "Pair of Eights"),
-    ("8 8 J 5 4", "Pair of Eights"),
-    ("8 8 J 5 3", "Pair of Eights"),
-    ("8 8 J 5 2", "Pair of Eights"),
-    ("8 8 J 4 3", "Pair of Eights"),
-    ("8 8 J 4 2", "Pair of Eights"),
-    ("8 8 J 3 2", "Pair of Eights"),
-    ("8 8 T 9 7", "Pair of Eights"),
-    ("8 8 T 9 6", "Pair of Eights"),
-    ("8 8 T 9 5", "Pair of Eights"),
-    ("8 8 T 9 4", "Pair of Eights"),
-    ("8 8 T 9 3", "Pair of Eights"),
-    ("8 8 T 9 2", "Pair of Eights"),
-    ("8 8 T 7 6", "Pair of Eights"),
-    ("8 8 T 7 5", "Pair of Eights"),
-    ("8 8 T 7 4", "Pair of Eights"),
-    ("8 8 T 7 3", "Pair of Eights"),
-    ("8 8 T 7 2", "Pair of Eights"),
-    ("8 8 T 6 5", "Pair of Eights"),
-    ("8 8 T 6 4", "Pair of Eights"),
-    ("8 8 T 6 3", "Pair of Eights"),
-    ("8 8 T 6 2", "Pair of Eights"),
-    ("8 8 T 5 4", "Pair of Eights"),
-    ("8 8 T 5 3", "Pair of Eights"),
-    ("8 8 T 5 2", "Pair of Eights"),
-    ("8 8 T 4 3", "Pair of Eights"),
-    ("8 8 T 4 2", "Pair of Eights"),
-    ("8 8 T 3 2", "Pair of Eights"),
-    ("8 8 9 7 6", "Pair of Eights"),
-    ("8 8 9 7 5", "Pair of Eights"),
-    ("8 8 9 7 4", "Pair of Eights"),
-    ("8 8 9 7 3", "Pair of Eights"),
-    ("8 8 9 7 2", "Pair of Eights"),
-    ("8 8 9 6 5", "Pair of Eights"),
-    ("8 8 9 6 4", "Pair of Eights"),
-    ("8 8 9 6 3", "Pair of Eights"),
-    ("8 8 9 6 2", "Pair of Eights"),
-    ("8 8 9 5 4", "Pair of Eights"),
-    ("8 8 9 5 3", "Pair of Eights"),
-    ("8 8 9 5 2", "Pair of Eights"),
-    ("8 8 9 4 3", "Pair of Eights"),
-    ("8 8 9 4 2", "Pair of Eights"),
-    ("8 8 9 3 2", "Pair of Eights"),
-    ("8 8 7 6 5", "Pair of Eights"),
-    ("8 8 7 6 4", "Pair of Eights"),
-    ("8 8 7 6 3", "Pair of Eights"),
-    ("8 8 7 6 2", "Pair of Eights"),
-    ("8 8 7 5 4", "Pair of Eights"),
-    ("8 8 7 5 3", "Pair of Eights"),
-    ("8 8 7 5 2", "Pair of Eights"),
-    ("8 8 7 4 3", "Pair of Eights"),
-    ("8 8 7 4 2", "Pair of Eights"),
-    ("8 8 7 3 2", "Pair of Eights"),
-    ("8 8 6 5 4", "Pair of Eights"),
-    ("8 8 6 5 3", "Pair of Eights"),
-    ("8 8 6 5 2", "Pair of Eights"),
-    ("8 8 6 4 3", "Pair of Eights"),
-    ("8 8 6 4 2", "Pair of Eights"),
-    ("8 8 6 3 2", "Pair of Eights"),
-    ("8 8 5 4 3", "Pair of Eights"),
-    ("8 8 5 4 2", "Pair of Eights"),
-    ("8 8 5 3 2", "Pair of Eights"),
-    ("8 8 4 3 2", "Pair of Eights"),
-    ("7 7 A K Q", "Pair of Sevens"),
-    ("7 7 A K J", "Pair of Sevens"),
-    ("7 7 A K T", "Pair of Sevens"),
-    ("7 7 A K 9", "Pair of Sevens"),
-    ("7 7 A K 8", "Pair of Sevens"),
-    ("7 7 A K 6", "Pair of Sevens"),
-    ("7 7 A K 5", "Pair of Sevens"),
-    ("7 7 A K 4", "Pair of Sevens"),
-    ("7 7 A K 3", "Pair of Sevens"),
-    ("7 7 A K 2", "Pair of Sevens"),
-    ("7 7 A Q J", "Pair of Sevens"),
-    ("7 7 A Q T", "Pair of Sevens"),
-    ("7 7 A Q 9", "Pair of Sevens"),
-    ("7 7 A Q 8", "Pair of Sevens"),
-    ("7 7 A Q 6", "Pair of Sevens"),
-    ("7 7 A Q 5", "Pair of Sevens"),
-    ("7 7 A Q 4", "Pair of Sevens"),
-    ("7 7 A Q 3", "Pair of Sevens"),
-    ("7 7 A Q 2", "Pair of Sevens"),
-    ("7 7 A J T", "Pair of Sevens"),
-    ("7 7 A J 9", "Pair of Sevens"),
-    ("7 7 A J 8", "Pair of Sevens"),
-    ("7 7 A J 6", "Pair of Sevens"),
-    ("7 7 A J 5", "Pair of Sevens"),
-    ("7 7 A J 4", "Pair of Sevens"),
-    ("7 7 A J 3", "Pair of Sevens"),
-    ("7 7 A J 2", "Pair of Sevens"),
-    ("7 7 A T 9", "Pair of Sevens"),
-    ("7 7 A T 8", "Pair of Sevens"),
-    ("7 7 A T 6", "Pair of Sevens"),
-    ("7 7 A T 5", "Pair of Sevens"),
-    ("7 7 A T 4", "Pair of Sevens"),
-    ("7 7 A T 3", "Pair of Sevens"),
-    ("7 7 A T 2", "Pair of Sevens"),
-    ("7 7 A 9 8", "Pair of Sevens"),
-    ("7 7 A 9 6", "Pair of Sevens"),
-    ("7 7 A 9 5", "Pair of Sevens"),
-    ("7 7 A 9 4", "Pair of Sevens"),
-    ("7 7 A 9 3", "Pair of Sevens"),
-    ("7 7 A 9 2", "Pair of Sevens"),
-    ("7 7 A 8 6", "Pair of Sevens"),
-    ("7 7 A 8 5", "Pair of Sevens"),
-    ("7 7 A 8 4", "Pair of Sevens"),
-    ("7 7 A 8 3", "Pair of Sevens"),
-    ("7 7 A 8 2", "Pair of Sevens"),
-    ("7 7 A 6 5", "Pair of Sevens"),
-    ("7 7 A 6 4", "Pair of Sevens"),
-    ("7 7 A 6 3", "Pair of Sevens"),
-    ("7 7 A 6 2", "Pair of Sevens"),
-    ("7 7 A 5 4", "Pair of Sevens"),
-    ("7 7 A 5 3", "Pair of Sevens"),
-    ("7 7 A 5 2", "Pair of Sevens"),
-    ("7 7 A 4 3", "Pair of Sevens"),
-    ("7 7 A 4 2", "Pair of Sevens"),
-    ("7 7 A 3 2", "Pair of Sevens"),
-    ("7 7 K Q J", "Pair of Sevens"),
-    ("7 7 K Q T", "Pair of Sevens"),
-    ("7 7 K Q 9", "Pair of Sevens"),
-    ("7 7 K Q 8", "Pair of Sevens"),
-    ("7 7 K Q 6", "Pair of Sevens"),
-    ("7 7 K Q 5", "Pair of Sevens"),
-    ("7 7 K Q 4", "Pair of Sevens"),
-    ("7 7 K Q 3", "Pair of Sevens"),
-    ("7 7 K Q 2", "Pair of Sevens"),
-    ("7 7 K J T", "Pair of Sevens"),
-    ("7 7 K J 9", "Pair of Sevens"),
-    ("7 7 K J 8", "Pair of Sevens"),
-    ("7 7 K J 6", "Pair of Sevens"),
-    ("7 7 K J 5", "Pair of Sevens"),
-    ("7 7 K J 4", "Pair of Sevens"),
-    ("7 7 K J 3", "Pair of Sevens"),
-    ("7 7 K J 2", "Pair of Sevens"),
-    ("7 7 K T 9", "Pair of Sevens"),
-    ("7 7 K T 8", "Pair of Sevens"),
-    ("7 7 K T 6", "Pair of Sevens"),
-    ("7 7 K T 5", "Pair of Sevens"),
-    ("7 7 K T 4", "Pair of Sevens"),
-    ("7 7 K T 3", "Pair of Sevens"),
-    ("7 7 K T 2", "Pair of Sevens"),
-    ("7 7 K 9 8", "Pair of Sevens"),
-    ("7 7 K 9 6", "Pair of Sevens"),
-    ("7 7 K 9 5", "Pair of Sevens"),
-    ("7 7 K 9 4", "Pair of Sevens"),
-    ("7 7 K 9 3", "Pair of Sevens"),
-    ("7 7 K 9 2", "Pair of Sevens"),
-    ("7 7 K 8 6", "Pair of Sevens"),
-    ("7 7 K 8 5", "Pair of Sevens"),
-    ("7 7 K 8 4", "Pair of Sevens"),
-    ("7 7 K 8 3", "Pair of Sevens"),
-    ("7 7 K 8 2", "Pair of Sevens"),
-    ("7 7 K 6 5", "Pair of Sevens"),
-    ("7 7 K 6 4", "Pair of Sevens"),
-    ("7 7 K 6 3", "Pair of Sevens"),
-    ("7 7 K 6 2", "Pair of Sevens"),
-    ("7 7 K 5 4", "Pair of Sevens"),
-    ("7 7 K 5 3", "Pair of Sevens"),
-    ("7 7 K 5 2", "Pair of Sevens"),
-    ("7 7 K 4 3", "Pair of Sevens"),
-    ("7 7 K 4 2", "Pair of Sevens"),
-    ("7 7 K 3 2", "Pair of Sevens"),
-    ("7 7 Q J T", "Pair of Sevens"),
-    ("7 7 Q J 9", "Pair of Sevens"),
-    ("7 7 Q J 8", "Pair of Sevens"),
-    ("7 7 Q J 6", "Pair of Sevens"),
-    ("7 7 Q J 5", "Pair of Sevens"),
-    ("7 7 Q J 4", "Pair of Sevens"),
-    ("7 7 Q J 3", "Pair of Sevens"),
-    ("7 7 Q J 2", "Pair of Sevens"),
-    ("7 7 Q T 9", "Pair of Sevens"),
-    ("7 7 Q T 8", "Pair of Sevens"),
-    ("7 7 Q T 6", "Pair of Sevens"),
-    ("7 7 Q T 5", "Pair of Sevens"),
-    ("7 7 Q T 4", "Pair of Sevens"),
-    ("7 7 Q T 3", "Pair of Sevens"),
-    ("7 7 Q T 2", "Pair of Sevens"),
-    ("7 7 Q 9 8", "Pair of Sevens"),
-    ("7 7 Q 9 6", "Pair of Sevens"),
-    ("7 7 Q 9 5", "Pair of Sevens"),
-    ("7 7 Q 9 4", "Pair of Sevens"),
-    ("7 7 Q 9 3", "Pair of Sevens"),
-    ("7 7 Q 9 2", "Pair of Sevens"),
-    ("7 7 Q 8 6", "Pair of Sevens"),
-    ("7 7 Q 8 5", "Pair of Sevens"),
-    ("7 7 Q 8 4", "Pair of Sevens"),
-    ("7 7 Q 8 3", "Pair of Sevens"),
-    ("7 7 Q 8 2", "Pair of Sevens"),
-    ("7 7 Q 6 5", "Pair of Sevens"),
-    ("7 7 Q 6 4", "Pair of Sevens"),
-    ("7 7 Q 6 3", "Pair of Sevens"),
-    ("7 7 Q 6 2", "Pair of Sevens"),
-    ("7 7 Q 5 4", "Pair of Sevens"),
-    ("7 7 Q 5 3", "Pair of Sevens"),
-    ("7 7 Q 5 2", "Pair of Sevens"),
-    ("7 7 Q 4 3", "Pair of Sevens"),
-    ("7 7 Q 4 2", "Pair of Sevens"),
-    ("7 7 Q 3 2", "Pair of Sevens"),
-    ("7 7 J T 9", "Pair of Sevens"),
-    ("7 7 J T 8", "Pair of Sevens"),
-    ("7 7 J T 6", "Pair of Sevens"),
-    ("7 7 J T 5", "Pair of Sevens"),
-    ("7 7 J T 4", "Pair of Sevens"),
-    ("7 7 J T 3", "Pair of Sevens"),
-    ("7 7 J T 2", "Pair of Sevens"),
-    ("7 7 J 9 8", "Pair of Sevens"),
-    ("7 7 J 9 6", "Pair of Sevens"),
-    ("7 7 J 9 5", "Pair of Sevens"),
-    ("7 7 J 9 4", "Pair of Sevens"),
-    ("7 7 J 9 3", "Pair of Sevens"),
-    ("7 7 J 9 2", "Pair of Sevens"),
-    ("7 7 J 8 6", "Pair of Sevens"),
-    ("7 7 J 8 5", "Pair of Sevens"),
-    ("7 7 J 8 4", "Pair of Sevens"),
-    ("7 7 J 8 3", "Pair of Sevens"),
-    ("7 7 J 8 2", "Pair of Sevens"),
-    ("7 7 J 6 5", "Pair of Sevens"),
-    ("7 7 J 6 4", "Pair of Sevens"),
-    ("7 7 J 6 3", "Pair of Sevens"),
-    ("7 7 J 6 2", "Pair of Sevens"),
-    ("7 7 J 5 4", "Pair of Sevens"),
-    ("7 7 J 5 3", "Pair of Sevens"),
-    ("7 7 J 5 2", "Pair of Sevens"),
-    ("7 7 J 4 3", "Pair of Sevens"),
-    ("7 7 J 4 2", "Pair of Sevens"),
-    ("7 7 J 3 2", "Pair of Sevens"),
-    ("7 7 T 9 8", "Pair of Sevens"),
-    ("7 7 T 9 6", "Pair of Sevens"),
-    ("7 7 T 9 5", "Pair of Sevens"),
-    ("7 7 T 9 4", "Pair of Sevens"),
-    ("7 7 T 9 3", "Pair of Sevens"),
-    ("7 7 T 9 2", "Pair of Sevens"),
-    ("7 7 T 8 6", "Pair of Sevens"),
-    ("7 7 T 8 5", "Pair of Sevens"),
-    ("7 7 T 8 4", "Pair of Sevens"),
-    ("7 7 T 8 3", "Pair of Sevens"),
-    ("7 7 T 8 2", "Pair of Sevens"),
-    ("7 7 T 6 5", "Pair of Sevens"),
-    ("7 7 T 6 4", "Pair of Sevens"),
-    ("7 7 T 6 3", "Pair of Sevens"),
-    ("7 7 T 6 2", "Pair of Sevens"),
-    ("7 7 T 5 4", "Pair of Sevens"),
-    ("7 7 T 5 3", "Pair of Sevens"),
-    ("7 7 T 5 2", "Pair of Sevens"),
-    ("7 7 T 4 3", "Pair of Sevens"),
-    ("7 7 T 4 2", "Pair of Sevens"),
-    ("7 7 T 3 2", "Pair of Sevens"),
-    ("7 7 9 8 6", "Pair of Sevens"),
-    ("7 7 9 8 5", "Pair of Sevens"),
-    ("7 7 9 8 4", "Pair of Sevens"),
-    ("7 7 9 8 3", "Pair of Sevens"),
-    ("7 7 9 8 2", "Pair of Sevens"),
-    ("7 7 9 6 5", "Pair of Sevens"),
-    ("7 7 9 6 4", "Pair of Sevens"),
-    ("7 7 9 6 3", "Pair of Sevens"),
-    ("7 7 9 6 2", "Pair of Sevens"),
-    ("7 7 9 5 4", "Pair of Sevens"),
-    ("7 7 9 5 3", "Pair of Sevens"),
-    ("7 7 9 5 2", "Pair of Sevens"),
-    ("7 7 9 4 3", "Pair of Sevens"),
-    ("7 7 9 4 2", "Pair of Sevens"),
-    ("7 7 9 3 2", "Pair of Sevens"),
-    ("7 7 8 6 5", "Pair of Sevens"),
-    ("7 7 8 6 4", "Pair of Sevens"),
-    ("7 7 8 6 3", "Pair of Sevens"),
-    ("7 7 8 6 2", "Pair of Sevens"),
-    ("7 7 8 5 4", "Pair of Sevens"),
-    ("7 7 8 5 3", "Pair of Sevens"),
-    ("7 7 8 5 2", "Pair of Sevens"),
-    ("7 7 8 4 3", "Pair of Sevens"),
-    ("7 7 8 4 2", "Pair of Sevens"),
-    ("7 7 8 3 2", "Pair of Sevens"),
-    ("7 7 6 5 4", "Pair of Sevens"),
-    ("7 7 6 5 3", "Pair of Sevens"),
-    ("7 7 6 5 2", "Pair of Sevens"),
-    ("7 7 6 4 3", "Pair of Sevens"),
-    ("7 7 6 4 2", "Pair of Sevens"),
-    ("7 7 6 3 2", "Pair of Sevens"),
-    ("7 7 5 4 3", "Pair of Sevens"),
-    ("7 7 5 4 2", "Pair of Sevens"),
-    ("7 7 5 3 2", "Pair of Sevens"),
-    ("7 7 4 3 2", "Pair of Sevens"),
-    ("6 6 A K Q", "Pair of Sixes"),
-    ("6 6 A K J", "Pair of Sixes"),
-    ("6 6 A K T", "Pair of Sixes"),
-    ("6 6 A K 9", "Pair of Sixes"),
-    ("6 6 A K 8", "Pair of Sixes"),
-    ("6 6 A K 7", "Pair of Sixes"),
-    ("6 6 A K 5", "Pair of Sixes"),
-    ("6 6 A K 4", "Pair of Sixes"),
-    ("6 6 A K 3", "Pair of Sixes"),
-    ("6 6 A K 2", "Pair of Sixes"),
-    ("6 6 A Q J", "Pair of Sixes"),
-    ("6 6 A Q T", "Pair of Sixes"),
-    ("6 6 A Q 9", "Pair of Sixes"),
-    ("6 6 A Q 8", "Pair of Sixes"),
-    ("6 6 A Q 7", "Pair of Sixes"),
-    ("6 6 A Q 5", "Pair of Sixes"),
-    ("6 6 A Q 4", "Pair of Sixes"),
-    ("6 6 A Q 3", "Pair of Sixes"),
-    ("6 6 A Q 2", "Pair of Sixes"),
-    ("6 6 A J T", "Pair of Sixes"),
-    ("6 6 A J 9", "Pair of Sixes"),
-    ("6 6 A J 8", "Pair of Sixes"),
-    ("6 6 A J 7", "Pair of Sixes"),
-    ("6 6 A J 5", "Pair of Sixes"),
-    ("6 6 A J 4", "Pair of Sixes"),
-    ("6 6 A J 3", "Pair of Sixes"),
-    ("6 6 A J 2", "Pair of Sixes"),
-    ("6 6 A T 9", "Pair of Sixes"),
-    ("6 6 A T 8", "Pair of Sixes"),
-    ("6 6 A T 7", "Pair of Sixes"),
-    ("6 6 A T 5", "Pair of Sixes"),
-    ("6 6 A T 4", "Pair of Sixes"),
-    ("6 6 A T 3", "Pair of Sixes"),
-    ("6 6 A T 2", "Pair of Sixes"),
-    ("6 6 A 9 8", "Pair of Sixes"),
-    ("6 6 A 9 7", "Pair of Sixes"),
-    ("6 6 A 9 5", "Pair of Sixes"),
-    ("6 6 A 9 4", "Pair of Sixes"),
-    ("6 6 A 9 3", "Pair of Sixes"),
-    ("6 6 A 9 2", "Pair of Sixes"),
-    ("6 6 A 8 7", "Pair of Sixes"),
-    ("6 6 A 8 5", "Pair of Sixes"),
-    ("6 6 A 8 4", "Pair of Sixes"),
-    ("6 6 A 8 3", "Pair of Sixes"),
-    ("6 6 A 8 2", "Pair of Sixes"),
-    ("6 6 A 7 5", "Pair of Sixes"),
-    ("6 6 A 7 4", "Pair of Sixes"),
-    ("6 6 A 7 3", "Pair of Sixes"),
-    ("6 6 A 7 2", "Pair of Sixes"),
-    ("6 6 A 5 4", "Pair of Sixes"),
-    ("6 6 A 5 3", "Pair of Sixes"),
-    ("6 6 A 5 2", "Pair of Sixes"),
-    ("6 6 A 4 3", "Pair of Sixes"),
-    ("6 6 A 4 2", "Pair of Sixes"),
-    ("6 6 A 3 2", "Pair of Sixes"),
-    ("6 6 K Q J", "Pair of Sixes"),
-    ("6 6 K Q T", "Pair of Sixes"),
-    ("6 6 K Q 9", "Pair of Sixes"),
-    ("6 6 K Q 8", "Pair of Sixes"),
-    ("6 6 K Q 7", "Pair of Sixes"),
-    ("6 6 K Q 5", "Pair of Sixes"),
-    ("6 6 K Q 4", "Pair of Sixes"),
-    ("6 6 K Q 3", "Pair of Sixes"),
-    ("6 6 K Q 2", "Pair of Sixes"),
-    ("6 6 K J T", "Pair of Sixes"),
-    ("6 6 K J 9", "Pair of Sixes"),
-    ("6 6 K J 8", "Pair of Sixes"),
-    ("6 6 K J 7", "Pair of Sixes"),
-    ("6 6 K J 5", "Pair of Sixes"),
-    ("6 6 K J 4", "Pair of Sixes"),
-    ("6 6 K J 3", "Pair of Sixes"),
-    ("6 6 K J 2", "Pair of Sixes"),
-    ("6 6 K T 9", "Pair of Sixes"),
-    ("6 6 K T 8", "Pair of Sixes"),
-    ("6 6 K T 7", "Pair of Sixes"),
-    ("6 6 K T 5", "Pair of Sixes"),
-    ("6 6 K T 4", "Pair of Sixes"),
-    ("6 6 K T 3", "Pair of Sixes"),
-    ("6 6 K T 2", "Pair of Sixes"),
-    ("6 6 K 9 8", "Pair of Sixes"),
-    ("6 6 K 9 7", "Pair of Sixes"),
-    ("6 6 K 9 5", "Pair of Sixes"),
-    ("6 6 K 9 4", "Pair of Sixes"),
-    ("6 6 K 9 3", "Pair of Sixes"),
-    ("6 6 K 9 2", "Pair of Sixes"),
-    ("6 6 K 8 7", "Pair of Sixes"),
-    ("6 6 K 8 5", "Pair of Sixes"),
-    ("6 6 K 8 4", "Pair of Sixes"),
-    ("6 6 K 8 3", "Pair of Sixes"),
-    ("6 6 K 8 2", "Pair of Sixes"),
-    ("6 6 K 7 5", "Pair of Sixes"),
-    ("6 6 K 7 4", "Pair of Sixes"),
-    ("6 6 K 7 3", "Pair of Sixes"),
-    ("6 6 K 7 2", "Pair of Sixes"),
-    ("6 6 K 5 4", "Pair of Sixes"),
-    ("6 6 K 5 3", "Pair of Sixes"),
-    ("6 6 K 5 2", "Pair of Sixes"),
-    ("6 6 K 4 3", "Pair of Sixes"),
-    ("6 6 K 4 2", "Pair of Sixes"),
-    ("6 6 K 3 2", "Pair of Sixes"),
-    ("6 6 Q J T", "Pair of Sixes"),
-    ("6 6 Q J 9", "Pair of Sixes"),
-    ("6 6 Q J 8", "Pair of Sixes"),
-    ("6 6 Q J 7", "Pair of Sixes"),
-    ("6 6 Q J 5", "Pair of Sixes"),
-    ("6 6 Q J 4", "Pair of Sixes"),
-    ("6 6 Q J 3", "Pair of Sixes"),
-    ("6 6 Q J 2", "Pair of Sixes"),
-    ("6 6 Q T 9", "Pair of Sixes"),
-    ("6 6 Q T 8", "Pair of Sixes"),
-    ("6 6 Q T 7", "Pair of Sixes"),
-    ("6 6 Q T 5", "Pair of Sixes"),
-    ("6 6 Q T 4", "Pair of Sixes"),
-    ("6 6 Q T 3", "Pair of Sixes"),
-    ("6 6 Q T 2", "Pair of Sixes"),
-    ("6 6 Q 9 8", "Pair of Sixes"),
-    ("6 6 Q 9 7", "Pair of Sixes"),
-    ("6 6 Q 9 5", "Pair of Sixes"),
-    ("6 6 Q 9 4", "Pair of Sixes"),
-    ("6 6 Q 9 3", "Pair of Sixes"),
-    ("6 6 Q 9 2", "Pair of Sixes"),
-    ("6 6 Q 8 7", "Pair of Sixes"),
-    ("6 6 Q 8 5", "Pair of Sixes"),
-    ("6 6 Q 8 4", "Pair of Sixes"),
-    ("6 6 Q 8 3", "Pair of Sixes"),
-    ("6 6 Q 8 2", "Pair of Sixes"),
-    ("6 6 Q 7 5", "Pair of Sixes"),
-    ("6 6 Q 7 4", "Pair of Sixes"),
-    ("6 6 Q 7 3", "Pair of Sixes"),
-    ("6 6 Q 7 2", "Pair of Sixes"),
-    ("6 6 Q 5 4", "Pair of Sixes"),
-    ("6 6 Q 5 3", "Pair of Sixes"),
-    ("6 6 Q 5 2", "Pair of Sixes"),
-    ("6 6 Q 4 3", "Pair of Sixes"),
-    ("6 6 Q 4 2", "Pair of Sixes"),
-    ("6 6 Q 3 2", "Pair of Sixes"),
-    ("6 6 J T 9", "Pair of Sixes"),
-    ("6 6 J T 8", "Pair of Sixes"),
-    ("6 6 J T 7", "Pair of Sixes"),
-    ("6 6 J T 5", "Pair of Sixes"),
-    ("6 6 J T 4", "Pair of Sixes"),
-    ("6 6 J T 3", "Pair of Sixes"),
-    ("6 6 J T 2", "Pair of Sixes"),
-    ("6 6 J 9 8", "Pair of Sixes"),
-    ("6 6 J 9 7", "Pair of Sixes"),
-    ("6 6 J 9 5", "Pair of Sixes"),
-    ("6 6 J 9 4", "Pair of Sixes"),
-    ("6 6 J 9 3", "Pair of Sixes"),
-    ("6 6 J 9 2", "Pair of Sixes"),
-    ("6 6 J 8 7", "Pair of Sixes"),
-    ("6 6 J 8 5", "Pair of Sixes"),
-    ("6 6 J 8 4", "Pair of Sixes"),
-    ("6 6 J 8 3", "Pair of Sixes"),
-    ("6 6 J 8 2", "Pair of Sixes"),
-    ("6 6 J 7 5", "Pair of Sixes"),
-    ("6 6 J 7 4", "Pair of Sixes"),
-    ("6 6 J 7 3", "Pair of Sixes"),
-    ("6 6 J 7 2", "Pair of Sixes"),
-    ("6 6 J 5 4", "Pair of Sixes"),
-    ("6 6 J 5 3", "Pair of Sixes"),
-    ("6 6 J 5 2", "Pair of Sixes"),
-    ("6 6 J 4 3", "Pair of Sixes"),
-    ("6 6 J 4 2", "Pair of Sixes"),
-    ("6 6 J 3 2", "Pair of Sixes"),
-    ("6 6 T 9 8", "Pair of Sixes"),
-    ("6 6 T 9 7", "Pair of Sixes"),
-    ("6 6 T 9 5", "Pair of Sixes"),
-    ("6 6 T 9 4", "Pair of Sixes"),
-    ("6 6 T 9 3", "Pair of Sixes"),
-    ("6 6 T 9 2", "Pair of Sixes"),
-    ("6 6 T 8 7", "Pair of Sixes"),
-    ("6 6 T 8 5", "Pair of Sixes"),
-    ("6 6 T 8 4", "Pair of Sixes"),
-    ("6 6 T 8 3", "Pair of Sixes"),
-    ("6 6 T 8 2", "Pair of Sixes"),
-    ("6 6 T 7 5", "Pair of Sixes"),
-    ("6 6 T 7 4", "Pair of Sixes"),
-    ("6 6 T 7 3", "Pair of Sixes"),
-    ("6 6 T 7 2", "Pair of Sixes"),
-    ("6 6 T 5 4", "Pair of Sixes"),
-    ("6 6 T 5 3", "Pair of Sixes"),
-    ("6 6 T 5 2", "Pair of Sixes"),
-    ("6 6 T 4 3", "Pair of Sixes"),
-    ("6 6 T 4 2", "Pair of Sixes"),
-    ("6 6 T 3 2", "Pair of Sixes"),
-    ("6 6 9 8 7", "Pair of Sixes"),
-    ("6 6 9 8 5", "Pair of Sixes"),
-    ("6 6 9 8 4", "Pair of Sixes"),
-    ("6 6 9 8 3", "Pair of Sixes"),
-    ("6 6 9 8 2", "Pair of Sixes"),
-    ("6 6 9 7 5", "Pair of Sixes"),
-    ("6 6 9 7 4", "Pair of Sixes"),
-    ("6 6 9 7 3", "Pair of Sixes"),
-    ("6 6 9 7 2", "Pair of Sixes"),
-    ("6 6 9 5 4", "Pair of Sixes"),
-    ("6 6 9 5 3", "Pair of Sixes"),
-    ("6 6 9 5 2", "Pair of Sixes"),
-    ("6 6 9 4 3", "Pair of Sixes"),
-    ("6 6 9 4 2", "Pair of Sixes"),
-    ("6 6 9 3 2", "Pair of Sixes"),
-    ("6 6 8 7 5", "Pair of Sixes"),
-    ("6 6 8 7 4", "Pair of Sixes"),
-    ("6 6 8 7 3", "Pair of Sixes"),
-    ("6 6 8 7 2", "Pair of Sixes"),
-    ("6 6 8 5 4", "Pair of Sixes"),
-    ("6 6 8 5 3", "Pair of Sixes"),
-    ("6 6 8 5 2", "Pair of Sixes"),
-    ("6 6 8 4 3", "Pair of Sixes"),
-    ("6 6 8 4 2", "Pair of Sixes"),
-    ("6 6 8 3 2", "Pair of Sixes"),
-    ("6 6 7 5 4", "Pair of Sixes"),
-    ("6 6 7 5 3", "Pair of Sixes"),
-    ("6 6 7 5 2", "Pair of Sixes"),
-    ("6 6 7 4 3", "Pair of Sixes"),
-    ("6 6 7 4 2", "Pair of Sixes"),
-    ("6 6 7 3 2", "Pair of Sixes"),
-    ("6 6 5 4 3", "Pair of Sixes"),
-    ("6 6 5 4 2", "Pair of Sixes"),
-    ("6 6 5 3 2", "Pair of Sixes"),
-    ("6 6 4 3 2", "Pair of Sixes"),
-    ("5 5 A K Q", "Pair of Fives"),
-    ("5 5 A K J", "Pair of Fives"),
-    ("5 5 A K T", "Pair of Fives"),
-    ("5 5 A K 9", "Pair of Fives"),
-    ("5 5 A K 8", "Pair of Fives"),
-    ("5 5 A K 7", "Pair of Fives"),
-    ("5 5 A K 6", "Pair of Fives"),
-    ("5 5 A K 4", "Pair of Fives"),
-    ("5 5 A K 3", "Pair of Fives"),
-    ("5 5 A K 2", "Pair of Fives"),
-    ("5 5 A Q J", "Pair of Fives"),
-    ("5 5 A Q T", "Pair of Fives"),
-    ("5 5 A Q 9", "Pair of Fives"),
-    ("5 5 A Q 8", "Pair of Fives"),
-    ("5 5 A Q 7", "Pair of Fives"),
-    ("5 5 A Q 6", "Pair of Fives"),
-    ("5 5 A Q 4", "Pair of Fives"),
-    ("5 5 A Q 3", "Pair of Fives"),
-    ("5 5 A Q 2", "Pair of Fives"),
-    ("5 5 A J T", "Pair of Fives"),
-    ("5 5 A J 9", "Pair of Fives"),
-    ("5 5 A J 8", "Pair of Fives"),
-    ("5 5 A J 7", "Pair of Fives"),
-    ("5 5 A J 6", "Pair of Fives"),
-    ("5 5 A J 4", "Pair of Fives"),
-    ("5 5 A J 3", "Pair of Fives"),
-    ("5 5 A J 2", "Pair of Fives"),
-    ("5 5 A T 9", "Pair of Fives"),
-    ("5 5 A T 8", "Pair of Fives"),
-    ("5 5 A T 7", "Pair of Fives"),
-    ("5 5 A T 6", "Pair of Fives"),
-    ("5 5 A T 4", "Pair of Fives"),
-    ("5 5 A T 3", "Pair of Fives"),
-    ("5 5 A T 2", "Pair of Fives"),
-    ("5 5 A 9 8", "Pair of Fives"),
-    ("5 5 A 9 7", "Pair of Fives"),
-    ("5 5 A 9 6", "Pair of Fives"),
-    ("5 5 A 9 4", "Pair of Fives"),
-    ("5 5 A 9 3", "Pair of Fives"),
-    ("5 5 A 9 2", "Pair of Fives"),
-    ("5 5 A 8 7", "Pair of Fives"),
-    ("5 5 A 8 6", "Pair of Fives"),
-    ("5 5 A 8 4", "Pair of Fives"),
-    ("5 5 A 8 3", "Pair of Fives"),
-    ("5 5 A 8 2", "Pair of Fives"),
-    ("5 5 A 7 6", "Pair of Fives"),
-    ("5 5 A 7 4", "Pair of Fives"),
-    ("5 5 A 7 3", "Pair of Fives"),
-    ("5 5 A 7 2", "Pair of Fives"),
-    ("5 5 A 6 4", "Pair of Fives"),
-    ("5 5 A 6 3", "Pair of Fives"),
-    ("5 5 A 6 2", "Pair of Fives"),
-    ("5 5 A 4 3", "Pair of Fives"),
-    ("5 5 A 4 2", "Pair of Fives"),
-    ("5 5 A 3 2", "Pair of Fives"),
-    ("5 5 K Q J", "Pair of Fives"),
-    ("5 5 K Q T", "Pair of Fives"),
-    ("5 5 K Q 9", "Pair of Fives"),
-    ("5 5 K Q 8", "Pair of Fives"),
-    ("5 5 K Q 7", "Pair of Fives"),
-    ("5 5 K Q 6", "Pair of Fives"),
-    ("5 5 K Q 4", "Pair of Fives"),
-    ("5 5 K Q 3", "Pair of Fives"),
-    ("5 5 K Q 2", "Pair of Fives"),
-    ("5 5 K J T", "Pair of Fives"),
-    ("5 5 K J 9", "Pair of Fives"),
-    ("5 5 K J 8", "Pair of Fives"),
-    ("5 5 K J 7", "Pair of Fives"),
-    ("5 5 K J 6", "Pair of Fives"),
-    ("5 5 K J 4", "Pair of Fives"),
-    ("5 5 K J 3", "Pair of Fives"),
-    ("5 5 K J 2", "Pair of Fives"),
-    ("5 5 K T 9", "Pair of Fives"),
-    ("5 5 K T 8", "Pair of Fives"),
-    ("5 5 K T 7", "Pair of Fives"),
-    ("5 5 K T 6", "Pair of Fives"),
-    ("5 5 K T 4", "Pair of Fives"),
-    ("5 5 K T 3", "Pair of Fives"),
-    ("5 5 K T 2", "Pair of Fives"),
-    ("5 5 K 9 8", "Pair of Fives"),
-    ("5 5 K 9 7", "Pair of Fives"),
-    ("5 5 K 9 6", "Pair of Fives"),
-    ("5 5 K 9 4", "Pair of Fives"),
-    ("5 5 K 9 3", "Pair of Fives"),
-    ("5 5 K 9 2", "Pair of Fives"),
-    ("5 5 K 8 7", "Pair of Fives"),
-    ("5 5 K 8 6", "Pair of Fives"),
-    ("5 5 K 8 4", "Pair of Fives"),
-    ("5 5 K 8 3", "Pair of Fives"),
-    ("5 5 K 8 2", "Pair of Fives"),
-    ("5 5 K 7 6", "Pair of Fives"),
-    ("5 5 K 7 4", "Pair of Fives"),
-    ("5 5 K 7 3", "Pair of Fives"),
-    ("5 5 K 7 2", "Pair of Fives"),
-    ("5 5 K 6 4", "Pair of Fives"),
-    ("5 5 K 6 3", "Pair of Fives"),
-    ("5 5 K 6 2", "Pair of Fives"),
-    ("5 5 K 4 3", "Pair of Fives"),
-    ("5 5 K 4 2", "Pair of Fives"),
-    ("5 5 K 3 2", "Pair of Fives"),
-    ("5 5 Q J T", "Pair of Fives"),
-    ("5 5 Q J 9", "Pair of Fives"),
-    ("5 5 Q J 8", "Pair of Fives"),
-    ("5 5 Q J 7", "Pair of Fives"),
-    ("5 5 Q J 6", "Pair of Fives"),
-    ("5 5 Q J 4", "Pair of Fives"),
-    ("5 5 Q J 3", "Pair of Fives"),
-    ("5 5 Q J 2", "Pair of Fives"),
-    ("5 5 Q T 9", "Pair of Fives"),
-    ("5 5 Q T 8", "Pair of Fives"),
-    ("5 5 Q T 7", "Pair of Fives"),
-    ("5 5 Q T 6", "Pair of Fives"),
-    ("5 5 Q T 4", "Pair of Fives"),
-    ("5 5 Q T 3", "Pair of Fives"),
-    ("5 5 Q T 2", "Pair of Fives"),
-    ("5 5 Q 9 8", "Pair of Fives"),
-    ("5 5 Q 9 7", "Pair of Fives"),
-    ("5 5 Q 9 6", "Pair of Fives"),
-    ("5 5 Q 9 4", "Pair of Fives"),
-    ("5 5 Q 9 3", "Pair of Fives"),
-    ("5 5 Q 9 2", "Pair of Fives"),
-    ("5 5 Q 8 7", "Pair of Fives"),
-    ("5 5 Q 8 6", "Pair of Fives"),
-    ("5 5 Q 8 4", "Pair of Fives"),
-    ("5 5 Q 8 3", "Pair of Fives"),
-    ("5 5 Q 8 2", "Pair of Fives"),
-    ("5 5 Q 7 6", "Pair of Fives"),
-    ("5 5 Q 7 4", "Pair of Fives"),
-    ("5 5 Q 7 3", "Pair of Fives"),
-    ("5 5 Q 7 2", "Pair of Fives"),
-    ("5 5 Q 6 4", "Pair of Fives"),
-    ("5 5 Q 6 3", "Pair of Fives"),
-    ("5 5 Q 6 2", "Pair of Fives"),
-    ("5 5 Q 4 3", "Pair of Fives"),
-    ("5 5 Q 4 2", "Pair of Fives"),
-    ("5 5 Q 3 2", "Pair of Fives"),
-    ("5 5 J T 9", "Pair of Fives"),
-    ("5 5 J T 8", "Pair of Fives"),
-    ("5 5 J T 7", "Pair of Fives"),
-    ("5 5 J T 6", "Pair of Fives"),
-    ("5 5 J T 4", "Pair of Fives"),
-    ("5 5 J T 3", "Pair of Fives"),
-    ("5 5 J T 2", "Pair of Fives"),
-    ("5 5 J 9 8", "Pair of Fives"),
-    ("5 5 J 9 7", "Pair of Fives"),
-    ("5 5 J 9 6", "Pair of Fives"),
-    ("5 5 J 9 4", "Pair of Fives"),
-    ("5 5 J 9 3", "Pair of Fives"),
-    ("5 5 J 9 2", "Pair of Fives"),
-    ("5 5 J 8 7", "Pair of Fives"),
-    ("5 5 J 8 6", "Pair of Fives"),
-    ("5 5 J 8 4", "Pair of Fives"),
-    ("5 5 J 8 3", "Pair of Fives"),
-    ("5 5 J 8 2", "Pair of Fives"),
-    ("5 5 J 7 6", "Pair of Fives"),
-    ("5 5 J 7 4", "Pair of Fives"),
-    ("5 5 J 7 3", "Pair of Fives"),
-    ("5 5 J 7 2", "Pair of Fives"),
-    ("5 5 J 6 4", "Pair of Fives"),
-    ("5 5 J 6 3", "Pair of Fives"),
-    ("5 5 J 6 2", "Pair of Fives"),
-    ("5 5 J 4 3", "Pair of Fives"),
-    ("5 5 J 4 2", "Pair of Fives"),
-    ("5 5 J 3 2", "Pair of Fives"),
-    ("5 5 T 9 8", "Pair of Fives"),
-    ("5 5 T 9 7", "Pair of Fives"),
-    ("5 5 T 9 6", "Pair of Fives"),
-    ("5 5 T 9 4", "Pair of Fives"),
-    ("5 5 T 9 3", "Pair of Fives"),
-    ("5 5 T 9 2", "Pair of Fives"),
-    ("5 5 T 8 7", "Pair of Fives"),
-    ("5 5 T 8 6", "Pair of Fives"),
-    ("5 5 T 8 4", "Pair of Fives"),
-    ("5 5 T 8 3", "Pair of Fives"),
-    ("5 5 T 8 2", "Pair of Fives"),
-    ("5 5 T 7 6", "Pair of Fives"),
-    ("5 5 T 7 4", "Pair of Fives"),
-    ("5 5 T 7 3", "Pair of Fives"),
-    ("5 5 T 7 2", "Pair of Fives"),
-    ("5 5 T 6 4", "Pair of Fives"),
-    ("5 5 T 6 3", "Pair of Fives"),
-    ("5 5 T 6 2", "Pair of Fives"),
-    ("5 5 T 4 3", "Pair of Fives"),
-    ("5 5 T 4 2", "Pair of Fives"),
-    ("5 5 T 3 2", "Pair of Fives"),
-    ("5 5 9 8 7", "Pair of Fives"),
-    ("5 5 9 8 6", "Pair of Fives"),
-    ("5 5 9 8 4", "Pair of Fives"),
-    ("5 5 9 8 3", "Pair of Fives"),
-    ("5 5 9 8 2", "Pair of Fives"),
-    ("5 5 9 7 6", "Pair of Fives"),
-    ("5 5 9 7 4", "Pair of Fives"),
-    ("5 5 9 7 3", "Pair of Fives"),
-    ("5 5 9 7 2", "Pair of Fives"),
-    ("5 5 9 6 4", "Pair of Fives"),
-    ("5 5 9 6 3", "Pair of Fives"),
-    ("5 5 9 6 2", "Pair of Fives"),
-    ("5 5 9 4 3", "Pair of Fives"),
-    ("5 5 9 4 2", "Pair of Fives"),
-    ("5 5 9 3 2", "Pair of Fives"),
-    ("5 5 8 7 6", "Pair of Fives"),
-    ("5 5 8 7 4", "Pair of Fives"),
-    ("5 5 8 7 3", "Pair of Fives"),
-    ("5 5 8 7 2", "Pair of Fives"),
-    ("5 5 8 6 4", "Pair of Fives"),
-    ("5 5 8 6 3", "Pair of Fives"),
-    ("5 5 8 6 2", "Pair of Fives"),
-    ("5 5 8 4 3", "Pair of Fives"),
-    ("5 5 8 4 2", "Pair of Fives"),
-    ("5 5 8 3 2", "Pair of Fives"),
-    ("5 5 7 6 4", "Pair of Fives"),
-    ("5 5 7 6 3", "Pair of Fives"),
-    ("5 5 7 6 2", "Pair of Fives"),
-    ("5 5 7 4 3", "Pair of Fives"),
-    ("5 5 7 4 2", "Pair of Fives"),
-    ("5 5 7 3 2", "Pair of Fives"),
-    ("5 5 6 4 3", "Pair of Fives"),
-    ("5 5 6 4 2", "Pair of Fives"),
-    ("5 5 6 3 2", "Pair of Fives"),
-    ("5 5 4 3 2", "Pair of Fives"),
-    ("4 4 A K Q", "Pair of Fours"),
-    ("4 4 A K J", "Pair of Fours"),
-    ("4 4 A K T", "Pair of Fours"),
-    ("4 4 A K 9", "Pair of Fours"),
-    ("4 4 A K 8", "Pair of Fours"),
-    ("4 4 A K 7", "Pair of Fours"),
-    ("4 4 A K 6", "Pair of Fours"),
-    ("4 4 A K 5", "Pair of Fours"),
-    ("4 4 A K 3", "Pair of Fours"),
-    ("4 4 A K 2", "Pair of Fours"),
-    ("4 4 A Q J", "Pair of Fours"),
-    ("4 4 A Q T", "Pair of Fours"),
-    ("4 4 A Q 9", "Pair of Fours"),
-    ("4 4 A Q 8", "Pair of Fours"),
-    ("4 4 A Q 7", "Pair of Fours"),
-    ("4 4 A Q 6", "Pair of Fours"),
-    ("4 4 A Q 5", "Pair of Fours"),
-    ("4 4 A Q 3", "Pair of Fours"),
-    ("4 4 A Q 2", "Pair of Fours"),
-    ("4 4 A J T", "Pair of Fours"),
-    ("4 4 A J 9", "Pair of Fours"),
-    ("4 4 A J 8", "Pair of Fours"),
-    ("4 4 A J 7", "Pair of Fours"),
-    ("4 4 A J 6", "Pair of Fours"),
-    ("4 4 A J 5", "Pair of Fours"),
-    ("4 4 A J 3", "Pair of Fours"),
-    ("4 4 A J 2", "Pair of Fours"),
-    ("4 4 A T 9", "Pair of Fours"),
-    ("4 4 A T 8", "Pair of Fours"),
-    ("4 4 A T 7", "Pair of Fours"),
-    ("4 4 A T 6", "Pair of Fours"),
-    ("4 4 A T 5", "Pair of Fours"),
-    ("4 4 A T 3", "Pair of Fours"),
-    ("4 4 A T 2", "Pair of Fours"),
-    ("4 4 A 9 8", "Pair of Fours"),
-    ("4 4 A 9 7", "Pair of Fours"),
-    ("4 4 A 9 6", "Pair of Fours"),
-    ("4 4 A 9 5", "Pair of Fours"),
-    ("4 4 A 9 3", "Pair of Fours"),
-    ("4 4 A 9 2", "Pair of Fours"),
-    ("4 4 A 8 7", "Pair of Fours"),
-    ("4 4 A 8 6", "Pair of Fours"),
-    ("4 4 A 8 5", "Pair of Fours"),
-    ("4 4 A 8 3", "Pair of Fours"),
-    ("4 4 A 8 2", "Pair of Fours"),
-    ("4 4 A 7 6", "Pair of Fours"),
-    ("4 4 A 7 5", "Pair of Fours"),
-    ("4 4 A 7 3", "Pair of Fours"),
-    ("4 4 A 7 2", "Pair of Fours"),
-    ("4 4 A 6 5", "Pair of Fours"),
-    ("4 4 A 6 3", "Pair of Fours"),
-    ("4 4 A 6 2", "Pair of Fours"),
-    ("4 4 A 5 3", "Pair of Fours"),
-    ("4 4 A 5 2", "Pair of Fours"),
-    ("4 4 A 3 2", "Pair of Fours"),
-    ("4 4 K Q J", "Pair of Fours"),
-    ("4 4 K Q T", "Pair of Fours"),
-    ("4 4 K Q 9", "Pair of Fours"),
-    ("4 4 K Q 8", "Pair of Fours"),
-    ("4 4 K Q 7", "Pair of Fours"),
-    ("4 4 K Q 6", "Pair of Fours"),
-    ("4 4 K Q 5", "Pair of Fours"),
-    ("4 4 K Q 3", "Pair of Fours"),
-    ("4 4 K Q 2", "Pair of Fours"),
-    ("4 4 K J T", "Pair of Fours"),
-    ("4 4 K J 9", "Pair of Fours"),
-    ("4 4 K J 8", "Pair of Fours"),
-    ("4 4 K J 7", "Pair of Fours"),
-    ("4 4 K J 6", "Pair of Fours"),
-    ("4 4 K J 5", "Pair of Fours"),
-    ("4 4 K J 3", "Pair of Fours"),
-    ("4 4 K J 2", "Pair of Fours"),
-    ("4 4 K T 9", "Pair of Fours"),
-    ("4 4 K T 8", "Pair of Fours"),
-    ("4 4 K T 7", "Pair of Fours"),
-    ("4 4 K T 6", "Pair of Fours"),
-    ("4 4 K T 5", "Pair of Fours"),
-    ("4 4 K T 3", "Pair of Fours"),
-    ("4 4 K T 2", "Pair of Fours"),
-    ("4 4 K 9 8", "Pair of Fours"),
-    ("4 4 K 9 7", "Pair of Fours"),
-    ("4 4 K 9 6", "Pair of Fours"),
-    ("4 4 K 9 5", "Pair of Fours"),
-    ("4 4 K 9 3", "Pair of Fours"),
-    ("4 4 K 9 2", "Pair of Fours"),
-    ("4 4 K 8 7", "Pair of Fours"),
-    ("4 4 K 8 6", "Pair of Fours"),
-    ("4 4 K 8 5", "Pair of Fours"),
-    ("4 4 K 8 3", "Pair of Fours"),
-    ("4 4 K 8 2", "Pair of Fours"),
-    ("4 4 K 7 6", "Pair of Fours"),
-    ("4 4 K 7 5", "Pair of Fours"),
-    ("4 4 K 7 3", "Pair of Fours"),
-    ("4 4 K 7 2", "Pair of Fours"),
-    ("4 4 K 6 5", "Pair of Fours"),
-    ("4 4 K 6 3", "Pair of Fours"),
-    ("4 4 K 6 2", "Pair of Fours"),
-    ("4 4 K 5 3", "Pair of Fours"),
-    ("4 4 K 5 2", "Pair of Fours"),
-    ("4 4 K 3 2", "Pair of Fours"),
-    ("4 4 Q J T", "Pair of Fours"),
-    ("4 4 Q J 9", "Pair of Fours"),
-    ("4 4 Q J 8", "Pair of Fours"),
-    ("4 4 Q J 7", "Pair of Fours"),
-    ("4 4 Q J 6", "Pair of Fours"),
-    ("4 4 Q J 5", "Pair of Fours"),
-    ("4 4 Q J 3", "Pair of Fours"),
-    ("4 4 Q J 2", "Pair of Fours"),
-    ("4 4 Q T 9", "Pair of Fours"),
-    ("4 4 Q T 8", "Pair of Fours"),
-    ("4 4 Q T 7", "Pair of Fours"),
-    ("4 4 Q T 6", "Pair of Fours"),
-    ("4 4 Q T 5", "Pair of Fours"),
-    ("4 4 Q T 3", "Pair of Fours"),
-    ("4 4 Q T 2", "Pair of Fours"),
-    ("4 4 Q 9 8", "Pair of Fours"),
-    ("4 4 Q 9 7", "Pair of Fours"),
-    ("4 4 Q 9 6", "Pair of Fours"),
-    ("4 4 Q 9 5", "Pair of Fours"),
-    ("4 4 Q 9 3", "Pair of Fours"),
-    ("4 4 Q 9 2", "Pair of Fours"),
-    ("4 4 Q 8 7", "Pair of Fours"),
-    ("4 4 Q 8 6", "Pair of Fours"),
-    ("4 4 Q 8 5", "Pair of Fours"),
-    ("4 4 Q 8 3", "Pair of Fours"),
-    ("4 4 Q 8 2", "Pair of Fours"),
-    ("4 4 Q 7 6", "Pair of Fours"),
-    ("4 4 Q 7 5", "Pair of Fours"),
-    ("4 4 Q 7 3", "Pair of Fours"),
-    ("4 4 Q 7 2", "Pair of Fours"),
-    ("4 4 Q 6 5", "Pair of Fours"),
-    ("4 4 Q 6 3", "Pair of Fours"),
-    ("4 4 Q 6 2", "Pair of Fours"),
-    ("4 4 Q 5 3", "Pair of Fours"),
-    ("4 4 Q 5 2", "Pair of Fours"),
-    ("4 4 Q 3 2", "Pair of Fours"),
-    ("4 4 J T 9", "Pair of Fours"),
-    ("4 4 J T 8", "Pair of Fours"),
-    ("4 4 J T 7", "Pair of Fours"),
-    ("4 4 J T 6", "Pair of Fours"),
-    ("4 4 J T 5", "Pair of Fours"),
-    ("4 4 J T 3", "Pair of Fours"),
-    ("4 4 J T 2", "Pair of Fours"),
-    ("4 4 J 9 8", "Pair of Fours"),
-    ("4 4 J 9 7", "Pair of Fours"),
-    ("4 4 J 9 6", "Pair of Fours"),
-    ("4 4 J 9 5", "Pair of Fours"),
-    ("4 4 J 9 3", "Pair of Fours"),
-    ("4 4 J 9 2", "Pair of Fours"),
-    ("4 4 J 8 7", "Pair of Fours"),
-    ("4 4 J 8 6", "Pair of Fours"),
-    ("4 4 J 8 5", "Pair of Fours"),
-    ("4 4 J 8 3", "Pair of Fours"),
-    ("4 4 J 8 2", "Pair of Fours"),
-    ("4 4 J 7 6", "Pair of Fours"),
-    ("4 4 J 7 5", "Pair of Fours"),
-    ("4 4 J 7 3", "Pair of Fours"),
-    ("4 4 J 7 2", "Pair of Fours"),
-    ("4 4 J 6 5", "Pair of Fours"),
-    ("4 4 J 6 3", "Pair of Fours"),
-    ("4 4 J 6 2", "Pair of Fours"),
-    ("4 4 J 5 3", "Pair of Fours"),
-    ("4 4 J 5 2", "Pair of Fours"),
-    ("4 4 J 3 2", "Pair of Fours"),
-    ("4 4 T 9 8", "Pair of Fours"),
-    ("4 4 T 9 7", "Pair of Fours"),
-    ("4 4 T 9 6", "Pair of Fours"),
-    ("4 4 T 9 5", "Pair of Fours"),
-    ("4 4 T 9 3", "Pair of Fours"),
-    ("4 4 T 9 2", "Pair of Fours"),
-    ("4 4 T 8 7", "Pair of Fours"),
-    ("4 4 T 8 6", "Pair of Fours"),
-    ("4 4 T 8 5", "Pair of Fours"),
-    ("4 4 T 8 3", "Pair of Fours"),
-    ("4 4 T 8 2", "Pair of Fours"),
-    ("4 4 T 7 6", "Pair of Fours"),
-    ("4 4 T 7 5", "Pair of Fours"),
-    ("4 4 T 7 3", "Pair of Fours"),
-    ("4 4 T 7 2", "Pair of Fours"),
-    ("4 4 T 6 5", "Pair of Fours"),
-    ("4 4 T 6 3", "Pair of Fours"),
-    ("4 4 T 6 2", "Pair of Fours"),
-    ("4 4 T 5 3", "Pair of Fours"),
-    ("4 4 T 5 2", "Pair of Fours"),
-    ("4 4 T 3 2", "Pair of Fours"),
-    ("4 4 9 8 7", "Pair of Fours"),
-    ("4 4 9 8 6", "Pair of Fours"),
-    ("4 4 9 8 5", "Pair of Fours"),
-    ("4 4 9 8 3", "Pair of Fours"),
-    ("4 4 9 8 2", "Pair of Fours"),
-    ("4 4 9 7 6", "Pair of Fours"),
-    ("4 4 9 7 5", "Pair of Fours"),
-    ("4 4 9 7 3", "Pair of Fours"),
-    ("4 4 9 7 2", "Pair of Fours"),
-    ("4 4 9 6 5", "Pair of Fours"),
-    ("4 4 9 6 3", "Pair of Fours"),
-    ("4 4 9 6 2", "Pair of Fours"),
-    ("4 4 9 5 3", "Pair of Fours"),
-    ("4 4 9 5 2", "Pair of Fours"),
-    ("4 4 9 3 2", "Pair of Fours"),
-    ("4 4 8 7 6", "Pair of Fours"),
-    ("4 4 8 7 5", "Pair of Fours"),
-    ("4 4 8 7 3", "Pair of Fours"),
-    ("4 4 8 7 2", "Pair of Fours"),
-    ("4 4 8 6 5", "Pair of Fours"),
-    ("4 4 8 6 3", "Pair of Fours"),
-    ("4 4 8 6 2", "Pair of Fours"),
-    ("4 4 8 5 3", "Pair of Fours"),
-    ("4 4 8 5 2", "Pair of Fours"),
-    ("4 4 8 3 2", "Pair of Fours"),
-    ("4 4 7 6 5", "Pair of Fours"),
-    ("4 4 7 6 3", "Pair of Fours"),
-    ("4 4 7 6 2", "Pair of Fours"),
-    ("4 4 7 5 3", "Pair of Fours"),
-    ("4 4 7 5 2", "Pair of Fours"),
-    ("4 4 7 3 2", "Pair of Fours"),
-    ("4 4 6 5 3", "Pair of Fours"),
-    ("4 4 6 5 2", "Pair of Fours"),
-    ("4 4 6 3 2", "Pair of Fours"),
-    ("4 4 5 3 2", "Pair of Fours"),
-    ("3 3 A K Q", "Pair of Treys"),
-    ("3 3 A K J", "Pair of Treys"),
-    ("3 3 A K T", "Pair of Treys"),
-    ("3 3 A K 9", "Pair of Treys"),
-    ("3 3 A K 8", "Pair of Treys"),
-    ("3 3 A K 7", "Pair of Treys"),
-    ("3 3 A K 6", "Pair of Treys"),
-    ("3 3 A K 5", "Pair of Treys"),
-    ("3 3 A K 4", "Pair of Treys"),
-    ("3 3 A K 2", "Pair of Treys"),
-    ("3 3 A Q J", "Pair of Treys"),
-    ("3 3 A Q T", "Pair of Treys"),
-    ("3 3 A Q 9", "Pair of Treys"),
-    ("3 3 A Q 8", "Pair of Treys"),
-    ("3 3 A Q 7", "Pair of Treys"),
-    ("3 3 A Q 6", "Pair of Treys"),
-    ("3 3 A Q 5", "Pair of Treys"),
-    ("3 3 A Q 4", "Pair of Treys"),
-    ("3 3 A Q 2", "Pair of Treys"),
-    ("3 3 A J T", "Pair of Treys"),
-    ("3 3 A J 9", "Pair of Treys"),
-    ("3 3 A J 8", "Pair of Treys"),
-    ("3 3 A J 7", "Pair of Treys"),
-    ("3 3 A J 6", "Pair of Treys"),
-    ("3 3 A J 5", "Pair of Treys"),
-    ("3 3 A J 4", "Pair of Treys"),
-    ("3 3 A J 2", "Pair of Treys"),
-    ("3 3 A T 9", "Pair of Treys"),
-    ("3 3 A T 8", "Pair of Treys"),
-    ("3 3 A T 7", "Pair of Treys"),
-    ("3 3 A T 6", "Pair of Treys"),
-    ("3 3 A T 5", "Pair of Treys"),
-    ("3 3 A T 4", "Pair of Treys"),
-    ("3 3 A T 2", "Pair of Treys"),
-    ("3 3 A 9 8", "Pair of Treys"),
-    ("3 3 A 9 7", "Pair of Treys"),
-    ("3 3 A 9 6", "Pair of Treys"),
-    ("3 3 A 9 5", "Pair of Treys"),
-    ("3 3 A 9 4", "Pair of Treys"),
-    ("3 3 A 9 2", "Pair of Treys"),
-    ("3 3 A 8 7", "Pair of Treys"),
-    ("3 3 A 8 6", "Pair of Treys"),
-    ("3 3 A 8 5", "Pair of Treys"),
-    ("3 3 A 8 4", "Pair of Treys"),
-    ("3 3 A 8 2", "Pair of Treys"),
-    ("3 3 A 7 6", "Pair of Treys"),
-    ("3 3 A 7 5", "Pair of Treys"),
-    ("3 3 A 7 4", "Pair of Treys"),
-    ("3 3 A 7 2", "Pair of Treys"),
-    ("3 3 A 6 5", "Pair of Treys"),
-    ("3 3 A 6 4", "Pair of Treys"),
-    ("3 3 A 6 2", "Pair of Treys"),
-    ("3 3 A 5 4", "Pair of Treys"),
-    ("3 3 A 5 2", "Pair of Treys"),
-    ("3 3 A 4 2", "Pair of Treys"),
-    ("3 3 K Q J", "Pair of Treys"),
-    ("3 3 K Q T", "Pair of Treys"),
-    ("3 3 K Q 9", "Pair of Treys"),
-    ("3 3 K Q 8", "Pair of Treys"),
-    ("3 3 K Q 7", "Pair of Treys"),
-    ("3 3 K Q 6", "Pair of Treys"),
-    ("3 3 K Q 5", "Pair of Treys"),
-    ("3 3 K Q 4", "Pair of Treys"),
-    ("3 3 K Q 2", "Pair of Treys"),
-    ("3 3 K J T", "Pair of Treys"),
-    ("3 3 K J 9", "Pair of Treys"),
-    ("3 3 K J 8", "Pair of Treys"),
-    ("3 3 K J 7", "Pair of Treys"),
-    ("3 3 K J 6", "Pair of Treys"),
-    ("3 3 K J 5", "Pair of Treys"),
-    ("3 3 K J 4", "Pair of Treys"),
-    ("3 3 K J 2", "Pair of Treys"),
-    ("3 3 K T 9", "Pair of Treys"),
-    ("3 3 K T 8", "Pair of Treys"),
-    ("3 3 K T 7", "Pair of Treys"),
-    ("3 3 K T 6", "Pair of Treys"),
-    ("3 3 K T 5", "Pair of Treys"),
-    ("3 3 K T 4", "Pair of Treys"),
-    ("3 3 K T 2", "Pair of Treys"),
-    ("3 3 K 9 8", "Pair of Treys"),
-    ("3 3 K 9 7", "Pair of Treys"),
-    ("3 3 K 9 6", "Pair of Treys"),
-    ("3 3 K 9 5", "Pair of Treys"),
-    ("3 3 K 9 4", "Pair of Treys"),
-    ("3 3 K 9 2", "Pair of Treys"),
-    ("3 3 K 8 7", "Pair of Treys"),
-    ("3 3 K 8 6", "Pair of Treys"),
-    ("3 3 K 8 5", "Pair of Treys"),
-    ("3 3 K 8 4", "Pair of Treys"),
-    ("3 3 K 8 2", "Pair of Treys"),
-    ("3 3 K 7 6", "Pair of Treys"),
-    ("3 3 K 7 5", "Pair of Treys"),
-    ("3 3 K 7 4", "Pair of Treys"),
-    ("3 3 K 7 2", "Pair of Treys"),
-    ("3 3 K 6 5", "Pair of Treys"),
-    ("3 3 K 6 4", "Pair of Treys"),
-    ("3 3 K 6 2", "Pair of Treys"),
-    ("3 3 K 5 4", "Pair of Treys"),
-    ("3 3 K 5 2", "Pair of Treys"),
-    ("3 3 K 4 2", "Pair of Treys"),
-    ("3 3 Q J T", "Pair of Treys"),
-    ("3 3 Q J 9", "Pair of Treys"),
-    ("3 3 Q J 8", "Pair of Treys"),
-    ("3 3 Q J 7", "Pair of Treys"),
-    ("3 3 Q J 6", "Pair of Treys"),
-    ("3 3 Q J 5", "Pair of Treys"),
-    ("3 3 Q J 4", "Pair of Treys"),
-    ("3 3 Q J 2", "Pair of Treys"),
-    ("3 3 Q T 9", "Pair of Treys"),
-    ("3 3 Q T 8", "Pair of Treys"),
-    ("3 3 Q T 7", "Pair of Treys"),
-    ("3 3 Q T 6", "Pair of Treys"),
-    ("3 3 Q T 5", "Pair of Treys"),
-    ("3 3 Q T 4", "Pair of Treys"),
-    ("3 3 Q T 2", "Pair of Treys"),
-    ("3 3 Q 9 8", "Pair of Treys"),
-    ("3 3 Q 9 7", "Pair of Treys"),
-    ("3 3 Q 9 6", "Pair of Treys"),
-    ("3 3 Q 9 5", "Pair of Treys"),
-    ("3 3 Q 9 4", "Pair of Treys"),
-    ("3 3 Q 9 2", "Pair of Treys"),
-    ("3 3 Q 8 7", "Pair of Treys"),
-    ("3 3 Q 8 6", "Pair of Treys"),
-    ("3 3 Q 8 5", "Pair of Treys"),
-    ("3 3 Q 8 4", "Pair of Treys"),
-    ("3 3 Q 8 2", "Pair of Treys"),
-    ("3 3 Q 7 6", "Pair of Treys"),
-    ("3 3 Q 7 5", "Pair of Treys"),
-    ("3 3 Q 7 4", "Pair of Treys"),
-    ("3 3 Q 7 2", "Pair of Treys"),
-    ("3 3 Q 6 5", "Pair of Treys"),
-    ("3 3 Q 6 4", "Pair of Treys"),
-    ("3 3 Q 6 2", "Pair of Treys"),
-    ("3 3 Q 5 4", "Pair of Treys"),
-    ("3 3 Q 5 2", "Pair of Treys"),
-    ("3 3 Q 4 2", "Pair of Treys"),
-    ("3 3 J T 9", "Pair of Treys"),
-    ("3 3 J T 8", "Pair of Treys"),
-    ("3 3 J T 7", "Pair of Treys"),
-    ("3 3 J T 6", "Pair of Treys"),
-    ("3 3 J T 5", "Pair of Treys"),
-    ("3 3 J T 4", "Pair of Treys"),
-    ("3 3 J T 2", "Pair of Treys"),
-    ("3 3 J 9 8", "Pair of Treys"),
-    ("3 3 J 9 7", "Pair of Treys"),
-    ("3 3 J 9 6", "Pair of Treys"),
-    ("3 3 J 9 5", "Pair of Treys"),
-    ("3 3 J 9 4", "Pair of Treys"),
-    ("3 3 J 9 2", "Pair of Treys"),
-    ("3 3 J 8 7", "Pair of Treys"),
-    ("3 3 J 8 6", "Pair of Treys"),
-    ("3 3 J 8 5", "Pair of Treys"),
-    ("3 3 J 8 4", "Pair of Treys"),
-    ("3 3 J 8 2", "Pair of Treys"),
-    ("3 3 J 7 6", "Pair of Treys"),
-    ("3 3 J 7 5", "Pair of Treys"),
-    ("3 3 J 7 4", "Pair of Treys"),
-    ("3 3 J 7 2", "Pair of Treys"),
-    ("3 3 J 6 5", "Pair of Treys"),
-    ("3 3 J 6 4", "Pair of Treys"),
-    ("3 3 J 6 2", "Pair of Treys"),
-    ("3 3 J 5 4", "Pair of Treys"),
-    ("3 3 J 5 2", "Pair of Treys"),
-    ("3 3 J 4 2", "Pair of Treys"),
-    ("3 3 T 9 8", "Pair of Treys"),
-    ("3 3 T 9 7", "Pair of Treys"),
-    ("3 3 T 9 6", "Pair of Treys"),
-    ("3 3 T 9 5", "Pair of Treys"),
-    ("3 3 T 9 4", "Pair of Treys"),
-    ("3 3 T 9 2", "Pair of Treys"),
-    ("3 3 T 8 7", "Pair of Treys"),
-    ("3 3 T 8 6", "Pair of Treys"),
-    ("3 3 T 8 5", "Pair of Treys"),
-    ("3 3 T 8 4", "Pair of Treys"),
-    ("3 3 T 8 2", "Pair of Treys"),
-    ("3 3 T 7 6", "Pair of Treys"),
-    ("3 3 T 7 5", "Pair of Treys"),
-    ("3 3 T 7 4", "Pair of Treys"),
-    ("3 3 T 7 2", "Pair of Treys"),
-    ("3 3 T 6 5", "Pair of Treys"),
-    ("3 3 T 6 4", "Pair of Treys"),
-    ("3 3 T 6 2", "Pair of Treys"),
-    ("3 3 T 5 4", "Pair of Treys"),
-    ("3 3 T 5 2", "Pair of Treys"),
-    ("3 3 T 4 2", "Pair of Treys"),
-    ("3 3 9 8 7", "Pair of Treys"),
-    ("3 3 9 8 6", "Pair of Treys"),
-    ("3 3 9 8 5", "Pair of Treys"),
-    ("3 3 9 8 4", "Pair of Treys"),
-    ("3 3 9 8 2", "Pair of Treys"),
-    ("3 3 9 7 6", "Pair of Treys"),
-    ("3 3 9 7 5", "Pair of Treys"),
-    ("3 3 9 7 4", "Pair of Treys"),
-    ("3 3 9 7 2", "Pair of Treys"),
-    ("3 3 9 6 5", "Pair of Treys"),
-    ("3 3 9 6 4", "Pair of Treys"),
-    ("3 3 9 6 2", "Pair of Treys"),
-    ("3 3 9 5 4", "Pair of Treys"),
-    ("3 3 9 5 2", "Pair of Treys"),
-    ("3 3 9 4 2", "Pair of Treys"),
-    ("3 3 8 7 6", "Pair of Treys"),
-    ("3 3 8 7 5", "Pair of Treys"),
-    ("3 3 8 7 4", "Pair of Treys"),
-    ("3 3 8 7 2", "Pair of Treys"),
-    ("3 3 8 6 5", "Pair of Treys"),
-    ("3 3 8 6 4", "Pair of Treys"),
-    ("3 3 8 6 2", "Pair of Treys"),
-    ("3 3 8 5 4", "Pair of Treys"),
-    ("3 3 8 5 2", "Pair of Treys"),
-    ("3 3 8 4 2", "Pair of Treys"),
-    ("3 3 7 6 5", "Pair of Treys"),
-    ("3 3 7 6 4", "Pair of Treys"),
-    ("3 3 7 6 2", "Pair of Treys"),
-    ("3 3 7 5 4", "Pair of Treys"),
-    ("3 3 7 5 2", "Pair of Treys"),
-    ("3 3 7 4 2", "Pair of Treys"),
-    ("3 3 6 5 4", "Pair of Treys"),
-    ("3 3 6 5 2", "Pair of Treys"),
-    ("3 3 6 4 2", "Pair of Treys"),
-    ("3 3 5 4 2", "Pair of Treys"),
-    ("2 2 A K Q", "Pair of Deuces"),
-    ("2 2 A K J", "Pair of Deuces"),
-    ("2 2 A K T", "Pair of Deuces"),
-    ("2 2 A K 9", "Pair of Deuces"),
-    ("2 2 A K 8", "Pair of Deuces"),
-    ("2 2 A K 7", "Pair of Deuces"),
-    ("2 2 A K 6", "Pair of Deuces"),
-    ("2 2 A K 5", "Pair of Deuces"),
-    ("2 2 A K 4", "Pair of Deuces"),
-    ("2 2 A K 3", "Pair of Deuces"),
-    ("2 2 A Q J", "Pair of Deuces"),
-    ("2 2 A Q T", "Pair of Deuces"),
-    ("2 2 A Q 9", "Pair of Deuces"),
-    ("2 2 A Q 8", "Pair of Deuces"),
-    ("2 2 A Q 7", "Pair of Deuces"),
-    ("2 2 A Q 6", "Pair of Deuces"),
-    ("2 2 A Q 5", "Pair of Deuces"),
-    ("2 2 A Q 4", "Pair of Deuces"),
-    ("2 2 A Q 3", "Pair of Deuces"),
-    ("2 2 A J T", "Pair of Deuces"),
-    ("2 2 A J 9", "Pair of Deuces"),
-    ("2 2 A J 8", "Pair of Deuces"),
-    ("2 2 A J 7", "Pair of Deuces"),
-    ("2 2 A J 6", "Pair of Deuces"),
-    ("2 2 A J 5", "Pair of Deuces"),
-    ("2 2 A J 4", "Pair of Deuces"),
-    ("2 2 A J 3", "Pair of Deuces"),
-    ("2 2 A T 9", "Pair of Deuces"),
-    ("2 2 A T 8", "Pair of Deuces"),
-    ("2 2 A T 7", "Pair of Deuces"),
-    ("2 2 A T 6", "Pair of Deuces"),
-    ("2 2 A T 5", "Pair of Deuces"),
-    ("2 2 A T 4", "Pair of Deuces"),
-    ("2 2 A T 3", "Pair of Deuces"),
-    ("2 2 A 9 8", "Pair of Deuces"),
-    ("2 2 A 9 7", "Pair of Deuces"),
-    ("2 2 A 9 6", "Pair of Deuces"),
-    ("2 2 A 9 5", "Pair of Deuces"),
-    ("2 2 A 9 4", "Pair of Deuces"),
-    ("2 2 A 9 3", "Pair of Deuces"),
-    ("2 2 A 8 7", "Pair of Deuces"),
-    ("2 2 A 8 6", "Pair of Deuces"),
-    ("2 2 A 8 5", "Pair of Deuces"),
-    ("2 2 A 8 4", "Pair of Deuces"),
-    ("2 2 A 8 3", "Pair of Deuces"),
-    ("2 2 A 7 6", "Pair of Deuces"),
-    ("2 2 A 7 5", "Pair of Deuces"),
-    ("2 2 A 7 4", "Pair of Deuces"),
-    ("2 2 A 7 3", "Pair of Deuces"),
-    ("2 2 A 6 5", "Pair of Deuces"),
-    ("2 2 A 6 4", "Pair of Deuces"),
-    ("2 2 A 6 3", "Pair of Deuces"),
-    ("2 2 A 5 4", "Pair of Deuces"),
-    ("2 2 A 5 3", "Pair of Deuces"),
-    ("2 2 A 4 3", "Pair of Deuces"),
-    ("2 2 K Q J", "Pair of Deuces"),
-    ("2 2 K Q T", "Pair of Deuces"),
-    ("2 2 K Q 9", "Pair of Deuces"),
-    ("2 2 K Q 8", "Pair of Deuces"),
-    ("2 2 K Q 7", "Pair of Deuces"),
-    ("2 2 K Q 6", "Pair of Deuces"),
-    ("2 2 K Q 5", "Pair of Deuces"),
-    ("2 2 K Q 4", "Pair of Deuces"),
-    ("2 2 K Q 3", "Pair of Deuces"),
-    ("2 2 K J T", "Pair of Deuces"),
-    ("2 2 K J 9", "Pair of Deuces"),
-    ("2 2 K J 8", "Pair of Deuces"),
-    ("2 2 K J 7", "Pair of Deuces"),
-    ("2 2 K J 6", "Pair of Deuces"),
-    ("2 2 K J 5", "Pair of Deuces"),
-    ("2 2 K J 4", "Pair of Deuces"),
-    ("2 2 K J 3", "Pair of Deuces"),
-    ("2 2 K T 9", "Pair of Deuces"),
-    ("2 2 K T 8", "Pair of Deuces"),
-    ("2 2 K T 7", "Pair of Deuces"),
-    ("2 2 K T 6", "Pair of Deuces"),
-    ("2 2 K T 5", "Pair of Deuces"),
-    ("2 2 K T 4", "Pair of Deuces"),
-    ("2 2 K T 3", "Pair of Deuces"),
-    ("2 2 K 9 8", "Pair of Deuces"),
-    ("2 2 K 9 7", "Pair of Deuces"),
-    ("2 2 K 9 6", "Pair of Deuces"),
-    ("2 2 K 9 5", "Pair of Deuces"),
-    ("2 2 K 9 4", "Pair of Deuces"),
-    ("2 2 K 9 3", "Pair of Deuces"),
-    ("2 2 K 8 7", "Pair of Deuces"),
-    ("2 2 K 8 6", "Pair of Deuces"),
-    ("2 2 K 8 5", "Pair of Deuces"),
-    ("2 2 K 8 4", "Pair of Deuces"),
-    ("2 2 K 8 3", "Pair of Deuces"),
-    ("2 2 K 7 6", "Pair of Deuces"),
-    ("2 2 K 7 5", "Pair of Deuces"),
-    ("2 2 K 7 4", "Pair of Deuces"),
-    ("2 2 K 7 3", "Pair of Deuces"),
-    ("2 2 K 6 5", "Pair of Deuces"),
-    ("2 2 K 6 4", "Pair of Deuces"),
-    ("2 2 K 6 3", "Pair of Deuces"),
-    ("2 2 K 5 4", "Pair of Deuces"),
-    ("2 2 K 5 3", "Pair of Deuces"),
-    ("2 2 K 4 3", "Pair of Deuces"),
-    ("2 2 Q J T", "Pair of Deuces"),
-    ("2 2 Q J 9", "Pair of Deuces"),
-    ("2 2 Q J 8", "Pair of Deuces"),
-    ("2 2 Q J 7", "Pair of Deuces"),
-    ("2 2 Q J 6", "Pair of Deuces"),
-    ("2 2 Q J 5", "Pair of Deuces"),
-    ("2 2 Q J 4", "Pair of Deuces"),
-    ("2 2 Q J 3", "Pair of Deuces"),
-    ("2 2 Q T 9", "Pair of Deuces"),
-    ("2 2 Q T 8", "Pair of Deuces"),
-    ("2 2 Q T 7", "Pair of Deuces"),
-    ("2 2 Q T 6", "Pair of Deuces"),
-    ("2 2 Q T 5", "Pair of Deuces"),
-    ("2 2 Q T 4", "Pair of Deuces"),
-    ("2 2 Q T 3", "Pair of Deuces"),
-    ("2 2 Q 9 8", "Pair of Deuces"),
-    ("2 2 Q 9 7", "Pair of Deuces"),
-    ("2 2 Q 9 6", "Pair of Deuces"),
-    ("2 2 Q 9 5", "Pair of Deuces"),
-    ("2 2 Q 9 4", "Pair of Deuces"),
-    ("2 2 Q 9 3", "Pair of Deuces"),
-    ("2 2 Q 8 7", "Pair of Deuces"),
-    ("2 2 Q 8 6", "Pair of Deuces"),
-    ("2 2 Q 8 5", "Pair of Deuces"),
-    ("2 2 Q 8 4", "Pair of Deuces"),
-    ("2 2 Q 8 3", "Pair of Deuces"),
-    ("2 2 Q 7 6", "Pair of Deuces"),
-    ("2 2 Q 7 5", "Pair of Deuces"),
-    ("2 2 Q 7 4", "Pair of Deuces"),
-    ("2 2 Q 7 3", "Pair of Deuces"),
-    ("2 2 Q 6 5", "Pair of Deuces"),
-    ("2 2 Q 6 4", "Pair of Deuces"),
-    ("2 2 Q 6 3", "Pair of Deuces"),
-    ("2 2 Q 5 4", "Pair of Deuces"),
-    ("2 2 Q 5 3", "Pair of Deuces"),
-    ("2 2 Q 4 3", "Pair of Deuces"),
-    ("2 2 J T 9", "Pair of Deuces"),
-    ("2 2 J T 8", "Pair of Deuces"),
-    ("2 2 J T 7", "Pair of Deuces"),
-    ("2 2 J T 6", "Pair of Deuces"),
-    ("2 2 J T 5", "Pair of Deuces"),
-    ("2 2 J T 4", "Pair of Deuces"),
-    ("2 2 J T 3", "Pair of Deuces"),
-    ("2 2 J 9 8", "Pair of Deuces"),
-    ("2 2 J 9 7", "Pair of Deuces"),
-    ("2 2 J 9 6", "Pair of Deuces"),
-    ("2 2 J 9 5", "Pair of Deuces"),
-    ("2 2 J 9 4", "Pair of Deuces"),
-    ("2 2 J 9 3", "Pair of Deuces"),
-    ("2 2 J 8 7", "Pair of Deuces"),
-    ("2 2 J 8 6", "Pair of Deuces"),
-    ("2 2 J 8 5", "Pair of Deuces"),
-    ("2 2 J 8 4", "Pair of Deuces"),
-    ("2 2 J 8 3", "Pair of Deuces"),
-    ("2 2 J 7 6", "Pair of Deuces"),
-    ("2 2 J 7 5", "Pair of Deuces"),
-    ("2 2 J 7 4", "Pair of Deuces"),
-    ("2 2 J 7 3", "Pair of Deuces"),
-    ("2 2 J 6 5", "Pair of Deuces"),
-    ("2 2 J 6 4", "Pair of Deuces"),
-    ("2 2 J 6 3", "Pair of Deuces"),
-    ("2 2 J 5 4", "Pair of Deuces"),
-    ("2 2 J 5 3", "Pair of Deuces"),
-    ("2 2 J 4 3", "Pair of Deuces"),
-    ("2 2 T 9 8", "Pair of Deuces"),
-    ("2 2 T 9 7", "Pair of Deuces"),
-    ("2 2 T 9 6", "Pair of Deuces"),
-    ("2 2 T 9 5", "Pair of Deuces"),
-    ("2 2 T 9 4", "Pair of Deuces"),
-    ("2 2 T 9 3", "Pair of Deuces"),
-    ("2 2 T 8 7", "Pair of Deuces"),
-    ("2 2 T 8 6", "Pair of Deuces"),
-    ("2 2 T 8 5", "Pair of Deuces"),
-    ("2 2 T 8 4", "Pair of Deuces"),
-    ("2 2 T 8 3", "Pair of Deuces"),
-    ("2 2 T 7 6", "Pair of Deuces"),
-    ("2 2 T 7 5", "Pair of Deuces"),
-    ("2 2 T 7 4", "Pair of Deuces"),
-    ("2 2 T 7 3", "Pair of Deuces"),
-    ("2 2 T 6 5", "Pair of Deuces"),
-    ("2 2 T 6 4", "Pair of Deuces"),
-    ("2 2 T 6 3", "Pair of Deuces"),
-    ("2 2 T 5 4", "Pair of Deuces"),
-    ("2 2 T 5 3", "Pair of Deuces"),
-    ("2 2 T 4 3", "Pair of Deuces"),
-    ("2 2 9 8 7", "Pair of Deuces"),
-    ("2 2 9 8 6", "Pair of Deuces"),
-    ("2 2 9 8 5", "Pair of Deuces"),
-    ("2 2 9 8 4", "Pair of Deuces"),
-    ("2 2 9 8 3", "Pair of Deuces"),
-    ("2 2 9 7 6", "Pair of Deuces"),
-    ("2 2 9 7 5", "Pair of Deuces"),
-    ("2 2 9 7 4", "Pair of Deuces"),
-    ("2 2 9 7 3", "Pair of Deuces"),
-    ("2 2 9 6 5", "Pair of Deuces"),
-    ("2 2 9 6 4", "Pair of Deuces"),
-    ("2 2 9 6 3", "Pair of Deuces"),
-    ("2 2 9 5 4", "Pair of Deuces"),
-    ("2 2 9 5 3", "Pair of Deuces"),
-    ("2 2 9 4 3", "Pair of Deuces"),
-    ("2 2 8 7 6", "Pair of Deuces"),
-    ("2 2 8 7 5", "Pair of Deuces"),
-    ("2 2 8 7 4", "Pair of Deuces"),
-    ("2 2 8 7 3", "Pair of Deuces"),
-    ("2 2 8 6 5", "Pair of Deuces"),
-    ("2 2 8 6 4", "Pair of Deuces"),
-    ("2 2 8 6 3", "Pair of Deuces"),
-    ("2 2 8 5 4", "Pair of Deuces"),
-    ("2 2 8 5 3", "Pair of Deuces"),
-    ("2 2 8 4 3", "Pair of Deuces"),
-    ("2 2 7 6 5", "Pair of Deuces"),
-    ("2 2 7 6 4", "Pair of Deuces"),
-    ("2 2 7 6 3", "Pair of Deuces"),
-    ("2 2 7 5 4", "Pair of Deuces"),
-    ("2 2 7 5 3", "Pair of Deuces"),
-    ("2 2 7 4 3", "Pair of Deuces"),
-    ("2 2 6 5 4", "Pair of Deuces"),
-    ("2 2 6 5 3", "Pair of Deuces"),
-    ("2 2 6 4 3", "Pair of Deuces"),
-    ("2 2 5 4 3", "Pair of Deuces"),
-    ("A K Q J 9", "Ace-High"),
-    ("A K Q J 8", "Ace-High"),
-    ("A K Q J 7", "Ace-High"),
-    ("A K Q J 6", "Ace-High"),
-    ("A K Q J 5", "Ace-High"),
-    ("A K Q J 4", "Ace-High"),
-    ("A K Q J 3", "Ace-High"),
-    ("A K Q J 2", "Ace-High"),
-    ("A K Q T 9", "Ace-High"),
-    ("A K Q T 8", "Ace-High"),
-    ("A K Q T 7", "Ace-High"),
-    ("A K Q T 6", "Ace-High"),
-    ("A K Q T 5", "Ace-High"),
-    ("A K Q T 4", "Ace-High"),
-    ("A K Q T 3", "Ace-High"),
-    ("A K Q T 2", "Ace-High"),
-    ("A K Q 9 8", "Ace-High"),
-    ("A K Q 9 7", "Ace-High"),
-    ("A K Q 9 6", "Ace-High"),
-    ("A K Q 9 5", "Ace-High"),
-    ("A K Q 9 4", "Ace-High"),
-    ("A K Q 9 3", "Ace-High"),
-    ("A K Q 9 2", "Ace-High"),
-    ("A K Q 8 7", "Ace-High"),
-    ("A K Q 8 6", "Ace-High"),
-    ("A K Q 8 5", "Ace-High"),
-    ("A K Q 8 4", "Ace-High"),
-    ("A K Q 8 3", "Ace-High"),
-    ("A K Q 8 2", "Ace-High"),
-    ("A K Q 7 6", "Ace-High"),
-    ("A K Q 7 5", "Ace-High"),
-    ("A K Q 7 4", "Ace-High"),
-    ("A K Q 7 3", "Ace-High"),
-    ("A K Q 7 2", "Ace-High"),
-    ("A K Q 6 5", "Ace-High"),
-    ("A K Q 6 4", "Ace-High"),
-    ("A K Q 6 3", "Ace-High"),
-    ("A K Q 6 2", "Ace-High"),
-    ("A K Q 5 4", "Ace-High"),
-    ("A K Q 5 3", "Ace-High"),
-    ("A K Q 5 2", "Ace-High"),
-    ("A K Q 4 3", "Ace-High"),
-    ("A K Q 4 2", "Ace-High"),
-    ("A K Q 3 2", "Ace-High"),
-    ("A K J T 9", "Ace-High"),
-    ("A K J T 8", "Ace-High"),
-    ("A K J T 7", "Ace-High"),
-    ("A K J T 6", "Ace-High"),
-    ("A K J T 5", "Ace-High"),
-    ("A K J T 4", "Ace-High"),
-    ("A K J T 3", "Ace-High"),
-    ("A K J T 2", "Ace-High"),
-    ("A K J 9 8", "Ace-High"),
-    ("A K J 9 7", "Ace-High"),
-    ("A K J 9 6", "Ace-High"),
-    ("A K J 9 5", "Ace-High"),
-    ("A K J 9 4", "Ace-High"),
-    ("A K J 9 3", "Ace-High"),
-    ("A K J 9 2", "Ace-High"),
-    ("A K J 8 7", "Ace-High"),
-    ("A K J 8 6", "Ace-High"),
-    ("A K J 8 5", "Ace-High"),
-    ("A K J 8 4", "Ace-High"),
-    ("A K J 8 3", "Ace-High"),
-    ("A K J 8 2", "Ace-High"),
-    ("A K J 7 6", "Ace-High"),
-    ("A K J 7 5", "Ace-High"),
-    ("A K J 7 4", "Ace-High"),
-    ("A K J 7 3", "Ace-High"),
-    ("A K J 7 2", "Ace-High"),
-    ("A K J 6 5", "Ace-High"),
-    ("A K J 6 4", "Ace-High"),
-    ("A K J 6 3", "Ace-High"),
-    ("A K J 6 2", "Ace-High"),
-    ("A K J 5 4", "Ace-High"),
-    ("A K J 5 3", "Ace-High"),
-    ("A K J 5 2", "Ace-High"),
-    ("A K J 4 3", "Ace-High"),
-    ("A K J 4 2", "Ace-High"),
-    ("A K J 3 2", "Ace-High"),
-    ("A K T 9 8", "Ace-High"),
-    ("A K T 9 7", "Ace-High"),
-    ("A K T 9 6", "Ace-High"),
-    ("A K T 9 5", "Ace-High"),
-    ("A K T 9 4", "Ace-High"),
-    ("A K T 9 3", "Ace-High"),
-    ("A K T 9 2", "Ace-High"),
-    ("A K T 8 7", "Ace-High"),
-    ("A K T 8 6", "Ace-High"),
-    ("A K T 8 5", "Ace-High"),
-    ("A K T 8 4", "Ace-High"),
-    ("A K T 8 3", "Ace-High"),
-    ("A K T 8 2", "Ace-High"),
-    ("A K T 7 6", "Ace-High"),
-    ("A K T 7 5", "Ace-High"),
-    ("A K T 7 4", "Ace-High"),
-    ("A K T 7 3", "Ace-High"),
-    ("A K T 7 2", "Ace-High"),
-    ("A K T 6 5", "Ace-High"),
-    ("A K T 6 4", "Ace-High"),
-    ("A K T 6 3", "Ace-High"),
-    ("A K T 6 2", "Ace-High"),
-    ("A K T 5 4", "Ace-High"),
-    ("A K T 5 3", "Ace-High"),
-    ("A K T 5 2", "Ace-High"),
-    ("A K T 4 3", "Ace-High"),
-    ("A K T 4 2", "Ace-High"),
-    ("A K T 3 2", "Ace-High"),
-    ("A K 9 8 7", "Ace-High"),
-    ("A K 9 8 6", "Ace-High"),
-    ("A K 9 8 5", "Ace-High"),
-    ("A K 9 8 4", "Ace-High"),
-    ("A K 9 8 3", "Ace-High"),
-    ("A K 9 8 2", "Ace-High"),
-    ("A K 9 7 6", "Ace-High"),
-    ("A K 9 7 5", "Ace-High"),
-    ("A K 9 7 4", "Ace-High"),
-    ("A K 9 7 3", "Ace-High"),
-    ("A K 9 7 2", "Ace-High"),
-    ("A K 9 6 5", "Ace-High"),
-    ("A K 9 6 4", "Ace-High"),
-    ("A K 9 6 3", "Ace-High"),
-    ("A K 9 6 2", "Ace-High"),
-    ("A K 9 5 4", "Ace-High"),
-    ("A K 9 5 3", "Ace-High"),
-    ("A K 9 5 2", "Ace-High"),
-    ("A K 9 4 3", "Ace-High"),
-    ("A K 9 4 2", "Ace-High"),
-    ("A K 9 3 2", "Ace-High"),
-    ("A K 8 7 6", "Ace-High"),
-    ("A K 8 7 5", "Ace-High"),
-    ("A K 8 7 4", "Ace-High"),
-    ("A K 8 7 3", "Ace-High"),
-    ("A K 8 7 2", "Ace-High"),
-    ("A K 8 6 5", "Ace-High"),
-    ("A K 8 6 4", "Ace-High"),
-    ("A K 8 6 3", "Ace-High"),
-    ("A K 8 6 2", "Ace-High"),
-    ("A K 8 5 4", "Ace-High"),
-    ("A K 8 5 3", "Ace-High"),
-    ("A K 8 5 2", "Ace-High"),
-    ("A K 8 4 3", "Ace-High"),
-    ("A K 8 4 2", "Ace-High"),
-    ("A K 8 3 2", "Ace-High"),
-    ("A K 7 6 5", "Ace-High"),
-    ("A K 7 6 4", "Ace-High"),
-    ("A K 7 6 3", "Ace-High"),
-    ("A K 7 6 2", "Ace-High"),
-    ("A K 7 5 4", "Ace-High"),
-    ("A K 7 5 3", "Ace-High"),
-    ("A K 7 5 2", "Ace-High"),
-    ("A K 7 4 3", "Ace-High"),
-    ("A K 7 4 2", "Ace-High"),
-    ("A K 7 3 2", "Ace-High"),
-    ("A K 6 5 4", "Ace-High"),
-    ("A K 6 5 3", "Ace-High"),
-    ("A K 6 5 2", "Ace-High"),
-    ("A K 6 4 3", "Ace-High"),
-    ("A K 6 4 2", "Ace-High"),
-    ("A K 6 3 2", "Ace-High"),
-    ("A K 5 4 3", "Ace-High"),
-    ("A K 5 4 2", "Ace-High"),
-    ("A K 5 3 2", "Ace-High"),
-    ("A K 4 3 2", "Ace-High"),
-    ("A Q J T 9", "Ace-High"),
-    ("A Q J T 8", "Ace-High"),
-    ("A Q J T 7", "Ace-High"),
-    ("A Q J T 6", "Ace-High"),
-    ("A Q J T 5", "Ace-High"),
-    ("A Q J T 4", "Ace-High"),
-    ("A Q J T 3", "Ace-High"),
-    ("A Q J T 2", "Ace-High"),
-    ("A Q J 9 8", "Ace-High"),
-    ("A Q J 9 7", "Ace-High"),
-    ("A Q J 9 6", "Ace-High"),
-    ("A Q J 9 5", "Ace-High"),
-    ("A Q J 9 4", "Ace-High"),
-    ("A Q J 9 3", "Ace-High"),
-    ("A Q J 9 2", "Ace-High"),
-    ("A Q J 8 7", "Ace-High"),
-    ("A Q J 8 6", "Ace-High"),
-    ("A Q J 8 5", "Ace-High"),
-    ("A Q J 8 4", "Ace-High"),
-    ("A Q J 8 3", "Ace-High"),
-    ("A Q J 8 2", "Ace-High"),
-    ("A Q J 7 6", "Ace-High"),
-    ("A Q J 7 5", "Ace-High"),
-    ("A Q J 7 4", "Ace-High"),
-    ("A Q J 7 3", "Ace-High"),
-    ("A Q J 7 2", "Ace-High"),
-    ("A Q J 6 5", "Ace-High"),
-    ("A Q J 6 4", "Ace-High"),
-    ("A Q J 6 3", "Ace-High"),
-    ("A Q J 6 2", "Ace-High"),
-    ("A Q J 5 4", "Ace-High"),
-    ("A Q J 5 3", "Ace-High"),
-    ("A Q J 5 2", "Ace-High"),
-    ("A Q J 4 3", "Ace-High"),
-    ("A Q J 4 2", "Ace-High"),
-    ("A Q J 3 2", "Ace-High"),
-    ("A Q T 9 8", "Ace-High"),
-    ("A Q T 9 7", "Ace-High"),
-    ("A Q T 9 6", "Ace-High"),
-    ("A Q T 9 5", "Ace-High"),
-    ("A Q T 9 4", "Ace-High"),
-    ("A Q T 9 3", "Ace-High"),
-    ("A Q T 9 2", "Ace-High"),
-    ("A Q T 8 7", "Ace-High"),
-    ("A Q T 8 6", "Ace-High"),
-    ("A Q T 8 5", "Ace-High"),
-    ("A Q T 8 4", "Ace-High"),
-    ("A Q T 8 3", "Ace-High"),
-    ("A Q T 8 2", "Ace-High"),
-    ("A Q T 7 6", "Ace-High"),
-    ("A Q T 7 5", "Ace-High"),
-    ("A Q T 7 4", "Ace-High"),
-    ("A Q T 7 3", "Ace-High"),
-    ("A Q T 7 2", "Ace-High"),
-    ("A Q T 6 5", "Ace-High"),
-    ("A Q T 6 4", "Ace-High"),
-    ("A Q T 6 3", "Ace-High"),
-    ("A Q T 6 2", "Ace-High"),
-    ("A Q T 5 4", "Ace-High"),
-    ("A Q T 5 3", "Ace-High"),
-    ("A Q T 5 2", "Ace-High"),
-    ("A Q T 4 3", "Ace-High"),
-    ("A Q T 4 2", "Ace-High"),
-    ("A Q T 3 2", "Ace-High"),
-    ("A Q 9 8 7", "Ace-High"),
-    ("A Q 9 8 6", "Ace-High"),
-    ("A Q 9 8 5", "Ace-High"),
-    ("A Q 9 8 4", "Ace-High"),
-    ("A Q 9 8 3", "Ace-High"),
-    ("A Q 9 8 2", "Ace-High"),
-    ("A Q 9 7 6", "Ace-High"),
-    ("A Q 9 7 5", "Ace-High"),
-    ("A Q 9 7 4", "Ace-High"),
-    ("A Q 9 7 3", "Ace-High"),
-    ("A Q 9 7 2", "Ace-High"),
-    ("A Q 9 6 5", "Ace-High"),
-    ("A Q 9 6 4", "Ace-High"),
-    ("A Q 9 6 3", "Ace-High"),
-    ("A Q 9 6 2", "Ace-High"),
-    ("A Q 9 5 4", "Ace-High"),
-    ("A Q 9 5 3", "Ace-High"),
-    ("A Q 9 5 2", "Ace-High"),
-    ("A Q 9 4 3", "Ace-High"),
-    ("A Q 9 4 2", "Ace-High"),
-    ("A Q 9 3 2", "Ace-High"),
-    ("A Q 8 7 6", "Ace-High"),
-    ("A Q 8 7 5", "Ace-High"),
-    ("A Q 8 7 4", "Ace-High"),
-    ("A Q 8 7 3", "Ace-High"),
-    ("A Q 8 7 2", "Ace-High"),
-    ("A Q 8 6 5", "Ace-High"),
-    ("A Q 8 6 4", "Ace-High"),
-    ("A Q 8 6 3", "Ace-High"),
-    ("A Q 8 6 2", "Ace-High"),
-    ("A Q 8 5 4", "Ace-High"),
-    ("A Q 8 5 3", "Ace-High"),
-    ("A Q 8 5 2", "Ace-High"),
-    ("A Q 8 4 3", "Ace-High"),
-    ("A Q 8 4 2", "Ace-High"),
-    ("A Q 8 3 2", "Ace-High"),
-    ("A Q 7 6 5", "Ace-High"),
-    ("A Q 7 6 4", "Ace-High"),
-    ("A Q 7 6 3", "Ace-High"),
-    ("A Q 7 6 2", "Ace-High"),
-    ("A Q 7 5 4", "Ace-High"),
-    ("A Q 7 5 3", "Ace-High"),
-    ("A Q 7 5 2", "Ace-High"),
-    ("A Q 7 4 3", "Ace-High"),
-    ("A Q 7 4 2", "Ace-High"),
-    ("A Q 7 3 2", "Ace-High"),
-    ("A Q 6 5 4", "Ace-High"),
-    ("A Q 6 5 3", "Ace-High"),
-    ("A Q 6 5 2", "Ace-High"),
-    ("A Q 6 4 3", "Ace-High"),
-    ("A Q 6 4 2", "Ace-High"),
-    ("A Q 6 3 2", "Ace-High"),
-    ("A Q 5 4 3", "Ace-High"),
-    ("A Q 5 4 2", "Ace-High"),
-    ("A Q 5 3 2", "Ace-High"),
-    ("A Q 4 3 2", "Ace-High"),
-    ("A J T 9 8", "Ace-High"),
-    ("A J T 9 7", "Ace-High"),
-    ("A J T 9 6", "Ace-High"),
-    ("A J T 9 5", "Ace-High"),
-    ("A J T 9 4", "Ace-High"),
-    ("A J T 9 3", "Ace-High"),
-    ("A J T 9 2", "Ace-High"),
-    ("A J T 8 7", "Ace-High"),
-    ("A J T 8 6", "Ace-High"),
-    ("A J T 8 5", "Ace-High"),
-    ("A J T 8 4", "Ace-High"),
-    ("A J T 8 3", "Ace-High"),
-    ("A J T 8 2", "Ace-High"),
-    ("A J T 7 6", "Ace-High"),
-    ("A J T 7 5", "Ace-High"),
-    ("A J T 7 4", "Ace-High"),
-    ("A J T 7 3", "Ace-High"),
-    ("A J T 7 2", "Ace-High"),
-    ("A J T 6 5", "Ace-High"),
-    ("A J T 6 4", "Ace-High"),
-    ("A J T 6 3", "Ace-High"),
-    ("A J T 6 2", "Ace-High"),
-    ("A J T 5 4", "Ace-High"),
-    ("A J T 5 3", "Ace-High"),
-    ("A J T 5 2", "Ace-High"),
-    ("A J T 4 3", "Ace-High"),
-    ("A J T 4 2", "Ace-High"),
-    ("A J T 3 2", "Ace-High"),
-    ("A J 9 8 7", "Ace-High"),
-    ("A J 9 8 6", "Ace-High"),
-    ("A J 9 8 5", "Ace-High"),
-    ("A J 9 8 4", "Ace-High"),
-    ("A J 9 8 3", "Ace-High"),
-    ("A J 9 8 2", "Ace-High"),
-    ("A J 9 7 6", "Ace-High"),
-    ("A J 9 7 5", "Ace-High"),
-    ("A J 9 7 4", "Ace-High"),
-    ("A J 9 7 3", "Ace-High"),
-    ("A J 9 7 2", "Ace-High"),
-    ("A J 9 6 5", "Ace-High"),
-    ("A J 9 6 4", "Ace-High"),
-    ("A J 9 6 3", "Ace-High"),
-    ("A J 9 6 2", "Ace-High"),
-    ("A J 9 5 4", "Ace-High"),
-    ("A J 9 5 3", "Ace-High"),
-    ("A J 9 5 2", "Ace-High"),
-    ("A J 9 4 3", "Ace-High"),
-    ("A J 9 4 2", "Ace-High"),
-    ("A J 9 3 2", "Ace-High"),
-    ("A J 8 7 6", "Ace-High"),
-    ("A J 8 7 5", "Ace-High"),
-    ("A J 8 7 4", "Ace-High"),
-    ("A J 8 7 3", "Ace-High"),
-    ("A J 8 7 2", "Ace-High"),
-    ("A J 8 6 5", "Ace-High"),
-    ("A J 8 6 4", "Ace-High"),
-    ("A J 8 6 3", "Ace-High"),
-    ("A J 8 6 2", "Ace-High"),
-    ("A J 8 5 4", "Ace-High"),
-    ("A J 8 5 3", "Ace-High"),
-    ("A J 8 5 2", "Ace-High"),
-    ("A J 8 4 3", "Ace-High"),
-    ("A J 8 4 2", "Ace-High"),
-    ("A J 8 3 2", "Ace-High"),
-    ("A J 7 6 5", "Ace-High"),
-    ("A J 7 6 4", "Ace-High"),
-    ("A J 7 6 3", "Ace-High"),
-    ("A J 7 6 2", "Ace-High"),
-    ("A J 7 5 4", "Ace-High"),
-    ("A J 7 5 3", "Ace-High"),
-    ("A J 7 5 2", "Ace-High"),
-    ("A J 7 4 3", "Ace-High"),
-    ("A J 7 4 2", "Ace-High"),
-    ("A J 7 3 2", "Ace-High"),
-    ("A J 6 5 4", "Ace-High"),
-    ("A J 6 5 3", "Ace-High"),
-    ("A J 6 5 2", "Ace-High"),
-    ("A J 6 4 3", "Ace-High"),
-    ("A J 6 4 2", "Ace-High"),
-    ("A J 6 3 2", "Ace-High"),
-    ("A J 5 4 3", "Ace-High"),
-    ("A J 5 4 2", "Ace-High"),
-    ("A J 5 3 2", "Ace-High"),
-    ("A J 4 3 2", "Ace-High"),
-    ("A T 9 8 7", "Ace-High"),
-    ("A T 9 8 6", "Ace-High"),
-    ("A T 9 8 5", "Ace-High"),
-    ("A T 9 8 4", "Ace-High"),
-    ("A T 9 8 3", "Ace-High"),
-    ("A T 9 8 2", "Ace-High"),
-    ("A T 9 7 6", "Ace-High"),
-    ("A T 9 7 5", "Ace-High"),
-    ("A T 9 7 4", "Ace-High"),
-    ("A T 9 7 3", "Ace-High"),
-    ("A T 9 7 2", "Ace-High"),
-    ("A T 9 6 5", "Ace-High"),
-    ("A T 9 6 4", "Ace-High"),
-    ("A T 9 6 3", "Ace-High"),
-    ("A T 9 6 2", "Ace-High"),
-    ("A T 9 5 4", "Ace-High"),
-    ("A T 9 5 3", "Ace-High"),
-    ("A T 9 5 2", "Ace-High"),
-    ("A T 9 4 3", "Ace-High"),
-    ("A T 9 4 2", "Ace-High"),
-    ("A T 9 3 2", "Ace-High"),
-    ("A T 8 7 6", "Ace-High"),
-    ("A T 8 7 5", "Ace-High"),
-    ("A T 8 7 4", "Ace-High"),
-    ("A T 8 7 3", "Ace-High"),
-    ("A T 8 7 2", "Ace-High"),
-    ("A T 8 6 5", "Ace-High"),
-    ("A T 8 6 4", "Ace-High"),
-    ("A T 8 6 3", "Ace-High"),
-    ("A T 8 6 2", "Ace-High"),
-    ("A T 8 5 4", "Ace-High"),
-    ("A T 8 5 3", "Ace-High"),
-    ("A T 8 5 2", "Ace-High"),
-    ("A T 8 4 3", "Ace-High"),
-    ("A T 8 4 2", "Ace-High"),
-    ("A T 8 3 2", "Ace-High"),
-    ("A T 7 6 5", "Ace-High"),
-    ("A T 7 6 4", "Ace-High"),
-    ("A T 7 6 3", "Ace-High"),
-    ("A T 7 6 2", "Ace-High"),
-    ("A T 7 5 4", "Ace-High"),
-    ("A T 7 5 3", "Ace-High"),
-    ("A T 7 5 2", "Ace-High"),
-    ("A T 7 4 3", "Ace-High"),
-    ("A T 7 4 2", "Ace-High"),
-    ("A T 7 3 2", "Ace-High"),
-    ("A T 6 5 4", "Ace-High"),
-    ("A T 6 5 3", "Ace-High"),
-    ("A T 6 5 2", "Ace-High"),
-    ("A T 6 4 3", "Ace-High"),
-    ("A T 6 4 2", "Ace-High"),
-    ("A T 6 3 2", "Ace-High"),
-    ("A T 5 4 3", "Ace-High"),
-    ("A T 5 4 2", "Ace-High"),
-    ("A T 5 3 2", "Ace-High"),
-    ("A T 4 3 2", "Ace-High"),
-    ("A 9 8 7 6", "Ace-High"),
-    ("A 9 8 7 5", "Ace-High"),
-    ("A 9 8 7 4", "Ace-High"),
-    ("A 9 8 7 3", "Ace-High"),
-    ("A 9 8 7 2", "Ace-High"),
-    ("A 9 8 6 5", "Ace-High"),
-    ("A 9 8 6 4", "Ace-High"),
-    ("A 9 8 6 3", "Ace-High"),
-    ("A 9 8 6 2", "Ace-High"),
-    ("A 9 8 5 4", "Ace-High"),
-    ("A 9 8 5 3", "Ace-High"),
-    ("A 9 8 5 2", "Ace-High"),
-    ("A 9 8 4 3", "Ace-High"),
-    ("A 9 8 4 2", "Ace-High"),
-    ("A 9 8 3 2", "Ace-High"),
-    ("A 9 7 6 5", "Ace-High"),
-    ("A 9 7 6 4", "Ace-High"),
-    ("A 9 7 6 3", "Ace-High"),
-    ("A 9 7 6 2", "Ace-High"),
-    ("A 9 7 5 4", "Ace-High"),
-    ("A 9 7 5 3", "Ace-High"),
-    ("A 9 7 5 2", "Ace-High"),
-    ("A 9 7 4 3", "Ace-High"),
-    ("A 9 7 4 2", "Ace-High"),
-    ("A 9 7 3 2", "Ace-High"),
-    ("A 9 6 5 4", "Ace-High"),
-    ("A 9 6 5 3", "Ace-High"),
-    ("A 9 6 5 2", "Ace-High"),
-    ("A 9 6 4 3", "Ace-High"),
-    ("A 9 6 4 2", "Ace-High"),
-    ("A 9 6 3 2", "Ace-High"),
-    ("A 9 5 4 3", "Ace-High"),
-    ("A 9 5 4 2", "Ace-High"),
-    ("A 9 5 3 2", "Ace-High"),
-    ("A 9 4 3 2", "Ace-High"),
-    ("A 8 7 6 5", "Ace-High"),
-    ("A 8 7 6 4", "Ace-High"),
-    ("A 8 7 6 3", "Ace-High"),
-    ("A 8 7 6 2", "Ace-High"),
-    ("A 8 7 5 4", "Ace-High"),
-    ("A 8 7 5 3", "Ace-High"),
-    ("A 8 7 5 2", "Ace-High"),
-    ("A 8 7 4 3", "Ace-High"),
-    ("A 8 7 4 2", "Ace-High"),
-    ("A 8 7 3 2", "Ace-High"),
-    ("A 8 6 5 4", "Ace-High"),
-    ("A 8 6 5 3", "Ace-High"),
-    ("A 8 6 5 2", "Ace-High"),
-    ("A 8 6 4 3", "Ace-High"),
-    ("A 8 6 4 2", "Ace-High"),
-    ("A 8 6 3 2", "Ace-High"),
-    ("A 8 5 4 3", "Ace-High"),
-    ("A 8 5 4 2", "Ace-High"),
-    ("A 8 5 3 2", "Ace-High"),
-    ("A 8 4 3 2", "Ace-High"),
-    ("A 7 6 5 4", "Ace-High"),
-    ("A 7 6 5 3", "Ace-High"),
-    ("A 7 6 5 2", "Ace-High"),
-    ("A 7 6 4 3", "Ace-High"),
-    ("A 7 6 4 2", "Ace-High"),
-    ("A 7 6 3 2", "Ace-High"),
-    ("A 7 5 4 3", "Ace-High"),
-    ("A 7 5 4 2", "Ace-High"),
-    ("A 7 5 3 2", "Ace-High"),
-    ("A 7 4 3 2", "Ace-High"),
-    ("A 6 5 4 3", "Ace-High"),
-    ("A 6 5 4 2", "Ace-High"),
-    ("A 6 5 3 2", "Ace-High"),
-    ("A 6 4 3 2", "Ace-High"),
-    ("K Q J T 8", "King-High"),
-    ("K Q J T 7", "King-High"),
-    ("K Q J T 6", "King-High"),
-    ("K Q J T 5", "King-High"),
-    ("K Q J T 4", "King-High"),
-    ("K Q J T 3", "King-High"),
-    ("K Q J T 2", "King-High"),
-    ("K Q J 9 8", "King-High"),
-    ("K Q J 9 7", "King-High"),
-    ("K Q J 9 6", "King-High"),
-    ("K Q J 9 5", "King-High"),
-    ("K Q J 9 4", "King-High"),
-    ("K Q J 9 3", "King-High"),
-    ("K Q J 9 2", "King-High"),
-    ("K Q J 8 7", "King-High"),
-    ("K Q J 8 6", "King-High"),
-    ("K Q J 8 5", "King-High"),
-    ("K Q J 8 4", "King-High"),
-    ("K Q J 8 3", "King-High"),
-    ("K Q J 8 2", "King-High"),
-    ("K Q J 7 6", "King-High"),
-    ("K Q J 7 5", "King-High"),
-    ("K Q J 7 4", "King-High"),
-    ("K Q J 7 3", "King-High"),
-    ("K Q J 7 2", "King-High"),
-    ("K Q J 6 5", "King-High"),
-    ("K Q J 6 4", "King-High"),
-    ("K Q J 6 3", "King-High"),
-    ("K Q J 6 2", "King-High"),
-    ("K Q J 5 4", "King-High"),
-    ("K Q J 5 3", "King-High"),
-    ("K Q J 5 2", "King-High"),
-    ("K Q J 4 3", "King-High"),
-    ("K Q J 4 2", "King-High"),
-    ("K Q J 3 2", "King-High"),
-    ("K Q T 9 8", "King-High"),
-    ("K Q T 9 7", "King-High"),
-    ("K Q T 9 6", "King-High"),
-    ("K Q T 9 5", "King-High"),
-    ("K Q T 9 4", "King-High"),
-    ("K Q T 9 3", "King-High"),
-    ("K Q T 9 2", "King-High"),
-    ("K Q T 8 7", "King-High"),
-    ("K Q T 8 6", "King-High"),
-    ("K Q T 8 5", "King-High"),
-    ("K Q T 8 4", "King-High"),
-    ("K Q T 8 3", "King-High"),
-    ("K Q T 8 2", "King-High"),
-    ("K Q T 7 6", "King-High"),
-    ("K Q T 7 5", "King-High"),
-    ("K Q T 7 4", "King-High"),
-    ("K Q T 7 3", "King-High"),
-    ("K Q T 7 2", "King-High"),
-    ("K Q T 6 5", "King-High"),
-    ("K Q T 6 4", "King-High"),
-    ("K Q T 6 3", "King-High"),
-    ("K Q T 6 2", "King-High"),
-    ("K Q T 5 4", "King-High"),
-    ("K Q T 5 3", "King-High"),
-    ("K Q T 5 2", "King-High"),
-    ("K Q T 4 3", "King-High"),
-    ("K Q T 4 2", "King-High"),
-    ("K Q T 3 2", "King-High"),
-    ("K Q 9 8 7", "King-High"),
-    ("K Q 9 8 6", "King-High"),
-    ("K Q 9 8 5", "King-High"),
-    ("K Q 9 8 4", "King-High"),
-    ("K Q 9 8 3", "King-High"),
-    ("K Q 9 8 2", "King-High"),
-    ("K Q 9 7 6", "King-High"),
-    ("K Q 9 7 5", "King-High"),
-    ("K Q 9 7 4", "King-High"),
-    ("K Q 9 7 3", "King-High"),
-    ("K Q 9 7 2", "King-High"),
-    ("K Q 9 6 5", "King-High"),
-    ("K Q 9 6 4", "King-High"),
-    ("K Q 9 6 3", "King-High"),
-    ("K Q 9 6 2", "King-High"),
-    ("K Q 9 5 4", "King-High"),
-    ("K Q 9 5 3", "King-High"),
-    ("K Q 9 5 2", "King-High"),
-    ("K Q 9 4 3", "King-High"),
-    ("K Q 9 4 2", "King-High"),
-    ("K Q 9 3 2", "King-High"),
-    ("K Q 8 7 6", "King-High"),
-    ("K Q 8 7 5", "King-High"),
-    ("K Q 8 7 4", "King-High"),
-    ("K Q 8 7 3", "King-High"),
-    ("K Q 8 7 2", "King-High"),
-    ("K Q 8 6 5", "King-High"),
-    ("K Q 8 6 4", "King-High"),
-    ("K Q 8 6 3", "King-High"),
-    ("K Q 8 6 2", "King-High"),
-    ("K Q 8 5 4", "King-High"),
-    ("K Q 8 5 3", "King-High"),
-    ("K Q 8 5 2", "King-High"),
-    ("K Q 8 4 3", "King-High"),
-    ("K Q 8 4 2", "King-High"),
-    ("K Q 8 3 2", "King-High"),
-    ("K Q 7 6 5", "King-High"),
-    ("K Q 7 6 4", "King-High"),
-    ("K Q 7 6 3", "King-High"),
-    ("K Q 7 6 2", "King-High"),
-    ("K Q 7 5 4", "King-High"),
-    ("K Q 7 5 3", "King-High"),
-    ("K Q 7 5 2", "King-High"),
-    ("K Q 7 4 3", "King-High"),
-    ("K Q 7 4 2", "King-High"),
-    ("K Q 7 3 2", "King-High"),
-    ("K Q 6 5 4", "King-High"),
-    ("K Q 6 5 3", "King-High"),
-    ("K Q 6 5 2", "King-High"),
-    ("K Q 6 4 3", "King-High"),
-    ("K Q 6 4 2", "King-High"),
-    ("K Q 6 3 2", "King-High"),
-    ("K Q 5 4 3", "King-High"),
-    ("K Q 5 4 2", "King-High"),
-    ("K Q 5 3 2", "King-High"),
-    ("K Q 4 3 2", "King-High"),
-    ("K J T 9 8", "King-High"),
-    ("K J T 9 7", "King-High"),
-    ("K J T 9 6", "King-High"),
-    ("K J T 9 5", "King-High"),
-    ("K J T 9 4", "King-High"),
-    ("K J T 9 3", "King-High"),
-    ("K J T 9 2", "King-High"),
-    ("K J T 8 7", "King-High"),
-    ("K J T 8 6", "King-High"),
-    ("K J T 8 5", "King-High"),
-    ("K J T 8 4", "King-High"),
-    ("K J T 8 3", "King-High"),
-    ("K J T 8 2", "King-High"),
-    ("K J T 7 6", "King-High"),
-    ("K J T 7 5", "King-High"),
-    ("K J T 7 4", "King-High"),
-    ("K J T 7 3", "King-High"),
-    ("K J T 7 2", "King-High"),
-    ("K J T 6 5", "King-High"),
-    ("K J T 6 4", "King-High"),
-    ("K J T 6 3", "King-High"),
-    ("K J T 6 2", "King-High"),
-    ("K J T 5 4", "King-High"),
-    ("K J T 5 3", "King-High"),
-    ("K J T 5 2", "King-High"),
-    ("K J T 4 3", "King-High"),
-    ("K J T 4 2", "King-High"),
-    ("K J T 3 2", "King-High"),
-    ("K J 9 8 7", "King-High"),
-    ("K J 9 8 6", "King-High"),
-    ("K J 9 8 5", "King-High"),
-    ("K J 9 8 4", "King-High"),
-    ("K J 9 8 3", "King-High"),
-    ("K J 9 8 2", "King-High"),
-    ("K J 9 7 6", "King-High"),
-    ("K J 9 7 5", "King-High"),
-    ("K J 9 7 4", "King-High"),
-    ("K J 9 7 3", "King-High"),
-    ("K J 9 7 2", "King-High"),
-    ("K J 9 6 5", "King-High"),
-    ("K J 9 6 4", "King-High"),
-    ("K J 9 6 3", "King-High"),
-    ("K J 9 6 2", "King-High"),
-    ("K J 9 5 4", "King-High"),
-    ("K J 9 5 3", "King-High"),
-    ("K J 9 5 2", "King-High"),
-    ("K J 9 4 3", "King-High"),
-    ("K J 9 4 2", "King-High"),
-    ("K J 9 3 2", "King-High"),
-    ("K J 8 7 6", "King-High"),
-    ("K J 8 7 5", "King-High"),
-    ("K J 8 7 4", "King-High"),
-    ("K J 8 7 3", "King-High"),
-    ("K J 8 7 2", "King-High"),
-    ("K J 8 6 5", "King-High"),
-    ("K J 8 6 4", "King-High"),
-    ("K J 8 6 3", "King-High"),
-    ("K J 8 6 2", "King-High"),
-    ("K J 8 5 4", "King-High"),
-    ("K J 8 5 3", "King-High"),
-    ("K J 8 5 2", "King-High"),
-    ("K J 8 4 3", "King-High"),
-    ("K J 8 4 2", "King-High"),
-    ("K J 8 3 2", "King-High"),
-    ("K J 7 6 5", "King-High"),
-    ("K J 7 6 4", "King-High"),
-    ("K J 7 6 3", "King-High"),
-    ("K J 7 6 2", "King-High"),
-    ("K J 7 5 4", "King-High"),
-    ("K J 7 5 3", "King-High"),
-    ("K J 7 5 2", "King-High"),
-    ("K J 7 4 3", "King-High"),
-    ("K J 7 4 2", "King-High"),
-    ("K J 7 3 2", "King-High"),
-    ("K J 6 5 4", "King-High"),
-    ("K J 6 5 3", "King-High"),
-    ("K J 6 5 2", "King-High"),
-    ("K J 6 4 3", "King-High"),
-    ("K J 6 4 2", "King-High"),
-    ("K J 6 3 2", "King-High"),
-    ("K J 5 4 3", "King-High"),
-    ("K J 5 4 2", "King-High"),
-    ("K J 5 3 2", "King-High"),
-    ("K J 4 3 2", "King-High"),
-    ("K T 9 8 7", "King-High"),
-    ("K T 9 8 6", "King-High"),
-    ("K T 9 8 5", "King-High"),
-    ("K T 9 8 4", "King-High"),
-    ("K T 9 8 3", "King-High"),
-    ("K T 9 8 2", "King-High"),
-    ("K T 9 7 6", "King-High"),
-    ("K T 9 7 5", "King-High"),
-    ("K T 9 7 4", "King-High"),
-    ("K T 9 7 3", "King-High"),
-    ("K T 9 7 2", "King-High"),
-    ("K T 9 6 5", "King-High"),
-    ("K T 9 6 4", "King-High"),
-    ("K T 9 6 3", "King-High"),
-    ("K T 9 6 2", "King-High"),
-    ("K T 9 5 4", "King-High"),
-    ("K T 9 5 3", "King-High"),
-    ("K T 9 5 2", "King-High"),
-    ("K T 9 4 3", "King-High"),
-    ("K T 9 4 2", "King-High"),
-    ("K T 9 3 2", "King-High"),
-    ("K T 8 7 6", "King-High"),
-    ("K T 8 7 5", "King-High"),
-    ("K T 8 7 4", "King-High"),
-    ("K T 8 7 3", "King-High"),
-    ("K T 8 7 2", "King-High"),
-    ("K T 8 6 5", "King-High"),
-    ("K T 8 6 4", "King-High"),
-    ("K T 8 6 3", "King-High"),
-    ("K T 8 6 2", "King-High"),
-    ("K T 8 5 4", "King-High"),
-    ("K T 8 5 3", "King-High"),
-    ("K T 8 5 2", "King-High"),
-    ("K T 8 4 3", "King-High"),
-    ("K T 8 4 2", "King-High"),
-    ("K T 8 3 2", "King-High"),
-    ("K T 7 6 5", "King-High"),
-    ("K T 7 6 4", "King-High"),
-    ("K T 7 6 3", "King-High"),
-    ("K T 7 6 2", "King-High"),
-    ("K T 7 5 4", "King-High"),
-    ("K T 7 5 3", "King-High"),
-    ("K T 7 5 2", "King-High"),
-    ("K T 7 4 3", "King-High"),
-    ("K T 7 4 2", "King-High"),
-    ("K T 7 3 2", "King-High"),
-    ("K T 6 5 4", "King-High"),
-    ("K T 6 5 3", "King-High"),
-    ("K T 6 5 2", "King-High"),
-    ("K T 6 4 3", "King-High"),
-    ("K T 6 4 2", "King-High"),
-    ("K T 6 3 2", "King-High"),
-    ("K T 5 4 3", "King-High"),
-    ("K T 5 4 2", "King-High"),
-    ("K T 5 3 2", "King-High"),
-    ("K T 4 3 2", "King-High"),
-    ("K 9 8 7 6", "King-High"),
-    ("K 9 8 7 5", "King-High"),
-    ("K 9 8 7 4", "King-High"),
-    ("K 9 8 7 3", "King-High"),
-    ("K 9 8 7 2", "King-High"),
-    ("K 9 8 6 5", "King-High"),
-    ("K 9 8 6 4", "King-High"),
-    ("K 9 8 6 3", "King-High"),
-    ("K 9 8 6 2", "King-High"),
-    ("K 9 8 5 4", "King-High"),
-    ("K 9 8 5 3", "King-High"),
-    ("K 9 8 5 2", "King-High"),
-    ("K 9 8 4 3", "King-High"),
-    ("K 9 8 4 2", "King-High"),
-    ("K 9 8 3 2", "King-High"),
-    ("K 9 7 6 5", "King-High"),
-    ("K 9 7 6 4", "King-High"),
-    ("K 9 7 6 3", "King-High"),
-    ("K 9 7 6 2", "King-High"),
-    ("K 9 7 5 4", "King-High"),
-    ("K 9 7 5 3", "King-High"),
-    ("K 9 7 5 2", "King-High"),
-    ("K 9 7 4 3", "King-High"),
-    ("K 9 7 4 2", "King-High"),
-    ("K 9 7 3 2", "King-High"),
-    ("K 9 6 5 4", "King-High"),
-    ("K 9 6 5 3", "King-High"),
-    ("K 9 6 5 2", "King-High"),
-    ("K 9 6 4 3", "King-High"),
-    ("K 9 6 4 2", "King-High"),
-    ("K 9 6 3 2", "King-High"),
-    ("K 9 5 4 3", "King-High"),
-    ("K 9 5 4 2", "King-High"),
-    ("K 9 5 3 2", "King-High"),
-    ("K 9 4 3 2", "King-High"),
-    ("K 8 7 6 5", "King-High"),
-    ("K 8 7 6 4", "King-High"),
-    ("K 8 7 6 3", "King-High"),
-    ("K 8 7 6 2", "King-High"),
-    ("K 8 7 5 4", "King-High"),
-    ("K 8 7 5 3", "King-High"),
-    ("K 8 7 5 2", "King-High"),
-    ("K 8 7 4 3", "King-High"),
-    ("K 8 7 4 2", "King-High"),
-    ("K 8 7 3 2", "King-High"),
-    ("K 8 6 5 4", "King-High"),
-    ("K 8 6 5 3", "King-High"),
-    ("K 8 6 5 2", "King-High"),
-    ("K 8 6 4 3", "King-High"),
-    ("K 8 6 4 2", "King-High"),
-    ("K 8 6 3 2", "King-High"),
-    ("K 8 5 4 3", "King-High"),
-    ("K 8 5 4 2", "King-High"),
-    ("K 8 5 3 2", "King-High"),
-    ("K 8 4 3 2", "King-High"),
-    ("K 7 6 5 4", "King-High"),
-    ("K 7 6 5 3", "King-High"),
-    ("K 7 6 5 2", "King-High"),
-    ("K 7 6 4 3", "King-High"),
-    ("K 7 6 4 2", "King-High"),
-    ("K 7 6 3 2", "King-High"),
-    ("K 7 5 4 3", "King-High"),
-    ("K 7 5 4 2", "King-High"),
-    ("K 7 5 3 2", "King-High"),
-    ("K 7 4 3 2", "King-High"),
-    ("K 6 5 4 3", "King-High"),
-    ("K 6 5 4 2", "King-High"),
-    ("K 6 5 3 2", "King-High"),
-    ("K 6 4 3 2", "King-High"),
-    ("K 5 4 3 2", "King-High"),
-    ("Q J T 9 7", "Queen-High"),
-    ("Q J T 9 6", "Queen-High"),
-    ("Q J T 9 5", "Queen-High"),
-    ("Q J T 9 4", "Queen-High"),
-    ("Q J T 9 3", "Queen-High"),
-    ("Q J T 9 2", "Queen-High"),
-    ("Q J T 8 7", "Queen-High"),
-    ("Q J T 8 6", "Queen-High"),
-    ("Q J T 8 5", "Queen-High"),
-    ("Q J T 8 4", "Queen-High"),
-    ("Q J T 8 3", "Queen-High"),
-    ("Q J T 8 2", "Queen-High"),
-    ("Q J T 7 6", "Queen-High"),
-    ("Q J T 7 5", "Queen-High"),
-    ("Q J T 7 4", "Queen-High"),
-    ("Q J T 7 3", "Queen-High"),
-    ("Q J T 7 2", "Queen-High"),
-    ("Q J T 6 5", "Queen-High"),
-    ("Q J T 6 4", "Queen-High"),
-    ("Q J T 6 3", "Queen-High"),
-    ("Q J T 6 2", "Queen-High"),
-    ("Q J T 5 4", "Queen-High"),
-    ("Q J T 5 3", "Queen-High"),
-    ("Q J T 5 2", "Queen-High"),
-    ("Q J T 4 3", "Queen-High"),
-    ("Q J T 4 2", "Queen-High"),
-    ("Q J T 3 2", "Queen-High"),
-    ("Q J 9 8 7", "Queen-High"),
-    ("Q J 9 8 6", "Queen-High"),
-    ("Q J 9 8 5", "Queen-High"),
-    ("Q J 9 8 4", "Queen-High"),
-    ("Q J 9 8 3", "Queen-High"),
-    ("Q J 9 8 2", "Queen-High"),
-    ("Q J 9 7 6", "Queen-High"),
-    ("Q J 9 7 5", "Queen-High"),
-    ("Q J 9 7 4", "Queen-High"),
-    ("Q J 9 7 3", "Queen-High"),
-    ("Q J 9 7 2", "Queen-High"),
-    ("Q J 9 6 5", "Queen-High"),
-    ("Q J 9 6 4", "Queen-High"),
-    ("Q J 9 6 3", "Queen-High"),
-    ("Q J 9 6 2", "Queen-High"),
-    ("Q J 9 5 4", "Queen-High"),
-    ("Q J 9 5 3", "Queen-High"),
-    ("Q J 9 5 2", "Queen-High"),
-    ("Q J 9 4 3", "Queen-High"),
-    ("Q J 9 4 2", "Queen-High"),
-    ("Q J 9 3 2", "Queen-High"),
-    ("Q J 8 7 6", "Queen-High"),
-    ("Q J 8 7 5", "Queen-High"),
-    ("Q J 8 7 4", "Queen-High"),
-    ("Q J 8 7 3", "Queen-High"),
-    ("Q J 8 7 2", "Queen-High"),
-    ("Q J 8 6 5", "Queen-High"),
-    ("Q J 8 6 4", "Queen-High"),
-    ("Q J 8 6 3", "Queen-High"),
-    ("Q J 8 6 2", "Queen-High"),
-    ("Q J 8 5 4", "Queen-High"),
-    ("Q J 8 5 3", "Queen-High"),
-    ("Q J 8 5 2", "Queen-High"),
-    ("Q J 8 4 3", "Queen-High"),
-    ("Q J 8 4 2", "Queen-High"),
-    ("Q J 8 3 2", "Queen-High"),
-    ("Q J 7 6 5", "Queen-High"),
-    ("Q J 7 6 4", "Queen-High"),
-    ("Q J 7 6 3", "Queen-High"),
-    ("Q J 7 6 2", "Queen-High"),
-    ("Q J 7 5 4", "Queen-High"),
-    ("Q J 7 5 3", "Queen-High"),
-    ("Q J 7 5 2", "Queen-High"),
-    ("Q J 7 4 3", "Queen-High"),
-    ("Q J 7 4 2", "Queen-High"),
-    ("Q J 7 3 2", "Queen-High"),
-    ("Q J 6 5 4", "Queen-High"),
-    ("Q J 6 5 3", "Queen-High"),
-    ("Q J 6 5 2", "Queen-High"),
-    ("Q J 6 4 3", "Queen-High"),
-    ("Q J 6 4 2", "Queen-High"),
-    ("Q J 6 3 2", "Queen-High"),
-    ("Q J 5 4 3", "Queen-High"),
-    ("Q J 5 4 2", "Queen-High"),
-    ("Q J 5 3 2", "Queen-High"),
-    ("Q J 4 3 2", "Queen-High"),
-    ("Q T 9 8 7", "Queen-High"),
-    ("Q T 9 8 6", "Queen-High"),
-    ("Q T 9 8 5", "Queen-High"),
-    ("Q T 9 8 4", "Queen-High"),
-    ("Q T 9 8 3", "Queen-High"),
-    ("Q T 9 8 2", "Queen-High"),
-    ("Q T 9 7 6", "Queen-High"),
-    ("Q T 9 7 5", "Queen-High"),
-    ("Q T 9 7 4", "Queen-High"),
-    ("Q T 9 7 3", "Queen-High"),
-    ("Q T 9 7 2", "Queen-High"),
-    ("Q T 9 6 5", "Queen-High"),
-    ("Q T 9 6 4", "Queen-High"),
-    ("Q T 9 6 3", "Queen-High"),
-    ("Q T 9 6 2", "Queen-High"),
-    ("Q T 9 5 4", "Queen-High"),
-    ("Q T 9 5 3", "Queen-High"),
-    ("Q T 9 5 2", "Queen-High"),
-    ("Q T 9 4 3", "Queen-High"),
-    ("Q T 9 4 2", "Queen-High"),
-    ("Q T 9 3 2", "Queen-High"),
-    ("Q T 8 7 6", "Queen-High"),
-    ("Q T 8 7 5", "Queen-High"),
-    ("Q T 8 7 4", "Queen-High"),
-    ("Q T 8 7 3", "Queen-High"),
-    ("Q T 8 7 2", "Queen-High"),
-    ("Q T 8 6 5", "Queen-High"),
-    ("Q T 8 6 4", "Queen-High"),
-    ("Q T 8 6 3", "Queen-High"),
-    ("Q T 8 6 2", "Queen-High"),
-    ("Q T 8 5 4", "Queen-High"),
-    ("Q T 8 5 3", "Queen-High"),
-    ("Q T 8 5 2", "Queen-High"),
-    ("Q T 8 4 3", "Queen-High"),
-    ("Q T 8 4 2", "Queen-High"),
-    ("Q T 8 3 2", "Queen-High"),
-    ("Q T 7 6 5", "Queen-High"),
-    ("Q T 7 6 4", "Queen-High"),
-    ("Q T 7 6 3", "Queen-High"),
-    ("Q T 7 6 2", "Queen-High"),
-    ("Q T 7 5 4", "Queen-High"),
-    ("Q T 7 5 3", "Queen-High"),
-    ("Q T 7 5 2", "Queen-High"),
-    ("Q T 7 4 3", "Queen-High"),
-    ("Q T 7 4 2", "Queen-High"),
-    ("Q T 7 3 2", "Queen-High"),
-    ("Q T 6 5 4", "Queen-High"),
-    ("Q T 6 5 3", "Queen-High"),
-    ("Q T 6 5 2", "Queen-High"),
-    ("Q T 6 4 3", "Queen-High"),
-    ("Q T 6 4 2", "Queen-High"),
-    ("Q T 6 3 2", "Queen-High"),
-    ("Q T 5 4 3", "Queen-High"),
-    ("Q T 5 4 2", "Queen-High"),
-    ("Q T 5 3 2", "Queen-High"),
-    ("Q T 4 3 2", "Queen-High"),
-    ("Q 9 8 7 6", "Queen-High"),
-    ("Q 9 8 7 5", "Queen-High"),
-    ("Q 9 8 7 4", "Queen-High"),
-    ("Q 9 8 7 3", "Queen-High"),
-    ("Q 9 8 7 2", "Queen-High"),
-    ("Q 9 8 6 5", "Queen-High"),
-    ("Q 9 8 6 4", "Queen-High"),
-    ("Q 9 8 6 3", "Queen-High"),
-    ("Q 9 8 6 2", "Queen-High"),
-    ("Q 9 8 5 4", "Queen-High"),
-    ("Q 9 8 5 3", "Queen-High"),
-    ("Q 9 8 5 2", "Queen-High"),
-    ("Q 9 8 4 3", "Queen-High"),
-    ("Q 9 8 4 2", "Queen-High"),
-    ("Q 9 8 3 2", "Queen-High"),
-    ("Q 9 7 6 5", "Queen-High"),
-    ("Q 9 7 6 4", "Queen-High"),
-    ("Q 9 7 6 3", "Queen-High"),
-    ("Q 9 7 6 2", "Queen-High"),
-    ("Q 9 7 5 4", "Queen-High"),
-    ("Q 9 7 5 3", "Queen-High"),
-    ("Q 9 7 5 2", "Queen-High"),
-    ("Q 9 7 4 3", "Queen-High"),
-    ("Q 9 7 4 2", "Queen-High"),
-    ("Q 9 7 3 2", "Queen-High"),
-    ("Q 9 6 5 4", "Queen-High"),
-    ("Q 9 6 5 3", "Queen-High"),
-    ("Q 9 6 5 2", "Queen-High"),
-    ("Q 9 6 4 3", "Queen-High"),
-    ("Q 9 6 4 2", "Queen-High"),
-    ("Q 9 6 3 2", "Queen-High"),
-    ("Q 9 5 4 3", "Queen-High"),
-    ("Q 9 5 4 2", "Queen-High"),
-    ("Q 9 5 3 2", "Queen-High"),
-    ("Q 9 4 3 2", "Queen-High"),
-    ("Q 8 7 6 5", "Queen-High"),
-    ("Q 8 7 6 4", "Queen-High"),
-    ("Q 8 7 6 3", "Queen-High"),
-    ("Q 8 7 6 2", "Queen-High"),
-    ("Q 8 7 5 4", "Queen-High"),
-    ("Q 8 7 5 3", "Queen-High"),
-    ("Q 8 7 5 2", "Queen-High"),
-    ("Q 8 7 4 3", "Queen-High"),
-    ("Q 8 7 4 2", "Queen-High"),
-    ("Q 8 7 3 2", "Queen-High"),
-    ("Q 8 6 5 4", "Queen-High"),
-    ("Q 8 6 5 3", "Queen-High"),
-    ("Q 8 6 5 2", "Queen-High"),
-    ("Q 8 6 4 3", "Queen-High"),
-    ("Q 8 6 4 2", "Queen-High"),
-    ("Q 8 6 3 2", "Queen-High"),
-    ("Q 8 5 4 3", "Queen-High"),
-    ("Q 8 5 4 2", "Queen-High"),
-    ("Q 8 5 3 2", "Queen-High"),
-    ("Q 8 4 3 2", "Queen-High"),
-    ("Q 7 6 5 4", "Queen-High"),
-    ("Q 7 6 5 3", "Queen-High"),
-    ("Q 7 6 5 2", "Queen-High"),
-    ("Q 7 6 4 3", "Queen-High"),
-    ("Q 7 6 4 2", "Queen-High"),
-    ("Q 7 6 3 2", "Queen-High"),
-    ("Q 7 5 4 3", "Queen-High"),
-    ("Q 7 5 4 2", "Queen-High"),
-    ("Q 7 5 3 2", "Queen-High"),
-    ("Q 7 4 3 2", "Queen-High"),
-    ("Q 6 5 4 3", "Queen-High"),
-    ("Q 6 5 4 2", "Queen-High"),
-    ("Q 6 5 3 2", "Queen-High"),
-    ("Q 6 4 3 2", "Queen-High"),
-    ("Q 5 4 3 2", "Queen-High"),
-    ("J T 9 8 6", "Jack-High"),
-    ("J T 9 8 5", "Jack-High"),
-    ("J T 9 8 4", "Jack-High"),
-    ("J T 9 8 3", "Jack-High"),
-    ("J T 9 8 2", "Jack-High"),
-    ("J T 9 7 6", "Jack-High"),
-    ("J T 9 7 5", "Jack-High"),
-    ("J T 9 7 4", "Jack-High"),
-    ("J T 9 7 3", "Jack-High"),
-    ("J T 9 7 2", "Jack-High"),
-    ("J T 9 6 5", "Jack-High"),
-    ("J T 9 6 4", "Jack-High"),
-    ("J T 9 6 3", "Jack-High"),
-    ("J T 9 6 2", "Jack-High"),
-    ("J T 9 5 4", "Jack-High"),
-    ("J T 9 5 3", "Jack-High"),
-    ("J T 9 5 2", "Jack-High"),
-    ("J T 9 4 3", "Jack-High"),
-    ("J T 9 4 2", "Jack-High"),
-    ("J T 9 3 2", "Jack-High"),
-    ("J T 8 7 6", "Jack-High"),
-    ("J T 8 7 5", "Jack-High"),
-    ("J T 8 7 4", "Jack-High"),
-    ("J T 8 7 3", "Jack-High"),
-    ("J T 8 7 2", "Jack-High"),
-    ("J T 8 6 5", "Jack-High"),
-    ("J T 8 6 4", "Jack-High"),
-    ("J T 8 6 3", "Jack-High"),
-    ("J T 8 6 2", "Jack-High"),
-    ("J T 8 5 4", "Jack-High"),
-    ("J T 8 5 3", "Jack-High"),
-    ("J T 8 5 2", "Jack-High"),
-    ("J T 8 4 3", "Jack-High"),
-    ("J T 8 4 2", "Jack-High"),
-    ("J T 8 3 2", "Jack-High"),
-    ("J T 7 6 5", "Jack-High"),
-    ("J T 7 6 4", "Jack-High"),
-    ("J T 7 6 3", "Jack-High"),
-    ("J T 7 6 2", "Jack-High"),
-    ("J T 7 5 4", "Jack-High"),
-    ("J T 7 5 3", "Jack-High"),
-    ("J T 7 5 2", "Jack-High"),
-    ("J T 7 4 3", "Jack-High"),
-    ("J T 7 4 2", "Jack-High"),
-    ("J T 7 3 2", "Jack-High"),
-    ("J T 6 5 4", "Jack-High"),
-    ("J T 6 5 3", "Jack-High"),
-    ("J T 6 5 2", "Jack-High"),
-    ("J T 6 4 3", "Jack-High"),
-    ("J T 6 4 2", "Jack-High"),
-    ("J T 6 3 2", "Jack-High"),
-    ("J T 5 4 3", "Jack-High"),
-    ("J T 5 4 2", "Jack-High"),
-    ("J T 5 3 2", "Jack-High"),
-    ("J T 4 3 2", "Jack-High"),
-    ("J 9 8 7 6", "Jack-High"),
-    ("J 9 8 7 5", "Jack-High"),
-    ("J 9 8 7 4", "Jack-High"),
-    ("J 9 8 7 3", "Jack-High"),
-    ("J 9 8 7 2", "Jack-High"),
-    ("J 9 8 6 5", "Jack-High"),
-    ("J 9 8 6 4", "Jack-High"),
-    ("J 9 8 6 3", "Jack-High"),
-    ("J 9 8 6 2", "Jack-High"),
-    ("J 9 8 5 4", "Jack-High"),
-    ("J 9 8 5 3", "Jack-High"),
-    ("J 9 8 5 2", "Jack-High"),
-    ("J 9 8 4 3", "Jack-High"),
-    ("J 9 8 4 2", "Jack-High"),
-    ("J 9 8 3 2", "Jack-High"),
-    ("J 9 7 6 5", "Jack-High"),
-    ("J 9 7 6 4", "Jack-High"),
-    ("J 9 7 6 3", "Jack-High"),
-    ("J 9 7 6 2", "Jack-High"),
-    ("J 9 7 5 4", "Jack-High"),
-    ("J 9 7 5 3", "Jack-High"),
-    ("J 9 7 5 2", "Jack-High"),
-    ("J 9 7 4 3", "Jack-High"),
-    ("J 9 7 4 2", "Jack-High"),
-    ("J 9 7 3 2", "Jack-High"),
-    ("J 9 6 5 4", "Jack-High"),
-    ("J 9 6 5 3", "Jack-High"),
-    ("J 9 6 5 2", "Jack-High"),
-    ("J 9 6 4 3", "Jack-High"),
-    ("J 9 6 4 2", "Jack-High"),
-    ("J 9 6 3 2", "Jack-High"),
-    ("J 9 5 4 3", "Jack-High"),
-    ("J 9 5 4 2", "Jack-High"),
-    ("J 9 5 3 2", "Jack-High"),
-    ("J 9 4 3 2", "Jack-High"),
-    ("J 8 7 6 5", "Jack-High"),
-    ("J 8 7 6 4", "Jack-High"),
-    ("J 8 7 6 3", "Jack-High"),
-    ("J 8 7 6 2", "Jack-High"),
-    ("J 8 7 5 4", "Jack-High"),
-    ("J 8 7 5 3", "Jack-High"),
-    ("J 8 7 5 2", "Jack-High"),
-    ("J 8 7 4 3", "Jack-High"),
-    ("J 8 7 4 2", "Jack-High"),
-    ("J 8 7 3 2", "Jack-High"),
-    ("J 8 6 5 4", "Jack-High"),
-    ("J 8 6 5 3", "Jack-High"),
-    ("J 8 6 5 2", "Jack-High"),
-    ("J 8 6 4 3", "Jack-High"),
-    ("J 8 6 4 2", "Jack-High"),
-    ("J 8 6 3 2", "Jack-High"),
-    ("J 8 5 4 3", "Jack-High"),
-    ("J 8 5 4 2", "Jack-High"),
-    ("J 8 5 3 2", "Jack-High"),
-    ("J 8 4 3 2", "Jack-High"),
-    ("J 7 6 5 4", "Jack-High"),
-    ("J 7 6 5 3", "Jack-High"),
-    ("J 7 6 5 2", "Jack-High"),
-    ("J 7 6 4 3", "Jack-High"),
-    ("J 7 6 4 2", "Jack-High"),
-    ("J 7 6 3 2", "Jack-High"),
-    ("J 7 5 4 3", "Jack-High"),
-    ("J 7 5 4 2", "Jack-High"),
-    ("J 7 5 3 2", "Jack-High"),
-    ("J 7 4 3 2", "Jack-High"),
-    ("J 6 5 4 3", "Jack-High"),
-    ("J 6 5 4 2", "Jack-High"),
-    ("J 6 5 3 2", "Jack-High"),
-    ("J 6 4 3 2", "Jack-High"),
-    ("J 5 4 3 2", "Jack-High"),
-    ("T 9 8 7 5", "Ten-High"),
-    ("T 9 8 7 4", "Ten-High"),
-    ("T 9 8 7 3", "Ten-High"),
-    ("T 9 8 7 2", "Ten-High"),
-    ("T 9 8 6 5", "Ten-High"),
-    ("T 9 8 6 4", "Ten-High"),
-    ("T 9 8 6 3", "Ten-High"),
-    ("T 9 8 6 2", "Ten-High"),
-    ("T 9 8 5 4", "Ten-High"),
-    ("T 9 8 5 3", "Ten-High"),
-    ("T 9 8 5 2", "Ten-High"),
-    ("T 9 8 4 3", "Ten-High"),
-    ("T 9 8 4 2", "Ten-High"),
-    ("T 9 8 3 2", "Ten-High"),
-    ("T 9 7 6 5", "Ten-High"),
-    ("T 9 7 6 4", "Ten-High"),
-    ("T 9 7 6 3", "Ten-High"),
-    ("T 9 7 6 2", "Ten-High"),
-    ("T 9 7 5 4", "Ten-High"),
-    ("T 9 7 5 3", "Ten-High"),
-    ("T 9 7 5 2", "Ten-High"),
-    ("T 9 7 4 3", "Ten-High"),
-    ("T 9 7 4 2", "Ten-High"),
-    ("T 9 7 3 2", "Ten-High"),
-    ("T 9 6 5 4", "Ten-High"),
-    ("T 9 6 5 3", "Ten-High"),
-    ("T 9 6 5 2", "Ten-High"),
-    ("T 9 6 4 3", "Ten-High"),
-    ("T 9 6 4 2", "Ten-High"),
-    ("T 9 6 3 2", "Ten-High"),
-    ("T 9 5 4 3", "Ten-High"),
-    ("T 9 5 4 2", "Ten-High"),
-    ("T 9 5 3 2", "Ten-High"),
-    ("T 9 4 3 2", "Ten-High"),
-    ("T 8 7 6 5", "Ten-High"),
-    ("T 8 7 6 4", "Ten-High"),
-    ("T 8 7 6 3", "Ten-High"),
-    ("T 8 7 6 2", "Ten-High"),
-    ("T 8 7 5 4", "Ten-High"),
-    ("T 8 7 5 3", "Ten-High"),
-    ("T 8 7 5 2", "Ten-High"),
-    ("T 8 7 4 3", "Ten-High"),
-    ("T 8 7 4 2", "Ten-High"),
-    ("T 8 7 3 2", "Ten-High"),
-    ("T 8 6 5 4", "Ten-High"),
-    ("T 8 6 5 3", "Ten-High"),
-    ("T 8 6 5 2", "Ten-High"),
-    ("T 8 6 4 3", "Ten-High"),
-    ("T 8 6 4 2", "Ten-High"),
-    ("T 8 6 3 2", "Ten-High"),
-    ("T 8 5 4 3", "Ten-High"),
-    ("T 8 5 4 2", "Ten-High"),
-    ("T 8 5 3 2", "Ten-High"),
-    ("T 8 4 3 2", "Ten-High"),
-    ("T 7 6 5 4", "Ten-High"),
-    ("T 7 6 5 3", "Ten-High"),
-    ("T 7 6 5 2", "Ten-High"),
-    ("T 7 6 4 3", "Ten-High"),
-    ("T 7 6 4 2", "Ten-High"),
-    ("T 7 6 3 2", "Ten-High"),
-    ("T 7 5 4 3", "Ten-High"),
-    ("T 7 5 4 2", "Ten-High"),
-    ("T 7 5 3 2", "Ten-High"),
-    ("T 7 4 3 2", "Ten-High"),
-    ("T 6 5 4 3", "Ten-High"),
-    ("T 6 5 4 2", "Ten-High"),
-    ("T 6 5 3 2", "Ten-High"),
-    ("T 6 4 3 2", "Ten-High"),
-    ("T 5 4 3 2", "Ten-High"),
-    ("9 8 7 6 4", "Nine-High"),
-    ("9 8 7 6 3", "Nine-High"),
-    ("9 8 7 6 2", "Nine-High"),
-    ("9 8 7 5 4", "Nine-High"),
-    ("9 8 7 5 3", "Nine-High"),
-    ("9 8 7 5 2", "Nine-High"),
-    ("9 8 7 4 3", "Nine-High"),
-    ("9 8 7 4 2", "Nine-High"),
-    ("9 8 7 3 2", "Nine-High"),
-    ("9 8 6 5 4", "Nine-High"),
-    ("9 8 6 5 3", "Nine-High"),
-    ("9 8 6 5 2", "Nine-High"),
-    ("9 8 6 4 3", "Nine-High"),
-    ("9 8 6 4 2", "Nine-High"),
-    ("9 8 6 3 2", "Nine-High"),
-    ("9 8 5 4 3", "Nine-High"),
-    ("9 8 5 4 2", "Nine-High"),
-    ("9 8 5 3 2", "Nine-High"),
-    ("9 8 4 3 2", "Nine-High"),
-    ("9 7 6 5 4", "Nine-High"),
-    ("9 7 6 5 3", "Nine-High"),
-    ("9 7 6 5 2", "Nine-High"),
-    ("9 7 6 4 3", "Nine-High"),
-    ("9 7 6 4 2", "Nine-High"),
-    ("9 7 6 3 2", "Nine-High"),
-    ("9 7 5 4 3", "Nine-High"),
-    ("9 7 5 4 2", "Nine-High"),
-    ("9 7 5 3 2", "Nine-High"),
-    ("9 7 4 3 2", "Nine-High"),
-    ("9 6 5 4 3", "Nine-High"),
-    ("9 6 5 4 2", "Nine-High"),
-    ("9 6 5 3 2", "Nine-High"),
-    ("9 6 4 3 2", "Nine-High"),
-    ("9 5 4 3 2", "Nine-High"),
-    ("8 7 6 5 3", "Eight-High"),
-    ("8 7 6 5 2", "Eight-High"),
-    ("8 7 6 4 3", "Eight-High"),
-    ("8 7 6 4 2", "Eight-High"),
-    ("8 7 6 3 2", "Eight-High"),
-    ("8 7 5 4 3", "Eight-High"),
-    ("8 7 5 4 2", "Eight-High"),
-    ("8 7 5 3 2", "Eight-High"),
-    ("8 7 4 3 2", "Eight-High"),
-    ("8 6 5 4 3", "Eight-High"),
-    ("8 6 5 4 2", "Eight-High"),
-    ("8 6 5 3 2", "Eight-High"),
-    ("8 6 4 3 2", "Eight-High"),
-    ("8 5 4 3 2", "Eight-High"),
-    ("7 6 5 4 2", "Seven-High"),
-    ("7 6 5 3 2", "Seven-High"),
-    ("7 6 4 3 2", "Seven-High"),
-    ("7 5 4 3 2", "Seven-High")
-];
\ No newline at end of file
+// Generated by build.rs, after [http://suffe.cool/poker/7462.html]
+include!(concat!(env!("OUT_DIR"), "/descriptors.rs"));
@@ -0,0 +1,324 @@
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+
+use super::card::*;
+use super::cardset::*;
+use super::combinatorics::*;
+use super::evaluator::*;
+use super::range::*;
+
+/// Equity is how often a hand wins the pot. All values are percentages, 0..100
+#[derive(PartialEq)]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Equity {
+    /// How often the hand wins the whole pot
+    pub win: f64,
+    /// How often the hand splits the pot
+    pub tie: f64,
+    /// The share of the pot the hand wins on average, split pots included
+    pub equity: f64,
+}
+
+/// Computes the equity of each hand when all cards are dealt out
+/// # Param hands: the hole cards of each player
+/// # Param board: the community cards already dealt, 0..5
+/// # Param dead: cards that are known to be out of play
+/// # Param samples: the most boards to evaluate. When the remaining boards are
+/// no more than this they are enumerated exhaustively, otherwise this many are
+/// dealt at random
+/// # Param seed: the seed for the random boards
+/// # Returns: the equity of each hand, in the order of the hands
+pub fn equity(hands: &[[Card; 2]], board: &[Card], dead: &[Card], samples: usize, seed: u64) -> Vec<Equity> {
+    if board.len() > 5 {
+        panic!("Cannot calculate equity with a board of {} cards", board.len());
+    }
+    let holes: Vec<CardSet> = hands.iter().map(|hand| hand.iter().collect()).collect();
+    let known: CardSet = hands.iter().flat_map(|hand| hand.iter()).chain(board.iter()).chain(dead.iter()).collect();
+    if known.len() != hands.len() * 2 + board.len() + dead.len() {
+        panic!("Cannot calculate equity when a card is used twice");
+    }
+
+    let board: CardSet = board.iter().collect();
+    let deck: Vec<usize> = (CardSet::full() - known).ids().collect();
+    let missing = 5 - board.len();
+    let mut tally = Tally::new(hands.len());
+
+    if combinations(deck.len(), missing) <= samples {
+        for_each_combination(&deck, missing, &mut |cards| {
+            tally.add(&holes, board | cards.iter().cloned().collect(), 1.0);
+        });
+    } else {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut deck = deck;
+        for _ in 0..samples {
+            for i in 0..missing {
+                let j = rng.gen_range(i..deck.len());
+                deck.swap(i, j);
+            }
+            tally.add(&holes, board | deck[..missing].iter().cloned().collect(), 1.0);
+        }
+    }
+    tally.equities()
+}
+
+/// Computes the equity of each range against the others when all cards are dealt out.
+/// A single hand is the range `Range::from(hole)`.
+/// # Param ranges: the range of each player
+/// # Param board: the community cards already dealt, 0..5
+/// # Param dead: cards that are known to be out of play
+/// # Param samples: the most deals to evaluate. When every combination of combos and
+/// boards is no more than this they are enumerated exhaustively with their weights,
+/// otherwise combos are drawn by weight and boards dealt at random this many times
+/// # Param seed: the seed for the random deals
+/// # Returns: the equity of each range, in the order of the ranges
+pub fn range_equity(ranges: &[Range], board: &[Card], dead: &[Card], samples: usize, seed: u64) -> Vec<Equity> {
+    if board.len() > 5 {
+        panic!("Cannot calculate equity with a board of {} cards", board.len());
+    }
+    let known: CardSet = board.iter().chain(dead.iter()).collect();
+    if known.len() != board.len() + dead.len() {
+        panic!("Cannot calculate equity when a card is used twice");
+    }
+    let combos: Vec<Vec<Combo>> = ranges.iter().map(|range| {
+        range.available(known).into_iter().filter(|combo| combo.weight > 0.0).collect()
+    }).collect();
+    if combos.iter().any(|combos| combos.is_empty()) {
+        panic!("Cannot calculate equity for a range with no possible combos");
+    }
+    if !possible(&combos, known) {
+        panic!("Cannot calculate equity for ranges that always share a card");
+    }
+
+    let board: CardSet = board.iter().collect();
+    let missing = 5 - board.len();
+    let mut tally = Tally::new(ranges.len());
+    let deals = combos.iter().map(|combos| combos.len() as f64).product::<f64>()
+        * combinations(52 - known.len() - 2 * ranges.len(), missing) as f64;
+
+    if deals <= samples as f64 {
+        assign(&combos, known, &mut Vec::new(), 1.0, &mut |holes, used, weight| {
+            let deck: Vec<usize> = (CardSet::full() - used).ids().collect();
+            for_each_combination(&deck, missing, &mut |cards| {
+                tally.add(holes, board | cards.iter().cloned().collect(), weight);
+            });
+        });
+    } else {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let totals: Vec<f64> = combos.iter().map(|combos| combos.iter().map(|combo| combo.weight).sum()).collect();
+        let mut holes = Vec::with_capacity(ranges.len());
+        for _ in 0..samples {
+            // Every player's combo is drawn again when any two clash, so each assignment
+            // is drawn as often as its weight, as the exhaustive deals count it
+            let used = loop {
+                holes.clear();
+                let mut used = known;
+                for (combos, &total) in combos.iter().zip(totals.iter()) {
+                    let cards = pick(combos, total, &mut rng).cards();
+                    if !(cards & used).is_empty() {
+                        break;
+                    }
+                    used = used | cards;
+                    holes.push(cards);
+                }
+                if holes.len() == ranges.len() {
+                    break used;
+                }
+            };
+            let mut deck: Vec<usize> = (CardSet::full() - used).ids().collect();
+            for i in 0..missing {
+                let j = rng.gen_range(i..deck.len());
+                deck.swap(i, j);
+            }
+            tally.add(&holes, board | deck[..missing].iter().cloned().collect(), 1.0);
+        }
+    }
+    tally.equities()
+}
+
+/// Calls f with every assignment of non-overlapping combos to the players,
+/// along with the cards in use and the weight of the assignment
+fn assign(combos: &[Vec<Combo>], used: CardSet, holes: &mut Vec<CardSet>, weight: f64, f: &mut dyn FnMut(&[CardSet], CardSet, f64)) {
+    if holes.len() == combos.len() {
+        f(holes, used, weight);
+        return;
+    }
+    for combo in combos[holes.len()].iter() {
+        let cards = combo.cards();
+        if !(cards & used).is_empty() {
+            continue;
+        }
+        holes.push(cards);
+        assign(combos, used | cards, holes, weight * combo.weight, f);
+        holes.pop();
+    }
+}
+
+/// Whether the players can be dealt combos that do not share a card
+fn possible(combos: &[Vec<Combo>], used: CardSet) -> bool {
+    match combos.split_first() {
+        Some((first, rest)) => first.iter().any(|combo| (combo.cards() & used).is_empty() && possible(rest, used | combo.cards())),
+        None => true,
+    }
+}
+
+/// Draws a combo with probability proportional to its weight
+fn pick<'a, R: Rng>(combos: &'a [Combo], total: f64, rng: &mut R) -> &'a Combo {
+    let mut target = rng.gen::<f64>() * total;
+    for combo in combos {
+        if target < combo.weight {
+            return combo;
+        }
+        target -= combo.weight;
+    }
+    combos.last().unwrap()
+}
+
+/// Running win, tie and pot share counts over the evaluated boards
+struct Tally {
+    wins: Vec<f64>,
+    ties: Vec<f64>,
+    shares: Vec<f64>,
+    boards: f64,
+    /// The ranks of the board being added, kept to save allocating them for every board
+    ranks: Vec<usize>,
+}
+
+impl Tally {
+    fn new(players: usize) -> Tally {
+        Tally {
+            wins: vec![0.0; players],
+            ties: vec![0.0; players],
+            shares: vec![0.0; players],
+            boards: 0.0,
+            ranks: Vec::with_capacity(players),
+        }
+    }
+
+    fn add(&mut self, holes: &[CardSet], board: CardSet, weight: f64) {
+        let board = Partial::from(board);
+        let ranks = &mut self.ranks;
+        ranks.clear();
+        ranks.extend(holes.iter().map(|&hole| board.with_set(hole).eval()));
+        let best = *ranks.iter().min().unwrap();
+        let winners = ranks.iter().filter(|&&rank| rank == best).count();
+        for (i, &rank) in ranks.iter().enumerate() {
+            if rank != best {
+                continue;
+            }
+            if winners == 1 {
+                self.wins[i] += weight;
+            } else {
+                self.ties[i] += weight;
+            }
+            self.shares[i] += weight / winners as f64;
+        }
+        self.boards += weight;
+    }
+
+    fn equities(&self) -> Vec<Equity> {
+        let percent = |count: f64| if self.boards > 0.0 { 100.0 * count / self.boards } else { 0.0 };
+        (0..self.wins.len()).map(|i| Equity {
+            win: percent(self.wins[i]),
+            tie: percent(self.ties[i]),
+            equity: percent(self.shares[i]),
+        }).collect()
+    }
+}
+
+#[cfg(test)]
+fn cards(names: &str) -> Vec<Card> {
+    if names.is_empty() {
+        return Vec::new();
+    }
+    names.split(' ').map(|name| name.parse().unwrap()).collect()
+}
+
+#[cfg(test)]
+fn hole(names: &str) -> [Card; 2] {
+    let cards = cards(names);
+    [cards[0], cards[1]]
+}
+
+#[test]
+fn test_equity_exhaustive() {
+    let river = equity(&[hole("A♠ A♥"), hole("K♠ K♥")], &cards("2♣ 7♦ 9♠ J♥ 3♦"), &[], 1000, 0);
+    assert_eq!(river[0], Equity { win: 100.0, tie: 0.0, equity: 100.0 });
+    assert_eq!(river[1], Equity { win: 0.0, tie: 0.0, equity: 0.0 });
+
+    let turn = equity(&[hole("A♠ A♥"), hole("K♠ K♥")], &cards("2♣ 7♦ 9♠ J♥"), &[], 1000, 0);
+    assert!((turn[0].win - 100.0 * 42.0 / 44.0).abs() < 1e-9);
+    assert!((turn[1].win - 100.0 * 2.0 / 44.0).abs() < 1e-9);
+
+    let dead = equity(&[hole("A♠ A♥"), hole("K♠ K♥")], &cards("2♣ 7♦ 9♠ J♥"), &cards("K♣"), 1000, 0);
+    assert!((dead[1].win - 100.0 * 1.0 / 43.0).abs() < 1e-9);
+
+    let split = equity(&[hole("A♠ K♠"), hole("A♥ K♥")], &cards("2♣ 7♦ 9♠ J♦"), &[], 1000, 0);
+    assert_eq!(split[0], Equity { win: 0.0, tie: 100.0, equity: 50.0 });
+    assert_eq!(split[1], Equity { win: 0.0, tie: 100.0, equity: 50.0 });
+}
+
+#[test]
+fn test_equity_monte_carlo() {
+    let hands = [hole("A♠ A♥"), hole("K♠ K♥")];
+    let first = equity(&hands, &[], &[], 20000, 7);
+    assert_eq!(first, equity(&hands, &[], &[], 20000, 7));
+    assert!((first[0].equity - 82.0).abs() < 1.5, "{:?}", first);
+    assert!((first[0].equity + first[1].equity - 100.0).abs() < 1e-9);
+
+    let three = equity(&[hole("A♠ A♥"), hole("K♠ K♥"), hole("7♣ 2♦")], &[], &[], 5000, 7);
+    let total: f64 = three.iter().map(|e| e.equity).sum();
+    assert!((total - 100.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_range_equity_exhaustive() {
+    let hero = Range::from(hole("A♠ K♦"));
+    let villain: Range = "QQ, AKo:0.5".parse().unwrap();
+    let river = range_equity(&[hero, villain], &cards("2♣ 7♦ 9♠ J♥ 3♦"), &[], 1000, 0);
+    assert!((river[0].win - 0.0).abs() < 1e-9);
+    assert!((river[0].tie - 100.0 * 3.5 / 9.5).abs() < 1e-9);
+    assert!((river[0].equity - 100.0 * 1.75 / 9.5).abs() < 1e-9);
+    assert!((river[1].equity - 100.0 * 7.75 / 9.5).abs() < 1e-9);
+
+    let hands = [hole("A♠ A♥"), hole("K♠ K♥")];
+    let turn = range_equity(&[Range::from(hands[0]), Range::from(hands[1])], &cards("2♣ 7♦ 9♠ J♥"), &[], 1000, 0);
+    assert_eq!(turn, equity(&hands, &cards("2♣ 7♦ 9♠ J♥"), &[], 1000, 0));
+}
+
+#[test]
+fn test_range_equity_monte_carlo() {
+    let aces: Range = "AA".parse().unwrap();
+    let kings: Range = "KK".parse().unwrap();
+    let first = range_equity(&[aces.clone(), kings.clone()], &[], &[], 20000, 3);
+    assert_eq!(first, range_equity(&[aces.clone(), kings], &[], &[], 20000, 3));
+    assert!((first[0].equity - 82.0).abs() < 1.5, "{:?}", first);
+
+    let mirror = range_equity(&[aces.clone(), aces], &[], &[], 5000, 3);
+    assert!(mirror[0].tie > 90.0, "{:?}", mirror);
+    assert!((mirror[0].equity + mirror[1].equity - 100.0).abs() < 1e-9);
+
+    let hero = Range::from(hole("J♠ J♥"));
+    let villain: Range = "TT+, AKs, A2s-A5s, KQo".parse().unwrap();
+    let result = range_equity(&[hero, villain], &cards("7♣ 2♦ 3♠"), &[], 5000, 3);
+    assert!(result[0].equity > 40.0 && result[0].equity < 70.0, "{:?}", result);
+}
+
+#[test]
+fn test_range_equity_card_removal() {
+    // The suited aces and kings block more of the aces and kings than of the queens,
+    // which the sampled deals must weigh as the exhaustive ones do
+    let ranges: [Range; 2] = ["AA, KK, QQ".parse().unwrap(), "AKs".parse().unwrap()];
+    let flop = cards("2♣ 7♦ 9♥");
+    let exhaustive = range_equity(&ranges, &flop, &[], 100000, 0);
+    let sampled = range_equity(&ranges, &flop, &[], 20000, 5);
+    for (exhaustive, sampled) in exhaustive.iter().zip(sampled.iter()) {
+        assert!((exhaustive.equity - sampled.equity).abs() < 1.0, "{:?} {:?}", exhaustive, sampled);
+    }
+}
+
+#[test]
+#[should_panic(expected = "always share a card")]
+fn test_range_equity_impossible() {
+    let ranges: [Range; 2] = ["A♠A♥".parse().unwrap(), "A♠K♠, A♥K♥".parse().unwrap()];
+    range_equity(&ranges, &[], &[], 1000, 0);
+}
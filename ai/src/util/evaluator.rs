@@ -1,3 +1,5 @@
+use super::cardset::*;
+use super::combinatorics::*;
 use super::descriptors::*;
 use super::lookup::*;
 
@@ -31,7 +33,7 @@ pub fn category(category: &usize) -> Category {
         167..=322 => Category::FullHouse,
         323..=1599 => Category::Flush,
         1600..=1609 => Category::Straight,
-        1610..=2463 => Category::ThreeOfAKind,
+        1610..=2467 => Category::ThreeOfAKind,
         2468..=3325 => Category::TwoPair,
         3326..=6185 => Category::Pair,
         6186..=7462 => Category::HighCard,
@@ -43,20 +45,20 @@ pub fn category(category: &usize) -> Category {
 /// # Param category: the 32 bit rank of the hand
 /// # Returns: a tuple containing a representative str of cards and a str description
 pub fn description(category: &usize) -> (&'static str, &'static str) {
-    DESCRIPTORS[*category as usize]
+    DESCRIPTORS[*category]
 }
 
 /// The rank id of the card
 /// # Param card: the id of the card, 0..52
 /// # Returns: the rank id of the card, 0..12
-fn rank_from_id(card: &usize) -> usize {
+pub(crate) fn rank_from_id(card: &usize) -> usize {
     (*card >> 2) & 0xFF
 }
 
 /// The suit id of the card
 /// # Param card: the id of the card, 0..52
 /// # Returns: the suit id of the card, 0..4
-fn suit_from_id(card: &usize) -> usize {
+pub(crate) fn suit_from_id(card: &usize) -> usize {
     *card & 0x3
 }
 
@@ -65,39 +67,484 @@ fn suit_from_id(card: &usize) -> usize {
 /// # Param n: the size of the hand, 5..7
 /// # Returns: the rank of the hand
 pub fn eval<'a>(cards: impl Iterator<Item=&'a usize>, n: usize) -> usize {
-    if n < 5 || n > 7 {
+    eval_ids(cards.cloned(), n)
+}
+
+/// Evaluates a set of cards to a rank, 1..7462
+/// # Param cards: the set of cards, 5..7 of them
+/// # Returns: the rank of the hand
+pub fn eval_set(cards: CardSet) -> usize {
+    eval_ids(cards.ids(), cards.len())
+}
+
+/// Evaluates an Omaha hand to a rank, 1..7462. The hand must use exactly
+/// two hole cards and three board cards.
+/// # Param hole: the hole cards, at least 2 of them
+/// # Param board: the board cards, at least 3 of them
+/// # Returns: the rank of the best hand
+pub fn eval_omaha(hole: CardSet, board: CardSet) -> usize {
+    if hole.len() < 2 || board.len() < 3 {
+        panic!("Cannot evaluate an Omaha hand of {} hole and {} board cards", hole.len(), board.len());
+    }
+    let hole: Vec<usize> = hole.ids().collect();
+    let board: Vec<usize> = board.ids().collect();
+    let mut best = usize::MAX;
+    for_each_combination(&hole, 2, &mut |hole| {
+        for_each_combination(&board, 3, &mut |board| {
+            best = best.min(eval(hole.iter().chain(board.iter()), 5));
+        });
+    });
+    best
+}
+
+/// Evaluates a short deck (6+) hand to a rank, 1..7462. The rank keeps the
+/// meaning it has in a full deck, so `category` and `description` still apply,
+/// except that A-6-7-8-9 is the wheel and takes the rank of the five-high straight.
+/// Compare short deck ranks with `short_deck_order`, since a flush beats a full house.
+/// # Param cards: the set of cards, 5..7 of them
+/// # Returns: the rank of the best hand
+pub fn eval_short_deck(cards: CardSet) -> usize {
+    if !(5..=7).contains(&cards.len()) {
+        panic!("Cannot evaluate a hand of size {}. Only 5, 6, or 7 card hands", cards.len());
+    }
+    let ids: Vec<usize> = cards.ids().collect();
+    let mut best = None;
+    for_each_combination(&ids, 5, &mut |hand| {
+        let rank = short_deck_wheel(hand).unwrap_or_else(|| eval(hand.iter(), 5));
+        if best.is_none_or(|best| short_deck_order(&rank) < short_deck_order(&best)) {
+            best = Some(rank);
+        }
+    });
+    best.unwrap()
+}
+
+/// Orders short deck ranks, lower is better
+/// # Param rank: a rank from `eval_short_deck`
+/// # Returns: a key that sorts flushes above full houses
+pub fn short_deck_order(rank: &usize) -> usize {
+    match category(rank) {
+        Category::FullHouse => *rank + (1599 - 322),
+        Category::Flush => *rank - (323 - 167),
+        _ => *rank,
+    }
+}
+
+/// A description of a short deck rank, where A-6-7-8-9 is the nine high wheel
+pub fn short_deck_description(rank: &usize) -> (&'static str, &'static str) {
+    match *rank {
+        10 => ("9 8 7 6 A", "Nine-High Straight Flush"),
+        1609 => ("9 8 7 6 A", "Nine-High Straight"),
+        rank => description(&rank),
+    }
+}
+
+/// The rank of a five card A-6-7-8-9 straight, which is the lowest straight of a short deck
+fn short_deck_wheel(hand: &[usize]) -> Option<usize> {
+    let mut ranks: Vec<usize> = hand.iter().map(rank_from_id).collect();
+    ranks.sort();
+    if ranks != [4, 5, 6, 7, 12] {
+        return None;
+    }
+    let suit = suit_from_id(&hand[0]);
+    Some(if hand.iter().all(|card| suit_from_id(card) == suit) { 10 } else { 1609 })
+}
+
+/// Evaluates the ace-to-five low of a hand, for the low half of a split pot.
+/// Straights and flushes do not count against a low, pairs do, and the
+/// hand only qualifies with five different ranks of eight or lower.
+/// # Param cards: the set of cards, 5 or more of them
+/// # Returns: the rank of the best low, lower is better, 5-4-3-2-A being the best,
+/// or None if the hand does not qualify
+pub fn eval_low(cards: CardSet) -> Option<usize> {
+    let mut ranks: Vec<usize> = cards.ids().map(|id| low_rank(&id)).filter(|&rank| rank <= 8).collect();
+    ranks.sort();
+    ranks.dedup();
+    if ranks.len() < 5 {
+        return None;
+    }
+    Some(ranks[..5].iter().rev().fold(0, |low, &rank| (low << 4) | rank))
+}
+
+/// Evaluates the ace-to-five low of an Omaha hand, which must use exactly
+/// two hole cards and three board cards.
+/// # Param hole: the hole cards, at least 2 of them
+/// # Param board: the board cards, at least 3 of them
+/// # Returns: the rank of the best low, or None if the hand does not qualify
+pub fn eval_omaha_low(hole: CardSet, board: CardSet) -> Option<usize> {
+    let hole: Vec<usize> = hole.ids().collect();
+    let board: Vec<usize> = board.ids().collect();
+    let mut best = None;
+    for_each_combination(&hole, 2, &mut |hole| {
+        for_each_combination(&board, 3, &mut |board| {
+            let low = eval_low(hole.iter().chain(board.iter()).cloned().collect());
+            if low.is_some() && (best.is_none() || low < best) {
+                best = low;
+            }
+        });
+    });
+    best
+}
+
+/// Evaluates a deuce-to-seven lowball hand. The rank keeps the meaning it has
+/// in high, so `description` still names the cards, but straights and flushes
+/// count against the hand and the ace is always high, so A-5-4-3-2 is no straight.
+/// Compare lowball ranks with `deuce_seven_order`.
+/// # Param cards: the set of cards, 5..7 of them
+/// # Returns: the rank of the best five card lowball hand
+pub fn eval_deuce_seven(cards: CardSet) -> usize {
+    if !(5..=7).contains(&cards.len()) {
+        panic!("Cannot evaluate a hand of size {}. Only 5, 6, or 7 card hands", cards.len());
+    }
+    let ids: Vec<usize> = cards.ids().collect();
+    let mut best = None;
+    for_each_combination(&ids, 5, &mut |hand| {
+        let rank = eval(hand.iter(), 5);
+        if best.is_none_or(|best| deuce_seven_order(&rank) < deuce_seven_order(&best)) {
+            best = Some(rank);
+        }
+    });
+    best.unwrap()
+}
+
+/// Orders deuce-to-seven ranks, lower is better
+/// # Param rank: a rank from `eval_deuce_seven`
+/// # Returns: the lowball rank, 1..7462, 7-5-4-3-2 being the best
+pub fn deuce_seven_order(rank: &usize) -> usize {
+    LOWBALL[*rank] as usize
+}
+
+/// Category of a deuce-to-seven rank, where A-5-4-3-2 is only ace high
+pub fn deuce_seven_category(rank: &usize) -> Category {
+    match *rank {
+        10 => Category::Flush,
+        1609 => Category::HighCard,
+        rank => category(&rank),
+    }
+}
+
+/// A description of a deuce-to-seven rank, where A-5-4-3-2 is only ace high
+pub fn deuce_seven_description(rank: &usize) -> (&'static str, &'static str) {
+    match *rank {
+        10 => ("A 5 4 3 2", "Ace-High Flush"),
+        1609 => ("A 5 4 3 2", "Ace-High"),
+        rank => description(&rank),
+    }
+}
+
+/// A description of an ace-to-five low
+/// # Param low: the rank of the low from `eval_low`
+/// # Returns: the cards from the highest down, e.g. "8 5 4 2 A", and a name, e.g. "Eight-Low"
+pub fn low_description(low: &usize) -> (String, String) {
+    const FIGURES: [(&str, &str); 9] = [
+        ("", ""), ("A", "Ace"), ("2", "Two"), ("3", "Three"), ("4", "Four"),
+        ("5", "Five"), ("6", "Six"), ("7", "Seven"), ("8", "Eight"),
+    ];
+    let ranks: Vec<usize> = (0..5).rev().map(|i| (*low >> (4 * i)) & 0xF).collect();
+    let cards: Vec<&str> = ranks.iter().map(|&rank| FIGURES[rank].0).collect();
+    (cards.join(" "), format!("{}-Low", FIGURES[ranks[0]].1))
+}
+
+/// The value of a card in a low hand, the ace counting as one
+fn low_rank(card: &usize) -> usize {
+    match rank_from_id(card) {
+        12 => 1,
+        rank => rank + 2,
+    }
+}
+
+fn eval_ids(cards: impl Iterator<Item=usize>, n: usize) -> usize {
+    if !(5..=7).contains(&n) {
         panic!("Cannot evaluate a hand of size {}. Only 5, 6, or 7 card hands", n);
     }
-    let mut sh: usize = 0;
-    let mut sbin: [usize; 4] = [0; 4];
-    let mut quin: [usize; 13] = [0; 13];
+    cards.fold(Partial::new(), Partial::with).eval()
+}
+
+/// Evaluates many hands at once, e.g. all the deals of a simulation
+/// # Param hands: the sets of cards, 5..7 in each
+/// # Param ranks: receives the rank of each hand, as many as there are hands
+pub fn eval_batch(hands: &[CardSet], ranks: &mut [usize]) {
+    if hands.len() != ranks.len() {
+        panic!("Cannot write {} ranks into {}", hands.len(), ranks.len());
+    }
+    for (hand, rank) in hands.iter().zip(ranks.iter_mut()) {
+        *rank = Partial::from(*hand).eval();
+    }
+}
+
+/// Partial is the evaluator state of some of the cards of a hand. It is built
+/// once for the cards hands have in common, such as the board, and then each
+/// hand's own cards are added to a copy of it, which costs a few table lookups.
+#[derive(Eq, PartialEq)]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Partial {
+    suit_hash: usize,
+    suit_bins: [usize; 4],
+    quinary: [u8; 13],
+    len: usize,
+}
+
+impl Partial {
+    pub fn new() -> Partial {
+        Partial::default()
+    }
+
+    /// Adds a card, which must not be in the state already
+    /// # Param card: the id of the card, 0..52
+    /// # Returns: the state with the card
+    pub fn with(mut self, card: usize) -> Partial {
+        self.suit_hash += SUIT_BINS[card];
+        self.suit_bins[suit_from_id(&card)] |= VALUE_BINS[card];
+        self.quinary[rank_from_id(&card)] += 1;
+        self.len += 1;
+        self
+    }
+
+    /// Adds a set of cards, none of which may be in the state already
+    pub fn with_set(self, cards: CardSet) -> Partial {
+        cards.ids().fold(self, Partial::with)
+    }
 
-    for card in cards {
-        let id = *card as usize;
-        let suit = suit_from_id(card) as usize;
-        let rank = rank_from_id(card) as usize;
-        sh += SUIT_BINS[id] as usize;
-        sbin[suit] |= VALUE_BINS[id] as usize;
-        quin[rank] += 1;
+    pub fn len(&self) -> usize {
+        self.len
     }
 
-    if SUITS[sh] != 0 {
-        return FLUSH[sbin[SUITS[sh] as usize - 1]] as usize;
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
     }
 
-    let mut hash: usize = 0;
-    let mut k: usize = n;
-    for i in 0..13 {
-        hash += DP[quin[i]][13 - i - 1][k] as usize;
-        if k <= quin[i] {
-            break;
+    /// Evaluates the cards added so far to a rank, 1..7462
+    /// # Returns: the rank of the hand, which must have 5..7 cards
+    pub fn eval(&self) -> usize {
+        if SUITS[self.suit_hash] != 0 {
+            return FLUSH[self.suit_bins[SUITS[self.suit_hash] - 1]] as usize;
+        }
+
+        let mut hash: usize = 0;
+        let mut k: usize = self.len;
+        for i in 0..13 {
+            let count = self.quinary[i] as usize;
+            hash += DP[count][13 - i - 1][k];
+            if k <= count {
+                break;
+            }
+            k -= count;
+        }
+        (match self.len {
+            5 => NOFLUSH5[hash],
+            6 => NOFLUSH6[hash],
+            7 => NOFLUSH7[hash],
+            n => panic!("Cannot evaluate a hand of size {}. Only 5, 6, or 7 card hands", n),
+        }) as usize
+    }
+}
+
+impl From<CardSet> for Partial {
+    fn from(cards: CardSet) -> Partial {
+        Partial::new().with_set(cards)
+    }
+}
+
+#[test]
+fn test_eval_set() {
+    let names = ["A♠ K♠ Q♠ J♠ T♠ 2♣ 3♦", "A♠ A♥ A♦ K♣ K♠ 2♣ 3♦", "7♠ 5♥ 4♦ 3♣ 2♠"];
+    for names in names.iter() {
+        let set: CardSet = names.split(' ').map(|name| name.parse::<super::card::Card>().unwrap()).collect();
+        let ids: Vec<usize> = set.ids().collect();
+        assert_eq!(eval_set(set), eval(ids.iter(), ids.len()));
+    }
+}
+
+#[test]
+fn test_eval_omaha() {
+    let set = |names: &str| names.split(' ').map(|name| name.parse::<super::card::Card>().unwrap()).collect::<CardSet>();
+
+    let one_spade = eval_omaha(set("A♠ 3♥ 4♦ 8♣"), set("2♠ 5♠ 9♠ K♠ 7♦"));
+    assert_eq!(category(&one_spade), Category::HighCard);
+    let two_spades = eval_omaha(set("A♠ Q♠ 3♥ 4♦"), set("2♠ 5♠ 9♠ K♠ 7♦"));
+    assert_eq!(category(&two_spades), Category::Flush);
+    assert_eq!(description(&two_spades).0, "A K Q 9 5");
+
+    let paired_board = eval_omaha(set("K♠ 9♦ 8♣ 7♥"), set("K♥ K♦ 5♣ 5♠ 2♥"));
+    assert_eq!(category(&paired_board), Category::ThreeOfAKind);
+    assert_eq!(eval_omaha(set("K♠ 9♦"), set("K♥ K♦ 5♣")), eval(set("K♠ 9♦ K♥ K♦ 5♣").ids().collect::<Vec<usize>>().iter(), 5));
+}
+
+#[test]
+fn test_eval_short_deck() {
+    let set = |names: &str| names.split(' ').map(|name| name.parse::<super::card::Card>().unwrap()).collect::<CardSet>();
+
+    let flush = eval_short_deck(set("A♠ J♠ 9♠ 7♠ 6♠ 6♥ 6♦"));
+    assert_eq!(category(&flush), Category::Flush);
+    let full_house = eval_short_deck(set("K♠ K♥ K♦ Q♣ Q♠"));
+    assert_eq!(category(&full_house), Category::FullHouse);
+    assert!(short_deck_order(&flush) < short_deck_order(&full_house));
+    assert!(short_deck_order(&eval_short_deck(set("A♠ A♥ A♦ A♣ K♠"))) < short_deck_order(&flush));
+
+    let wheel = eval_short_deck(set("A♠ 6♥ 7♦ 8♣ 9♠ K♥ K♦"));
+    assert_eq!(category(&wheel), Category::Straight);
+    assert!(wheel > eval_short_deck(set("T♠ 6♥ 7♦ 8♣ 9♠")));
+    assert!(wheel < eval_short_deck(set("A♠ A♥ A♦ K♣ Q♠")));
+    assert_eq!(eval_short_deck(set("A♠ 6♠ 7♠ 8♠ 9♠")), 10);
+    assert_eq!(short_deck_description(&wheel), ("9 8 7 6 A", "Nine-High Straight"));
+
+    let ranks: Vec<usize> = (1..=7462).map(|rank| short_deck_order(&rank)).collect();
+    let mut sorted = ranks.clone();
+    sorted.sort();
+    sorted.dedup();
+    assert_eq!(sorted, (1..=7462).collect::<Vec<usize>>());
+}
+
+#[test]
+fn test_eval_low() {
+    let set = |names: &str| names.split(' ').map(|name| name.parse::<super::card::Card>().unwrap()).collect::<CardSet>();
+
+    let wheel = eval_low(set("A♠ 2♠ 3♠ 4♠ 5♠ K♥ K♦"));
+    assert_eq!(wheel, Some(0x54321));
+    assert!(wheel < eval_low(set("A♠ 2♥ 3♦ 4♣ 6♠")));
+    assert!(eval_low(set("8♠ 5♥ 4♦ 3♣ 2♠")) < eval_low(set("8♠ 6♥ 4♦ 3♣ 2♠")));
+    assert_eq!(eval_low(set("A♠ 2♥ 7♦ 8♣ 7♠ 8♥ 9♦")), None);
+    assert_eq!(eval_low(set("A♠ A♥ 2♦ 3♣ 4♠ 5♥ K♦")), Some(0x54321));
+    assert_eq!(eval_low(set("9♠ 5♥ 4♦ 3♣ 2♠")), None);
+
+    // Omaha must use two hole cards, so a board low does not play by itself
+    assert_eq!(eval_omaha_low(set("K♠ K♥ Q♦ Q♣"), set("A♠ 2♥ 3♦ 4♣ 5♠")), None);
+    assert_eq!(eval_omaha_low(set("A♥ 2♣ K♦ Q♣"), set("3♠ 4♥ 8♦ K♣ Q♠")), Some(0x84321));
+    assert_eq!(eval_omaha_low(set("A♥ 2♣ 3♦ Q♣"), set("3♠ 4♥ 8♦ K♣ 5♠")), Some(0x54321));
+
+    assert_eq!(low_description(&0x54321), ("5 4 3 2 A".to_string(), "Five-Low".to_string()));
+    assert_eq!(low_description(&0x85421), ("8 5 4 2 A".to_string(), "Eight-Low".to_string()));
+}
+
+#[test]
+fn test_eval_deuce_seven() {
+    let set = |names: &str| names.split(' ').map(|name| name.parse::<super::card::Card>().unwrap()).collect::<CardSet>();
+    let order = |names: &str| deuce_seven_order(&eval_deuce_seven(set(names)));
+
+    assert_eq!(order("7♠ 5♥ 4♦ 3♣ 2♠"), 1);
+    assert_eq!(order("A♠ K♠ Q♠ J♠ T♠"), 7462);
+    assert!(order("A♠ A♥ A♦ A♣ K♠") < order("6♠ 5♠ 4♠ 3♠ 2♠"));
+    assert!(order("7♠ 5♥ 4♦ 3♣ 2♠") < order("7♠ 6♥ 4♦ 3♣ 2♠"));
+    assert!(order("8♠ 5♥ 4♦ 3♣ 2♠") < order("8♠ 6♥ 5♦ 4♣ 3♠"));
+    assert!(order("7♠ 5♠ 4♠ 3♠ 2♠") > order("K♠ Q♥ J♦ T♣ 8♠"));
+    assert!(order("6♠ 5♥ 4♦ 3♣ 2♠") > order("A♠ K♥ Q♦ J♣ 9♠"));
+    assert!(order("9♠ 9♥ 4♦ 3♣ 2♠") > order("A♠ K♥ Q♦ J♣ 9♠"));
+
+    // The ace is high, so A-5-4-3-2 is the best ace high hand, not a straight
+    let ace_five = eval_deuce_seven(set("A♠ 5♥ 4♦ 3♣ 2♠"));
+    assert_eq!(deuce_seven_category(&ace_five), Category::HighCard);
+    assert_eq!(deuce_seven_description(&ace_five), ("A 5 4 3 2", "Ace-High"));
+    assert!(order("A♠ 5♥ 4♦ 3♣ 2♠") > order("K♠ Q♥ J♦ T♣ 8♠"));
+    assert!(order("A♠ 5♥ 4♦ 3♣ 2♠") < order("A♠ 6♥ 4♦ 3♣ 2♠"));
+    assert!(order("A♠ 5♠ 4♠ 3♠ 2♠") > order("K♠ Q♠ J♠ T♠ 8♠"));
+    assert!(order("A♠ 5♠ 4♠ 3♠ 2♠") < order("A♠ 6♠ 4♠ 3♠ 2♠"));
+    assert_eq!(deuce_seven_category(&eval_deuce_seven(set("A♠ 5♠ 4♠ 3♠ 2♠"))), Category::Flush);
+
+    // Of more cards the best five are kept, here avoiding the pair and the straight
+    assert_eq!(order("7♠ 7♥ 6♦ 5♣ 4♠ 3♥ 2♦"), order("7♠ 5♥ 4♦ 3♣ 2♠"));
+
+    let mut orders: Vec<usize> = (1..=7462).map(|rank| deuce_seven_order(&rank)).collect();
+    orders.sort();
+    assert_eq!(orders, (1..=7462).collect::<Vec<usize>>());
+}
+
+#[test]
+fn test_eval_exhaustive() {
+    const CHARS: [char; 13] = ['2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A'];
+    let mut seen = vec![false; 7463];
+    let mut counts = [0usize; 9];
+    let mut hand = [0usize; 5];
+    for a in 0..52 {
+        hand[0] = a;
+        for b in a + 1..52 {
+            hand[1] = b;
+            for c in b + 1..52 {
+                hand[2] = c;
+                for d in c + 1..52 {
+                    hand[3] = d;
+                    for e in d + 1..52 {
+                        hand[4] = e;
+                        let rank = eval(hand.iter(), 5);
+                        assert!((1..=7462).contains(&rank));
+                        seen[rank] = true;
+
+                        // Classify the hand without the tables: ranks by count, then by rank
+                        let mut quin = [0usize; 13];
+                        for card in hand.iter() {
+                            quin[rank_from_id(card)] += 1;
+                        }
+                        let mut ranks: Vec<usize> = (0..13).rev().filter(|&r| quin[r] > 0).collect();
+                        ranks.sort_by(|x, y| quin[*y].cmp(&quin[*x]));
+                        let flush = hand.iter().all(|card| suit_from_id(card) == suit_from_id(&hand[0]));
+                        let wheel = ranks == [12, 3, 2, 1, 0];
+                        let straight = ranks.len() == 5 && (ranks[0] - ranks[4] == 4 || wheel);
+                        let expected = match (flush, straight, quin[ranks[0]], ranks.len()) {
+                            (true, true, _, _) => Category::StraightFlush,
+                            (_, _, 4, _) => Category::FourOfAKind,
+                            (_, _, 3, 2) => Category::FullHouse,
+                            (true, _, _, _) => Category::Flush,
+                            (_, true, _, _) => Category::Straight,
+                            (_, _, 3, _) => Category::ThreeOfAKind,
+                            (_, _, 2, 3) => Category::TwoPair,
+                            (_, _, 2, _) => Category::Pair,
+                            _ => Category::HighCard,
+                        };
+                        assert_eq!(category(&rank), expected, "{:?}", hand);
+                        counts[expected as usize] += 1;
+
+                        if wheel {
+                            ranks.rotate_left(1);
+                        }
+                        let cards = ranks.iter().flat_map(|&r| std::iter::repeat_n(CHARS[r], quin[r]));
+                        assert!(description(&rank).0.chars().filter(|&c| c != ' ').eq(cards), "{:?}", hand);
+                    }
+                }
+            }
         }
-        k -= quin[i];
-    }
-    (match n {
-        5 => NOFLUSH5[hash],
-        6 => NOFLUSH6[hash],
-        7 => NOFLUSH7[hash],
-        _ => panic!("Failed during hash lookup!")
-    }) as usize
-}
\ No newline at end of file
+    }
+    assert!(seen[1..].iter().all(|&seen| seen));
+    for rank in 1..=7462 {
+        let name = description(&rank).1;
+        let named = match category(&rank) {
+            Category::StraightFlush => name.ends_with("Straight Flush") || name == "Royal Flush",
+            Category::FourOfAKind => name.starts_with("Four "),
+            Category::FullHouse => name.contains(" Full over "),
+            Category::Flush => name.ends_with("-High Flush"),
+            Category::Straight => name.ends_with("-High Straight"),
+            Category::ThreeOfAKind => name.starts_with("Three "),
+            Category::TwoPair => name.contains(" and "),
+            Category::Pair => name.starts_with("Pair of "),
+            Category::HighCard => name.ends_with("-High"),
+        };
+        assert!(named, "{} is {:?}", name, category(&rank));
+    }
+    assert_eq!(counts, [40, 624, 3744, 5108, 10200, 54912, 123552, 1098240, 1302540]);
+    assert_eq!(counts.iter().sum::<usize>(), 2598960);
+}
+
+#[test]
+fn test_eval_partial() {
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+    use super::deck::Deck;
+
+    let mut rng = StdRng::seed_from_u64(14);
+    let mut hands = Vec::new();
+    for _ in 0..1000 {
+        let mut deck = Deck::new();
+        deck.shuffle(&mut rng);
+        let board: CardSet = (0..5).map(|_| deck.deal().unwrap()).collect();
+        let hole: CardSet = (0..2).map(|_| deck.deal().unwrap()).collect();
+
+        let partial = Partial::from(board);
+        assert_eq!(partial.len(), 5);
+        assert_eq!(partial.eval(), eval_set(board));
+        let turn = hole.ids().next().unwrap();
+        assert_eq!(partial.with(turn).eval(), eval_set(board | CardSet::from_bits(1 << turn)));
+        assert_eq!(partial.with_set(hole).eval(), eval_set(board | hole));
+        hands.push(board | hole);
+    }
+
+    let mut ranks = vec![0; hands.len()];
+    eval_batch(&hands, &mut ranks);
+    assert!(hands.iter().zip(ranks.iter()).all(|(&hand, &rank)| rank == eval_set(hand)));
+}
@@ -0,0 +1,308 @@
+use std::collections::{BTreeSet, HashMap};
+
+use super::cardset::*;
+use super::combinatorics::*;
+use super::evaluator::{rank_from_id, suit_from_id};
+
+/// How many cards of one suit are dealt in each round
+type Shape = Vec<usize>;
+
+/// HandIndexer maps the cards dealt over several rounds to a dense index that is the same
+/// for every hand that differs only by a renaming of the suits, e.g. 169 indices for the
+/// hole cards and 1,286,792 for the hole cards and the flop.
+/// Hands are counted by the shape of each suit: how many of its cards come in each round.
+/// A configuration is the shapes of the four suits, from the largest, and every
+/// configuration has a block of indices. Within a block the ranks of each suit are
+/// numbered, and suits of the same shape are interchangeable so only their multiset counts.
+pub struct HandIndexer {
+    cards: Vec<usize>,
+    rounds: Vec<Round>,
+}
+
+/// The configurations of the rounds up to one round, and where their indices start
+struct Round {
+    configurations: Vec<Vec<Shape>>,
+    offsets: Vec<usize>,
+    positions: HashMap<Vec<Shape>, usize>,
+}
+
+impl HandIndexer {
+    /// Creates an indexer
+    /// # Param cards: the number of cards dealt in each round, e.g. [2, 3, 1, 1] for the
+    /// hole cards, flop, turn and river
+    /// # Returns: the indexer
+    pub fn new(cards: &[usize]) -> HandIndexer {
+        if cards.is_empty() || cards.contains(&0) || cards.iter().sum::<usize>() > 52 {
+            panic!("Cannot index rounds of {:?} cards", cards);
+        }
+        HandIndexer {
+            cards: cards.to_vec(),
+            rounds: (1..=cards.len()).map(|rounds| Round::new(&cards[..rounds])).collect(),
+        }
+    }
+
+    /// The number of rounds
+    pub fn rounds(&self) -> usize {
+        self.cards.len()
+    }
+
+    /// The number of hands up to a round that differ by more than a renaming of the suits
+    /// # Param round: the last round, 0 for the first
+    /// # Returns: the number of indices of the round
+    pub fn size(&self, round: usize) -> usize {
+        *self.rounds[round].offsets.last().unwrap()
+    }
+
+    /// The index of a hand
+    /// # Param cards: the cards dealt in each round up to the last round of the hand
+    /// # Returns: the index, 0..size of the last round
+    pub fn index(&self, cards: &[CardSet]) -> usize {
+        let valid = !cards.is_empty() && cards.len() <= self.rounds()
+            && cards.iter().zip(self.cards.iter()).all(|(set, &count)| set.len() == count)
+            && cards.iter().fold(CardSet::new(), |all, &set| all | set).len() == cards.iter().map(CardSet::len).sum::<usize>();
+        if !valid {
+            panic!("Cannot index {:?} cards with rounds of {:?} cards", cards.iter().map(CardSet::len).collect::<Vec<usize>>(), self.cards);
+        }
+        let round = &self.rounds[cards.len() - 1];
+
+        // The shape of each suit and the index of its ranks among those of the shape
+        let mut suits: Vec<(Shape, usize)> = (0..4).map(|suit| {
+            let (mut shape, mut index, mut used) = (Vec::new(), 0, 0u16);
+            for set in cards {
+                let ranks = set.ids().filter(|id| suit_from_id(id) == suit).fold(0u16, |ranks, id| ranks | 1 << rank_from_id(&id));
+                let count = ranks.count_ones() as usize;
+                index = index * combinations(13 - used.count_ones() as usize, count) + colex(&unused_positions(ranks, used));
+                used |= ranks;
+                shape.push(count);
+            }
+            (shape, index)
+        }).collect();
+        suits.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+
+        let shapes: Vec<Shape> = suits.iter().map(|suit| suit.0.clone()).collect();
+        let configuration = round.positions[&shapes];
+        let mut index = 0;
+        for (start, count) in groups(&shapes) {
+            let indices: Vec<usize> = suits[start..start + count].iter().enumerate().map(|(i, suit)| suit.1 + i).collect();
+            index = index * combinations(shape_size(&shapes[start]) + count - 1, count) + colex(&indices);
+        }
+        round.offsets[configuration] + index
+    }
+
+    /// The hand of an index, with the suits named in the order clubs, diamonds, hearts, spades
+    /// # Param round: the last round of the hand, 0 for the first
+    /// # Param index: the index, 0..size of the round
+    /// # Returns: the cards dealt in each round up to the last round
+    pub fn unindex(&self, round: usize, index: usize) -> Vec<CardSet> {
+        if index >= self.size(round) {
+            panic!("Index {} is out of {} hands", index, self.size(round));
+        }
+        let round = &self.rounds[round];
+        let configuration = round.offsets.partition_point(|&offset| offset <= index) - 1;
+        let shapes = &round.configurations[configuration];
+
+        let mut index = index - round.offsets[configuration];
+        let mut suits = [0; 4];
+        for (start, count) in groups(shapes).into_iter().rev() {
+            let size = shape_size(&shapes[start]) + count - 1;
+            let radix = combinations(size, count);
+            for (i, position) in colex_positions(index % radix, count, size).into_iter().enumerate() {
+                suits[start + i] = position - i;
+            }
+            index /= radix;
+        }
+
+        let mut cards = vec![CardSet::new(); shapes[0].len()];
+        for (suit, shape) in shapes.iter().enumerate() {
+            let mut digits = vec![0; shape.len()];
+            let mut index = suits[suit];
+            for round in (0..shape.len()).rev() {
+                let radix = combinations(13 - shape[..round].iter().sum::<usize>(), shape[round]);
+                digits[round] = index % radix;
+                index /= radix;
+            }
+            let mut used = 0u16;
+            for (round, &digit) in digits.iter().enumerate() {
+                let unused: Vec<usize> = (0..13).filter(|rank| used & 1 << rank == 0).collect();
+                for position in colex_positions(digit, shape[round], unused.len()) {
+                    used |= 1 << unused[position];
+                    cards[round].insert_id(unused[position] << 2 | suit);
+                }
+            }
+        }
+        cards
+    }
+}
+
+impl Round {
+    fn new(cards: &[usize]) -> Round {
+        let mut found = BTreeSet::new();
+        configure(cards, 0, 0, cards[0], &mut vec![Vec::new(); 4], &mut found);
+        let configurations: Vec<Vec<Shape>> = found.into_iter().collect();
+        let mut offsets = vec![0];
+        for shapes in configurations.iter() {
+            let size = groups(shapes).into_iter()
+                .map(|(start, count)| combinations(shape_size(&shapes[start]) + count - 1, count))
+                .product::<usize>();
+            offsets.push(offsets.last().unwrap() + size);
+        }
+        let positions = configurations.iter().cloned().enumerate().map(|(i, shapes)| (shapes, i)).collect();
+        Round { configurations, offsets, positions }
+    }
+}
+
+/// Finds every configuration by giving each suit in turn some of the cards left in a round
+fn configure(cards: &[usize], round: usize, suit: usize, left: usize, suits: &mut Vec<Shape>, found: &mut BTreeSet<Vec<Shape>>) {
+    if round == cards.len() {
+        let mut shapes = suits.clone();
+        shapes.sort_by(|a, b| b.cmp(a));
+        found.insert(shapes);
+        return;
+    }
+    let free = 13 - suits[suit].iter().sum::<usize>();
+    let counts = if suit == 3 { left..=left } else { 0..=left };
+    for count in counts.filter(|&count| count <= free) {
+        suits[suit].push(count);
+        if suit == 3 {
+            configure(cards, round + 1, 0, cards.get(round + 1).cloned().unwrap_or(0), suits, found);
+        } else {
+            configure(cards, round, suit + 1, left - count, suits, found);
+        }
+        suits[suit].pop();
+    }
+}
+
+/// The runs of equal shapes, as the start and length of each
+fn groups(shapes: &[Shape]) -> Vec<(usize, usize)> {
+    let mut groups: Vec<(usize, usize)> = Vec::new();
+    for (i, shape) in shapes.iter().enumerate() {
+        match groups.last_mut() {
+            Some(group) if shapes[group.0] == *shape => group.1 += 1,
+            _ => groups.push((i, 1)),
+        }
+    }
+    groups
+}
+
+/// The number of ways to deal the ranks of one suit in a shape
+fn shape_size(shape: &[usize]) -> usize {
+    let mut used = 0;
+    shape.iter().map(|&count| {
+        used += count;
+        combinations(13 - (used - count), count)
+    }).product()
+}
+
+/// The positions of ranks among the ranks not used yet
+fn unused_positions(ranks: u16, used: u16) -> Vec<usize> {
+    (0..13).filter(|rank| ranks & 1 << rank != 0)
+        .map(|rank| rank - (used & ((1 << rank) - 1)).count_ones() as usize)
+        .collect()
+}
+
+/// The colexicographic index of increasing positions among all sets of as many positions
+fn colex(positions: &[usize]) -> usize {
+    positions.iter().enumerate().map(|(i, &position)| combinations(position, i + 1)).sum()
+}
+
+/// The increasing positions, below n, with the given colexicographic index
+fn colex_positions(mut index: usize, k: usize, n: usize) -> Vec<usize> {
+    let mut positions = vec![0; k];
+    for i in (1..=k).rev() {
+        // The largest position whose count does not pass the index
+        let (mut low, mut high) = (i - 1, n);
+        while high - low > 1 {
+            let middle = (low + high) / 2;
+            if combinations(middle, i) <= index {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+        index -= combinations(low, i);
+        positions[i - 1] = low;
+    }
+    positions
+}
+
+#[cfg(test)]
+fn with_suits(cards: &[CardSet], suits: [usize; 4]) -> Vec<CardSet> {
+    cards.iter().map(|set| set.ids().map(|id| id & !0x3 | suits[suit_from_id(&id)]).collect()).collect()
+}
+
+#[test]
+fn test_index_sizes() {
+    assert_eq!(HandIndexer::new(&[2]).size(0), 169);
+    assert_eq!(HandIndexer::new(&[3]).size(0), 1755);
+    assert_eq!(HandIndexer::new(&[5]).size(0), 134459);
+    let holdem = HandIndexer::new(&[2, 3, 1, 1]);
+    assert_eq!(holdem.size(0), 169);
+    assert_eq!(holdem.size(1), 1286792);
+    assert_eq!(holdem.size(2), 55190538);
+    assert_eq!(holdem.size(3), 2428287420);
+}
+
+#[test]
+fn test_index_exhaustive() {
+    use super::preflop::StartingHand;
+
+    let ids: Vec<usize> = (0..52).collect();
+    for &(cards, size) in [(2, 169), (3, 1755)].iter() {
+        let indexer = HandIndexer::new(&[cards]);
+        let mut hands = vec![0; size];
+        for_each_combination(&ids, cards, &mut |ids| {
+            let hand: CardSet = ids.iter().cloned().collect();
+            let index = indexer.index(&[hand]);
+            assert_eq!(index, indexer.index(&with_suits(&[hand], [2, 0, 3, 1])));
+            hands[index] += 1;
+        });
+        assert!(hands.iter().all(|&hands| hands > 0));
+        for index in 0..size {
+            let hand = indexer.unindex(0, index);
+            assert_eq!(hand[0].len(), cards);
+            assert_eq!(indexer.index(&hand), index);
+        }
+    }
+
+    // Preflop the indices are the starting hands
+    let indexer = HandIndexer::new(&[2]);
+    for hand in StartingHand::all() {
+        let combos = hand.combos();
+        let index = indexer.index(&[combos[0].iter().collect()]);
+        assert!(combos.iter().all(|combo| indexer.index(&[combo.iter().collect()]) == index));
+        let cards: Vec<_> = indexer.unindex(0, index)[0].iter().collect();
+        assert_eq!(StartingHand::of([cards[0], cards[1]]), hand);
+    }
+}
+
+#[test]
+fn test_index_rounds() {
+    use rand::{Rng, SeedableRng};
+    use rand::rngs::StdRng;
+    use rand::seq::SliceRandom;
+
+    let indexer = HandIndexer::new(&[2, 3, 1, 1]);
+    let mut rng = StdRng::seed_from_u64(19);
+    for _ in 0..2000 {
+        let mut ids: Vec<usize> = (0..52).collect();
+        ids.shuffle(&mut rng);
+        let cards: Vec<CardSet> = vec![ids[..2].iter().cloned().collect(), ids[2..5].iter().cloned().collect(),
+                                       ids[5..6].iter().cloned().collect(), ids[6..7].iter().cloned().collect()];
+        let mut suits = [0, 1, 2, 3];
+        suits.shuffle(&mut rng);
+        for round in 0..4 {
+            let index = indexer.index(&cards[..=round]);
+            assert!(index < indexer.size(round));
+            assert_eq!(indexer.index(&with_suits(&cards[..=round], suits)), index);
+            assert_eq!(indexer.index(&indexer.unindex(round, index)), index);
+        }
+        // Cards of one round in another are another hand
+        let swapped = [cards[0], cards[1], cards[3], cards[2]];
+        if cards[2].iter().next().unwrap().rank != cards[3].iter().next().unwrap().rank {
+            assert!(indexer.index(&swapped) != indexer.index(&cards));
+        }
+
+        let index = rng.gen_range(0..indexer.size(3));
+        assert_eq!(indexer.index(&indexer.unindex(3, index)), index);
+    }
+}
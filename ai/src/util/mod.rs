@@ -1,3 +1,14 @@
+pub mod best;
+pub mod card;
+pub mod cardset;
+pub mod combinatorics;
+pub mod deck;
+pub mod equity;
 pub mod evaluator;
+pub mod isomorphism;
+pub mod outs;
+pub mod preflop;
+pub mod range;
+pub mod strength;
 mod descriptors;
 mod lookup;
@@ -0,0 +1,201 @@
+use super::card::*;
+use super::cardset::*;
+use super::evaluator::*;
+
+/// Out is a card that improves a hand, and what the hand becomes with it
+#[derive(Eq, PartialEq)]
+#[derive(Copy, Clone, Debug)]
+pub struct Out {
+    pub card: Card,
+    /// The rank of the hand with the card
+    pub rank: usize,
+    /// The category of the hand with the card
+    pub category: Category,
+}
+
+/// Draw is an enum of the hands a Hold'em hand is drawing to
+#[derive(Eq, PartialEq)]
+#[derive(Copy, Clone, Debug)]
+pub enum Draw {
+    /// Four cards of a suit, at least one of them in the hole
+    Flush,
+    /// A straight that two ranks complete, four in a row or a double gutshot
+    OpenEnded,
+    /// A straight that only one rank completes
+    Gutshot,
+    /// On the flop, three cards of a suit that need both the turn and the river
+    BackdoorFlush,
+    /// On the flop, a straight that needs both the turn and the river
+    BackdoorStraight,
+}
+
+/// The cards that improve a hand to a better category, and what it becomes. A card only
+/// counts when the hand is better than the board makes with the card alone, so a card
+/// that pairs the board for everyone is not an out.
+/// # Param hole: the hole cards
+/// # Param board: the flop or the turn
+/// # Returns: the outs, from the lowest card up
+pub fn outs(hole: CardSet, board: CardSet) -> Vec<Out> {
+    check(hole, board);
+    let now = category(&eval_set(hole | board));
+    (CardSet::full() - hole - board).iter().filter_map(|card| {
+        let rank = eval_set(hole | board | card.into());
+        let category = category(&rank);
+        if category < now && category < board_category(board | card.into()) {
+            Some(Out { card, rank, category })
+        } else {
+            None
+        }
+    }).collect()
+}
+
+/// The outs that put a hand ahead of a known opponent. An out that improves the
+/// opponent as well as the hand is only clean if the hand still ends up ahead.
+/// # Param hole: the hole cards
+/// # Param board: the flop or the turn
+/// # Param opponent: the hole cards of the opponent
+/// # Returns: the clean outs, from the lowest card up
+pub fn clean_outs(hole: CardSet, board: CardSet, opponent: CardSet) -> Vec<Out> {
+    if !(opponent & (hole | board)).is_empty() {
+        panic!("Cannot count outs against an opponent holding a known card");
+    }
+    outs(hole, board).into_iter()
+        .filter(|out| !opponent.contains(out.card) && out.rank < eval_set(opponent | board | out.card.into()))
+        .collect()
+}
+
+/// The draws of a Hold'em hand. Draws the board makes alone are not counted,
+/// and a made flush or straight draws to nothing more.
+/// # Param hole: the two hole cards
+/// # Param board: the flop or the turn
+/// # Returns: each kind of draw the hand has, in the order of the Draw enum
+pub fn draws(hole: CardSet, board: CardSet) -> Vec<Draw> {
+    check(hole, board);
+    let cards = hole | board;
+    let mut draws = Vec::new();
+
+    let suited = |cards: CardSet, suit: Suit| cards.iter().filter(|card| card.suit == suit).count();
+    let flush = |count: usize| Suit::ALL.iter().any(|&suit| suited(cards, suit) == count && suited(hole, suit) > 0);
+    let made_flush = Suit::ALL.iter().any(|&suit| suited(cards, suit) >= 5);
+
+    let ranks = rank_mask(cards);
+    let board_ranks = rank_mask(board);
+    let made_straight = straight(ranks);
+    let completing = (0..13).filter(|&rank| ranks & 1 << rank == 0)
+        .filter(|&rank| straight(ranks | 1 << rank) && !straight(board_ranks | 1 << rank))
+        .count();
+
+    if !made_flush && flush(4) {
+        draws.push(Draw::Flush);
+    }
+    if !made_straight && completing >= 2 {
+        draws.push(Draw::OpenEnded);
+    }
+    if !made_straight && completing == 1 {
+        draws.push(Draw::Gutshot);
+    }
+    if board.len() == 3 && !draws.contains(&Draw::Flush) && flush(3) {
+        draws.push(Draw::BackdoorFlush);
+    }
+    if board.len() == 3 && !made_straight && completing == 0 {
+        let backdoor = (0..13).any(|first| (first + 1..13).any(|second| {
+            let runout = 1 << first | 1 << second;
+            ranks & runout == 0 && straight(ranks | runout) && !straight(board_ranks | runout)
+        }));
+        if backdoor {
+            draws.push(Draw::BackdoorStraight);
+        }
+    }
+    draws
+}
+
+/// The best category of a board, which may be too short to evaluate
+fn board_category(board: CardSet) -> Category {
+    if board.len() >= 5 {
+        return category(&eval_set(board));
+    }
+    let mut counts = [0; 13];
+    for card in board.iter() {
+        counts[card.rank.id()] += 1;
+    }
+    match (counts.iter().max().unwrap(), counts.iter().filter(|&&count| count == 2).count()) {
+        (4, _) => Category::FourOfAKind,
+        (3, _) => Category::ThreeOfAKind,
+        (2, 2) => Category::TwoPair,
+        (2, _) => Category::Pair,
+        _ => Category::HighCard,
+    }
+}
+
+/// The ranks of the cards as a mask of rank ids
+fn rank_mask(cards: CardSet) -> u16 {
+    cards.iter().fold(0, |ranks, card| ranks | 1 << card.rank.id())
+}
+
+/// Whether five ranks in a row are in the mask, the ace also playing low
+fn straight(ranks: u16) -> bool {
+    let ranks = ranks << 1 | (ranks >> 12 & 1);
+    (0..10).any(|low| ranks >> low & 0x1F == 0x1F)
+}
+
+fn check(hole: CardSet, board: CardSet) {
+    if hole.len() != 2 || !(3..=4).contains(&board.len()) || !(hole & board).is_empty() {
+        panic!("Cannot find outs of {} hole and {} board cards", hole.len(), board.len());
+    }
+}
+
+#[cfg(test)]
+fn set(names: &str) -> CardSet {
+    names.split(' ').map(|name| name.parse::<Card>().unwrap()).collect()
+}
+
+#[test]
+fn test_outs() {
+    // Nine hearts for the flush, and three aces and three fives for a pair
+    let draw = outs(set("A♥ 5♥"), set("K♥ 9♥ 2♣"));
+    assert_eq!(draw.len(), 15);
+    assert_eq!(draw.iter().filter(|out| out.category == Category::Flush).count(), 9);
+    assert_eq!(draw.iter().filter(|out| out.category == Category::Pair).count(), 6);
+    assert_eq!(draw[0].card.to_string(), "2♥");
+
+    // Open-ended on the turn, the eight straight cards and the pairs of the hole cards
+    let draw = outs(set("9♠ 8♦"), set("7♣ 6♥ 2♠ K♦"));
+    assert_eq!(draw.iter().filter(|out| out.category == Category::Straight).count(), 8);
+    assert_eq!(draw.len(), 14);
+
+    // Overpair: only a full house improves it
+    let overpair = outs(set("A♠ A♦"), set("7♣ 7♥ 2♠"));
+    assert_eq!(overpair.len(), 4);
+    assert!(overpair.iter().all(|out| out.category == Category::FullHouse));
+
+    // A card that pairs the board makes two pair for everyone
+    assert!(outs(set("A♠ K♦"), set("7♣ 7♥ 2♠")).iter().all(|out| out.card.rank != Rank::Two));
+}
+
+#[test]
+fn test_draws() {
+    assert_eq!(draws(set("A♥ 5♥"), set("K♥ 9♥ 2♣")), vec![Draw::Flush, Draw::BackdoorStraight]);
+    assert_eq!(draws(set("9♠ 8♦"), set("7♣ 6♥ 2♠")), vec![Draw::OpenEnded]);
+    assert_eq!(draws(set("9♠ 8♦"), set("6♣ 5♥ K♠")), vec![Draw::Gutshot]);
+    assert_eq!(draws(set("9♠ 7♦"), set("5♣ J♥ 8♠")), vec![Draw::OpenEnded]);
+    assert_eq!(draws(set("A♠ 2♦"), set("3♣ 4♥ K♠")), vec![Draw::Gutshot]);
+    assert_eq!(draws(set("A♥ K♥"), set("Q♥ 7♣ 2♦")), vec![Draw::BackdoorFlush, Draw::BackdoorStraight]);
+    assert_eq!(draws(set("J♥ T♥"), set("9♥ 8♥ 2♦ K♣")), vec![Draw::Flush, Draw::OpenEnded]);
+
+    // The board draws alone, or the hand is made
+    assert_eq!(draws(set("A♠ A♦"), set("5♣ 6♥ 7♠ 8♦")), vec![]);
+    assert_eq!(draws(set("9♠ 8♦"), set("7♣ 6♥ 5♠")), vec![]);
+    assert_eq!(draws(set("A♥ 5♥"), set("K♥ 9♥ 2♥ 3♣")), vec![Draw::Gutshot]);
+}
+
+#[test]
+fn test_clean_outs() {
+    // The flush is behind a full house when the board pairs
+    let clean = clean_outs(set("A♥ 5♥"), set("K♥ 9♥ 2♣"), set("K♣ K♦"));
+    assert_eq!(clean.len(), 8);
+    assert!(clean.iter().all(|out| out.category == Category::Flush && out.card.rank != Rank::Two));
+
+    // Against a worse hand every out is clean
+    let all = outs(set("A♥ 5♥"), set("K♥ 9♥ 2♣"));
+    assert_eq!(clean_outs(set("A♥ 5♥"), set("K♥ 9♥ 2♣"), set("4♣ 3♦")).len(), all.len());
+}
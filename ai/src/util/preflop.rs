@@ -0,0 +1,167 @@
+use std::fmt;
+use std::str::FromStr;
+
+use super::card::*;
+
+// Generated by examples/preflop.rs
+include!("../../data/preflop.rs");
+
+/// StartingHand is one of the 169 classes of hole cards that play alike before the flop,
+/// e.g. `AA`, `AKs` or `72o`
+#[derive(Eq, PartialEq)]
+#[derive(Copy, Clone, Debug, Hash)]
+pub struct StartingHand {
+    pub high: Rank,
+    pub low: Rank,
+    /// Whether both cards are of one suit, never for a pair
+    pub suited: bool,
+}
+
+impl StartingHand {
+    pub const COUNT: usize = 169;
+
+    /// The class of two hole cards
+    pub fn of(cards: [Card; 2]) -> StartingHand {
+        StartingHand {
+            high: cards[0].rank.max(cards[1].rank),
+            low: cards[0].rank.min(cards[1].rank),
+            suited: cards[0].suit == cards[1].suit,
+        }
+    }
+
+    /// The class with the given index
+    /// # Param index: the place in the usual 13 by 13 grid, read row by row from `AA`.
+    /// Suited hands are above the diagonal and offsuit hands below it
+    /// # Returns: the starting hand
+    pub fn from_index(index: usize) -> StartingHand {
+        let (row, column) = (Rank::from_id(12 - index / 13), Rank::from_id(12 - index % 13));
+        StartingHand {
+            high: row.max(column),
+            low: row.min(column),
+            suited: row > column,
+        }
+    }
+
+    /// The index of the class, 0..168
+    pub fn index(&self) -> usize {
+        let (row, column) = if self.suited { (self.high, self.low) } else { (self.low, self.high) };
+        (12 - row.id()) * 13 + 12 - column.id()
+    }
+
+    /// Every starting hand in the order of their indices
+    pub fn all() -> impl Iterator<Item=StartingHand> {
+        (0..StartingHand::COUNT).map(StartingHand::from_index)
+    }
+
+    pub fn is_pair(&self) -> bool {
+        self.high == self.low
+    }
+
+    /// The hole cards of the class, 6 for a pair, 4 suited or 12 offsuit
+    pub fn combos(&self) -> Vec<[Card; 2]> {
+        let mut combos = Vec::new();
+        for &first in Suit::ALL.iter() {
+            for &second in Suit::ALL.iter() {
+                if (self.is_pair() && first >= second) || self.suited != (first == second) {
+                    continue;
+                }
+                combos.push([Card::new(self.high, first), Card::new(self.low, second)]);
+            }
+        }
+        combos
+    }
+
+    /// The equity against random hole cards when all cards are dealt out
+    /// # Param opponents: the number of opponents, 1..9
+    /// # Returns: the share of the pot won on average, a percentage 0..100
+    pub fn equity(&self, opponents: usize) -> f64 {
+        if !(1..=9).contains(&opponents) {
+            panic!("No preflop equity against {} opponents. Only 1 to 9", opponents);
+        }
+        f64::from(VERSUS_RANDOM[self.index()][opponents - 1])
+    }
+
+    /// The equity against another starting hand when all cards are dealt out, averaged
+    /// over the hole cards of both that do not share a card
+    /// # Returns: the share of the pot won on average, a percentage 0..100
+    pub fn equity_against(&self, other: StartingHand) -> f64 {
+        f64::from(HEAD_TO_HEAD[self.index()][other.index()])
+    }
+}
+
+impl fmt::Display for StartingHand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}", self.high.to_char(), self.low.to_char())?;
+        if !self.is_pair() {
+            write!(f, "{}", if self.suited { 's' } else { 'o' })?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for StartingHand {
+    type Err = String;
+
+    /// Parses a starting hand such as `AKs`, `KQo` or `TT`
+    fn from_str(s: &str) -> Result<StartingHand, String> {
+        let chars: Vec<String> = s.chars().map(|c| c.to_string()).collect();
+        let rank = |i: usize| chars.get(i).and_then(|c| Rank::parse(c));
+        let hand = match (rank(0), rank(1), chars.get(2).map(String::as_str), chars.len()) {
+            (Some(first), Some(second), None, 2) if first == second => Some((first, second, false)),
+            (Some(first), Some(second), Some("s"), 3) if first != second => Some((first, second, true)),
+            (Some(first), Some(second), Some("o"), 3) if first != second => Some((first, second, false)),
+            _ => None,
+        };
+        match hand {
+            Some((first, second, suited)) => Ok(StartingHand { high: first.max(second), low: first.min(second), suited }),
+            None => Err(format!("Invalid starting hand '{}'", s)),
+        }
+    }
+}
+
+#[test]
+fn test_starting_hand() {
+    let mut combos = 0;
+    for (index, hand) in StartingHand::all().enumerate() {
+        assert_eq!(hand.index(), index);
+        assert_eq!(hand.to_string().parse::<StartingHand>(), Ok(hand));
+        for cards in hand.combos() {
+            assert_eq!(StartingHand::of(cards), hand);
+            assert_eq!(StartingHand::of([cards[1], cards[0]]), hand);
+        }
+        combos += hand.combos().len();
+    }
+    assert_eq!(combos, 1326);
+
+    assert_eq!(StartingHand::from_index(0).to_string(), "AA");
+    assert_eq!(StartingHand::from_index(1).to_string(), "AKs");
+    assert_eq!(StartingHand::from_index(13).to_string(), "AKo");
+    assert_eq!(StartingHand::from_index(168).to_string(), "22");
+    assert_eq!("T9s".parse::<StartingHand>().unwrap().combos().len(), 4);
+    assert_eq!("KAo".parse::<StartingHand>().unwrap().to_string(), "AKo");
+    assert!("AAs".parse::<StartingHand>().is_err());
+    assert!("AK".parse::<StartingHand>().is_err());
+    assert!("AKx".parse::<StartingHand>().is_err());
+}
+
+#[test]
+fn test_preflop_equity() {
+    let hand = |s: &str| s.parse::<StartingHand>().unwrap();
+    assert!((hand("AA").equity(1) - 85.2).abs() < 0.5);
+    assert!((hand("72o").equity(1) - 34.6).abs() < 0.5);
+    assert!((hand("AA").equity_against(hand("KK")) - 81.9).abs() < 0.5);
+    assert!((hand("AKo").equity_against(hand("22")) - 47.3).abs() < 0.5);
+    for first in StartingHand::all() {
+        for opponents in 1..9 {
+            assert!(first.equity(opponents) > first.equity(opponents + 1));
+        }
+        for second in StartingHand::all() {
+            assert!((first.equity_against(second) + second.equity_against(first) - 100.0).abs() < 0.02);
+        }
+        assert_eq!(first.equity_against(first), 50.0);
+    }
+    let best = StartingHand::all().max_by(|a, b| a.equity(1).partial_cmp(&b.equity(1)).unwrap()).unwrap();
+    let worst = StartingHand::all().min_by(|a, b| a.equity(9).partial_cmp(&b.equity(9)).unwrap()).unwrap();
+    assert_eq!(best.to_string(), "AA");
+    assert_eq!(worst.to_string(), "72o");
+}
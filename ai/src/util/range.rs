@@ -0,0 +1,272 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use super::card::*;
+use super::cardset::*;
+
+/// Combo is one concrete pair of hole cards in a range, with the fraction
+/// of the time the range holds it, 0..1
+#[derive(PartialEq)]
+#[derive(Copy, Clone, Debug)]
+pub struct Combo {
+    pub cards: [Card; 2],
+    pub weight: f64,
+}
+
+impl Combo {
+    pub fn cards(&self) -> CardSet {
+        self.cards.iter().collect()
+    }
+}
+
+/// Range is a weighted set of hole card combos, written in the usual notation,
+/// e.g. `TT+, AKs, A2s-A5s, KQo, AsKs, AKo:0.5`
+#[derive(PartialEq)]
+#[derive(Clone, Debug, Default)]
+pub struct Range {
+    combos: Vec<Combo>,
+}
+
+impl Range {
+    pub fn combos(&self) -> &[Combo] {
+        &self.combos
+    }
+
+    /// The combos that do not use any of the given cards
+    /// # Param dead: the board and any other known cards
+    /// # Returns: the combos still possible
+    pub fn available(&self, dead: CardSet) -> Vec<Combo> {
+        self.combos.iter().filter(|combo| (combo.cards() & dead).is_empty()).cloned().collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.combos.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.combos.is_empty()
+    }
+
+    /// Adds a combo, replacing the weight of the same combo if it is already in the range
+    fn add(&mut self, cards: [Card; 2], weight: f64) {
+        let set: CardSet = cards.iter().collect();
+        match self.combos.iter_mut().find(|combo| combo.cards() == set) {
+            Some(combo) => combo.weight = weight,
+            None => self.combos.push(Combo { cards, weight }),
+        }
+    }
+}
+
+impl From<[Card; 2]> for Range {
+    fn from(cards: [Card; 2]) -> Range {
+        Range {
+            combos: vec![Combo { cards, weight: 1.0 }],
+        }
+    }
+}
+
+/// ParseRangeError is the reason a string could not be read as a range
+#[derive(Eq, PartialEq)]
+#[derive(Clone, Debug)]
+pub enum ParseRangeError {
+    Hand(String),
+    Weight(String),
+}
+
+impl fmt::Display for ParseRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ParseRangeError::Hand(ref s) => write!(f, "invalid hand in range '{}'", s),
+            ParseRangeError::Weight(ref s) => write!(f, "invalid weight in range '{}'", s),
+        }
+    }
+}
+
+impl Error for ParseRangeError {}
+
+/// A class of starting hands such as `AKs`, `AKo`, `AK` or `TT`
+#[derive(Copy, Clone)]
+struct Class {
+    high: Rank,
+    low: Rank,
+    suited: Option<bool>,
+}
+
+impl Class {
+    fn parse(s: &str) -> Option<Class> {
+        let chars: Vec<char> = s.chars().collect();
+        if chars.len() < 2 || chars.len() > 3 {
+            return None;
+        }
+        let first = Rank::parse(&chars[0].to_string())?;
+        let second = Rank::parse(&chars[1].to_string())?;
+        let suited = match chars.get(2) {
+            None => None,
+            Some('s') => Some(true),
+            Some('o') => Some(false),
+            _ => return None,
+        };
+        if first == second && suited.is_some() {
+            return None;
+        }
+        Some(Class {
+            high: first.max(second),
+            low: first.min(second),
+            suited,
+        })
+    }
+
+    fn is_pair(&self) -> bool {
+        self.high == self.low
+    }
+
+    fn with_low(&self, low: Rank) -> Class {
+        Class { low, ..*self }
+    }
+
+    fn combos(&self) -> Vec<[Card; 2]> {
+        let mut combos = Vec::new();
+        for &first in Suit::ALL.iter() {
+            for &second in Suit::ALL.iter() {
+                if self.is_pair() && first >= second {
+                    continue;
+                }
+                if self.suited.is_some_and(|suited| suited != (first == second)) {
+                    continue;
+                }
+                combos.push([Card::new(self.high, first), Card::new(self.low, second)]);
+            }
+        }
+        combos
+    }
+}
+
+/// Expands one comma separated item of a range into its classes
+fn classes(s: &str) -> Option<Vec<Class>> {
+    let ranks = |from: Rank, to: Rank| Rank::ALL.iter().cloned().filter(move |&rank| rank >= from && rank <= to);
+    if let Some(base) = s.strip_suffix('+') {
+        let class = Class::parse(base)?;
+        if class.is_pair() {
+            return Some(ranks(class.low, Rank::Ace).map(|rank| Class { high: rank, low: rank, suited: None }).collect());
+        }
+        return Some(ranks(class.low, class.high.pred()?).map(|rank| class.with_low(rank)).collect());
+    }
+    if let Some(dash) = s.find('-') {
+        let from = Class::parse(&s[..dash])?;
+        let to = Class::parse(&s[dash + 1..])?;
+        if from.is_pair() && to.is_pair() {
+            let (low, high) = (from.low.min(to.low), from.low.max(to.low));
+            return Some(ranks(low, high).map(|rank| Class { high: rank, low: rank, suited: None }).collect());
+        }
+        if from.high != to.high || from.suited != to.suited || from.is_pair() || to.is_pair() {
+            return None;
+        }
+        let (low, high) = (from.low.min(to.low), from.low.max(to.low));
+        return Some(ranks(low, high).map(|rank| from.with_low(rank)).collect());
+    }
+    Some(vec![Class::parse(s)?])
+}
+
+/// Reads an explicit combo such as `AsKs`
+fn explicit(s: &str) -> Option<[Card; 2]> {
+    if s.chars().count() != 4 {
+        return None;
+    }
+    let split = s.char_indices().nth(2)?.0;
+    let first: Card = s[..split].parse().ok()?;
+    let second: Card = s[split..].parse().ok()?;
+    if first == second {
+        return None;
+    }
+    Some([first.max(second), first.min(second)])
+}
+
+impl FromStr for Range {
+    type Err = ParseRangeError;
+
+    fn from_str(s: &str) -> Result<Range, ParseRangeError> {
+        let mut range = Range::default();
+        for item in s.split(',').map(str::trim).filter(|item| !item.is_empty()) {
+            let (hand, weight) = match item.find(':') {
+                Some(colon) => {
+                    let weight = match item[colon + 1..].trim().parse::<f64>() {
+                        Ok(weight) if (0.0..=1.0).contains(&weight) => weight,
+                        _ => return Err(ParseRangeError::Weight(item.to_string())),
+                    };
+                    (item[..colon].trim(), weight)
+                }
+                None => (item, 1.0),
+            };
+            if let Some(cards) = explicit(hand) {
+                range.add(cards, weight);
+                continue;
+            }
+            match classes(hand) {
+                Some(classes) => {
+                    for class in classes {
+                        for cards in class.combos() {
+                            range.add(cards, weight);
+                        }
+                    }
+                }
+                None => return Err(ParseRangeError::Hand(item.to_string())),
+            }
+        }
+        Ok(range)
+    }
+}
+
+#[cfg(test)]
+fn range(s: &str) -> Range {
+    s.parse().unwrap()
+}
+
+#[test]
+fn test_range_classes() {
+    assert_eq!(range("AA").len(), 6);
+    assert_eq!(range("AKs").len(), 4);
+    assert_eq!(range("AKo").len(), 12);
+    assert_eq!(range("AK").len(), 16);
+    assert_eq!(range("KA").len(), 16);
+    assert_eq!(range("KQo").len(), 12);
+    assert_eq!(range("TT+").len(), 30);
+    assert_eq!(range("22+").len(), 78);
+    assert_eq!(range("TT-77").len(), 24);
+    assert_eq!(range("A2s-A5s").len(), 16);
+    assert_eq!(range("A5s-A2s").len(), 16);
+    assert_eq!(range("A2s+").len(), 48);
+    assert_eq!(range("K9o+").len(), 48);
+    assert_eq!(range("AsKs").len(), 1);
+    assert_eq!(range("TT+, AKs, A2s-A5s, KQo").len(), 30 + 4 + 16 + 12);
+    assert_eq!(range("AKs, AKs").len(), 4);
+    assert_eq!(range("").len(), 0);
+
+    let suited = range("AKs");
+    assert!(suited.combos().iter().all(|combo| combo.cards[0].suit == combo.cards[1].suit));
+    let offsuit = range("AKo");
+    assert!(offsuit.combos().iter().all(|combo| combo.cards[0].suit != combo.cards[1].suit));
+}
+
+#[test]
+fn test_range_weights() {
+    let weighted = range("AKs:0.5, QQ");
+    assert_eq!(weighted.len(), 10);
+    assert_eq!(weighted.combos().iter().filter(|combo| combo.weight == 0.5).count(), 4);
+    assert_eq!(range("AKs, AsKs:0.25").combos().iter().filter(|combo| combo.weight == 0.25).count(), 1);
+
+    assert_eq!("AKs:2".parse::<Range>(), Err(ParseRangeError::Weight("AKs:2".to_string())));
+    assert_eq!("AKs:x".parse::<Range>(), Err(ParseRangeError::Weight("AKs:x".to_string())));
+    assert_eq!("AAs".parse::<Range>(), Err(ParseRangeError::Hand("AAs".to_string())));
+    assert_eq!("AKx".parse::<Range>(), Err(ParseRangeError::Hand("AKx".to_string())));
+    assert_eq!("AKs-QJs".parse::<Range>(), Err(ParseRangeError::Hand("AKs-QJs".to_string())));
+    assert_eq!("AsAs".parse::<Range>(), Err(ParseRangeError::Hand("AsAs".to_string())));
+}
+
+#[test]
+fn test_range_card_removal() {
+    let board: CardSet = "A♠ K♥ 2♣".split(' ').map(|name| name.parse::<Card>().unwrap()).collect();
+    assert_eq!(range("AA").available(board).len(), 3);
+    assert_eq!(range("AK").available(board).len(), 9);
+    assert_eq!(range("QQ").available(board).len(), 6);
+}
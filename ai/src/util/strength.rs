@@ -0,0 +1,165 @@
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+
+use super::cardset::*;
+use super::combinatorics::*;
+use super::evaluator::*;
+
+/// Potential is how often a hand's standing changes by the river. Both values are fractions, 0..1
+#[derive(PartialEq)]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Potential {
+    /// How often a hand that is behind now ends up ahead, ties counting half
+    pub positive: f64,
+    /// How often a hand that is ahead now ends up behind, ties counting half
+    pub negative: f64,
+}
+
+/// Immediate hand strength: how often the hand is ahead of random hole cards on the current board
+/// # Param hole: the two hole cards
+/// # Param board: the community cards, 3..5
+/// # Param opponents: the number of opponents, each holding random cards
+/// # Returns: the chance of being ahead of all opponents, ties counting half, 0..1
+pub fn hand_strength(hole: CardSet, board: CardSet, opponents: usize) -> f64 {
+    check(hole, board);
+    let board_state = Partial::from(board);
+    let ours = board_state.with_set(hole).eval();
+    let deck: Vec<usize> = (CardSet::full() - hole - board).ids().collect();
+    let mut standing = [0.0; 3];
+    for_each_combination(&deck, 2, &mut |theirs| {
+        let theirs = board_state.with(theirs[0]).with(theirs[1]).eval();
+        standing[compare(ours, theirs)] += 1.0;
+    });
+    let strength = (standing[AHEAD] + standing[TIED] / 2.0) / standing.iter().sum::<f64>();
+    strength.powi(opponents as i32)
+}
+
+/// Positive and negative potential over the cards still to come
+/// # Param hole: the two hole cards
+/// # Param board: the community cards, 3..5. A full board has no potential
+/// # Param samples: the most opponent hands and runouts to evaluate. When there are
+/// no more than this they are enumerated exhaustively, otherwise this many are
+/// dealt at random
+/// # Param seed: the seed for the random deals
+/// # Returns: the potential against one opponent
+pub fn potential(hole: CardSet, board: CardSet, samples: usize, seed: u64) -> Potential {
+    check(hole, board);
+    let missing = 5 - board.len();
+    let deck: Vec<usize> = (CardSet::full() - hole - board).ids().collect();
+    // counts[now][later] of the opponent hands and runouts, by standing now and on the river
+    let mut counts = [[0.0; 3]; 3];
+    let mut add = |theirs: &[usize], runout: &[usize]| {
+        let now = Partial::from(board);
+        let later = runout.iter().fold(now, |state, &card| state.with(card));
+        let (them_now, them_later) = (now.with(theirs[0]).with(theirs[1]), later.with(theirs[0]).with(theirs[1]));
+        let ours_now = now.with_set(hole).eval();
+        let ours_later = later.with_set(hole).eval();
+        counts[compare(ours_now, them_now.eval())][compare(ours_later, them_later.eval())] += 1.0;
+    };
+
+    if combinations(deck.len(), 2) * combinations(deck.len() - 2, missing) <= samples {
+        for_each_combination(&deck, 2, &mut |theirs| {
+            let rest: Vec<usize> = deck.iter().cloned().filter(|card| !theirs.contains(card)).collect();
+            for_each_combination(&rest, missing, &mut |runout| add(theirs, runout));
+        });
+    } else {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut deck = deck;
+        for _ in 0..samples {
+            for i in 0..2 + missing {
+                let j = rng.gen_range(i..deck.len());
+                deck.swap(i, j);
+            }
+            add(&deck[..2], &deck[2..2 + missing]);
+        }
+    }
+
+    let total = |now: usize| counts[now].iter().sum::<f64>();
+    let ratio = |count: f64, total: f64| if total > 0.0 { count / total } else { 0.0 };
+    Potential {
+        positive: ratio(
+            counts[BEHIND][AHEAD] + counts[BEHIND][TIED] / 2.0 + counts[TIED][AHEAD] / 2.0,
+            total(BEHIND) + total(TIED) / 2.0,
+        ),
+        negative: ratio(
+            counts[AHEAD][BEHIND] + counts[TIED][BEHIND] / 2.0 + counts[AHEAD][TIED] / 2.0,
+            total(AHEAD) + total(TIED) / 2.0,
+        ),
+    }
+}
+
+/// Effective hand strength: the chance of being ahead now and staying ahead, or being
+/// behind now and drawing ahead
+/// # Param strength: the hand strength from `hand_strength`
+/// # Param potential: the potential from `potential`
+/// # Returns: the effective hand strength, 0..1
+pub fn effective_strength(strength: f64, potential: Potential) -> f64 {
+    strength * (1.0 - potential.negative) + (1.0 - strength) * potential.positive
+}
+
+const AHEAD: usize = 0;
+const TIED: usize = 1;
+const BEHIND: usize = 2;
+
+/// Whether a hand of rank `ours` is ahead of, tied with or behind a hand of rank `theirs`
+fn compare(ours: usize, theirs: usize) -> usize {
+    if ours < theirs {
+        AHEAD
+    } else if ours == theirs {
+        TIED
+    } else {
+        BEHIND
+    }
+}
+
+fn check(hole: CardSet, board: CardSet) {
+    if hole.len() != 2 || !(3..=5).contains(&board.len()) || !(hole & board).is_empty() {
+        panic!("Cannot calculate hand strength of {} hole and {} board cards", hole.len(), board.len());
+    }
+}
+
+#[cfg(test)]
+fn set(names: &str) -> CardSet {
+    names.split(' ').map(|name| name.parse::<super::card::Card>().unwrap()).collect()
+}
+
+#[test]
+fn test_hand_strength() {
+    let board = set("K♠ 7♦ 2♣");
+    let overpair = hand_strength(set("A♠ A♥"), board, 1);
+    let bottom_pair = hand_strength(set("2♠ 3♥"), board, 1);
+    assert!(overpair > 0.9);
+    assert!(bottom_pair < overpair);
+    assert!(bottom_pair > hand_strength(set("4♠ 3♥"), board, 1));
+    assert!((hand_strength(set("A♠ A♥"), board, 3) - overpair.powi(3)).abs() < 1e-12);
+
+    assert_eq!(hand_strength(set("A♠ K♠"), set("Q♠ J♠ T♠ 2♦ 3♣"), 2), 1.0);
+    // Every opponent plays the board too
+    assert_eq!(hand_strength(set("2♦ 3♣"), set("A♠ K♠ Q♠ J♠ T♠"), 1), 0.5);
+}
+
+#[test]
+fn test_potential() {
+    let draw = potential(set("A♥ 5♥"), set("K♥ 9♥ 2♣ 7♠"), usize::MAX, 0);
+    let set_of_kings = potential(set("K♦ K♣"), set("K♥ 9♥ 2♣ 7♠"), usize::MAX, 0);
+    assert!(draw.positive > 0.15);
+    assert!(draw.positive > set_of_kings.positive);
+    assert!(set_of_kings.negative < 0.15);
+    assert_eq!(potential(set("A♥ 5♥"), set("K♥ 9♥ 2♣ 7♠ 3♦"), usize::MAX, 0), Potential::default());
+
+    // Sampling the turn gives about the same as enumerating it
+    let sampled = potential(set("A♥ 5♥"), set("K♥ 9♥ 2♣ 7♠"), 20000, 7);
+    assert!((sampled.positive - draw.positive).abs() < 0.03);
+    assert!((sampled.negative - draw.negative).abs() < 0.03);
+
+    let flop = potential(set("A♥ 5♥"), set("K♥ 9♥ 2♣"), 20000, 7);
+    assert!(flop.positive > draw.positive);
+}
+
+#[test]
+fn test_effective_strength() {
+    let potential = Potential { positive: 0.4, negative: 0.1 };
+    assert!((effective_strength(0.5, potential) - (0.5 * 0.9 + 0.5 * 0.4)).abs() < 1e-12);
+    assert_eq!(effective_strength(1.0, Potential::default()), 1.0);
+    assert_eq!(effective_strength(0.0, Potential::default()), 0.0);
+}
@@ -0,0 +1,154 @@
+/// The rules for the size of bets and raises
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[allow(clippy::enum_variant_names)]
+pub enum BettingStructure {
+    /// Any raise of at least the last one, up to all the player has
+    NoLimit,
+    /// Raises of at most the size of the pot after calling
+    PotLimit,
+    /// Every bet and raise is of one size, the small bet in the first two betting rounds
+    /// and the big bet after them, with a cap on the bets and raises of a round
+    FixedLimit { small_bet: i32, big_bet: i32, cap: usize },
+}
+
+impl BettingStructure {
+    /// The usual cap of fixed limit: a bet and three raises
+    pub const CAP: usize = 4;
+
+    pub fn from_id(id: i32, small_bet: i32, big_bet: i32, cap: usize) -> BettingStructure {
+        match id {
+            1 => BettingStructure::PotLimit,
+            2 => BettingStructure::FixedLimit { small_bet, big_bet, cap },
+            _ => BettingStructure::NoLimit,
+        }
+    }
+
+    /// The blinds of a hand. Fixed limit blinds are set by the small bet, half of it and all
+    /// of it, so the big blind is the first bet of the round at the size of the round
+    /// # Param small: the small blind asked for, used by the other structures
+    /// # Param big: the big blind asked for, used by the other structures
+    /// # Returns: the small and the big blind
+    pub fn blinds(&self, small: i32, big: i32) -> (i32, i32) {
+        match *self {
+            BettingStructure::FixedLimit { small_bet, .. } => (small_bet / 2, small_bet),
+            _ => (small, big),
+        }
+    }
+
+    /// The size of every bet and raise in a betting round, only for fixed limit
+    /// # Param round: the betting round of the hand, from 0
+    pub fn bet_size(&self, round: usize) -> Option<i32> {
+        match *self {
+            BettingStructure::FixedLimit { small_bet, big_bet, .. } => Some(if round < 2 { small_bet } else { big_bet }),
+            _ => None,
+        }
+    }
+
+    /// The total a raise must at least go to
+    /// # Param round: the betting round of the hand, from 0
+    /// # Param max_bet: the largest total bet of the hand
+    /// # Param min_raise: the size of the last full raise of the round, or the big blind
+    pub fn min_raise(&self, round: usize, max_bet: i32, min_raise: i32) -> i32 {
+        max_bet + self.bet_size(round).unwrap_or(min_raise)
+    }
+
+    /// The largest total a bet may go to, if the structure limits it. In fixed limit that is
+    /// a raise of the size of the round, or a call once the round is capped
+    /// # Param round: the betting round of the hand, from 0
+    /// # Param raises: the bets and raises of the round so far, the big blind counting as one
+    /// # Param max_bet: the largest total bet of the hand
+    /// # Param pot: the chips in the middle, every bet of the hand included
+    /// # Param to_call: the chips the player needs to call
+    pub fn limit(&self, round: usize, raises: usize, max_bet: i32, pot: i32, to_call: i32) -> Option<i32> {
+        match *self {
+            BettingStructure::NoLimit => None,
+            BettingStructure::PotLimit => Some(max_bet + pot + to_call),
+            BettingStructure::FixedLimit { cap, .. } if raises >= cap => Some(max_bet),
+            BettingStructure::FixedLimit { .. } => Some(max_bet + self.bet_size(round).unwrap()),
+        }
+    }
+
+    /// Checks a raise against the limit of the structure. A raise of any other size than the
+    /// structure allows is the player's mistake, and is not changed to one that is allowed
+    /// # Param bet: the total the player raises to in the hand
+    /// # Returns: the reason the raise is not allowed, if it is not
+    pub fn check(&self, bet: i32, round: usize, raises: usize, max_bet: i32, pot: i32, to_call: i32) -> Result<(), String> {
+        match (*self, self.limit(round, raises, max_bet, pot, to_call)) {
+            (_, Some(limit)) if bet <= limit => Ok(()),
+            (BettingStructure::PotLimit, Some(limit)) => Err(format!("raise to {} is more than the pot limit of {}", bet, limit)),
+            (BettingStructure::FixedLimit { cap, .. }, _) if raises >= cap => Err(format!("fixed-limit betting is capped at {} bets and raises", cap)),
+            (BettingStructure::FixedLimit { .. }, Some(limit)) => Err(format!("fixed-limit raise is to {}", limit)),
+            _ => Ok(()),
+        }
+    }
+
+    /// The bet a player asks for with the old BET, cut down to the largest the structure
+    /// allows. BET is kept for clients written before the structures, which bet any amount
+    /// they like, so it is not held to a legal size as RAISE and ALLIN are
+    /// # Param bet: the total the player asks to bet in the hand
+    /// # Param round: the betting round of the hand, from 0
+    /// # Param raises: the bets and raises of the round so far, the big blind counting as one
+    /// # Param max_bet: the largest total bet of the hand
+    /// # Param pot: the chips in the middle, every bet of the hand included
+    /// # Param to_call: the chips the player needs to call
+    /// # Returns: the total the player bets
+    pub fn bet(&self, bet: i32, round: usize, raises: usize, max_bet: i32, pot: i32, to_call: i32) -> i32 {
+        if bet <= max_bet {
+            return bet;
+        }
+        match self.limit(round, raises, max_bet, pot, to_call) {
+            Some(limit) if self.bet_size(round).is_some() => limit,
+            Some(limit) => bet.min(limit),
+            None => bet,
+        }
+    }
+}
+
+#[test]
+fn test_no_limit() {
+    let structure = BettingStructure::from_id(0, 20, 40, BettingStructure::CAP);
+    assert_eq!(structure, BettingStructure::NoLimit);
+    assert_eq!(structure.bet(1000, 0, 3, 20, 30, 10), 1000);
+    assert_eq!(structure.bet(10, 0, 1, 20, 30, 10), 10);
+    assert_eq!(structure.check(1000, 0, 3, 20, 30, 10), Ok(()));
+    assert_eq!(structure.min_raise(2, 60, 40), 100);
+    assert_eq!(structure.blinds(10, 25), (10, 25));
+}
+
+#[test]
+fn test_pot_limit() {
+    // Blinds of 10 and 20: the small blind calls 10 for a pot of 40, and raises it to 60
+    let structure = BettingStructure::from_id(1, 20, 40, BettingStructure::CAP);
+    assert_eq!(structure.limit(0, 1, 20, 30, 10), Some(60));
+    assert_eq!(structure.check(60, 0, 1, 20, 30, 10), Ok(()));
+    assert_eq!(structure.check(61, 0, 1, 20, 30, 10), Err("raise to 61 is more than the pot limit of 60".to_string()));
+    assert_eq!(structure.bet(1000, 0, 1, 20, 30, 10), 60);
+    assert_eq!(structure.bet(50, 0, 1, 20, 30, 10), 50);
+    // Then the big blind calls 40 for a pot of 120, and raises it to 180
+    assert_eq!(structure.limit(0, 2, 60, 80, 40), Some(180));
+    assert_eq!(structure.bet(1000, 0, 2, 60, 80, 40), 180);
+    assert_eq!(structure.min_raise(0, 60, 40), 100);
+}
+
+#[test]
+fn test_fixed_limit() {
+    let structure = BettingStructure::from_id(2, 20, 40, 4);
+    assert_eq!(structure.bet_size(1), Some(20));
+    assert_eq!(structure.bet_size(2), Some(40));
+    assert_eq!(structure.check(40, 0, 1, 20, 30, 10), Ok(()));
+    assert_eq!(structure.check(45, 0, 1, 20, 30, 10), Err("fixed-limit raise is to 40".to_string()));
+    assert_eq!(structure.check(1000, 3, 0, 60, 120, 0), Err("fixed-limit raise is to 100".to_string()));
+    assert_eq!(structure.bet(25, 0, 1, 20, 30, 10), 40);
+    assert_eq!(structure.bet(1000, 3, 0, 60, 120, 0), 100);
+    assert_eq!(structure.bet(60, 3, 1, 60, 160, 40), 60);
+    assert_eq!(structure.min_raise(3, 100, 20), 140);
+
+    // A bet and three raises cap the round
+    assert_eq!(structure.check(80, 0, 3, 60, 120, 20), Ok(()));
+    assert_eq!(structure.check(100, 0, 4, 80, 160, 20), Err("fixed-limit betting is capped at 4 bets and raises".to_string()));
+    assert_eq!(structure.bet(1000, 0, 4, 80, 160, 20), 80);
+
+    // The blinds asked for give way to half the small bet and the small bet
+    assert_eq!(structure.blinds(10, 25), (10, 20));
+    assert_eq!(BettingStructure::from_id(2, 30, 60, 4).blinds(10, 20), (15, 30));
+}
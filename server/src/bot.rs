@@ -1,24 +1,49 @@
 use std;
+use std::collections::HashMap;
 use std::io::*;
 use std::net::TcpStream;
 use std::sync::*;
 use std::thread;
 
+use rand::{thread_rng, Rng};
+use tendeuce_ai::util::preflop::*;
+use tendeuce_ai::util::strength::*;
+
+use betting::*;
 use card::*;
 use player::*;
+use variant::*;
 
 pub struct Bot {
     name: String,
-    cards: Option<[Card; 2]>,
+    cards: Option<Vec<Card>>,
     money: i32,
     fold: bool,
     bet: i32,
     shared: Vec<Card>,
     max_bet: i32,
+    big_blind: i32,
+    min_raise: i32,
+    drawing: bool,
+    /// The actions of this turn answered with ERROR
+    errors: usize,
+    opponents: usize,
+    variant: Variant,
+    structure: BettingStructure,
+    /// The chips in the middle from earlier hands
+    bank: i32,
+    /// The total bet of each player in the hand
+    bets: HashMap<String, i32>,
+    /// The betting round of the hand, from 0
+    betting_round: usize,
+    /// The bets and raises of the betting round
+    raises: usize,
+    /// Whether there was a bet since the last card or draw, so the next one starts a betting round
+    betting: bool,
 }
 
 impl Bot {
-    pub fn new(nr: i32) -> Bot {
+    pub fn new(nr: i32, variant: Variant, structure: BettingStructure) -> Bot {
         let bot = Bot {
             name: format!("BOT{}", nr),
             cards: None,
@@ -27,50 +52,133 @@ impl Bot {
             bet: 0,
             shared: Vec::new(),
             max_bet: 0,
+            big_blind: 0,
+            min_raise: 0,
+            drawing: false,
+            errors: 0,
+            opponents: 1,
+            variant,
+            structure,
+            bank: 0,
+            bets: HashMap::new(),
+            betting_round: 0,
+            raises: 0,
+            betting: false,
         };
         bot
     }
+
+    /// The legal action closest to the bet wanted: the largest raise the betting structure
+    /// allows, a call when the raise would be too small, and all in when the bot cannot cover it
+    fn bet_message(&self, bet: i32) -> String {
+        let pot = self.bank + self.bets.values().sum::<i32>();
+        let bet = match self.structure.limit(self.betting_round, self.raises, self.max_bet, pot, self.max_bet - self.bet) {
+            Some(limit) => bet.min(limit),
+            None => bet,
+        };
+        let min_raise = self.structure.min_raise(self.betting_round, self.max_bet, self.min_raise);
+        let bet = if bet > self.max_bet && bet < min_raise { self.max_bet } else { bet };
+        if bet >= self.money + self.bet {
+            "ALLIN".to_string()
+        } else if bet > self.max_bet {
+            format!("RAISE {}", bet)
+        } else if self.bet < self.max_bet {
+            "CALL".to_string()
+        } else {
+            "CHECK".to_string()
+        }
+    }
+
+    /// Counts a new betting round once a card is shown or a draw starts after bets
+    fn next_betting_round(&mut self) {
+        if self.betting {
+            self.betting_round += 1;
+            self.raises = 0;
+            self.betting = false;
+        }
+    }
+
+    /// The effective hand strength of two hole cards against the opponents still in the hand
+    fn effective_strength(&self) -> f64 {
+        let hole: CardSet = self.get_cards().iter().collect();
+        let board: CardSet = self.shared.iter().collect();
+        let strength = hand_strength(hole, board, self.opponents);
+        effective_strength(strength, potential(hole, board, 2000, thread_rng().gen()))
+    }
+
+    /// The share of the money to bet with the best hand type made, for the games the Hold'em
+    /// strength metrics do not fit
+    fn hand_type_share(&self, total_money: f32) -> f32 {
+        let cards: Vec<Card> = self.get_cards().iter().chain(self.shared.iter()).cloned().collect();
+        let best = Hand::find_all(0, &cards[..]).into_iter().max().unwrap();
+        match best.hand_type {
+            HandType::HighCard => total_money * 0.3,
+            HandType::Pair => total_money * 0.4,
+            HandType::TwoPair => total_money * 0.6,
+            HandType::ThreeOfAKind => total_money * 0.6,
+            HandType::Straight => total_money * 0.8,
+            HandType::Flush => total_money * 0.9,
+            HandType::FullHouse => total_money * 0.9,
+            HandType::FourOfAKind => total_money * 1.0,
+            HandType::StraightFlush => total_money * 1.0,
+        }
+    }
 }
 
 impl Player for Bot {
     fn get_message(&mut self) -> Option<String> {
-        Some(format!("READY {}", self.name))
+        None
     }
 
     fn wait_for_message(&mut self) -> String {
-        if self.shared.len() == 0 {
-            format!("BET {}", self.max_bet)
+        if self.errors > 1 {
+            // Folding is always allowed
+            "FOLD".to_string()
+        } else if self.errors == 1 && !self.drawing {
+            // A raise of a size the betting structure does not allow, so it calls instead
+            self.bet_message(self.max_bet)
+        } else if self.drawing {
+            self.drawing = false;
+            // Draws to a deuce-to-seven low, breaking pairs and throwing anything above an eight
+            let mut kept: Vec<Card> = Vec::new();
+            let mut discards = Vec::new();
+            for card in self.get_cards() {
+                if card.rank > Rank::Eight || kept.iter().any(|kept| kept.rank == card.rank) {
+                    discards.push(card.to_string());
+                } else {
+                    kept.push(card);
+                }
+            }
+            format!("DRAW {}", discards.join(" ")).trim_end().to_string()
+        } else if self.shared.is_empty() && self.variant != Variant::Holdem {
+            self.bet_message(self.max_bet)
         } else {
-            let cards: Vec<Card> = self.cards.unwrap().iter().chain(self.shared.iter()).cloned().collect();
-            let hands = Hand::find_all(0, &cards[..]);
             let total_money = (self.money + self.bet) as f32;
-            let max = match hands[0].hand_type {
-                HandType::HighCard => total_money * 0.3,
-                HandType::Pair => total_money * 0.4,
-                HandType::TwoPair => total_money * 0.6,
-                HandType::ThreeOfAKind => total_money * 0.6,
-                HandType::Straight => total_money * 0.8,
-                HandType::Flush => total_money * 0.9,
-                HandType::FullHouse => total_money * 0.9,
-                HandType::FourOfAKind => total_money * 1.0,
-                HandType::StraightFlush => total_money * 1.0,
+            // The preflop table and EHS are for two hole cards and the full deck
+            let max = if self.variant != Variant::Holdem {
+                self.hand_type_share(total_money)
+            } else if self.shared.is_empty() {
+                let hand = StartingHand::of([self.get_cards()[0], self.get_cards()[1]]);
+                total_money * (hand.equity(self.opponents.min(9)) / 100.0) as f32
+            } else {
+                total_money * self.effective_strength() as f32
             };
             let max = max as i32;
 
             if self.max_bet > max {
                 "FOLD".to_string()
             } else {
-                format!("BET {}", max)
+                self.bet_message(max)
             }
         }
     }
 
-    fn set_cards(&mut self, cards: [Card; 2]) {
+    fn set_cards(&mut self, cards: Vec<Card>) {
         self.cards = Some(cards);
     }
 
-    fn get_cards(&self) -> [Card; 2] {
-        self.cards.unwrap()
+    fn get_cards(&self) -> Vec<Card> {
+        self.cards.clone().unwrap()
     }
 
     fn get_name(&self) -> Option<String> {
@@ -106,12 +214,10 @@ impl Player for Bot {
     }
 
     fn bet(&mut self, bet: i32) {
-        if bet - self.bet > self.money {
-            self.money = 0;
-        } else {
-            self.money -= bet - self.bet;
-            self.bet = bet;
-        }
+        // Betting more than the player has left is going all in
+        let bet = bet.min(self.bet + self.money);
+        self.money -= bet - self.bet;
+        self.bet = bet;
     }
 
     fn is_dead(&self) -> bool {
@@ -123,11 +229,114 @@ impl Player for Bot {
         let msg: Vec<&str> = msg.split(char::is_whitespace).collect();
 
         match msg[0] {
+            "ROUND" => {
+                // Everyone with money left is dealt in, the bot included
+                let dealt = msg[2..].iter().filter(|money| money.parse::<i32>().unwrap_or(0) > 0).count();
+                self.opponents = dealt.saturating_sub(1).max(1);
+                self.errors = 0;
+                self.bank = msg[1].parse().unwrap();
+                self.bets.clear();
+                self.betting_round = 0;
+                self.raises = 0;
+                self.betting = false;
+            }
+            "FOLD" if msg[1] != self.name => self.opponents = self.opponents.saturating_sub(1).max(1),
             "CARDS" => self.shared.clear(),
-            "CARD" => self.shared.push(Card::new(msg[1])),
-            "SBLIND" | "BBLIND" => self.max_bet = msg[2].parse().unwrap(),
-            "BET" => self.max_bet = msg[1].parse().unwrap(),
+            "CARD" => {
+                if let Ok(card) = msg[1].parse() {
+                    self.shared.push(card);
+                }
+                self.min_raise = self.big_blind;
+                self.next_betting_round();
+            }
+            "SBLIND" => {
+                self.max_bet = msg[2].parse().unwrap();
+                self.bets.insert(msg[1].to_string(), self.max_bet);
+            }
+            "BBLIND" => {
+                self.max_bet = msg[2].parse().unwrap();
+                self.big_blind = self.max_bet;
+                self.min_raise = self.big_blind;
+                self.bets.insert(msg[1].to_string(), self.max_bet);
+                // The big blind is the first bet of the round
+                self.raises = 1;
+                self.betting = true;
+            }
+            "BET" => {
+                if msg[2] == self.name {
+                    self.errors = 0;
+                }
+                let bet: i32 = msg[1].parse().unwrap();
+                if bet - self.max_bet >= self.min_raise {
+                    self.min_raise = bet - self.max_bet;
+                    self.raises += 1;
+                }
+                self.max_bet = self.max_bet.max(bet);
+                self.bets.insert(msg[2].to_string(), bet);
+                self.betting = true;
+            }
+            "ERROR" => self.errors += 1,
+            "DRAWMOVE" => {
+                self.drawing = msg[1] == self.name;
+                self.next_betting_round();
+            }
+            "DRAW" if msg[1] == self.name => self.errors = 0,
             _ => {}
         }
     }
 }
+
+#[test]
+fn test_bot_variant() {
+    let cards: Vec<Card> = ["A♠", "A♥"].iter().map(|name| name.parse().unwrap()).collect();
+    let mut holdem = Bot::new(0, Variant::Holdem, BettingStructure::NoLimit);
+    let mut short = Bot::new(1, Variant::ShortDeck, BettingStructure::NoLimit);
+    for bot in [&mut holdem, &mut short].iter_mut() {
+        bot.set_money(1000);
+        bot.set_cards(cards.clone());
+        bot.send("BBLIND BOT2 20");
+    }
+    // Hold'em raises aces with the preflop table, short deck only calls before the flop
+    assert_eq!(holdem.wait_for_message(), "RAISE 850");
+    assert_eq!(short.wait_for_message(), "CALL");
+
+    short.send("CARD 6♣");
+    short.send("CARD 7♦");
+    short.send("CARD K♥");
+    assert_eq!(short.wait_for_message(), "RAISE 400");
+}
+
+#[test]
+fn test_bot_structure() {
+    let cards: Vec<Card> = ["A♠", "A♥"].iter().map(|name| name.parse().unwrap()).collect();
+    let mut pot_limit = Bot::new(0, Variant::Holdem, BettingStructure::PotLimit);
+    let mut fixed_limit = Bot::new(1, Variant::Holdem, BettingStructure::FixedLimit { small_bet: 20, big_bet: 40, cap: 4 });
+    for bot in [&mut pot_limit, &mut fixed_limit].iter_mut() {
+        bot.set_money(1000);
+        bot.set_cards(cards.clone());
+        bot.send("ROUND 0 1000 1000");
+        bot.send("SBLIND BOT2 10");
+        bot.send("BBLIND BOT3 20");
+    }
+    // Aces want to raise to 850, as far as the structure lets them
+    assert_eq!(pot_limit.wait_for_message(), "RAISE 70");
+    assert_eq!(fixed_limit.wait_for_message(), "RAISE 40");
+
+    // Three raises to 80 cap the round
+    fixed_limit.send("BET 40 BOT1");
+    fixed_limit.send("BET 60 BOT2");
+    fixed_limit.send("BET 80 BOT3");
+    assert_eq!(fixed_limit.wait_for_message(), "CALL");
+
+    // And the turn is played with the big bet
+    fixed_limit.send("BET 80 BOT1");
+    fixed_limit.send("BET 80 BOT2");
+    for card in ["2♣", "7♦", "9♥"].iter() {
+        fixed_limit.send(&format!("CARD {}", card));
+    }
+    fixed_limit.send("BET 80 BOT2");
+    fixed_limit.send("BET 80 BOT3");
+    fixed_limit.send("BET 80 BOT1");
+    fixed_limit.send("CARD K♣");
+    assert_eq!(fixed_limit.wait_for_message(), "RAISE 120");
+}
@@ -1,83 +1,8 @@
 use std::cmp::Ordering;
-use std::fmt;
-use std::ops::Sub;
-
-use rand::prelude::SliceRandom;
-use rand::thread_rng;
-
-#[derive(Debug, Copy, Clone, Eq)]
-pub struct Card {
-    name: [char; 2],
-}
-
-impl Card {
-    pub fn new(name: &str) -> Card {
-        Card {
-            name: [name.chars().nth(0).unwrap(), name.chars().nth(1).unwrap()]
-        }
-    }
-
-    pub fn generate(names: &str, suits: &str) -> Vec<Card> {
-        let mut vec = Vec::new();
-        for n in names.chars() {
-            for c in suits.chars() {
-                vec.push(Card { name: [n, c] });
-            }
-        }
-        vec[..].shuffle(&mut thread_rng());
-        vec
-    }
-
-    fn fig(&self) -> char {
-        self.name[0]
-    }
-
-    fn col(&self) -> char {
-        self.name[1]
-    }
-}
-
-impl fmt::Display for Card {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}{}", self.name[0], self.name[1])
-    }
-}
-
-static CARD_ORDER: &'static str = "_23456789TJDKA";
-
-impl PartialEq for Card {
-    fn eq(&self, other: &Self) -> bool {
-        CARD_ORDER.find(self.name[0]).eq(&CARD_ORDER.find(other.name[0]))
-    }
-}
-
-impl PartialOrd for Card {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        CARD_ORDER.find(self.name[0]).partial_cmp(&CARD_ORDER.find(other.name[0]))
-    }
-}
-
-impl Ord for Card {
-    fn cmp(&self, other: &Self) -> Ordering {
-        CARD_ORDER.find(self.name[0]).partial_cmp(&CARD_ORDER.find(other.name[0])).unwrap()
-    }
-}
-
-impl Sub<i32> for Card {
-    type Output = Card;
-    fn sub(self, offset: i32) -> Card {
-        let pos = CARD_ORDER.find(self.name[0]).unwrap();
-        match CARD_ORDER.chars().nth((pos as i32 - offset) as usize) {
-            Some(fig) => Card {
-                name: [fig, self.name[1]],
-            },
-            None => Card {
-                name: ['_', '_'],
-            },
-        }
-    }
-}
 
+pub use tendeuce_ai::util::card::{Card, Notation, ParseCardError, Rank, Suit};
+pub use tendeuce_ai::util::cardset::CardSet;
+pub use tendeuce_ai::util::deck::Deck;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum HandType {
@@ -103,7 +28,7 @@ impl Hand {
     fn test_new(ht: HandType, cards: Vec<&str>) -> Hand {
         let mut vec = Vec::new();
         for card in cards {
-            vec.push(Card::new(card));
+            vec.push(card.parse().unwrap());
         }
         Hand {
             hand_type: ht,
@@ -121,7 +46,7 @@ impl Hand {
             let mut vec = Vec::new();
             vec.push(cards[i]);
             while vec.len() < 5 {
-                match cards.iter().find(|&&card| card == *vec.last().unwrap() - 1 && card.col() == vec.last().unwrap().col()) {
+                match cards.iter().find(|&&card| Self::links_below(card, &vec) && card.suit == vec.last().unwrap().suit) {
                     Some(card) => vec.push(*card),
                     None => continue 'sflush,
                 }
@@ -133,12 +58,13 @@ impl Hand {
             })
         }
 
-        'flush: for i in 0..cards.len() {
-            let mut vec: Vec<Card> = cards.iter().filter(|&&card| card.col() == cards[i].col()).cloned().collect();
+        for i in 0..cards.len() {
+            let mut vec: Vec<Card> = cards.iter().filter(|&&card| card.suit == cards[i].suit).cloned().collect();
             if vec.len() < 5 {
                 continue;
             }
             vec.sort_by(|a, b| b.cmp(a));
+            vec.truncate(5);
             ret.push(Hand {
                 hand_type: HandType::Flush,
                 player,
@@ -150,7 +76,7 @@ impl Hand {
             let mut vec = Vec::new();
             vec.push(cards[i]);
             while vec.len() < 5 {
-                match cards.iter().find(|&&card| card == *vec.last().unwrap() - 1) {
+                match cards.iter().find(|&&card| Self::links_below(card, &vec)) {
                     Some(card) => vec.push(*card),
                     None => continue 'straight,
                 }
@@ -162,59 +88,59 @@ impl Hand {
             })
         }
 
-        'four: for i in 0..cards.len() {
-            let vec: Vec<Card> = cards.iter().filter(|&&card| card == cards[i]).cloned().collect();
+        for i in 0..cards.len() {
+            let vec: Vec<Card> = cards.iter().filter(|&&card| card.rank == cards[i].rank).cloned().collect();
             if vec.len() != 4 {
                 continue;
             }
             ret.push(Hand {
                 hand_type: HandType::FourOfAKind,
                 player,
-                cards: vec,
+                cards: Self::with_kickers(vec, cards),
             })
         }
 
-        'three: for i in 0..cards.len() {
-            let vec: Vec<Card> = cards.iter().filter(|&&card| card == cards[i]).cloned().collect();
+        for i in 0..cards.len() {
+            let vec: Vec<Card> = cards.iter().filter(|&&card| card.rank == cards[i].rank).cloned().collect();
             if vec.len() != 3 {
                 continue;
             }
             ret.push(Hand {
                 hand_type: HandType::ThreeOfAKind,
                 player,
-                cards: vec,
+                cards: Self::with_kickers(vec, cards),
             })
         }
 
-        'pair: for i in 0..cards.len() {
-            let vec: Vec<Card> = cards.iter().filter(|&&card| card == cards[i]).cloned().collect();
+        for i in 0..cards.len() {
+            let vec: Vec<Card> = cards.iter().filter(|&&card| card.rank == cards[i].rank).cloned().collect();
             if vec.len() != 2 {
                 continue;
             }
             ret.push(Hand {
                 hand_type: HandType::Pair,
                 player,
-                cards: vec,
+                cards: Self::with_kickers(vec, cards),
             })
         }
 
-        'card: for i in 0..cards.len() {
+        if !cards.is_empty() {
             ret.push(Hand {
                 hand_type: HandType::HighCard,
                 player,
-                cards: vec![cards[i]],
+                cards: Self::with_kickers(Vec::new(), cards),
             })
         }
 
         {
             let mut fullhouses = Vec::new();
             for tri in ret.iter().filter(|hand| hand.hand_type == HandType::ThreeOfAKind) {
-                for par in ret.iter().filter(|hand| hand.hand_type == HandType::Pair) {
-                    let mut vec: Vec<Card> = tri.cards.iter().chain(par.cards.iter()).cloned().collect();
-                    vec.sort_by(|a, b| b.cmp(a));
+                let pairs = ret.iter().filter(|hand| hand.hand_type == HandType::Pair || hand.hand_type == HandType::ThreeOfAKind);
+                for par in pairs.filter(|hand| hand.cards[0].rank != tri.cards[0].rank) {
+                    let vec: Vec<Card> = tri.cards[..3].iter().chain(par.cards[..2].iter()).cloned().collect();
                     fullhouses.push(Hand {
                         hand_type: HandType::FullHouse,
-                        player: player,
+                        player,
                         cards: vec,
                     });
                 }
@@ -225,13 +151,12 @@ impl Hand {
         {
             let mut two_pair = Vec::new();
             for par1 in ret.iter().filter(|hand| hand.hand_type == HandType::Pair) {
-                for par2 in ret.iter().filter(|hand| hand.hand_type == HandType::Pair && *hand != par1) {
-                    let mut vec: Vec<Card> = par1.cards.iter().chain(par2.cards.iter()).cloned().collect();
-                    vec.sort_by(|a, b| b.cmp(a));
+                for par2 in ret.iter().filter(|hand| hand.hand_type == HandType::Pair && hand.cards[0].rank < par1.cards[0].rank) {
+                    let vec: Vec<Card> = par1.cards[..2].iter().chain(par2.cards[..2].iter()).cloned().collect();
                     two_pair.push(Hand {
                         hand_type: HandType::TwoPair,
                         player,
-                        cards: vec,
+                        cards: Self::with_kickers(vec, cards),
                     });
                 }
             }
@@ -242,22 +167,36 @@ impl Hand {
         ret.dedup();
         ret
     }
+
+    /// Whether the card continues the straight downwards, the ace closing the wheel after the deuce
+    fn links_below(card: Card, vec: &[Card]) -> bool {
+        let last = *vec.last().unwrap();
+        Some(card.rank) == last.rank.pred() || (vec.len() == 4 && last.rank == Rank::Two && card.rank == Rank::Ace)
+    }
+
+    /// Fills the hand up to five cards with the highest cards not already used
+    fn with_kickers(mut vec: Vec<Card>, cards: &[Card]) -> Vec<Card> {
+        let mut rest: Vec<Card> = cards.iter().filter(|card| vec.iter().all(|used| used != *card)).cloned().collect();
+        rest.sort_by(|a, b| b.cmp(a));
+        for card in rest {
+            if vec.len() >= 5 {
+                break;
+            }
+            vec.push(card);
+        }
+        vec
+    }
 }
 
 impl PartialEq for Hand {
     fn eq(&self, other: &Self) -> bool {
-        self.hand_type == other.hand_type && self.cards[0] == other.cards[0]
+        self.cmp(other) == Ordering::Equal
     }
 }
 
 impl PartialOrd for Hand {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        match self.hand_type.cmp(&other.hand_type) {
-            Ordering::Equal => {
-                self.cards[0].partial_cmp(&other.cards[0])
-            }
-            ord => Some(ord),
-        }
+        Some(self.cmp(other))
     }
 }
 
@@ -265,7 +204,7 @@ impl Ord for Hand {
     fn cmp(&self, other: &Self) -> Ordering {
         match self.hand_type.cmp(&other.hand_type) {
             Ordering::Equal => {
-                self.cards[0].cmp(&other.cards[0])
+                self.cards.iter().map(|card| card.rank).cmp(other.cards.iter().map(|card| card.rank))
             }
             ord => ord,
         }
@@ -274,28 +213,35 @@ impl Ord for Hand {
 
 #[test]
 fn test_card_order() {
-    assert!(Card::new("2♠") == Card::new("2♠"));
-    assert!(Card::new("2♠") == Card::new("2♥"));
-    assert!(Card::new("2♠") != Card::new("3♠"));
-    assert!(Card::new("2♠") != Card::new("3♥"));
+    let rank = |name: &str| name.parse::<Card>().unwrap().rank;
+
+    assert!(rank("2♠") == rank("2♠"));
+    assert!(rank("2♠") == rank("2♥"));
+    assert!(rank("2♠") != rank("3♠"));
+    assert!(rank("2♠") != rank("3♥"));
+
+    assert!(rank("5♠") > rank("3♥"));
+    assert!(rank("4♠") < rank("7♥"));
 
-    assert!(Card::new("5♠") > Card::new("3♥"));
-    assert!(Card::new("4♠") < Card::new("7♥"));
+    assert!(rank("4♠") <= rank("4♠"));
+    assert!(rank("4♠") >= rank("4♠"));
 
-    assert!(Card::new("4♠") <= Card::new("4♠"));
-    assert!(Card::new("4♠") >= Card::new("4♠"));
+    assert!(rank("4♠") <= rank("4♥"));
+    assert!(rank("4♠") >= rank("4♥"));
 
-    assert!(Card::new("4♠") <= Card::new("4♥"));
-    assert!(Card::new("4♠") >= Card::new("4♥"));
+    assert!(rank("K♠") == rank("K♠"));
+    assert!(rank("A♠") == rank("A♥"));
 
-    assert!(Card::new("K♠") == Card::new("K♠"));
-    assert!(Card::new("A♠") == Card::new("A♥"));
+    assert!(rank("9♠") < rank("T♠"));
+    assert!(rank("T♠") < rank("J♠"));
+    assert!(rank("J♠") < rank("D♠"));
+    assert!(rank("D♠") < rank("K♠"));
+    assert!(rank("K♠") < rank("A♠"));
 
-    assert!(Card::new("9♠") < Card::new("T♠"));
-    assert!(Card::new("T♠") < Card::new("J♠"));
-    assert!(Card::new("J♠") < Card::new("D♠"));
-    assert!(Card::new("D♠") < Card::new("K♠"));
-    assert!(Card::new("K♠") < Card::new("A♠"));
+    assert!(rank("Q♠") == rank("D♠"));
+    assert!(rank("10♠") == rank("T♠"));
+    assert!("A".parse::<Card>().is_err());
+    assert!("Xs".parse::<Card>().is_err());
 }
 
 #[test]
@@ -323,30 +269,131 @@ fn test_hand_order() {
 
 #[test]
 fn test_hand_find() {
-    let sflush = Hand::find_all(0, &["Aa", "Ka", "Da", "Ja", "Ta"].iter().map(|s| Card::new(s)).collect::<Vec<Card>>()[..]);
+    let sflush = Hand::find_all(0, &["A♠", "K♠", "D♠", "J♠", "T♠"].iter().map(|s| s.parse().unwrap()).collect::<Vec<Card>>()[..]);
     assert!(sflush.iter().any(|hand| hand.hand_type == HandType::StraightFlush));
 
-    let four = Hand::find_all(0, &["Aa", "Ab", "Ac", "Ad"].iter().map(|s| Card::new(s)).collect::<Vec<Card>>()[..]);
+    let four = Hand::find_all(0, &["A♠", "A♥", "A♦", "A♣"].iter().map(|s| s.parse().unwrap()).collect::<Vec<Card>>()[..]);
     assert!(four.iter().any(|hand| hand.hand_type == HandType::FourOfAKind));
 
-    let fullhouse = Hand::find_all(0, &["Aa", "Ab", "Ac", "Ka", "Kb"].iter().map(|s| Card::new(s)).collect::<Vec<Card>>()[..]);
+    let fullhouse = Hand::find_all(0, &["A♠", "A♥", "A♦", "K♠", "K♥"].iter().map(|s| s.parse().unwrap()).collect::<Vec<Card>>()[..]);
     assert!(fullhouse.iter().any(|hand| hand.hand_type == HandType::FullHouse));
 
-    let flush = Hand::find_all(0, &["Aa", "Ta", "6a", "5a", "4a"].iter().map(|s| Card::new(s)).collect::<Vec<Card>>()[..]);
+    let flush = Hand::find_all(0, &["A♠", "T♠", "6♠", "5♠", "4♠"].iter().map(|s| s.parse().unwrap()).collect::<Vec<Card>>()[..]);
     assert!(flush.iter().any(|hand| hand.hand_type == HandType::Flush));
 
-    let straight = Hand::find_all(0, &["9a", "8a", "7b", "6c", "5a"].iter().map(|s| Card::new(s)).collect::<Vec<Card>>()[..]);
+    let straight = Hand::find_all(0, &["9♠", "8♠", "7♥", "6♦", "5♠"].iter().map(|s| s.parse().unwrap()).collect::<Vec<Card>>()[..]);
     assert!(straight.iter().any(|hand| hand.hand_type == HandType::Straight));
 
-    let three = Hand::find_all(0, &["9a", "9b", "9c"].iter().map(|s| Card::new(s)).collect::<Vec<Card>>()[..]);
+    let three = Hand::find_all(0, &["9♠", "9♥", "9♦"].iter().map(|s| s.parse().unwrap()).collect::<Vec<Card>>()[..]);
     assert!(three.iter().any(|hand| hand.hand_type == HandType::ThreeOfAKind));
 
-    let two_pair = Hand::find_all(0, &["9a", "9b", "Ta", "Tb"].iter().map(|s| Card::new(s)).collect::<Vec<Card>>()[..]);
+    let two_pair = Hand::find_all(0, &["9♠", "9♥", "T♠", "T♥"].iter().map(|s| s.parse().unwrap()).collect::<Vec<Card>>()[..]);
     assert!(two_pair.iter().any(|hand| hand.hand_type == HandType::TwoPair));
 
-    let pair = Hand::find_all(0, &["9a", "9b"].iter().map(|s| Card::new(s)).collect::<Vec<Card>>()[..]);
+    let pair = Hand::find_all(0, &["9♠", "9♥"].iter().map(|s| s.parse().unwrap()).collect::<Vec<Card>>()[..]);
     assert!(pair.iter().any(|hand| hand.hand_type == HandType::Pair));
 
-    let card = Hand::find_all(0, &["Ta"].iter().map(|s| Card::new(s)).collect::<Vec<Card>>()[..]);
+    let card = Hand::find_all(0, &["T♠"].iter().map(|s| s.parse().unwrap()).collect::<Vec<Card>>()[..]);
     assert!(card.iter().any(|hand| hand.hand_type == HandType::HighCard));
+
+    let wheel = Hand::find_all(0, &["As", "2h", "3d", "4c", "5s"].iter().map(|s| s.parse().unwrap()).collect::<Vec<Card>>()[..]);
+    assert!(wheel.iter().any(|hand| hand.hand_type == HandType::Straight));
+
+    let steel_wheel = Hand::find_all(0, &["5s", "4s", "3s", "2s", "As"].iter().map(|s| s.parse().unwrap()).collect::<Vec<Card>>()[..]);
+    assert!(steel_wheel.iter().any(|hand| hand.hand_type == HandType::StraightFlush));
+
+    let wrap = Hand::find_all(0, &["3s", "2h", "Ad", "Kc", "Qs"].iter().map(|s| s.parse().unwrap()).collect::<Vec<Card>>()[..]);
+    assert!(!wrap.iter().any(|hand| hand.hand_type == HandType::Straight));
+}
+
+#[cfg(test)]
+fn best_hand(names: &str) -> Hand {
+    let cards: Vec<Card> = names.split(' ').map(|name| name.parse().unwrap()).collect();
+    Hand::find_all(0, &cards[..]).into_iter().max().unwrap()
+}
+
+#[test]
+fn test_hand_kickers() {
+    let matchups = [
+        // Pair decided by the kicker
+        ("As Kh 7d 7c 2s 9h 4d", "Ah Qd 7d 7c 2s 9h 4d", Ordering::Greater),
+        ("7s 7h Ad Kc Ts 5h 3d", "7d 7c As Kh 9d 4h 3s", Ordering::Greater),
+        ("As Ah Kd Qc Js 3h 2d", "Ad Ac Kd Qc Js 4h 2d", Ordering::Equal),
+        // Two pair decided by the second pair and by the kicker
+        ("Ks Kh 9d 9c 2s 3h 4d", "Kd Kc 8d 8c 2s 3h 4d", Ordering::Greater),
+        ("As Ah Kd Kc Qs 2h 3d", "Ad Ac Ks Kh Js 2h 3d", Ordering::Greater),
+        ("Qs Qh Jd Jc 5s 5h Ad", "Qd Qc Js Jh 5d 5c Kd", Ordering::Greater),
+        ("9s 9h 4d 4c Ks 2h 3d", "9d 9c 4s 4h Kd 2s 3c", Ordering::Equal),
+        // Trips and quads decided by the kickers
+        ("7s 7h 7d Ah 2c", "7s 7h 7d Kh Qc", Ordering::Greater),
+        ("7s 7h 7d Ah 3c", "7s 7h 7d Ah 2c", Ordering::Greater),
+        ("5s 5h 5d 5c Ks", "5s 5h 5d 5c Qs", Ordering::Greater),
+        ("5s 5h 5d 5c Ks 2h Qd", "5s 5h 5d 5c Kh 3s Jd", Ordering::Equal),
+        // Full houses compare the trips first, then the pair
+        ("3s 3h 3d As Ah", "2s 2h 2d Ks Kh", Ordering::Greater),
+        ("Ks Kh Kd 9s 9h", "Ks Kh Kd 8s 8h", Ordering::Greater),
+        ("As Ah Ad Ks Kh Kd 2c", "As Ah Ad Qs Qh Qd 2c", Ordering::Greater),
+        ("Ts Th Td 4s 4h 2d 2c", "Ts Th Td 4d 4c 3s 3h", Ordering::Equal),
+        // Flushes compare all five cards
+        ("As Js 9s 6s 3s", "Ah Jh 9h 6h 2h", Ordering::Greater),
+        ("Ks Qs 8s 6s 3s", "Kh Jh 9h 6h 4h", Ordering::Greater),
+        ("As Js 9s 6s 3s 2s Kd", "Ah Jh 9h 6h 3h 2d Kc", Ordering::Equal),
+        // Straights and straight flushes compare the top card only
+        ("9s 8h 7d 6c 5s", "8s 7h 6d 5c 4s", Ordering::Greater),
+        ("9s 8h 7d 6c 5s 4h", "9h 8s 7c 6d 5h 2s", Ordering::Equal),
+        ("9s 8s 7s 6s 5s", "Ks Kh Kd Kc As", Ordering::Greater),
+        // High cards compare all five cards
+        ("As Kh 9d 7c 5s 3h 2d", "As Kh 9d 7c 4s 3h 2d", Ordering::Greater),
+        ("As Kh 9d 7c 5s 3h 2d", "Ah Kd 9c 7s 6h 4d 2c", Ordering::Less),
+        ("As Kh 9d 7c 5s 3h 2d", "Ah Kd 9c 7s 5h 3d 2c", Ordering::Equal),
+        // Categories still come first
+        ("2s 2h 3d 4c 6s", "As Kh Qd Jc 9s", Ordering::Greater),
+        ("2s 2h 3d 3c 4s", "As Ah Kd Qc Js", Ordering::Greater),
+    ];
+    for &(a, b, ord) in matchups.iter() {
+        assert_eq!(best_hand(a).cmp(&best_hand(b)), ord, "{} vs {}", a, b);
+        assert_eq!(best_hand(b).cmp(&best_hand(a)), ord.reverse(), "{} vs {}", b, a);
+    }
+}
+
+#[test]
+fn test_hand_order_matches_evaluator() {
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+    use tendeuce_ai::util::evaluator::eval;
+
+    let mut rng = StdRng::seed_from_u64(0x7e2d);
+    for _ in 0..5000 {
+        let mut deck = Deck::new();
+        deck.shuffle(&mut rng);
+        let dealt: Vec<Card> = (0..9).map(|_| deck.deal().unwrap()).collect();
+        let a: Vec<Card> = dealt[0..7].to_vec();
+        let b: Vec<Card> = dealt[2..9].to_vec();
+        let rank_a = eval(a.iter().map(Card::id).collect::<Vec<usize>>().iter(), 7);
+        let rank_b = eval(b.iter().map(Card::id).collect::<Vec<usize>>().iter(), 7);
+        let hand_a = Hand::find_all(0, &a[..]).into_iter().max().unwrap();
+        let hand_b = Hand::find_all(0, &b[..]).into_iter().max().unwrap();
+        assert_eq!(hand_a.cmp(&hand_b), rank_b.cmp(&rank_a), "{:?} vs {:?}", a, b);
+    }
+}
+
+#[test]
+fn test_hand_wheel() {
+    use tendeuce_ai::util::evaluator::{description, eval};
+
+    let wheel = ("As 2h 3d 4c 5s Kh Kd", "Five-High Straight");
+    let steel_wheel = ("5s 4s 3s 2s As Ah Ad", "Five-High Straight Flush");
+    for &(names, descriptor) in [wheel, steel_wheel].iter() {
+        let cards: Vec<Card> = names.split(' ').map(|name| name.parse().unwrap()).collect();
+        let hand = best_hand(names);
+        let rank = eval(cards.iter().map(Card::id).collect::<Vec<usize>>().iter(), cards.len());
+        let figs: Vec<String> = hand.cards.iter().map(|card| card.rank.to_char().to_string()).collect();
+        assert_eq!(description(&rank), (&figs.join(" ")[..], descriptor));
+    }
+
+    assert!(best_hand("As 2h 3d 4c 5s") < best_hand("2s 3h 4d 5c 6s"));
+    assert!(best_hand("As 2h 3d 4c 5s") > best_hand("As Ah Ad Kc Qs"));
+    assert!(best_hand("As 2h 3d 4c 5s") == best_hand("Ah 2d 3c 4s 5h"));
+    assert!(best_hand("5s 4s 3s 2s As") < best_hand("6h 5h 4h 3h 2h"));
+    assert!(best_hand("5s 4s 3s 2s As") > best_hand("As Ah Ad Ac Ks"));
 }
@@ -0,0 +1,101 @@
+use rand::{Rng, SeedableRng};
+use rand::rngs::{OsRng, StdRng};
+
+use card::*;
+
+/// Where the cards of each hand come from
+pub enum DeckSource {
+    /// A fresh seed from the operating system for every hand
+    Random,
+    /// Hand `n` is shuffled with `seed + n`, so a logged seed replays as the first hand
+    Seeded(u64),
+    /// The same fixed card order for every hand, first card dealt first
+    Scripted(Vec<Card>),
+}
+
+impl DeckSource {
+    /// Builds the deck for a hand
+    /// # Param hand: the number of the hand, counting from 0
+    /// # Param ranks: the ranks a shuffled deck is made of
+    /// # Returns: the deck and the seed it was shuffled with, if any
+    pub fn deck(&self, hand: u64, ranks: &[Rank]) -> (Deck, Option<u64>) {
+        match *self {
+            DeckSource::Random => {
+                let seed = OsRng.gen();
+                (Self::shuffled(seed, ranks), Some(seed))
+            }
+            DeckSource::Seeded(seed) => {
+                let seed = seed.wrapping_add(hand);
+                (Self::shuffled(seed, ranks), Some(seed))
+            }
+            DeckSource::Scripted(ref cards) => (Deck::from_cards(cards.clone()), None),
+        }
+    }
+
+    /// The generator the discards of a hand are shuffled with when the deck runs out in a
+    /// draw. It follows from the seed of the hand, so a logged seed replays the draws too
+    /// # Param seed: the seed of the hand, None for a scripted deck
+    /// # Param hand: the number of the hand, which seeds the reshuffles of a scripted deck
+    pub fn reshuffler(seed: Option<u64>, hand: u64) -> StdRng {
+        // Seeded from the generator of the deck rather than with its seed, so the first
+        // reshuffle does not start where the shuffle of the deck did
+        let mut rng = StdRng::seed_from_u64(seed.unwrap_or(hand));
+        StdRng::from_rng(&mut rng).unwrap()
+    }
+
+    fn shuffled(seed: u64, ranks: &[Rank]) -> Deck {
+        let mut deck = Deck::with_ranks(ranks);
+        deck.shuffle(&mut StdRng::seed_from_u64(seed));
+        deck
+    }
+}
+
+#[cfg(test)]
+fn deal_all(mut deck: Deck) -> Vec<Card> {
+    let mut cards = Vec::new();
+    while let Some(card) = deck.deal() {
+        cards.push(card);
+    }
+    cards
+}
+
+#[test]
+fn test_seeded_source() {
+    let source = DeckSource::Seeded(42);
+    let (first, seed) = source.deck(0, &Rank::ALL);
+    assert_eq!(seed, Some(42));
+    assert_eq!(deal_all(first), deal_all(source.deck(0, &Rank::ALL).0));
+
+    let (second, seed) = source.deck(1, &Rank::ALL);
+    assert_eq!(seed, Some(43));
+    assert_eq!(deal_all(second), deal_all(DeckSource::Seeded(43).deck(0, &Rank::ALL).0));
+    assert_ne!(deal_all(source.deck(0, &Rank::ALL).0), deal_all(source.deck(1, &Rank::ALL).0));
+
+    let (random, seed) = DeckSource::Random.deck(0, &Rank::ALL);
+    assert_eq!(deal_all(random), deal_all(DeckSource::Seeded(seed.unwrap()).deck(0, &Rank::ALL).0));
+
+    let short = deal_all(source.deck(0, &Rank::ALL[4..]).0);
+    assert_eq!(short.len(), 36);
+    assert!(short.iter().all(|card| card.rank >= Rank::Six));
+}
+
+#[test]
+fn test_scripted_source() {
+    let cards: Vec<Card> = "A♠ K♠ 2♣".split(' ').map(|name| name.parse().unwrap()).collect();
+    let (deck, seed) = DeckSource::Scripted(cards.clone()).deck(7, &Rank::ALL);
+    assert_eq!(seed, None);
+    assert_eq!(deal_all(deck), cards);
+}
+
+#[test]
+fn test_reshuffler() {
+    let reshuffled = |mut rng: StdRng| {
+        let mut deck = Deck::new();
+        deck.shuffle(&mut rng);
+        deal_all(deck)
+    };
+    assert_eq!(reshuffled(DeckSource::reshuffler(Some(42), 0)), reshuffled(DeckSource::reshuffler(Some(42), 5)));
+    assert_ne!(reshuffled(DeckSource::reshuffler(Some(42), 0)), reshuffled(DeckSource::reshuffler(Some(43), 0)));
+    assert_ne!(reshuffled(DeckSource::reshuffler(Some(42), 0)), deal_all(DeckSource::Seeded(42).deck(0, &Rank::ALL).0));
+    assert_eq!(reshuffled(DeckSource::reshuffler(None, 3)), reshuffled(DeckSource::reshuffler(None, 3)));
+}
@@ -12,7 +12,7 @@ pub struct Human {
     msgs: Arc<Mutex<Vec<String>>>,
     name: Option<String>,
     dead: Arc<Mutex<bool>>,
-    cards: Option<[Card; 2]>,
+    cards: Option<Vec<Card>>,
     money: i32,
     fold: bool,
     bet: i32,
@@ -36,17 +36,16 @@ impl Human {
     }
 
     pub fn test_new(msgs: Arc<Mutex<Vec<String>>>) -> Human {
-        let human = Human {
+        Human {
             stream: None,
-            msgs: msgs,
+            msgs,
             name: None,
             dead: Arc::new(Mutex::new(false)),
             cards: None,
             money: 0,
             fold: false,
             bet: 0,
-        };
-        human
+        }
     }
 
     fn start_listening(stream: TcpStream, msgs: Arc<Mutex<Vec<String>>>, dead: Arc<Mutex<bool>>) {
@@ -77,7 +76,7 @@ impl Human {
 impl Player for Human {
     fn get_message(&mut self) -> Option<String> {
         let mut msgs = self.msgs.lock().unwrap();
-        if msgs.len() < 1 {
+        if msgs.is_empty() {
             None
         } else {
             let msg = msgs.remove(0);
@@ -90,11 +89,11 @@ impl Player for Human {
         loop {
             {
                 let mut msgs = self.msgs.lock().unwrap();
-                if msgs.len() >= 1 {
+                if !msgs.is_empty() {
                     let msg = msgs.remove(0);
                     println!("> {}", msg);
                     return msg;
-                } else if self.dead.lock().unwrap().clone() {
+                } else if *self.dead.lock().unwrap() {
                     return "FOLD".to_string();
                 }
             }
@@ -102,12 +101,12 @@ impl Player for Human {
         }
     }
 
-    fn set_cards(&mut self, cards: [Card; 2]) {
+    fn set_cards(&mut self, cards: Vec<Card>) {
         self.cards = Some(cards);
     }
 
-    fn get_cards(&self) -> [Card; 2] {
-        self.cards.unwrap()
+    fn get_cards(&self) -> Vec<Card> {
+        self.cards.clone().unwrap()
     }
 
     fn get_name(&self) -> Option<String> {
@@ -143,16 +142,14 @@ impl Player for Human {
     }
 
     fn bet(&mut self, bet: i32) {
-        if bet - self.bet > self.money {
-            self.money = 0;
-        } else {
-            self.money -= bet - self.bet;
-            self.bet = bet;
-        }
+        // Betting more than the player has left is going all in
+        let bet = bet.min(self.bet + self.money);
+        self.money -= bet - self.bet;
+        self.bet = bet;
     }
 
     fn is_dead(&self) -> bool {
-        self.dead.lock().unwrap().clone()
+        *self.dead.lock().unwrap()
     }
 
     fn send(&mut self, msg: &str) {
@@ -3,12 +3,17 @@
 #![allow(unreachable_code)]
 
 extern crate rand;
+extern crate tendeuce_ai;
 
 use std::*;
 use std::io::prelude::*;
 
+use betting::*;
+use card::*;
+use dealer::*;
 use server::*;
 use table::*;
+use variant::*;
 
 mod server;
 
@@ -16,8 +21,11 @@ mod player;
 mod human;
 mod bot;
 mod message;
+mod betting;
 mod card;
+mod dealer;
 mod table;
+mod variant;
 
 mod test;
 
@@ -42,16 +50,59 @@ fn read_number(text: &str, def: i32, min: i32, max: i32) -> i32 {
     }
 }
 
+fn read_seed(text: &str) -> Option<u64> {
+    loop {
+        print!("{}", text);
+        io::stdout().flush().unwrap();
+        let mut line = String::new();
+        io::stdin().read_line(&mut line).unwrap();
+        if line.trim() == "" {
+            return None;
+        }
+        match line.trim().parse::<u64>() {
+            Ok(seed) => return Some(seed),
+            _ => {
+                print!("Try again: ");
+                io::stdout().flush().unwrap();
+            }
+        }
+    }
+}
+
 fn main() {
     let port = read_number("Port number <default = 9001>: ", 9001, 0, u16::MAX as i32);
-    let players = read_number("Players count <default = 1>: ", 1, 0, 11);
-    let bots = read_number("Bots count <default = 1>: ", 1, 0, 11 - players);
+    let variant = read_number("Variant, 0 = Hold'em, 1 = Pot-Limit Omaha, 2 = Short Deck, 3 = Pot-Limit Omaha Hi-Lo, 4 = 2-7 Triple Draw <default = 0>: ", 0, 0, 5);
+    let seats = Variant::from_id(variant).max_players() as i32;
+    let players = read_number("Players count <default = 1>: ", 1, 0, seats + 1);
+    let bots = read_number("Bots count <default = 1>: ", 1, 0, seats + 1 - players);
     let money = read_number("Money per player <default = 300>: ", 300, 0, i32::MAX);
-    let small_blind = read_number("Small blind <default = 10>: ", 10, 0, i32::MAX);
-    let big_blind = read_number("Big blind <default = 20>: ", 20, 0, i32::MAX);
+    let usual = if Variant::from_id(variant).pot_limit() { 1 } else { 0 };
+    let structure = read_number(&format!("Betting, 0 = no limit, 1 = pot limit, 2 = fixed limit <default = {}>: ", usual), usual, 0, 3);
+    // The table takes the fixed-limit blinds from the small bet, so only the bets are asked for
+    let (small_blind, big_blind, small_bet, big_bet, cap) = if structure == 2 {
+        let small_bet = read_number("Small bet, the big blind is the small bet <default = 20>: ", 20, 0, i32::MAX);
+        let big_bet = read_number(&format!("Big bet <default = {}>: ", 2 * small_bet), 2 * small_bet, 0, i32::MAX);
+        let cap = read_number(&format!("Bets and raises per round <default = {}>: ", BettingStructure::CAP), BettingStructure::CAP as i32, 1, i32::MAX);
+        (0, 0, small_bet, big_bet, cap as usize)
+    } else {
+        let small_blind = read_number("Small blind <default = 10>: ", 10, 0, i32::MAX);
+        let big_blind = read_number("Big blind <default = 20>: ", 20, 0, i32::MAX);
+        (small_blind, big_blind, 0, 0, 0)
+    };
+    let odd_chip = read_number("Odd chips, 0 = first winner left of the button, 1 = highest hole card, lowest suit first <default = 0>: ", 0, 0, 2);
+    let notation = read_number("Cards, 0 = unicode suits, 1 = suit letters <default = 0>: ", 0, 0, 2);
+    let seed = read_seed("Deck seed <default = random>: ");
 
     let mut server = Server::start_listening(port as u16, players);
     let mut table = Table::new(&mut server);
+    let variant = Variant::from_id(variant);
+    table.set_variant(variant);
+    table.set_structure(BettingStructure::from_id(structure, small_bet, big_bet, cap));
+    table.set_odd_chip(OddChip::from_id(odd_chip));
+    table.set_notation(Notation::from_id(notation));
+    if let Some(seed) = seed {
+        table.set_deck_source(DeckSource::Seeded(seed));
+    }
 
     table.wait_for_players(players);
     table.start(money, bots, None);
@@ -59,14 +110,16 @@ fn main() {
         table.round();
         table.first_bet(small_blind, big_blind);
         table.bet(3);
-        table.show_card();
-        table.show_card();
-        table.show_card();
-        table.bet(1);
-        table.show_card();
-        table.bet(1);
-        table.show_card();
-        table.bet(1);
+        for _ in 0..variant.draws() {
+            table.draw();
+            table.bet(1);
+        }
+        for &street in variant.streets() {
+            for _ in 0..street {
+                table.show_card();
+            }
+            table.bet(1);
+        }
         table.finalize();
     }
 
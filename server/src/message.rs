@@ -1,3 +1,4 @@
+use card::*;
 use player::*;
 
 #[derive(Debug)]
@@ -5,7 +6,12 @@ pub enum MessageType {
     Unknown,
     Ready,
     Bet,
+    Check,
+    Call,
+    Raise,
+    AllIn,
     Fold,
+    Draw,
 }
 
 macro_rules! count_exprs {
@@ -70,13 +76,18 @@ pub trait Message {
     fn parse(_: Vec<&str>) -> Option<Self> where Self: Sized;
 }
 
-impl Message {
-    pub fn from_str(msg: &String) -> Box<Self> {
+impl dyn Message {
+    pub fn from_str(msg: &str) -> Box<Self> {
         let args = msg.split(' ').collect::<Vec<&str>>();
         match args[0] {
             "READY" => try_box!(ReadyMessage::parse(args), UnknownMessage),
             "BET" => try_box!(BetMessage::parse(args), UnknownMessage),
+            "CHECK" => try_box!(CheckMessage::parse(args), UnknownMessage),
+            "CALL" => try_box!(CallMessage::parse(args), UnknownMessage),
+            "RAISE" => try_box!(RaiseMessage::parse(args), UnknownMessage),
+            "ALLIN" => try_box!(AllInMessage::parse(args), UnknownMessage),
             "FOLD" => try_box!(FoldMessage::parse(args), UnknownMessage),
+            "DRAW" => try_box!(DrawMessage::parse(args), UnknownMessage),
             _ => try_box!(UnknownMessage::parse(args), UnknownMessage)
         }
     }
@@ -102,5 +113,25 @@ define_messages! {
     UnknownMessage(MessageType::Unknown => );
     ReadyMessage(MessageType::Ready => name: String);
     BetMessage(MessageType::Bet => money: i32);
+    CheckMessage(MessageType::Check => );
+    CallMessage(MessageType::Call => );
+    RaiseMessage(MessageType::Raise => money: i32);
+    AllInMessage(MessageType::AllIn => );
     FoldMessage(MessageType::Fold => );
 }
+
+/// DrawMessage lists the cards a player discards, none to stand pat
+pub struct DrawMessage {
+    pub cards: Vec<Card>,
+}
+
+impl Message for DrawMessage {
+    fn get_type(&self) -> MessageType {
+        MessageType::Draw
+    }
+
+    fn parse(vec: Vec<&str>) -> Option<DrawMessage> {
+        let cards = vec.iter().skip(1).filter(|name| !name.is_empty()).map(|name| name.parse().ok()).collect::<Option<Vec<Card>>>()?;
+        Some(DrawMessage { cards })
+    }
+}
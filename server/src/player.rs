@@ -3,8 +3,8 @@ use card::*;
 pub trait Player {
     fn get_message(&mut self) -> Option<String>;
     fn wait_for_message(&mut self) -> String;
-    fn set_cards(&mut self, cards: [Card; 2]);
-    fn get_cards(&self) -> [Card; 2];
+    fn set_cards(&mut self, cards: Vec<Card>);
+    fn get_cards(&self) -> Vec<Card>;
     fn get_name(&self) -> Option<String>;
     fn set_name(&mut self, name: String);
     fn get_money(&self) -> i32;
@@ -18,5 +18,5 @@ pub trait Player {
         self.get_money() == 0
     }
     fn is_dead(&self) -> bool;
-    fn send(&mut self, &str);
+    fn send(&mut self, msg: &str);
 }
@@ -1,13 +1,45 @@
 use std::*;
 use std::sync::*;
-use rand::{Rng, thread_rng, seq::IteratorRandom};
+use rand::{Rng, SeedableRng, thread_rng, seq::IteratorRandom};
+use rand::rngs::StdRng;
+use tendeuce_ai::util::evaluator::low_description;
 
+use super::betting::*;
 use super::bot::*;
 use super::card::*;
+use super::dealer::*;
 use super::human::*;
 use super::message::*;
 use super::player::*;
 use super::server::*;
+use super::variant::*;
+
+/// The rule for who gets the chips left over when a pot does not split evenly.
+/// Each winner in the order of the rule gets one of them.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum OddChip {
+    /// The first winners left of the button
+    #[default]
+    LeftOfButton,
+    /// The winners holding the highest hole card, and between cards of the same rank the one
+    /// of the lowest suit, suits ranked clubs, diamonds, hearts, spades from the lowest
+    HighCardLowestSuit,
+}
+
+impl OddChip {
+    pub fn from_id(id: i32) -> OddChip {
+        match id {
+            1 => OddChip::HighCardLowestSuit,
+            _ => OddChip::LeftOfButton,
+        }
+    }
+}
+
+/// A pot and the players who can win it
+struct Pot {
+    amount: i32,
+    eligible: Vec<usize>,
+}
 
 pub struct Table {
     server: Arc<Mutex<ServerData>>,
@@ -15,8 +47,29 @@ pub struct Table {
     shared: Vec<Card>,
     shared_visible: usize,
     max_bet: i32,
+    min_raise: i32,
+    big_blind: i32,
+    structure: BettingStructure,
+    /// The betting round of the hand, from 0
+    betting_round: usize,
+    /// The bets and raises of the betting round
+    raises: usize,
+    /// Who acted since the last full raise. They cannot raise again until a full raise
+    /// reopens the betting, as an all-in for less does not
+    acted: Vec<bool>,
     dealer: isize,
     players: isize,
+    deck_source: DeckSource,
+    hand: u64,
+    /// The seed the deck of the current hand was shuffled with, None for a scripted deck
+    seed: Option<u64>,
+    variant: Variant,
+    odd_chip: OddChip,
+    notation: Notation,
+    deck: Deck,
+    discards: Vec<Card>,
+    /// Shuffles the discards back in when the deck runs out, seeded by the hand
+    reshuffler: StdRng,
 }
 
 impl Table {
@@ -27,11 +80,49 @@ impl Table {
             shared: Vec::new(),
             shared_visible: 0,
             max_bet: 0,
+            min_raise: 0,
+            big_blind: 0,
+            structure: BettingStructure::NoLimit,
+            betting_round: 0,
+            raises: 0,
+            acted: Vec::new(),
             dealer: 0,
             players: 0,
+            deck_source: DeckSource::Random,
+            hand: 0,
+            seed: None,
+            variant: Variant::Holdem,
+            odd_chip: OddChip::LeftOfButton,
+            notation: Notation::Unicode,
+            deck: Deck::new(),
+            discards: Vec::new(),
+            reshuffler: StdRng::seed_from_u64(0),
         }
     }
 
+    /// Sets the variant, and the betting structure it is usually played with
+    pub fn set_variant(&mut self, variant: Variant) {
+        self.variant = variant;
+        self.structure = if variant.pot_limit() { BettingStructure::PotLimit } else { BettingStructure::NoLimit };
+    }
+
+    pub fn set_structure(&mut self, structure: BettingStructure) {
+        self.structure = structure;
+    }
+
+    pub fn set_odd_chip(&mut self, odd_chip: OddChip) {
+        self.odd_chip = odd_chip;
+    }
+
+    /// Sets how the cards are written in the messages to the players
+    pub fn set_notation(&mut self, notation: Notation) {
+        self.notation = notation;
+    }
+
+    pub fn set_deck_source(&mut self, deck_source: DeckSource) {
+        self.deck_source = deck_source;
+    }
+
     fn unwrap_msg<T>(msg: Box<dyn Message>) -> Box<T> where T: Message {
         unsafe { Box::from_raw(Box::into_raw(msg) as *mut T) }
     }
@@ -44,11 +135,11 @@ impl Table {
                 let mut data = self.server.lock().unwrap();
                 for player in data.players.iter_mut() {
                     while let Some(raw_msg) = player.get_message() {
-                        let msg = Message::from_str(&raw_msg);
+                        let msg = <dyn Message>::from_str(&raw_msg);
                         match msg.get_type() {
                             MessageType::Ready => {
                                 let msg = Self::unwrap_msg::<ReadyMessage>(msg);
-                                if let Some(_) = player.get_name() {
+                                if player.get_name().is_some() {
                                     println!("Unexpected packet: {}", raw_msg);
                                     println!("Waiting for players( {}/{} )", ready, players);
                                 } else {
@@ -67,7 +158,7 @@ impl Table {
                     }
                 }
                 for dead_player in data.players.iter().filter(|&player| player.is_dead()) {
-                    if let Some(_) = dead_player.get_name() {
+                    if dead_player.get_name().is_some() {
                         ready -= 1;
                     }
                     if ready != players as usize {
@@ -85,9 +176,9 @@ impl Table {
         server.players.retain(|player| player.get_name().is_some());
         println!("\tStarting Game!");
         for i in 0..bots {
-            server.players.push(Box::new(Bot::new(i)));
+            server.players.push(Box::new(Bot::new(i, self.variant, self.structure)));
         }
-        let msg = Message::start(&server.players[..]);
+        let msg = <dyn Message>::start(&server.players[..]);
         server.send_all(msg);
         for player in server.players.iter_mut() {
             player.set_money(start_money);
@@ -98,20 +189,38 @@ impl Table {
 
     pub fn round(&mut self) {
         let mut server = self.server.lock().unwrap();
-        let msg = Message::round(self.bank, &server.players[..]);
+        let msg = <dyn Message>::round(self.bank, &server.players[..]);
         server.send_all(msg);
 
-        let mut cards = Card::generate("23456789TJDKA", "♠♥♦♣");
+        let (deck, seed) = self.deck_source.deck(self.hand, self.variant.ranks());
+        self.deck = deck;
+        self.seed = seed;
+        self.discards.clear();
+        self.reshuffler = DeckSource::reshuffler(seed, self.hand);
+        match seed {
+            Some(seed) => println!("Hand #{} seed: {}", self.hand, seed),
+            None => println!("Hand #{} scripted", self.hand),
+        }
+        self.hand += 1;
+        self.betting_round = 0;
+        self.raises = 0;
         println!("Players:", );
+        let deck = &mut self.deck;
         for player in server.players.iter_mut() {
-            let pcards = [cards.pop().unwrap(), cards.pop().unwrap()];
-            player.send(&format!("CARDS {} {}", pcards[0], pcards[1]));
+            let pcards: Vec<Card> = (0..self.variant.hole_cards()).map(|_| deck.deal().unwrap()).collect();
+            player.send(&format!("CARDS {}", Self::card_list(&pcards, self.notation)));
             player.set_cards(pcards);
             player.set_bet(0);
             player.set_fold(false);
             println!("{}: {} coins.", player.get_name().unwrap(), player.get_money());
         }
-        self.shared = vec![cards.pop().unwrap(), cards.pop().unwrap(), cards.pop().unwrap(), cards.pop().unwrap(), cards.pop().unwrap()];
+        self.shared.clear();
+        for &street in self.variant.streets() {
+            self.deck.burn();
+            for _ in 0..street {
+                self.shared.push(self.deck.deal().unwrap());
+            }
+        }
         self.shared_visible = 0;
 
 
@@ -123,10 +232,97 @@ impl Table {
 
     pub fn show_card(&mut self) {
         let mut server = self.server.lock().unwrap();
-        server.send_all(format!("CARD {}", self.shared[self.shared_visible]));
+        server.send_all(format!("CARD {}", self.shared[self.shared_visible].notation(self.notation)));
         self.shared_visible += 1;
     }
 
+    /// A draw, in which every player still in discards any of their hole cards
+    /// and is dealt as many new ones, starting left of the dealer. A player may fold
+    /// instead, and one who disconnected is folded
+    pub fn draw(&mut self) {
+        let mut server = self.server.lock().unwrap();
+        println!("\tStarting Draw!");
+        for i in 1..=self.players {
+            let pos = self.get_pos(self.dealer + i);
+            if server.get_player(pos).get_fold() {
+                continue;
+            }
+            let name = server.get_player(pos).get_name().unwrap();
+            Self::drain(&mut server);
+            server.send_all(format!("DRAWMOVE {}", name));
+
+            let mut cards = server.get_player(pos).get_cards();
+            let mut discards = Vec::new();
+            let mut fold = false;
+            loop {
+                if server.get_player(pos).is_dead() {
+                    fold = true;
+                    break;
+                }
+                let raw_msg = server.get_player(pos).wait_for_message();
+                let msg = <dyn Message>::from_str(&raw_msg);
+                let reason = match msg.get_type() {
+                    MessageType::Draw => {
+                        // Every card must be held, and only once, or none of them is discarded
+                        let msg = Self::unwrap_msg::<DrawMessage>(msg);
+                        let missing = msg.cards.iter().enumerate()
+                            .find(|&(i, card)| !cards.contains(card) || msg.cards[..i].contains(card));
+                        match missing {
+                            Some((_, card)) => format!("cannot discard {}", card.notation(self.notation)),
+                            None => {
+                                discards = msg.cards;
+                                break;
+                            }
+                        }
+                    }
+                    MessageType::Fold => {
+                        fold = true;
+                        break;
+                    }
+                    MessageType::Unknown => "cannot parse packet".to_string(),
+                    _ => "expected DRAW or FOLD".to_string(),
+                };
+                println!("Invalid draw {}: {}", raw_msg, reason);
+                let player = server.get_player(pos);
+                player.send(&format!("ERROR {}", reason));
+                player.send(&format!("DRAWMOVE {}", name));
+            }
+            if fold {
+                server.get_player(pos).set_fold(true);
+                server.send_all(format!("FOLD {}", name));
+                continue;
+            }
+
+            cards.retain(|card| !discards.contains(card));
+            for _ in 0..discards.len() {
+                cards.push(Self::deal(&mut self.deck, &mut self.discards, &mut self.reshuffler));
+            }
+            self.discards.extend(discards.iter().cloned());
+            let player = server.get_player(pos);
+            player.send(&format!("CARDS {}", Self::card_list(&cards, self.notation)));
+            player.set_cards(cards);
+            server.send_all(format!("DRAW {} {}", name, discards.len()));
+        }
+    }
+
+    /// Deals the top card, shuffling the discards into a new stub when the deck runs out
+    fn deal(deck: &mut Deck, discards: &mut Vec<Card>, reshuffler: &mut StdRng) -> Card {
+        if deck.is_empty() {
+            *deck = Deck::from_cards(mem::take(discards));
+            deck.shuffle(reshuffler);
+        }
+        deck.deal().unwrap()
+    }
+
+    fn card_list(cards: &[Card], notation: Notation) -> String {
+        cards.iter().map(|card| card.notation(notation).to_string()).collect::<Vec<String>>().join(" ")
+    }
+
+    /// The chips in the middle, including the bets of the current hand
+    fn pot(&self, server: &ServerData) -> i32 {
+        self.bank + server.players.iter().map(|player| player.get_bet()).sum::<i32>()
+    }
+
     fn get_pos(&self, mut pos: isize) -> isize {
         while pos >= self.players {
             pos -= self.players;
@@ -138,6 +334,7 @@ impl Table {
     }
 
     pub fn first_bet(&mut self, small: i32, big: i32) {
+        let (small, big) = self.structure.blinds(small, big);
         let mut server = self.server.lock().unwrap();
         let mut pos = self.dealer;
 
@@ -152,11 +349,19 @@ impl Table {
         server.send_all(msg);
 
         self.max_bet = if big > small { big } else { small };
+        self.big_blind = self.max_bet;
+        // The big blind is the first bet of the round
+        self.raises = 1;
     }
 
     pub fn bet(&mut self, start: isize) {
-        let mut server = self.server.lock().unwrap();
+        // A clone of the handle, so the lock does not hold self while actions update the table
+        let server = self.server.clone();
+        let mut server = server.lock().unwrap();
         println!("\tStarting Round!");
+        // Every betting round starts with raises of at least the big blind
+        self.min_raise = self.big_blind;
+        self.acted = vec![false; self.players as usize];
         let mut pos = self.dealer;
         pos = self.get_pos(pos + start);
         let mut check = false;
@@ -175,35 +380,21 @@ impl Table {
                 played += 1;
             }
 
-            let msg = format!("MOVE {}", server.get_player(pos).get_name().unwrap());
-            server.send_all(msg);
-
-            let raw_msg = server.get_player(pos).wait_for_message();
-            let msg = Message::from_str(&raw_msg);
-            //println!(">{}", raw_msg);
-            match msg.get_type() {
-                MessageType::Bet => {
-                    let msg = Self::unwrap_msg::<BetMessage>(msg);
-                    if msg.money < self.max_bet {
-                        panic!("Bet is too small!");
-                    }
-                    server.get_player(pos).bet(msg.money);
-                    if msg.money > self.max_bet {
-                        self.max_bet = msg.money;
+            let name = server.get_player(pos).get_name().unwrap();
+            Self::drain(&mut server);
+            server.send_all(format!("MOVE {}", name));
+
+            // An invalid action is answered with the reason, and the player is asked again
+            loop {
+                let raw_msg = server.get_player(pos).wait_for_message();
+                match self.act(&mut server, pos, &raw_msg) {
+                    Ok(()) => break,
+                    Err(reason) => {
+                        println!("Invalid action {}: {}", raw_msg, reason);
+                        let player = server.get_player(pos);
+                        player.send(&format!("ERROR {}", reason));
+                        player.send(&format!("MOVE {}", name));
                     }
-                    let msg = format!("BET {} {}", msg.money, server.get_player(pos).get_name().unwrap());
-                    server.send_all(msg);
-                }
-                MessageType::Fold => {
-                    server.get_player(pos).set_fold(true);
-                    let msg = format!("FOLD {}", server.get_player(pos).get_name().unwrap());
-                    server.send_all(msg);
-                }
-                MessageType::Unknown => {
-                    println!("Can't parse packet: {}", raw_msg);
-                }
-                _ => {
-                    println!("Unexpected packet: {}", raw_msg);
                 }
             }
 
@@ -218,89 +409,251 @@ impl Table {
                 }
             }
         }
+        self.betting_round += 1;
+        self.raises = 0;
         println!("\tCheck!");
     }
 
-    pub fn finalize(&mut self) {
-        let mut server = self.server.lock().unwrap();
-        for player in server.players.iter_mut() {
-            self.bank += player.get_bet();
+    /// Applies the action of the player whose turn it is. `BET` is the total of the player
+    /// in the hand, as `RAISE` is, and is kept for older clients
+    /// # Param raw_msg: the action as the player sent it
+    /// # Returns: the reason the action is not allowed, if it is not
+    fn act(&mut self, server: &mut ServerData, pos: isize, raw_msg: &str) -> Result<(), String> {
+        let msg = <dyn Message>::from_str(raw_msg);
+        // The old BET is cut down to a size the structure allows, the other actions are not
+        let sized = !matches!(msg.get_type(), MessageType::Bet);
+        let all_in = matches!(msg.get_type(), MessageType::AllIn);
+        let (player_bet, money) = (server.get_player(pos).get_bet(), server.get_player(pos).get_money());
+        let mut bet = match msg.get_type() {
+            MessageType::Check => {
+                if player_bet < self.max_bet {
+                    return Err(format!("cannot check with {} to call", self.max_bet - player_bet));
+                }
+                player_bet
+            }
+            MessageType::Call => self.max_bet.min(player_bet + money),
+            MessageType::Raise => {
+                let msg = Self::unwrap_msg::<RaiseMessage>(msg);
+                if msg.money <= self.max_bet {
+                    return Err(format!("raise to {} is not more than the bet of {}", msg.money, self.max_bet));
+                }
+                msg.money
+            }
+            MessageType::AllIn => player_bet + money,
+            MessageType::Bet => Self::unwrap_msg::<BetMessage>(msg).money,
+            MessageType::Fold => {
+                server.get_player(pos).set_fold(true);
+                let msg = format!("FOLD {}", server.get_player(pos).get_name().unwrap());
+                server.send_all(msg);
+                return Ok(());
+            }
+            MessageType::Unknown => return Err("cannot parse packet".to_string()),
+            _ => return Err("expected CHECK, CALL, RAISE, ALLIN or FOLD".to_string()),
+        };
+        let (pot, to_call) = (self.pot(server), self.max_bet - player_bet);
+        if !sized {
+            let asked = bet;
+            bet = self.structure.bet(bet, self.betting_round, self.raises, self.max_bet, pot, to_call);
+            if bet != asked {
+                // A bet the structure changed is not the player's mistake, so they are all in if short
+                bet = bet.min(player_bet + money);
+            }
         }
+        self.check_bet(bet, player_bet, money)?;
+        if bet > self.max_bet {
+            // Under a limit, ALLIN is only allowed for a stack the limit covers
+            self.structure.check(bet, self.betting_round, self.raises, self.max_bet, pot, to_call)
+                .map_err(|reason| if all_in { format!("cannot go all in, {}", reason) } else { reason })?;
+        }
+        if bet > self.max_bet && self.acted[pos as usize] {
+            return Err(format!("cannot raise, the all-in to {} was not a full raise", self.max_bet));
+        }
+        if bet >= self.structure.min_raise(self.betting_round, self.max_bet, self.min_raise) {
+            self.min_raise = bet - self.max_bet;
+            self.raises += 1;
+            self.acted = vec![false; self.players as usize];
+        }
+        self.acted[pos as usize] = true;
+        let player = server.get_player(pos);
+        player.bet(bet);
+        self.max_bet = self.max_bet.max(bet);
+        // Every action but a fold is announced as the total bet of the player
+        let msg = format!("BET {} {}", bet, player.get_name().unwrap());
+        server.send_all(msg);
+        Ok(())
+    }
 
-        let mut hands = Vec::new();
-        for (id, player) in server.players.iter_mut().enumerate() {
-            let player_cards = &player.get_cards();
-            let cards = player_cards.iter().chain(self.shared.iter());
-            let mut player_hands: Vec<Hand> = Hand::find_all(id, &cards.cloned().collect::<Vec<Card>>());
-            hands.append(&mut player_hands);
+    /// Checks a bet against the chips of the player and the bets so far. A player who cannot
+    /// call or make a full raise may still go all in for less.
+    /// # Param bet: the total the player would have bet in the hand
+    /// # Param player_bet: what the player has bet in the hand so far
+    /// # Param money: the chips the player has left
+    /// # Returns: the reason the bet is not allowed, if it is not
+    fn check_bet(&self, bet: i32, player_bet: i32, money: i32) -> Result<(), String> {
+        let all_in = player_bet + money;
+        let min_raise = self.structure.min_raise(self.betting_round, self.max_bet, self.min_raise);
+        if bet > all_in {
+            Err(format!("bet of {} is more than the {} you have", bet, all_in))
+        } else if bet == all_in {
+            Ok(())
+        } else if bet < self.max_bet {
+            Err(format!("bet of {} is less than the {} to call", bet, self.max_bet))
+        } else if bet > self.max_bet && bet < min_raise {
+            Err(format!("raise to {} is less than the minimum raise to {}", bet, min_raise))
+        } else {
+            Ok(())
         }
-        hands.sort();
-
-        let mut best: Vec<Hand> = Vec::new();
-        let mut winners: Vec<usize> = server.players.iter().enumerate().filter_map(|(id, player)| if player.get_fold() { None } else { Some(id) }).collect();
-        while winners.len() > 1 {
-            let hand = hands.pop();
-            if hand.is_some() && (best.is_empty() || best[0] == *hand.as_ref().unwrap()) {
-                best.push(hand.unwrap());
-            } else {
-                let mut players = Vec::new();
-                for hand in best.iter() {
-                    if players.iter().all(|&p| p != hand.player) {
-                        players.push(hand.player);
-                    }
-                }
-                winners.retain(|w| players.iter().any(|p| p == w));
-                hands.retain(|h| winners.iter().any(|&w| h.player == w));
+    }
 
-                if let Some(hand) = hand {
-                    if winners.len() <= 1 {
-                        break;
-                    }
-                    best.clear();
-                    best.push(hand);
-                } else {
-                    break;
-                }
+    /// Answers every message a player sent before their turn with an error
+    fn drain(server: &mut ServerData) {
+        for player in server.players.iter_mut() {
+            while let Some(raw_msg) = player.get_message() {
+                println!("Out of turn packet: {}", raw_msg);
+                player.send("ERROR not your turn");
             }
         }
-        let mut per_player = 0;
-        if winners.len() > 0 {
-            per_player = self.bank / winners.len() as i32;
-        }
-        let mut msgs = Vec::new();
-        for winner in winners {
-            let player = &mut server.players[winner];
-            let player_money = player.get_money();
-            let player_bet = player.get_bet();
-            let money;
-
-            if player.is_allin() && per_player > player_bet * self.players as i32 {
-                self.bank -= player_bet * self.players as i32;
-                money = player_bet * self.players as i32;
-                player.set_money(player_money + player_bet * self.players as i32);
-            } else {
-                self.bank -= per_player;
-                money = per_player;
-                player.set_money(player_money + per_player);
+    }
+
+    pub fn finalize(&mut self) {
+        let mut server = self.server.lock().unwrap();
+        let chips = self.bank + server.players.iter().map(|player| player.get_money() + player.get_bet()).sum::<i32>();
+        let bets: Vec<i32> = server.players.iter().map(|player| player.get_bet()).collect();
+        let live: Vec<bool> = server.players.iter().map(|player| !player.get_fold()).collect();
+        let mut pots = Self::side_pots(&bets, &live);
+        // Chips already in the middle go to the main pot
+        pots[0].amount += self.bank;
+        self.bank = 0;
+
+        let mut ranks: Vec<(usize, usize)> = Vec::new();
+        let mut lows: Vec<(usize, usize)> = Vec::new();
+        for (id, player) in server.players.iter().enumerate() {
+            if player.get_fold() {
+                continue;
             }
-            if let Some(hand) = best.iter().find(|h| h.player == winner) {
-                println!("{} won {} because of {:?}", player.get_name().unwrap(), money, hand.hand_type);
-                let msg = format!("WON {} {} {:?}", player.get_name().unwrap(), money, hand.hand_type);
-                msgs.push(msg);
-            } else {
-                println!("{} won {}", winner, money);
-                let msg = format!("WON {} {} last_standing", player.get_name().unwrap(), money);
-                msgs.push(msg);
+            ranks.push((id, self.variant.rank(&player.get_cards(), &self.shared)));
+            if let Some(low) = self.variant.low(&player.get_cards(), &self.shared) {
+                lows.push((id, low));
             }
         }
+
+        let mut msgs = Vec::new();
+        for pot in pots.iter().filter(|pot| pot.amount > 0) {
+            let ranks: Vec<(usize, usize)> = ranks.iter().filter(|&&(id, _)| pot.eligible.contains(&id)).cloned().collect();
+            let lows: Vec<(usize, usize)> = lows.iter().filter(|&&(id, _)| pot.eligible.contains(&id)).cloned().collect();
+            let best = ranks.iter().map(|&(_, rank)| rank).min_by_key(|&rank| self.variant.order(rank));
+            let mut winners: Vec<usize> = ranks.iter().filter(|&&(_, rank)| Some(rank) == best).map(|&(id, _)| id).collect();
+            let best_low = lows.iter().map(|&(_, low)| low).min();
+            let mut low_winners: Vec<usize> = lows.iter().filter(|&&(_, low)| Some(low) == best_low).map(|&(id, _)| id).collect();
+            self.odd_chip_order(&server.players, &mut winners);
+            self.odd_chip_order(&server.players, &mut low_winners);
+
+            // A qualifying low takes half the pot, the odd chip staying with the high half
+            let low_pot = if low_winners.is_empty() || ranks.len() < 2 { 0 } else { pot.amount / 2 };
+            let high_pot = pot.amount - low_pot;
+            // Showdown winners are announced with their best five cards and what they make
+            let winners: Vec<(usize, String)> = winners.into_iter().map(|id| {
+                let reason = match best {
+                    Some(rank) if ranks.len() > 1 => {
+                        let cards = self.variant.best_five(&server.players[id].get_cards(), &self.shared).cards;
+                        format!("{} {}", Self::card_list(&cards, self.notation), self.variant.description(rank).1)
+                    }
+                    _ => "last_standing".to_string(),
+                };
+                (id, reason)
+            }).collect();
+            let low_winners: Vec<(usize, String)> = low_winners.into_iter().map(|id| {
+                let cards = self.variant.best_low_five(&server.players[id].get_cards(), &self.shared).unwrap().cards;
+                (id, format!("{} {}", Self::card_list(&cards, self.notation), low_description(&best_low.unwrap()).1))
+            }).collect();
+            self.bank += pot.amount;
+            self.bank -= Self::award(&mut server.players, &winners, high_pot, &mut msgs);
+            self.bank -= Self::award(&mut server.players, &low_winners, low_pot, &mut msgs);
+        }
         for player in server.players.iter() {
-            let msg = format!("ENDCARDS {} {} {}", player.get_name().unwrap(), player.get_cards()[0], player.get_cards()[1]);
+            let msg = format!("ENDCARDS {} {}", player.get_name().unwrap(), Self::card_list(&player.get_cards(), self.notation));
             msgs.push(msg);
         }
         for msg in msgs.iter_mut().rev() {
             server.send_all(msg.clone());
         }
-        println!("{} left in bank", self.bank);
+
+        // Every chip in play before the showdown is in a stack after it
+        let paid = server.players.iter().map(|player| player.get_money()).sum::<i32>();
+        if self.bank != 0 || paid != chips {
+            self.log_hand(&server, &bets, &live);
+            panic!("Chips do not add up: {} in play, {} paid out and {} left in the bank", chips, paid, self.bank);
+        }
+    }
+
+    /// Prints what is needed to replay the hand: its seed, the board, and each player's
+    /// cards, bets and stack
+    fn log_hand(&self, server: &ServerData, bets: &[i32], live: &[bool]) {
+        match self.seed {
+            Some(seed) => println!("Hand #{} seed: {}", self.hand.saturating_sub(1), seed),
+            None => println!("Hand #{} scripted", self.hand.saturating_sub(1)),
+        }
+        println!("Board: {}", Self::card_list(&self.shared, self.notation));
+        for (id, player) in server.players.iter().enumerate() {
+            println!("{} {} bet {} {} with {} left", player.get_name().unwrap(), Self::card_list(&player.get_cards(), self.notation),
+                     bets[id], if live[id] { "in" } else { "folded" }, player.get_money());
+        }
+    }
+
+    /// Orders the winners of a pot by who gets its odd chips first
+    fn odd_chip_order(&self, players: &[Box<dyn Player + Send>], winners: &mut [usize]) {
+        match self.odd_chip {
+            OddChip::LeftOfButton => winners.sort_by_key(|&id| self.get_pos(id as isize - self.dealer - 1)),
+            OddChip::HighCardLowestSuit => winners.sort_by_key(|&id| {
+                players[id].get_cards().iter().map(|card| (cmp::Reverse(card.rank), card.suit)).min()
+            }),
+        }
+    }
+
+    /// Splits the bets of a hand into the main pot and the side pots. Each all-in amount of a
+    /// player still in closes a pot, which only the players who put in at least as much can win.
+    /// # Param bets: what each player put in over the hand
+    /// # Param live: whether each player is still in the hand
+    /// # Returns: the pots from the main pot up, at least one
+    fn side_pots(bets: &[i32], live: &[bool]) -> Vec<Pot> {
+        let mut levels: Vec<i32> = bets.iter().zip(live.iter()).filter(|&(_, &live)| live).map(|(&bet, _)| bet).collect();
+        levels.sort();
+        levels.dedup();
+
+        let mut pots = Vec::new();
+        let mut below = 0;
+        for &level in levels.iter() {
+            pots.push(Pot {
+                amount: bets.iter().map(|&bet| bet.min(level) - bet.min(below)).sum(),
+                eligible: (0..bets.len()).filter(|&id| live[id] && bets[id] >= level).collect(),
+            });
+            below = level;
+        }
+        // What folded players put in above every live bet goes to the last pot
+        let rest: i32 = bets.iter().map(|&bet| bet - bet.min(below)).sum();
+        pots.last_mut().expect("Cannot split the pot without a player in the hand").amount += rest;
+        pots
+    }
+
+    /// Splits a pot evenly between its winners
+    /// # Param winners: the position of each winner and what they won with, the winners
+    /// who get the odd chips first
+    /// # Returns: the money paid out, the whole pot when there are winners
+    fn award(players: &mut [Box<dyn Player + Send>], winners: &[(usize, String)], pot: i32, msgs: &mut Vec<String>) -> i32 {
+        if winners.is_empty() {
+            return 0;
+        }
+        let per_player = pot / winners.len() as i32;
+        let odd_chips = (pot % winners.len() as i32) as usize;
+        for (i, &(winner, ref reason)) in winners.iter().enumerate() {
+            let player = &mut players[winner];
+            let money = if i < odd_chips { per_player + 1 } else { per_player };
+            let player_money = player.get_money();
+            player.set_money(player_money + money);
+            println!("{} won {} because of {}", player.get_name().unwrap(), money, reason);
+            msgs.push(format!("WON {} {} {}", player.get_name().unwrap(), money, reason));
+        }
+        pot
     }
 
     pub fn end(&mut self) -> bool {
@@ -309,18 +662,34 @@ impl Table {
     }
 }
 
+#[cfg(test)]
+impl Table {
+    /// A table of the players seated on the server with every shared card shown
+    /// # Param bank: the chips in the middle from the earlier betting rounds
+    fn at_showdown(server: &mut Arc<Mutex<ServerData>>, shared: Vec<Card>, bank: i32) -> Table {
+        let mut table = Table::new(server);
+        table.players = server.lock().unwrap().players.len() as isize;
+        table.shared_visible = shared.len();
+        table.shared = shared;
+        table.bank = bank;
+        table
+    }
+}
+
 #[test]
 fn test_finalize() {
-    let shared = vec![Card::new("Ta"), Card::new("5a"), Card::new("8a"), Card::new("3b"), Card::new("Kb")];
-    let c1 = [Card::new("Tb"), Card::new("5d")];
-    let c2 = [Card::new("Tc"), Card::new("4c")];
+    let shared = vec!["Ts".parse::<Card>().unwrap(), "5s".parse::<Card>().unwrap(), "8s".parse::<Card>().unwrap(), "3h".parse::<Card>().unwrap(), "Kh".parse::<Card>().unwrap()];
+    let c1 = vec!["Th".parse::<Card>().unwrap(), "5c".parse::<Card>().unwrap()];
+    let c2 = vec!["Td".parse::<Card>().unwrap(), "4d".parse::<Card>().unwrap()];
 
     let mut p1 = Box::new(Human::test_new(Arc::new(Mutex::new(Vec::new()))));
     let mut p2 = Box::new(Human::test_new(Arc::new(Mutex::new(Vec::new()))));
 
+    p1.set_name("p1".to_string());
     p1.set_cards(c1);
     p1.set_money(10);
     p1.set_bet(5);
+    p2.set_name("p2".to_string());
     p2.set_cards(c2);
     p2.set_money(10);
     p2.set_bet(5);
@@ -332,15 +701,183 @@ fn test_finalize() {
             p2,
         ],
     }));
-    let mut table = Table {
-        server: server_data.clone(),
-        bank: 300,
-        shared: shared,
-        shared_visible: 0,
-        max_bet: 0,
-        dealer: 0,
-        players: 2,
-    };
+    let mut table = Table::at_showdown(&mut server_data.clone(), shared, 300);
 
     table.finalize();
+
+    let server = server_data.lock().unwrap();
+    assert_eq!(server.players[0].get_money(), 320);
+    assert_eq!(server.players[1].get_money(), 10);
+}
+
+#[cfg(test)]
+fn finalize_with(variant: Variant, shared: [&str; 5], hands: &[&str], bank: i32) -> Vec<i32> {
+    let mut players: Vec<Box<dyn Player + Send>> = Vec::new();
+    for (i, cards) in hands.iter().enumerate() {
+        let mut player = Box::new(Human::test_new(Arc::new(Mutex::new(Vec::new()))));
+        player.set_name(format!("p{}", i + 1));
+        player.set_cards(cards.split(' ').map(|name| name.parse().unwrap()).collect());
+        player.set_money(1000);
+        players.push(player);
+    }
+    let server_data = Arc::new(Mutex::new(ServerData {
+        started: true,
+        players,
+    }));
+    let mut table = Table::at_showdown(&mut server_data.clone(), shared.iter().map(|name| name.parse().unwrap()).collect(), bank);
+    table.set_variant(variant);
+
+    table.finalize();
+
+    let server = server_data.lock().unwrap();
+    server.players.iter().map(|player| player.get_money() - 1000).collect()
+}
+
+#[test]
+fn test_finalize_kicker() {
+    let money = finalize_with(Variant::Holdem, ["7s", "7h", "2d", "9c", "4s"], &["Ad Kc", "Ac Qd"], 100);
+    assert_eq!(money, vec![100, 0]);
+
+    let money = finalize_with(Variant::Holdem, ["Ks", "Kh", "9d", "9c", "2s"], &["Ad 3c", "Qc Jd", "8h 8d"], 90);
+    assert_eq!(money, vec![90, 0, 0]);
+}
+
+#[test]
+fn test_finalize_split() {
+    let money = finalize_with(Variant::Holdem, ["As", "Kh", "Qd", "Jc", "9s"], &["2d 3c", "4c 5d"], 100);
+    assert_eq!(money, vec![50, 50]);
+
+    let money = finalize_with(Variant::Holdem, ["Ts", "Jh", "Qd", "Kc", "2s"], &["Ad 3c", "Ac 5d", "Th Td"], 90);
+    assert_eq!(money, vec![45, 45, 0]);
+}
+
+#[test]
+fn test_finalize_omaha() {
+    // Hold'em would give p1 kings full, Omaha only trips with two hole cards
+    let money = finalize_with(Variant::Omaha, ["Kh", "Kd", "5c", "5s", "2h"], &["Ks 9d 8c 7h", "5h 2c 9c 3d"], 100);
+    assert_eq!(money, vec![0, 100]);
+}
+
+#[test]
+fn test_finalize_hi_lo() {
+    let board = ["A♠", "2♥", "7♦", "K♣", "Q♠"];
+
+    // Kings with a seven low scoops both halves
+    let money = finalize_with(Variant::OmahaHiLo, board, &["3♣ 4♦ K♥ K♦", "Q♥ J♦ 9♣ 9♦"], 100);
+    assert_eq!(money, vec![100, 0]);
+
+    // The high and the low split, the odd chip going high
+    let money = finalize_with(Variant::OmahaHiLo, board, &["3♣ 4♦ 9♥ T♦", "K♥ K♦ Q♦ J♣"], 101);
+    assert_eq!(money, vec![50, 51]);
+
+    // A tied low quarters the pot
+    let money = finalize_with(Variant::OmahaHiLo, board, &["3♣ 4♦ K♥ K♦", "3♦ 4♣ 9♦ T♣"], 100);
+    assert_eq!(money, vec![75, 25]);
+
+    // Without three low cards on the board nobody qualifies and the high takes it all
+    let money = finalize_with(Variant::OmahaHiLo, ["A♠", "2♥", "9♦", "K♣", "Q♠"], &["3♣ 4♦ 9♥ T♦", "K♥ K♦ Q♦ J♣"], 100);
+    assert_eq!(money, vec![0, 100]);
+}
+
+#[cfg(test)]
+fn finalize_bets(hands: &[(&str, i32, bool)], bank: i32, odd_chip: OddChip) -> (Vec<i32>, i32) {
+    let mut players: Vec<Box<dyn Player + Send>> = Vec::new();
+    for (i, &(cards, bet, fold)) in hands.iter().enumerate() {
+        let mut player = Box::new(Human::test_new(Arc::new(Mutex::new(Vec::new()))));
+        player.set_name(format!("p{}", i + 1));
+        player.set_cards(cards.split(' ').map(|name| name.parse().unwrap()).collect());
+        player.set_bet(bet);
+        player.set_fold(fold);
+        players.push(player);
+    }
+    let server_data = Arc::new(Mutex::new(ServerData {
+        started: true,
+        players,
+    }));
+    let shared = ["2♣", "7♦", "9♥", "J♠", "K♣"].iter().map(|name| name.parse().unwrap()).collect();
+    let mut table = Table::at_showdown(&mut server_data.clone(), shared, bank);
+    table.set_odd_chip(odd_chip);
+
+    table.finalize();
+
+    let server = server_data.lock().unwrap();
+    let money: Vec<i32> = server.players.iter().map(|player| player.get_money()).collect();
+    assert_eq!(money.iter().sum::<i32>() + table.bank, hands.iter().map(|hand| hand.1).sum::<i32>() + bank);
+    (money, table.bank)
+}
+
+#[test]
+fn test_side_pots() {
+    let pots = Table::side_pots(&[50, 200, 200], &[true, true, true]);
+    assert_eq!(pots.iter().map(|pot| pot.amount).collect::<Vec<i32>>(), vec![150, 300]);
+    assert_eq!(pots[0].eligible, vec![0, 1, 2]);
+    assert_eq!(pots[1].eligible, vec![1, 2]);
+
+    // Folded chips play in every pot they reach, and above every live bet in the last
+    let pots = Table::side_pots(&[30, 100, 60, 300, 20], &[true, true, true, false, false]);
+    assert_eq!(pots.iter().map(|pot| pot.amount).collect::<Vec<i32>>(), vec![140, 90, 280]);
+    assert_eq!(pots[2].eligible, vec![1]);
+
+    let pots = Table::side_pots(&[0, 0], &[true, true]);
+    assert_eq!(pots.len(), 1);
+    assert_eq!(pots[0].amount, 0);
+}
+
+#[test]
+fn test_finalize_side_pots() {
+    // The short stack wins the main pot, the best of the rest the side pot
+    let (money, bank) = finalize_bets(&[("A♠ A♦", 50, false), ("Q♠ Q♥", 200, false), ("K♦ Q♦", 200, false)], 0, OddChip::LeftOfButton);
+    assert_eq!((money, bank), (vec![150, 0, 300], 0));
+
+    // The short stack loses, and the winner takes everything
+    let (money, _) = finalize_bets(&[("3♠ 4♥", 50, false), ("A♠ A♦", 200, false), ("K♦ Q♦", 200, false)], 0, OddChip::LeftOfButton);
+    assert_eq!(money, vec![0, 450, 0]);
+
+    // Two all-ins of different sizes, and a player who folded after putting in the most
+    let hands = [("A♠ A♦", 30, false), ("K♦ Q♦", 60, false), ("Q♠ Q♥", 100, false), ("3♠ 4♥", 150, true)];
+    let (money, _) = finalize_bets(&hands, 20, OddChip::LeftOfButton);
+    assert_eq!(money, vec![120 + 20, 90, 130, 0]);
+
+    // A tie splits the side pot
+    let hands = [("A♠ A♦", 40, false), ("K♦ Q♦", 100, false), ("K♥ Q♣", 100, false), ("3♠ 4♥", 20, true)];
+    let (money, _) = finalize_bets(&hands, 0, OddChip::LeftOfButton);
+    assert_eq!(money, vec![140, 60, 60, 0]);
+
+    // An uncalled bet goes back to the player who made it
+    let (money, _) = finalize_bets(&[("A♠ A♦", 50, false), ("3♠ 4♥", 300, false)], 0, OddChip::LeftOfButton);
+    assert_eq!(money, vec![100, 250]);
+}
+
+#[test]
+fn test_finalize_odd_chips() {
+    // p1 deals, so p2 is the first winner left of the button
+    let hands = [("A♠ A♦", 40, false), ("K♦ Q♦", 101, false), ("K♥ Q♣", 101, false), ("3♠ 4♥", 41, true)];
+    let (money, bank) = finalize_bets(&hands, 0, OddChip::LeftOfButton);
+    assert_eq!((money, bank), (vec![160, 62, 61, 0], 0));
+
+    // The king of diamonds is of a lower suit than the king of hearts
+    let (money, bank) = finalize_bets(&hands, 0, OddChip::HighCardLowestSuit);
+    assert_eq!((money, bank), (vec![160, 62, 61, 0], 0));
+    let hands = [("A♠ A♦", 40, false), ("K♥ Q♦", 101, false), ("K♦ Q♣", 101, false), ("3♠ 4♥", 41, true)];
+    let (money, bank) = finalize_bets(&hands, 0, OddChip::HighCardLowestSuit);
+    assert_eq!((money, bank), (vec![160, 61, 62, 0], 0));
+
+    // Three ways, two odd chips go round from the button, or to the kings of diamonds and hearts
+    let hands = [("K♠ Q♠", 50, false), ("K♥ Q♦", 50, false), ("K♦ Q♣", 50, false), ("3♠ 4♥", 2, true)];
+    let (money, _) = finalize_bets(&hands, 0, OddChip::LeftOfButton);
+    assert_eq!(money, vec![50, 51, 51, 0]);
+    let (money, _) = finalize_bets(&hands, 0, OddChip::HighCardLowestSuit);
+    assert_eq!(money, vec![50, 51, 51, 0]);
+
+    // Between pairs of eights the lowest suit is the eight of clubs
+    let hands = [("8♦ 8♣", 50, false), ("8♠ 8♥", 50, false), ("4♠ 4♥", 1, true)];
+    let (money, _) = finalize_bets(&hands, 0, OddChip::HighCardLowestSuit);
+    assert_eq!(money, vec![51, 50, 0]);
+}
+
+#[test]
+fn test_card_list_notation() {
+    let cards: Vec<Card> = ["A♠", "Th"].iter().map(|name| name.parse().unwrap()).collect();
+    assert_eq!(Table::card_list(&cards, Notation::Unicode), "A♠ T♥");
+    assert_eq!(Table::card_list(&cards, Notation::Ascii), "As Th");
 }
@@ -1,61 +1,608 @@
 use std::*;
 use std;
+use std::io::{BufRead, BufReader, Write};
 use std::net::{TcpListener, TcpStream};
 use std::sync::*;
 
 use human::*;
 use player::*;
 
+use super::betting::*;
 use super::card::*;
+use super::dealer::*;
 use super::message::*;
 use super::server::*;
 use super::table::*;
+use super::variant::*;
 
-#[test]
-pub fn table_test() {
-    let p1 = Arc::new(Mutex::new(Vec::new()));
-    let p2 = Arc::new(Mutex::new(Vec::new()));
+fn scripted(names: &str) -> DeckSource {
+    DeckSource::Scripted(names.split(' ').map(|name| name.parse().unwrap()).collect())
+}
+
+/// A player that plays from a script. Once named, it hands over its next scripted message
+/// only when it is its turn, like a client waiting for MOVE
+struct Scripted {
+    script: Arc<Mutex<Vec<String>>>,
+    /// Messages sent without waiting for the turn
+    early: Arc<Mutex<Vec<String>>>,
+    sent: Arc<Mutex<Vec<String>>>,
+    /// Whether the player disconnected, after which it only answers FOLD like a human does
+    dead: Arc<Mutex<bool>>,
+    prompted: bool,
+    name: Option<String>,
+    cards: Option<Vec<Card>>,
+    money: i32,
+    fold: bool,
+    bet: i32,
+}
+
+impl Player for Scripted {
+    fn get_message(&mut self) -> Option<String> {
+        let mut early = self.early.lock().unwrap();
+        if !early.is_empty() {
+            return Some(early.remove(0));
+        }
+        let mut script = self.script.lock().unwrap();
+        if (self.name.is_some() && !self.prompted) || script.is_empty() {
+            None
+        } else {
+            Some(script.remove(0))
+        }
+    }
+
+    fn wait_for_message(&mut self) -> String {
+        loop {
+            {
+                let mut script = self.script.lock().unwrap();
+                if !script.is_empty() {
+                    self.prompted = false;
+                    return script.remove(0);
+                } else if *self.dead.lock().unwrap() {
+                    return "FOLD".to_string();
+                }
+            }
+            thread::yield_now();
+        }
+    }
+
+    fn set_cards(&mut self, cards: Vec<Card>) {
+        self.cards = Some(cards);
+    }
+
+    fn get_cards(&self) -> Vec<Card> {
+        self.cards.clone().unwrap()
+    }
+
+    fn get_name(&self) -> Option<String> {
+        self.name.clone()
+    }
+
+    fn set_name(&mut self, name: String) {
+        self.name = Some(name);
+    }
+
+    fn get_money(&self) -> i32 {
+        self.money
+    }
+
+    fn set_money(&mut self, money: i32) {
+        self.money = money;
+    }
+
+    fn get_fold(&self) -> bool {
+        self.fold
+    }
+
+    fn set_fold(&mut self, fold: bool) {
+        self.fold = fold;
+    }
+
+    fn get_bet(&self) -> i32 {
+        self.bet
+    }
+
+    fn set_bet(&mut self, bet: i32) {
+        self.bet = bet;
+    }
+
+    fn bet(&mut self, bet: i32) {
+        let bet = bet.min(self.bet + self.money);
+        self.money -= bet - self.bet;
+        self.bet = bet;
+    }
+
+    fn is_dead(&self) -> bool {
+        *self.dead.lock().unwrap()
+    }
 
+    fn send(&mut self, msg: &str) {
+        let name = self.name.clone().unwrap_or_default();
+        if msg == format!("MOVE {}", name) || msg == format!("DRAWMOVE {}", name) {
+            self.prompted = true;
+        }
+        self.sent.lock().unwrap().push(msg.to_string());
+    }
+}
+
+/// A scripted player of a test table and the messages sent to it
+struct Seat {
+    script: Arc<Mutex<Vec<String>>>,
+    early: Arc<Mutex<Vec<String>>>,
+    sent: Arc<Mutex<Vec<String>>>,
+    dead: Arc<Mutex<bool>>,
+}
+
+impl Seat {
+    /// A seat whose player is ready under the given name
+    fn new(name: &str) -> Seat {
+        let seat = Seat {
+            script: Arc::new(Mutex::new(Vec::new())),
+            early: Arc::new(Mutex::new(Vec::new())),
+            sent: Arc::new(Mutex::new(Vec::new())),
+            dead: Arc::new(Mutex::new(false)),
+        };
+        seat.play(&format!("READY {}", name));
+        seat
+    }
+
+    fn player(&self) -> Box<dyn Player + Send> {
+        Box::new(Scripted {
+            script: self.script.clone(),
+            early: self.early.clone(),
+            sent: self.sent.clone(),
+            dead: self.dead.clone(),
+            prompted: false,
+            name: None,
+            cards: None,
+            money: 0,
+            fold: false,
+            bet: 0,
+        })
+    }
+
+    /// Adds a message to the end of the script
+    fn play(&self, msg: &str) {
+        self.script.lock().unwrap().push(msg.to_string());
+    }
+
+    /// Sends a message right away, whether it is the player's turn or not
+    fn interrupt(&self, msg: &str) {
+        self.early.lock().unwrap().push(msg.to_string());
+    }
+
+    /// Disconnects the player
+    fn disconnect(&self) {
+        *self.dead.lock().unwrap() = true;
+    }
+
+    /// The messages sent to the player that start with the given word
+    fn sent(&self, word: &str) -> Vec<String> {
+        self.sent.lock().unwrap().iter().filter(|msg| msg.split(' ').next() == Some(word)).cloned().collect()
+    }
+}
+
+/// A started table of the given players with 300 each, the first one dealing
+/// # Param deck: the cards dealt, first card first
+fn table_with(players: Vec<Box<dyn Player + Send>>, deck: &str) -> (Table, Arc<Mutex<ServerData>>) {
+    let count = players.len() as i32;
     let server_data = Arc::new(Mutex::new(ServerData {
         started: true,
-        players: vec![
-            Box::new(Human::test_new(p1.clone())),
-            Box::new(Human::test_new(p2.clone())),
-        ],
+        players,
     }));
     let mut table = Table::new(&mut server_data.clone());
+    table.set_deck_source(scripted(deck));
+    table.wait_for_players(count);
+    table.start(300, 0, Some(0));
+    (table, server_data)
+}
 
-    let test1 = |msg: &str| {
-        p1.lock().unwrap().push(msg.to_string());
-    };
-    let test2 = |msg: &str| {
-        p2.lock().unwrap().push(msg.to_string());
-    };
+#[test]
+pub fn table_test() {
+    let (p1, p2) = (Seat::new("p1"), Seat::new("p2"));
+    //PLAYER p1 IS THE DEALER
+    let (mut table, server_data) = table_with(vec![p1.player(), p2.player()], "2♣ 7♦ A♠ A♥ 5♣ 9♠ 9♥ 9♦ 5♦ K♣ 5♥ 4♠");
+    table.round();
+    // A raise is at least the big blind, so 30 is refused and p1 is asked again
+    p1.play("BET 30");
+    p1.play("BET 40");
+    p2.play("BET 40");
+    table.first_bet(10, 20);
+    table.bet(3);
+    assert_eq!(p1.sent("ERROR"), vec!["ERROR raise to 30 is less than the minimum raise to 40"]);
+    assert_eq!(p1.sent("MOVE")[..3], ["MOVE p1", "MOVE p1", "MOVE p2"]);
+    table.show_card();
+    table.show_card();
+    table.show_card();
+    p1.play("BET 40");
+    p2.play("BET 40");
+    table.bet(1);
+    table.show_card();
+    // A raise is at least the last one: after p1 bets 50 more, p2 may raise to 150 but not
+    // to less than 140
+    p1.play("BET 90");
+    p2.play("BET 130");
+    p2.play("BET 150");
+    p1.play("BET 150");
+    table.bet(1);
+    assert_eq!(p2.sent("ERROR"), vec!["ERROR raise to 130 is less than the minimum raise to 140"]);
+    assert!(p1.sent("BET").contains(&"BET 150 p2".to_string()));
+    table.show_card();
+    p1.play("BET 150");
+    p2.play("FOLD");
+    table.bet(1);
+    table.finalize();
+
+    let server = server_data.lock().unwrap();
+    assert_eq!(server.players[0].get_money(), 450);
+    assert_eq!(server.players[1].get_money(), 150);
+}
+
+#[test]
+pub fn table_showdown_test() {
+    let (p1, p2) = (Seat::new("p1"), Seat::new("p2"));
+    let (mut table, server_data) = table_with(vec![p1.player(), p2.player()], "A♠ A♥ K♠ K♥ 5♣ 2♣ 7♦ 9♠ 5♦ J♥ 5♥ 3♦");
+    table.round();
+    for _ in 0..4 {
+        p1.play("BET 20");
+        p2.play("BET 20");
+    }
+    table.first_bet(10, 20);
+    table.bet(3);
+    table.show_card();
+    table.show_card();
+    table.show_card();
+    table.bet(1);
+    table.show_card();
+    table.bet(1);
+    table.show_card();
+    table.bet(1);
+    table.finalize();
+
+    let server = server_data.lock().unwrap();
+    assert_eq!(server.players[0].get_money(), 320);
+    assert_eq!(server.players[1].get_money(), 280);
+}
+
+#[test]
+pub fn table_pot_limit_test() {
+    let (p1, p2) = (Seat::new("p1"), Seat::new("p2"));
+    let (mut table, server_data) = table_with(vec![p1.player(), p2.player()], "A♠ A♥ K♠ K♥ Q♣ Q♦ J♣ J♦ 5♣ 2♣ 7♦ 9♠ 5♦ T♥ 5♥ 3♦");
+    table.set_variant(Variant::Omaha);
+    table.round();
+    {
+        let server = server_data.lock().unwrap();
+        assert_eq!(server.players[0].get_cards().len(), 4);
+        assert_eq!(server.players[1].get_cards().len(), 4);
+    }
+
+    // Blinds 10/20 make a pot of 30, so the small blind may call 10 and raise 40, to 60.
+    // The old BET is cut down to that, a RAISE or ALLIN over the pot is an error
+    p1.play("BET 1000");
+    p2.play("ALLIN");
+    p2.play("RAISE 200");
+    p2.play("BET 60");
+    table.first_bet(10, 20);
+    table.bet(3);
+    assert_eq!(p2.sent("ERROR"), vec![
+        "ERROR cannot go all in, raise to 300 is more than the pot limit of 180",
+        "ERROR raise to 200 is more than the pot limit of 180",
+    ]);
+
+    let server = server_data.lock().unwrap();
+    assert_eq!(server.players[0].get_bet(), 60);
+    assert_eq!(server.players[1].get_bet(), 60);
+}
+
+#[test]
+pub fn table_triple_draw_test() {
+    let (p1, p2) = (Seat::new("p1"), Seat::new("p2"));
+    let (mut table, server_data) = table_with(vec![p1.player(), p2.player()], "7♠ 5♥ 4♦ 3♣ K♠ 8♠ 6♥ 4♠ 3♦ 2♠ 2♣ Q♥");
+    table.set_variant(Variant::TripleDraw);
+    table.round();
+    // p1 breaks the king for the deuce, p2 stands pat on an eight low. The ten is not held,
+    // so the first draw is refused as a whole and p1 is asked again
+    for &msg in ["BET 20", "DRAW K♠ T♠", "DRAW K♠", "BET 20"].iter() {
+        p1.play(msg);
+    }
+    for &msg in ["BET 20", "DRAW", "BET 20"].iter() {
+        p2.play(msg);
+    }
+    table.first_bet(10, 20);
+    table.bet(3);
+    table.draw();
+    assert_eq!(p1.sent("ERROR"), vec!["ERROR cannot discard T♠"]);
+    assert_eq!(p1.sent("DRAWMOVE"), vec!["DRAWMOVE p1", "DRAWMOVE p1", "DRAWMOVE p2"]);
+    {
+        let server = server_data.lock().unwrap();
+        let cards: Vec<String> = server.players[0].get_cards().iter().map(|card| card.to_string()).collect();
+        assert_eq!(cards.join(" "), "7♠ 5♥ 4♦ 3♣ 2♣");
+        assert_eq!(server.players[1].get_cards().len(), 5);
+    }
+    table.bet(1);
+    table.finalize();
+    {
+        let server = server_data.lock().unwrap();
+        assert_eq!(server.players[0].get_money(), 320);
+        assert_eq!(server.players[1].get_money(), 280);
+    }
 
-    test1("READY p1");
-    test2("READY p2");
-    table.wait_for_players(2);
-    table.start(300, 0, Some(0)); //PLAYER p1 IS THE DEALER
+    // p2 disconnects before the next draw, which is the same as a fold, and p1 takes the pot
+    table.round();
+    p2.play("BET 20");
+    p1.play("BET 20");
+    table.first_bet(10, 20);
+    table.bet(3);
+    p2.disconnect();
+    p1.play("DRAW");
+    table.draw();
+    table.bet(1);
+    table.finalize();
+    assert!(p1.sent("FOLD").contains(&"FOLD p2".to_string()));
+
+    let server = server_data.lock().unwrap();
+    assert_eq!(server.players[0].get_money(), 340);
+    assert_eq!(server.players[1].get_money(), 260);
+}
+
+#[test]
+pub fn table_full_draw_test() {
+    // The most seats triple draw deals to, each player drawing five. Seven cards are left
+    // after the deal, so from the second player on the draw needs the discards shuffled in
+    let seats: Vec<Seat> = (1..=Variant::TripleDraw.max_players()).map(|i| Seat::new(&format!("p{}", i))).collect();
+    let deck: Vec<String> = (0..52).map(|id| Card::from_id(id).to_string()).collect();
+    let (mut table, server_data) = table_with(seats.iter().map(|seat| seat.player()).collect(), &deck.join(" "));
+    table.set_variant(Variant::TripleDraw);
+    table.round();
+    for (i, seat) in seats.iter().enumerate() {
+        seat.play(&format!("DRAW {}", deck[i * 5..i * 5 + 5].join(" ")));
+    }
+    table.draw();
+
+    let server = server_data.lock().unwrap();
+    let mut held = CardSet::new();
+    for (i, player) in server.players.iter().enumerate() {
+        let cards = player.get_cards();
+        assert_eq!(cards.len(), 5);
+        assert!(cards.iter().all(|card| !deck[i * 5..i * 5 + 5].contains(&card.to_string())));
+        held = held | cards.iter().collect();
+    }
+    assert_eq!(held.len(), 45);
+}
+
+#[test]
+pub fn table_side_pot_test() {
+    let (p1, p2, p3) = (Seat::new("p1"), Seat::new("p2"), Seat::new("p3"));
+    let (mut table, server_data) = table_with(vec![p1.player(), p2.player(), p3.player()], "A♠ A♦ K♦ Q♦ Q♠ Q♥ 3♣ 2♣ 7♦ 9♥ 4♣ J♠ 5♣ K♣");
+    server_data.lock().unwrap().players[0].set_money(100);
     table.round();
-    test1("BET 30");
-    test2("BET 40");
-    test1("BET 40");
+
+    // p2 deals, p3 and p1 post the blinds. Everyone is all in and p1 only covers 100 of it
+    p2.play("BET 300");
+    p3.play("BET 300");
+    p1.play("BET 100");
     table.first_bet(10, 20);
     table.bet(3);
+    {
+        let server = server_data.lock().unwrap();
+        assert_eq!(server.players[0].get_bet(), 100);
+        assert_eq!(server.players[0].get_money(), 0);
+    }
+    for _ in 0..3 {
+        table.show_card();
+    }
+    table.bet(1);
     table.show_card();
+    table.bet(1);
     table.show_card();
+    table.bet(1);
+    table.finalize();
+
+    // Aces win the main pot of 300, kings beat queens for the side pot of 400
+    let server = server_data.lock().unwrap();
+    assert_eq!(server.players[0].get_money(), 300);
+    assert_eq!(server.players[1].get_money(), 400);
+    assert_eq!(server.players[2].get_money(), 0);
+}
+
+#[test]
+pub fn table_invalid_action_test() {
+    let p1 = Seat::new("p1");
+    p1.play("DRAW 2♣");
+    p1.play("BET 20");
+
+    // p2 is a client on a connection, so it can talk whenever it likes
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let mut client = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+    let (stream, _) = listener.accept().unwrap();
+    client.write_all(b"READY p2\n").unwrap();
+
+    let (mut table, server_data) = table_with(vec![p1.player(), Box::new(Human::new(stream))], "2♣ 7♦ A♠ A♥ 5♣ 9♠ 9♥ 9♦ 5♦ K♣ 5♥ 4♠");
+    let game = thread::spawn(move || {
+        table.round();
+        table.first_bet(10, 20);
+        table.bet(3);
+        for _ in 0..3 {
+            table.show_card();
+        }
+        table.bet(1);
+        table.finalize();
+    });
+
+    // Reads up to the given line, keeping the errors
+    let mut reader = BufReader::new(client.try_clone().unwrap());
+    let mut errors = |until: &str| {
+        let mut errors = Vec::new();
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            match line.trim() {
+                line if line == until => return errors,
+                line if line.starts_with("ERROR") => errors.push(line.to_string()),
+                _ => {}
+            }
+        }
+    };
+
+    // p2 is the big blind and acts after p1 calls
+    assert!(errors("MOVE p2").is_empty());
+    client.write_all(b"BET 5\n").unwrap();
+    assert_eq!(errors("MOVE p2"), vec!["ERROR bet of 5 is less than the 20 to call"]);
+    client.write_all(b"BET 30\n").unwrap();
+    assert_eq!(errors("MOVE p2"), vec!["ERROR raise to 30 is less than the minimum raise to 40"]);
+    client.write_all(b"BET 400\n").unwrap();
+    assert_eq!(errors("MOVE p2"), vec!["ERROR bet of 400 is more than the 300 you have"]);
+    client.write_all(b"SHOVE\n").unwrap();
+    assert_eq!(errors("MOVE p2"), vec!["ERROR cannot parse packet"]);
+    client.write_all(b"BET 40\n").unwrap();
+
+    // p1 calls the raise and bets the flop
+    assert!(errors("MOVE p1").is_empty());
+    p1.play("BET 40");
+    p1.play("BET 40");
+    assert!(errors("MOVE p2").is_empty());
+    client.write_all(b"FOLD\n").unwrap();
+    game.join().unwrap();
+
+    assert!(p1.sent("ERROR").contains(&"ERROR expected CHECK, CALL, RAISE, ALLIN or FOLD".to_string()));
+    let server = server_data.lock().unwrap();
+    assert_eq!(server.players[0].get_money(), 340);
+    assert_eq!(server.players[1].get_money(), 260);
+}
+
+#[test]
+pub fn table_out_of_turn_test() {
+    let (p1, p2) = (Seat::new("p1"), Seat::new("p2"));
+    let (mut table, server_data) = table_with(vec![p1.player(), p2.player()], "2♣ 7♦ A♠ A♥ 5♣ 9♠ 9♥ 9♦ 5♦ K♣ 5♥ 4♠");
+    table.round();
+
+    // p2 raises before p1 has acted, which is answered before p1 is asked and does not count
+    p2.interrupt("RAISE 60");
+    p1.play("CALL");
+    p2.play("CHECK");
+    table.first_bet(10, 20);
+    table.bet(3);
+
+    assert_eq!(p2.sent("ERROR"), vec!["ERROR not your turn"]);
+    assert_eq!(p2.sent("BET"), vec!["BET 20 p1", "BET 20 p2"]);
+    let server = server_data.lock().unwrap();
+    assert_eq!(server.players[1].get_bet(), 20);
+}
+
+#[test]
+pub fn table_short_all_in_test() {
+    let (p1, p2, p3) = (Seat::new("p1"), Seat::new("p2"), Seat::new("p3"));
+    let (mut table, server_data) = table_with(vec![p1.player(), p2.player(), p3.player()], "A♠ A♦ K♦ Q♦ Q♠ Q♥ 3♣ 2♣ 7♦ 9♥ 4♣ J♠ 5♣ K♣");
+    server_data.lock().unwrap().players[0].set_money(50);
+    table.round();
+
+    // p2 deals and p1 is the big blind, who goes all in for 10 more than the raise to 40, less
+    // than a full raise. p2 and p3 have acted on the raise already, so they may only call or fold
+    p2.play("RAISE 40");
+    p3.play("CALL");
+    p1.play("ALLIN");
+    p2.play("RAISE 100");
+    p2.play("CALL");
+    p3.play("ALLIN");
+    p3.play("CALL");
+    table.first_bet(10, 20);
+    table.bet(3);
+
+    assert_eq!(p2.sent("ERROR"), vec!["ERROR cannot raise, the all-in to 50 was not a full raise"]);
+    assert_eq!(p3.sent("ERROR"), vec!["ERROR cannot raise, the all-in to 50 was not a full raise"]);
+    assert_eq!(p1.sent("BET"), vec!["BET 40 p2", "BET 40 p3", "BET 50 p1", "BET 50 p2", "BET 50 p3"]);
+}
+
+#[test]
+pub fn table_actions_test() {
+    let (p1, p2) = (Seat::new("p1"), Seat::new("p2"));
+    let (mut table, server_data) = table_with(vec![p1.player(), p2.player()], "2♣ 7♦ A♠ A♥ 5♣ 9♠ 9♥ 9♦ 5♦ K♣ 5♥ 4♠");
+    table.round();
+
+    // p1 is the small blind and cannot check the big blind
+    p1.play("CHECK");
+    p1.play("CALL");
+    p2.play("RAISE 20");
+    p2.play("RAISE 60");
+    p1.play("CALL");
+    table.first_bet(10, 20);
+    table.bet(3);
+    for _ in 0..3 {
+        table.show_card();
+    }
+    p1.play("CHECK");
+    p2.play("CHECK");
+    table.bet(1);
     table.show_card();
-    test1("BET 40");
-    test2("BET 40");
+    p1.play("BET 100");
+    p2.play("ALLIN");
+    p1.play("CALL");
+    table.bet(1);
+    table.show_card();
+    table.bet(1);
+    table.finalize();
+
+    assert!(p1.sent("ERROR").contains(&"ERROR cannot check with 10 to call".to_string()));
+    assert!(p2.sent("ERROR").contains(&"ERROR raise to 20 is not more than the bet of 20".to_string()));
+    assert_eq!(p2.sent("BET"), vec!["BET 20 p1", "BET 60 p2", "BET 60 p1", "BET 60 p1", "BET 60 p2", "BET 100 p1", "BET 300 p2", "BET 300 p1"]);
+
+    let server = server_data.lock().unwrap();
+    assert_eq!(server.players[0].get_money(), 0);
+    assert_eq!(server.players[1].get_money(), 600);
+}
+
+#[test]
+pub fn table_fixed_limit_test() {
+    let (p1, p2) = (Seat::new("p1"), Seat::new("p2"));
+    let (mut table, server_data) = table_with(vec![p1.player(), p2.player()], "2♣ 7♦ A♠ A♥ 5♣ 9♠ 9♥ 9♦ 5♦ K♣ 5♥ 4♠");
+    table.set_structure(BettingStructure::FixedLimit { small_bet: 20, big_bet: 40, cap: 4 });
+    table.round();
+
+    // Every raise is of the small bet, and the fourth bet caps the round. Any other size
+    // is an error, an ALLIN for more too
+    p1.play("RAISE 50");
+    p1.play("RAISE 40");
+    p2.play("RAISE 65");
+    p2.play("RAISE 60");
+    p1.play("ALLIN");
+    p1.play("RAISE 80");
+    p2.play("RAISE 100");
+    p2.play("CALL");
+    table.first_bet(10, 20);
+    table.bet(3);
+    for _ in 0..3 {
+        table.show_card();
+    }
+    p1.play("CHECK");
+    p2.play("RAISE 100");
+    p1.play("CALL");
     table.bet(1);
+
+    // Then of the big bet
     table.show_card();
-    test1("BET 100");
-    test2("BET 150");
-    test1("BET 150");
+    p1.play("RAISE 150");
+    p1.play("RAISE 140");
+    p2.play("CALL");
     table.bet(1);
     table.show_card();
-    test1("BET 150");
-    test2("FOLD");
+    p1.play("CHECK");
+    p2.play("CHECK");
     table.bet(1);
     table.finalize();
+
+    assert_eq!(p1.sent("ERROR"), vec![
+        "ERROR fixed-limit raise is to 40",
+        "ERROR cannot go all in, fixed-limit raise is to 80",
+        "ERROR fixed-limit raise is to 140",
+    ]);
+    assert_eq!(p2.sent("ERROR"), vec!["ERROR fixed-limit raise is to 60", "ERROR fixed-limit betting is capped at 4 bets and raises"]);
+    assert_eq!(p2.sent("BET"), vec![
+        "BET 40 p1", "BET 60 p2", "BET 80 p1", "BET 80 p2",
+        "BET 80 p1", "BET 100 p2", "BET 100 p1",
+        "BET 140 p1", "BET 140 p2",
+        "BET 140 p1", "BET 140 p2",
+    ]);
+
+    let server = server_data.lock().unwrap();
+    assert_eq!(server.players[0].get_money(), 160);
+    assert_eq!(server.players[1].get_money(), 440);
 }
@@ -0,0 +1,210 @@
+use tendeuce_ai::util::best::*;
+use tendeuce_ai::util::evaluator::*;
+
+use card::*;
+
+/// The game being dealt at the table
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Variant {
+    Holdem,
+    Omaha,
+    /// 6+ Hold'em, dealt from the 36 cards six and up
+    ShortDeck,
+    /// Omaha with the pot split between the best high and the best eight-or-better low
+    OmahaHiLo,
+    /// Deuce-to-seven triple draw, five hole cards and three draws, the best low wins
+    TripleDraw,
+}
+
+impl Variant {
+    pub fn from_id(id: i32) -> Variant {
+        match id {
+            1 => Variant::Omaha,
+            2 => Variant::ShortDeck,
+            3 => Variant::OmahaHiLo,
+            4 => Variant::TripleDraw,
+            _ => Variant::Holdem,
+        }
+    }
+
+    /// The number of hole cards dealt to each player
+    pub fn hole_cards(&self) -> usize {
+        match *self {
+            Variant::Holdem | Variant::ShortDeck => 2,
+            Variant::Omaha | Variant::OmahaHiLo => 4,
+            Variant::TripleDraw => 5,
+        }
+    }
+
+    /// The number of board cards turned on each street
+    pub fn streets(&self) -> &'static [usize] {
+        match *self {
+            Variant::TripleDraw => &[],
+            _ => &[3, 1, 1],
+        }
+    }
+
+    /// The number of rounds in which players may replace their hole cards
+    pub fn draws(&self) -> usize {
+        match *self {
+            Variant::TripleDraw => 3,
+            _ => 0,
+        }
+    }
+
+    /// The ranks the deck is made of
+    pub fn ranks(&self) -> &'static [Rank] {
+        match *self {
+            Variant::Holdem | Variant::Omaha | Variant::OmahaHiLo | Variant::TripleDraw => &Rank::ALL,
+            Variant::ShortDeck => &Rank::ALL[4..],
+        }
+    }
+
+    /// The most players a hand can be dealt to, ten at most. In a draw a player's discards
+    /// only go back in after they drew, so the cards no one holds must cover a whole new hand
+    pub fn max_players(&self) -> usize {
+        let cards = self.ranks().len() * 4;
+        if self.draws() > 0 {
+            ((cards - self.hole_cards()) / self.hole_cards()).min(10)
+        } else {
+            10
+        }
+    }
+
+    /// Whether raises are capped at the size of the pot
+    pub fn pot_limit(&self) -> bool {
+        match *self {
+            Variant::Holdem | Variant::ShortDeck | Variant::TripleDraw => false,
+            Variant::Omaha | Variant::OmahaHiLo => true,
+        }
+    }
+
+    /// The evaluator rank of a player's best hand, compare ranks with `order`
+    pub fn rank(&self, hole: &[Card], board: &[Card]) -> usize {
+        let hole: CardSet = hole.iter().collect();
+        let board: CardSet = board.iter().collect();
+        match *self {
+            Variant::Holdem => eval_set(hole | board),
+            Variant::Omaha | Variant::OmahaHiLo => eval_omaha(hole, board),
+            Variant::ShortDeck => eval_short_deck(hole | board),
+            Variant::TripleDraw => eval_deuce_seven(hole),
+        }
+    }
+
+    /// The rank of a player's best qualifying low, lower is better
+    /// # Returns: None if the game has no low or the hand does not qualify
+    pub fn low(&self, hole: &[Card], board: &[Card]) -> Option<usize> {
+        match *self {
+            Variant::OmahaHiLo => eval_omaha_low(hole.iter().collect(), board.iter().collect()),
+            _ => None,
+        }
+    }
+
+    /// The cards and name that describe a rank, e.g. ("A A A K K", "Aces Full over Kings")
+    pub fn description(&self, rank: usize) -> (&'static str, &'static str) {
+        self.game().description(rank)
+    }
+
+    /// The five cards a player's best hand is made of
+    pub fn best_five(&self, hole: &[Card], board: &[Card]) -> BestFive {
+        best_five(self.game(), hole.iter().collect(), board.iter().collect())
+    }
+
+    /// The five cards a player's best qualifying low is made of, if there is one
+    pub fn best_low_five(&self, hole: &[Card], board: &[Card]) -> Option<BestFive> {
+        best_low_five(self.game(), hole.iter().collect(), board.iter().collect())
+    }
+
+    /// How the evaluator picks and ranks the five cards of a hand
+    fn game(&self) -> Game {
+        match *self {
+            Variant::Holdem => Game::Holdem,
+            Variant::Omaha | Variant::OmahaHiLo => Game::Omaha,
+            Variant::ShortDeck => Game::ShortDeck,
+            Variant::TripleDraw => Game::DeuceSeven,
+        }
+    }
+
+    /// Orders evaluator ranks, lower is better
+    pub fn order(&self, rank: usize) -> usize {
+        self.game().order(rank)
+    }
+
+    /// The category a rank is announced as
+    pub fn category(&self, rank: usize) -> Category {
+        match *self {
+            Variant::TripleDraw => deuce_seven_category(&rank),
+            _ => category(&rank),
+        }
+    }
+}
+
+#[test]
+fn test_variant_rank() {
+    let cards = |names: &str| names.split(' ').map(|name| name.parse().unwrap()).collect::<Vec<Card>>();
+    let board = cards("K♥ K♦ 5♣ 5♠ 2♥");
+
+    let holdem = Variant::Holdem.rank(&cards("K♠ 9♦"), &board);
+    assert_eq!(category(&holdem), Category::FullHouse);
+
+    let omaha = Variant::Omaha.rank(&cards("K♠ 9♦ 8♣ 7♥"), &board);
+    assert_eq!(category(&omaha), Category::ThreeOfAKind);
+}
+
+#[test]
+fn test_variant_short_deck() {
+    let cards = |names: &str| names.split(' ').map(|name| name.parse().unwrap()).collect::<Vec<Card>>();
+    let board = cards("K♥ K♦ 9♥ 6♥ 7♣");
+
+    let full_house = Variant::ShortDeck.rank(&cards("K♠ 9♦"), &board);
+    let flush = Variant::ShortDeck.rank(&cards("A♥ T♥"), &board);
+    assert!(Variant::ShortDeck.order(flush) < Variant::ShortDeck.order(full_house));
+    assert!(Variant::Holdem.order(full_house) < Variant::Holdem.order(flush));
+
+    let wheel = Variant::ShortDeck.rank(&cards("A♠ 8♦"), &board);
+    assert_eq!(category(&wheel), Category::Straight);
+    assert_eq!(Variant::ShortDeck.ranks().len(), 9);
+}
+
+#[test]
+fn test_variant_low() {
+    let cards = |names: &str| names.split(' ').map(|name| name.parse().unwrap()).collect::<Vec<Card>>();
+    let board = cards("3♠ 4♥ 8♦ K♣ Q♠");
+
+    assert_eq!(Variant::OmahaHiLo.low(&cards("A♥ 2♣ K♦ Q♣"), &board), Some(0x84321));
+    assert_eq!(Variant::OmahaHiLo.low(&cards("K♥ K♦ Q♦ J♣"), &board), None);
+    assert_eq!(Variant::Omaha.low(&cards("A♥ 2♣ K♦ Q♣"), &board), None);
+}
+
+#[test]
+fn test_variant_triple_draw() {
+    let cards = |names: &str| names.split(' ').map(|name| name.parse().unwrap()).collect::<Vec<Card>>();
+
+    let number_one = Variant::TripleDraw.rank(&cards("7♠ 5♥ 4♦ 3♣ 2♠"), &[]);
+    let wheel = Variant::TripleDraw.rank(&cards("A♠ 5♥ 4♦ 3♣ 2♠"), &[]);
+    let straight = Variant::TripleDraw.rank(&cards("6♠ 5♥ 4♦ 3♣ 2♠"), &[]);
+    assert!(Variant::TripleDraw.order(number_one) < Variant::TripleDraw.order(wheel));
+    assert!(Variant::TripleDraw.order(wheel) < Variant::TripleDraw.order(straight));
+    assert_eq!(Variant::TripleDraw.category(wheel), Category::HighCard);
+    assert_eq!(Variant::Holdem.category(wheel), Category::Straight);
+
+    // Nine hands of five leave seven cards, enough for the first player to draw five
+    assert_eq!(Variant::TripleDraw.max_players(), 9);
+    assert_eq!(Variant::Holdem.max_players(), 10);
+}
+
+#[test]
+fn test_variant_best_five() {
+    let cards = |names: &str| names.split(' ').map(|name| name.parse().unwrap()).collect::<Vec<Card>>();
+    let names = |best: BestFive| best.cards.iter().map(|card| card.to_string()).collect::<Vec<String>>().join(" ");
+
+    let board = cards("K♥ K♦ 5♣ 5♠ 2♥");
+    assert_eq!(names(Variant::Holdem.best_five(&cards("K♠ 9♦"), &board)), "K♠ K♥ K♦ 5♠ 5♣");
+    assert_eq!(names(Variant::Omaha.best_five(&cards("K♠ 9♦ 8♣ 7♥"), &board)), "K♠ K♥ K♦ 9♦ 5♣");
+    assert_eq!(names(Variant::Holdem.best_five(&cards("A♠ 3♦"), &cards("4♥ 5♦ 2♣ K♣ K♠"))), "5♦ 4♥ 3♦ 2♣ A♠");
+    assert_eq!(names(Variant::ShortDeck.best_five(&cards("A♠ 8♦"), &cards("K♥ K♦ 9♥ 6♥ 7♣"))), "9♥ 8♦ 7♣ 6♥ A♠");
+
+    let low = Variant::OmahaHiLo.best_low_five(&cards("A♥ 2♣ K♦ Q♣"), &cards("3♠ 4♥ 8♦ K♣ Q♠"));
+    assert_eq!(names(low.unwrap()), "8♦ 4♥ 3♠ 2♣ A♥");
+    assert_eq!(Variant::OmahaHiLo.best_low_five(&cards("K♥ K♦ Q♦ J♣"), &cards("3♠ 4♥ 8♦ K♣ Q♠")), None);
+}